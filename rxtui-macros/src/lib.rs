@@ -2,8 +2,8 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::{
-    DeriveInput, Expr, FnArg, Ident, ImplItem, ItemFn, ItemImpl, LitStr, Pat, PatType, Token, Type,
-    parse_macro_input,
+    DeriveInput, Expr, FnArg, Ident, ImplItem, ItemFn, ItemImpl, LitStr, Pat, PatType, ReturnType,
+    Signature, Token, Type, parse_macro_input,
 };
 
 //--------------------------------------------------------------------------------------------------
@@ -89,6 +89,47 @@ impl Parse for UpdateArgs {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Checks whether a method has the signature of the `Component` hook it is named after.
+///
+/// Only `&self` methods are hooks: `key` takes nothing and returns an `Option`,
+/// `props_changed` takes a reference and returns `bool`, and `on_mount` and
+/// `on_unmount` take a reference and return nothing. Other methods that share
+/// a hook's name are left alone.
+fn is_component_hook(sig: &Signature) -> bool {
+    let takes_ref_self = matches!(
+        sig.inputs.first(),
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    if !takes_ref_self || sig.asyncness.is_some() || !sig.generics.params.is_empty() {
+        return false;
+    }
+
+    let takes_one_ref = sig.inputs.len() == 2
+        && matches!(
+            sig.inputs.last(),
+            Some(FnArg::Typed(PatType { ty, .. })) if matches!(**ty, Type::Reference(_))
+        );
+    let returns = |name: &str| match &sig.output {
+        ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(path) => path.path.segments.last().is_some_and(|s| s.ident == name),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    };
+    let returns_unit = match &sig.output {
+        ReturnType::Default => true,
+        ReturnType::Type(_, ty) => matches!(&**ty, Type::Tuple(tuple) if tuple.elems.is_empty()),
+    };
+
+    match sig.ident.to_string().as_str() {
+        "key" => sig.inputs.len() == 1 && returns("Option"),
+        "props_changed" => takes_one_ref && returns("bool"),
+        "on_mount" | "on_unmount" => takes_one_ref && returns_unit,
+        _ => false,
+    }
+}
+
 /// Extract parameter name and type from a function argument
 fn extract_param_info(arg: &FnArg) -> Option<(Ident, Type)> {
     if let FnArg::Typed(PatType { pat, ty, .. }) = arg
@@ -164,6 +205,34 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
                 use rxtui::providers::EffectsProvider;
                 self.__component_effects_impl(ctx)
             }
+
+            // Use method resolution to call a `key` method renamed by #[component] if it exists,
+            // otherwise fall back to the trait's default implementation (no key)
+            fn key(&self) -> Option<String> {
+                use rxtui::providers::KeyProvider;
+                self.__component_key_impl()
+            }
+
+            // Use method resolution to call a `props_changed` method renamed by #[component]
+            // if it exists, otherwise fall back to the trait's default implementation (false)
+            fn props_changed(&self, previous: &dyn rxtui::Component) -> bool {
+                use rxtui::providers::PropsProvider;
+                self.__component_props_changed_impl(previous)
+            }
+
+            // Use method resolution to call an `on_mount` method renamed by #[component]
+            // if it exists, otherwise fall back to the trait's default implementation (no-op)
+            fn on_mount(&self, ctx: &rxtui::Context) {
+                use rxtui::providers::MountProvider;
+                self.__component_on_mount_impl(ctx)
+            }
+
+            // Use method resolution to call an `on_unmount` method renamed by #[component]
+            // if it exists, otherwise fall back to the trait's default implementation (no-op)
+            fn on_unmount(&self, ctx: &rxtui::Context) {
+                use rxtui::providers::UnmountProvider;
+                self.__component_on_unmount_impl(ctx)
+            }
         }
 
     };
@@ -617,6 +686,8 @@ pub fn effect(_args: TokenStream, input: TokenStream) -> TokenStream {
 /// 1. Collects all methods marked with `#[effect]`
 /// 2. Generates helper methods for each effect
 /// 3. Automatically creates the `effects()` method
+/// 4. Routes `key`, `props_changed`, `on_mount` and `on_unmount` methods to the
+///    derived `Component` impl, when their signatures match the trait's hooks
 ///
 /// # Example
 ///
@@ -722,6 +793,10 @@ pub fn component(_args: TokenStream, input: TokenStream) -> TokenStream {
                 method.attrs.push(syn::parse_quote! { #[allow(dead_code)] });
                 processed_items.push(ImplItem::Fn(method));
             } else {
+                // Identity and lifecycle methods are picked up by the derive through the providers
+                if is_component_hook(&method.sig) {
+                    method.sig.ident = format_ident!("__component_{}_impl", method.sig.ident);
+                }
                 processed_items.push(ImplItem::Fn(method));
             }
         } else {
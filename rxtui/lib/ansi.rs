@@ -0,0 +1,400 @@
+//! Parsing text colored with ANSI escape sequences into rich text.
+//!
+//! Command output often carries SGR sequences (`ESC [ ... m`) for colors and
+//! attributes. [`parse`] turns such output into one [`RichText`] per line,
+//! with the sequences mapped to [`TextStyle`]s, so it can be shown as the
+//! program meant it. Styles carry over from one line to the next, as they do
+//! in a terminal.
+//!
+//! The 16 standard colors, the 256-color palette and 24-bit colors are
+//! understood, in both the `;` and `:` separated forms. The other escape
+//! sequences, such as cursor movement, clearing and window titles, are
+//! dropped, along with control characters other than tabs. A carriage return
+//! starts its line over, like a progress bar redrawing itself.
+//!
+//! ```
+//! use rxtui::Color;
+//! use rxtui::ansi;
+//!
+//! let lines = ansi::parse("\x1b[1;31merror\x1b[0m: not found");
+//! assert_eq!(lines[0].spans[0].content, "error");
+//! let style = lines[0].spans[0].style.as_ref().unwrap();
+//! assert_eq!((style.color, style.bold), (Some(Color::Red), Some(true)));
+//!
+//! assert_eq!(ansi::strip("\x1b[32mok\x1b[m\r\n"), "ok\n");
+//! ```
+
+use crate::node::{RichText, TextSpan};
+use crate::style::{Color, TextStyle, UnderlineStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+const ESC: char = '\x1b';
+
+/// Bell, one of the terminators of an OSC sequence
+const BEL: char = '\x07';
+
+/// The 16 standard colors in SGR order, normal then bright
+const STANDARD_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Builds lines of spans as text and styles come in
+#[derive(Default)]
+struct LineBuilder {
+    lines: Vec<RichText>,
+    spans: Vec<TextSpan>,
+    style: TextStyle,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl LineBuilder {
+    /// Appends a character in the current style, extending the last span if it matches
+    fn push(&mut self, ch: char) {
+        let style = (self.style != TextStyle::default()).then(|| self.style.clone());
+        match self.spans.last_mut() {
+            Some(span) if span.style == style => span.content.push(ch),
+            _ => self.spans.push(TextSpan {
+                content: ch.to_string(),
+                style,
+                is_cursor: false,
+            }),
+        }
+    }
+
+    /// Ends the current line
+    fn newline(&mut self) {
+        self.lines.push(RichText {
+            spans: std::mem::take(&mut self.spans),
+            style: None,
+        });
+    }
+
+    /// Drops what the current line has so far, for a carriage return
+    fn restart_line(&mut self) {
+        self.spans.clear();
+    }
+
+    /// Applies the parameters of an SGR sequence to the current style
+    fn apply_sgr(&mut self, params: &str) {
+        let groups: Vec<Vec<Option<u16>>> = params
+            .split(';')
+            .map(|group| group.split(':').map(|p| p.parse().ok()).collect())
+            .collect();
+
+        let mut index = 0;
+        while index < groups.len() {
+            let group = &groups[index];
+            index += 1;
+            let style = &mut self.style;
+            match group[0].unwrap_or(0) {
+                0 => *style = TextStyle::default(),
+                1 => style.bold = Some(true),
+                3 => style.italic = Some(true),
+                4 => apply_underline(style, group.get(1).copied().flatten()),
+                5 | 6 => style.blink = Some(true),
+                7 => style.reverse = Some(true),
+                8 => style.conceal = Some(true),
+                9 => style.strikethrough = Some(true),
+                21 => apply_underline(style, Some(2)),
+                22 => style.bold = None,
+                23 => style.italic = None,
+                24 => apply_underline(style, Some(0)),
+                25 => style.blink = None,
+                27 => style.reverse = None,
+                28 => style.conceal = None,
+                29 => style.strikethrough = None,
+                code @ 30..=37 => style.color = Some(STANDARD_COLORS[code as usize - 30]),
+                code @ 40..=47 => style.background = Some(STANDARD_COLORS[code as usize - 40]),
+                code @ 90..=97 => style.color = Some(STANDARD_COLORS[code as usize - 82]),
+                code @ 100..=107 => {
+                    style.background = Some(STANDARD_COLORS[code as usize - 92]);
+                }
+                39 => style.color = None,
+                49 => style.background = None,
+                59 => style.underline_color = None,
+                code @ (38 | 48 | 58) => {
+                    let color = if group.len() > 1 {
+                        extended_color(&group[1..], true)
+                    } else {
+                        let rest: Vec<Option<u16>> =
+                            groups[index..].iter().map(|group| group[0]).collect();
+                        let (color, used) = extended_color_with_length(&rest);
+                        index += used;
+                        color
+                    };
+                    let target = match code {
+                        38 => &mut style.color,
+                        48 => &mut style.background,
+                        _ => &mut style.underline_color,
+                    };
+                    if color.is_some() {
+                        *target = color;
+                    }
+                }
+                // Dim, fonts, frames and the rest have no equivalent
+                _ => {}
+            }
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Parses ANSI-colored text into one [`RichText`] per line.
+///
+/// A trailing newline doesn't add an empty last line. Default colors
+/// (SGR 39 and 49) leave the color unset, so the text takes the color of
+/// the element it is shown in.
+pub fn parse(raw: &str) -> Vec<RichText> {
+    let mut builder = LineBuilder::default();
+    let mut chars = raw.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            ESC => match chars.next() {
+                // CSI: parameters and intermediates up to a final byte
+                Some('[') => {
+                    let mut params = String::new();
+                    for ch in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&ch) {
+                            if ch == 'm' {
+                                builder.apply_sgr(&params);
+                            }
+                            break;
+                        }
+                        params.push(ch);
+                    }
+                }
+                // OSC: a string up to BEL or ESC \, such as a title or hyperlink
+                Some(']') => {
+                    while let Some(ch) = chars.next() {
+                        if ch == BEL || (ch == ESC && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                // Other escapes: intermediates then a final byte
+                Some(ch) if ('\x20'..='\x2f').contains(&ch) => {
+                    while chars.next_if(|ch| ('\x20'..='\x2f').contains(ch)).is_some() {}
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\n' => builder.newline(),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' => builder.restart_line(),
+            '\t' => builder.push(ch),
+            ch if ch.is_control() => {}
+            ch => builder.push(ch),
+        }
+    }
+
+    if !builder.spans.is_empty() || builder.lines.is_empty() {
+        builder.newline();
+    }
+    builder.lines
+}
+
+/// Returns the text without escape sequences or control characters.
+///
+/// Lines are joined with `\n`, and a trailing newline is kept.
+pub fn strip(raw: &str) -> String {
+    let mut text = parse(raw)
+        .iter()
+        .map(|line| {
+            line.spans
+                .iter()
+                .map(|span| span.content.as_str())
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    if raw.ends_with('\n') {
+        text.push('\n');
+    }
+    text
+}
+
+/// Sets the underline from an SGR 4 subparameter, 0 turning it off
+fn apply_underline(style: &mut TextStyle, kind: Option<u16>) {
+    let underline_style = match kind {
+        Some(0) => {
+            style.underline = None;
+            style.underline_style = None;
+            return;
+        }
+        Some(2) => Some(UnderlineStyle::Double),
+        Some(3) => Some(UnderlineStyle::Curly),
+        Some(4) => Some(UnderlineStyle::Dotted),
+        Some(5) => Some(UnderlineStyle::Dashed),
+        _ => None,
+    };
+    style.underline = Some(true);
+    style.underline_style = underline_style;
+}
+
+/// Reads a color given as `5;n` or `2;r;g;b` and how many parameters it took
+fn extended_color_with_length(params: &[Option<u16>]) -> (Option<Color>, usize) {
+    match params.first() {
+        Some(Some(5)) => (extended_color(&params[..params.len().min(2)], false), 2),
+        Some(Some(2)) => (extended_color(&params[..params.len().min(4)], false), 4),
+        _ => (None, 0),
+    }
+}
+
+/// Reads a color from the subparameters after 38, 48 or 58.
+///
+/// The `:` form of a 24-bit color may put a color space id before the
+/// channels, as in `38:2::r:g:b`.
+fn extended_color(params: &[Option<u16>], colon_form: bool) -> Option<Color> {
+    match params {
+        [Some(5), Some(index), ..] => indexed_color(*index),
+        [Some(2), _, r, g, b] if colon_form => rgb(*r, *g, *b),
+        [Some(2), r, g, b, ..] => rgb(*r, *g, *b),
+        _ => None,
+    }
+}
+
+/// Returns a 24-bit color from channels, missing ones read as 0
+fn rgb(r: Option<u16>, g: Option<u16>, b: Option<u16>) -> Option<Color> {
+    let channel = |value: Option<u16>| u8::try_from(value.unwrap_or(0)).ok();
+    Some(Color::Rgb(channel(r)?, channel(g)?, channel(b)?))
+}
+
+/// Returns a color of the 256-color palette
+fn indexed_color(index: u16) -> Option<Color> {
+    match index {
+        0..=15 => Some(STANDARD_COLORS[index as usize]),
+        16..=231 => {
+            let index = index - 16;
+            let level = |value: u16| CUBE_LEVELS[value as usize];
+            Some(Color::Rgb(
+                level(index / 36),
+                level(index / 6 % 6),
+                level(index % 6),
+            ))
+        }
+        232..=255 => {
+            let gray = (8 + (index - 232) * 10) as u8;
+            Some(Color::Rgb(gray, gray, gray))
+        }
+        _ => None,
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns each span of the first line with its style
+    fn spans(raw: &str) -> Vec<(String, TextStyle)> {
+        parse(raw)[0]
+            .spans
+            .iter()
+            .map(|span| (span.content.clone(), span.style.clone().unwrap_or_default()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sgr_codes_map_to_text_styles() {
+        let spans = spans("\x1b[1;4:3;31mbad\x1b[22;24m plain red\x1b[0;7m inverted\x1b[mnone");
+        let red = TextStyle {
+            color: Some(Color::Red),
+            ..Default::default()
+        };
+        assert_eq!(spans[0].0, "bad");
+        assert_eq!(
+            spans[0].1,
+            TextStyle {
+                bold: Some(true),
+                underline: Some(true),
+                underline_style: Some(UnderlineStyle::Curly),
+                ..red.clone()
+            }
+        );
+        assert_eq!(spans[1], (" plain red".to_string(), red));
+        assert_eq!(spans[2].1.reverse, Some(true));
+        assert_eq!(spans[3], ("none".to_string(), TextStyle::default()));
+    }
+
+    #[test]
+    fn test_16_256_and_24_bit_colors() {
+        let colors = |raw: &str| {
+            let style = &spans(raw)[0].1;
+            (style.color, style.background)
+        };
+        assert_eq!(
+            colors("\x1b[92;104mx"),
+            (Some(Color::BrightGreen), Some(Color::BrightBlue))
+        );
+        assert_eq!(
+            colors("\x1b[38;5;9;48;5;196mx"),
+            (Some(Color::BrightRed), Some(Color::Rgb(255, 0, 0)))
+        );
+        assert_eq!(
+            colors("\x1b[38;5;244;48;2;10;20;30mx"),
+            (
+                Some(Color::Rgb(128, 128, 128)),
+                Some(Color::Rgb(10, 20, 30))
+            )
+        );
+        assert_eq!(
+            colors("\x1b[38:2::1:2:3;48:5:16mx"),
+            (Some(Color::Rgb(1, 2, 3)), Some(Color::Rgb(0, 0, 0)))
+        );
+        assert_eq!(colors("\x1b[31;42m\x1b[39;49mx"), (None, None));
+    }
+
+    #[test]
+    fn test_control_sequences_are_stripped() {
+        let raw = "\x1b]0;title\x07\x1b[2K\x1b[1Gdone\x1b(B\x08\n\
+                   \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\tok\r\n\
+                   10%\r100%\n";
+        assert_eq!(strip(raw), "done\nlink\tok\n100%\n");
+    }
+
+    #[test]
+    fn test_styles_carry_across_lines() {
+        let lines = parse("\x1b[33mwarn\n\ncontinued\x1b[0m\nplain");
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].spans.is_empty());
+        let color = |line: &RichText| line.spans[0].style.as_ref().and_then(|s| s.color);
+        assert_eq!(color(&lines[2]), Some(Color::Yellow));
+        assert_eq!(lines[3].spans[0].style, None);
+        assert_eq!(parse("").len(), 1);
+    }
+}
@@ -0,0 +1,300 @@
+//! Registry of described key bindings.
+//!
+//! Components register bindings with [`Context::bind`](crate::Context::bind) while
+//! rendering. Each render pass rebuilds the registry, so bindings of unmounted
+//! components disappear with them, and the complete set can be listed for help screens.
+
+use crate::component::ComponentId;
+use crate::key::{Key, KeyWithModifiers};
+use crate::render_tree::RenderNode;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Type alias for a binding's handler, shared with contexts held by effects
+type BindingHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Where a registered binding fires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingScope {
+    /// Fires regardless of focus
+    Global,
+
+    /// Fires while focus is inside the registering component, or nothing is focused
+    Component,
+}
+
+/// A key binding registered with a description
+#[derive(Clone)]
+pub struct Binding {
+    /// The key combination that triggers the binding
+    pub key: KeyWithModifiers,
+
+    /// Human-readable description, e.g. for a help overlay
+    pub description: String,
+
+    /// Whether the binding depends on focus
+    pub scope: BindingScope,
+
+    /// The component that registered the binding
+    pub component_id: ComponentId,
+
+    handler: BindingHandler,
+}
+
+/// Bindings registered during the current render pass and the last complete one
+pub(crate) struct BindingRegistry {
+    /// Bindings collected by the pass in progress
+    pending: Mutex<Vec<Binding>>,
+
+    /// Bindings of the last complete pass, used for dispatch and listing
+    active: RwLock<Vec<Binding>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Binding {
+    pub(crate) fn new(
+        key: KeyWithModifiers,
+        description: String,
+        scope: BindingScope,
+        component_id: ComponentId,
+        handler: BindingHandler,
+    ) -> Self {
+        Self {
+            key,
+            description,
+            scope,
+            component_id,
+            handler,
+        }
+    }
+
+    /// Checks whether a key press triggers this binding.
+    ///
+    /// A binding without modifiers matches the key regardless of Shift, like
+    /// plain `on_key` handlers, but never while Ctrl, Alt or Meta is held.
+    pub fn matches(&self, pressed: KeyWithModifiers) -> bool {
+        let plain = !self.key.ctrl && !self.key.alt && !self.key.shift && !self.key.meta;
+        if plain {
+            self.key.key == pressed.key && !pressed.ctrl && !pressed.alt && !pressed.meta
+        } else {
+            self.key == pressed
+        }
+    }
+
+    /// Returns whether the binding is in scope for the component owning focus.
+    fn in_scope(&self, focus_owner: Option<&ComponentId>) -> bool {
+        match (self.scope, focus_owner) {
+            (BindingScope::Global, _) | (BindingScope::Component, None) => true,
+            (BindingScope::Component, Some(owner)) => owner.is_within(&self.component_id),
+        }
+    }
+}
+
+impl BindingRegistry {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+            active: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Records a binding for the pass in progress
+    pub(crate) fn register(&self, binding: Binding) {
+        self.pending.lock().unwrap().push(binding);
+    }
+
+    /// Starts collecting bindings for a new render pass
+    pub(crate) fn begin_pass(&self) {
+        self.pending.lock().unwrap().clear();
+    }
+
+    /// Makes the bindings collected by the finished pass the active ones.
+    ///
+    /// Returns whether the listed bindings changed, so views showing them can be redrawn.
+    pub(crate) fn finish_pass(&self) -> bool {
+        let finished = std::mem::take(&mut *self.pending.lock().unwrap());
+        let mut active = self.active.write().unwrap();
+        let changed = finished.len() != active.len()
+            || finished.iter().zip(active.iter()).any(|(a, b)| {
+                a.key != b.key
+                    || a.description != b.description
+                    || a.scope != b.scope
+                    || a.component_id != b.component_id
+            });
+        *active = finished;
+        changed
+    }
+
+    /// Returns the active bindings in registration order
+    pub(crate) fn active(&self) -> Vec<Binding> {
+        self.active.read().unwrap().clone()
+    }
+
+    /// Fires every active binding triggered by the key press.
+    ///
+    /// `focused` is the focused render node, if any; component-scoped bindings
+    /// only fire while it sits inside the registering component. Tab and
+    /// BackTab belong to focus navigation and never fire bindings, nor do
+    /// characters typed into a focused element taking text input.
+    pub(crate) fn dispatch(
+        &self,
+        pressed: KeyWithModifiers,
+        focused: Option<&Rc<RefCell<RenderNode>>>,
+    ) {
+        if matches!(pressed.key, Key::Tab | Key::BackTab)
+            || focused.is_some_and(|node| takes_typed_char(&node.borrow(), pressed))
+        {
+            return;
+        }
+
+        let focus_owner = focused.and_then(owning_component);
+        let triggered: Vec<BindingHandler> = self
+            .active
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|binding| binding.matches(pressed) && binding.in_scope(focus_owner.as_ref()))
+            .map(|binding| binding.handler.clone())
+            .collect();
+
+        for handler in triggered {
+            handler();
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns whether the key press types a character into the node's text input.
+fn takes_typed_char(node: &RenderNode, pressed: KeyWithModifiers) -> bool {
+    matches!(pressed.key, Key::Char(_))
+        && !pressed.ctrl
+        && !pressed.alt
+        && !pressed.meta
+        && node.events.on_any_char.is_some()
+}
+
+/// Finds the innermost component whose rendered subtree contains the node.
+fn owning_component(node: &Rc<RefCell<RenderNode>>) -> Option<ComponentId> {
+    let mut current = Some(node.clone());
+    while let Some(node) = current {
+        let node_ref = node.borrow();
+        if let Some(path) = &node_ref.component_path {
+            return Some(path.clone());
+        }
+        current = node_ref.parent.as_ref().and_then(|parent| parent.upgrade());
+    }
+    None
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting(
+        key: KeyWithModifiers,
+        scope: BindingScope,
+        component: &str,
+        count: &Arc<AtomicUsize>,
+    ) -> Binding {
+        let count = count.clone();
+        Binding::new(
+            key,
+            "test".to_string(),
+            scope,
+            ComponentId::new(component),
+            Arc::new(move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }),
+        )
+    }
+
+    #[test]
+    fn test_binding_matches_modifiers() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let help = counting(Key::Char('?').into(), BindingScope::Global, "0", &count);
+        let save = counting(
+            KeyWithModifiers::with_ctrl(Key::Char('s')),
+            BindingScope::Global,
+            "0",
+            &count,
+        );
+
+        assert!(help.matches(KeyWithModifiers::with_shift(Key::Char('?'))));
+        assert!(!help.matches(KeyWithModifiers::with_ctrl(Key::Char('?'))));
+        assert!(save.matches(KeyWithModifiers::with_ctrl(Key::Char('s'))));
+        assert!(!save.matches(Key::Char('s').into()));
+        assert_eq!(save.key.to_string(), "Ctrl+S");
+    }
+
+    #[test]
+    fn test_registry_scopes_and_passes() {
+        let global = Arc::new(AtomicUsize::new(0));
+        let component = Arc::new(AtomicUsize::new(0));
+        let registry = BindingRegistry::new();
+
+        registry.begin_pass();
+        registry.register(counting(
+            Key::F1.into(),
+            BindingScope::Global,
+            "0.1",
+            &global,
+        ));
+        registry.register(counting(
+            Key::F1.into(),
+            BindingScope::Component,
+            "0.1",
+            &component,
+        ));
+        assert!(registry.active().is_empty());
+        assert!(registry.finish_pass());
+
+        // Focus inside the component, then in a sibling
+        let focused = Rc::new(RefCell::new(RenderNode::element()));
+        focused.borrow_mut().component_path = Some(ComponentId::new("0.1.0"));
+        registry.dispatch(Key::F1.into(), Some(&focused));
+        focused.borrow_mut().component_path = Some(ComponentId::new("0.10"));
+        registry.dispatch(Key::F1.into(), Some(&focused));
+        assert_eq!(global.load(Ordering::SeqCst), 2);
+        assert_eq!(component.load(Ordering::SeqCst), 1);
+
+        // Typed characters go to a focused text input, and Tab moves focus
+        registry.begin_pass();
+        for key in [Key::Char('q'), Key::Tab] {
+            registry.register(counting(key.into(), BindingScope::Global, "0.1", &global));
+        }
+        let ctrl_q = KeyWithModifiers::with_ctrl(Key::Char('q'));
+        registry.register(counting(ctrl_q, BindingScope::Global, "0.1", &global));
+        registry.finish_pass();
+        focused.borrow_mut().events.on_any_char = Some(Rc::new(|_| {}));
+        registry.dispatch(Key::Char('q').into(), Some(&focused));
+        registry.dispatch(Key::Tab.into(), Some(&focused));
+        assert_eq!(global.load(Ordering::SeqCst), 2);
+        registry.dispatch(ctrl_q, Some(&focused));
+        assert_eq!(global.load(Ordering::SeqCst), 3);
+        focused.borrow_mut().events.on_any_char = None;
+        registry.dispatch(Key::Char('q').into(), Some(&focused));
+        assert_eq!(global.load(Ordering::SeqCst), 4);
+
+        // An unmounted component registers nothing on the next pass
+        registry.begin_pass();
+        assert!(registry.finish_pass());
+        assert!(registry.active().is_empty());
+        assert!(!registry.finish_pass());
+    }
+}
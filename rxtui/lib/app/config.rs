@@ -1,3 +1,5 @@
+use crate::bounds::Rect;
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -13,6 +15,10 @@ pub enum TerminalMode {
     /// Inline rendering in main terminal buffer.
     /// Content persists in terminal history after app exits.
     Inline(InlineConfig),
+
+    /// Rendering confined to a rectangle of the main terminal buffer.
+    /// Cells outside the rectangle are never written.
+    Viewport(ViewportConfig),
 }
 
 /// Configuration for inline rendering mode.
@@ -49,6 +55,66 @@ pub enum InlineHeight {
     Fill { min: u16 },
 }
 
+/// Configuration for viewport rendering mode.
+#[derive(Clone)]
+pub struct ViewportConfig {
+    /// Region of the terminal to render into.
+    ///
+    /// Layout uses the region's size, and all output is offset by its origin.
+    /// The part of the region beyond the terminal edge is ignored.
+    pub area: Rect,
+
+    /// Whether to show cursor during rendering.
+    pub cursor_visible: bool,
+
+    /// Whether to capture mouse events.
+    ///
+    /// Positions are made relative to `area`; events outside it are dropped.
+    pub mouse_capture: bool,
+}
+
+/// What an app prints when stdout is not a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonTtyFallback {
+    /// The first frame's characters, without colors or styles.
+    PlainText,
+
+    /// The first frame's semantic outline of roles, labels and text.
+    Semantic,
+}
+
+/// Width band of the area an app renders in, returned by `Context::breakpoint`.
+///
+/// Bands are ordered from narrowest to widest, so views can compare them:
+/// `ctx.breakpoint() >= Breakpoint::Md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Narrower than the `sm` threshold
+    Xs,
+
+    /// From the `sm` threshold up to `md`
+    Sm,
+
+    /// From the `md` threshold up to `lg`
+    Md,
+
+    /// The `lg` threshold and wider
+    Lg,
+}
+
+/// Widths, in columns, at which each [`Breakpoint`] begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakpoints {
+    /// First width of [`Breakpoint::Sm`] (default: 40)
+    pub sm: u16,
+
+    /// First width of [`Breakpoint::Md`] (default: 80)
+    pub md: u16,
+
+    /// First width of [`Breakpoint::Lg`] (default: 120)
+    pub lg: u16,
+}
+
 /// Configuration options for debugging and optimization control.
 #[derive(Clone)]
 pub struct RenderConfig {
@@ -64,12 +130,71 @@ pub struct RenderConfig {
     /// Event polling duration in milliseconds (default: 100ms)
     /// Lower values make the app more responsive but use more CPU
     pub poll_duration_ms: u64,
+
+    /// Record per-frame timings and counters (default: false)
+    pub profiling: bool,
+
+    /// How long a key sequence waits for its next key, in milliseconds (default: 1000ms)
+    pub key_sequence_timeout_ms: u64,
+
+    /// Columns between tab stops for text without its own `tab_width` (default: 8)
+    pub tab_width: u16,
+
+    /// Event polling duration while the terminal window is unfocused (default: None)
+    /// Longer values throttle animations and effects in background windows
+    pub unfocused_poll_duration_ms: Option<u64>,
+
+    /// Whether `Context::set_title` changes the terminal window title (default: true)
+    pub window_title: bool,
+
+    /// Width thresholds for `Context::breakpoint` (default: 40, 80 and 120 columns)
+    pub breakpoints: Breakpoints,
+
+    /// Report layout mistakes on stderr when the app exits (default: false)
+    pub strict: bool,
+
+    /// Move focus with the arrow keys to the nearest focusable element on screen (default: false)
+    pub spatial_focus: bool,
+
+    /// Draw without colors, showing highlights in reverse video (default: false)
+    pub monochrome: bool,
 }
 
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
 
+impl ViewportConfig {
+    /// Creates a viewport configuration for the given region.
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            cursor_visible: false,
+            mouse_capture: false,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Creates thresholds from the first width of each band above `Xs`.
+    pub fn new(sm: u16, md: u16, lg: u16) -> Self {
+        Self { sm, md, lg }
+    }
+
+    /// Returns the band a width falls in.
+    pub fn classify(&self, width: u16) -> Breakpoint {
+        if width >= self.lg {
+            Breakpoint::Lg
+        } else if width >= self.md {
+            Breakpoint::Md
+        } else if width >= self.sm {
+            Breakpoint::Sm
+        } else {
+            Breakpoint::Xs
+        }
+    }
+}
+
 impl RenderConfig {
     /// Creates a debug configuration with all optimizations disabled.
     pub fn debug() -> Self {
@@ -78,6 +203,15 @@ impl RenderConfig {
             terminal_optimizations: false,
             cell_diffing: false,
             poll_duration_ms: 50,
+            profiling: false,
+            key_sequence_timeout_ms: 1000,
+            tab_width: 8,
+            unfocused_poll_duration_ms: None,
+            window_title: true,
+            breakpoints: Breakpoints::default(),
+            strict: false,
+            spatial_focus: false,
+            monochrome: false,
         }
     }
 }
@@ -105,6 +239,12 @@ impl Default for InlineHeight {
     }
 }
 
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(40, 80, 120)
+    }
+}
+
 impl Default for RenderConfig {
     fn default() -> Self {
         Self {
@@ -112,6 +252,15 @@ impl Default for RenderConfig {
             terminal_optimizations: true,
             cell_diffing: true,
             poll_duration_ms: 50,
+            profiling: false,
+            key_sequence_timeout_ms: 1000,
+            tab_width: 8,
+            unfocused_poll_duration_ms: None,
+            window_title: true,
+            breakpoints: Breakpoints::default(),
+            strict: false,
+            spatial_focus: false,
+            monochrome: false,
         }
     }
 }
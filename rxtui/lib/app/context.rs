@@ -1,10 +1,18 @@
+use crate::app::bindings::{Binding, BindingRegistry, BindingScope};
+use crate::app::config::{Breakpoint, Breakpoints};
+use crate::app::stats::FrameStats;
 use crate::component::{ComponentId, Message, State};
-use std::any::TypeId;
+#[cfg(feature = "components")]
+use crate::components::toast::{ToastLevel, ToastQueue};
+use crate::key::KeyWithModifiers;
+use crate::theme::Theme;
+use std::any::{Any, TypeId};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{
-    Arc, RwLock,
+    Arc, Condvar, Mutex, RwLock,
     atomic::{AtomicBool, Ordering},
 };
+use std::time::{Duration, Instant};
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -16,6 +24,9 @@ type MessageQueueMap = Arc<RwLock<HashMap<ComponentId, VecDeque<Box<dyn Message>
 /// Type alias for topic message queue storage
 type TopicMessageQueueMap = Arc<RwLock<HashMap<String, VecDeque<Box<dyn Message>>>>>;
 
+/// Type alias for local cell storage, one type-erased cell per component and type
+type LocalCellMap = Arc<RwLock<HashMap<ComponentId, HashMap<TypeId, Arc<dyn Any + Send + Sync>>>>>;
+
 /// Dispatcher for sending messages to components
 #[derive(Clone)]
 pub struct Dispatcher {
@@ -29,6 +40,50 @@ pub struct StateMap {
     states: Arc<RwLock<HashMap<ComponentId, Box<dyn State>>>>,
 }
 
+/// Local scratch cells for components, kept outside the message/state cycle
+#[derive(Clone)]
+pub(crate) struct LocalStore {
+    cells: LocalCellMap,
+}
+
+/// Handle to a component's local scratch value, returned by [`Context::local`]
+///
+/// Writes don't send a message or run `update`; the new value is seen by the
+/// next `view`, which runs after any input event or message. Clones share the
+/// same cell, so handles can be moved into event handlers.
+pub struct Local<T> {
+    cell: Arc<RwLock<T>>,
+}
+
+/// Handle for requesting a redraw from outside the UI, returned by [`Context::redraw_handle`]
+///
+/// The handle is `Send + Sync` and cheap to clone, so it can be moved into
+/// threads that update data the view reads, such as an `Arc<Mutex<...>>`.
+#[derive(Debug, Clone)]
+pub struct RedrawHandle {
+    wakeup: Arc<Wakeup>,
+}
+
+/// Wakes the event loop when a redraw is requested or terminal input is ready
+#[derive(Debug, Default)]
+pub(crate) struct Wakeup {
+    /// What the event loop has been woken for and not yet handled
+    state: Mutex<WakeState>,
+
+    /// Notified whenever the state changes
+    changed: Condvar,
+}
+
+/// Reasons for the event loop to stop waiting
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct WakeState {
+    /// A redraw was requested and not yet taken
+    pub(crate) redraw: bool,
+
+    /// Terminal input is waiting to be read
+    pub(crate) input: bool,
+}
+
 /// Target for focus requests emitted during rendering
 #[derive(Clone)]
 pub(crate) enum FocusTarget {
@@ -37,6 +92,25 @@ pub(crate) enum FocusTarget {
 
     /// Focus the first focusable element in the entire application tree
     GlobalFirst,
+
+    /// Focus the nearest focusable element in a direction from the focused one
+    Direction(FocusDirection),
+}
+
+/// Direction on screen to move focus in, for [`Context::focus_direction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusDirection {
+    /// Towards the top of the screen
+    Up,
+
+    /// Towards the bottom of the screen
+    Down,
+
+    /// Towards the left edge of the screen
+    Left,
+
+    /// Towards the right edge of the screen
+    Right,
 }
 
 /// Pending focus request queued by components
@@ -45,6 +119,29 @@ pub(crate) struct FocusRequest {
     pub target: FocusTarget,
 }
 
+/// Change to the terminal window title requested through the context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TitleRequest {
+    /// Show this title
+    Set(String),
+
+    /// Give back the title the terminal had before the app changed it
+    Restore,
+}
+
+/// Timestamps of the current and previous frame, shared by every context of an app
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FrameClock {
+    /// When the clock was created
+    start: Instant,
+
+    /// When the current frame began
+    frame: Instant,
+
+    /// Time between the previous frame and the current one
+    delta: Duration,
+}
+
 /// Topic storage for shared state between components
 pub struct TopicStore {
     /// Topic states indexed by topic name
@@ -76,6 +173,12 @@ pub struct Context {
     /// Topic states
     pub(crate) topics: Arc<TopicStore>,
 
+    /// Local scratch cells
+    pub(crate) locals: LocalStore,
+
+    /// Described key bindings registered by components
+    pub(crate) bindings: Arc<BindingRegistry>,
+
     /// Message queues (shared with dispatcher)
     pub(crate) message_queues: MessageQueueMap,
 
@@ -88,6 +191,9 @@ pub struct Context {
     /// Focus requests queued during rendering
     pub(crate) pending_focus_requests: Arc<RwLock<Vec<FocusRequest>>>,
 
+    /// Components whose elements should be scrolled into view after this render
+    pub(crate) pending_scroll_requests: Arc<RwLock<Vec<ComponentId>>>,
+
     /// Pending request to clear focus if nothing else claims it
     pub(crate) pending_focus_clear: Arc<AtomicBool>,
 
@@ -96,12 +202,77 @@ pub struct Context {
 
     /// Whether the current component invocation is on its first render
     pub(crate) current_is_first_render: Arc<RwLock<bool>>,
+
+    /// Application theme, shared so it can be swapped for every context at once
+    pub(crate) theme: Arc<RwLock<Arc<Theme>>>,
+
+    /// Last error from reloading a watched theme file
+    pub(crate) theme_error: Arc<RwLock<Option<String>>>,
+
+    /// Stats of the last drawn frame, when profiling is enabled
+    pub(crate) frame_stats: Arc<RwLock<Option<FrameStats>>>,
+
+    /// Pending request to ring the terminal bell
+    pub(crate) pending_bell: Arc<AtomicBool>,
+
+    /// Pending request to flash the screen
+    pub(crate) pending_flash: Arc<AtomicBool>,
+
+    /// Window title change to make after the current update
+    pub(crate) pending_title: Arc<Mutex<Option<TitleRequest>>>,
+
+    /// Raw escape sequences to write after the next frame
+    pub(crate) pending_raw: Arc<Mutex<Vec<String>>>,
+
+    /// Pending request to render even though no message arrived, and the
+    /// event loop's wakeup for it
+    pub(crate) wakeup: Arc<Wakeup>,
+
+    /// Components whose memoized elements are rebuilt on the next render
+    pub(crate) pending_renders: Arc<Mutex<HashSet<ComponentId>>>,
+
+    /// Value to exit the application with, once an exit is requested
+    pub(crate) pending_exit: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
+
+    /// Frame timestamps read by animations
+    pub(crate) frame_clock: Arc<RwLock<FrameClock>>,
+
+    /// Whether the terminal window has focus, as last reported by the terminal
+    pub(crate) terminal_focused: Arc<AtomicBool>,
+
+    /// Width and height of the area the app renders in, as of the current frame
+    pub(crate) render_size: Arc<RwLock<(u16, u16)>>,
+
+    /// Width thresholds for `breakpoint`
+    pub(crate) breakpoints: Arc<RwLock<Breakpoints>>,
+
+    /// Toasts queued with `toast`, drawn by the `Toast` component
+    #[cfg(feature = "components")]
+    pub(crate) toasts: Arc<Mutex<ToastQueue>>,
 }
 
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
 
+impl FrameClock {
+    /// Creates a clock whose first frame begins at `start`
+    pub(crate) fn new(start: Instant) -> Self {
+        Self {
+            start,
+            frame: start,
+            delta: Duration::ZERO,
+        }
+    }
+
+    /// Starts a new frame at `now`, ignoring instants before the current frame
+    pub(crate) fn advance(&mut self, now: Instant) {
+        let now = now.max(self.frame);
+        self.delta = now - self.frame;
+        self.frame = now;
+    }
+}
+
 impl Dispatcher {
     pub fn new(queues: MessageQueueMap, topic_queues: TopicMessageQueueMap) -> Self {
         Self {
@@ -166,6 +337,127 @@ impl StateMap {
     pub fn remove(&self, component_id: &ComponentId) -> Option<Box<dyn State>> {
         self.states.write().unwrap().remove(component_id)
     }
+
+    /// Removes the state of a component and of every component beneath it
+    pub fn remove_subtree(&self, component_id: &ComponentId) {
+        self.states
+            .write()
+            .unwrap()
+            .retain(|id, _| !id.is_within(component_id));
+    }
+}
+
+impl LocalStore {
+    pub(crate) fn new() -> Self {
+        Self {
+            cells: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the component's cell for `T`, creating it with the default value
+    pub(crate) fn get_or_init<T: Default + Send + Sync + 'static>(
+        &self,
+        component_id: &ComponentId,
+    ) -> Local<T> {
+        let mut cells = self.cells.write().unwrap();
+        let cell = cells
+            .entry(component_id.clone())
+            .or_default()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Arc::new(RwLock::new(T::default())))
+            .clone();
+
+        Local {
+            cell: cell.downcast::<RwLock<T>>().unwrap(),
+        }
+    }
+
+    /// Drops the cells of components that are no longer mounted
+    pub(crate) fn retain(&self, mut is_mounted: impl FnMut(&ComponentId) -> bool) {
+        self.cells
+            .write()
+            .unwrap()
+            .retain(|component_id, _| is_mounted(component_id));
+    }
+}
+
+impl<T> Local<T> {
+    /// Returns a copy of the current value
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.cell.read().unwrap().clone()
+    }
+
+    /// Replaces the value
+    pub fn set(&self, value: T) {
+        *self.cell.write().unwrap() = value;
+    }
+
+    /// Modifies the value in place
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.cell.write().unwrap());
+    }
+}
+
+impl RedrawHandle {
+    /// Requests a redraw, see [`Context::request_redraw`]
+    pub fn request_redraw(&self) {
+        self.wakeup.request_redraw();
+    }
+}
+
+impl Wakeup {
+    /// Changes the state and wakes every waiting thread.
+    fn update(&self, f: impl FnOnce(&mut WakeState)) {
+        f(&mut self.state.lock().unwrap());
+        self.changed.notify_all();
+    }
+
+    /// Flags a redraw and wakes the event loop
+    pub(crate) fn request_redraw(&self) {
+        self.update(|state| state.redraw = true);
+    }
+
+    /// Returns whether a redraw was requested and not yet taken
+    pub(crate) fn redraw_requested(&self) -> bool {
+        self.state.lock().unwrap().redraw
+    }
+
+    /// Returns and clears the pending redraw request
+    pub(crate) fn take_redraw(&self) -> bool {
+        std::mem::take(&mut self.state.lock().unwrap().redraw)
+    }
+
+    /// Flags whether terminal input is waiting to be read
+    pub(crate) fn set_input(&self, ready: bool) {
+        self.update(|state| state.input = ready);
+    }
+
+    /// Wakes waiting threads without changing the state, so they recheck their conditions
+    pub(crate) fn notify(&self) {
+        self.update(|_| {});
+    }
+
+    /// Blocks until a redraw is requested or input is ready, or `timeout` passes.
+    pub(crate) fn wait(&self, timeout: Duration) -> WakeState {
+        let state = self.state.lock().unwrap();
+        let (state, _) = self
+            .changed
+            .wait_timeout_while(state, timeout, |state| !state.redraw && !state.input)
+            .unwrap();
+        *state
+    }
+
+    /// Blocks while flagged input is unread, until `stop` is set.
+    pub(crate) fn wait_input_taken(&self, stop: &AtomicBool) {
+        let state = self.state.lock().unwrap();
+        let _state = self
+            .changed
+            .wait_while(state, |state| state.input && !stop.load(Ordering::SeqCst))
+            .unwrap();
+    }
 }
 
 impl TopicStore {
@@ -296,13 +588,32 @@ impl Context {
             dispatch: Dispatcher::new(queues.clone(), topic_queues.clone()),
             states: StateMap::new(),
             topics: Arc::new(TopicStore::new()),
+            locals: LocalStore::new(),
+            bindings: Arc::new(BindingRegistry::new()),
             message_queues: queues,
             topic_message_queues: topic_queues,
             effect_tracker: ComponentInstanceTracker::new(),
             pending_focus_requests: Arc::new(RwLock::new(Vec::new())),
+            pending_scroll_requests: Arc::new(RwLock::new(Vec::new())),
             pending_focus_clear,
             rendered_components: Arc::new(RwLock::new(HashSet::new())),
             current_is_first_render: Arc::new(RwLock::new(false)),
+            theme: Arc::new(RwLock::new(Arc::new(Theme::default()))),
+            theme_error: Arc::new(RwLock::new(None)),
+            frame_stats: Arc::new(RwLock::new(None)),
+            pending_bell: Arc::new(AtomicBool::new(false)),
+            pending_flash: Arc::new(AtomicBool::new(false)),
+            pending_title: Arc::new(Mutex::new(None)),
+            pending_raw: Arc::new(Mutex::new(Vec::new())),
+            wakeup: Arc::new(Wakeup::default()),
+            pending_renders: Arc::new(Mutex::new(HashSet::new())),
+            pending_exit: Arc::new(Mutex::new(None)),
+            frame_clock: Arc::new(RwLock::new(FrameClock::new(Instant::now()))),
+            terminal_focused: Arc::new(AtomicBool::new(true)),
+            render_size: Arc::new(RwLock::new((0, 0))),
+            breakpoints: Arc::new(RwLock::new(Breakpoints::default())),
+            #[cfg(feature = "components")]
+            toasts: Arc::new(Mutex::new(ToastQueue::default())),
         }
     }
 
@@ -344,6 +655,69 @@ impl Context {
         self.states.insert(self.current_component_id.clone(), state);
     }
 
+    /// Get a local scratch cell for the current component, created with Default on first use
+    ///
+    /// Meant for transient interaction state (is-open, hover index) that doesn't
+    /// belong in the message-driven state. Cells are keyed by component path and
+    /// value type, like [`Context::get_state`], so a component that moves to a new
+    /// position in the tree starts over with a fresh cell. Cells are dropped once
+    /// their component unmounts.
+    pub fn local<T: Default + Send + Sync + 'static>(&self) -> Local<T> {
+        self.locals.get_or_init::<T>(&self.current_component_id)
+    }
+
+    /// Binds a key to a message for this component and records it with a description
+    ///
+    /// Call it from `view`; bindings are rebuilt every render, so they go away when
+    /// the component unmounts. The binding fires while focus is inside this
+    /// component (or nothing is focused), alongside any element key handlers.
+    /// Tab, BackTab and characters typed into a focused text input never fire it.
+    pub fn bind<M: Message + Clone>(
+        &self,
+        key: impl Into<KeyWithModifiers>,
+        description: impl Into<String>,
+        msg: M,
+    ) {
+        self.register_binding(key.into(), description.into(), BindingScope::Component, msg);
+    }
+
+    /// Like [`Context::bind`], but the binding fires regardless of focus
+    pub fn bind_global<M: Message + Clone>(
+        &self,
+        key: impl Into<KeyWithModifiers>,
+        description: impl Into<String>,
+        msg: M,
+    ) {
+        self.register_binding(key.into(), description.into(), BindingScope::Global, msg);
+    }
+
+    /// Returns the bindings of the last complete render, in tree order
+    ///
+    /// Meant for help overlays. A change to the listed bindings triggers a redraw,
+    /// so a view listing them catches up with bindings registered after it.
+    pub fn bindings(&self) -> Vec<Binding> {
+        self.bindings.active()
+    }
+
+    fn register_binding<M: Message + Clone>(
+        &self,
+        key: KeyWithModifiers,
+        description: String,
+        scope: BindingScope,
+        msg: M,
+    ) {
+        let id = self.current_component_id.clone();
+        let dispatcher = self.dispatch.clone();
+        let handler = Arc::new(move || dispatcher.send_to_id(id.clone(), msg.clone()));
+        self.bindings.register(Binding::new(
+            key,
+            description,
+            scope,
+            self.current_component_id.clone(),
+            handler,
+        ));
+    }
+
     /// Read state from a topic
     pub fn read_topic<T: State + Clone + 'static>(&self, topic: &str) -> Option<T> {
         self.topics.read_topic(topic)
@@ -401,18 +775,313 @@ impl Context {
         Self {
             current_component_id: self.current_component_id.child(index),
             dispatch: self.dispatch.clone(),
-            states: self.states.clone(), // Share the state map
-            topics: self.topics.clone(), // Share the topic store
+            states: self.states.clone(),     // Share the state map
+            topics: self.topics.clone(),     // Share the topic store
+            locals: self.locals.clone(),     // Share the local cells
+            bindings: self.bindings.clone(), // Share the binding registry
             message_queues: self.message_queues.clone(), // Share the message queues
             topic_message_queues: self.topic_message_queues.clone(), // Share the topic message queues
             effect_tracker: self.effect_tracker.clone(),             // Share the effect tracker
             pending_focus_requests: self.pending_focus_requests.clone(),
+            pending_scroll_requests: self.pending_scroll_requests.clone(),
             pending_focus_clear: self.pending_focus_clear.clone(),
             rendered_components: self.rendered_components.clone(),
             current_is_first_render: self.current_is_first_render.clone(),
+            theme: self.theme.clone(),
+            theme_error: self.theme_error.clone(),
+            frame_stats: self.frame_stats.clone(),
+            pending_bell: self.pending_bell.clone(),
+            pending_flash: self.pending_flash.clone(),
+            pending_title: self.pending_title.clone(),
+            pending_raw: self.pending_raw.clone(),
+            wakeup: self.wakeup.clone(),
+            pending_renders: self.pending_renders.clone(),
+            pending_exit: self.pending_exit.clone(),
+            frame_clock: self.frame_clock.clone(),
+            terminal_focused: self.terminal_focused.clone(),
+            render_size: self.render_size.clone(),
+            breakpoints: self.breakpoints.clone(),
+            #[cfg(feature = "components")]
+            toasts: self.toasts.clone(),
+        }
+    }
+
+    /// Returns the application theme
+    pub fn theme(&self) -> Arc<Theme> {
+        self.theme.read().unwrap().clone()
+    }
+
+    /// Replaces the theme for this context and every context sharing it
+    pub(crate) fn set_theme(&self, theme: Arc<Theme>) {
+        *self.theme.write().unwrap() = theme;
+    }
+
+    /// Returns the error from the last failed reload of a watched theme file.
+    ///
+    /// Set while the file fails to parse and cleared once it loads again; the
+    /// previous theme stays active in the meantime. See `App::watch_theme`.
+    pub fn theme_error(&self) -> Option<String> {
+        self.theme_error.read().unwrap().clone()
+    }
+
+    /// Records or clears the theme reload error
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_theme_error(&self, error: Option<String>) {
+        *self.theme_error.write().unwrap() = error;
+    }
+
+    /// Returns timings and counters for the last drawn frame.
+    ///
+    /// `None` unless profiling is enabled with `App::profiling`, and before
+    /// the first frame is drawn.
+    pub fn frame_stats(&self) -> Option<FrameStats> {
+        *self.frame_stats.read().unwrap()
+    }
+
+    /// Publishes the stats of a finished frame
+    pub(crate) fn set_frame_stats(&self, stats: FrameStats) {
+        *self.frame_stats.write().unwrap() = Some(stats);
+    }
+
+    /// Rings the terminal bell.
+    ///
+    /// The bell is written after the current update, once per frame however
+    /// many times this is called. Does nothing outside a running `App`.
+    pub fn bell(&self) {
+        self.pending_bell.store(true, Ordering::SeqCst);
+    }
+
+    /// Briefly inverts the colors of the whole UI.
+    ///
+    /// A visual alternative to [`bell`](Self::bell) for terminals where the
+    /// bell is muted. Does nothing outside a running `App`.
+    pub fn flash(&self) {
+        self.pending_flash.store(true, Ordering::SeqCst);
+    }
+
+    /// Writes a raw escape sequence to the terminal, such as a DEC private
+    /// mode the framework doesn't model.
+    ///
+    /// Sequences are queued and written in order once the next frame has been
+    /// drawn, so they never land in the middle of the frame's output. Nothing
+    /// is written when stdout isn't a terminal.
+    ///
+    /// This is an escape hatch, and the framework doesn't know what the
+    /// sequence did:
+    /// - Colors, attributes and the cursor position are reset afterwards, and
+    ///   the next frame is drawn from there.
+    /// - Anything else changed, such as a terminal mode, stays changed, and is
+    ///   not undone on exit unless another raw write does it.
+    /// - A sequence that moves text or clears the screen leaves the screen out
+    ///   of step with what the app thinks is there until those cells change.
+    pub fn write_raw(&self, sequence: impl Into<String>) {
+        self.pending_raw.lock().unwrap().push(sequence.into());
+    }
+
+    /// Re-runs the views and renders the next frame.
+    ///
+    /// For views showing data kept outside component state, such as a shared
+    /// `Arc<Mutex<...>>` another thread writes to. The app wakes right away
+    /// instead of waiting for the next input event or poll. To
+    /// request redraws from other threads, move a
+    /// [`redraw_handle`](Self::redraw_handle) there. Does nothing outside a
+    /// running `App`.
+    pub fn request_redraw(&self) {
+        self.wakeup.request_redraw();
+    }
+
+    /// Marks the current component dirty, rebuilding all of it on the next frame.
+    ///
+    /// Like [`request_redraw`](Self::request_redraw), but divs memoized with
+    /// [`Div::memo`](crate::Div::memo) in this component and the components
+    /// beneath it are rebuilt once even though their keys are unchanged. For
+    /// changes a memo key doesn't cover, such as a `ctx.local` mutation or data
+    /// shared with another thread. Called from the root component, the whole
+    /// tree is marked. Any number of calls before the next frame collapse into
+    /// one render, made right away.
+    ///
+    /// Prefer sending a message when the change can live in component state:
+    /// state updates render on their own and keep views a function of state.
+    pub fn request_render(&self) {
+        self.pending_renders
+            .lock()
+            .unwrap()
+            .insert(self.current_component_id.clone());
+        self.request_redraw();
+    }
+
+    /// Returns a thread-safe handle for [`request_redraw`](Self::request_redraw).
+    pub fn redraw_handle(&self) -> RedrawHandle {
+        RedrawHandle {
+            wakeup: self.wakeup.clone(),
+        }
+    }
+
+    /// Shows a notification for `duration`.
+    ///
+    /// Toasts are drawn by a [`Toast`](crate::components::Toast) component
+    /// placed in the root view, which also removes them once their time is up;
+    /// without one, queued toasts are never shown. Several toasts at once stack
+    /// in the order they were queued.
+    #[cfg(feature = "components")]
+    pub fn toast(&self, message: impl Into<String>, level: ToastLevel, duration: Duration) {
+        self.toasts
+            .lock()
+            .unwrap()
+            .push(message.into(), level, duration, Instant::now());
+        self.request_redraw();
+    }
+
+    /// Sets the terminal window title.
+    ///
+    /// The title is written after the current update, and only the last change
+    /// made in a frame is sent. Terminals that keep a title stack get their
+    /// original title back when the app exits. Does nothing outside a running
+    /// `App`, without a terminal, or when titles are disabled with
+    /// [`App::window_title`](crate::App::window_title).
+    pub fn set_title(&self, title: impl Into<String>) {
+        *self.pending_title.lock().unwrap() = Some(TitleRequest::Set(title.into()));
+    }
+
+    /// Gives back the title the terminal had before [`set_title`](Self::set_title).
+    ///
+    /// Only terminals with a title stack can restore it; elsewhere the last
+    /// title stays, and setting a title explicitly is the only way to change it.
+    pub fn restore_title(&self) {
+        *self.pending_title.lock().unwrap() = Some(TitleRequest::Restore);
+    }
+
+    /// Returns the time from the start of the app to the start of the current frame.
+    ///
+    /// Every update, view and effect sees the same value until the next
+    /// frame begins, so animations driven by it stay in step with each other.
+    /// The clock only advances when the event loop runs, which happens on
+    /// each event and at least every `App::poll_duration`; it never wakes the
+    /// app by itself. Outside a running `App` it stays at zero.
+    pub fn frame_time(&self) -> Duration {
+        let clock = self.frame_clock.read().unwrap();
+        clock.frame.duration_since(clock.start)
+    }
+
+    /// Returns the time between the previous frame and the current one.
+    ///
+    /// Zero on the first frame. Like [`frame_time`](Self::frame_time), it is
+    /// fixed for the whole frame.
+    pub fn delta(&self) -> Duration {
+        self.frame_clock.read().unwrap().delta
+    }
+
+    /// Starts a new frame at `now`
+    pub(crate) fn advance_frame_clock(&self, now: Instant) {
+        self.frame_clock.write().unwrap().advance(now);
+    }
+
+    /// Returns whether the terminal window has focus.
+    ///
+    /// Views and effects can check it to pause animations while the app sits
+    /// in a background window. Terminals without focus reporting never say
+    /// focus was lost, so this stays `true` there and outside a running `App`.
+    pub fn is_terminal_focused(&self) -> bool {
+        self.terminal_focused.load(Ordering::SeqCst)
+    }
+
+    /// Records a focus report from the terminal
+    pub(crate) fn set_terminal_focused(&self, focused: bool) {
+        self.terminal_focused.store(focused, Ordering::SeqCst);
+    }
+
+    /// Returns the width and height of the area the app renders in.
+    ///
+    /// That's the terminal, or the region of a viewport app. The size is read
+    /// once per frame before the views run, so every view of a frame sees the
+    /// same value, and a resize shows up in the frame it triggers. Outside a
+    /// running `App` it is `(0, 0)`.
+    pub fn size(&self) -> (u16, u16) {
+        *self.render_size.read().unwrap()
+    }
+
+    /// Returns the width band of [`size`](Self::size), for views that pick a
+    /// different layout on narrow or wide terminals.
+    ///
+    /// Thresholds are set with `App::breakpoints`.
+    pub fn breakpoint(&self) -> Breakpoint {
+        let (width, _) = self.size();
+        self.breakpoints.read().unwrap().classify(width)
+    }
+
+    /// Records the render area size for the frame about to be built
+    pub(crate) fn set_size(&self, size: (u16, u16)) {
+        *self.render_size.write().unwrap() = size;
+    }
+
+    /// Sets the width thresholds used by `breakpoint`
+    pub(crate) fn set_breakpoints(&self, breakpoints: Breakpoints) {
+        *self.breakpoints.write().unwrap() = breakpoints;
+    }
+
+    /// Exits the application, returning `value` from `App::run_with_value`.
+    ///
+    /// The app stops after the current update instead of waiting for an
+    /// action, so this also works from effects. Like
+    /// [`Action::exit_with`](crate::Action::exit_with), the first exit
+    /// requested wins.
+    pub fn quit_with(&self, value: impl Any + Send) {
+        self.request_exit(Box::new(value));
+    }
+
+    /// Records an exit value unless an exit was already requested
+    pub(crate) fn request_exit(&self, value: Box<dyn Any + Send>) {
+        let mut pending = self.pending_exit.lock().unwrap();
+        if pending.is_none() {
+            *pending = Some(value);
         }
     }
 
+    /// Returns whether an exit was requested
+    pub(crate) fn exit_requested(&self) -> bool {
+        self.pending_exit.lock().unwrap().is_some()
+    }
+
+    /// Takes the value the application was asked to exit with
+    pub(crate) fn take_exit_value(&self) -> Option<Box<dyn Any + Send>> {
+        self.pending_exit.lock().unwrap().take()
+    }
+
+    /// Returns and clears the pending bell request
+    pub(crate) fn take_bell_request(&self) -> bool {
+        self.pending_bell.swap(false, Ordering::SeqCst)
+    }
+
+    /// Returns whether a redraw was requested and not yet taken
+    pub(crate) fn redraw_requested(&self) -> bool {
+        self.wakeup.redraw_requested()
+    }
+
+    /// Returns and clears the pending redraw request
+    pub(crate) fn take_redraw_request(&self) -> bool {
+        self.wakeup.take_redraw()
+    }
+
+    /// Returns and clears the components marked with `request_render`
+    pub(crate) fn take_render_requests(&self) -> HashSet<ComponentId> {
+        std::mem::take(&mut *self.pending_renders.lock().unwrap())
+    }
+
+    /// Returns and clears the pending flash request
+    pub(crate) fn take_flash_request(&self) -> bool {
+        self.pending_flash.swap(false, Ordering::SeqCst)
+    }
+
+    /// Returns and clears the queued raw escape sequences
+    pub(crate) fn take_raw_writes(&self) -> Vec<String> {
+        std::mem::take(&mut *self.pending_raw.lock().unwrap())
+    }
+
+    /// Returns and clears the pending window title change
+    pub(crate) fn take_title_request(&self) -> Option<TitleRequest> {
+        self.pending_title.lock().unwrap().take()
+    }
+
     /// Request focus for the first focusable element inside the current component
     pub fn focus_self(&self) {
         let mut queue = self.pending_focus_requests.write().unwrap();
@@ -429,6 +1098,32 @@ impl Context {
         });
     }
 
+    /// Request focus for the nearest focusable element in a direction on screen.
+    ///
+    /// Elements are compared by their laid out boxes: the closest one past the
+    /// focused element's edge wins, with those lined up with it preferred over
+    /// those off to the side. Focus stays put when there is nothing in that
+    /// direction, and the first focusable element is focused when nothing is.
+    pub fn focus_direction(&self, direction: FocusDirection) {
+        let mut queue = self.pending_focus_requests.write().unwrap();
+        queue.push(FocusRequest {
+            target: FocusTarget::Direction(direction),
+        });
+    }
+
+    /// Request that the scroll containers around a component scroll to show it.
+    ///
+    /// The component's outermost element is revealed once the next frame is
+    /// laid out, scrolling each container as little as needed. Focus moved
+    /// with Tab or [`focus_self`](Self::focus_self) does this by itself. Pass
+    /// [`id`](Self::id) to reveal the current component.
+    pub fn scroll_into_view(&self, id: &ComponentId) {
+        self.pending_scroll_requests
+            .write()
+            .unwrap()
+            .push(id.clone());
+    }
+
     /// Request that no element remain focused after this render cycle.
     pub fn blur_focus(&self) {
         self.pending_focus_clear.store(true, Ordering::SeqCst);
@@ -440,6 +1135,11 @@ impl Context {
         queue.drain(..).collect()
     }
 
+    /// Drain all scroll-into-view requests accumulated during rendering
+    pub(crate) fn take_scroll_requests(&self) -> Vec<ComponentId> {
+        std::mem::take(&mut *self.pending_scroll_requests.write().unwrap())
+    }
+
     /// Returns true if a focus clear was requested and resets the flag.
     pub(crate) fn take_focus_clear_request(&self) -> bool {
         self.pending_focus_clear.swap(false, Ordering::SeqCst)
@@ -588,8 +1288,89 @@ impl Default for StateMap {
     }
 }
 
+impl<T> Clone for Local<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
 impl Default for Context {
     fn default() -> Self {
         Self::new(Arc::new(AtomicBool::new(false)))
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_clock_is_fixed_within_a_frame() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        assert_eq!(ctx.frame_time(), Duration::ZERO);
+        assert_eq!(ctx.delta(), Duration::ZERO);
+
+        let start = ctx.frame_clock.read().unwrap().start;
+        ctx.advance_frame_clock(start + Duration::from_millis(16));
+        ctx.advance_frame_clock(start + Duration::from_millis(50));
+
+        // Clones, such as the ones effects hold, share the clock
+        let clone = ctx.clone();
+        assert_eq!(clone.frame_time(), Duration::from_millis(50));
+        assert_eq!(clone.delta(), Duration::from_millis(34));
+
+        // Time never runs backwards
+        ctx.advance_frame_clock(start);
+        assert_eq!(ctx.frame_time(), Duration::from_millis(50));
+        assert_eq!(ctx.delta(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_raw_writes_are_queued_in_order() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let effect_ctx = ctx.clone();
+        ctx.write_raw("\x1b[?1004h");
+        effect_ctx.write_raw(String::from("\x1b[?2004h"));
+
+        assert_eq!(ctx.take_raw_writes(), ["\x1b[?1004h", "\x1b[?2004h"]);
+        assert!(ctx.take_raw_writes().is_empty());
+    }
+
+    #[test]
+    fn test_terminal_focus_is_shared() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let effect_ctx = ctx.clone();
+        let child_ctx = ctx.child(0);
+        assert!(effect_ctx.is_terminal_focused());
+
+        ctx.set_terminal_focused(false);
+        assert!(!effect_ctx.is_terminal_focused());
+        assert!(!child_ctx.is_terminal_focused());
+    }
+
+    #[test]
+    fn test_local_cells_are_per_component_and_type() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let child = ctx.child(0);
+
+        let open = child.local::<bool>();
+        let handler = open.clone();
+        handler.set(true);
+        child.local::<u16>().update(|index| *index += 3);
+
+        assert!(child.local::<bool>().get());
+        assert_eq!(child.local::<u16>().get(), 3);
+        assert!(!ctx.local::<bool>().get());
+        assert!(!ctx.child(1).local::<bool>().get());
+
+        // Unmounted components lose their cells
+        ctx.locals.retain(|id| id != child.id());
+        assert!(!child.local::<bool>().get());
+    }
+}
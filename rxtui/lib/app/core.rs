@@ -1,32 +1,48 @@
 use crate::app::Context;
 use crate::bounds::Rect;
 use crate::buffer::{DoubleBuffer, ScreenBuffer};
-use crate::component::{Action, Component, ComponentId};
+use crate::component::{Action, Component, ComponentId, Reconcile, RenderError, reconcile};
+use crate::key::KeyWithModifiers;
 use crate::node::Div;
 use crate::node::Node;
-use crate::terminal::TerminalRenderer;
+use crate::node::TextSpan;
+use crate::render_tree::Hit;
+use crate::style::{Style, TextStyle};
+use crate::terminal::{POP_TITLE, TerminalRenderer};
+use crate::theme::Theme;
+#[cfg(feature = "serde")]
+use crate::theme::ThemeWatcher;
+use crate::utils::{expand_tabs, expand_tabs_from, transform_text, transform_text_from};
 use crate::vdom::VDom;
 use crate::vnode::VNode;
 use crossterm::{
     ExecutableCommand, cursor,
     event::{self, Event},
-    execute,
-    style::{Print, ResetColor, SetBackgroundColor, SetForegroundColor},
-    terminal,
+    execute, terminal,
 };
-use std::cell::RefCell;
-use std::io;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::io::{self, IsTerminal};
+use std::panic::{self, AssertUnwindSafe};
 use std::rc::Rc;
-use std::sync::Arc;
-
-use super::config::{InlineConfig, InlineHeight, RenderConfig, TerminalMode};
-use super::context::{FocusRequest, FocusTarget};
-use super::events::{handle_key_event, handle_mouse_event};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use super::config::{
+    Breakpoints, InlineConfig, InlineHeight, NonTtyFallback, RenderConfig, TerminalMode,
+    ViewportConfig,
+};
+use super::context::{FocusRequest, FocusTarget, TitleRequest, Wakeup};
+use super::events::{
+    KeySequenceState, handle_key_event, handle_mouse_event, handle_spatial_focus,
+    localize_mouse_event,
+};
 use super::inline::InlineState;
-use super::renderer::render_node_to_buffer;
-use std::collections::HashMap;
-#[cfg(feature = "effects")]
-use std::collections::HashSet;
+use super::renderer::render_frame;
+use super::stats::{FrameStats, count_nodes, elapsed, start_timer};
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "effects")]
 use crate::effect::EffectRuntime;
@@ -35,13 +51,64 @@ use crate::effect::EffectRuntime;
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// How often a watched theme file is checked for changes.
+#[cfg(feature = "serde")]
+const THEME_POLL_INTERVAL_MS: u64 = 250;
+
+/// How long `Context::flash` keeps the screen inverted.
+const FLASH_DURATION_MS: u64 = 100;
+
+/// How long each terminal poll of the input watcher lasts, bounding how long
+/// stopping it takes.
+const INPUT_POLL_INTERVAL_MS: u64 = 50;
+
+/// Screen size laid out for text output when stdout isn't a terminal.
+const NON_TTY_SIZE: (u16, u16) = (80, 24);
+
+/// Environment variable that turns on [`App::strict`] without a code change.
+const LINT_VAR: &str = "RXTUI_LINT";
+
 /// Type alias for the render log callback function.
 type RenderLogFn = Box<dyn Fn(&str)>;
 
+/// Type alias for the frame stats callback function.
+type FrameStatsFn = Box<dyn Fn(&FrameStats)>;
+
 /// Signal to indicate that the application should exit.
 /// Used to propagate exit requests through the component tree.
 pub struct ExitSignal;
 
+/// Terminal setup done by [`App::with_mode`] that has to be undone on exit.
+#[derive(Debug, Clone, Copy)]
+struct RawTerminal {
+    /// Whether the alternate screen was entered
+    alternate_screen: bool,
+
+    /// Whether mouse capture was enabled
+    mouse_capture: bool,
+
+    /// Whether the original window title was saved before the app changed it
+    title_pushed: bool,
+}
+
+thread_local! {
+    /// Setup to undo if this thread panics, set while an app owns the terminal.
+    /// Taken by whichever of the panic hook and `App`'s drop runs first.
+    static RAW_TERMINAL: Cell<Option<RawTerminal>> = const { Cell::new(None) };
+
+    /// Set while a view runs under `catch_unwind`, so its panics leave the terminal alone
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Why expanding the component tree stopped early.
+enum ExpandError {
+    /// A component asked the application to exit
+    Exit(ExitSignal),
+
+    /// A view failed and no error boundary has handled it yet
+    Failed(RenderError),
+}
+
 /// Main application controller for terminal UI applications.
 ///
 /// Manages the lifecycle of a terminal application including:
@@ -101,6 +168,27 @@ pub struct App {
     /// Optional function to call after each render for logging
     render_log_fn: Option<RenderLogFn>,
 
+    /// Optional function receiving each frame's stats while profiling
+    frame_stats_fn: Option<FrameStatsFn>,
+
+    /// Stats for the frame currently being recorded
+    frame_stats: FrameStats,
+
+    /// Component view calls since the last frame, counted while profiling
+    view_calls: Cell<usize>,
+
+    /// Set when a view panicked, since the panic hook may have written over the screen
+    caught_panic: Cell<bool>,
+
+    /// Components of the last complete pass by id, compared against the next pass
+    mounted: RefCell<HashMap<ComponentId, Arc<dyn Component>>>,
+
+    /// Components marked with `Context::request_render` for the current pass
+    render_requests: RefCell<HashSet<ComponentId>>,
+
+    /// Key sequence in progress across key events
+    key_sequence: KeySequenceState,
+
     /// Terminal renderer for optimized output
     terminal_renderer: TerminalRenderer,
 
@@ -110,14 +198,83 @@ pub struct App {
     /// Terminal rendering mode (alternate screen or inline)
     terminal_mode: TerminalMode,
 
+    /// Whether stdout is a terminal
+    tty: bool,
+
+    /// What to print instead of running when stdout is not a terminal
+    non_tty_fallback: Option<NonTtyFallback>,
+
     /// State for inline rendering mode
     inline_state: InlineState,
 
+    /// Theme shared with components through the context
+    theme: Arc<Theme>,
+
+    /// When the current screen flash ends
+    flash_until: Option<Instant>,
+
+    /// Layout warnings found in strict mode, printed once the terminal is restored
+    layout_warnings: Vec<String>,
+
+    /// Component path and message of each collected warning, so a mistake is
+    /// reported once however often its node moves
+    seen_layout_warnings: HashSet<(String, String)>,
+
+    /// Reloads the theme when its file changes
+    #[cfg(feature = "serde")]
+    theme_watcher: Option<ThemeWatcher>,
+
     /// Effect runtime for managing async tasks
     #[cfg(feature = "effects")]
     effect_runtime: Option<EffectRuntime>,
 }
 
+/// An [`App`] started with [`App::init`] and driven one step at a time.
+///
+/// Dropping it without [`shutdown`](Self::shutdown) leaves effects running
+/// until the app itself is dropped.
+pub struct RunningApp<'a> {
+    /// The app being driven
+    app: &'a mut App,
+
+    /// Context shared with every component of the app
+    context: Context,
+
+    /// Components by id, including ones mounted by earlier ticks
+    components: HashMap<ComponentId, Arc<dyn Component>>,
+
+    /// Id of the root component
+    root_id: ComponentId,
+
+    /// View built by the last tick, waiting to be rendered
+    vnode_tree: Option<VNode>,
+
+    /// Whether the next render should draw
+    needs_render: bool,
+
+    /// Whether the last tick changed the registered bindings
+    bindings_changed: bool,
+
+    /// Whether a tick already ran, so the next one starts a new frame
+    ticked: bool,
+}
+
+/// Polls the terminal on a background thread while [`App::run`] waits, so the
+/// event loop can block on input and redraw requests together.
+///
+/// The thread only polls; the event loop reads each event, after which the
+/// thread polls again.
+struct InputWatcher {
+    /// Wakeup flagged when input is ready
+    wakeup: Arc<Wakeup>,
+
+    /// Tells the polling thread to exit
+    stop: Arc<AtomicBool>,
+
+    /// The polling thread, joined on drop
+    thread: Option<JoinHandle<()>>,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -167,17 +324,65 @@ impl App {
         Self::with_mode(TerminalMode::Inline(config))
     }
 
+    /// Creates a new terminal UI application that renders into a region of the screen.
+    ///
+    /// Layout uses the size of `area` and all output is offset by its origin,
+    /// so the rest of the terminal is left to the surrounding application.
+    /// Use [`App::with_mode`] with a [`ViewportConfig`] to also enable the
+    /// cursor or mouse capture.
+    pub fn viewport(area: Rect) -> io::Result<Self> {
+        Self::with_mode(TerminalMode::Viewport(ViewportConfig::new(area)))
+    }
+
     /// Creates a new terminal UI application with the specified terminal mode.
     ///
-    /// This is the core constructor that handles both alternate screen and inline modes.
+    /// This is the core constructor that handles alternate screen, inline and viewport modes.
+    ///
+    /// When stdout isn't a terminal, nothing is set up, so redirected output
+    /// isn't filled with escape codes; [`run`](Self::run) then fails unless
+    /// [`allow_non_tty`](Self::allow_non_tty) picks a text fallback.
     pub fn with_mode(mode: TerminalMode) -> io::Result<Self> {
-        let mut stdout = io::stdout();
+        if !io::stdout().is_terminal() {
+            return Ok(Self::create(mode, NON_TTY_SIZE, false));
+        }
+
+        install_panic_hook();
 
         // Always enable raw mode for event handling
         terminal::enable_raw_mode()?;
 
+        let raw = RawTerminal {
+            alternate_screen: matches!(mode, TerminalMode::AlternateScreen),
+            mouse_capture: match &mode {
+                TerminalMode::AlternateScreen => true,
+                TerminalMode::Inline(config) => config.mouse_capture,
+                TerminalMode::Viewport(config) => config.mouse_capture,
+            },
+            title_pushed: false,
+        };
+        RAW_TERMINAL.set(Some(raw));
+
+        match Self::setup_terminal(&mode) {
+            Ok(size) => Ok(Self::create(mode, size, true)),
+            Err(error) => {
+                // No app will be dropped to undo the partial setup
+                if let Some(raw) = RAW_TERMINAL.take() {
+                    restore_terminal(raw);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Sets up the terminal for `mode` once raw mode is on, returning its size.
+    fn setup_terminal(mode: &TerminalMode) -> io::Result<(u16, u16)> {
+        let mut stdout = io::stdout();
+
+        // Ask the terminal to report when its window gains or loses focus
+        stdout.execute(event::EnableFocusChange)?;
+
         // Mode-specific terminal setup
-        match &mode {
+        match mode {
             TerminalMode::AlternateScreen => {
                 stdout.execute(terminal::EnterAlternateScreen)?;
                 stdout.execute(cursor::Hide)?;
@@ -194,31 +399,63 @@ impl App {
                 }
                 // Space reservation happens on first render
             }
+            TerminalMode::Viewport(config) => {
+                if !config.cursor_visible {
+                    stdout.execute(cursor::Hide)?;
+                }
+                if config.mouse_capture {
+                    stdout.execute(event::EnableMouseCapture)?;
+                }
+            }
         }
 
+        terminal::size()
+    }
+
+    /// Creates the app for a screen of the given size.
+    fn create(mode: TerminalMode, (width, height): (u16, u16), tty: bool) -> Self {
         let running = Rc::new(RefCell::new(true));
         let needs_render = Rc::new(RefCell::new(true));
+        let mut double_buffer = DoubleBuffer::new(width, height);
 
-        // Get initial terminal size for double buffer
-        let (width, height) = terminal::size()?;
+        // The viewport region still shows whatever was there before, so the
+        // first frame must overwrite every cell, blanks included
+        if matches!(mode, TerminalMode::Viewport(_)) {
+            double_buffer.invalidate();
+        }
 
         // Initialize effect runtime if feature is enabled
         #[cfg(feature = "effects")]
         let effect_runtime = Some(EffectRuntime::new());
 
-        Ok(Self {
+        Self {
             vdom: VDom::new(),
             running,
             needs_render,
-            double_buffer: DoubleBuffer::new(width, height),
+            double_buffer,
             render_log_fn: None,
+            frame_stats_fn: None,
+            frame_stats: FrameStats::default(),
+            view_calls: Cell::new(0),
+            caught_panic: Cell::new(false),
+            mounted: RefCell::new(HashMap::new()),
+            render_requests: RefCell::new(HashSet::new()),
+            key_sequence: KeySequenceState::default(),
             terminal_renderer: TerminalRenderer::new(),
             config: RenderConfig::default(),
             terminal_mode: mode,
+            tty,
+            non_tty_fallback: None,
             inline_state: InlineState::new(),
+            theme: Arc::new(Theme::default()),
+            flash_until: None,
+            layout_warnings: Vec::new(),
+            seen_layout_warnings: HashSet::new(),
+            #[cfg(feature = "serde")]
+            theme_watcher: None,
             #[cfg(feature = "effects")]
             effect_runtime,
-        })
+        }
     }
 
     /// Runs the application with a component instance.
@@ -235,12 +472,37 @@ impl App {
     /// app.run(root)?;
     /// ```
     ///
-    /// This method blocks until the application exits.
+    /// This method blocks until the application exits. A view error or panic
+    /// that no error boundary catches ends the loop with an `Err` carrying the
+    /// [`RenderError`]. Other panics restore the terminal before their message
+    /// is printed, so it stays readable.
     pub fn run<C>(&mut self, root_component: C) -> io::Result<()>
     where
         C: Component,
     {
-        self.run_loop(root_component)
+        self.run_loop(root_component).map(|_| ())
+    }
+
+    /// Runs the application like [`run`](Self::run) and returns the value it exited with.
+    ///
+    /// The value comes from [`Action::exit_with`] or [`Context::quit_with`].
+    /// A plain [`Action::exit`] exits with `()`. Returns `None` when the app
+    /// stopped without an exit value or with a value of a type other than `T`.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// // The picker's update returns `Action::exit_with(item)` on Enter
+    /// let choice: Option<String> = App::new()?.run_with_value(Picker::new(items))?;
+    /// ```
+    pub fn run_with_value<T, C>(&mut self, root_component: C) -> io::Result<Option<T>>
+    where
+        T: 'static,
+        C: Component,
+    {
+        let value = self.run_loop(root_component)?;
+        Ok(value
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value))
     }
 
     /// Sets the render configuration for debugging and optimization control.
@@ -249,6 +511,88 @@ impl App {
         self
     }
 
+    /// Sets the theme that built-in components read their defaults from.
+    ///
+    /// Components access it through [`Context::theme`]. Defaults to [`Theme::dark`].
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Arc::new(theme);
+        self
+    }
+
+    /// Loads the theme from a TOML or JSON file and reloads it whenever the file changes.
+    ///
+    /// The file is polled on a background thread. Each successful reload swaps
+    /// the theme and redraws the whole screen. When the file fails to parse, the
+    /// last good theme stays active and the error is available through
+    /// [`Context::theme_error`] until the file loads again.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn watch_theme(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.theme_watcher = Some(ThemeWatcher::spawn(
+            path.into(),
+            Duration::from_millis(THEME_POLL_INTERVAL_MS),
+        ));
+        self
+    }
+
+    /// Enables recording of per-frame timings and counters.
+    ///
+    /// Stats for the last frame are available to components through
+    /// [`Context::frame_stats`] and passed to [`App::on_frame_stats`]. When
+    /// disabled (the default), no clocks are read.
+    pub fn profiling(mut self, enabled: bool) -> Self {
+        self.config.profiling = enabled;
+        self
+    }
+
+    /// Enables or disables layout warnings.
+    ///
+    /// In strict mode each drawn frame is checked for layout mistakes that
+    /// fail silently: percentage sizes inside content-sized parents, key
+    /// handlers on elements that can't be focused, elements laid out with no
+    /// width or height that have children, and sizes that contradict their
+    /// minimum or maximum. Each distinct warning is printed to stderr, with
+    /// the component that built the element, once the terminal is restored
+    /// on exit. Setting the `RXTUI_LINT` environment variable enables strict
+    /// mode as well.
+    pub fn strict(mut self, enabled: bool) -> Self {
+        self.config.strict = enabled;
+        self
+    }
+
+    /// Enables spatial focus, where the arrow keys move focus to the nearest
+    /// focusable element in their direction instead of reaching handlers.
+    ///
+    /// Tab order is unchanged. An arrow key is still delivered as usual when
+    /// there is nothing to move to, when Ctrl, Alt or Meta is held, or when
+    /// the focused element handles that key itself (an input moving its
+    /// cursor, a scrollable list). Defaults to false.
+    pub fn spatial_focus(mut self, enabled: bool) -> Self {
+        self.config.spatial_focus = enabled;
+        self
+    }
+
+    /// Draws in two tones, the terminal's own foreground and background.
+    ///
+    /// Colors are dropped as each frame is painted and emphasis is kept with
+    /// attributes: cells whose background differs from the root element's
+    /// are drawn in reverse video, so selections, buttons and focus
+    /// highlights still stand out, text in colors other than the theme's
+    /// foreground, muted and border colors is drawn bold, and so is the
+    /// focused element's border. For monochrome terminals and users who need
+    /// high contrast. Defaults to false.
+    pub fn monochrome(mut self, enabled: bool) -> Self {
+        self.config.monochrome = enabled;
+        self
+    }
+
+    /// Sets a callback that receives the stats of each drawn frame while profiling.
+    pub fn on_frame_stats(mut self, callback: impl Fn(&FrameStats) + 'static) -> Self {
+        self.frame_stats_fn = Some(Box::new(callback));
+        self
+    }
+
     /// Disables all rendering optimizations for debugging.
     /// This is equivalent to calling all disable_* methods.
     pub fn disable_all_optimizations(mut self) -> Self {
@@ -285,6 +629,55 @@ impl App {
         self
     }
 
+    /// Sets the event polling duration used while the terminal window is unfocused.
+    /// Longer than `poll_duration`, it lowers the frame rate of background windows;
+    /// the screen is repainted in full once focus returns. Off by default.
+    pub fn unfocused_poll_duration(mut self, duration_ms: u64) -> Self {
+        self.config.unfocused_poll_duration_ms = Some(duration_ms);
+        self
+    }
+
+    /// Sets how long a key sequence waits for its next key, in milliseconds.
+    /// Defaults to 1000ms; the keys typed so far are dropped once it runs out.
+    pub fn key_sequence_timeout(mut self, timeout_ms: u64) -> Self {
+        self.config.key_sequence_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Sets the columns between tab stops that tabs in text expand to.
+    /// Defaults to 8, like most terminals; text can override it with its own `tab_width`.
+    pub fn tab_width(mut self, width: u16) -> Self {
+        self.config.tab_width = width;
+        self
+    }
+
+    /// Sets the widths at which `Context::breakpoint` changes band.
+    /// Defaults to 40, 80 and 120 columns for `Sm`, `Md` and `Lg`.
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.config.breakpoints = breakpoints;
+        self
+    }
+
+    /// Sets whether `Context::set_title` may change the terminal window title.
+    /// On by default; turn it off where the title belongs to something else,
+    /// such as a multiplexer showing its own.
+    pub fn window_title(mut self, enabled: bool) -> Self {
+        self.config.window_title = enabled;
+        self
+    }
+
+    /// Lets the app run when stdout is not a terminal, such as in a pipe or CI log.
+    ///
+    /// Instead of taking over the screen, the first frame is laid out at 80
+    /// columns, printed once as plain text or a semantic outline, and the app
+    /// exits. Without it, [`run`](Self::run) returns an
+    /// [`Unsupported`](io::ErrorKind::Unsupported) error in that case. Has no
+    /// effect when stdout is a terminal.
+    pub fn allow_non_tty(mut self, fallback: NonTtyFallback) -> Self {
+        self.non_tty_fallback = Some(fallback);
+        self
+    }
+
     /// Sets the app to use a fast polling rate (10ms).
     /// This makes the app very responsive but uses more CPU.
     pub fn fast_polling(mut self) -> Self {
@@ -299,209 +692,216 @@ impl App {
         self
     }
 
-    /// Main event loop using component-based architecture.
+    /// Starts the application without entering its event loop.
     ///
-    /// Manages component state through messages and actions,
-    /// expanding component trees into VNode trees for rendering.
+    /// Use this to drive the app from a loop you own, e.g. one that also
+    /// serves a network socket. The returned [`RunningApp`] performs one step
+    /// of [`run`](Self::run) per call:
     ///
-    /// Only renders when:
-    /// 1. Initial render
-    /// 2. Messages are processed and state changes
-    /// 3. External events trigger render
-    /// 4. Terminal is resized
-    fn run_loop<C>(&mut self, root_component: C) -> io::Result<()>
+    /// 1. [`tick`](RunningApp::tick) processes pending messages, effects and
+    ///    timers and rebuilds the view
+    /// 2. [`render`](RunningApp::render) draws the frame if anything changed
+    /// 3. [`handle_event`](RunningApp::handle_event) passes in each terminal
+    ///    event you read, then go back to 1
+    ///
+    /// Stop once [`is_running`](RunningApp::is_running) is false and call
+    /// [`shutdown`](RunningApp::shutdown). The caller owns input: nothing
+    /// reads the terminal, so [`poll_timeout`](RunningApp::poll_timeout)
+    /// tells how long to wait for an event before ticking again.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let mut app = App::new()?;
+    /// let mut running = app.init(MyRootComponent::default())?;
+    /// while running.is_running() {
+    ///     running.tick()?;
+    ///     running.render()?;
+    ///     if event::poll(running.poll_timeout())? {
+    ///         running.handle_event(event::read()?)?;
+    ///     }
+    ///     serve_requests();
+    /// }
+    /// running.shutdown();
+    /// ```
+    pub fn init<C>(&mut self, root_component: C) -> io::Result<RunningApp<'_>>
     where
         C: Component,
     {
+        if !self.tty && self.non_tty_fallback.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "stdout is not a terminal; use App::allow_non_tty to print a text fallback",
+            ));
+        }
+
+        if std::env::var_os(LINT_VAR).is_some() {
+            self.config.strict = true;
+        }
+
         let focus_clear_flag = self.vdom.focus_clear_flag();
-        let mut context = Context::new(focus_clear_flag);
+        let context = Context::new(focus_clear_flag);
+        context.set_theme(self.theme.clone());
+        context.set_breakpoints(self.config.breakpoints);
         let mut components: HashMap<ComponentId, Arc<dyn Component>> = HashMap::new();
 
         // Store the root component
         let root_id = ComponentId::default();
         let root_arc = Arc::new(root_component) as Arc<dyn Component>;
-        let root_type_id = root_arc.type_id();
         components.insert(root_id.clone(), root_arc.clone());
+        root_arc.on_mount(&context);
 
-        let mut needs_render = true; // Initial render
-
-        // Spawn effects for root component ONCE before entering the loop
+        // Spawn effects for root component ONCE before the first tick
         #[cfg(feature = "effects")]
-        if let Some(runtime) = &self.effect_runtime
-            && !context.effect_tracker.has_effects(&root_id, root_type_id)
-        {
-            let effects = root_arc.effects(&context);
-            if !effects.is_empty() {
-                runtime.spawn(root_id.clone(), effects);
-                context
-                    .effect_tracker
-                    .mark_spawned(root_id.clone(), root_type_id);
-            }
-        }
-
-        while *self.running.borrow() {
-            // Check if we have pending messages that need processing
-            if context.has_pending_messages() {
-                needs_render = true;
-            }
-
-            // Expand component tree to VNode tree
-            let vnode_tree = if let Some(root_component) = components.get(&root_id) {
-                context.current_component_id = root_id.clone();
-                // Create a temporary clone of components to avoid borrow issues
-                let mut temp_components = HashMap::new();
-
-                // Expand the tree, processing messages and handling exit signals
-                match self.expand_component_tree(
-                    root_component.as_ref(),
-                    &mut context,
-                    &mut temp_components,
-                ) {
-                    Ok(vnode) => {
-                        // Handle effects for dynamically mounted/unmounted components
-                        #[cfg(feature = "effects")]
-                        if let Some(runtime) = &self.effect_runtime {
-                            // Build a set of current component instances with their types
-                            let mut current_instances: HashSet<(ComponentId, std::any::TypeId)> =
-                                HashSet::new();
-                            for (comp_id, component) in &temp_components {
-                                if comp_id != &root_id {
-                                    // Skip root, already handled
-                                    current_instances
-                                        .insert((comp_id.clone(), component.type_id()));
-                                }
-                            }
-
-                            // Spawn effects for newly mounted components (not root)
-                            for (comp_id, component) in &temp_components {
-                                // Skip root component as it's already handled
-                                if comp_id != &root_id {
-                                    let type_id = component.type_id();
-
-                                    // Check if this exact component instance (ID + Type) has effects
-                                    if !context.effect_tracker.has_effects(comp_id, type_id) {
-                                        // This is a truly new component instance
-                                        // CRITICAL: Set the context's component ID so effects send messages to the right component
-                                        let original_id = context.current_component_id.clone();
-                                        context.current_component_id = comp_id.clone();
-
-                                        let effects = component.effects(&context);
-                                        if !effects.is_empty() {
-                                            runtime.spawn(comp_id.clone(), effects);
-                                            context
-                                                .effect_tracker
-                                                .mark_spawned(comp_id.clone(), type_id);
-                                        }
-
-                                        // Restore original ID
-                                        context.current_component_id = original_id;
-                                    }
-                                }
-                            }
-
-                            // Cleanup effects for unmounted components (excluding root)
-                            let tracked = context.effect_tracker.get_all();
-                            for (comp_id, type_id) in tracked {
-                                // Never cleanup root component effects
-                                if comp_id == root_id {
-                                    continue;
-                                }
-
-                                // Check if this component instance is still in the tree
-                                if !current_instances.contains(&(comp_id.clone(), type_id)) {
-                                    // Component was unmounted or type changed
-                                    runtime.cleanup(&comp_id);
-                                    context.effect_tracker.remove(&comp_id, type_id);
-                                }
-                            }
-                        }
-
-                        // Merge temp_components back into main components map
-                        // This is critical for nested components to receive messages
-                        components.extend(temp_components);
-                        vnode
-                    }
-                    Err(ExitSignal) => {
-                        *self.running.borrow_mut() = false;
-                        break;
-                    }
+        if let Some(runtime) = &self.effect_runtime {
+            let root_type_id = root_arc.type_id();
+            if !context.effect_tracker.has_effects(&root_id, root_type_id) {
+                let effects = root_arc.effects(&context);
+                if !effects.is_empty() {
+                    runtime.spawn(root_id.clone(), effects);
+                    context
+                        .effect_tracker
+                        .mark_spawned(root_id.clone(), root_type_id);
                 }
-            } else {
-                VNode::div()
-            };
+            }
+        }
 
-            // Render if needed
-            if needs_render || *self.needs_render.borrow() {
-                // Render VNode tree
-                self.vdom.render(vnode_tree);
+        *self.running.borrow_mut() = true;
+
+        Ok(RunningApp {
+            app: self,
+            context,
+            components,
+            root_id,
+            vnode_tree: None,
+            needs_render: true, // Initial render
+            bindings_changed: false,
+            ticked: false,
+        })
+    }
 
-                let focus_requests = context.take_focus_requests();
-                self.apply_focus_requests(&context, focus_requests);
+    /// Main event loop using component-based architecture.
+    ///
+    /// Manages component state through messages and actions,
+    /// expanding component trees into VNode trees for rendering.
+    ///
+    /// Only renders when:
+    /// 1. Initial render
+    /// 2. Messages are processed and state changes
+    /// 3. External events trigger render
+    /// 4. Terminal is resized
+    fn run_loop<C>(&mut self, root_component: C) -> io::Result<Option<Box<dyn Any + Send>>>
+    where
+        C: Component,
+    {
+        let mut running = self.init(root_component)?;
+        let _input = InputWatcher::spawn(running.context.wakeup.clone());
 
-                let (width, height) = terminal::size()?;
-                self.vdom.layout(width, height);
+        while running.is_running() {
+            running.tick()?;
+            running.render()?;
 
-                self.draw()?;
+            if running.is_running()
+                && let Some(event) = running.wait_for_event()?
+            {
+                running.handle_event(event)?;
+            }
+        }
 
-                // Log render tree if callback is set
-                if let Some(log_fn) = &self.render_log_fn {
-                    let debug_string = self.render_tree_debug_string();
-                    log_fn(&debug_string);
-                }
+        Ok(running.finish())
+    }
 
-                // Clear render flags
-                *self.needs_render.borrow_mut() = false;
-                needs_render = false;
-            }
+    /// Applies the latest result from the theme watcher, if any.
+    ///
+    /// Returns whether the theme or its error changed and a render is needed.
+    #[cfg(feature = "serde")]
+    fn apply_theme_reload(&mut self, context: &Context) -> io::Result<bool> {
+        let Some(result) = self.theme_watcher.as_ref().and_then(ThemeWatcher::poll) else {
+            return Ok(false);
+        };
 
-            // Poll for events with configurable timeout
-            if event::poll(std::time::Duration::from_millis(
-                self.config.poll_duration_ms,
-            ))? {
-                match event::read()? {
-                    Event::Key(key_event) => {
-                        handle_key_event(&self.vdom, key_event);
-                        // Key events may have triggered messages via event handlers
-                        needs_render = true;
-                    }
-                    Event::Mouse(mouse_event) => {
-                        handle_mouse_event(&self.vdom, mouse_event);
-                        // Mouse events may have triggered messages via event handlers
-                        needs_render = true;
-                    }
-                    Event::Resize(width, height) => {
-                        match &self.terminal_mode {
-                            TerminalMode::AlternateScreen => {
-                                // Full re-layout and screen clear for alternate screen
-                                self.vdom.layout(width, height);
-                                self.double_buffer.resize(width, height);
-                                self.double_buffer.reset();
-                                self.terminal_renderer.clear_screen()?;
-                            }
-                            TerminalMode::Inline(_) => {
-                                // For inline mode, just update terminal size tracking
-                                // Height is managed by space reservation, width changes trigger re-render
-                                self.inline_state.terminal_size = (width, height);
-                                // Don't clear screen - we're rendering in reserved space
-                            }
-                        }
-                        *self.needs_render.borrow_mut() = true;
-                    }
-                    _ => {}
+        match result {
+            Ok(theme) => {
+                self.theme = Arc::new(theme);
+                context.set_theme(self.theme.clone());
+                context.set_theme_error(None);
+
+                // Every cell may have changed color, so repaint from scratch
+                self.double_buffer.reset();
+                match self.terminal_mode {
+                    TerminalMode::AlternateScreen => self.terminal_renderer.clear_screen()?,
+                    TerminalMode::Viewport(_) => self.double_buffer.invalidate(),
+                    TerminalMode::Inline(_) => {}
                 }
             }
+            Err(error) => context.set_theme_error(Some(error.to_string())),
         }
 
-        Ok(())
+        Ok(true)
+    }
+
+    /// Applies the window title change requested through the context.
+    fn update_title(&mut self, context: &Context) -> io::Result<()> {
+        match context.take_title_request() {
+            Some(TitleRequest::Set(title)) => self.set_title(&title),
+            Some(TitleRequest::Restore) => self.restore_title(),
+            None => Ok(()),
+        }
+    }
+
+    /// Rings the bell and starts or ends a screen flash as requested.
+    ///
+    /// Returns whether a flash started or ended and a render is needed.
+    fn update_alerts(&mut self, context: &Context) -> io::Result<bool> {
+        if context.take_bell_request() && self.tty {
+            self.terminal_renderer.bell()?;
+        }
+
+        if context.take_flash_request() {
+            self.flash_until = Some(Instant::now() + Duration::from_millis(FLASH_DURATION_MS));
+            return Ok(true);
+        }
+
+        if self
+            .flash_until
+            .is_some_and(|until| Instant::now() >= until)
+        {
+            self.flash_until = None;
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Completes the stats for the frame just drawn and publishes them.
+    fn finish_frame_stats(&mut self, context: &Context) {
+        let mut stats = std::mem::take(&mut self.frame_stats);
+        stats.frame += 1;
+        self.frame_stats.frame = stats.frame;
+
+        stats.view_calls = self.view_calls.take();
+        stats.nodes_laid_out = self
+            .vdom
+            .get_render_tree()
+            .root
+            .as_ref()
+            .map_or(0, count_nodes);
+        stats.bytes_written = self.terminal_renderer.take_bytes_written();
+
+        context.set_frame_stats(stats);
+        if let Some(callback) = &self.frame_stats_fn {
+            callback(&stats);
+        }
     }
 
-    /// Expands a component tree into a VNode tree recursively
+    /// Expands a component tree into VNodes recursively.
+    ///
+    /// A component whose view is a fragment expands to several sibling VNodes.
     fn expand_component_tree(
         &self,
         component: &dyn Component,
         context: &mut Context,
         components: &mut HashMap<ComponentId, Arc<dyn Component>>,
-    ) -> Result<VNode, ExitSignal> {
+    ) -> Result<Vec<VNode>, ExpandError> {
         // Process all pending messages (regular, owned topics, and unassigned topics)
         let messages = context.drain_all_messages();
         for (msg, topic) in messages {
@@ -539,7 +939,12 @@ impl App {
                     }
                 }
                 Action::Exit => {
-                    return Err(ExitSignal);
+                    context.request_exit(Box::new(()));
+                    return Err(ExpandError::Exit(ExitSignal));
+                }
+                Action::ExitWith(value) => {
+                    context.request_exit(value);
+                    return Err(ExpandError::Exit(ExitSignal));
                 }
                 Action::None => {
                     // Component didn't handle this message, leave topic unassigned
@@ -548,33 +953,167 @@ impl App {
         }
 
         // Get the node from the component's view
+        if self.config.profiling {
+            self.view_calls.set(self.view_calls.get() + 1);
+        }
         context.begin_component_render();
-        let node = component.view(context);
+        let catching = CATCHING_PANICS.replace(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| component.try_view(context)));
+        CATCHING_PANICS.set(catching);
         context.end_component_render();
 
-        // Convert Node to VNode, expanding any nested components
-        self.node_to_vnode(node, context, components, 0)
+        let node = match result {
+            Ok(Ok(node)) => node,
+            Ok(Err(error)) => return Err(ExpandError::Failed(error)),
+            Err(payload) => {
+                self.caught_panic.set(true);
+                return Err(ExpandError::Failed(RenderError::from_panic(
+                    payload.as_ref(),
+                )));
+            }
+        };
+
+        // Convert Node to VNodes, expanding any nested components
+        let mut vnodes = Vec::new();
+        self.node_to_vnodes(node, context, components, 0, &mut vnodes)?;
+        Ok(vnodes)
+    }
+
+    /// Expands a component, rendering its fallback if its subtree fails.
+    ///
+    /// Errors the component has no fallback for are passed on to the caller,
+    /// which is the next error boundary up.
+    fn expand_guarded(
+        &self,
+        component: &dyn Component,
+        context: &mut Context,
+        components: &mut HashMap<ComponentId, Arc<dyn Component>>,
+    ) -> Result<Vec<VNode>, ExpandError> {
+        let component_id = context.current_component_id.clone();
+        match self.expand_component_tree(component, context, components) {
+            Err(ExpandError::Failed(error)) => {
+                // Failures deeper in the subtree return without restoring the path
+                context.current_component_id = component_id;
+                let Some(fallback) = component.fallback(context, &error) else {
+                    return Err(ExpandError::Failed(error));
+                };
+
+                let mut vnodes = Vec::new();
+                self.node_to_vnodes(fallback, context, components, 0, &mut vnodes)?;
+                Ok(vnodes)
+            }
+            result => result,
+        }
+    }
+
+    /// Returns the tab width for text with the given style, falling back to the app's.
+    fn tab_width_for(&self, style: Option<&TextStyle>) -> u16 {
+        style
+            .and_then(|style| style.tab_width)
+            .unwrap_or(self.config.tab_width)
+    }
+
+    /// Returns whether `id` lies in a component marked with `Context::request_render`.
+    fn render_requested(&self, id: &ComponentId) -> bool {
+        self.render_requests
+            .borrow()
+            .iter()
+            .any(|requested| id.is_within(requested))
+    }
+
+    /// Carries a component over from the instance rendered at its id last pass.
+    ///
+    /// A different component type drops the old one's state, and changed props
+    /// restart the component's effects.
+    ///
+    /// Returns whether the component mounted, in which case `on_mount` has run.
+    fn reconcile_component(&self, component: &dyn Component, context: &mut Context) -> bool {
+        let component_id = context.current_component_id.clone();
+        let previous = self.mounted.borrow().get(&component_id).cloned();
+        match reconcile(previous.as_deref(), component) {
+            Reconcile::Remount => {
+                // The old subtree unmounts now, while its state is still there,
+                // so its children mount fresh too rather than carrying over
+                let subtree = self
+                    .mounted
+                    .borrow()
+                    .iter()
+                    .filter(|(id, _)| id.is_within(&component_id))
+                    .map(|(id, component)| (id.clone(), Arc::clone(component)))
+                    .collect();
+                unmount_components(subtree, context);
+                context.current_component_id = component_id.clone();
+                self.mounted
+                    .borrow_mut()
+                    .retain(|id, _| !id.is_within(&component_id));
+
+                // The old component's effects are cleaned up with the unmounted instances
+                context.states.remove_subtree(&component_id);
+                context.locals.retain(|id| !id.is_within(&component_id));
+                component.on_mount(context);
+                return true;
+            }
+            Reconcile::Restart => {
+                // Untracked effects are spawned again, with the new props, after expansion
+                #[cfg(feature = "effects")]
+                if let Some(runtime) = &self.effect_runtime {
+                    runtime.cleanup(&component_id);
+                    context
+                        .effect_tracker
+                        .remove(&component_id, component.type_id());
+                }
+            }
+            Reconcile::Mount => {
+                component.on_mount(context);
+                return true;
+            }
+            Reconcile::Keep => {}
+        }
+        false
     }
 
-    /// Converts a Node to a VNode, expanding components recursively
-    fn node_to_vnode(
+    /// Converts a Node to VNodes appended to `out`, expanding components recursively.
+    ///
+    /// Fragments are flattened, so their children land directly in `out`.
+    fn node_to_vnodes(
         &self,
         node: Node,
         context: &mut Context,
         components: &mut HashMap<ComponentId, Arc<dyn Component>>,
         child_index: usize,
-    ) -> Result<VNode, ExitSignal> {
+        out: &mut Vec<VNode>,
+    ) -> Result<(), ExpandError> {
         match node {
             Node::Component(component) => {
-                // Update context for this component
+                // Update context for this component, identified by its key if it has one
                 let parent_id = context.current_component_id.clone();
-                context.current_component_id = parent_id.child(child_index);
+                context.current_component_id = match component.key() {
+                    Some(key) => parent_id.keyed(&key),
+                    None => parent_id.child(child_index),
+                };
 
                 // Store component in the map
                 let component_id = context.current_component_id.clone();
+                let mounted = self.reconcile_component(component.as_ref(), context);
+
+                // Expand the component recursively, propagating exits and unhandled failures
+                let mut vnodes = match self.expand_guarded(component.as_ref(), context, components)
+                {
+                    Ok(vnodes) => vnodes,
+                    Err(error) => {
+                        // A component that never rendered leaves the tree right away
+                        if mounted && matches!(error, ExpandError::Failed(_)) {
+                            context.current_component_id = component_id;
+                            component.on_unmount(context);
+                        }
+                        return Err(error);
+                    }
+                };
 
-                // Expand the component recursively, propagating any exit signal
-                let vnode = self.expand_component_tree(component.as_ref(), context, components)?;
+                // Tag the component's root div so semantic output can name it
+                if let [VNode::Div(div)] = vnodes.as_mut_slice() {
+                    div.component_name = Some(component.type_name());
+                }
 
                 // Store the component for future updates
                 components.insert(component_id, Arc::clone(&component));
@@ -582,7 +1121,8 @@ impl App {
                 // Restore parent context
                 context.current_component_id = parent_id;
 
-                Ok(vnode)
+                out.extend(vnodes);
+                Ok(())
             }
             Node::Div(div) => {
                 // Track the path through divs to ensure unique component IDs
@@ -592,8 +1132,16 @@ impl App {
                 // Convert div children
                 let mut vnode_children = Vec::new();
                 for (i, child) in div.children.into_iter().enumerate() {
-                    // Propagate any exit signal from children
-                    vnode_children.push(self.node_to_vnode(child, context, components, i)?);
+                    let start = vnode_children.len();
+                    // Propagate any exit signal or unhandled failure from children
+                    self.node_to_vnodes(child, context, components, i, &mut vnode_children)?;
+                    if div.selected == Some(i) {
+                        highlight_selected(
+                            &mut vnode_children[start..],
+                            div.selected_style.as_ref(),
+                            &context.theme(),
+                        );
+                    }
                 }
 
                 // Restore parent context after processing div children
@@ -607,30 +1155,179 @@ impl App {
                 vnode_div.styles = div.styles;
                 vnode_div.events = div.events;
                 vnode_div.focusable = div.focusable;
+                vnode_div.scroll_capture = div.scroll_capture;
+                vnode_div.label = div.label;
+                vnode_div.role = div.role;
                 vnode_div.focused = div.focused;
                 vnode_div.hovered = div.hovered;
+                vnode_div.memo_key = div.memo_key.filter(|_| !self.render_requested(&parent_id));
                 vnode_div.component_path = Some(parent_id);
 
-                Ok(VNode::Div(vnode_div))
+                out.push(VNode::Div(vnode_div));
+                Ok(())
             }
-            Node::Text(text) => {
-                // Text nodes are directly converted
-                Ok(VNode::Text(text))
-            }
-            Node::RichText(rich) => {
-                // RichText nodes are directly converted
-                Ok(VNode::RichText(rich))
-            }
-        }
-    }
+            Node::Fragment(children) => {
+                // A fragment takes one path segment, like a div, so components inside it
+                // keep their identity when siblings around the fragment change
+                let parent_id = context.current_component_id.clone();
+                context.current_component_id = parent_id.child(child_index);
 
-    /// Returns a debug string representation of the current render tree.
-    ///
+                // Splice the children into the parent's list
+                for (i, child) in children.into_iter().enumerate() {
+                    self.node_to_vnodes(child, context, components, i, out)?;
+                }
+
+                context.current_component_id = parent_id;
+                Ok(())
+            }
+            Node::Text(mut text) => {
+                // The displayed case only; the component's content stays as written
+                if let Some(transform) = text.style.as_ref().and_then(|s| s.text_transform) {
+                    text.content = transform_text(&text.content, transform);
+                }
+
+                // Tabs are expanded here so layout and diffing only see spaces
+                if text.content.contains('\t') {
+                    let tab_width = self.tab_width_for(text.style.as_ref());
+                    text.content = expand_tabs(&text.content, tab_width);
+                }
+                out.push(VNode::Text(text));
+                Ok(())
+            }
+            Node::RichText(mut rich) => {
+                // Spans use their own transform or the rich text's, tracking words across them
+                let shared = rich.style.as_ref().and_then(|s| s.text_transform);
+                let transform_of = |span: &TextSpan| {
+                    span.style
+                        .as_ref()
+                        .and_then(|s| s.text_transform)
+                        .or(shared)
+                };
+                if rich.spans.iter().any(|span| transform_of(span).is_some()) {
+                    let mut word_start = true;
+                    for span in &mut rich.spans {
+                        let transform = transform_of(span).unwrap_or_default();
+                        span.content =
+                            transform_text_from(&span.content, transform, &mut word_start);
+                    }
+                }
+
+                // Spans share the tab stops of the line they're on
+                if rich.spans.iter().any(|span| span.content.contains('\t')) {
+                    let tab_width = self.tab_width_for(rich.style.as_ref());
+                    let mut column = 0;
+                    for span in &mut rich.spans {
+                        span.content = expand_tabs_from(&span.content, tab_width, &mut column);
+                    }
+                }
+                out.push(VNode::RichText(rich));
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns a debug string representation of the current render tree.
+    ///
     /// This is useful for debugging and logging the UI structure.
     pub fn render_tree_debug_string(&self) -> String {
         self.vdom.get_render_tree().debug_string()
     }
 
+    /// Lays out the current tree and returns the layout as indented text.
+    ///
+    /// The tree is laid out at the size of the last frame, or at the app's
+    /// size before the first one, so changes since that frame show up. Lists
+    /// every render node with its position, box and content size, scroll
+    /// state and layout style, in a stable format meant for bug reports and
+    /// for comparing layouts in tests.
+    pub fn debug_layout(&mut self) -> String {
+        let (width, height, unclamped_height) = match self.vdom.get_render_tree().last_layout() {
+            Some(layout) => layout,
+            None => {
+                let (width, height) = self.double_buffer.back_buffer_mut().dimensions();
+                (width, height, false)
+            }
+        };
+        self.vdom
+            .layout_with_options(width, height, unclamped_height);
+        self.vdom.get_render_tree().layout_string()
+    }
+
+    /// Returns the cells of the last frame as a text snapshot.
+    ///
+    /// The laid out tree is drawn again into a fresh buffer, so the snapshot
+    /// holds every cell whichever terminal mode the app runs in. Save it as a
+    /// golden file with [`assert_snapshot`](crate::assert_snapshot) to catch
+    /// visual regressions; [`diff_snapshots`](crate::diff_snapshots) lists
+    /// the cells that changed.
+    pub fn snapshot(&self) -> String {
+        let root = self.vdom.get_render_tree().root.clone();
+        let Some(root) = root else {
+            return ScreenBuffer::new(0, 0).to_snapshot();
+        };
+        let root = root.borrow();
+        let (width, height) = (root.x + root.width, root.y + root.height);
+        let mut buffer = ScreenBuffer::new(width, height);
+        let clip_rect = Rect::new(0, 0, width, height);
+        render_frame(
+            &root,
+            &mut buffer,
+            &clip_rect,
+            &self.theme,
+            self.config.monochrome,
+        );
+        buffer.to_snapshot()
+    }
+
+    /// Returns the topmost element drawn at the given terminal coordinates.
+    ///
+    /// This is the element a click there would go to: z-index, portals,
+    /// clipping and scrolling are taken into account as they are for mouse
+    /// events, and text is skipped in favor of the element around it. The
+    /// [`Hit`] tells which component built it and where the point falls
+    /// within it, for custom pointer handling such as picking in a canvas.
+    /// Uses the layout of the last frame.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<Hit> {
+        self.vdom.get_render_tree().hit_test(x, y)
+    }
+
+    /// Returns the layout mistakes found in the last frame, see [`App::strict`].
+    pub fn layout_warnings(&self) -> Vec<String> {
+        self.vdom.get_render_tree().layout_warnings()
+    }
+
+    /// Records the last frame's new layout warnings for printing on exit.
+    fn collect_layout_warnings(&mut self) {
+        for warning in self.vdom.get_render_tree().lint() {
+            let text = warning.to_string();
+            if self
+                .seen_layout_warnings
+                .insert((warning.component, warning.message))
+            {
+                self.layout_warnings.push(text);
+            }
+        }
+    }
+
+    /// Returns a plain-text semantic outline of the current UI.
+    ///
+    /// Unlike the ANSI output, this reflects structure and content (text, labels,
+    /// focus state) rather than cells, which makes it suitable for CI logs,
+    /// screen readers, and assertions in automated tests.
+    pub fn render_semantic(&self) -> String {
+        self.vdom.render_semantic()
+    }
+
+    /// Returns the current UI as linear text for screen readers.
+    ///
+    /// Content is read top to bottom and left to right, one line per piece of
+    /// text, with the [`Role`](crate::Role) and label of each div announced and
+    /// decoration such as borders and spinners left out. Pipe it to a reader
+    /// or log it to follow the UI without the visual grid.
+    pub fn accessible_text(&self) -> String {
+        self.vdom.accessible_text()
+    }
+
     /// Sets a callback function to be called after each render with the render tree debug string.
     ///
     /// This is useful for logging the render tree state for debugging purposes.
@@ -638,6 +1335,42 @@ impl App {
         self.render_log_fn = Some(Box::new(log_fn));
     }
 
+    /// Sets the terminal window title, like [`Context::set_title`] but right away.
+    ///
+    /// The terminal's own title is saved on its title stack the first time, so
+    /// it can be restored on exit or with [`restore_title`](Self::restore_title).
+    /// Does nothing without a terminal or when titles are disabled with
+    /// [`window_title`](Self::window_title).
+    pub fn set_title(&mut self, title: &str) -> io::Result<()> {
+        if !self.tty || !self.config.window_title {
+            return Ok(());
+        }
+
+        if let Some(mut raw) = RAW_TERMINAL.get()
+            && !raw.title_pushed
+        {
+            self.terminal_renderer.push_title()?;
+            raw.title_pushed = true;
+            RAW_TERMINAL.set(Some(raw));
+        }
+        self.terminal_renderer.set_title(title)
+    }
+
+    /// Gives back the title the terminal had before the app set one.
+    ///
+    /// Terminals without a title stack keep the last title; set one explicitly
+    /// with [`set_title`](Self::set_title) there instead.
+    pub fn restore_title(&mut self) -> io::Result<()> {
+        if let Some(mut raw) = RAW_TERMINAL.get()
+            && raw.title_pushed
+        {
+            self.terminal_renderer.pop_title()?;
+            raw.title_pushed = false;
+            RAW_TERMINAL.set(Some(raw));
+        }
+        Ok(())
+    }
+
     /// Applies any focus requests that were queued during the render cycle.
     fn apply_focus_requests(&self, context: &Context, requests: Vec<FocusRequest>) {
         let render_tree = self.vdom.get_render_tree();
@@ -649,16 +1382,21 @@ impl App {
                     if let Some(root) = render_tree.find_component_root(&component_id)
                         && let Some(target) = render_tree.find_first_focusable_in(&root)
                     {
-                        render_tree.set_focused_node(Some(target));
+                        render_tree.set_focused_node(Some(target.clone()));
+                        render_tree.request_reveal(target);
                         focus_applied = true;
                     }
                 }
                 FocusTarget::GlobalFirst => {
                     if let Some(target) = render_tree.find_first_focusable_global() {
-                        render_tree.set_focused_node(Some(target));
+                        render_tree.set_focused_node(Some(target.clone()));
+                        render_tree.request_reveal(target);
                         focus_applied = true;
                     }
                 }
+                FocusTarget::Direction(direction) => {
+                    focus_applied |= render_tree.focus_direction(direction);
+                }
             }
         }
 
@@ -671,11 +1409,22 @@ impl App {
         }
     }
 
+    /// Queues the root elements of components asked to be scrolled into view.
+    fn apply_scroll_requests(&self, context: &Context) {
+        let render_tree = self.vdom.get_render_tree();
+        for component_id in context.take_scroll_requests() {
+            if let Some(root) = render_tree.find_component_root(&component_id) {
+                render_tree.request_reveal(root);
+            }
+        }
+    }
+
     /// Renders the current UI tree to the terminal.
     ///
     /// Dispatches to the appropriate rendering method based on terminal mode:
     /// - AlternateScreen: Uses double buffering for flicker-free full-screen rendering
     /// - Inline: Renders to a reserved region in the main terminal buffer
+    /// - Viewport: Renders to a fixed rectangle of the main terminal buffer
     fn draw(&mut self) -> io::Result<()> {
         match &self.terminal_mode {
             TerminalMode::AlternateScreen => {
@@ -690,7 +1439,72 @@ impl App {
                 let config = config.clone();
                 self.draw_inline(&config)
             }
+            TerminalMode::Viewport(config) => {
+                let area = config.area;
+                self.draw_viewport(area)
+            }
+        }
+    }
+
+    /// Draws in viewport mode, confined to `area` of the terminal.
+    fn draw_viewport(&mut self, area: Rect) -> io::Result<()> {
+        use std::io::Write;
+
+        // Only the part of the area that is on screen can be drawn
+        let (term_width, term_height) = terminal::size()?;
+        let area = area.intersection(&Rect::new(0, 0, term_width, term_height));
+
+        let layout_start = start_timer(self.config.profiling);
+        self.vdom.layout(area.width, area.height);
+        self.frame_stats.layout_time += elapsed(layout_start);
+
+        if self.double_buffer.back_buffer_mut().dimensions() != (area.width, area.height) {
+            self.double_buffer.resize(area.width, area.height);
+            self.double_buffer.reset();
+            self.double_buffer.invalidate();
+        }
+
+        // Clear the back buffer
+        let paint_start = start_timer(self.config.profiling);
+        self.double_buffer.clear_back();
+
+        // Render the tree to the back buffer
+        if let Some(root) = &self.vdom.get_render_tree().root {
+            let root_ref = root.borrow();
+            let buffer = self.double_buffer.back_buffer_mut();
+            let clip_rect = Rect::new(0, 0, area.width, area.height);
+            render_frame(
+                &root_ref,
+                buffer,
+                &clip_rect,
+                &self.theme,
+                self.config.monochrome,
+            );
+        }
+        if self.flash_until.is_some() {
+            self.double_buffer.back_buffer_mut().invert();
         }
+        self.frame_stats.paint_time = elapsed(paint_start);
+
+        // Diff and apply updates offset by the area's origin
+        let diff_start = start_timer(self.config.profiling);
+        let updates = self.double_buffer.diff();
+        self.frame_stats.diff_time = elapsed(diff_start);
+        self.frame_stats.cells_written = updates.len();
+
+        let flush_start = start_timer(self.config.profiling);
+        self.terminal_renderer
+            .apply_updates_at(updates, area.x, area.y)?;
+
+        // Swap buffers
+        self.double_buffer.swap();
+
+        // Clear dirty flags
+        self.vdom.get_render_tree().clear_all_dirty();
+
+        io::stdout().flush()?;
+        self.frame_stats.flush_time = elapsed(flush_start);
+        Ok(())
     }
 
     /// Draws in inline mode with space reservation.
@@ -713,8 +1527,10 @@ impl App {
         };
 
         // Layout with full dimensions - unclamped allows root to grow beyond viewport
+        let layout_start = start_timer(self.config.profiling);
         self.vdom
             .layout_with_options(term_width, layout_height, unclamped);
+        self.frame_stats.layout_time += elapsed(layout_start);
 
         // Get actual content height from rendered tree
         let content_height = self
@@ -752,6 +1568,7 @@ impl App {
         }
 
         // Clear the back buffer
+        let paint_start = start_timer(self.config.profiling);
         self.double_buffer.clear_back();
 
         // Render the tree to the back buffer
@@ -759,11 +1576,26 @@ impl App {
             let root_ref = root.borrow();
             let buffer = self.double_buffer.back_buffer_mut();
             let clip_rect = Rect::new(0, 0, term_width, render_height);
-            render_node_to_buffer(&root_ref, buffer, &clip_rect, None);
+            render_frame(
+                &root_ref,
+                buffer,
+                &clip_rect,
+                &self.theme,
+                self.config.monochrome,
+            );
         }
+        if self.flash_until.is_some() {
+            self.double_buffer.back_buffer_mut().invert();
+        }
+        self.frame_stats.paint_time = elapsed(paint_start);
 
         // Diff and apply updates with origin offset
+        let diff_start = start_timer(self.config.profiling);
         let updates = self.double_buffer.diff();
+        self.frame_stats.diff_time = elapsed(diff_start);
+        self.frame_stats.cells_written = updates.len();
+
+        let flush_start = start_timer(self.config.profiling);
         self.terminal_renderer
             .apply_updates_inline(updates, self.inline_state.origin_row)?;
 
@@ -774,12 +1606,14 @@ impl App {
         self.vdom.get_render_tree().clear_all_dirty();
 
         stdout.flush()?;
+        self.frame_stats.flush_time = elapsed(flush_start);
         Ok(())
     }
 
     /// Draws using double buffering and cell diffing for optimal performance.
     fn draw_with_double_buffer(&mut self) -> io::Result<()> {
         // Clear the back buffer
+        let paint_start = start_timer(self.config.profiling);
         self.double_buffer.clear_back();
 
         // Render the tree to the back buffer
@@ -788,14 +1622,29 @@ impl App {
             let buffer = self.double_buffer.back_buffer_mut();
             let (width, height) = buffer.dimensions();
             let clip_rect = Rect::new(0, 0, width, height);
-            render_node_to_buffer(&root_ref, buffer, &clip_rect, None);
+            render_frame(
+                &root_ref,
+                buffer,
+                &clip_rect,
+                &self.theme,
+                self.config.monochrome,
+            );
         }
+        if self.flash_until.is_some() {
+            self.double_buffer.back_buffer_mut().invert();
+        }
+        self.frame_stats.paint_time = elapsed(paint_start);
 
+        let flush_start;
         if self.config.cell_diffing {
             // Diff the buffers to find changes
+            let diff_start = start_timer(self.config.profiling);
             let updates = self.double_buffer.diff();
+            self.frame_stats.diff_time = elapsed(diff_start);
+            self.frame_stats.cells_written = updates.len();
 
             // Apply updates to terminal
+            flush_start = start_timer(self.config.profiling);
             if self.config.terminal_optimizations {
                 self.terminal_renderer.apply_updates(updates)?;
             } else {
@@ -805,8 +1654,13 @@ impl App {
         } else {
             // Redraw entire screen without diffing
             let buffer = self.double_buffer.back_buffer_mut();
+            let (width, height) = buffer.dimensions();
+            self.frame_stats.cells_written = width as usize * height as usize;
+
+            flush_start = start_timer(self.config.profiling);
             self.terminal_renderer.draw_full_buffer(buffer)?;
         }
+        self.frame_stats.flush_time = elapsed(flush_start);
 
         // Swap buffers for next frame
         self.double_buffer.swap();
@@ -817,81 +1671,45 @@ impl App {
         Ok(())
     }
 
-    /// Draws directly to terminal without double buffering (for debugging).
-    fn draw_direct(&mut self) -> io::Result<()> {
-        // Clear screen
-        execute!(io::stdout(), terminal::Clear(terminal::ClearType::All))?;
-
-        // Create a temporary buffer for direct rendering
-        let (width, height) = terminal::size()?;
-        let mut buffer = ScreenBuffer::new(width, height);
-
-        // Render the tree to the temporary buffer
-        if let Some(root) = &self.vdom.get_render_tree().root {
-            let root_ref = root.borrow();
-            let clip_rect = Rect::new(0, 0, width, height);
-            render_node_to_buffer(&root_ref, &mut buffer, &clip_rect, None);
+    /// Returns the size views are laid out in: the viewport region, the
+    /// terminal, or the text fallback size without a terminal.
+    fn render_size(&self) -> (u16, u16) {
+        if !self.tty {
+            return NON_TTY_SIZE;
         }
-
-        // Draw each cell directly to terminal
-        let mut stdout = io::stdout();
-        for y in 0..height {
-            for x in 0..width {
-                if let Some(cell) = buffer.get_cell(x, y) {
-                    execute!(stdout, cursor::MoveTo(x, y))?;
-
-                    // Set colors if present
-                    if let Some(fg) = &cell.fg {
-                        execute!(
-                            stdout,
-                            SetForegroundColor(self.terminal_renderer.color_to_crossterm(*fg))
-                        )?;
-                    }
-                    if let Some(bg) = &cell.bg {
-                        execute!(
-                            stdout,
-                            SetBackgroundColor(self.terminal_renderer.color_to_crossterm(*bg))
-                        )?;
-                    }
-
-                    // Print character
-                    execute!(stdout, Print(cell.char))?;
-
-                    // Reset colors
-                    if cell.fg.is_some() || cell.bg.is_some() {
-                        execute!(stdout, ResetColor)?;
-                    }
-                }
+        let (width, height) = terminal::size().unwrap_or(NON_TTY_SIZE);
+        match &self.terminal_mode {
+            TerminalMode::Viewport(config) => {
+                // Only the part of the region on screen is laid out
+                let area = config.area.intersection(&Rect::new(0, 0, width, height));
+                (area.width, area.height)
             }
+            _ => (width, height),
         }
-
-        // Clear all dirty flags after drawing
-        self.vdom.get_render_tree().clear_all_dirty();
-
-        Ok(())
     }
-}
-
-//--------------------------------------------------------------------------------------------------
-// Trait Implementations
-//--------------------------------------------------------------------------------------------------
 
-/// Cleanup handler that restores terminal state on application exit.
-///
-/// Automatically:
-/// - Disables mouse capture
-/// - Shows the cursor
-/// - Returns to main screen buffer (alternate screen mode only)
-/// - Moves cursor below content (inline mode with preserve_on_exit)
-/// - Disables raw mode
-impl Drop for App {
-    fn drop(&mut self) {
+    /// Leaves raw mode and undoes the terminal setup of the mode the app ran in.
+    ///
+    /// The screen is restored even after a panic hook already did so; only
+    /// leaving raw mode and the saved title are tied to the pending setup.
+    fn restore_terminal(&mut self) {
         use std::io::Write;
 
+        if !self.tty {
+            return;
+        }
+
+        let raw = RAW_TERMINAL.take();
         let mut stdout = io::stdout();
 
         // Show cursor for both modes
         let _ = stdout.execute(cursor::Show);
+        let _ = stdout.execute(event::DisableFocusChange);
+
+        // Give the terminal its own title back
+        if raw.is_some_and(|raw| raw.title_pushed) {
+            let _ = stdout.write_all(POP_TITLE);
+        }
 
         // Mode-specific cleanup
         match &self.terminal_mode {
@@ -920,12 +1738,1361 @@ impl Drop for App {
                     ));
                 }
             }
+            TerminalMode::Viewport(config) => {
+                if config.mouse_capture {
+                    let _ = stdout.execute(event::DisableMouseCapture);
+                }
+            }
         }
 
         // Flush to ensure all commands are sent before disabling raw mode
         let _ = stdout.flush();
 
-        // Finally disable raw mode
-        let _ = terminal::disable_raw_mode();
+        // Finally disable raw mode, unless the panic hook already left it
+        if raw.is_some() {
+            let _ = terminal::disable_raw_mode();
+        }
+    }
+
+    /// Prints the layout warnings collected in strict mode to stderr.
+    fn print_layout_warnings(&self) {
+        for warning in &self.layout_warnings {
+            eprintln!("rxtui: layout warning: {warning}");
+        }
+    }
+
+    /// Prints the rendered tree once as text, for stdout that isn't a terminal.
+    fn print_fallback(&mut self, fallback: NonTtyFallback) -> io::Result<()> {
+        use std::io::Write;
+
+        let (width, height) = NON_TTY_SIZE;
+        // Let the root grow past the nominal height so nothing is cut off
+        self.vdom.layout_with_options(width, height, true);
+
+        let output = match fallback {
+            NonTtyFallback::Semantic => self.vdom.render_semantic(),
+            NonTtyFallback::PlainText => {
+                let root = self.vdom.get_render_tree().root.clone();
+                let content_height = root.as_ref().map_or(1, |root| root.borrow().height);
+                let mut buffer = ScreenBuffer::new(width, content_height.max(1));
+                if let Some(root) = root {
+                    let clip_rect = Rect::new(0, 0, width, content_height);
+                    render_frame(
+                        &root.borrow(),
+                        &mut buffer,
+                        &clip_rect,
+                        &self.theme,
+                        self.config.monochrome,
+                    );
+                }
+                buffer.to_plain_text()
+            }
+        };
+
+        let mut stdout = io::stdout();
+        writeln!(stdout, "{}", output.trim_end())?;
+        stdout.flush()
+    }
+
+    /// Draws directly to terminal without double buffering (for debugging).
+    fn draw_direct(&mut self) -> io::Result<()> {
+        // Clear screen
+        execute!(io::stdout(), terminal::Clear(terminal::ClearType::All))?;
+
+        // Create a temporary buffer for direct rendering
+        let paint_start = start_timer(self.config.profiling);
+        let (width, height) = terminal::size()?;
+        let mut buffer = ScreenBuffer::new(width, height);
+
+        // Render the tree to the temporary buffer
+        if let Some(root) = &self.vdom.get_render_tree().root {
+            let root_ref = root.borrow();
+            let clip_rect = Rect::new(0, 0, width, height);
+            render_frame(
+                &root_ref,
+                &mut buffer,
+                &clip_rect,
+                &self.theme,
+                self.config.monochrome,
+            );
+        }
+        if self.flash_until.is_some() {
+            buffer.invert();
+        }
+        self.frame_stats.paint_time = elapsed(paint_start);
+        self.frame_stats.cells_written = width as usize * height as usize;
+
+        // Draw each cell directly to terminal, attributes included
+        let flush_start = start_timer(self.config.profiling);
+        self.terminal_renderer.draw_full_buffer(&buffer)?;
+        self.frame_stats.flush_time = elapsed(flush_start);
+
+        // Clear all dirty flags after drawing
+        self.vdom.get_render_tree().clear_all_dirty();
+
+        Ok(())
+    }
+}
+
+impl RunningApp<'_> {
+    /// Returns whether the app is still running.
+    ///
+    /// Turns false once a component exits, or after the text fallback was
+    /// printed when stdout is not a terminal.
+    pub fn is_running(&self) -> bool {
+        *self.app.running.borrow()
+    }
+
+    /// Processes pending messages, effects and timers, and rebuilds the view.
+    ///
+    /// Components update and render here, so an exit they request is seen by
+    /// [`is_running`](Self::is_running) right after. Call it before
+    /// [`render`](Self::render) on every turn of the loop, even when no event
+    /// arrived, as effects and timers deliver messages on their own.
+    pub fn tick(&mut self) -> io::Result<()> {
+        if !self.is_running() {
+            return Ok(());
+        }
+
+        // Updates and views after the first tick see a new frame
+        if std::mem::replace(&mut self.ticked, true) {
+            self.context.advance_frame_clock(Instant::now());
+        }
+
+        // Views branch on the size they are about to be laid out in
+        self.context.set_size(self.app.render_size());
+
+        // Apply theme file changes before expanding so views see the new theme
+        #[cfg(feature = "serde")]
+        if self.app.apply_theme_reload(&self.context)? {
+            self.needs_render = true;
+        }
+
+        // Data outside component state changed and views must run again
+        if self.context.take_redraw_request() {
+            self.needs_render = true;
+        }
+        *self.app.render_requests.borrow_mut() = self.context.take_render_requests();
+
+        // Check if we have pending messages that need processing
+        if self.context.has_pending_messages() {
+            self.needs_render = true;
+        }
+
+        // Views listing bindings only saw the previous set last tick
+        if std::mem::take(&mut self.bindings_changed) {
+            self.needs_render = true;
+        }
+
+        // Expand component tree to VNode tree
+        let view_start = start_timer(self.app.config.profiling);
+        let vnode_tree = if let Some(root_component) = self.components.get(&self.root_id) {
+            self.context.current_component_id = self.root_id.clone();
+            // Create a temporary clone of components to avoid borrow issues
+            let mut temp_components = HashMap::new();
+            self.context.bindings.begin_pass();
+
+            // Expand the tree, processing messages and handling exit signals
+            match self.app.expand_guarded(
+                root_component.as_ref(),
+                &mut self.context,
+                &mut temp_components,
+            ) {
+                Ok(_) if self.context.exit_requested() => {
+                    // A component or effect called `Context::quit_with`
+                    self.app.mounted.borrow_mut().extend(temp_components);
+                    *self.app.running.borrow_mut() = false;
+                    return Ok(());
+                }
+                Ok(vnodes) => {
+                    // Components left out of this pass unmount before their effects stop
+                    let unmounted = self
+                        .app
+                        .mounted
+                        .borrow()
+                        .iter()
+                        .filter(|(id, _)| !temp_components.contains_key(*id))
+                        .map(|(id, component)| (id.clone(), Arc::clone(component)))
+                        .collect();
+                    unmount_components(unmounted, &mut self.context);
+                    self.context.current_component_id = self.root_id.clone();
+
+                    #[cfg(feature = "effects")]
+                    self.update_effects(&temp_components);
+
+                    self.bindings_changed = self.context.bindings.finish_pass();
+
+                    // Drop local cells of components that are no longer mounted
+                    let root_id = &self.root_id;
+                    self.context.locals.retain(|component_id| {
+                        component_id == root_id || temp_components.contains_key(component_id)
+                    });
+
+                    // The next pass reconciles against the components mounted now
+                    *self.app.mounted.borrow_mut() = temp_components.clone();
+
+                    // Merge temp_components back into main components map
+                    // This is critical for nested components to receive messages
+                    self.components.extend(temp_components);
+                    root_vnode(vnodes)
+                }
+                Err(ExpandError::Exit(_)) => {
+                    // Components mounted before the exit still unmount when the app stops
+                    self.app.mounted.borrow_mut().extend(temp_components);
+                    *self.app.running.borrow_mut() = false;
+                    return Ok(());
+                }
+                Err(ExpandError::Failed(error)) => {
+                    return Err(io::Error::other(error));
+                }
+            }
+        } else {
+            VNode::div()
+        };
+        self.app.frame_stats.view_time += elapsed(view_start);
+        self.vnode_tree = Some(vnode_tree);
+
+        // Paint over anything the panic hook printed
+        if self.app.caught_panic.take() {
+            self.app.double_buffer.invalidate();
+            self.needs_render = true;
+        }
+
+        if self.app.update_alerts(&self.context)? {
+            self.needs_render = true;
+        }
+
+        // Raw writes go out after the next frame, and nowhere without a terminal
+        let raw_writes = self.context.take_raw_writes();
+        if self.app.tty {
+            self.app.terminal_renderer.queue_raw(raw_writes);
+        }
+        self.app.update_title(&self.context)
+    }
+
+    /// Spawns effects for newly mounted components and cleans up after unmounted ones.
+    #[cfg(feature = "effects")]
+    fn update_effects(&mut self, mounted: &HashMap<ComponentId, Arc<dyn Component>>) {
+        let Some(runtime) = &self.app.effect_runtime else {
+            return;
+        };
+        let context = &mut self.context;
+
+        // Build a set of current component instances with their types
+        let mut current_instances: HashSet<(ComponentId, std::any::TypeId)> = HashSet::new();
+        for (comp_id, component) in mounted {
+            if comp_id != &self.root_id {
+                // Skip root, already handled
+                current_instances.insert((comp_id.clone(), component.type_id()));
+            }
+        }
+
+        // Spawn effects for newly mounted components (not root)
+        for (comp_id, component) in mounted {
+            // Skip root component as it's already handled
+            if comp_id != &self.root_id {
+                let type_id = component.type_id();
+
+                // Check if this exact component instance (ID + Type) has effects
+                if !context.effect_tracker.has_effects(comp_id, type_id) {
+                    // This is a truly new component instance
+                    // CRITICAL: Set the context's component ID so effects send messages to the right component
+                    let original_id = context.current_component_id.clone();
+                    context.current_component_id = comp_id.clone();
+
+                    let effects = component.effects(context);
+                    if !effects.is_empty() {
+                        runtime.spawn(comp_id.clone(), effects);
+                        context
+                            .effect_tracker
+                            .mark_spawned(comp_id.clone(), type_id);
+                    }
+
+                    // Restore original ID
+                    context.current_component_id = original_id;
+                }
+            }
+        }
+
+        // Cleanup effects for unmounted components (excluding root)
+        let tracked = context.effect_tracker.get_all();
+        for (comp_id, type_id) in tracked {
+            // Never cleanup root component effects
+            if comp_id == self.root_id {
+                continue;
+            }
+
+            // Check if this component instance is still in the tree
+            if !current_instances.contains(&(comp_id.clone(), type_id)) {
+                // Component was unmounted or type changed
+                runtime.cleanup(&comp_id);
+                context.effect_tracker.remove(&comp_id, type_id);
+            }
+        }
+    }
+
+    /// Draws the view built by the last [`tick`](Self::tick) if anything changed.
+    ///
+    /// Without a terminal, prints the text fallback instead and stops the app.
+    pub fn render(&mut self) -> io::Result<()> {
+        let Some(vnode_tree) = self.vnode_tree.take() else {
+            return Ok(());
+        };
+        if !self.is_running() {
+            return Ok(());
+        }
+        if !(self.needs_render || *self.app.needs_render.borrow()) {
+            // No frame is being drawn, so raw writes can go out right away
+            return self.app.terminal_renderer.flush_raw();
+        }
+
+        let app = &mut *self.app;
+
+        // Render VNode tree
+        let reconcile_start = start_timer(app.config.profiling);
+        app.vdom.render(vnode_tree);
+        app.frame_stats.reconcile_time = elapsed(reconcile_start);
+
+        let focus_requests = self.context.take_focus_requests();
+        app.apply_focus_requests(&self.context, focus_requests);
+        app.apply_scroll_requests(&self.context);
+
+        if let Some(fallback) = app.non_tty_fallback.filter(|_| !app.tty) {
+            app.print_fallback(fallback)?;
+            *app.running.borrow_mut() = false;
+            return Ok(());
+        }
+
+        let (width, height) = terminal::size()?;
+        let layout_start = start_timer(app.config.profiling);
+        app.vdom.layout(width, height);
+        app.frame_stats.layout_time = elapsed(layout_start);
+
+        app.draw()?;
+        app.vdom.get_render_tree().clear_reveals();
+        if app.config.strict {
+            app.collect_layout_warnings();
+        }
+        app.terminal_renderer.flush_raw()?;
+
+        if app.config.profiling {
+            app.finish_frame_stats(&self.context);
+        }
+
+        // Log render tree if callback is set
+        if let Some(log_fn) = &app.render_log_fn {
+            let debug_string = app.render_tree_debug_string();
+            log_fn(&debug_string);
+        }
+
+        // Clear render flags
+        *app.needs_render.borrow_mut() = false;
+        self.needs_render = false;
+        Ok(())
+    }
+
+    /// Lays out the current tree and returns its layout, see [`App::debug_layout`].
+    pub fn debug_layout(&mut self) -> String {
+        self.app.debug_layout()
+    }
+
+    /// Returns the cells of the last rendered frame, see [`App::snapshot`].
+    pub fn snapshot(&self) -> String {
+        self.app.snapshot()
+    }
+
+    /// Returns the topmost element at the given terminal coordinates in the
+    /// last rendered frame, see [`App::hit_test`].
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<Hit> {
+        self.app.hit_test(x, y)
+    }
+
+    /// Returns how long to wait for a terminal event before the next tick.
+    ///
+    /// This is the app's poll duration, or its unfocused poll duration while
+    /// the window is in the background, shortened so a screen flash ends on time.
+    pub fn poll_timeout(&self) -> Duration {
+        let config = &self.app.config;
+        let poll_duration_ms = match config.unfocused_poll_duration_ms {
+            Some(unfocused_ms) if !self.context.is_terminal_focused() => unfocused_ms,
+            _ => config.poll_duration_ms,
+        };
+        let poll_duration = Duration::from_millis(poll_duration_ms);
+        match self.app.flash_until {
+            Some(until) => poll_duration.min(until.saturating_duration_since(Instant::now())),
+            None => poll_duration,
+        }
+    }
+
+    /// Returns whether [`Context::request_redraw`] was called since the last
+    /// [`tick`](Self::tick).
+    ///
+    /// Loops waiting on other sources can check this to tick early.
+    pub fn redraw_requested(&self) -> bool {
+        self.context.redraw_requested()
+    }
+
+    /// Waits up to the poll timeout for a terminal event flagged by the
+    /// [`InputWatcher`], and reads it.
+    ///
+    /// Returns early, with `None`, when a redraw is requested in the meantime.
+    fn wait_for_event(&self) -> io::Result<Option<Event>> {
+        let wakeup = &self.context.wakeup;
+        if !wakeup.wait(self.poll_timeout()).input {
+            return Ok(None);
+        }
+
+        // The watcher polls again only once the event is read
+        let event = event::read();
+        wakeup.set_input(false);
+        event.map(Some)
+    }
+
+    /// Delivers a terminal event read by the caller.
+    ///
+    /// Key and mouse events run the handlers of the rendered nodes, whose
+    /// messages are processed by the next [`tick`](Self::tick). Events
+    /// arriving before the first [`render`](Self::render) find no handlers.
+    pub fn handle_event(&mut self, event: Event) -> io::Result<()> {
+        let app = &mut *self.app;
+        match event {
+            Event::Key(key_event) => {
+                let timeout = Duration::from_millis(app.config.key_sequence_timeout_ms);
+                let consumed = app
+                    .key_sequence
+                    .handle_key_event(&app.vdom, key_event, timeout)
+                    || (app.config.spatial_focus && handle_spatial_focus(&app.vdom, key_event));
+                // Keys taken by a sequence, spatial focus or scroll capture
+                // don't reach the bindings
+                if !consumed
+                    && !handle_key_event(&app.vdom, key_event)
+                    && let Some(pressed) = KeyWithModifiers::from_key_event(key_event)
+                {
+                    let focused = app.vdom.get_render_tree().get_focused_node();
+                    self.context.bindings.dispatch(pressed, focused.as_ref());
+                }
+                // Key events may have triggered messages via event handlers
+                self.needs_render = true;
+            }
+            Event::Mouse(mouse_event) => {
+                let mouse_event = match &app.terminal_mode {
+                    TerminalMode::Viewport(config) => {
+                        localize_mouse_event(mouse_event, config.area)
+                    }
+                    _ => Some(mouse_event),
+                };
+                if let Some(mouse_event) = mouse_event {
+                    handle_mouse_event(&app.vdom, mouse_event);
+                }
+                // Mouse events may have triggered messages via event handlers
+                self.needs_render = true;
+            }
+            Event::Resize(width, height) => {
+                match &app.terminal_mode {
+                    TerminalMode::AlternateScreen => {
+                        // Full re-layout and screen clear for alternate screen
+                        app.vdom.layout(width, height);
+                        app.double_buffer.resize(width, height);
+                        app.double_buffer.reset();
+                        app.terminal_renderer.clear_screen()?;
+                    }
+                    TerminalMode::Inline(_) => {
+                        // For inline mode, just update terminal size tracking
+                        // Height is managed by space reservation, width changes trigger re-render
+                        app.inline_state.terminal_size = (width, height);
+                        // Don't clear screen - we're rendering in reserved space
+                    }
+                    TerminalMode::Viewport(_) => {
+                        // The terminal may have reflowed the region, so repaint all of it
+                        app.double_buffer.invalidate();
+                    }
+                }
+                *app.needs_render.borrow_mut() = true;
+            }
+            Event::FocusGained => {
+                self.context.set_terminal_focused(true);
+                // Repaint in full in case the window was disturbed in the background
+                app.double_buffer.invalidate();
+                *app.needs_render.borrow_mut() = true;
+            }
+            Event::FocusLost => {
+                self.context.set_terminal_focused(false);
+                // Views may render a paused state
+                *app.needs_render.borrow_mut() = true;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Stops the app and its effects.
+    ///
+    /// The terminal is restored when the [`App`] itself is dropped, so it can
+    /// be started again with [`App::init`].
+    pub fn shutdown(self) {
+        self.finish();
+    }
+
+    /// Stops the app like [`shutdown`](Self::shutdown) and returns the value it exited with.
+    ///
+    /// Returns `None` when no component exited with a value of type `T`, see
+    /// [`App::run_with_value`].
+    pub fn shutdown_with_value<T: 'static>(self) -> Option<T> {
+        self.finish()
+            .and_then(|value| value.downcast::<T>().ok())
+            .map(|value| *value)
+    }
+
+    /// Stops the app and takes its exit value.
+    fn finish(mut self) -> Option<Box<dyn Any + Send>> {
+        *self.app.running.borrow_mut() = false;
+
+        // Everything still in the tree unmounts, the root last
+        let mounted = self.app.mounted.take().into_iter().collect();
+        unmount_components(mounted, &mut self.context);
+        if let Some(root) = self.components.get(&self.root_id) {
+            self.context.current_component_id = self.root_id.clone();
+            root.on_unmount(&self.context);
+        }
+
+        #[cfg(feature = "effects")]
+        if let Some(runtime) = &self.app.effect_runtime {
+            runtime.cleanup_all();
+        }
+
+        self.context.take_exit_value()
+    }
+}
+
+impl InputWatcher {
+    /// Starts polling the terminal, flagging ready input on `wakeup`.
+    ///
+    /// Poll errors are flagged as input too, so the event loop's read
+    /// reports them.
+    fn spawn(wakeup: Arc<Wakeup>) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = thread::spawn({
+            let wakeup = wakeup.clone();
+            let stop = stop.clone();
+            move || {
+                let interval = Duration::from_millis(INPUT_POLL_INTERVAL_MS);
+                while !stop.load(Ordering::SeqCst) {
+                    if event::poll(interval).unwrap_or(true) {
+                        wakeup.set_input(true);
+                        wakeup.wait_input_taken(&stop);
+                    }
+                }
+            }
+        });
+
+        Self {
+            wakeup,
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Calls `on_unmount` on each component, children before their parents.
+///
+/// Leaves the context's current component set to the last one unmounted.
+fn unmount_components(
+    mut components: Vec<(ComponentId, Arc<dyn Component>)>,
+    context: &mut Context,
+) {
+    // Descendant ids extend their ancestors', so longer ids go first
+    components.sort_by(|(a, _), (b, _)| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+    for (component_id, component) in components {
+        context.current_component_id = component_id;
+        component.on_unmount(context);
+    }
+}
+
+/// Turns the root component's expansion into a single tree.
+///
+/// A root fragment has no parent to splice into, so its children are wrapped in a plain div.
+fn root_vnode(mut vnodes: Vec<VNode>) -> VNode {
+    if vnodes.len() == 1 {
+        return vnodes.remove(0);
+    }
+    Div::new().children(vnodes).into()
+}
+
+/// Layers a div's selected style onto the nodes its selected child expanded to.
+///
+/// Divs get the style merged over their own and text takes its background.
+/// Without a style the theme's selection colors are used, text taking both.
+fn highlight_selected(vnodes: &mut [VNode], style: Option<&Style>, theme: &Theme) {
+    let palette = &theme.palette;
+    let (style, foreground) = match style {
+        Some(style) => (style.clone(), None),
+        None => (
+            Style {
+                background: Some(palette.selection_background),
+                ..Default::default()
+            },
+            Some(palette.selection_foreground),
+        ),
+    };
+
+    let highlight_text = |text_style: &mut Option<TextStyle>| {
+        let text_style = text_style.get_or_insert_with(TextStyle::default);
+        if style.background.is_some() {
+            text_style.background = style.background;
+        }
+        if foreground.is_some() {
+            text_style.color = foreground;
+        }
+    };
+    for vnode in vnodes {
+        match vnode {
+            VNode::Div(div) => {
+                div.styles.base = Style::merge(div.styles.base.take(), Some(style.clone()));
+            }
+            VNode::Text(text) => highlight_text(&mut text.style),
+            VNode::RichText(rich) => {
+                for span in &mut rich.spans {
+                    highlight_text(&mut span.style);
+                }
+            }
+        }
+    }
+}
+
+/// Installs a panic hook, once per process, that restores the terminal first.
+///
+/// Without it the panic message would be printed to the alternate screen in
+/// raw mode and lost when the app's drop leaves it. The hook only acts on the
+/// thread running an app, and not for view panics caught by error boundaries;
+/// the previous hook then prints the message as usual.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANICS.get()
+                && let Some(raw) = RAW_TERMINAL.take()
+            {
+                restore_terminal(raw);
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Undoes the terminal setup so the shell is usable and output readable again.
+fn restore_terminal(raw: RawTerminal) {
+    use std::io::Write;
+
+    let mut stdout = io::stdout();
+    let _ = execute!(stdout, cursor::Show, event::DisableFocusChange);
+    if raw.title_pushed {
+        let _ = stdout.write_all(POP_TITLE);
+    }
+    if raw.mouse_capture {
+        let _ = stdout.execute(event::DisableMouseCapture);
+    }
+    if raw.alternate_screen {
+        let _ = stdout.execute(terminal::LeaveAlternateScreen);
+    }
+    let _ = terminal::disable_raw_mode();
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+/// Cleanup handler that restores terminal state on application exit.
+///
+/// Automatically:
+/// - Disables mouse capture and focus reporting
+/// - Shows the cursor
+/// - Restores the window title saved before `Context::set_title` changed it
+/// - Returns to main screen buffer (alternate screen mode only)
+/// - Moves cursor below content (inline mode with preserve_on_exit)
+/// - Leaves the rendered region in place (viewport mode)
+/// - Disables raw mode, unless a panic hook already did
+impl Drop for App {
+    fn drop(&mut self) {
+        self.restore_terminal();
+        self.print_layout_warnings();
+    }
+}
+
+/// Stops the polling thread, so nothing reads the terminal once the app is done.
+impl Drop for InputWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        self.wakeup.notify();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::config::Breakpoint;
+    use crate::component::{Message, MessageExt};
+    use crate::node::{Role, Text};
+    use crate::render_tree::RenderNodeType;
+    use crate::style::Color;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, Default)]
+    struct Count(u32);
+
+    #[derive(Debug, Clone)]
+    struct Increment;
+
+    /// A keyed counter showing its key and count
+    struct Counter(&'static str);
+
+    /// Counters in an order that can change between frames
+    struct Counters(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Component for Counter {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_none() {
+                return Action::none();
+            }
+            Action::update(Count(ctx.get_state::<Count>().0 + 1))
+        }
+
+        fn view(&self, ctx: &Context) -> Node {
+            let count = ctx.get_state::<Count>().0;
+            Div::new()
+                .focusable(true)
+                .child(Text::new(format!("{}={count}", self.0)).into())
+                .into()
+        }
+
+        fn key(&self) -> Option<String> {
+            Some(self.0.to_string())
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for Counters {
+        fn view(&self, _ctx: &Context) -> Node {
+            let order = self.0.lock().unwrap();
+            Div::new()
+                .children(
+                    order
+                        .iter()
+                        .map(|key| Node::Component(Arc::new(Counter(key))))
+                        .collect(),
+                )
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Exits with its count once incremented three times
+    struct Quitter;
+
+    impl Component for Quitter {
+        fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_none() {
+                return Action::none();
+            }
+            let count = ctx.get_state::<Count>().0 + 1;
+            if count == 3 {
+                return Action::exit_with(count);
+            }
+            Action::update(Count(count))
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new().into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    /// Logs its lifecycle, and asks for its data as soon as it mounts
+    struct Loader(&'static str, Log);
+
+    /// Shows a loader while the flag is set
+    struct Page(Arc<Mutex<bool>>, Log);
+
+    impl Component for Loader {
+        fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+            if msg.downcast::<Increment>().is_some() {
+                self.1.lock().unwrap().push(format!("update {}", self.0));
+            }
+            Action::none()
+        }
+
+        fn view(&self, _ctx: &Context) -> Node {
+            self.1.lock().unwrap().push(format!("view {}", self.0));
+            Div::new().into()
+        }
+
+        fn on_mount(&self, ctx: &Context) {
+            self.1.lock().unwrap().push(format!("mount {}", self.0));
+            ctx.send(Increment);
+        }
+
+        fn on_unmount(&self, _ctx: &Context) {
+            self.1.lock().unwrap().push(format!("unmount {}", self.0));
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for Page {
+        fn view(&self, _ctx: &Context) -> Node {
+            let mut page = Div::new();
+            if *self.0.lock().unwrap() {
+                let inner = Loader("inner", self.1.clone());
+                let outer = Div::new().child(Node::Component(Arc::new(inner)));
+                page = page.child(Node::Component(Arc::new(Loader("child", self.1.clone()))));
+                page = page.child(outer.into());
+            }
+            page.into()
+        }
+
+        fn on_mount(&self, _ctx: &Context) {
+            self.1.lock().unwrap().push("mount page".to_string());
+        }
+
+        fn on_unmount(&self, _ctx: &Context) {
+            self.1.lock().unwrap().push("unmount page".to_string());
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// A memoized panel around a memoized child
+    struct MemoPanels;
+
+    impl Component for MemoPanels {
+        fn view(&self, _ctx: &Context) -> Node {
+            let child = Node::Component(Arc::new(MemoPanel));
+            Div::new().memo(0).child(child).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    struct MemoPanel;
+
+    impl Component for MemoPanel {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new().memo(0).into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Panics while rendering
+    struct Chart;
+
+    /// An error boundary around a chart and its legend
+    struct ChartPanel;
+
+    /// A chart panel next to a feed
+    struct Dashboard;
+
+    impl Component for Chart {
+        fn view(&self, _ctx: &Context) -> Node {
+            panic!("chart broke")
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for ChartPanel {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .child(Node::Component(Arc::new(Chart)))
+                .child(Text::new("legend").into())
+                .into()
+        }
+
+        fn fallback(&self, _ctx: &Context, error: &RenderError) -> Option<Node> {
+            Some(Text::new(format!("failed: {error}")).into())
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for Dashboard {
+        fn view(&self, _ctx: &Context) -> Node {
+            Div::new()
+                .child(Node::Component(Arc::new(ChartPanel)))
+                .child(Text::new("feed").into())
+                .into()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Expands and renders a frame, returning the text of each counter
+    fn frame(app: &mut App, context: &mut Context, root: &Counters) -> Vec<String> {
+        context.current_component_id = ComponentId::default();
+        let mut components = HashMap::new();
+        let Ok(vnodes) = app.expand_guarded(root, context, &mut components) else {
+            panic!("expanding the counters failed");
+        };
+        *app.mounted.borrow_mut() = components;
+        app.vdom.render(root_vnode(vnodes));
+
+        let root = app.vdom.get_render_tree().root.clone().unwrap();
+        let root = root.borrow();
+        root.children
+            .iter()
+            .map(
+                |counter| match &counter.borrow().children[0].borrow().node_type {
+                    RenderNodeType::Text(text) => text.clone(),
+                    _ => String::new(),
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn test_reordered_counters_keep_counts_and_focus() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        let mut context = Context::new(app.vdom.focus_clear_flag());
+        let order = Arc::new(Mutex::new(vec!["a", "b", "c"]));
+        let root = Counters(order.clone());
+        frame(&mut app, &mut context, &root);
+
+        // Count a twice and b once, then focus a
+        let list = ComponentId::default().child(0);
+        for key in ["a", "a", "b"] {
+            context.dispatch.send_to_id(list.keyed(key), Increment);
+        }
+        assert_eq!(frame(&mut app, &mut context, &root), ["a=2", "b=1", "c=0"]);
+        let render_tree = app.vdom.get_render_tree();
+        let a = render_tree.root.as_ref().unwrap().borrow().children[0].clone();
+        render_tree.set_focused_node(Some(a.clone()));
+
+        // Reversed, each counter keeps its count, and a its node and focus
+        order.lock().unwrap().reverse();
+        assert_eq!(frame(&mut app, &mut context, &root), ["c=0", "b=1", "a=2"]);
+        let root_node = app.vdom.get_render_tree().root.clone().unwrap();
+        assert!(Rc::ptr_eq(&root_node.borrow().children[2], &a));
+        assert!(a.borrow().focused);
+
+        // Dropping a counter in front of a moves it up with its node
+        order.lock().unwrap().remove(0);
+        assert_eq!(frame(&mut app, &mut context, &root), ["b=1", "a=2"]);
+        assert!(Rc::ptr_eq(&root_node.borrow().children[1], &a));
+        let focused = app.vdom.get_render_tree().get_focused_node().unwrap();
+        assert!(Rc::ptr_eq(&focused, &a));
+    }
+
+    #[test]
+    fn test_running_app_steps_until_exit() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        let error = app.init(Quitter).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(Quitter).unwrap();
+        running.tick().unwrap();
+        assert!(running.is_running());
+        assert!(running.vnode_tree.is_some());
+
+        // Messages sent between ticks are processed by the next one
+        for _ in 0..2 {
+            running
+                .context
+                .dispatch
+                .send_to_id(ComponentId::default(), Increment);
+        }
+        running.tick().unwrap();
+        assert!(running.is_running());
+        running
+            .context
+            .dispatch
+            .send_to_id(ComponentId::default(), Increment);
+        running.tick().unwrap();
+        assert!(!running.is_running());
+        assert_eq!(running.shutdown_with_value::<u32>(), Some(3));
+    }
+
+    #[test]
+    fn test_components_mount_before_first_view_and_unmount_once() {
+        let log = Log::default();
+        let shown = Arc::new(Mutex::new(true));
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(Page(shown.clone(), log.clone())).unwrap();
+        assert_eq!(*log.lock().unwrap(), ["mount page"]);
+
+        // Messages sent while mounting are handled before the first view
+        running.tick().unwrap();
+        assert_eq!(
+            std::mem::take(&mut *log.lock().unwrap()),
+            [
+                "mount page",
+                "mount child",
+                "update child",
+                "view child",
+                "mount inner",
+                "update inner",
+                "view inner",
+            ]
+        );
+        running.tick().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["view child", "view inner"]);
+
+        // Hidden loaders unmount once, and the page last when the app stops
+        log.lock().unwrap().clear();
+        *shown.lock().unwrap() = false;
+        running.context.request_redraw();
+        running.tick().unwrap();
+        running.tick().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["unmount inner", "unmount child"]);
+        running.shutdown();
+        assert_eq!(log.lock().unwrap().last().unwrap(), "unmount page");
+    }
+
+    #[test]
+    fn test_breakpoint_follows_render_size() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false)
+            .breakpoints(Breakpoints::new(20, 60, 80));
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(Quitter).unwrap();
+        assert_eq!(running.context.size(), (0, 0));
+        assert_eq!(running.context.breakpoint(), Breakpoint::Xs);
+
+        // Without a terminal, views are laid out at the fallback size
+        running.tick().unwrap();
+        assert_eq!(running.context.size(), NON_TTY_SIZE);
+        assert_eq!(running.context.breakpoint(), Breakpoint::Lg);
+        assert!(Breakpoint::Sm < Breakpoint::Md);
+        assert_eq!(Breakpoints::default().classify(79), Breakpoint::Sm);
+    }
+
+    #[test]
+    fn test_debug_layout_lays_out_the_current_tree() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.vdom.render(Div::new().width(12).height(3).into());
+        assert!(app.debug_layout().starts_with("Div @ (0, 0) [12x3]"));
+
+        // A later change shows up at the size of the last layout
+        app.vdom.layout(30, 8);
+        app.vdom
+            .render(Div::new().width_fraction(0.5).height(3).into());
+        assert!(app.debug_layout().starts_with("Div @ (0, 0) [15x3]"));
+    }
+
+    #[test]
+    fn test_layout_warnings_are_collected_once_per_component_and_message() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.vdom.render(
+            Div::new()
+                .width_fraction(1.0)
+                .min_height(4)
+                .max_height(3)
+                .into(),
+        );
+
+        // The node is resized between frames, which moves it in the message
+        app.vdom.layout(40, 10);
+        app.collect_layout_warnings();
+        app.vdom.layout(30, 8);
+        app.collect_layout_warnings();
+
+        assert_eq!(app.layout_warnings.len(), 1, "{:?}", app.layout_warnings);
+        assert!(app.layout_warnings[0].ends_with("min_height 4 is larger than max_height 3"));
+    }
+
+    /// Expands a node through the app and renders it, as a frame would.
+    fn render_through_app(node: Node) -> App {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        let mut context = Context::new(app.vdom.focus_clear_flag());
+        let mut out = Vec::new();
+        let mut components = HashMap::new();
+        if app
+            .node_to_vnodes(node, &mut context, &mut components, 0, &mut out)
+            .is_err()
+        {
+            panic!("expanding the tree failed");
+        }
+        app.vdom.render(out.remove(0));
+        app.vdom.layout(40, 10);
+        app
+    }
+
+    #[test]
+    fn test_labels_reach_the_semantic_output() {
+        #[cfg(feature = "components")]
+        {
+            let app = render_through_app(crate::node! { input(placeholder: "Your name") });
+            assert!(
+                app.render_semantic().contains("\"Your name\""),
+                "{}",
+                app.render_semantic()
+            );
+        }
+
+        let app = render_through_app(
+            Div::new()
+                .label("Path")
+                .child(Text::new("~/src").into())
+                .into(),
+        );
+        assert_eq!(app.accessible_text(), "Path: ~/src\n");
+    }
+
+    #[test]
+    fn test_roles_reach_the_semantic_output() {
+        let app = render_through_app(
+            Div::new()
+                .children(vec![
+                    Div::new()
+                        .role(Role::Heading)
+                        .child(Text::new("Settings").into())
+                        .into(),
+                    Div::new()
+                        .role(Role::Button)
+                        .label("Save")
+                        .focusable(true)
+                        .into(),
+                ])
+                .into(),
+        );
+        let text = app.accessible_text();
+        assert!(text.contains("Heading: Settings"), "{text}");
+        assert!(text.contains("Button: Save"), "{text}");
+    }
+
+    #[test]
+    fn test_selected_child_is_highlighted() {
+        let app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        let mut context = Context::new(app.vdom.focus_clear_flag());
+        let expand = |node: Node, context: &mut Context| {
+            let mut out = Vec::new();
+            let mut components = HashMap::new();
+            if app
+                .node_to_vnodes(node, context, &mut components, 0, &mut out)
+                .is_err()
+            {
+                panic!("expanding the list failed");
+            }
+            match out.remove(0) {
+                VNode::Div(div) => div.children,
+                _ => panic!("expected a div"),
+            }
+        };
+        let rows = || -> Vec<Node> {
+            vec![
+                Text::new("one").into(),
+                Text::new("two").into(),
+                Div::new().into(),
+            ]
+        };
+
+        // Text takes the theme's selection colors by default
+        let children = expand(Div::new().children(rows()).selected(1).into(), &mut context);
+        let palette = context.theme().palette.clone();
+        let text_style = |vnode: &VNode| match vnode {
+            VNode::Text(text) => text.style.clone().unwrap_or_default(),
+            _ => panic!("expected text"),
+        };
+        assert_eq!(text_style(&children[0]).background, None);
+        assert_eq!(
+            text_style(&children[1]).background,
+            Some(palette.selection_background)
+        );
+        assert_eq!(
+            text_style(&children[1]).color,
+            Some(palette.selection_foreground)
+        );
+
+        // A div child gets a custom style merged over its own
+        let selected = Style {
+            background: Some(Color::Red),
+            ..Default::default()
+        };
+        let children = expand(
+            Div::new()
+                .children(rows())
+                .selected(2)
+                .selected_style(selected)
+                .into(),
+            &mut context,
+        );
+        let VNode::Div(div) = &children[2] else {
+            panic!("expected a div");
+        };
+        assert_eq!(
+            div.styles.base.as_ref().and_then(|style| style.background),
+            Some(Color::Red)
+        );
+        assert_eq!(text_style(&children[1]).background, None);
+    }
+
+    #[test]
+    fn test_render_request_rebuilds_memoized_divs_of_the_component() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(MemoPanels).unwrap();
+        let memo_keys = |running: &RunningApp| {
+            let Some(VNode::Div(panels)) = &running.vnode_tree else {
+                panic!("expected a div");
+            };
+            let VNode::Div(panel) = &panels.children[0] else {
+                panic!("expected a div");
+            };
+            (panels.memo_key.is_some(), panel.memo_key.is_some())
+        };
+        running.tick().unwrap();
+        assert_eq!(memo_keys(&running), (true, true));
+
+        // Repeated requests from the child mark only its subtree, for one frame
+        running.needs_render = false;
+        let mut panel = running.context.clone();
+        panel.current_component_id = ComponentId::default().child(0).child(0);
+        panel.request_render();
+        panel.request_render();
+        assert!(running.redraw_requested());
+        running.tick().unwrap();
+        assert!(running.needs_render);
+        assert_eq!(memo_keys(&running), (true, false));
+        running.tick().unwrap();
+        assert_eq!(memo_keys(&running), (true, true));
+    }
+
+    #[test]
+    fn test_redraw_requested_from_another_thread() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(Quitter).unwrap();
+        running.tick().unwrap();
+        running.needs_render = false;
+
+        let handle = running.context.redraw_handle();
+        std::thread::spawn(move || handle.request_redraw())
+            .join()
+            .unwrap();
+        assert!(running.redraw_requested());
+
+        running.tick().unwrap();
+        assert!(running.needs_render);
+        assert!(!running.redraw_requested());
+    }
+
+    #[test]
+    fn test_redraw_request_ends_the_wait_for_input() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        app.config.poll_duration_ms = 60_000;
+        let running = app.init(Quitter).unwrap();
+
+        let handle = running.context.redraw_handle();
+        let start = Instant::now();
+        let requester = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            handle.request_redraw();
+        });
+
+        // Woken by the request rather than after the minute-long poll
+        assert!(running.wait_for_event().unwrap().is_none());
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert!(running.redraw_requested());
+        requester.join().unwrap();
+    }
+
+    #[test]
+    fn test_panicking_child_is_replaced_by_the_nearest_fallback() {
+        let mut app = App::create(TerminalMode::AlternateScreen, (40, 10), false);
+        app.non_tty_fallback = Some(NonTtyFallback::PlainText);
+        let mut running = app.init(Dashboard).unwrap();
+        running.tick().unwrap();
+        assert!(running.is_running());
+
+        // The panel and its legend give way to the fallback, the feed stays
+        let Some(VNode::Div(dashboard)) = &running.vnode_tree else {
+            panic!("expected a div");
+        };
+        let texts: Vec<_> = dashboard
+            .children
+            .iter()
+            .map(|child| match child {
+                VNode::Text(text) => text.content.as_str(),
+                _ => panic!("expected text"),
+            })
+            .collect();
+        assert_eq!(texts, ["failed: chart broke", "feed"]);
     }
 }
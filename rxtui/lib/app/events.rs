@@ -1,9 +1,147 @@
+use crate::app::FocusDirection;
+use crate::bounds::Rect;
 use crate::key::{Key, KeyWithModifiers};
-use crate::render_tree::RenderNode;
+use crate::node::{EventCallbacks, KeySequenceHandler};
+use crate::render_tree::{Hit, RenderNode, RenderTree};
 use crate::vdom::VDom;
-use crossterm::event::{KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Keys typed so far towards a registered key sequence.
+///
+/// Owned by the app so a sequence can span several key events.
+#[derive(Debug, Default)]
+pub(crate) struct KeySequenceState {
+    /// Keys of the sequence in progress
+    keys: Vec<Key>,
+
+    /// When the last of those keys was pressed
+    last_press: Option<Instant>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl KeySequenceState {
+    /// Feeds a key event to the sequence handlers in the tree.
+    ///
+    /// Sequences are routed like single keys: global ones from anywhere in the
+    /// tree, plus the focused element's own, or everyone's when nothing is
+    /// focused. Global sequences are skipped while the focused element takes
+    /// text input through `on_any_char`. Keys with Ctrl, Alt or Meta held
+    /// never take part and reset the sequence.
+    ///
+    /// Returns whether the key was consumed, either by completing a sequence
+    /// or by extending one in progress. Consumed keys are not delivered to any
+    /// other handler, even if the sequence is later abandoned.
+    pub(crate) fn handle_key_event(
+        &mut self,
+        vdom: &VDom,
+        key_event: KeyEvent,
+        timeout: Duration,
+    ) -> bool {
+        let Some(key) = Key::from_key_code(key_event.code) else {
+            return false;
+        };
+        if key_event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::META)
+        {
+            self.keys.clear();
+            return false;
+        }
+
+        let render_tree = vdom.get_render_tree();
+        let Some(root) = &render_tree.root else {
+            return false;
+        };
+
+        let mut handlers = Vec::new();
+        match render_tree.get_focused_node() {
+            Some(focused) => {
+                let focused = focused.borrow();
+                if focused.events.on_any_char.is_none() {
+                    collect_key_sequences(root, true, &mut handlers);
+                }
+                handlers.extend(
+                    focused
+                        .events
+                        .on_key_sequence
+                        .iter()
+                        .filter(|(_, _, is_global)| !is_global)
+                        .cloned(),
+                );
+            }
+            None => {
+                collect_key_sequences(root, true, &mut handlers);
+                collect_key_sequences(root, false, &mut handlers);
+            }
+        }
+
+        self.press(key, Instant::now(), timeout, &handlers)
+    }
+
+    /// Adds a key to the sequence in progress and fires any sequence it completes.
+    fn press(
+        &mut self,
+        key: Key,
+        now: Instant,
+        timeout: Duration,
+        handlers: &[KeySequenceHandler],
+    ) -> bool {
+        if self
+            .last_press
+            .is_some_and(|last| now.duration_since(last) > timeout)
+        {
+            self.keys.clear();
+        }
+        self.last_press = Some(now);
+
+        self.keys.push(key);
+        if self.advance(handlers) {
+            return true;
+        }
+
+        // The pending keys lead nowhere, but the new key may start a sequence of its own
+        if self.keys.len() > 1 {
+            self.keys.clear();
+            self.keys.push(key);
+            if self.advance(handlers) {
+                return true;
+            }
+        }
+
+        self.keys.clear();
+        false
+    }
+
+    /// Fires the sequences matching the keys so far, or checks that some sequence
+    /// still starts with them. Returns whether the keys were consumed.
+    fn advance(&mut self, handlers: &[KeySequenceHandler]) -> bool {
+        let completed: Vec<_> = handlers
+            .iter()
+            .filter(|(keys, _, _)| *keys == self.keys)
+            .collect();
+        if !completed.is_empty() {
+            self.keys.clear();
+            for (_, handler, _) in completed {
+                handler();
+            }
+            return true;
+        }
+
+        handlers
+            .iter()
+            .any(|(keys, _, _)| keys.len() > self.keys.len() && keys.starts_with(&self.keys))
+    }
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -11,22 +149,39 @@ use std::rc::Rc;
 
 /// Processes keyboard input events.
 ///
-/// Handles Tab/Shift+Tab for focus navigation, Enter to activate focused elements,
+/// Handles Tab/Shift+Tab for focus navigation (unless the focused element handles Tab
+/// itself), Enter to activate focused elements,
 /// broadcasts to global handlers,
 /// then routes other keys to the focused element.
-pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent) {
+///
+/// Returns whether a scroll capturing region took the key, which then
+/// belongs to no one else.
+pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent) -> bool {
     // Try to create both simple key and key with modifiers
     if let Some(key) = Key::from_key_code(key_event.code) {
         let render_tree = vdom.get_render_tree();
 
         // Handle Tab/BackTab navigation for focus switching
         if key == Key::Tab {
+            // A focused element with its own Tab handler (e.g. accepting a suggestion)
+            // keeps the key instead of moving focus
+            if let Some(focused) = render_tree.get_focused_node()
+                && focused
+                    .borrow()
+                    .events
+                    .on_key
+                    .iter()
+                    .any(|(k, _, is_global)| *k == Key::Tab && !is_global)
+            {
+                focused.borrow().handle_key(key);
+                return false;
+            }
             render_tree.focus_next();
-            return;
+            return false;
         }
         if key == Key::BackTab {
             render_tree.focus_prev();
-            return;
+            return false;
         }
 
         // Handle Enter to activate focused element
@@ -39,11 +194,17 @@ pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent) {
                 focused.borrow().handle_click();
                 // Return immediately to prevent Enter from being handled again
                 // The click simulation takes precedence
-                return;
+                return false;
             }
             // If no click handler, let Enter continue to be processed as a normal key
         }
 
+        // Scroll keys that nothing would act on scroll the capturing region
+        if let Some(region) = scroll_capture_target(render_tree, key, key_event.modifiers) {
+            handle_scroll_key(&region, key);
+            return true;
+        }
+
         // Create KeyWithModifiers for handlers that need it
         if let Some(key_with_modifiers) = KeyWithModifiers::from_key_event(key_event) {
             // Phase 1: Always broadcast to global handlers
@@ -106,6 +267,118 @@ pub fn handle_key_event(vdom: &VDom, key_event: KeyEvent) {
             }
         }
     }
+    false
+}
+
+/// Moves focus in the direction of an arrow key, for apps with spatial focus.
+///
+/// The key is left alone when a modifier other than Shift is held or the
+/// focused element would handle it: scrollable elements, and elements with
+/// their own handler for that key or for any key. Returns whether focus
+/// moved, in which case the key is consumed.
+pub(crate) fn handle_spatial_focus(vdom: &VDom, key_event: KeyEvent) -> bool {
+    let direction = match key_event.code {
+        KeyCode::Up => FocusDirection::Up,
+        KeyCode::Down => FocusDirection::Down,
+        KeyCode::Left => FocusDirection::Left,
+        KeyCode::Right => FocusDirection::Right,
+        _ => return false,
+    };
+    if key_event
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::META)
+    {
+        return false;
+    }
+
+    let render_tree = vdom.get_render_tree();
+    if let Some(focused) = render_tree.get_focused_node()
+        && let Some(key) = Key::from_key_code(key_event.code)
+        && handles_key_itself(&focused.borrow(), key)
+    {
+        return false;
+    }
+    render_tree.focus_direction(direction)
+}
+
+/// Returns whether a focused node would act on a key through its own handlers.
+fn handles_key_itself(node: &RenderNode, key: Key) -> bool {
+    node.scrollable || has_key_handler(&node.events, key, false)
+}
+
+/// Returns whether any of the handlers registered as global, or as not
+/// global, would act on a key, counting key sequences that use it.
+fn has_key_handler(events: &EventCallbacks, key: Key, global: bool) -> bool {
+    (!global && events.on_any_key.is_some())
+        || events
+            .on_key
+            .iter()
+            .any(|(k, _, is_global)| *k == key && *is_global == global)
+        || events
+            .on_key_with_modifiers
+            .iter()
+            .any(|(k, _, is_global)| k.key == key && *is_global == global)
+        || events
+            .on_key_if
+            .iter()
+            .any(|(matches, _, is_global)| *is_global == global && matches(key))
+        || events
+            .on_key_sequence
+            .iter()
+            .any(|(keys, _, is_global)| *is_global == global && keys.contains(&key))
+}
+
+/// Returns the scroll capturing region that should take a key no one else would act on.
+///
+/// Only unmodified scroll keys qualify. They are claimed by the focused
+/// element's own handlers or scrolling, by global handlers anywhere, and,
+/// while nothing is focused, by any handler in the tree, since those are
+/// broadcast the key.
+fn scroll_capture_target(
+    render_tree: &RenderTree,
+    key: Key,
+    modifiers: KeyModifiers,
+) -> Option<Rc<RefCell<RenderNode>>> {
+    if !matches!(
+        key,
+        Key::Up | Key::Down | Key::PageUp | Key::PageDown | Key::Home | Key::End
+    ) || modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::META)
+    {
+        return None;
+    }
+
+    let root = render_tree.root.as_ref()?;
+    let focused = render_tree.get_focused_node();
+    if let Some(focused) = &focused
+        && handles_key_itself(&focused.borrow(), key)
+    {
+        return None;
+    }
+    if claims_key(root, key, focused.is_none()) {
+        return None;
+    }
+    find_scroll_capture(root)
+}
+
+/// Returns whether a handler in the subtree would act on a key, counting
+/// non-global handlers only when `include_local` is set.
+fn claims_key(node: &Rc<RefCell<RenderNode>>, key: Key, include_local: bool) -> bool {
+    let node_ref = node.borrow();
+    has_key_handler(&node_ref.events, key, true)
+        || (include_local && has_key_handler(&node_ref.events, key, false))
+        || node_ref
+            .children
+            .iter()
+            .any(|child| claims_key(child, key, include_local))
+}
+
+/// Finds the first scrollable node in the subtree that captures scroll keys.
+fn find_scroll_capture(node: &Rc<RefCell<RenderNode>>) -> Option<Rc<RefCell<RenderNode>>> {
+    let node_ref = node.borrow();
+    if node_ref.scroll_capture && node_ref.scrollable {
+        return Some(node.clone());
+    }
+    node_ref.children.iter().find_map(find_scroll_capture)
 }
 
 /// Recursively broadcasts a key press to all nodes in the subtree.
@@ -162,20 +435,52 @@ pub fn broadcast_global_key_with_modifiers(
     }
 }
 
+/// Collects the global or non-global key sequence handlers of every node in the subtree.
+fn collect_key_sequences(
+    node: &Rc<RefCell<RenderNode>>,
+    global: bool,
+    out: &mut Vec<KeySequenceHandler>,
+) {
+    let node_ref = node.borrow();
+    out.extend(
+        node_ref
+            .events
+            .on_key_sequence
+            .iter()
+            .filter(|(_, _, is_global)| *is_global == global)
+            .cloned(),
+    );
+    for child in &node_ref.children {
+        collect_key_sequences(child, global, out);
+    }
+}
+
 /// Processes mouse input events.
 ///
 /// Handles:
 /// - Mouse down events by finding the node at the click position
 /// - Sets focus to the clicked node if it's focusable
 /// - Triggers the node's click handler
-/// - Mouse wheel events for scrolling
+/// - Right and middle clicks for the nearest matching handler
+/// - Mouse wheel events for wheel handlers and scrolling
+///
+/// Right and middle clicks fire on release, at the position the button was
+/// pressed, and only if the pointer wasn't dragged in between. A drag with
+/// either button cancels the click.
 pub fn handle_mouse_event(vdom: &VDom, mouse_event: MouseEvent) {
     let render_tree = vdom.get_render_tree();
 
     match mouse_event.kind {
+        MouseEventKind::Down(button @ (MouseButton::Right | MouseButton::Middle)) => {
+            let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
+            render_tree.set_hovered_node(hovered);
+            render_tree.set_pending_press(Some((button, mouse_event.column, mouse_event.row)));
+        }
         MouseEventKind::Down(_) => {
+            render_tree.set_pending_press(None);
             if let Some(node) = render_tree.find_node_at(mouse_event.column, mouse_event.row) {
                 render_tree.set_hovered_node(Some(node.clone()));
+                render_tree.set_active_node(Some(node.clone()));
                 // Set focus if the node is focusable
                 {
                     let node_ref = node.borrow();
@@ -189,65 +494,124 @@ pub fn handle_mouse_event(vdom: &VDom, mouse_event: MouseEvent) {
                 node.borrow().handle_click();
             } else {
                 render_tree.set_hovered_node(None);
+                render_tree.set_active_node(None);
             }
         }
-        MouseEventKind::ScrollUp => {
-            // Find the scrollable node at the mouse position
-            if let Some(node) = render_tree.find_node_at(mouse_event.column, mouse_event.row) {
-                render_tree.set_hovered_node(Some(node.clone()));
-                // Find the nearest scrollable ancestor (including self)
-                if let Some(scrollable_node) = find_scrollable_ancestor(&node) {
-                    let mut node_ref = scrollable_node.borrow_mut();
-                    if node_ref.update_scroll(-3) {
-                        // Mark dirty if scroll position changed
-                        node_ref.mark_dirty();
-                    }
-                }
-            } else {
-                render_tree.set_hovered_node(None);
-            }
-        }
-        MouseEventKind::ScrollDown => {
-            // Find the scrollable node at the mouse position
-            if let Some(node) = render_tree.find_node_at(mouse_event.column, mouse_event.row) {
-                render_tree.set_hovered_node(Some(node.clone()));
-                // Find the nearest scrollable ancestor (including self)
-                if let Some(scrollable_node) = find_scrollable_ancestor(&node) {
-                    let mut node_ref = scrollable_node.borrow_mut();
-                    if node_ref.update_scroll(3) {
-                        // Mark dirty if scroll position changed
-                        node_ref.mark_dirty();
-                    }
-                }
-            } else {
-                render_tree.set_hovered_node(None);
-            }
+        MouseEventKind::ScrollUp => handle_wheel(vdom, mouse_event, -1),
+        MouseEventKind::ScrollDown => handle_wheel(vdom, mouse_event, 1),
+        MouseEventKind::Drag(_) => {
+            render_tree.set_pending_press(None);
+            let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
+            render_tree.set_hovered_node(hovered);
         }
-        MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+        MouseEventKind::Moved => {
             let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
             render_tree.set_hovered_node(hovered);
         }
         MouseEventKind::Up(_) => {
             let hovered = render_tree.find_node_at(mouse_event.column, mouse_event.row);
             render_tree.set_hovered_node(hovered);
+
+            // The press ends wherever the pointer was released
+            render_tree.set_active_node(None);
+
+            // Some terminals don't report which button was released
+            if let Some((button, column, row)) = render_tree.take_pending_press() {
+                handle_pointer_click(vdom, button, column, row);
+            }
         }
         _ => {}
     }
 }
 
-/// Finds the nearest scrollable ancestor of a node (including the node itself).
-fn find_scrollable_ancestor(node: &Rc<RefCell<RenderNode>>) -> Option<Rc<RefCell<RenderNode>>> {
-    // Check if this node is scrollable
-    if node.borrow().scrollable {
-        return Some(node.clone());
+/// Routes a right or middle click to the nearest node (including ancestors)
+/// with a handler for that button, passing the position relative to that node.
+fn handle_pointer_click(vdom: &VDom, button: MouseButton, column: u16, row: u16) {
+    let render_tree = vdom.get_render_tree();
+    let Some(node) = render_tree.find_node_at(column, row) else {
+        return;
+    };
+
+    let handler_of = |node: &RenderNode| match button {
+        MouseButton::Right => node.events.on_right_click.clone(),
+        MouseButton::Middle => node.events.on_middle_click.clone(),
+        MouseButton::Left => None,
+    };
+
+    let mut current = Some(node);
+    while let Some(node) = current {
+        let (handler, parent) = {
+            let node_ref = node.borrow();
+            (
+                handler_of(&node_ref),
+                node_ref.parent.as_ref().and_then(|parent| parent.upgrade()),
+            )
+        };
+        if let Some(handler) = handler {
+            handler(Hit::of(&node, column, row).local);
+            return;
+        }
+        current = parent;
+    }
+}
+
+/// Translates a mouse event into coordinates relative to `area`.
+///
+/// Returns `None` when the pointer is outside the area.
+pub fn localize_mouse_event(mouse_event: MouseEvent, area: Rect) -> Option<MouseEvent> {
+    if !area.contains_point(mouse_event.column, mouse_event.row) {
+        return None;
+    }
+
+    Some(MouseEvent {
+        column: mouse_event.column - area.x,
+        row: mouse_event.row - area.y,
+        ..mouse_event
+    })
+}
+
+/// Routes a mouse wheel step to the node under the pointer.
+///
+/// The nearest node (including ancestors) that has a wheel handler or is
+/// scrollable receives it: handlers are called with `direction`, scrollable
+/// nodes scroll by three lines.
+fn handle_wheel(vdom: &VDom, mouse_event: MouseEvent, direction: i16) {
+    let render_tree = vdom.get_render_tree();
+
+    // Find the wheel target at the mouse position
+    if let Some(node) = render_tree.find_node_at(mouse_event.column, mouse_event.row) {
+        render_tree.set_hovered_node(Some(node.clone()));
+        if let Some(target) = find_wheel_target(&node) {
+            let handler = target.borrow().events.on_scroll.clone();
+            if let Some(handler) = handler {
+                handler(direction);
+            } else {
+                let mut node_ref = target.borrow_mut();
+                if node_ref.update_scroll(direction * 3) {
+                    // Mark dirty if scroll position changed
+                    node_ref.mark_dirty();
+                }
+            }
+        }
+    } else {
+        render_tree.set_hovered_node(None);
+    }
+}
+
+/// Finds the nearest node that handles the mouse wheel (including the node itself).
+fn find_wheel_target(node: &Rc<RefCell<RenderNode>>) -> Option<Rc<RefCell<RenderNode>>> {
+    {
+        let node_ref = node.borrow();
+        if node_ref.events.on_scroll.is_some() || node_ref.scrollable {
+            return Some(node.clone());
+        }
     }
 
-    // Check parent nodes
     let parent_weak = node.borrow().parent.clone();
     if let Some(parent_weak) = parent_weak
         && let Some(parent) = parent_weak.upgrade()
     {
-        return find_scrollable_ancestor(&parent);
+        return find_wheel_target(&parent);
     }
 
     None
@@ -263,17 +627,13 @@ fn handle_scroll_key(node: &Rc<RefCell<RenderNode>>, key: Key) -> bool {
     }
 
     match key {
-        Key::Up => {
-            if node_ref.update_scroll(-1) {
-                node_ref.mark_dirty();
-                return true;
-            }
+        Key::Up if node_ref.update_scroll(-1) => {
+            node_ref.mark_dirty();
+            return true;
         }
-        Key::Down => {
-            if node_ref.update_scroll(1) {
-                node_ref.mark_dirty();
-                return true;
-            }
+        Key::Down if node_ref.update_scroll(1) => {
+            node_ref.mark_dirty();
+            return true;
         }
         Key::PageUp => {
             // Scroll up by half the viewport height
@@ -309,3 +669,414 @@ fn handle_scroll_key(node: &Rc<RefCell<RenderNode>>, key: Key) -> bool {
 
     false
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::ComponentId;
+    use crate::node::Div;
+    use crate::style::{Color, Direction, Overflow, Spacing, Style};
+    use crate::vnode::VNode;
+    use crossterm::event::KeyCode;
+    use std::cell::Cell;
+
+    /// A handler for `keys` that counts how often it fires.
+    fn counting(keys: &str, count: &Rc<Cell<usize>>) -> KeySequenceHandler {
+        let count = count.clone();
+        (
+            keys.chars().map(Key::Char).collect(),
+            Rc::new(move || count.set(count.get() + 1)),
+            false,
+        )
+    }
+
+    /// A mouse event without modifiers.
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_key_sequence_fires_and_swallows_prefix() {
+        let fired = Rc::new(Cell::new(0));
+        let handlers = [counting("gg", &fired)];
+        let mut state = KeySequenceState::default();
+        let timeout = Duration::from_millis(500);
+        let start = Instant::now();
+
+        assert!(state.press(Key::Char('g'), start, timeout, &handlers));
+        assert_eq!(fired.get(), 0);
+        assert!(state.press(Key::Char('g'), start, timeout, &handlers));
+        assert_eq!(fired.get(), 1);
+
+        // A mismatch releases the key, and the next key can start over
+        assert!(state.press(Key::Char('g'), start, timeout, &handlers));
+        assert!(!state.press(Key::Char('x'), start, timeout, &handlers));
+        assert!(state.press(Key::Char('g'), start, timeout, &handlers));
+        assert!(state.press(Key::Char('g'), start, timeout, &handlers));
+        assert_eq!(fired.get(), 2);
+    }
+
+    #[test]
+    fn test_key_sequence_times_out() {
+        let fired = Rc::new(Cell::new(0));
+        let handlers = [counting("dd", &fired)];
+        let mut state = KeySequenceState::default();
+        let timeout = Duration::from_millis(500);
+        let start = Instant::now();
+
+        assert!(state.press(Key::Char('d'), start, timeout, &handlers));
+        let late = start + Duration::from_millis(600);
+        assert!(state.press(Key::Char('d'), late, timeout, &handlers));
+        assert_eq!(fired.get(), 0);
+
+        assert!(state.press(Key::Char('d'), late, timeout, &handlers));
+        assert_eq!(fired.get(), 1);
+    }
+
+    #[test]
+    fn test_key_predicates_follow_specific_handlers() {
+        let log = Rc::new(RefCell::new(Vec::<String>::new()));
+        let (specific, range, arrows, global) =
+            (log.clone(), log.clone(), log.clone(), log.clone());
+        let root: VNode = Div::new()
+            .on_key_if_global(
+                |key| key == Key::F1,
+                move |_| global.borrow_mut().push("help".into()),
+            )
+            .child(
+                Div::new()
+                    .focusable(true)
+                    .on_char('0', move || specific.borrow_mut().push("zero".into()))
+                    .on_char_range('0', '9', move |ch| range.borrow_mut().push(ch.to_string()))
+                    .on_key_if(
+                        |key| matches!(key, Key::Char(_) | Key::Up | Key::Down),
+                        move |key| arrows.borrow_mut().push(format!("{key:?}")),
+                    )
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.get_render_tree().focus_next();
+
+        for code in [
+            KeyCode::Char('0'),
+            KeyCode::Char('7'),
+            KeyCode::Char('x'),
+            KeyCode::Up,
+            KeyCode::Left,
+            KeyCode::F(1),
+        ] {
+            handle_key_event(&vdom, KeyEvent::new(code, KeyModifiers::NONE));
+        }
+
+        // Only the first matching predicate fires, and only when no specific handler did
+        assert_eq!(log.take(), ["zero", "7", "Char('x')", "Up", "help"]);
+    }
+
+    #[test]
+    fn test_right_click_bubbles_with_local_position() {
+        let clicked = Rc::new(Cell::new(None));
+        let recorder = clicked.clone();
+        let root: VNode = Div::new()
+            .width(20)
+            .height(10)
+            .padding(Spacing::all(1))
+            .child(
+                Div::new()
+                    .padding(Spacing::all(2))
+                    .on_right_click(move |position| recorder.set(Some(position)))
+                    .child(Div::new().width(5).height(2).into())
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.layout(20, 10);
+
+        // The innermost div has no handler, so its parent receives the click
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Down(MouseButton::Right), 4, 4));
+        assert_eq!(clicked.get(), None);
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Up(MouseButton::Right), 4, 4));
+        assert_eq!(clicked.get(), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_middle_click_and_right_drag() {
+        let right = Rc::new(Cell::new(0));
+        let middle = Rc::new(Cell::new(None));
+        let (right_count, middle_position) = (right.clone(), middle.clone());
+        let root: VNode = Div::new()
+            .width(20)
+            .height(10)
+            .on_right_click(move |_| right_count.set(right_count.get() + 1))
+            .on_middle_click(move |position| middle_position.set(Some(position)))
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.layout(20, 10);
+
+        handle_mouse_event(
+            &vdom,
+            mouse(MouseEventKind::Down(MouseButton::Middle), 6, 2),
+        );
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Up(MouseButton::Middle), 6, 2));
+        assert_eq!(middle.get(), Some((6, 2)));
+        assert_eq!(right.get(), 0);
+
+        // Dragging with the button held is not a click
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Down(MouseButton::Right), 1, 1));
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Drag(MouseButton::Right), 5, 1));
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Up(MouseButton::Right), 5, 1));
+        assert_eq!(right.get(), 0);
+
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Down(MouseButton::Right), 1, 1));
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Up(MouseButton::Right), 1, 1));
+        assert_eq!(right.get(), 1);
+    }
+
+    #[test]
+    fn test_pressed_style_lasts_until_release() {
+        let button = || -> VNode {
+            Div::new()
+                .width(20)
+                .height(10)
+                .child(
+                    Div::new()
+                        .width(6)
+                        .height(1)
+                        .background(Color::Blue)
+                        .pressed_style(Style::default().background(Color::Red))
+                        .on_click(|| {})
+                        .into(),
+                )
+                .into()
+        };
+
+        let mut vdom = VDom::new();
+        vdom.render(button());
+        vdom.layout(20, 10);
+        let node = vdom
+            .get_render_tree()
+            .root
+            .as_ref()
+            .unwrap()
+            .borrow()
+            .children[0]
+            .clone();
+        let background = || {
+            node.borrow()
+                .style
+                .as_ref()
+                .and_then(|style| style.background)
+        };
+
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Down(MouseButton::Left), 2, 0));
+        assert!(node.borrow().active);
+        assert_eq!(background(), Some(Color::Red));
+
+        // A re-render while the button is held keeps it pressed
+        vdom.render(button());
+        assert_eq!(background(), Some(Color::Red));
+
+        // Releasing away from the button still ends the press
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Drag(MouseButton::Left), 15, 8));
+        assert!(node.borrow().active);
+        handle_mouse_event(&vdom, mouse(MouseEventKind::Up(MouseButton::Left), 15, 8));
+        assert!(!node.borrow().active);
+        assert_eq!(background(), Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_focus_change_reaches_containing_panes() {
+        let log = Rc::new(RefCell::new(Vec::<String>::new()));
+        let pane = |name: &'static str| -> VNode {
+            let change_log = log.clone();
+            let item = |index: usize| -> VNode {
+                let (blur_log, focus_log) = (log.clone(), log.clone());
+                Div::new()
+                    .focusable(true)
+                    .on_blur(move || blur_log.borrow_mut().push(format!("{name}{index} blur")))
+                    .on_focus(move || focus_log.borrow_mut().push(format!("{name}{index} focus")))
+                    .into()
+            };
+            let mut pane = Div::new()
+                .on_focus_change(move |path| {
+                    let path =
+                        path.map(|path| format!("{}{:?}", path.component.unwrap().0, path.indices));
+                    change_log.borrow_mut().push(format!("{name} {path:?}"));
+                })
+                .child(item(0))
+                .child(item(1));
+            pane.component_path = Some(ComponentId::new(format!("0.{name}")));
+            pane.into()
+        };
+        let root: VNode = Div::new().child(pane("left")).child(pane("right")).into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        let render_tree = vdom.get_render_tree();
+
+        render_tree.focus_next();
+        assert_eq!(log.take(), ["left0 focus", r#"left Some("0.left[0]")"#]);
+
+        // Elements of the same component are told apart by their path
+        render_tree.focus_next();
+        assert_eq!(
+            log.take(),
+            ["left0 blur", "left1 focus", r#"left Some("0.left[1]")"#]
+        );
+
+        // Moving to the other pane tells the left one that focus is gone
+        render_tree.focus_next();
+        assert_eq!(
+            log.take(),
+            [
+                "left1 blur",
+                "right0 focus",
+                "left None",
+                r#"right Some("0.right[0]")"#
+            ]
+        );
+
+        render_tree.set_focused_node(None);
+        assert_eq!(log.take(), ["right0 blur", "right None"]);
+    }
+
+    #[test]
+    fn test_arrows_move_focus_to_the_nearest_element() {
+        let cell =
+            |width: u16| -> VNode { Div::new().width(width).height(1).focusable(true).into() };
+        let row = |left: u16, right: u16| -> VNode {
+            Div::new()
+                .direction(Direction::Horizontal)
+                .child(cell(left))
+                .child(cell(right))
+                .into()
+        };
+        let input: VNode = Div::new()
+            .width(20)
+            .height(1)
+            .focusable(true)
+            .on_key(Key::Left, || {})
+            .into();
+        let root: VNode = Div::new()
+            .child(row(10, 10))
+            .child(row(4, 16))
+            .child(input)
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.layout(40, 10);
+        let focused = || {
+            let node = vdom.get_render_tree().get_focused_node().unwrap();
+            let node = node.borrow();
+            (node.x, node.y)
+        };
+        let press = |code| handle_spatial_focus(&vdom, KeyEvent::new(code, KeyModifiers::NONE));
+
+        // Nothing focused yet, so the first element takes focus
+        assert!(press(KeyCode::Right));
+        assert_eq!(focused(), (0, 0));
+        assert!(press(KeyCode::Right));
+        assert_eq!(focused(), (10, 0));
+
+        // The wide element below overlaps this one, so it wins over the nearer one in tree order
+        assert!(press(KeyCode::Down));
+        assert_eq!(focused(), (4, 1));
+
+        // Both elements above overlap; the one whose center is closer wins
+        assert!(press(KeyCode::Up));
+        assert_eq!(focused(), (10, 0));
+
+        // Nothing further in that direction, so the key isn't consumed
+        assert!(!press(KeyCode::Right));
+        assert!(!handle_spatial_focus(
+            &vdom,
+            KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL)
+        ));
+
+        // An element that handles the arrow keeps it
+        assert!(press(KeyCode::Down));
+        assert!(press(KeyCode::Down));
+        assert_eq!(focused(), (0, 2));
+        assert!(!press(KeyCode::Left));
+        assert!(press(KeyCode::Up));
+    }
+
+    #[test]
+    fn test_unclaimed_scroll_keys_reach_the_capturing_region() {
+        let reader = |claim: fn(Div<VNode>) -> Div<VNode>| -> VNode {
+            let lines = (0..10).map(|i| {
+                Div::new()
+                    .height(1)
+                    .child(VNode::text(i.to_string()))
+                    .into()
+            });
+            let pane = Div::new()
+                .height(3)
+                .overflow(Overflow::Scroll)
+                .scroll_capture(true)
+                .children(lines.collect());
+            let pane = claim(pane);
+            Div::new()
+                .child(
+                    Div::new()
+                        .width(6)
+                        .height(1)
+                        .focusable(true)
+                        .on_click(|| {})
+                        .into(),
+                )
+                .child(pane.into())
+                .into()
+        };
+        let scroll_of = |vdom: &VDom| {
+            let root = vdom.get_render_tree().root.clone().unwrap();
+            let pane = root.borrow().children[1].clone();
+            pane.borrow().scroll_y
+        };
+        let press =
+            |vdom: &VDom, code, modifiers| handle_key_event(vdom, KeyEvent::new(code, modifiers));
+
+        let mut vdom = VDom::new();
+        vdom.render(reader(|pane| pane));
+        vdom.layout(20, 10);
+        vdom.get_render_tree().focus_next();
+
+        // The focused button doesn't handle Down, so the pane scrolls and
+        // the key is consumed
+        assert!(press(&vdom, KeyCode::Down, KeyModifiers::NONE));
+        assert!(press(&vdom, KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(scroll_of(&vdom), 2);
+        assert!(press(&vdom, KeyCode::Home, KeyModifiers::NONE));
+        assert_eq!(scroll_of(&vdom), 0);
+        assert!(!press(&vdom, KeyCode::Down, KeyModifiers::CONTROL));
+        assert_eq!(scroll_of(&vdom), 0);
+
+        // A global handler or key sequence claims the key first
+        for claim in [
+            (|pane: Div<VNode>| pane.on_key_global(Key::Down, || {})) as fn(_) -> _,
+            |pane| pane.on_key_sequence_global([Key::Char('g'), Key::Down], || {}),
+        ] {
+            let mut vdom = VDom::new();
+            vdom.render(reader(claim));
+            vdom.layout(20, 10);
+            assert!(!press(&vdom, KeyCode::Down, KeyModifiers::NONE));
+            assert_eq!(scroll_of(&vdom), 0);
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Joining of touching box-drawing borders into junctions.
+//!
+//! Elements with `join_borders` lay their children over their own border and
+//! over each other's, so neighbouring boxes share border lines. Painting them
+//! leaves plain corners where the lines meet. This pass looks at each
+//! box-drawing cell, adds an arm towards every neighbour whose line points
+//! back at it, and swaps in the glyph with those arms.
+//!
+//! ```text
+//!   painted           joined
+//!   ┌───┌───┐         ┌───┬───┐
+//!   │ a │ b │         │ a │ b │
+//!   ┌───┌───┐    ─▶   ├───┼───┤
+//!   │ c │ d │         │ c │ d │
+//!   └───└───┘         └───┴───┘
+//! ```
+
+use crate::bounds::Rect;
+use crate::buffer::ScreenBuffer;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Line weight of each arm of a glyph, in the order up, right, down, left.
+///
+/// `0` is no arm, `1` light, `2` heavy and `3` double.
+type Arms = [u8; 4];
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Glyphs that can be produced, with their arms.
+const GLYPHS: &[(char, Arms)] = &[
+    ('─', [0, 1, 0, 1]),
+    ('━', [0, 2, 0, 2]),
+    ('│', [1, 0, 1, 0]),
+    ('┃', [2, 0, 2, 0]),
+    ('┌', [0, 1, 1, 0]),
+    ('┍', [0, 2, 1, 0]),
+    ('┎', [0, 1, 2, 0]),
+    ('┏', [0, 2, 2, 0]),
+    ('┐', [0, 0, 1, 1]),
+    ('┑', [0, 0, 1, 2]),
+    ('┒', [0, 0, 2, 1]),
+    ('┓', [0, 0, 2, 2]),
+    ('└', [1, 1, 0, 0]),
+    ('┕', [1, 2, 0, 0]),
+    ('┖', [2, 1, 0, 0]),
+    ('┗', [2, 2, 0, 0]),
+    ('┘', [1, 0, 0, 1]),
+    ('┙', [1, 0, 0, 2]),
+    ('┚', [2, 0, 0, 1]),
+    ('┛', [2, 0, 0, 2]),
+    ('├', [1, 1, 1, 0]),
+    ('┝', [1, 2, 1, 0]),
+    ('┞', [2, 1, 1, 0]),
+    ('┟', [1, 1, 2, 0]),
+    ('┠', [2, 1, 2, 0]),
+    ('┡', [2, 2, 1, 0]),
+    ('┢', [1, 2, 2, 0]),
+    ('┣', [2, 2, 2, 0]),
+    ('┤', [1, 0, 1, 1]),
+    ('┥', [1, 0, 1, 2]),
+    ('┦', [2, 0, 1, 1]),
+    ('┧', [1, 0, 2, 1]),
+    ('┨', [2, 0, 2, 1]),
+    ('┩', [2, 0, 1, 2]),
+    ('┪', [1, 0, 2, 2]),
+    ('┫', [2, 0, 2, 2]),
+    ('┬', [0, 1, 1, 1]),
+    ('┭', [0, 1, 1, 2]),
+    ('┮', [0, 2, 1, 1]),
+    ('┯', [0, 2, 1, 2]),
+    ('┰', [0, 1, 2, 1]),
+    ('┱', [0, 1, 2, 2]),
+    ('┲', [0, 2, 2, 1]),
+    ('┳', [0, 2, 2, 2]),
+    ('┴', [1, 1, 0, 1]),
+    ('┵', [1, 1, 0, 2]),
+    ('┶', [1, 2, 0, 1]),
+    ('┷', [1, 2, 0, 2]),
+    ('┸', [2, 1, 0, 1]),
+    ('┹', [2, 1, 0, 2]),
+    ('┺', [2, 2, 0, 1]),
+    ('┻', [2, 2, 0, 2]),
+    ('┼', [1, 1, 1, 1]),
+    ('┽', [1, 1, 1, 2]),
+    ('┾', [1, 2, 1, 1]),
+    ('┿', [1, 2, 1, 2]),
+    ('╀', [2, 1, 1, 1]),
+    ('╁', [1, 1, 2, 1]),
+    ('╂', [2, 1, 2, 1]),
+    ('╃', [2, 1, 1, 2]),
+    ('╄', [2, 2, 1, 1]),
+    ('╅', [1, 1, 2, 2]),
+    ('╆', [1, 2, 2, 1]),
+    ('╇', [2, 2, 1, 2]),
+    ('╈', [1, 2, 2, 2]),
+    ('╉', [2, 1, 2, 2]),
+    ('╊', [2, 2, 2, 1]),
+    ('╋', [2, 2, 2, 2]),
+    ('═', [0, 3, 0, 3]),
+    ('║', [3, 0, 3, 0]),
+    ('╒', [0, 3, 1, 0]),
+    ('╓', [0, 1, 3, 0]),
+    ('╔', [0, 3, 3, 0]),
+    ('╕', [0, 0, 1, 3]),
+    ('╖', [0, 0, 3, 1]),
+    ('╗', [0, 0, 3, 3]),
+    ('╘', [1, 3, 0, 0]),
+    ('╙', [3, 1, 0, 0]),
+    ('╚', [3, 3, 0, 0]),
+    ('╛', [1, 0, 0, 3]),
+    ('╜', [3, 0, 0, 1]),
+    ('╝', [3, 0, 0, 3]),
+    ('╞', [1, 3, 1, 0]),
+    ('╟', [3, 1, 3, 0]),
+    ('╠', [3, 3, 3, 0]),
+    ('╡', [1, 0, 1, 3]),
+    ('╢', [3, 0, 3, 1]),
+    ('╣', [3, 0, 3, 3]),
+    ('╤', [0, 3, 1, 3]),
+    ('╥', [0, 1, 3, 1]),
+    ('╦', [0, 3, 3, 3]),
+    ('╧', [1, 3, 0, 3]),
+    ('╨', [3, 1, 0, 1]),
+    ('╩', [3, 3, 0, 3]),
+    ('╪', [1, 3, 1, 3]),
+    ('╫', [3, 1, 3, 1]),
+    ('╬', [3, 3, 3, 3]),
+    ('╴', [0, 0, 0, 1]),
+    ('╵', [1, 0, 0, 0]),
+    ('╶', [0, 1, 0, 0]),
+    ('╷', [0, 0, 1, 0]),
+    ('╸', [0, 0, 0, 2]),
+    ('╹', [2, 0, 0, 0]),
+    ('╺', [0, 2, 0, 0]),
+    ('╻', [0, 0, 2, 0]),
+    ('╼', [0, 2, 0, 1]),
+    ('╽', [1, 0, 2, 0]),
+    ('╾', [0, 1, 0, 2]),
+    ('╿', [2, 0, 1, 0]),
+];
+
+/// Rounded and dashed glyphs, read as their plain equivalents but never produced.
+const ALIASES: &[(char, Arms)] = &[
+    ('┄', [0, 1, 0, 1]),
+    ('┅', [0, 2, 0, 2]),
+    ('┆', [1, 0, 1, 0]),
+    ('┇', [2, 0, 2, 0]),
+    ('┈', [0, 1, 0, 1]),
+    ('┉', [0, 2, 0, 2]),
+    ('┊', [1, 0, 1, 0]),
+    ('┋', [2, 0, 2, 0]),
+    ('╌', [0, 1, 0, 1]),
+    ('╍', [0, 2, 0, 2]),
+    ('╎', [1, 0, 1, 0]),
+    ('╏', [2, 0, 2, 0]),
+    ('╭', [0, 1, 1, 0]),
+    ('╮', [0, 0, 1, 1]),
+    ('╯', [1, 0, 0, 1]),
+    ('╰', [1, 1, 0, 0]),
+];
+
+/// Index of the arm opposite each direction.
+const OPPOSITE: [usize; 4] = [2, 3, 0, 1];
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Replaces box-drawing glyphs in `area` with junctions where lines meet.
+///
+/// A glyph gains an arm towards a neighbour in `area` whose line points back
+/// at it, with that neighbour's weight. Glyphs whose arms don't change are
+/// left as painted, so rounded and dashed lines keep their look.
+pub(crate) fn join_borders(buffer: &mut ScreenBuffer, area: &Rect) {
+    let (buffer_width, buffer_height) = buffer.dimensions();
+    let area = area.intersection(&Rect::new(0, 0, buffer_width, buffer_height));
+    if area.is_empty() {
+        return;
+    }
+
+    // Arms are read from a snapshot so joins don't cascade along a line
+    let width = area.width as usize;
+    let snapshot: Vec<Option<Arms>> = (area.y..area.bottom())
+        .flat_map(|y| (area.x..area.right()).map(move |x| (x, y)))
+        .map(|(x, y)| buffer.get_cell(x, y).and_then(|cell| arms_of(cell.char)))
+        .collect();
+    let arms_at = |col: i32, row: i32| -> Option<Arms> {
+        if col < 0 || row < 0 || col >= area.width as i32 || row >= area.height as i32 {
+            return None;
+        }
+        snapshot[row as usize * width + col as usize]
+    };
+
+    for row in 0..area.height as i32 {
+        for col in 0..area.width as i32 {
+            let Some(arms) = arms_at(col, row) else {
+                continue;
+            };
+
+            let neighbours = [
+                (col, row - 1),
+                (col + 1, row),
+                (col, row + 1),
+                (col - 1, row),
+            ];
+            let mut joined = arms;
+            for (direction, &(x, y)) in neighbours.iter().enumerate() {
+                if joined[direction] == 0
+                    && let Some(neighbour) = arms_at(x, y)
+                {
+                    joined[direction] = neighbour[OPPOSITE[direction]];
+                }
+            }
+
+            if joined == arms {
+                continue;
+            }
+
+            if let Some(glyph) = glyph_for(joined)
+                && let Some(cell) = buffer.get_cell_mut(area.x + col as u16, area.y + row as u16)
+            {
+                cell.char = glyph;
+            }
+        }
+    }
+}
+
+/// Arms of a box-drawing glyph, or `None` for any other character.
+fn arms_of(ch: char) -> Option<Arms> {
+    GLYPHS
+        .iter()
+        .chain(ALIASES)
+        .find(|&&(glyph, _)| glyph == ch)
+        .map(|&(_, arms)| arms)
+}
+
+/// Closest glyph with the given arms.
+///
+/// Unicode has no glyph for many weight mixes, such as a heavy arm meeting a
+/// double one. Those fall back to a glyph with matching weights along each
+/// axis, then to light arms in place of heavy ones, then to all light arms.
+fn glyph_for(arms: Arms) -> Option<char> {
+    let exact = |arms: Arms| {
+        GLYPHS
+            .iter()
+            .find(|&&(_, candidate)| candidate == arms)
+            .map(|&(glyph, _)| glyph)
+    };
+
+    let mut axes = arms;
+    for (a, b) in [(0, 2), (1, 3)] {
+        if axes[a] != 0 && axes[b] != 0 {
+            let weight = axes[a].max(axes[b]);
+            axes[a] = weight;
+            axes[b] = weight;
+        }
+    }
+    let no_heavy = axes.map(|weight| if weight == 2 { 1 } else { weight });
+    let light = arms.map(|weight| weight.min(1));
+
+    exact(arms)
+        .or_else(|| exact(axes))
+        .or_else(|| exact(no_heavy))
+        .or_else(|| exact(light))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer_from(lines: &[&str]) -> ScreenBuffer {
+        let width = lines[0].chars().count() as u16;
+        let mut buffer = ScreenBuffer::new(width, lines.len() as u16);
+        for (y, line) in lines.iter().enumerate() {
+            buffer.write_str(0, y as u16, line, None, None);
+        }
+        buffer
+    }
+
+    fn lines_of(buffer: &ScreenBuffer) -> Vec<String> {
+        let (width, height) = buffer.dimensions();
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer.get_cell(x, y).unwrap().char)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_grid_corners_become_junctions() {
+        let mut buffer = buffer_from(&[
+            "┌───┌───┐",
+            "│ a │ b │",
+            "┌───┌───┐",
+            "│ c │ d │",
+            "└───└───┘",
+        ]);
+        join_borders(&mut buffer, &Rect::new(0, 0, 9, 5));
+
+        assert_eq!(
+            lines_of(&buffer),
+            [
+                "┌───┬───┐",
+                "│ a │ b │",
+                "├───┼───┤",
+                "│ c │ d │",
+                "└───┴───┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weights_and_styles() {
+        // Double lines meeting single ones use the mixed glyphs, while rounded
+        // corners with nothing to join keep their shape
+        let mut buffer = buffer_from(&[
+            "╔═══╔═══╗  ╭─╮",
+            "║   ║   ║  ╰─╯",
+            "╚═══╚═══╝     ",
+            "┌──┏━━┓       ",
+        ]);
+        join_borders(&mut buffer, &Rect::new(0, 0, 14, 4));
+
+        let lines = lines_of(&buffer);
+        assert_eq!(lines[0], "╔═══╦═══╗  ╭─╮");
+        assert_eq!(lines[1], "║   ║   ║  ╰─╯");
+        assert_eq!(lines[2], "╚═══╩═══╝     ");
+        assert_eq!(lines[3], "┌──┲━━┓       ");
+    }
+
+    #[test]
+    fn test_join_is_limited_to_area() {
+        let mut buffer = buffer_from(&["──│──"]);
+        join_borders(&mut buffer, &Rect::new(2, 0, 3, 1));
+
+        assert_eq!(lines_of(&buffer), ["──├──"]);
+    }
+}
@@ -1,14 +1,22 @@
+pub mod bindings;
 pub mod config;
 pub mod context;
 pub mod core;
 pub mod events;
 pub(crate) mod inline;
+pub(crate) mod junctions;
 pub mod renderer;
+pub mod stats;
 
 //--------------------------------------------------------------------------------------------------
 // Exports
 //--------------------------------------------------------------------------------------------------
 
-pub use config::{InlineConfig, InlineHeight, TerminalMode};
-pub use context::Context;
-pub use core::App;
+pub use bindings::{Binding, BindingScope};
+pub use config::{
+    Breakpoint, Breakpoints, InlineConfig, InlineHeight, NonTtyFallback, TerminalMode,
+    ViewportConfig,
+};
+pub use context::{Context, FocusDirection, Local, RedrawHandle};
+pub use core::{App, RunningApp};
+pub use stats::FrameStats;
@@ -1,9 +1,56 @@
 use crate::bounds::Rect;
-use crate::buffer::{Cell, ScreenBuffer};
+use crate::buffer::{Cell, Monochrome, ScreenBuffer};
+use crate::node::TextSpan;
 use crate::render_tree::RenderNode;
 use crate::render_tree::RenderNodeType;
-use crate::style::{Color, Overflow};
-use crate::utils::{display_width, substring_by_columns};
+use crate::style::{Color, Overflow, TextAlign};
+use crate::theme::Theme;
+use crate::utils::{display_width, justify_line, justify_segments, substring_by_columns};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A stacking context child whose painting is deferred to its enclosing context,
+/// or a portaled one deferred to the overlay pass.
+///
+/// Captures the clip, inherited background, and scroll offset in effect where
+/// the node sits in the tree, so it renders the same as it would in place.
+struct StackingLayer {
+    node: Rc<RefCell<RenderNode>>,
+    clip_rect: Rect,
+    inherited_bg: InheritedBg,
+    scroll_offset: i16,
+}
+
+/// Background a node inherits from where it sits in the tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InheritedBg {
+    /// The nearest ancestor background
+    Color(Color),
+
+    /// No ancestor has a background, so the terminal's default shows
+    Terminal,
+
+    /// An ancestor opted out with `inherit_bg: false`, so cells without a
+    /// background of their own keep whatever is already beneath them
+    SeeThrough,
+}
+
+/// State carried through one paint of a node tree.
+struct RenderPass<'a> {
+    /// Colors for decorations such as scrollbars
+    theme: &'a Theme,
+
+    /// Portaled subtrees found so far, painted once the main tree is done.
+    /// Their clip is ignored; only the viewport clips them.
+    portals: Vec<StackingLayer>,
+
+    /// Whether the frame is drawn without colors, see [`render_frame`]
+    monochrome: bool,
+}
 
 //--------------------------------------------------------------------------------------------------
 // Functions
@@ -21,7 +68,7 @@ use crate::utils::{display_width, substring_by_columns};
 ///
 /// 2. **children_clip**: Used for clipping child elements
 ///    - When overflow:hidden, clips to padding box (CSS behavior)
-///    - When overflow:none, uses parent's clip_rect unchanged
+///    - When overflow:none or overflow:visible, uses parent's clip_rect unchanged
 ///
 /// ```text
 /// CSS Box Model & Clipping:
@@ -39,6 +86,7 @@ use crate::utils::{display_width, substring_by_columns};
 ///
 /// overflow:hidden clips at padding edge (includes padding, excludes border)
 /// overflow:none allows children to render outside all bounds
+/// overflow:visible also renders children when the node itself is clipped away
 /// ```
 pub fn render_node_to_buffer(
     node: &RenderNode,
@@ -46,16 +94,154 @@ pub fn render_node_to_buffer(
     clip_rect: &Rect,
     parent_bg: Option<Color>,
 ) {
-    render_node_with_offset(node, buffer, clip_rect, parent_bg, 0);
+    render_node_to_buffer_with_theme(node, buffer, clip_rect, parent_bg, &Theme::default());
+}
+
+/// Renders a node tree like [`render_node_to_buffer`], drawing decorations
+/// such as scrollbars in the given theme's colors.
+pub fn render_node_to_buffer_with_theme(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_bg: Option<Color>,
+    theme: &Theme,
+) {
+    render_tree(node, buffer, clip_rect, parent_bg, theme, false);
+}
+
+/// Renders a frame of the app, in two tones when `monochrome` is set.
+///
+/// Colors are stripped once everything is painted, see
+/// [`ScreenBuffer::resolve_colors`], and focused borders are drawn bold.
+pub(crate) fn render_frame(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    theme: &Theme,
+    monochrome: bool,
+) {
+    render_tree(node, buffer, clip_rect, None, theme, monochrome);
+}
+
+/// Paints a node tree, then its stacking layers and portals on top.
+fn render_tree(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_bg: Option<Color>,
+    theme: &Theme,
+    monochrome: bool,
+) {
+    let mut pass = RenderPass {
+        theme,
+        portals: Vec::new(),
+        monochrome,
+    };
+
+    // The root always acts as a stacking context
+    let mut layers = Vec::new();
+    render_node_with_offset(
+        node,
+        buffer,
+        clip_rect,
+        parent_bg.map_or(InheritedBg::Terminal, InheritedBg::Color),
+        0,
+        &mut layers,
+        &mut pass,
+    );
+    render_stacking_layers(layers, buffer, &mut pass);
+    render_portals(buffer, clip_rect, &mut pass);
+
+    // The root's background stays the screen's, even under a modal covering most of it
+    let monochrome = monochrome.then(|| {
+        let palette = &theme.palette;
+        Monochrome {
+            screen_bg: node
+                .style
+                .as_ref()
+                .and_then(|style| style.background)
+                .filter(|&bg| bg != Color::Default),
+            plain_fg: vec![palette.foreground, palette.muted, palette.border],
+        }
+    });
+    buffer.resolve_colors(monochrome.as_ref());
+}
+
+/// Paints deferred stacking context children in z-index order.
+///
+/// ```text
+/// Root context
+/// ├── A (flow)                  Paint order:
+/// │   └── C (absolute, z: 10)     1. Root, A, B's flow content
+/// └── B (absolute, z: 5)          2. B (z: 5) with its subtree
+///                                 3. C (z: 10) with its subtree
+/// ```
+///
+/// C escapes A's flat sibling order and layers against B because both belong
+/// to the root context. The sort is stable, so equal z-indices keep tree order.
+fn render_stacking_layers(
+    mut layers: Vec<StackingLayer>,
+    buffer: &mut ScreenBuffer,
+    pass: &mut RenderPass,
+) {
+    layers.sort_by(|a, b| a.node.borrow().z_index.total_cmp(&b.node.borrow().z_index));
+
+    for layer in layers {
+        // Each layer is a context root, so it collects its own descendants
+        let mut unused = Vec::new();
+        render_node_with_offset(
+            &layer.node.borrow(),
+            buffer,
+            &layer.clip_rect,
+            layer.inherited_bg,
+            layer.scroll_offset,
+            &mut unused,
+            pass,
+        );
+    }
+}
+
+/// Paints portaled subtrees over everything else, lowest layer first.
+///
+/// Each portal is a stacking context root clipped only by `clip_rect`, and
+/// keeps the background and scroll offset of the place it was declared.
+/// Equal layers keep tree order. Portals declared inside a portal are
+/// painted after all the portals of the round that found them.
+fn render_portals(buffer: &mut ScreenBuffer, clip_rect: &Rect, pass: &mut RenderPass) {
+    while !pass.portals.is_empty() {
+        let mut portals = std::mem::take(&mut pass.portals);
+        portals.sort_by_key(|portal| portal.node.borrow().portal_layer());
+
+        for portal in portals {
+            let mut layers = Vec::new();
+            render_node_with_offset(
+                &portal.node.borrow(),
+                buffer,
+                clip_rect,
+                portal.inherited_bg,
+                portal.scroll_offset,
+                &mut layers,
+                pass,
+            );
+            render_stacking_layers(layers, buffer, pass);
+        }
+    }
 }
 
 /// Internal function that handles rendering with accumulated scroll offset
+///
+/// Children that establish a stacking context with a non-negative z-index are
+/// pushed onto `layers` (the enclosing context's list) instead of being painted
+/// in place. Negative z-index children paint in place, beneath their siblings.
+/// Portaled children are set aside in `pass` for the overlay pass.
 fn render_node_with_offset(
     node: &RenderNode,
     buffer: &mut ScreenBuffer,
     clip_rect: &Rect,
-    parent_bg: Option<Color>,
+    inherited_bg: InheritedBg,
     parent_scroll_offset: i16,
+    layers: &mut Vec<StackingLayer>,
+    pass: &mut RenderPass,
 ) {
     // Calculate the rendered position with parent scroll offset applied
     // Using i32 to allow negative positions for proper clipping
@@ -96,8 +282,15 @@ fn render_node_with_offset(
     // Calculate rendered_y for actual rendering (clamped to 0 for partially visible elements)
     let rendered_y = rendered_y_i32.max(0) as u16;
 
+    // overflow:visible children may show even where this node doesn't, so only
+    // the clip decides what gets drawn, never this node's own bounds
+    let overflow_visible = node
+        .style
+        .as_ref()
+        .is_some_and(|s| s.overflow == Some(Overflow::Visible));
+
     // Check if node is visible within current clip rect
-    if !node_bounds.intersects(clip_rect) {
+    if !overflow_visible && !node_bounds.intersects(clip_rect) {
         return; // Skip rendering if completely outside clip area
     }
 
@@ -165,6 +358,17 @@ fn render_node_with_offset(
 
     match &node.node_type {
         RenderNodeType::Element => {
+            // An element that opts out of inheritance sees no parent background
+            let inherited_bg = if node.style.as_ref().and_then(|s| s.inherit_bg) == Some(false) {
+                InheritedBg::SeeThrough
+            } else {
+                inherited_bg
+            };
+            let parent_bg = match inherited_bg {
+                InheritedBg::Color(color) => Some(color),
+                InheritedBg::Terminal | InheritedBg::SeeThrough => None,
+            };
+
             // Determine the effective background for the node's text children
             // Start with parent's background to ensure proper inheritance chain
             let mut effective_bg = parent_bg;
@@ -190,35 +394,55 @@ fn render_node_with_offset(
 
             // Draw border if enabled
             if let Some(style) = &node.style {
+                // Border cells without a background take the inherited one, or
+                // keep what's beneath inside an element that doesn't inherit
+                let border_bg = |buffer: &ScreenBuffer, x: u16, y: u16| {
+                    style.background.or(parent_bg).or_else(|| {
+                        buffer
+                            .get_cell(x, y)
+                            .and_then(|cell| cell.bg)
+                            .filter(|_| inherited_bg == InheritedBg::SeeThrough)
+                    })
+                };
+
+                // Remember what's beneath transparent rounded corners before painting
+                let corners_beneath = if style.clip_corners == Some(true)
+                    && style
+                        .border
+                        .as_ref()
+                        .is_some_and(|b| b.enabled && b.style == crate::style::BorderStyle::Rounded)
+                {
+                    let bounds = node_bounds.intersection(&element_clip);
+                    let area = (rendered_x as i32, rendered_y_i32, node.width, node.height);
+                    corner_backgrounds(buffer, &bounds, area)
+                } else {
+                    Vec::new()
+                };
+
                 if let Some(border) = &style.border
                     && border.enabled
                     && node.width > 1
                     && node.height > 1
                 {
                     // Get border characters based on style
-                    let (top_left, top, top_right, left, right, bottom_left, bottom, bottom_right) =
-                        match border.style {
-                            crate::style::BorderStyle::Single => {
-                                ('┌', '─', '┐', '│', '│', '└', '─', '┘')
-                            }
-                            crate::style::BorderStyle::Double => {
-                                ('╔', '═', '╗', '║', '║', '╚', '═', '╝')
-                            }
-                            crate::style::BorderStyle::Thick => {
-                                ('┏', '━', '┓', '┃', '┃', '┗', '━', '┛')
-                            }
-                            crate::style::BorderStyle::Rounded => {
-                                ('╭', '─', '╮', '│', '│', '╰', '─', '╯')
-                            }
-                            crate::style::BorderStyle::Dashed => {
-                                ('┌', '╌', '┐', '╎', '╎', '└', '╌', '┘')
-                            }
-                        };
+                    let [
+                        top_left,
+                        top,
+                        top_right,
+                        left,
+                        right,
+                        bottom_left,
+                        bottom,
+                        bottom_right,
+                    ] = border.style.chars();
 
                     // Draw border within the clipped area
                     let border_bounds = node_bounds.intersection(&element_clip);
                     use crate::style::BorderEdges;
 
+                    // Without colors, a focused border is told apart by weight
+                    let bold = pass.monochrome && node.focused;
+
                     // Top border
                     if border.edges.contains(BorderEdges::TOP)
                         && border_bounds.y == rendered_y
@@ -243,9 +467,10 @@ fn render_node_with_offset(
                             let mut cell = Cell::new(ch);
                             if ch != ' ' {
                                 cell.fg = Some(border.color);
+                                cell.style.bold = bold;
                             }
                             // Always set background for border cells (including empty corners)
-                            cell.bg = style.background.or(parent_bg);
+                            cell.bg = border_bg(buffer, x, rendered_y);
                             buffer.set_cell(x, rendered_y, cell);
                         }
                     }
@@ -275,9 +500,10 @@ fn render_node_with_offset(
                             let mut cell = Cell::new(ch);
                             if ch != ' ' {
                                 cell.fg = Some(border.color);
+                                cell.style.bold = bold;
                             }
                             // Always set background for border cells (including empty corners)
-                            cell.bg = style.background.or(parent_bg);
+                            cell.bg = border_bg(buffer, x, bottom_y);
                             buffer.set_cell(x, bottom_y, cell);
                         }
                     }
@@ -293,8 +519,9 @@ fn render_node_with_offset(
                         {
                             let mut cell = Cell::new(left);
                             cell.fg = Some(border.color);
+                            cell.style.bold = bold;
                             // Use element's background if it has one, otherwise inherit from parent
-                            cell.bg = style.background.or(parent_bg);
+                            cell.bg = border_bg(buffer, rendered_x, y);
                             buffer.set_cell(rendered_x, y, cell);
                         }
 
@@ -306,8 +533,9 @@ fn render_node_with_offset(
                         {
                             let mut cell = Cell::new(right);
                             cell.fg = Some(border.color);
+                            cell.style.bold = bold;
                             // Use element's background if it has one, otherwise inherit from parent
-                            cell.bg = style.background.or(parent_bg);
+                            cell.bg = border_bg(buffer, right_x, y);
                             buffer.set_cell(right_x, y, cell);
                         }
                     }
@@ -323,8 +551,9 @@ fn render_node_with_offset(
                     {
                         let mut cell = Cell::new(top_left);
                         cell.fg = Some(border.color);
+                        cell.style.bold = bold;
                         // Use element's background if it has one, otherwise inherit from parent
-                        cell.bg = style.background.or(parent_bg);
+                        cell.bg = border_bg(buffer, rendered_x, rendered_y);
                         buffer.set_cell(rendered_x, rendered_y, cell);
                     }
                     let right_x = rendered_x + node.width - 1;
@@ -338,8 +567,9 @@ fn render_node_with_offset(
                     {
                         let mut cell = Cell::new(top_right);
                         cell.fg = Some(border.color);
+                        cell.style.bold = bold;
                         // Use element's background if it has one, otherwise inherit from parent
-                        cell.bg = style.background.or(parent_bg);
+                        cell.bg = border_bg(buffer, right_x, rendered_y);
                         buffer.set_cell(right_x, rendered_y, cell);
                     }
                     let bottom_y = rendered_y + node.height - 1;
@@ -353,8 +583,9 @@ fn render_node_with_offset(
                     {
                         let mut cell = Cell::new(bottom_left);
                         cell.fg = Some(border.color);
+                        cell.style.bold = bold;
                         // Use element's background if it has one, otherwise inherit from parent
-                        cell.bg = style.background.or(parent_bg);
+                        cell.bg = border_bg(buffer, rendered_x, bottom_y);
                         buffer.set_cell(rendered_x, bottom_y, cell);
                     }
                     let right_x = rendered_x + node.width - 1;
@@ -369,8 +600,9 @@ fn render_node_with_offset(
                     {
                         let mut cell = Cell::new(bottom_right);
                         cell.fg = Some(border.color);
+                        cell.style.bold = bold;
                         // Use element's background if it has one, otherwise inherit from parent
-                        cell.bg = style.background.or(parent_bg);
+                        cell.bg = border_bg(buffer, right_x, bottom_y);
                         buffer.set_cell(right_x, bottom_y, cell);
                     }
                 }
@@ -406,6 +638,45 @@ fn render_node_with_offset(
                         }
                     }
                 }
+
+                // Draw the fill character over the content area, keeping the
+                // background already painted there
+                if let Some(ch) = style.fill_char {
+                    let padding = node.padding();
+                    let inset = u16::from(style.border.as_ref().is_some_and(|b| b.enabled));
+                    let top = rendered_y_i32 + (inset + padding.top) as i32;
+                    let bottom =
+                        rendered_y_i32 + node.height as i32 - (inset + padding.bottom) as i32;
+                    let left = rendered_x + inset + padding.left;
+                    let right = (rendered_x + node.width).saturating_sub(inset + padding.right);
+
+                    if bottom > top.max(0) && right > left {
+                        let content = Rect::new(
+                            left,
+                            top.max(0) as u16,
+                            right - left,
+                            (bottom - top.max(0)) as u16,
+                        );
+                        let fill_bounds = content.intersection(&element_clip);
+                        for y in fill_bounds.y..fill_bounds.bottom() {
+                            for x in fill_bounds.x..fill_bounds.right() {
+                                let bg = buffer.get_cell(x, y).and_then(|cell| cell.bg);
+                                let mut cell = Cell::new(ch);
+                                cell.fg = style.fill_color;
+                                cell.bg = bg;
+                                buffer.set_cell(x, y, cell);
+                            }
+                        }
+                    }
+                }
+
+                for (x, y, bg) in corners_beneath {
+                    if let Some(cell) = buffer.get_cell_mut(x, y)
+                        && matches!(cell.char, '╭' | '╮' | '╰' | '╯')
+                    {
+                        cell.bg = bg;
+                    }
+                }
             }
 
             // Calculate content area to check if we should render children
@@ -420,11 +691,7 @@ fn render_node_with_offset(
             //
             // In this case, content_width = 0 and content_height = 0,
             // so we skip rendering children entirely.
-            let padding = node
-                .style
-                .as_ref()
-                .and_then(|s| s.padding)
-                .unwrap_or(crate::style::Spacing::all(0));
+            let padding = node.padding();
             let border_offset = if node
                 .style
                 .as_ref()
@@ -443,11 +710,10 @@ fn render_node_with_offset(
                 .height
                 .saturating_sub(padding.top + padding.bottom + (border_offset * 2));
 
-            // Only render children if there's content area available
-            if content_width > 0 && content_height > 0 {
+            // Only render children if there's content area available (or they overflow it)
+            if overflow_visible || (content_width > 0 && content_height > 0) {
                 // Sort children by z-index for proper layering
-                let mut sorted_children: Vec<_> = node.children.iter().collect();
-                sorted_children.sort_by_key(|child| child.borrow().z_index);
+                let sorted_children = node.children_in_paint_order();
 
                 // Render children in z-index order with the children clip rect and background
                 // Calculate total scroll offset to pass to children
@@ -457,16 +723,61 @@ fn render_node_with_offset(
                     parent_scroll_offset
                 };
 
+                // A stacking context collects its own layers; other nodes
+                // contribute to the enclosing context's layers
+                let establishes_context = node.establishes_stacking_context();
+                let mut own_layers = Vec::new();
+                let context_layers = if establishes_context {
+                    &mut own_layers
+                } else {
+                    &mut *layers
+                };
+
+                // Children take this element's background, or what it inherited
+                let children_bg = effective_bg.map_or(inherited_bg, InheritedBg::Color);
+
                 for child in sorted_children {
+                    let child_ref = child.borrow();
+                    if child_ref.portal_layer().is_some() {
+                        pass.portals.push(StackingLayer {
+                            node: Rc::clone(child),
+                            clip_rect: children_clip,
+                            inherited_bg: children_bg,
+                            scroll_offset: child_scroll_offset,
+                        });
+                        continue;
+                    }
+                    if child_ref.establishes_stacking_context() && child_ref.z_index >= 0.0 {
+                        context_layers.push(StackingLayer {
+                            node: Rc::clone(child),
+                            clip_rect: children_clip,
+                            inherited_bg: children_bg,
+                            scroll_offset: child_scroll_offset,
+                        });
+                        continue;
+                    }
+
                     render_node_with_offset(
-                        &child.borrow(),
+                        &child_ref,
                         buffer,
                         &children_clip,
-                        effective_bg,
+                        children_bg,
                         child_scroll_offset,
+                        context_layers,
+                        pass,
                     );
                 }
 
+                if establishes_context {
+                    render_stacking_layers(own_layers, buffer, pass);
+                }
+
+                // Children overlap this element's border and each other's, so
+                // turn the corners where their lines meet into junctions
+                if node.style.as_ref().and_then(|s| s.join_borders) == Some(true) {
+                    crate::app::junctions::join_borders(buffer, &element_clip);
+                }
+
                 // Render scrollbars if needed (for Scroll and Auto modes)
                 // Only show scrollbar if explicitly enabled via style
                 if node.scrollable
@@ -476,7 +787,13 @@ fn render_node_with_offset(
                         .and_then(|s| s.show_scrollbar)
                         .unwrap_or(true)
                 {
-                    render_scrollbars(node, buffer, &element_clip, parent_scroll_offset);
+                    render_scrollbars(
+                        node,
+                        buffer,
+                        &element_clip,
+                        parent_scroll_offset,
+                        pass.theme,
+                    );
                 }
             }
         }
@@ -501,7 +818,8 @@ fn render_node_with_offset(
                 && available_width > text_width
             {
                 match align {
-                    crate::style::TextAlign::Left => 0,
+                    // A single line is also the last line, which is never stretched
+                    crate::style::TextAlign::Left | crate::style::TextAlign::Justify => 0,
                     crate::style::TextAlign::Center => {
                         // Center text within the available width
                         available_width.saturating_sub(text_width) / 2
@@ -515,6 +833,15 @@ fn render_node_with_offset(
                 0 // Default to left alignment or no space for alignment
             };
 
+            fill_text_background(
+                node,
+                buffer,
+                rendered_x,
+                rendered_y,
+                available_width,
+                clip_rect,
+            );
+
             // Apply alignment offset to the rendered position
             let aligned_x = rendered_x + align_offset;
             let text_bounds = crate::bounds::Rect::new(aligned_x, rendered_y, text_width, 1);
@@ -541,20 +868,16 @@ fn render_node_with_offset(
 
                     // Use the full text style if available, otherwise fall back to individual color fields
                     if let Some(text_style) = &node.text_style {
-                        // Create a merged text style with background inheritance
-                        let mut merged_style = text_style.clone();
-                        if merged_style.background.is_none() {
-                            merged_style.background = parent_bg;
-                        }
+                        // Without its own background the text shows what's beneath
                         buffer.write_styled_str(
                             render_x,
                             rendered_y,
                             visible_text,
-                            Some(&merged_style),
+                            Some(text_style),
                         );
                     } else {
                         // Fallback to old method if no full text style
-                        let text_bg = node.style.as_ref().and_then(|s| s.background).or(parent_bg);
+                        let text_bg = node.style.as_ref().and_then(|s| s.background);
                         buffer.write_str(
                             render_x,
                             rendered_y,
@@ -586,6 +909,15 @@ fn render_node_with_offset(
                 }
 
                 if line_y >= clip_rect.y {
+                    // Justified lines fill the node's width, except the last
+                    let justified;
+                    let line = if is_justified(node) && line_idx + 1 < lines.len() {
+                        justified = justify_line(line, node.width);
+                        &justified
+                    } else {
+                        line
+                    };
+
                     let line_width = display_width(line) as u16;
 
                     // Calculate alignment offset for this line
@@ -593,7 +925,7 @@ fn render_node_with_offset(
                         && let Some(align) = text_style.align
                     {
                         match align {
-                            crate::style::TextAlign::Left => 0,
+                            crate::style::TextAlign::Left | crate::style::TextAlign::Justify => 0,
                             crate::style::TextAlign::Center => {
                                 // Center each line independently within the node's width
                                 node.width.saturating_sub(line_width) / 2
@@ -607,6 +939,8 @@ fn render_node_with_offset(
                         0 // Default to left alignment
                     };
 
+                    fill_text_background(node, buffer, rendered_x, line_y, node.width, clip_rect);
+
                     // Apply alignment offset to the rendered position
                     let aligned_x = rendered_x + align_offset;
                     let text_bounds = crate::bounds::Rect::new(aligned_x, line_y, line_width, 1);
@@ -633,21 +967,16 @@ fn render_node_with_offset(
 
                             // Use the full text style if available
                             if let Some(text_style) = &node.text_style {
-                                // Create a merged text style with background inheritance
-                                let mut merged_style = text_style.clone();
-                                if merged_style.background.is_none() {
-                                    merged_style.background = parent_bg;
-                                }
+                                // Without its own background the text shows what's beneath
                                 buffer.write_styled_str(
                                     render_x,
                                     line_y,
                                     visible_text,
-                                    Some(&merged_style),
+                                    Some(text_style),
                                 );
                             } else {
                                 // Fallback to old method if no full text style
-                                let text_bg =
-                                    node.style.as_ref().and_then(|s| s.background).or(parent_bg);
+                                let text_bg = node.style.as_ref().and_then(|s| s.background);
                                 buffer.write_str(
                                     render_x,
                                     line_y,
@@ -674,7 +1003,8 @@ fn render_node_with_offset(
                 && let Some(align) = text_style.align
             {
                 match align {
-                    crate::style::TextAlign::Left => 0,
+                    // A single line is also the last line, which is never stretched
+                    crate::style::TextAlign::Left | crate::style::TextAlign::Justify => 0,
                     crate::style::TextAlign::Center => {
                         // Center the entire rich text line within the node's width
                         node.width.saturating_sub(total_width) / 2
@@ -722,28 +1052,13 @@ fn render_node_with_offset(
                             );
                             let render_x = current_x.max(clip_rect.x);
 
-                            // Apply span's style, falling back to parent background
-                            if let Some(span_style) = &span.style {
-                                let mut merged_style = span_style.clone();
-                                if merged_style.background.is_none() {
-                                    merged_style.background = parent_bg;
-                                }
-                                buffer.write_styled_str(
-                                    render_x,
-                                    rendered_y,
-                                    visible_text,
-                                    Some(&merged_style),
-                                );
-                            } else {
-                                // No style on this span - use default with parent background
-                                buffer.write_str(
-                                    render_x,
-                                    rendered_y,
-                                    visible_text,
-                                    None,
-                                    parent_bg,
-                                );
-                            }
+                            // Spans without a background of their own show what's beneath
+                            buffer.write_styled_str(
+                                render_x,
+                                rendered_y,
+                                visible_text,
+                                span.style.as_ref(),
+                            );
                         }
                     }
 
@@ -771,6 +1086,15 @@ fn render_node_with_offset(
                 }
 
                 if line_y >= clip_rect.y {
+                    // Justified lines fill the node's width, except the last
+                    let justified;
+                    let line_spans = if is_justified(node) && line_idx + 1 < lines.len() {
+                        justified = justify_spans(line_spans, node.width);
+                        &justified
+                    } else {
+                        line_spans
+                    };
+
                     // Calculate total line width
                     let line_width: u16 = line_spans
                         .iter()
@@ -782,7 +1106,7 @@ fn render_node_with_offset(
                         && let Some(align) = text_style.align
                     {
                         match align {
-                            crate::style::TextAlign::Left => 0,
+                            crate::style::TextAlign::Left | crate::style::TextAlign::Justify => 0,
                             crate::style::TextAlign::Center => {
                                 // Center each line independently within the node's width
                                 node.width.saturating_sub(line_width) / 2
@@ -832,28 +1156,13 @@ fn render_node_with_offset(
                                     );
                                     let render_x = current_x.max(clip_rect.x);
 
-                                    // Apply span's style, falling back to parent background
-                                    if let Some(span_style) = &span.style {
-                                        let mut merged_style = span_style.clone();
-                                        if merged_style.background.is_none() {
-                                            merged_style.background = parent_bg;
-                                        }
-                                        buffer.write_styled_str(
-                                            render_x,
-                                            line_y,
-                                            visible_text,
-                                            Some(&merged_style),
-                                        );
-                                    } else {
-                                        // No style on this span - use default with parent background
-                                        buffer.write_str(
-                                            render_x,
-                                            line_y,
-                                            visible_text,
-                                            None,
-                                            parent_bg,
-                                        );
-                                    }
+                                    // Spans without a background of their own show what's beneath
+                                    buffer.write_styled_str(
+                                        render_x,
+                                        line_y,
+                                        visible_text,
+                                        span.style.as_ref(),
+                                    );
                                 }
                             }
 
@@ -866,6 +1175,129 @@ fn render_node_with_offset(
     }
 }
 
+/// The corner cells of a box that lie within `bounds`, with the background
+/// currently drawn at each.
+///
+/// `area` is the box's rendered `(x, y, width, height)`; `y` is negative when
+/// the box is scrolled partly above the viewport.
+fn corner_backgrounds(
+    buffer: &ScreenBuffer,
+    bounds: &Rect,
+    (x, y, width, height): (i32, i32, u16, u16),
+) -> Vec<(u16, u16, Option<Color>)> {
+    let right = x + width.saturating_sub(1) as i32;
+    let bottom = y + height.saturating_sub(1) as i32;
+
+    [(x, y), (right, y), (x, bottom), (right, bottom)]
+        .into_iter()
+        .filter_map(|(x, y)| Some((u16::try_from(x).ok()?, u16::try_from(y).ok()?)))
+        .filter(|&(x, y)| bounds.contains_point(x, y))
+        .map(|(x, y)| (x, y, buffer.get_cell(x, y).and_then(|cell| cell.bg)))
+        .collect()
+}
+
+/// Paints a text row's own background across `width` columns when the
+/// node's style sets `bg_fill`, so aligned lines form solid bars.
+fn fill_text_background(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    x: u16,
+    y: u16,
+    width: u16,
+    clip_rect: &Rect,
+) {
+    let Some(text_style) = &node.text_style else {
+        return;
+    };
+    let Some(bg) = text_style
+        .background
+        .filter(|_| text_style.bg_fill == Some(true))
+    else {
+        return;
+    };
+
+    let row = Rect::new(x, y, width, 1).intersection(clip_rect);
+    if !row.is_empty() {
+        buffer.fill_rect(row.x, row.y, row.width, 1, Cell::new(' ').with_bg(bg));
+    }
+}
+
+/// Whether a text node uses [`TextAlign::Justify`].
+fn is_justified(node: &RenderNode) -> bool {
+    node.text_style.as_ref().and_then(|ts| ts.align) == Some(TextAlign::Justify)
+}
+
+/// Justifies one line of styled spans to `width` columns.
+fn justify_spans(spans: &[TextSpan], width: u16) -> Vec<TextSpan> {
+    let contents: Vec<&str> = spans.iter().map(|span| span.content.as_str()).collect();
+    spans
+        .iter()
+        .zip(justify_segments(&contents, width))
+        .map(|(span, content)| TextSpan {
+            content,
+            ..span.clone()
+        })
+        .collect()
+}
+
+/// Renders scrollbar indicators for a scrollable node.
+///
+/// Shows vertical scrollbar when content exceeds viewport.
+fn render_scrollbars(
+    node: &RenderNode,
+    buffer: &mut ScreenBuffer,
+    clip_rect: &Rect,
+    parent_scroll_offset: i16,
+    theme: &Theme,
+) {
+    // Determine if scrollbar is needed
+    let needs_scrollbar = node.content_height > node.height;
+
+    // Only show scrollbar for Auto mode if content overflows
+    if let Some(style) = &node.style
+        && let Some(Overflow::Auto) = style.overflow
+        && !needs_scrollbar
+    {
+        return;
+    }
+
+    // Calculate rendered position with parent scroll offset
+    let rendered_y = if parent_scroll_offset > 0 {
+        node.y.saturating_sub(parent_scroll_offset as u16)
+    } else {
+        node.y
+    };
+    let rendered_x = node.x;
+
+    // Vertical scrollbar
+    if needs_scrollbar && node.height > 2 {
+        let scrollbar_x = rendered_x + node.width.saturating_sub(1);
+        let scrollbar_height = node.height;
+
+        // Calculate thumb position and size
+        let content_ratio = node.height as f32 / node.content_height as f32;
+        let thumb_height = ((scrollbar_height as f32 * content_ratio).ceil() as u16).max(1);
+        let scroll_ratio =
+            node.scroll_y as f32 / node.content_height.saturating_sub(node.height) as f32;
+        let thumb_y = rendered_y
+            + ((scrollbar_height.saturating_sub(thumb_height) as f32 * scroll_ratio) as u16);
+
+        // Draw scrollbar track
+        for y in rendered_y..rendered_y + scrollbar_height {
+            if clip_rect.contains_point(scrollbar_x, y) {
+                let ch = if y >= thumb_y && y < thumb_y + thumb_height {
+                    '█' // Thumb
+                } else {
+                    '│' // Track
+                };
+                let mut cell = Cell::new(ch);
+                cell.fg = Some(theme.palette.muted);
+                buffer.set_cell(scrollbar_x, y, cell);
+            }
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -968,6 +1400,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_text_composites_over_background_beneath() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // A red stripe covers the left half of a blue root; text laid over
+        // both keeps each cell's background instead of the root's color
+        let stripe: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(3)
+            .height(1)
+            .background(Color::Red)
+            .into();
+        let label: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .z_index(1)
+            .child(Text::new("abcdef").into())
+            .into();
+        let node: VNode = Div::new()
+            .background(Color::Blue)
+            .width(6)
+            .height(1)
+            .children(vec![stripe, label])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(6, 1);
+
+        let mut buffer = ScreenBuffer::new(6, 1);
+        let clip_rect = crate::Rect::new(0, 0, 6, 1);
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        for x in 0..6 {
+            let cell = buffer.get_cell(x, 0).unwrap();
+            let beneath = if x < 3 { Color::Red } else { Color::Blue };
+            assert_eq!(
+                (cell.char, cell.bg),
+                ("abcdef".chars().nth(x as usize).unwrap(), Some(beneath))
+            );
+        }
+    }
+
     #[test]
     fn test_multi_level_background_inheritance() {
         // Create a grandparent div with blue background
@@ -1299,6 +1781,44 @@ mod tests {
         assert_eq!(cell_i.char, 'i', "Expected 'i' at position 5");
     }
 
+    #[test]
+    fn test_text_justify_alignment() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let node: VNode = Div::new()
+            .width(12)
+            .child(
+                Text::new("one two three four")
+                    .wrap(TextWrap::Word)
+                    .align(TextAlign::Justify)
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 10);
+
+        let mut buffer = ScreenBuffer::new(20, 10);
+        let clip_rect = crate::Rect::new(0, 0, 20, 10);
+
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        let row = |y| -> String {
+            (0..12)
+                .map(|x| buffer.get_cell(x, y).unwrap().char)
+                .collect()
+        };
+
+        // Every line but the last is stretched to the full width
+        assert_eq!(row(0), "one      two");
+        assert_eq!(row(1), "three four  ");
+    }
+
     #[test]
     fn test_text_right_alignment() {
         use crate::prelude::*;
@@ -1333,19 +1853,146 @@ mod tests {
     }
 
     #[test]
-    fn test_justify_content_start() {
+    fn test_text_bg_fill_spans_node_width() {
         use crate::prelude::*;
         use crate::vdom::VDom;
         use crate::vnode::VNode;
 
         let node: VNode = Div::new()
-            .width(20)
-            .height(3)
-            .direction(Direction::Horizontal)
-            .justify_content(JustifyContent::Start)
-            .children(vec![
-                Div::new().width(3).height(1).into(),
-                Div::new().width(3).height(1).into(),
+            .width(10)
+            .child(
+                Text::new("Hi")
+                    .background(Color::Blue)
+                    .align(TextAlign::Center)
+                    .bg_fill(true)
+                    .into(),
+            )
+            .child(Text::new("Hi").background(Color::Red).into())
+            .child(
+                Text::new("one two")
+                    .background(Color::Green)
+                    .wrap(TextWrap::Word)
+                    .align(TextAlign::Left)
+                    .bg_fill(true)
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 10);
+
+        let mut buffer = ScreenBuffer::new(20, 10);
+        let clip_rect = crate::Rect::new(0, 0, 20, 10);
+
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        let backgrounds = |y| -> Vec<Option<Color>> {
+            (0..10).map(|x| buffer.get_cell(x, y).unwrap().bg).collect()
+        };
+
+        // Filled lines are solid bars, the plain one stops at its text
+        assert_eq!(buffer.get_cell(4, 0).unwrap().char, 'H');
+        assert_eq!(backgrounds(0), vec![Some(Color::Blue); 10]);
+        assert_eq!(
+            backgrounds(1)[..3],
+            [Some(Color::Red), Some(Color::Red), None]
+        );
+        assert_eq!(backgrounds(2), vec![Some(Color::Green); 10]);
+    }
+
+    #[test]
+    fn test_fill_char_covers_content_area() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let node: VNode = Div::new()
+            .width(8)
+            .height(5)
+            .background(Color::Blue)
+            .border(BorderStyle::Single)
+            .padding(Spacing::horizontal(1))
+            .fill_char('·')
+            .fill_color(Color::BrightBlack)
+            .child(Text::new("ok").into())
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(8, 5);
+
+        let mut buffer = ScreenBuffer::new(8, 5);
+        let clip_rect = crate::Rect::new(0, 0, 8, 5);
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        let row = |y| -> String {
+            (0..8)
+                .map(|x| buffer.get_cell(x, y).unwrap().char)
+                .collect()
+        };
+
+        // Border and padding stay clear, children draw over the fill
+        assert_eq!(row(0), "┌──────┐");
+        assert_eq!(row(1), "│ ok·· │");
+        assert_eq!(row(2), "│ ···· │");
+        let dot = buffer.get_cell(3, 2).unwrap();
+        assert_eq!(dot.fg, Some(Color::BrightBlack));
+        assert_eq!(dot.bg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_custom_border_chars() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let node: VNode = Div::new()
+            .border_color(Color::Green)
+            .border_chars(BorderStyle::ASCII.chars())
+            .width(5)
+            .height(3)
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(5, 3);
+
+        let mut buffer = ScreenBuffer::new(5, 3);
+        let clip_rect = crate::Rect::new(0, 0, 5, 3);
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        let row = |y| -> String {
+            (0..5)
+                .map(|x| buffer.get_cell(x, y).unwrap().char)
+                .collect()
+        };
+        assert_eq!(row(0), "+---+");
+        assert_eq!(row(1), "|   |");
+        assert_eq!(row(2), "+---+");
+        assert_eq!(buffer.get_cell(0, 0).unwrap().fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_justify_content_start() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let node: VNode = Div::new()
+            .width(20)
+            .height(3)
+            .direction(Direction::Horizontal)
+            .justify_content(JustifyContent::Start)
+            .children(vec![
+                Div::new().width(3).height(1).into(),
+                Div::new().width(3).height(1).into(),
                 Div::new().width(3).height(1).into(),
             ])
             .into();
@@ -1566,6 +2213,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_align_items_baseline() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let row = |wrap_row: bool| -> VNode {
+            let mut div = Div::new()
+                .width(20)
+                .height(6)
+                .direction(Direction::Horizontal)
+                .align_items(AlignItems::Baseline)
+                .children(vec![
+                    Text::new("Name:").into(),
+                    Div::new()
+                        .width(5)
+                        .child(Text::new("one two three").wrap(TextWrap::Word).into())
+                        .into(),
+                    Div::new().width(3).height(2).into(),
+                    Div::new()
+                        .align_self(AlignSelf::Start)
+                        .child(Text::new("top").into())
+                        .into(),
+                ]);
+            if wrap_row {
+                div = div.wrap(WrapMode::Wrap);
+            }
+            div.into()
+        };
+
+        for wrap_row in [false, true] {
+            let mut vdom = VDom::new();
+            vdom.render(row(wrap_row));
+            vdom.layout(20, 6);
+
+            let root = vdom.get_render_tree().root.clone().unwrap();
+            let root_ref = root.borrow();
+            let tops: Vec<_> = root_ref
+                .children
+                .iter()
+                .map(|child| (child.borrow().y, child.borrow().height))
+                .collect();
+            // The label and the bottom of the box sit on the last wrapped line
+            assert_eq!(tops, [(2, 1), (0, 3), (1, 2), (0, 1)], "wrap: {wrap_row}");
+        }
+    }
+
     #[test]
     fn test_align_self_override() {
         use crate::prelude::*;
@@ -1773,61 +2467,799 @@ mod tests {
             assert_eq!(child4.x, 20); // Last item at end (30 - 10 = 20)
         }
     }
-}
 
-/// Renders scrollbar indicators for a scrollable node.
-///
-/// Shows vertical scrollbar when content exceeds viewport.
-fn render_scrollbars(
-    node: &RenderNode,
-    buffer: &mut ScreenBuffer,
-    clip_rect: &Rect,
-    parent_scroll_offset: i16,
-) {
-    // Determine if scrollbar is needed
-    let needs_scrollbar = node.content_height > node.height;
+    #[test]
+    fn test_stacking_context_escapes_flow_parent() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
 
-    // Only show scrollbar for Auto mode if content overflows
-    if let Some(style) = &node.style
-        && let Some(Overflow::Auto) = style.overflow
-        && !needs_scrollbar
-    {
-        return;
+        // C (z: 10) sits inside a flow parent A; B (z: 5) is A's sibling.
+        // Both belong to the root context, so C paints above B.
+        let c: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(3)
+            .height(1)
+            .background(Color::Red)
+            .z_index(10)
+            .into();
+        let a: VNode = Div::new().width(10).height(3).child(c).into();
+        let b: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(3)
+            .height(1)
+            .background(Color::Blue)
+            .z_index(5)
+            .into();
+        let node: VNode = Div::new().width(10).height(3).children(vec![a, b]).into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 3);
+
+        let mut buffer = ScreenBuffer::new(10, 3);
+        let clip_rect = crate::Rect::new(0, 0, 10, 3);
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        assert_eq!(buffer.get_cell(0, 0).unwrap().bg, Some(Color::Red));
     }
 
-    // Calculate rendered position with parent scroll offset
-    let rendered_y = if parent_scroll_offset > 0 {
-        node.y.saturating_sub(parent_scroll_offset as u16)
-    } else {
-        node.y
-    };
-    let rendered_x = node.x;
+    #[test]
+    fn test_stacking_context_contains_descendants() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
 
-    // Vertical scrollbar
-    if needs_scrollbar && node.height > 2 {
-        let scrollbar_x = rendered_x + node.width.saturating_sub(1);
-        let scrollbar_height = node.height;
+        // P (z: 1) establishes a context, so its child C (z: 100)
+        // can't paint above P's sibling Q (z: 2).
+        let c: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(3)
+            .height(1)
+            .background(Color::Red)
+            .z_index(100)
+            .into();
+        let p: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(5)
+            .height(2)
+            .z_index(1)
+            .child(c)
+            .into();
+        let q: VNode = Div::new()
+            .absolute_position()
+            .top(0)
+            .left(0)
+            .width(3)
+            .height(1)
+            .background(Color::Green)
+            .z_index(2)
+            .into();
+        let node: VNode = Div::new().width(10).height(3).children(vec![p, q]).into();
 
-        // Calculate thumb position and size
-        let content_ratio = node.height as f32 / node.content_height as f32;
-        let thumb_height = ((scrollbar_height as f32 * content_ratio).ceil() as u16).max(1);
-        let scroll_ratio =
-            node.scroll_y as f32 / node.content_height.saturating_sub(node.height) as f32;
-        let thumb_y = rendered_y
-            + ((scrollbar_height.saturating_sub(thumb_height) as f32 * scroll_ratio) as u16);
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 3);
 
-        // Draw scrollbar track
-        for y in rendered_y..rendered_y + scrollbar_height {
-            if clip_rect.contains_point(scrollbar_x, y) {
-                let ch = if y >= thumb_y && y < thumb_y + thumb_height {
-                    '█' // Thumb
-                } else {
-                    '│' // Track
-                };
-                let mut cell = Cell::new(ch);
-                cell.fg = Some(Color::BrightBlack);
-                buffer.set_cell(scrollbar_x, y, cell);
+        let mut buffer = ScreenBuffer::new(10, 3);
+        let clip_rect = crate::Rect::new(0, 0, 10, 3);
+        if let Some(root) = &vdom.get_render_tree().root {
+            render_node_to_buffer(&root.borrow(), &mut buffer, &clip_rect, None);
+        }
+
+        assert_eq!(buffer.get_cell(0, 0).unwrap().bg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_stacking_order_across_subtrees() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // Two overlapping panels nested in separate flow subtrees. Whichever
+        // has the higher z-index paints on top and receives the pointer,
+        // regardless of tree order.
+        let panel = |color, z| -> VNode {
+            Div::new()
+                .absolute_position()
+                .top(1)
+                .left(2)
+                .width(6)
+                .height(2)
+                .background(color)
+                .z_index(z)
+                .into()
+        };
+
+        for (red_z, blue_z, top) in [(3, 2, Color::Red), (2, 3, Color::Blue)] {
+            let left: VNode = Div::new()
+                .width(5)
+                .height(4)
+                .child(Div::new().child(panel(Color::Red, red_z)).into())
+                .into();
+            let right: VNode = Div::new()
+                .width(5)
+                .height(4)
+                .child(Div::new().child(panel(Color::Blue, blue_z)).into())
+                .into();
+            let node: VNode = Div::new()
+                .direction(Direction::Horizontal)
+                .width(12)
+                .height(4)
+                .children(vec![left, right])
+                .into();
+
+            let mut vdom = VDom::new();
+            vdom.render(node);
+            vdom.layout(12, 4);
+
+            let mut buffer = ScreenBuffer::new(12, 4);
+            let clip_rect = crate::Rect::new(0, 0, 12, 4);
+            let tree = vdom.get_render_tree();
+            render_node_to_buffer(
+                &tree.root.as_ref().unwrap().borrow(),
+                &mut buffer,
+                &clip_rect,
+                None,
+            );
+
+            // The red panel spans columns 2-7 and the blue one 7-12
+            assert_eq!(buffer.get_cell(6, 1).unwrap().bg, Some(Color::Red));
+            assert_eq!(buffer.get_cell(7, 1).unwrap().bg, Some(top));
+            assert_eq!(buffer.get_cell(8, 1).unwrap().bg, Some(Color::Blue));
+
+            let hit = tree.find_node_at(7, 1).unwrap();
+            let hit_color = hit.borrow().style.as_ref().unwrap().background;
+            assert_eq!(hit_color, Some(top));
+        }
+    }
+
+    #[test]
+    fn test_fractional_z_index_stacks_between_whole_values() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // Staggered panels given in descending z order, so tree order alone
+        // would put the blue one on top. The red panel at 1.5 slots between
+        // the green one (2) and the blue one (1).
+        let panel = |left, color, z: ZIndex| -> VNode {
+            Div::new()
+                .absolute_position()
+                .top(0)
+                .left(left)
+                .width(4)
+                .height(1)
+                .background(color)
+                .z_index(z)
+                .into()
+        };
+        let node: VNode = Div::new()
+            .width(10)
+            .height(1)
+            .children(vec![
+                panel(0, Color::Green, 2.into()),
+                panel(2, Color::Red, 1.5.into()),
+                panel(4, Color::Blue, 1.into()),
+            ])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(10, 1);
+
+        let mut buffer = ScreenBuffer::new(10, 1);
+        let clip_rect = crate::Rect::new(0, 0, 10, 1);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &clip_rect,
+            None,
+        );
+
+        assert_eq!(buffer.get_cell(3, 0).unwrap().bg, Some(Color::Green));
+        assert_eq!(buffer.get_cell(5, 0).unwrap().bg, Some(Color::Red));
+        assert_eq!(buffer.get_cell(7, 0).unwrap().bg, Some(Color::Blue));
+
+        for (x, color) in [(3, Color::Green), (5, Color::Red)] {
+            let hit = tree.find_node_at(x, 0).unwrap();
+            let hit_color = hit.borrow().style.as_ref().unwrap().background;
+            assert_eq!(hit_color, Some(color));
+        }
+    }
+
+    #[test]
+    fn test_negative_z_index_is_hit_below_later_siblings() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // A panel pushed below the flow content it comes before in the tree
+        // is covered by it, so the content receives the pointer
+        let panel: VNode = Div::new()
+            .absolute_position()
+            .width(6)
+            .height(2)
+            .background(Color::Red)
+            .z_index(-1)
+            .into();
+        let content: VNode = Div::new()
+            .width(12)
+            .height(4)
+            .background(Color::Blue)
+            .into();
+        let node: VNode = Div::new()
+            .width(12)
+            .height(4)
+            .children(vec![panel, content])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(12, 4);
+
+        let mut buffer = ScreenBuffer::new(12, 4);
+        let clip_rect = crate::Rect::new(0, 0, 12, 4);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &clip_rect,
+            None,
+        );
+        assert_eq!(buffer.get_cell(1, 1).unwrap().bg, Some(Color::Blue));
+
+        let hit = tree.find_node_at(1, 1).unwrap();
+        let hit_color = hit.borrow().style.as_ref().unwrap().background;
+        assert_eq!(hit_color, Some(Color::Blue));
+    }
+
+    #[test]
+    fn test_clip_corners_shows_background_beneath() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        for clip in [false, true] {
+            let panel: VNode = Div::new()
+                .border(BorderStyle::Rounded)
+                .clip_corners(clip)
+                .background(Color::Red)
+                .width(4)
+                .height(3)
+                .into();
+            let root: VNode = Div::new()
+                .background(Color::Blue)
+                .width(6)
+                .height(4)
+                .child(panel)
+                .into();
+
+            let mut vdom = VDom::new();
+            vdom.render(root);
+            vdom.layout(6, 4);
+
+            let mut buffer = ScreenBuffer::new(6, 4);
+            let tree = vdom.get_render_tree();
+            render_node_to_buffer(
+                &tree.root.as_ref().unwrap().borrow(),
+                &mut buffer,
+                &crate::Rect::new(0, 0, 6, 4),
+                None,
+            );
+
+            let corner = if clip { Color::Blue } else { Color::Red };
+            for (x, y, ch) in [(0, 0, '╭'), (3, 0, '╮'), (0, 2, '╰'), (3, 2, '╯')] {
+                let cell = buffer.get_cell(x, y).unwrap();
+                assert_eq!((cell.char, cell.bg), (ch, Some(corner)));
             }
+            assert_eq!(buffer.get_cell(1, 0).unwrap().bg, Some(Color::Red));
+            assert_eq!(buffer.get_cell(1, 1).unwrap().bg, Some(Color::Red));
+        }
+    }
+
+    #[test]
+    fn test_inherit_bg_false_shows_content_beneath() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        for inherit in [true, false] {
+            // A red block under a borderless-background overlay on a blue root
+            let block: VNode = Div::new()
+                .background(Color::Red)
+                .position(Position::Absolute)
+                .width(4)
+                .height(4)
+                .into();
+            let inner: VNode = Div::new()
+                .border(BorderStyle::Single)
+                .width(3)
+                .height(2)
+                .into();
+            let overlay: VNode = Div::new()
+                .border(BorderStyle::Single)
+                .inherit_bg(inherit)
+                .position(Position::Absolute)
+                .z_index(1)
+                .width(8)
+                .height(4)
+                .child(inner)
+                .into();
+            let root: VNode = Div::new()
+                .background(Color::Blue)
+                .width(8)
+                .height(4)
+                .children(vec![block, overlay])
+                .into();
+
+            let mut vdom = VDom::new();
+            vdom.render(root);
+            vdom.layout(8, 4);
+
+            let mut buffer = ScreenBuffer::new(8, 4);
+            let tree = vdom.get_render_tree();
+            render_node_to_buffer(
+                &tree.root.as_ref().unwrap().borrow(),
+                &mut buffer,
+                &crate::Rect::new(0, 0, 8, 4),
+                None,
+            );
+
+            // Over the red block, borders show red unless they inherit blue
+            let over_block = if inherit { Color::Blue } else { Color::Red };
+            assert_eq!(buffer.get_cell(1, 0).unwrap().bg, Some(over_block));
+            assert_eq!(buffer.get_cell(1, 1).unwrap().char, '┌');
+            assert_eq!(buffer.get_cell(1, 1).unwrap().bg, Some(over_block));
+            assert_eq!(buffer.get_cell(6, 0).unwrap().bg, Some(Color::Blue));
         }
     }
+
+    #[test]
+    fn test_default_color_overrides_inherited_background() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let pane: VNode = Div::new()
+            .background(Color::Default)
+            .width(4)
+            .height(1)
+            .child(VNode::Text(Text::new("ab").color(Color::Default)))
+            .into();
+        let root: VNode = Div::new()
+            .background(Color::Blue)
+            .direction(Direction::Horizontal)
+            .width(8)
+            .height(1)
+            .child(pane)
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.layout(8, 1);
+
+        let mut buffer = ScreenBuffer::new(8, 1);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &crate::Rect::new(0, 0, 8, 1),
+            None,
+        );
+
+        // The pane and its text show the terminal's defaults, stored as no
+        // color so they match cells that were never colored
+        let text = buffer.get_cell(0, 0).unwrap();
+        assert_eq!((text.char, text.fg, text.bg), ('a', None, None));
+        assert_eq!(buffer.get_cell(3, 0).unwrap().bg, None);
+        assert_eq!(buffer.get_cell(4, 0).unwrap().bg, Some(Color::Blue));
+        assert_eq!(*buffer.get_cell(3, 0).unwrap(), Cell::empty());
+    }
+
+    #[test]
+    fn test_monochrome_keeps_highlights_and_focus_as_attributes() {
+        use crate::style::{Border, BorderEdges, BorderStyle};
+
+        // Drawn in the theme's border color, which carries no emphasis
+        let border = Border {
+            enabled: true,
+            color: Theme::default().palette.border,
+            style: BorderStyle::Single,
+            edges: BorderEdges::ALL,
+        };
+        let mut root = RenderNode::element();
+        root.width = 10;
+        root.height = 4;
+        root.style = Some(Style {
+            background: Some(Color::Black),
+            ..Default::default()
+        });
+
+        // A focused bordered box and a highlighted row
+        let mut focused = RenderNode::element();
+        focused.width = 4;
+        focused.height = 3;
+        focused.focused = true;
+        focused.style = Some(Style {
+            border: Some(border.clone()),
+            ..Default::default()
+        });
+        let mut row = RenderNode::element();
+        row.x = 5;
+        row.width = 5;
+        row.height = 1;
+        row.style = Some(Style {
+            background: Some(Color::Blue),
+            ..Default::default()
+        });
+        let root = Rc::new(RefCell::new(root));
+        for child in [focused, row] {
+            root.borrow_mut()
+                .children
+                .push(Rc::new(RefCell::new(child)));
+        }
+
+        let clip_rect = crate::bounds::Rect::new(0, 0, 10, 4);
+        let mut buffer = ScreenBuffer::new(10, 4);
+        render_frame(
+            &root.borrow(),
+            &mut buffer,
+            &clip_rect,
+            &Theme::default(),
+            true,
+        );
+
+        let corner = buffer.get_cell(0, 0).unwrap();
+        assert_eq!((corner.char, corner.fg, corner.bg), ('┌', None, None));
+        assert!(corner.style.bold && !corner.style.reverse);
+        let highlight = buffer.get_cell(5, 0).unwrap();
+        assert_eq!((highlight.fg, highlight.bg), (None, None));
+        assert!(highlight.style.reverse);
+        assert!(!buffer.get_cell(5, 1).unwrap().style.reverse);
+
+        // Once focus moves away the border is drawn plainly
+        root.borrow().children[0].borrow_mut().focused = false;
+        let mut buffer = ScreenBuffer::new(10, 4);
+        render_frame(
+            &root.borrow(),
+            &mut buffer,
+            &clip_rect,
+            &Theme::default(),
+            true,
+        );
+        assert!(!buffer.get_cell(0, 0).unwrap().style.bold);
+    }
+
+    #[test]
+    fn test_monochrome_highlights_against_the_root_background() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+
+        // A modal covering most of the screen, with an error message in it
+        let theme = Theme::default();
+        let modal = Div::new()
+            .background(Color::Blue)
+            .width(8)
+            .height(3)
+            .children(vec![
+                Text::new("ok").into(),
+                Text::new("err").color(theme.palette.error).into(),
+            ]);
+        let mut vdom = VDom::new();
+        vdom.render(
+            Div::new()
+                .background(Color::Black)
+                .width(10)
+                .height(4)
+                .child(modal.into())
+                .into(),
+        );
+        vdom.layout(10, 4);
+
+        let clip_rect = Rect::new(0, 0, 10, 4);
+        let mut buffer = ScreenBuffer::new(10, 4);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        render_frame(&root.borrow(), &mut buffer, &clip_rect, &theme, true);
+
+        let plain = buffer.get_cell(0, 0).unwrap();
+        assert_eq!((plain.char, plain.fg, plain.bg), ('o', None, None));
+        assert!(plain.style.reverse && !plain.style.bold);
+        let error = buffer.get_cell(0, 1).unwrap();
+        assert_eq!((error.char, error.fg), ('e', None));
+        assert!(error.style.reverse && error.style.bold);
+        assert!(!buffer.get_cell(9, 3).unwrap().style.reverse);
+    }
+
+    #[test]
+    fn test_join_borders_draws_table() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        let cell = |label: &str| -> VNode {
+            Div::new()
+                .border(BorderStyle::Single)
+                .width_auto()
+                .height(3)
+                .child(VNode::text(label))
+                .into()
+        };
+        let row = |left: &str, right: &str| -> VNode {
+            Div::new()
+                .direction(Direction::Horizontal)
+                .join_borders(true)
+                .width_fraction(1.0)
+                .height_content()
+                .children(vec![cell(left), cell(right)])
+                .into()
+        };
+        let table: VNode = Div::new()
+            .border(BorderStyle::Single)
+            .join_borders(true)
+            .width(9)
+            .height(5)
+            .children(vec![row("a", "b"), row("c", "d")])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(table);
+        vdom.layout(9, 5);
+
+        let mut buffer = ScreenBuffer::new(9, 5);
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        render_node_to_buffer(
+            &root.borrow(),
+            &mut buffer,
+            &crate::Rect::new(0, 0, 9, 5),
+            None,
+        );
+
+        let lines: Vec<String> = (0..5)
+            .map(|y| {
+                (0..9)
+                    .map(|x| buffer.get_cell(x, y).unwrap().char)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "┌───┬───┐",
+                "│a  │b  │",
+                "├───┼───┤",
+                "│c  │d  │",
+                "└───┴───┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overflow_visible_child_extends_past_each_edge() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // A 4x2 container at (4, 3) with one child poking past each edge
+        let probe = |top: i16, left: i16| -> VNode {
+            Div::new()
+                .absolute_position()
+                .top(top)
+                .left(left)
+                .width(2)
+                .height(1)
+                .background(Color::Red)
+                .into()
+        };
+        let container: VNode = Div::new()
+            .absolute_position()
+            .top(3)
+            .left(4)
+            .width(4)
+            .height(2)
+            .overflow(Overflow::Visible)
+            .children(vec![probe(-2, 1), probe(0, 5), probe(3, 1), probe(1, -3)])
+            .into();
+        let node: VNode = Div::new().width(12).height(8).child(container).into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(12, 8);
+
+        let mut buffer = ScreenBuffer::new(12, 8);
+        let clip_rect = crate::Rect::new(0, 0, 12, 8);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &clip_rect,
+            None,
+        );
+
+        // Above, right of, below, and left of the container
+        for (x, y) in [(5, 1), (9, 3), (5, 6), (1, 4)] {
+            assert_eq!(
+                buffer.get_cell(x, y).unwrap().bg,
+                Some(Color::Red),
+                "({x}, {y})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_overflow_visible_renders_past_empty_container() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // A zero-height anchor still paints the menu hanging below it
+        for (overflow, expected) in [
+            (Overflow::None, None),
+            (Overflow::Visible, Some(Color::Red)),
+        ] {
+            let menu: VNode = Div::new()
+                .absolute_position()
+                .top(0)
+                .left(0)
+                .width(3)
+                .height(2)
+                .background(Color::Red)
+                .into();
+            let anchor: VNode = Div::new()
+                .width(6)
+                .height(0)
+                .overflow(overflow)
+                .child(menu)
+                .into();
+            let node: VNode = Div::new().width(6).height(3).child(anchor).into();
+
+            let mut vdom = VDom::new();
+            vdom.render(node);
+            vdom.layout(6, 3);
+
+            let mut buffer = ScreenBuffer::new(6, 3);
+            let clip_rect = crate::Rect::new(0, 0, 6, 3);
+            let tree = vdom.get_render_tree();
+            render_node_to_buffer(
+                &tree.root.as_ref().unwrap().borrow(),
+                &mut buffer,
+                &clip_rect,
+                None,
+            );
+
+            assert_eq!(buffer.get_cell(1, 1).unwrap().bg, expected, "{overflow:?}");
+        }
+    }
+
+    #[test]
+    fn test_overflow_visible_layers_above_later_siblings() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // A dropdown overflowing its one-row header covers the body below,
+        // both when painting and when hit testing
+        let dropdown: VNode = Div::new()
+            .absolute_position()
+            .top(1)
+            .left(0)
+            .width(4)
+            .height(2)
+            .background(Color::Red)
+            .on_click(|| {})
+            .into();
+        let header: VNode = Div::new()
+            .width(8)
+            .height(1)
+            .overflow(Overflow::Visible)
+            .z_index(1)
+            .child(dropdown)
+            .into();
+        let body: VNode = Div::new().width(8).height(3).background(Color::Blue).into();
+        let node: VNode = Div::new()
+            .width(8)
+            .height(4)
+            .children(vec![header, body])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(8, 4);
+
+        let mut buffer = ScreenBuffer::new(8, 4);
+        let clip_rect = crate::Rect::new(0, 0, 8, 4);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &clip_rect,
+            None,
+        );
+
+        assert_eq!(buffer.get_cell(1, 2).unwrap().bg, Some(Color::Red));
+        assert_eq!(buffer.get_cell(5, 2).unwrap().bg, Some(Color::Blue));
+
+        let hit = tree.find_node_at(1, 2).unwrap();
+        assert_eq!(
+            hit.borrow().style.as_ref().unwrap().background,
+            Some(Color::Red)
+        );
+    }
+
+    #[test]
+    fn test_portal_escapes_clipping_and_stacking() {
+        use crate::prelude::*;
+        use crate::vdom::VDom;
+        use crate::vnode::VNode;
+
+        // The dropdown sits inside a one-row clipping header, and a later
+        // panel with a high z-index covers the rows below it
+        let dropdown: VNode = Div::new()
+            .absolute_position()
+            .top(1)
+            .left(0)
+            .width(4)
+            .height(2)
+            .background(Color::Red)
+            .portal(0)
+            .on_click(|| {})
+            .into();
+        let header: VNode = Div::new()
+            .width(8)
+            .height(1)
+            .overflow(Overflow::Hidden)
+            .child(dropdown)
+            .into();
+        let panel: VNode = Div::new()
+            .absolute_position()
+            .top(1)
+            .left(0)
+            .width(8)
+            .height(3)
+            .z_index(50)
+            .background(Color::Blue)
+            .on_click(|| {})
+            .into();
+        let node: VNode = Div::new()
+            .width(8)
+            .height(4)
+            .children(vec![header, panel])
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(8, 4);
+
+        let mut buffer = ScreenBuffer::new(8, 4);
+        let clip_rect = crate::Rect::new(0, 0, 8, 4);
+        let tree = vdom.get_render_tree();
+        render_node_to_buffer(
+            &tree.root.as_ref().unwrap().borrow(),
+            &mut buffer,
+            &clip_rect,
+            None,
+        );
+
+        assert_eq!(buffer.get_cell(1, 2).unwrap().bg, Some(Color::Red));
+        assert_eq!(buffer.get_cell(5, 2).unwrap().bg, Some(Color::Blue));
+        assert_eq!(buffer.get_cell(1, 3).unwrap().bg, Some(Color::Blue));
+
+        let background = |x, y| {
+            let hit = tree.find_node_at(x, y).unwrap();
+            hit.borrow().style.as_ref().unwrap().background
+        };
+        assert_eq!(background(1, 2), Some(Color::Red));
+        assert_eq!(background(5, 2), Some(Color::Blue));
+    }
 }
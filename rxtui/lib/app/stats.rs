@@ -0,0 +1,161 @@
+//! Per-frame timings and counters for profiling.
+//!
+//! Enabled with [`App::profiling`](crate::App::profiling). Each drawn frame
+//! produces a [`FrameStats`] that components read through
+//! [`Context::frame_stats`](crate::Context::frame_stats) and that
+//! [`App::on_frame_stats`](crate::App::on_frame_stats) receives for logging.
+//!
+//! ```text
+//!   view ─▶ reconcile ─▶ layout ─▶ paint ─▶ diff ─▶ flush
+//!    │         │                     │        │        │
+//!  expand   vdom diff            buffer   cell diff  terminal
+//! ```
+
+use crate::render_tree::RenderNode;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Timings and counters for one drawn frame.
+///
+/// View expansion runs on every loop iteration, not only when a frame is
+/// drawn, so `view_calls` and `view_time` cover everything since the previous
+/// frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    /// Sequence number of the frame, starting at 1
+    pub frame: u64,
+
+    /// Component `view` calls since the previous frame
+    pub view_calls: usize,
+
+    /// Time spent expanding components into the virtual tree
+    pub view_time: Duration,
+
+    /// Time spent diffing the virtual tree and patching the render tree
+    pub reconcile_time: Duration,
+
+    /// Time spent computing layout
+    pub layout_time: Duration,
+
+    /// Time spent painting the render tree into the screen buffer
+    pub paint_time: Duration,
+
+    /// Time spent diffing screen buffers
+    pub diff_time: Duration,
+
+    /// Time spent writing changes to the terminal
+    pub flush_time: Duration,
+
+    /// Render nodes laid out
+    pub nodes_laid_out: usize,
+
+    /// Cells sent to the terminal
+    pub cells_written: usize,
+
+    /// Bytes, including escape sequences, sent to the terminal
+    pub bytes_written: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl FrameStats {
+    /// Total time across all phases.
+    pub fn total_time(&self) -> Duration {
+        self.view_time
+            + self.reconcile_time
+            + self.layout_time
+            + self.paint_time
+            + self.diff_time
+            + self.flush_time
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Starts timing a phase, or does nothing when profiling is off.
+pub(crate) fn start_timer(enabled: bool) -> Option<Instant> {
+    enabled.then(Instant::now)
+}
+
+/// Time elapsed since [`start_timer`], or zero when profiling is off.
+pub(crate) fn elapsed(start: Option<Instant>) -> Duration {
+    start.map(|start| start.elapsed()).unwrap_or_default()
+}
+
+/// Counts the nodes in a render subtree.
+pub(crate) fn count_nodes(node: &Rc<RefCell<RenderNode>>) -> usize {
+    1 + node
+        .borrow()
+        .children
+        .iter()
+        .map(count_nodes)
+        .sum::<usize>()
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for FrameStats {
+    /// One-line summary suitable for a debug overlay or log.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        write!(
+            f,
+            "frame {} | {:.2}ms (view {:.2}, reconcile {:.2}, layout {:.2}, paint {:.2}, diff {:.2}, flush {:.2}) | {} views, {} nodes, {} cells, {} bytes",
+            self.frame,
+            ms(self.total_time()),
+            ms(self.view_time),
+            ms(self.reconcile_time),
+            ms(self.layout_time),
+            ms(self.paint_time),
+            ms(self.diff_time),
+            ms(self.flush_time),
+            self.view_calls,
+            self.nodes_laid_out,
+            self.cells_written,
+            self.bytes_written,
+        )
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::Div;
+    use crate::vdom::VDom;
+    use crate::vnode::VNode;
+
+    #[test]
+    fn test_timers_are_inert_when_disabled() {
+        assert!(start_timer(false).is_none());
+        assert_eq!(elapsed(None), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_count_nodes() {
+        let root: VNode = Div::new()
+            .child(VNode::text("a"))
+            .child(Div::new().child(VNode::text("b")).into())
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+
+        let tree = vdom.get_render_tree();
+        assert_eq!(count_nodes(tree.root.as_ref().unwrap()), 4);
+    }
+}
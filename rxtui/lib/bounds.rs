@@ -2,6 +2,9 @@
 //!
 //! This module provides types and operations for tracking rectangular regions
 //! on the terminal screen, used for efficient dirty region tracking and clipping.
+//! The same helpers work for hit testing, overlay positioning, and custom drawing.
+
+use crate::style::Spacing;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -13,6 +16,7 @@
 /// - Tracking dirty regions that need redrawing
 /// - Clipping child elements to parent bounds
 /// - Hit testing for mouse events
+/// - Splitting and offsetting areas in custom draw logic
 ///
 /// ## Coordinate System
 ///
@@ -191,6 +195,82 @@ impl Rect {
             )
         }
     }
+
+    /// Shrinks the rectangle by a separate amount on each side.
+    ///
+    /// Sides that would cross collapse to zero size, like [`Rect::contract`].
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// Rect { x: 0, y: 0, width: 10, height: 6 }
+    /// inset(Spacing { top: 1, right: 2, bottom: 1, left: 2 })
+    ///     → Rect { x: 2, y: 1, width: 6, height: 4 }
+    /// ```
+    pub fn inset(&self, spacing: Spacing) -> Rect {
+        let horizontal = spacing.left.saturating_add(spacing.right);
+        let vertical = spacing.top.saturating_add(spacing.bottom);
+        if horizontal >= self.width || vertical >= self.height {
+            return Rect::new(
+                self.x.saturating_add(spacing.left),
+                self.y.saturating_add(spacing.top),
+                0,
+                0,
+            );
+        }
+
+        Rect::new(
+            self.x + spacing.left,
+            self.y + spacing.top,
+            self.width - horizontal,
+            self.height - vertical,
+        )
+    }
+
+    /// Moves the rectangle by the given offset, stopping at the screen edges.
+    pub fn translate(&self, dx: i16, dy: i16) -> Rect {
+        Rect::new(
+            self.x.saturating_add_signed(dx),
+            self.y.saturating_add_signed(dy),
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Returns the center cell, rounding toward the top-left.
+    pub fn center(&self) -> (u16, u16) {
+        (self.x + self.width / 2, self.y + self.height / 2)
+    }
+
+    /// Splits into left and right parts at the given column offset.
+    ///
+    /// The left part is `at` columns wide (clamped to the width).
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// ┌────┬──────┐
+    /// │left│right │   split_horizontal(4) of a 10-wide rect
+    /// └────┴──────┘
+    /// ```
+    pub fn split_horizontal(&self, at: u16) -> (Rect, Rect) {
+        let at = at.min(self.width);
+        (
+            Rect::new(self.x, self.y, at, self.height),
+            Rect::new(self.x + at, self.y, self.width - at, self.height),
+        )
+    }
+
+    /// Splits into top and bottom parts at the given row offset.
+    ///
+    /// The top part is `at` rows tall (clamped to the height).
+    pub fn split_vertical(&self, at: u16) -> (Rect, Rect) {
+        let at = at.min(self.height);
+        (
+            Rect::new(self.x, self.y, self.width, at),
+            Rect::new(self.x, self.y + at, self.width, self.height - at),
+        )
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -236,4 +316,43 @@ mod tests {
         let result = rect1.union(&rect2);
         assert_eq!(result, Rect::new(10, 10, 30, 30));
     }
+
+    #[test]
+    fn test_inset_and_translate() {
+        let rect = Rect::new(0, 0, 10, 6);
+        let spacing = Spacing {
+            top: 1,
+            right: 2,
+            bottom: 1,
+            left: 2,
+        };
+        assert_eq!(rect.inset(spacing), Rect::new(2, 1, 6, 4));
+        assert!(rect.inset(Spacing::horizontal(5)).is_empty());
+
+        assert_eq!(rect.translate(3, -1), Rect::new(3, 0, 10, 6));
+        assert_eq!(
+            Rect::new(5, 5, 2, 2).translate(-2, 4),
+            Rect::new(3, 9, 2, 2)
+        );
+        assert_eq!(rect.center(), (5, 3));
+        assert_eq!(Rect::new(4, 4, 3, 1).center(), (5, 4));
+    }
+
+    #[test]
+    fn test_split() {
+        let rect = Rect::new(2, 3, 10, 4);
+        assert_eq!(
+            rect.split_horizontal(4),
+            (Rect::new(2, 3, 4, 4), Rect::new(6, 3, 6, 4))
+        );
+        assert_eq!(
+            rect.split_vertical(1),
+            (Rect::new(2, 3, 10, 1), Rect::new(2, 4, 10, 3))
+        );
+
+        // Offsets past the edge leave an empty second part
+        let (left, right) = rect.split_horizontal(20);
+        assert_eq!(left, rect);
+        assert!(right.is_empty());
+    }
 }
@@ -14,10 +14,30 @@
 //!     └─────────────┘      └─────────────┘      └─────────────┘
 //!        Front Buffer         Back Buffer          Cell Updates
 //! ```
+//!
+//! ## Snapshots
+//!
+//! A buffer can also be written out as a text snapshot, a grid of characters
+//! followed by the colors and attributes of the styled cells. Snapshots are
+//! saved as golden files and compared with [`diff_snapshots`], which lists
+//! each cell that changed.
 
-use crate::style::{Color, TextStyle};
+use crate::style::{Color, TextStyle, UnderlineStyle};
 use crate::utils::char_width;
 use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Environment variable that makes [`assert_snapshot`] overwrite golden files
+const UPDATE_SNAPSHOTS_VAR: &str = "RXTUI_UPDATE_SNAPSHOTS";
+
+/// Most mismatched cells listed by [`diff_snapshots`]
+const MAX_REPORTED_MISMATCHES: usize = 20;
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -54,8 +74,23 @@ pub struct CellStyle {
     /// Underlined text
     pub underline: bool,
 
+    /// Underline decoration style (only used when `underline` is set)
+    pub underline_style: UnderlineStyle,
+
+    /// Underline color (only used when `underline` is set)
+    pub underline_color: Option<Color>,
+
     /// Strikethrough text
     pub strikethrough: bool,
+
+    /// Foreground and background colors swapped by the terminal
+    pub reverse: bool,
+
+    /// Blinking text
+    pub blink: bool,
+
+    /// Text hidden by the terminal
+    pub conceal: bool,
 }
 
 /// A buffer representing the entire terminal screen as a 2D grid of cells.
@@ -87,6 +122,19 @@ pub struct DoubleBuffer {
 
     /// The buffer we're rendering to for the next frame
     back: ScreenBuffer,
+
+    /// Whether the front buffer no longer reflects the screen, so the next
+    /// diff must report every cell
+    invalidated: bool,
+}
+
+/// Colors that carry no emphasis when a frame is drawn in two tones.
+pub(crate) struct Monochrome {
+    /// Background of the screen itself; cells with any other are highlighted
+    pub(crate) screen_bg: Option<Color>,
+
+    /// Text colors drawn plainly; cells with any other are drawn bold
+    pub(crate) plain_fg: Vec<Color>,
 }
 
 /// Represents an update to a single cell.
@@ -96,6 +144,14 @@ pub enum CellUpdate {
     Single { x: u16, y: u16, cell: Cell },
 }
 
+/// A snapshot read back into rows of cells, each a character and its
+/// attributes as written in the snapshot
+struct SnapshotGrid {
+    width: u16,
+    height: u16,
+    rows: Vec<Vec<(char, String)>>,
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -107,17 +163,34 @@ impl CellStyle {
             bold: text_style.bold.unwrap_or(false),
             italic: text_style.italic.unwrap_or(false),
             underline: text_style.underline.unwrap_or(false),
+            underline_style: text_style.underline_style.unwrap_or_default(),
+            underline_color: text_style.underline_color,
             strikethrough: text_style.strikethrough.unwrap_or(false),
+            reverse: text_style.reverse.unwrap_or(false),
+            blink: text_style.blink.unwrap_or(false),
+            conceal: text_style.conceal.unwrap_or(false),
         }
     }
 
     /// Merges this CellStyle with another, taking the other's values where they differ from defaults.
     pub fn merge_with(self, other: &CellStyle) -> Self {
+        // Underline decoration follows whichever side actually draws an underline
+        let (underline_style, underline_color) = if other.underline {
+            (other.underline_style, other.underline_color)
+        } else {
+            (self.underline_style, self.underline_color)
+        };
+
         Self {
             bold: self.bold || other.bold,
             italic: self.italic || other.italic,
             underline: self.underline || other.underline,
+            underline_style,
+            underline_color,
             strikethrough: self.strikethrough || other.strikethrough,
+            reverse: self.reverse || other.reverse,
+            blink: self.blink || other.blink,
+            conceal: self.conceal || other.conceal,
         }
     }
 }
@@ -234,6 +307,118 @@ impl ScreenBuffer {
         (self.width, self.height)
     }
 
+    /// Toggles reverse video on every cell, inverting the whole screen.
+    pub fn invert(&mut self) {
+        for cell in self.cells.iter_mut().flatten() {
+            cell.style.reverse = !cell.style.reverse;
+        }
+    }
+
+    /// Replaces [`Color::Default`] with no color in every cell, and strips
+    /// every color when `monochrome` is given.
+    ///
+    /// Both mean the terminal's default, so after this the cells compare
+    /// equal however they were drawn, and diffing doesn't rewrite them. In
+    /// monochrome, emphasis is kept with attributes: cells with a background
+    /// other than the screen's have reverse video toggled, so a selected row
+    /// or a button stays visible, and cells with an accent text color are
+    /// drawn bold.
+    pub(crate) fn resolve_colors(&mut self, monochrome: Option<&Monochrome>) {
+        let resolve = |color: &mut Option<Color>| {
+            if *color == Some(Color::Default) {
+                *color = None;
+            }
+        };
+        for cell in self.cells.iter_mut().flatten() {
+            resolve(&mut cell.fg);
+            resolve(&mut cell.bg);
+            resolve(&mut cell.style.underline_color);
+
+            let Some(monochrome) = monochrome else {
+                continue;
+            };
+            if cell.bg != monochrome.screen_bg {
+                cell.style.reverse = !cell.style.reverse;
+            }
+            if cell.fg.is_some_and(|fg| !monochrome.plain_fg.contains(&fg)) {
+                cell.style.bold = true;
+            }
+            cell.fg = None;
+            cell.bg = None;
+            cell.style.underline_color = None;
+        }
+    }
+
+    /// Returns `bg`, or the background already at a cell when there is none.
+    fn background_at(&self, x: u16, y: u16, bg: Option<Color>) -> Option<Color> {
+        bg.or_else(|| self.get_cell(x, y).and_then(|cell| cell.bg))
+    }
+
+    /// Returns the buffer's characters as lines of plain text.
+    ///
+    /// Trailing spaces and blank lines at the bottom are trimmed, and the
+    /// padding cell after a wide character is skipped.
+    pub fn to_plain_text(&self) -> String {
+        let mut lines: Vec<String> = self
+            .cells
+            .iter()
+            .map(|row| {
+                let mut line = String::new();
+                let mut skip = false;
+                for cell in row {
+                    if std::mem::take(&mut skip) {
+                        continue;
+                    }
+                    line.push(cell.char);
+                    skip = char_width(cell.char) == 2;
+                }
+                line.trim_end().to_string()
+            })
+            .collect();
+
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    /// Returns the buffer as a text snapshot for golden file tests.
+    ///
+    /// The first line holds the size, followed by one line per row with
+    /// every cell's character between `|` bars, the padding cell after a wide
+    /// character included. Then each run of identically styled cells in a row
+    /// gets a line with its colors and attributes, like
+    /// `row 0 col 2-6: fg=Red bold`. Unstyled cells are left out.
+    pub fn to_snapshot(&self) -> String {
+        let mut snapshot = format!("size {}x{}\n", self.width, self.height);
+        for row in &self.cells {
+            snapshot.push('|');
+            snapshot.extend(row.iter().map(|cell| cell.char));
+            snapshot.push_str("|\n");
+        }
+
+        for (y, row) in self.cells.iter().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let attributes = cell_attributes(&row[x]);
+                let end = x + row[x..]
+                    .iter()
+                    .take_while(|cell| cell_attributes(cell) == attributes)
+                    .count();
+                if !attributes.is_empty() {
+                    let cols = if end - x == 1 {
+                        x.to_string()
+                    } else {
+                        format!("{x}-{}", end - 1)
+                    };
+                    let _ = writeln!(snapshot, "row {y} col {cols}: {attributes}");
+                }
+                x = end;
+            }
+        }
+        snapshot
+    }
+
     /// Fills a rectangular region with the given cell.
     pub fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, cell: Cell) {
         for dy in 0..height {
@@ -247,6 +432,7 @@ impl ScreenBuffer {
     ///
     /// The string is written horizontally. If it extends beyond the buffer width,
     /// it is truncated. Properly handles wide characters (CJK, emoji) that take 2 columns.
+    /// Without `bg`, each cell keeps the background already drawn there.
     pub fn write_str(&mut self, x: u16, y: u16, text: &str, fg: Option<Color>, bg: Option<Color>) {
         let mut current_x = x;
 
@@ -261,7 +447,7 @@ impl ScreenBuffer {
             // Set the main cell
             let mut cell = Cell::new(ch);
             cell.fg = fg;
-            cell.bg = bg;
+            cell.bg = self.background_at(current_x, y, bg);
             self.set_cell(current_x, y, cell);
 
             // For wide characters, fill the next cell with a space
@@ -269,7 +455,7 @@ impl ScreenBuffer {
             if ch_width == 2 && current_x + 1 < self.width {
                 let mut space_cell = Cell::new(' ');
                 space_cell.fg = fg;
-                space_cell.bg = bg;
+                space_cell.bg = self.background_at(current_x + 1, y, bg);
                 self.set_cell(current_x + 1, y, space_cell);
             }
 
@@ -281,6 +467,7 @@ impl ScreenBuffer {
     ///
     /// The string is written horizontally. If it extends beyond the buffer width,
     /// it is truncated. Properly handles wide characters (CJK, emoji) that take 2 columns.
+    /// Without a background in the style, each cell keeps the background already drawn there.
     pub fn write_styled_str(&mut self, x: u16, y: u16, text: &str, text_style: Option<&TextStyle>) {
         let (fg, bg, cell_style) = if let Some(style) = text_style {
             (
@@ -305,7 +492,7 @@ impl ScreenBuffer {
             // Set the main cell
             let mut cell = Cell::new(ch);
             cell.fg = fg;
-            cell.bg = bg;
+            cell.bg = self.background_at(current_x, y, bg);
             cell.style = cell_style.clone();
             self.set_cell(current_x, y, cell);
 
@@ -314,7 +501,7 @@ impl ScreenBuffer {
             if ch_width == 2 && current_x + 1 < self.width {
                 let mut space_cell = Cell::new(' ');
                 space_cell.fg = fg;
-                space_cell.bg = bg;
+                space_cell.bg = self.background_at(current_x + 1, y, bg);
                 space_cell.style = cell_style.clone();
                 self.set_cell(current_x + 1, y, space_cell);
             }
@@ -330,6 +517,7 @@ impl DoubleBuffer {
         Self {
             front: ScreenBuffer::new(width, height),
             back: ScreenBuffer::new(width, height),
+            invalidated: false,
         }
     }
 
@@ -340,6 +528,7 @@ impl DoubleBuffer {
     /// - The front buffer becomes the back buffer (ready for next frame)
     pub fn swap(&mut self) {
         std::mem::swap(&mut self.front, &mut self.back);
+        self.invalidated = false;
     }
 
     /// Provides mutable access to the back buffer for rendering.
@@ -353,6 +542,14 @@ impl DoubleBuffer {
         self.back.clear();
     }
 
+    /// Forgets what is on screen so the next diff reports every cell.
+    ///
+    /// Unlike [`reset`](Self::reset), this also repaints blank cells, which
+    /// matters when the terminal region holds content the buffers never drew.
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
     /// Resizes both buffers to the new dimensions.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.front.resize(width, height);
@@ -374,7 +571,7 @@ impl DoubleBuffer {
                 let back_cell = self.back.get_cell(x, y);
 
                 match (front_cell, back_cell) {
-                    (Some(front), Some(back)) if front != back => {
+                    (Some(front), Some(back)) if self.invalidated || front != back => {
                         updates.push(CellUpdate::Single {
                             x,
                             y,
@@ -395,12 +592,229 @@ impl DoubleBuffer {
     }
 }
 
+impl SnapshotGrid {
+    /// Reads a snapshot written by [`ScreenBuffer::to_snapshot`]
+    fn parse(snapshot: &str) -> Result<Self, String> {
+        let mut lines = snapshot.lines().map(|line| line.trim_end_matches('\r'));
+        let size = lines.next().unwrap_or_default();
+        let (width, height) = size
+            .strip_prefix("size ")
+            .and_then(|size| size.split_once('x'))
+            .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+            .ok_or_else(|| format!("expected `size <width>x<height>`, found `{size}`"))?;
+
+        let mut rows: Vec<Vec<(char, String)>> = Vec::with_capacity(height as usize);
+        for y in 0..height {
+            let line = lines.next().unwrap_or_default();
+            let chars: Vec<char> = line
+                .strip_prefix('|')
+                .and_then(|line| line.strip_suffix('|'))
+                .ok_or_else(|| format!("row {y} is not enclosed in `|`"))?
+                .chars()
+                .collect();
+            if chars.len() != width as usize {
+                return Err(format!("row {y} has {} cells, not {width}", chars.len()));
+            }
+            rows.push(chars.into_iter().map(|ch| (ch, String::new())).collect());
+        }
+
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let run = line
+                .strip_prefix("row ")
+                .and_then(|line| line.split_once(" col "))
+                .and_then(|(y, rest)| Some((y, rest.split_once(": ")?)))
+                .and_then(|(y, (cols, attributes))| {
+                    let (start, end) = cols.split_once('-').unwrap_or((cols, cols));
+                    let (y, start, end) = (
+                        y.parse::<usize>().ok()?,
+                        start.parse::<usize>().ok()?,
+                        end.parse::<usize>().ok()?,
+                    );
+                    let cells = rows.get_mut(y)?.get_mut(start..=end)?;
+                    Some((cells, attributes))
+                });
+            let Some((cells, attributes)) = run else {
+                return Err(format!("invalid style line `{line}`"));
+            };
+            for cell in cells {
+                cell.1 = attributes.to_string();
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            rows,
+        })
+    }
+}
+
 impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.char)
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Compares two snapshots from [`ScreenBuffer::to_snapshot`] cell by cell.
+///
+/// Returns `None` when they match, or a report listing the position and the
+/// expected and actual content of each cell that differs, the first 20 of
+/// them, or the sizes if those differ.
+pub fn diff_snapshots(expected: &str, actual: &str) -> Option<String> {
+    let expected = match SnapshotGrid::parse(expected) {
+        Ok(grid) => grid,
+        Err(error) => return Some(format!("expected snapshot is invalid: {error}")),
+    };
+    let actual = match SnapshotGrid::parse(actual) {
+        Ok(grid) => grid,
+        Err(error) => return Some(format!("actual snapshot is invalid: {error}")),
+    };
+    if (expected.width, expected.height) != (actual.width, actual.height) {
+        return Some(format!(
+            "size differs: expected {}x{}, got {}x{}",
+            expected.width, expected.height, actual.width, actual.height
+        ));
+    }
+
+    let describe = |(ch, attributes): &(char, String)| {
+        if attributes.is_empty() {
+            format!("{ch:?}")
+        } else {
+            format!("{ch:?} {attributes}")
+        }
+    };
+    let mismatches: Vec<String> = expected
+        .rows
+        .iter()
+        .zip(&actual.rows)
+        .enumerate()
+        .flat_map(|(y, (expected_row, actual_row))| {
+            expected_row
+                .iter()
+                .zip(actual_row)
+                .enumerate()
+                .filter(|(_, (expected, actual))| expected != actual)
+                .map(move |(x, (expected, actual))| {
+                    format!(
+                        "  ({x}, {y}): expected {}, got {}",
+                        describe(expected),
+                        describe(actual)
+                    )
+                })
+        })
+        .collect();
+    if mismatches.is_empty() {
+        return None;
+    }
+
+    let mut report = format!("{} cells differ:\n", mismatches.len());
+    for mismatch in mismatches.iter().take(MAX_REPORTED_MISMATCHES) {
+        report.push_str(mismatch);
+        report.push('\n');
+    }
+    if mismatches.len() > MAX_REPORTED_MISMATCHES {
+        let _ = writeln!(
+            report,
+            "  ... and {} more",
+            mismatches.len() - MAX_REPORTED_MISMATCHES
+        );
+    }
+    Some(report)
+}
+
+/// Asserts that `actual` matches the snapshot saved in the golden file at
+/// `path`, panicking with the [`diff_snapshots`] report if it doesn't.
+///
+/// A missing golden file is created from `actual`. Run the tests with the
+/// `RXTUI_UPDATE_SNAPSHOTS` environment variable set to overwrite golden
+/// files after an intended change.
+pub fn assert_snapshot(actual: &str, path: impl AsRef<Path>) {
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some();
+    check_snapshot(actual, path.as_ref(), update);
+}
+
+/// Compares `actual` with the golden file at `path` like [`assert_snapshot`],
+/// or overwrites the file when `update` is set
+fn check_snapshot(actual: &str, path: &Path, update: bool) {
+    match std::fs::read_to_string(path) {
+        Ok(expected) if !update => {
+            if let Some(report) = diff_snapshots(&expected, actual) {
+                panic!(
+                    "snapshot {} does not match:\n{report}\
+                     set {UPDATE_SNAPSHOTS_VAR}=1 to accept the new output",
+                    path.display()
+                );
+            }
+        }
+        Err(error) if error.kind() != io::ErrorKind::NotFound => {
+            panic!("failed to read snapshot {}: {error}", path.display());
+        }
+        _ => {
+            let written = match path.parent() {
+                Some(parent) => std::fs::create_dir_all(parent),
+                None => Ok(()),
+            }
+            .and_then(|()| std::fs::write(path, actual));
+            if let Err(error) = written {
+                panic!("failed to write snapshot {}: {error}", path.display());
+            }
+        }
+    }
+}
+
+/// Returns the colors and attributes of a cell as written in a snapshot,
+/// empty for an unstyled cell
+fn cell_attributes(cell: &Cell) -> String {
+    let mut attributes = Vec::new();
+    if let Some(fg) = cell.fg {
+        attributes.push(format!("fg={}", snapshot_color(fg)));
+    }
+    if let Some(bg) = cell.bg {
+        attributes.push(format!("bg={}", snapshot_color(bg)));
+    }
+
+    let style = &cell.style;
+    if style.bold {
+        attributes.push("bold".to_string());
+    }
+    if style.italic {
+        attributes.push("italic".to_string());
+    }
+    if style.underline {
+        let mut underline = "underline".to_string();
+        if style.underline_style != UnderlineStyle::default() {
+            let _ = write!(underline, "={:?}", style.underline_style);
+        }
+        if let Some(color) = style.underline_color {
+            let _ = write!(underline, ":{}", snapshot_color(color));
+        }
+        attributes.push(underline);
+    }
+    for (set, name) in [
+        (style.strikethrough, "strikethrough"),
+        (style.reverse, "reverse"),
+        (style.blink, "blink"),
+        (style.conceal, "conceal"),
+    ] {
+        if set {
+            attributes.push(name.to_string());
+        }
+    }
+    attributes.join(" ")
+}
+
+/// Writes a color by its variant name, or as hex for RGB colors
+fn snapshot_color(color: Color) -> String {
+    match color {
+        Color::Rgb(r, g, b) => format!("#{r:02x}{g:02x}{b:02x}"),
+        named => format!("{named:?}"),
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
@@ -458,6 +872,48 @@ mod tests {
         assert_eq!(updates.len(), 0); // No changes!
     }
 
+    #[test]
+    fn test_invalidate_reports_every_cell_once() {
+        let mut db = DoubleBuffer::new(4, 2);
+        db.back_buffer_mut().set_cell(1, 0, Cell::new('A'));
+
+        db.invalidate();
+        assert_eq!(db.diff().len(), 8);
+
+        db.swap();
+        db.back_buffer_mut().set_cell(1, 0, Cell::new('A'));
+        assert!(db.diff().is_empty());
+    }
+
+    #[test]
+    fn test_invert_toggles_reverse() {
+        let mut buffer = ScreenBuffer::new(2, 1);
+        buffer.invert();
+        assert!(buffer.get_cell(0, 0).unwrap().style.reverse);
+        assert!(buffer.get_cell(1, 0).unwrap().style.reverse);
+
+        buffer.invert();
+        assert!(!buffer.get_cell(0, 0).unwrap().style.reverse);
+    }
+
+    #[test]
+    fn test_toggling_reverse_repaints_cell() {
+        let mut db = DoubleBuffer::new(4, 1);
+        let reversed = TextStyle::default().reverse(true);
+        db.back_buffer_mut()
+            .write_styled_str(0, 0, "ab", Some(&reversed));
+        assert_eq!(db.diff().len(), 2);
+        db.swap();
+
+        // Same characters and colors, only the attribute differs
+        db.back_buffer_mut().write_styled_str(0, 0, "ab", None);
+        assert_eq!(db.diff().len(), 2);
+        db.swap();
+
+        db.back_buffer_mut().write_styled_str(0, 0, "ab", None);
+        assert!(db.diff().is_empty());
+    }
+
     #[test]
     fn test_screen_buffer_write_str() {
         let mut buffer = ScreenBuffer::new(20, 5);
@@ -470,6 +926,70 @@ mod tests {
         assert_eq!(buffer.get_cell(2, 1).unwrap().bg, Some(Color::Black));
     }
 
+    #[test]
+    fn test_write_str_keeps_background_beneath() {
+        let mut buffer = ScreenBuffer::new(6, 1);
+        buffer.fill_rect(0, 0, 6, 1, Cell::new(' ').with_bg(Color::Blue));
+        buffer.write_str(0, 0, "ab", Some(Color::White), None);
+        buffer.write_styled_str(2, 0, "中", None);
+        buffer.write_str(4, 0, "c", None, Some(Color::Red));
+
+        let bg = |x| buffer.get_cell(x, 0).unwrap().bg;
+        assert_eq!(buffer.get_cell(0, 0).unwrap().char, 'a');
+        assert_eq!(bg(0), Some(Color::Blue));
+        assert_eq!(bg(2), Some(Color::Blue));
+        assert_eq!(bg(3), Some(Color::Blue));
+        assert_eq!(bg(4), Some(Color::Red));
+    }
+
+    #[test]
+    fn test_to_plain_text_trims_and_skips_wide_padding() {
+        let mut buffer = ScreenBuffer::new(10, 4);
+        buffer.write_str(0, 0, "日本 ok", None, None);
+        buffer.write_str(2, 1, "x", None, None);
+
+        assert_eq!(buffer.to_plain_text(), "日本 ok\n  x");
+    }
+
+    #[test]
+    fn test_write_styled_str_underline_style() {
+        let mut buffer = ScreenBuffer::new(20, 5);
+        let style = TextStyle::default()
+            .underline_style(UnderlineStyle::Curly)
+            .underline_color(Color::Red);
+        buffer.write_styled_str(0, 0, "typo", Some(&style));
+
+        let cell = buffer.get_cell(0, 0).unwrap();
+        assert!(cell.style.underline);
+        assert_eq!(cell.style.underline_style, UnderlineStyle::Curly);
+        assert_eq!(cell.style.underline_color, Some(Color::Red));
+
+        // Plain underline keeps the default single style
+        let plain = CellStyle::from_text_style(&TextStyle::default().underline(true));
+        assert_eq!(plain.underline_style, UnderlineStyle::Single);
+        assert_eq!(plain.underline_color, None);
+    }
+
+    #[test]
+    fn test_write_styled_str_sgr_attributes() {
+        let mut buffer = ScreenBuffer::new(20, 5);
+        let style = TextStyle::default()
+            .bold(true)
+            .reverse(true)
+            .blink(true)
+            .conceal(true);
+        buffer.write_styled_str(0, 0, "pin", Some(&style));
+
+        let cell = buffer.get_cell(0, 0).unwrap();
+        assert!(cell.style.bold && cell.style.reverse && cell.style.blink && cell.style.conceal);
+
+        // Attributes compose when styles are merged
+        let blinking = CellStyle::from_text_style(&TextStyle::default().blink(true));
+        let reversed = CellStyle::from_text_style(&TextStyle::default().reverse(true));
+        let merged = blinking.merge_with(&reversed);
+        assert!(merged.blink && merged.reverse && !merged.conceal);
+    }
+
     #[test]
     fn test_no_flicker_scenario() {
         let mut db = DoubleBuffer::new(20, 5);
@@ -519,4 +1039,85 @@ mod tests {
         // Total: 6 changes
         assert!(actual_changes == 6);
     }
+
+    #[test]
+    fn test_snapshot_lists_styled_runs() {
+        let mut buffer = ScreenBuffer::new(6, 2);
+        buffer.write_str(0, 0, "ab", Some(Color::Red), None);
+        buffer.write_str(3, 1, "漢", None, Some(Color::Rgb(0x1e, 0x1e, 0x2e)));
+        buffer.get_cell_mut(5, 0).unwrap().style = CellStyle {
+            bold: true,
+            underline: true,
+            underline_style: UnderlineStyle::Curly,
+            underline_color: Some(Color::Yellow),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            buffer.to_snapshot(),
+            "size 6x2\n\
+             |ab    |\n\
+             |   漢  |\n\
+             row 0 col 0-1: fg=Red\n\
+             row 0 col 5: bold underline=Curly:Yellow\n\
+             row 1 col 3-4: bg=#1e1e2e\n"
+        );
+        assert_eq!(
+            diff_snapshots(&buffer.to_snapshot(), &buffer.to_snapshot()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots_reports_changed_cells() {
+        let mut expected = ScreenBuffer::new(4, 1);
+        expected.write_str(0, 0, "ok", Some(Color::Green), None);
+        let mut actual = ScreenBuffer::new(4, 1);
+        actual.write_str(0, 0, "no", Some(Color::Green), None);
+        actual.get_cell_mut(1, 0).unwrap().fg = Some(Color::Red);
+
+        assert_eq!(
+            diff_snapshots(&expected.to_snapshot(), &actual.to_snapshot()).unwrap(),
+            "2 cells differ:\n  \
+             (0, 0): expected 'o' fg=Green, got 'n' fg=Green\n  \
+             (1, 0): expected 'k' fg=Green, got 'o' fg=Red\n"
+        );
+        assert_eq!(
+            diff_snapshots(
+                &expected.to_snapshot(),
+                &ScreenBuffer::new(3, 1).to_snapshot()
+            ),
+            Some("size differs: expected 4x1, got 3x1".to_string())
+        );
+        assert!(
+            diff_snapshots("size 4x1\n|ok|\n", &actual.to_snapshot())
+                .unwrap()
+                .starts_with("expected snapshot is invalid")
+        );
+    }
+
+    #[test]
+    fn test_assert_snapshot_creates_then_compares_golden_file() {
+        let path = std::env::temp_dir()
+            .join(format!("rxtui-snapshot-{}", std::process::id()))
+            .join("golden.snap");
+        let mut buffer = ScreenBuffer::new(3, 1);
+        buffer.write_str(0, 0, "hi", None, None);
+
+        check_snapshot(&buffer.to_snapshot(), &path, false);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            buffer.to_snapshot()
+        );
+        check_snapshot(&buffer.to_snapshot(), &path, false);
+
+        buffer.write_str(0, 0, "yo", None, None);
+        let changed = buffer.to_snapshot();
+        let result = std::panic::catch_unwind(|| check_snapshot(&changed, &path, false));
+        check_snapshot(&changed, &path, true);
+        let updated = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert!(result.is_err());
+        assert_eq!(updated, changed);
+    }
 }
@@ -0,0 +1,358 @@
+//! Plotting at sub-cell resolution with braille patterns.
+//!
+//! Each terminal cell holds a braille glyph with a 2x4 grid of dots, so a
+//! [`BrailleCanvas`] of `width` x `height` cells has `2 * width` x
+//! `4 * height` dots to draw on. Dots are addressed from the top left corner.
+//! A canvas converts into a [`Node`] that takes exactly its size in cells.
+//!
+//! ```
+//! use rxtui::canvas::{self, BrailleCanvas};
+//!
+//! let mut canvas = BrailleCanvas::new(2, 1);
+//! canvas.line(0, 3, 3, 0);
+//! assert_eq!(canvas.rows(), ["⡠⠊"]);
+//!
+//! // Two samples per cell, scaled between the smallest and largest
+//! assert_eq!(canvas::sparkline(&[0.0, 1.0, 2.0, 3.0]).rows(), ["⡠⠊"]);
+//! ```
+
+use crate::node::{Div, Node, Text};
+use crate::style::{Color, Direction};
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Code point of the empty braille pattern; the dot bits are added to it
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// Bit of each dot in a braille pattern, indexed by `[row][column]`
+const DOT_BITS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A grid of cells drawn with braille glyphs, 2x4 dots per cell
+///
+/// Dots outside the canvas are ignored, so shapes can be drawn partly off
+/// its edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrailleCanvas {
+    width: u16,
+    height: u16,
+    cells: Vec<u8>,
+    color: Option<Color>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl BrailleCanvas {
+    /// Creates an empty canvas of `width` x `height` cells
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![0; width as usize * height as usize],
+            color: None,
+        }
+    }
+
+    /// Creates a canvas from rows of dots, `true` for a raised dot
+    ///
+    /// The canvas is as many cells as it takes to hold the longest row and
+    /// every row, with the leftover dots of partial cells lowered.
+    pub fn from_grid<R: AsRef<[bool]>>(grid: &[R]) -> Self {
+        let dot_width = grid.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        let width = dot_width.div_ceil(2).min(u16::MAX as usize) as u16;
+        let height = grid.len().div_ceil(4).min(u16::MAX as usize) as u16;
+
+        let mut canvas = Self::new(width, height);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, &raised) in row.as_ref().iter().enumerate() {
+                if raised {
+                    canvas.set(x, y);
+                }
+            }
+        }
+        canvas
+    }
+
+    /// Sets the color the dots are drawn in
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the width in cells
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height in cells
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the width in dots, two per cell
+    pub fn dot_width(&self) -> usize {
+        self.width as usize * 2
+    }
+
+    /// Returns the height in dots, four per cell
+    pub fn dot_height(&self) -> usize {
+        self.height as usize * 4
+    }
+
+    /// Raises the dot at `(x, y)`
+    pub fn set(&mut self, x: usize, y: usize) {
+        if let Some((index, bit)) = self.locate(x, y) {
+            self.cells[index] |= bit;
+        }
+    }
+
+    /// Lowers the dot at `(x, y)`
+    pub fn unset(&mut self, x: usize, y: usize) {
+        if let Some((index, bit)) = self.locate(x, y) {
+            self.cells[index] &= !bit;
+        }
+    }
+
+    /// Returns whether the dot at `(x, y)` is raised
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.locate(x, y)
+            .is_some_and(|(index, bit)| self.cells[index] & bit != 0)
+    }
+
+    /// Lowers every dot
+    pub fn clear(&mut self) {
+        self.cells.fill(0);
+    }
+
+    /// Raises every dot in `points`
+    pub fn points(&mut self, points: impl IntoIterator<Item = (usize, usize)>) {
+        for (x, y) in points {
+            self.set(x, y);
+        }
+    }
+
+    /// Raises the dots of a straight line between two points, both included
+    pub fn line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize) {
+        // Bresenham's algorithm, in signed coordinates so it can step either way
+        let (mut x, mut y) = (x0 as i64, y0 as i64);
+        let (x1, y1) = (x1 as i64, y1 as i64);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let step_x = if x < x1 { 1 } else { -1 };
+        let step_y = if y < y1 { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        loop {
+            self.set(x as usize, y as usize);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Plots `data` as a line chart filling the canvas
+    ///
+    /// Each value takes one dot column, left to right, and consecutive values
+    /// are joined by lines. Values are scaled so the smallest sits on the
+    /// bottom row and the largest on the top; if they are all equal the line
+    /// runs along the bottom. NaN and infinite values leave a gap, and values
+    /// past the right edge are left out.
+    pub fn plot(&mut self, data: &[f64]) {
+        let Some((min, max)) = finite_bounds(data) else {
+            return;
+        };
+        let bottom = self.dot_height().saturating_sub(1);
+
+        let mut previous = None;
+        for (x, &value) in data.iter().enumerate().take(self.dot_width()) {
+            if !value.is_finite() {
+                previous = None;
+                continue;
+            }
+            let y = bottom - scale(value, min, max, bottom).unwrap_or(0);
+            match previous {
+                Some((px, py)) => self.line(px, py, x, y),
+                None => self.set(x, y),
+            }
+            previous = Some((x, y));
+        }
+    }
+
+    /// Returns the glyphs of each row of cells, top to bottom
+    ///
+    /// Cells without raised dots are the blank braille pattern, which is as
+    /// wide as the others.
+    pub fn rows(&self) -> Vec<String> {
+        if self.width == 0 {
+            return vec![String::new(); self.height as usize];
+        }
+        self.cells
+            .chunks(self.width as usize)
+            .map(|row| row.iter().map(|&bits| braille_glyph(bits)).collect())
+            .collect()
+    }
+
+    /// Returns the cell index and pattern bit of a dot, if it is on the canvas
+    fn locate(&self, x: usize, y: usize) -> Option<(usize, u8)> {
+        if x >= self.dot_width() || y >= self.dot_height() {
+            return None;
+        }
+        let index = (y / 4) * self.width as usize + x / 2;
+        Some((index, DOT_BITS[y % 4][x % 2]))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the braille glyph with the dots of `bits` raised
+///
+/// Bits 0 to 5 are the dots of the left and right columns of the top three
+/// rows, column by column, and bits 6 and 7 are the bottom row's.
+pub fn braille_glyph(bits: u8) -> char {
+    char::from_u32(BRAILLE_BASE + bits as u32).unwrap_or(' ')
+}
+
+/// Plots `data` on a one-row canvas, two values per cell
+///
+/// A thin wrapper over [`BrailleCanvas::plot`] for inline metrics. Build a
+/// taller canvas and plot into it for more vertical resolution.
+pub fn sparkline(data: &[f64]) -> BrailleCanvas {
+    let width = data.len().div_ceil(2).min(u16::MAX as usize) as u16;
+    let mut canvas = BrailleCanvas::new(width, 1);
+    canvas.plot(latest(data, canvas.dot_width()));
+    canvas
+}
+
+/// Returns the last `count` values of `data`, or all of them if there are fewer
+pub(crate) fn latest(data: &[f64], count: usize) -> &[f64] {
+    &data[data.len().saturating_sub(count)..]
+}
+
+/// Returns the smallest and largest finite values, if there are any
+pub(crate) fn finite_bounds(data: &[f64]) -> Option<(f64, f64)> {
+    data.iter().copied().filter(|value| value.is_finite()).fold(
+        None,
+        |bounds, value| match bounds {
+            None => Some((value, value)),
+            Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+        },
+    )
+}
+
+/// Returns the step from 0 to `steps` nearest to `value` scaled between `min`
+/// and `max`, clamped to that range, or `None` if the range is empty
+pub(crate) fn scale(value: f64, min: f64, max: f64, steps: usize) -> Option<usize> {
+    if max <= min {
+        return None;
+    }
+    let scaled = (value - min) / (max - min) * steps as f64;
+    Some(scaled.round().clamp(0.0, steps as f64) as usize)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl From<BrailleCanvas> for Node {
+    fn from(canvas: BrailleCanvas) -> Self {
+        let mut div = Div::new()
+            .direction(Direction::Vertical)
+            .width(canvas.width)
+            .height(canvas.height);
+        for row in canvas.rows() {
+            let mut text = Text::new(row);
+            if let Some(color) = canvas.color {
+                text = text.color(color);
+            }
+            div = div.child(text.into());
+        }
+        div.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dots_map_to_braille_bits() {
+        let mut canvas = BrailleCanvas::new(1, 1);
+        assert_eq!(canvas.rows(), ["\u{2800}"]);
+
+        canvas.set(0, 0);
+        assert_eq!(canvas.rows(), ["⠁"]);
+        canvas.set(1, 3);
+        assert_eq!(canvas.rows(), ["⢁"]);
+
+        canvas.points((0..2).flat_map(|x| (0..4).map(move |y| (x, y))));
+        assert_eq!(canvas.rows(), ["⣿"]);
+        canvas.unset(0, 3);
+        assert!(!canvas.get(0, 3));
+        assert_eq!(canvas.rows(), ["⢿"]);
+    }
+
+    #[test]
+    fn test_dots_off_the_canvas_are_ignored() {
+        let mut canvas = BrailleCanvas::new(1, 1);
+        canvas.set(2, 0);
+        canvas.set(0, 4);
+        canvas.line(1, 3, 9, 3);
+        assert_eq!(canvas.rows(), ["⢀"]);
+    }
+
+    #[test]
+    fn test_from_grid_rounds_up_to_whole_cells() {
+        let canvas = BrailleCanvas::from_grid(&[
+            vec![true, false, true],
+            vec![false, true, false],
+            vec![false; 3],
+            vec![false; 3],
+            vec![true],
+        ]);
+        assert_eq!((canvas.width(), canvas.height()), (2, 2));
+        assert_eq!(canvas.rows(), ["⠑⠁", "⠁\u{2800}"]);
+    }
+
+    #[test]
+    fn test_sparkline_scales_and_skips_non_finite() {
+        assert_eq!(sparkline(&[0.0, 1.0, 2.0, 3.0]).rows(), ["⡠⠊"]);
+        assert_eq!(sparkline(&[5.0, 5.0, 5.0]).rows(), ["⣀⡀"]);
+        assert_eq!(sparkline(&[0.0, f64::NAN, 3.0]).rows(), ["⡀⠁"]);
+        assert_eq!(sparkline(&[]).rows(), [""]);
+    }
+
+    #[test]
+    fn test_canvas_node_takes_its_cell_size() {
+        let node: Node = BrailleCanvas::new(3, 2).color(Color::Green).into();
+        let Node::Div(div) = node else {
+            panic!("expected a div");
+        };
+        assert_eq!(div.children.len(), 2);
+        let Node::Text(row) = &div.children[0] else {
+            panic!("expected a text row");
+        };
+        assert_eq!(row.content, "\u{2800}".repeat(3));
+    }
+}
@@ -23,12 +23,40 @@ pub enum Action {
     /// Exit the application
     #[default]
     Exit,
+
+    /// Exit the application, handing a value back to `App::run_with_value`
+    ExitWith(Box<dyn Any + Send>),
+}
+
+/// How a component relates to the instance rendered at its position last pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reconcile {
+    /// Nothing was rendered there; the component mounts fresh
+    Mount,
+
+    /// Same component with the same props; state and effects carry over
+    Keep,
+
+    /// Same component with changed props; state carries over, effects restart
+    Restart,
+
+    /// A different component type; the old one's state is dropped
+    Remount,
 }
 
 /// Unique identifier for components in the tree
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ComponentId(pub String);
 
+/// Error raised while rendering a component's view.
+///
+/// Produced by a failing [`Component::try_view`] or a panic inside `view`, and
+/// handed to the nearest [`Component::fallback`] above the failing component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderError {
+    message: String,
+}
+
 /// Trait for messages that can be sent between components
 pub trait Message: Any + Send + Sync + 'static {
     fn as_any(&self) -> &dyn Any;
@@ -237,6 +265,37 @@ pub trait Component: 'static {
 
     fn view(&self, ctx: &Context) -> Node;
 
+    /// Fallible version of `view`.
+    ///
+    /// Defaults to calling `view`. Override it to fail with a [`RenderError`]
+    /// that the nearest error boundary renders in place of this component.
+    fn try_view(&self, ctx: &Context) -> Result<Node, RenderError> {
+        Ok(self.view(ctx))
+    }
+
+    /// Makes this component an error boundary.
+    ///
+    /// Called when the view of this component, or of any component beneath
+    /// it, panics or returns an error. The returned node is rendered in place
+    /// of this component's whole subtree, keeping the rest of the UI alive.
+    /// The default `None` passes the error on to the next boundary up; an
+    /// error no boundary handles crashes the app.
+    ///
+    /// # Caveats
+    ///
+    /// - Panics are caught with `std::panic::catch_unwind`, which does nothing
+    ///   when the binary is built with `panic = "abort"`.
+    /// - The panic hook still runs first. The default hook prints to stderr,
+    ///   so the screen is redrawn in full afterwards; install a quiet hook
+    ///   with `std::panic::set_hook` to avoid the flicker.
+    /// - Only views are guarded. A panic in `update` or an effect is not caught.
+    /// - Views run on every pass of the event loop, so a view that keeps
+    ///   failing keeps calling this, and state written before the failure stays.
+    #[allow(unused_variables)]
+    fn fallback(&self, ctx: &Context, error: &RenderError) -> Option<Node> {
+        None
+    }
+
     /// Define effects for this component
     ///
     /// Effects are async tasks that run outside the main event loop.
@@ -288,11 +347,58 @@ pub trait Component: 'static {
         vec![]
     }
 
+    /// Identity key distinguishing this component from its siblings.
+    ///
+    /// By default a component is identified by its position among its
+    /// siblings, so inserting or removing an earlier sibling hands its state
+    /// to a different instance. A keyed component is identified by its key
+    /// instead and keeps its state, local cells and effects across reorders,
+    /// while its rendered elements move along with their focus and scroll
+    /// position. Keys only need to be unique among siblings.
+    fn key(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether props differ from the instance rendered here last pass.
+    ///
+    /// `previous` always has the same type and identity as `self`. Returning
+    /// `true` restarts the component's effects so they see the new props;
+    /// state is kept either way. For components implementing `PartialEq`,
+    /// [`props_differ`] is a ready-made implementation.
+    #[allow(unused_variables)]
+    fn props_changed(&self, previous: &dyn Component) -> bool {
+        false
+    }
+
+    /// Called once when the component enters the tree.
+    ///
+    /// Runs before the component's first `update` and `view`, so messages
+    /// sent here with `ctx.send` are handled before it first renders, and
+    /// before its effects are spawned. A good place to kick off initial data
+    /// loads. A component replaced by one of another type at the same
+    /// position mounts fresh, after the old one unmounts.
+    #[allow(unused_variables)]
+    fn on_mount(&self, ctx: &Context) {}
+
+    /// Called once when the component leaves the tree.
+    ///
+    /// Runs after the pass in which the component was last left out, before
+    /// its effects are stopped, with its state still readable. Children
+    /// unmount before their parents. Components still mounted when the app
+    /// stops unmount then, the root last. A good place to release resources.
+    #[allow(unused_variables)]
+    fn on_unmount(&self, ctx: &Context) {}
+
     /// Get the TypeId of this component for identity tracking
     fn type_id(&self) -> TypeId {
         self.as_any().type_id()
     }
 
+    /// Get the type name of this component for diagnostics and semantic output
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
     fn as_any(&self) -> &dyn Any;
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -326,6 +432,39 @@ impl Action {
     pub fn exit() -> Self {
         Action::Exit
     }
+
+    /// Create an ExitWith action that terminates the application and returns
+    /// `value` from [`App::run_with_value`](crate::App::run_with_value)
+    #[inline]
+    pub fn exit_with(value: impl Any + Send) -> Self {
+        Action::ExitWith(Box::new(value))
+    }
+}
+
+impl RenderError {
+    /// Creates an error with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error from the payload of a caught panic.
+    pub(crate) fn from_panic(payload: &(dyn Any + Send)) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "view panicked".to_string()
+        };
+        Self { message }
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
 }
 
 impl ComponentId {
@@ -336,12 +475,66 @@ impl ComponentId {
     pub fn child(&self, index: usize) -> Self {
         Self(format!("{}.{}", self.0, index))
     }
+
+    /// Returns the id of a keyed child, which doesn't depend on its position
+    ///
+    /// Dots in the key are escaped, along with `%`, so the key stays a single
+    /// segment of the path.
+    pub fn keyed(&self, key: &str) -> Self {
+        let key = key.replace('%', "%25").replace('.', "%2E");
+        Self(format!("{}.#{}", self.0, key))
+    }
+
+    /// Returns whether this id is `ancestor` or lies beneath it
+    pub fn is_within(&self, ancestor: &ComponentId) -> bool {
+        self.0
+            .strip_prefix(&ancestor.0)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Compares a component's props with the previous instance using `PartialEq`.
+///
+/// Meant as the body of [`Component::props_changed`]:
+///
+/// ```ignore
+/// fn props_changed(&self, previous: &dyn Component) -> bool {
+///     props_differ(self, previous)
+/// }
+/// ```
+pub fn props_differ<C: Component + PartialEq>(current: &C, previous: &dyn Component) -> bool {
+    previous
+        .as_any()
+        .downcast_ref::<C>()
+        .is_none_or(|previous| previous != current)
+}
+
+/// Decides how a component carries over from the instance rendered at its id last pass
+pub(crate) fn reconcile(previous: Option<&dyn Component>, next: &dyn Component) -> Reconcile {
+    match previous {
+        None => Reconcile::Mount,
+        Some(previous) if previous.type_id() != next.type_id() => Reconcile::Remount,
+        Some(previous) if next.props_changed(previous) => Reconcile::Restart,
+        Some(_) => Reconcile::Keep,
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
 
+impl std::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for RenderError {}
+
 impl Default for ComponentId {
     fn default() -> Self {
         Self("0".to_string())
@@ -360,3 +553,110 @@ where
         Box::new(self.clone())
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_error_from_panic_payload() {
+        let payload = std::panic::catch_unwind(|| panic!("widget {} broke", 3)).unwrap_err();
+        assert_eq!(
+            RenderError::from_panic(payload.as_ref()).message(),
+            "widget 3 broke"
+        );
+
+        let payload = std::panic::catch_unwind(|| std::panic::panic_any(42)).unwrap_err();
+        assert_eq!(
+            RenderError::from_panic(payload.as_ref()).message(),
+            "view panicked"
+        );
+    }
+
+    #[test]
+    fn test_first_exit_value_wins() {
+        use std::sync::{Arc, atomic::AtomicBool};
+
+        let Action::ExitWith(value) = Action::exit_with(7u8) else {
+            panic!("expected ExitWith");
+        };
+        assert_eq!(value.downcast_ref::<u8>(), Some(&7));
+
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        assert!(!ctx.exit_requested());
+        ctx.quit_with(String::from("picked"));
+        ctx.request_exit(Box::new(()));
+        assert!(ctx.exit_requested());
+
+        let value = ctx.take_exit_value().unwrap();
+        assert_eq!(value.downcast_ref::<String>().unwrap(), "picked");
+        assert!(!ctx.exit_requested());
+    }
+
+    #[derive(PartialEq)]
+    struct Label(&'static str);
+
+    struct Spacer;
+
+    impl Component for Label {
+        fn view(&self, _ctx: &Context) -> Node {
+            Node::div()
+        }
+
+        fn props_changed(&self, previous: &dyn Component) -> bool {
+            props_differ(self, previous)
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    impl Component for Spacer {
+        fn view(&self, _ctx: &Context) -> Node {
+            Node::div()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_keyed_ids() {
+        let parent = ComponentId::new("0.1");
+        let keyed = parent.keyed("row-3");
+        assert_eq!(keyed.0, "0.1.#row-3");
+        assert!(keyed.child(0).is_within(&keyed));
+        assert!(keyed.is_within(&parent));
+        assert!(!ComponentId::new("0.10").is_within(&parent));
+
+        // A key with a dot is one segment, not a parent of another key
+        let dotted = parent.keyed("a.b");
+        assert_eq!(dotted.0, "0.1.#a%2Eb");
+        assert!(!dotted.is_within(&parent.keyed("a")));
+        assert_ne!(dotted, parent.keyed("a%2Eb"));
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let label = Label("a");
+        assert_eq!(reconcile(None, &label), Reconcile::Mount);
+        assert_eq!(reconcile(Some(&Label("a")), &label), Reconcile::Keep);
+        assert_eq!(reconcile(Some(&Label("b")), &label), Reconcile::Restart);
+        assert_eq!(reconcile(Some(&Spacer), &label), Reconcile::Remount);
+        assert_eq!(reconcile(Some(&Spacer), &Spacer), Reconcile::Keep);
+    }
+}
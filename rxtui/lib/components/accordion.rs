@@ -0,0 +1,285 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Node, RichText, Role};
+use crate::style::{Dimension, Direction, Style, TextStyle};
+use crate::{Context, Div};
+use std::any::Any;
+use std::collections::BTreeSet;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// How many sections of an [`Accordion`] can be expanded at once
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccordionMode {
+    /// Sections expand and collapse independently
+    #[default]
+    Multiple,
+
+    /// Expanding a section collapses the one that was open
+    Single,
+}
+
+/// Messages for Accordion component
+#[derive(Debug, Clone)]
+pub enum AccordionMsg {
+    /// Expand or collapse the section at an index
+    Toggle(usize),
+}
+
+/// State for Accordion component
+#[derive(Debug, Clone, Default)]
+pub struct AccordionState {
+    /// Indices of the expanded sections, or `None` until the first toggle
+    pub expanded: Option<BTreeSet<usize>>,
+}
+
+/// A stack of collapsible sections, each a header above a body
+///
+/// Clicking a header, or pressing Enter or Space while it is focused, shows
+/// or hides the section's body. A collapsed body is not rendered, so it takes
+/// no space in the layout. Sections start collapsed unless marked with
+/// `expanded`, and `on_toggle` is called with the section's index and whether
+/// it is now expanded.
+///
+/// In [`AccordionMode::Single`], expanding a section collapses the open one,
+/// which is reported to `on_toggle` as well.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let settings = Accordion::new()
+///     .mode(AccordionMode::Single)
+///     .section("Display", node! { text("Theme: dark") })
+///     .section("Keys", node! { text("Vim bindings: on") })
+///     .expanded(0)
+///     .on_toggle(|index, expanded| println!("{index}: {expanded}"));
+/// ```
+#[derive(Default)]
+pub struct Accordion {
+    sections: Vec<(String, Node)>,
+    initially_expanded: BTreeSet<usize>,
+    mode: AccordionMode,
+    on_toggle: Option<Box<dyn Fn(usize, bool)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: AccordionState
+//--------------------------------------------------------------------------------------------------
+
+impl AccordionState {
+    /// Toggles a section of `len`, starting from `initial` if nothing was toggled yet.
+    ///
+    /// Returns every section whose state changed, with whether it is now expanded.
+    pub(crate) fn toggle(
+        &mut self,
+        index: usize,
+        len: usize,
+        mode: AccordionMode,
+        initial: &BTreeSet<usize>,
+    ) -> Vec<(usize, bool)> {
+        if index >= len {
+            return Vec::new();
+        }
+
+        let expanded = self.expanded.get_or_insert_with(|| initial.clone());
+        let mut changes = Vec::new();
+        if expanded.remove(&index) {
+            changes.push((index, false));
+        } else {
+            if mode == AccordionMode::Single {
+                changes.extend(expanded.iter().map(|&other| (other, false)));
+                expanded.clear();
+            }
+            expanded.insert(index);
+            changes.push((index, true));
+        }
+        changes
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Accordion
+//--------------------------------------------------------------------------------------------------
+
+impl Accordion {
+    /// Creates an accordion without sections
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a section with a header showing `title`
+    pub fn section(mut self, title: impl Into<String>, body: impl Into<Node>) -> Self {
+        self.sections.push((title.into(), body.into()));
+        self
+    }
+
+    /// Marks the section at `index` as expanded until the user toggles a section
+    pub fn expanded(mut self, index: usize) -> Self {
+        self.initially_expanded.insert(index);
+        self
+    }
+
+    /// Sets whether one or several sections can be expanded (several by default)
+    pub fn mode(mut self, mode: AccordionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the callback called with a section's index and whether it is now expanded
+    pub fn on_toggle(mut self, callback: impl Fn(usize, bool) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the expanded sections for the current state
+    fn expanded_sections(&self, state: &AccordionState) -> BTreeSet<usize> {
+        match &state.expanded {
+            Some(expanded) => expanded.clone(),
+            None => match self.mode {
+                // Only the first marked section opens when just one may be open
+                AccordionMode::Single => self.initially_expanded.iter().take(1).copied().collect(),
+                AccordionMode::Multiple => self.initially_expanded.clone(),
+            },
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(AccordionMsg::Toggle(index)) = msg.downcast::<AccordionMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<AccordionState>();
+        let initial = self.expanded_sections(&state);
+        let changes = state.toggle(*index, self.sections.len(), self.mode, &initial);
+
+        if let Some(callback) = &self.on_toggle {
+            for (index, expanded) in changes {
+                callback(index, expanded);
+            }
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<AccordionState>();
+        let expanded = self.expanded_sections(&state);
+        let palette = &ctx.theme().palette;
+
+        let header_style = Style {
+            direction: Some(Direction::Horizontal),
+            width: Some(Dimension::Percentage(1.0)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        };
+
+        let mut accordion = Div::new()
+            .direction(Direction::Vertical)
+            .width_fraction(1.0);
+
+        for (index, (title, body)) in self.sections.iter().enumerate() {
+            let open = expanded.contains(&index);
+            let marker = if open { "▾ " } else { "▸ " };
+            let label = RichText::new()
+                .styled(marker, TextStyle::default().color(palette.primary))
+                .styled(title, TextStyle::default().color(palette.foreground));
+
+            let header = Div::new()
+                .style(header_style.clone())
+                .focus_style(Style::default().background(palette.selection_background))
+                .focusable(true)
+                .role(Role::Button)
+                .label(title.clone())
+                .on_click(ctx.handler(AccordionMsg::Toggle(index)))
+                .on_key(Key::Enter, ctx.handler(AccordionMsg::Toggle(index)))
+                .on_char(' ', ctx.handler(AccordionMsg::Toggle(index)))
+                .child(label.into());
+            accordion = accordion.child(header.into());
+
+            if open {
+                accordion = accordion.child(body.clone());
+            }
+        }
+
+        accordion.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Accordion {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Accordion::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Accordion::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_multiple_sections() {
+        let mut state = AccordionState::default();
+        let initial = BTreeSet::from([1]);
+
+        assert_eq!(
+            state.toggle(0, 3, AccordionMode::Multiple, &initial),
+            vec![(0, true)]
+        );
+        assert_eq!(
+            state.toggle(1, 3, AccordionMode::Multiple, &initial),
+            vec![(1, false)]
+        );
+        assert_eq!(state.expanded, Some(BTreeSet::from([0])));
+        assert!(
+            state
+                .toggle(5, 3, AccordionMode::Multiple, &initial)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_single_mode_collapses_open_section() {
+        let accordion = Accordion::new()
+            .mode(AccordionMode::Single)
+            .section("a", Node::text("a"))
+            .section("b", Node::text("b"))
+            .expanded(0)
+            .expanded(1);
+        let mut state = AccordionState::default();
+
+        let initial = accordion.expanded_sections(&state);
+        assert_eq!(initial, BTreeSet::from([0]));
+        assert_eq!(
+            state.toggle(1, 2, AccordionMode::Single, &initial),
+            vec![(0, false), (1, true)]
+        );
+        assert_eq!(
+            state.toggle(1, 2, AccordionMode::Single, &initial),
+            vec![(1, false)]
+        );
+        assert_eq!(state.expanded, Some(BTreeSet::new()));
+    }
+}
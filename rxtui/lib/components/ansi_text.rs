@@ -0,0 +1,134 @@
+use crate::Context;
+use crate::ansi;
+use crate::component::Component;
+use crate::node::{Div, Node, RichText};
+use crate::style::{Direction, TextWrap};
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Text colored with ANSI escape sequences, such as a command's output
+///
+/// The text is parsed with [`ansi::parse`], so SGR colors and attributes are
+/// shown as styles, other escape sequences and control characters are
+/// dropped, and each line of the text becomes a row. Uncolored text takes the
+/// color of the element around it.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let output = std::process::Command::new("ls")
+///     .arg("--color=always")
+///     .output()?;
+/// let listing = AnsiText::new(String::from_utf8_lossy(&output.stdout));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnsiText {
+    raw: String,
+    wrap: Option<TextWrap>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl AnsiText {
+    /// Creates a view of `raw`, escape sequences included
+    pub fn new(raw: impl Into<String>) -> Self {
+        Self {
+            raw: raw.into(),
+            wrap: None,
+        }
+    }
+
+    /// Wraps lines longer than the available width instead of cutting them off
+    pub fn wrap(mut self, wrap: TextWrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Builds one row per line of the text
+    fn render<T: From<RichText> + From<Div<T>>>(&self) -> Div<T> {
+        let lines = ansi::parse(&self.raw)
+            .into_iter()
+            .map(|line| match self.wrap {
+                Some(wrap) => line.wrap(wrap).into(),
+                None => line.into(),
+            });
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .height_content()
+            .children(lines.collect())
+    }
+
+    fn view(&self, _ctx: &Context) -> Node {
+        self.render::<Node>().into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for AnsiText {
+    fn view(&self, ctx: &Context) -> Node {
+        AnsiText::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::ScreenBuffer;
+    use crate::style::Color;
+    use crate::vdom::VDom;
+    use crate::vnode::VNode;
+
+    #[test]
+    fn test_colored_output_draws_one_row_per_line() {
+        let output = AnsiText::new("\x1b[32mok\x1b[0m build\n\n\x1b[1;31mFAILED\x1b[0m test\n");
+        let node: VNode = output.render().into();
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(20, 5);
+
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        assert_eq!(root.borrow().height, 3);
+        let mut buffer = ScreenBuffer::new(20, 5);
+        crate::app::renderer::render_node_to_buffer(
+            &root.borrow(),
+            &mut buffer,
+            &crate::bounds::Rect::new(0, 0, 20, 5),
+            None,
+        );
+
+        let row = |y| -> String {
+            (0..12)
+                .map(|x| buffer.get_cell(x, y).unwrap().char)
+                .collect()
+        };
+        assert_eq!(row(0), "ok build    ");
+        assert_eq!(row(2), "FAILED test ");
+        assert_eq!(buffer.get_cell(0, 0).unwrap().fg, Some(Color::Green));
+        assert_eq!(buffer.get_cell(3, 0).unwrap().fg, None);
+        assert_eq!(buffer.get_cell(0, 2).unwrap().fg, Some(Color::Red));
+        assert!(buffer.get_cell(0, 2).unwrap().style.bold);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::spinner::Spinner;
+use crate::key::Key;
+use crate::node::{Node, Role, Text};
+use crate::style::{Border, BorderStyle, Color, Direction, Spacing, Style};
+use crate::{Context, Div};
+use std::any::Any;
+use std::sync::Arc;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// How prominently a [`Button`] is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ButtonVariant {
+    /// The main action of a view, in the palette's primary color
+    Primary,
+
+    /// An ordinary action, in the foreground color
+    #[default]
+    Secondary,
+
+    /// A destructive action, in the palette's error color
+    Danger,
+}
+
+/// Messages for Button component
+#[derive(Debug, Clone)]
+pub enum ButtonMsg {
+    /// The button was clicked or activated from the keyboard
+    Press,
+}
+
+/// A bordered, focusable button with a label
+///
+/// Clicking the button, or pressing Enter or Space while it is focused, calls
+/// `on_click`. Its border and label take the variant's color, the border
+/// follows the theme's focus and hover styles, and it thickens while the
+/// mouse holds the button down.
+///
+/// A disabled button is dimmed, can't be focused and ignores clicks. A loading
+/// button shows a spinner before its label and ignores presses too, but stays
+/// focusable so focus doesn't jump away while the work runs.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let save = Button::new("Save")
+///     .variant(ButtonVariant::Primary)
+///     .on_click(|| println!("saved"))
+///     .disabled(!dirty);
+/// ```
+pub struct Button {
+    label: String,
+    variant: ButtonVariant,
+    disabled: bool,
+    loading: bool,
+    on_click: Option<Box<dyn Fn()>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Button {
+    /// Creates a secondary button showing `label`
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            variant: ButtonVariant::default(),
+            disabled: false,
+            loading: false,
+            on_click: None,
+        }
+    }
+
+    /// Sets the variant (secondary by default)
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the callback called when the button is pressed
+    pub fn on_click(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_click = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets whether the button is dimmed and ignores presses
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets whether the button shows a spinner and ignores presses
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Calls `on_click` unless the button is disabled or loading
+    ///
+    /// Returns whether the callback was called.
+    pub(crate) fn press(&self) -> bool {
+        if self.disabled || self.loading {
+            return false;
+        }
+        match &self.on_click {
+            Some(callback) => {
+                callback();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the color of the label and border
+    fn color(&self, ctx: &Context) -> Color {
+        let palette = &ctx.theme().palette;
+        if self.disabled {
+            return palette.muted;
+        }
+        match self.variant {
+            ButtonVariant::Primary => palette.primary,
+            ButtonVariant::Secondary => palette.foreground,
+            ButtonVariant::Danger => palette.error,
+        }
+    }
+
+    fn update(&self, _ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        if let Some(ButtonMsg::Press) = msg.downcast::<ButtonMsg>() {
+            self.press();
+        }
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        self.render(ctx).into()
+    }
+
+    /// Builds the button container
+    pub(crate) fn render(&self, ctx: &Context) -> Div<Node> {
+        let theme = ctx.theme();
+        let color = self.color(ctx);
+
+        let mut label = Text::new(self.label.clone()).color(color);
+        if self.variant != ButtonVariant::Secondary && !self.disabled {
+            label = label.bold();
+        }
+
+        let mut button = Div::new()
+            .direction(Direction::Horizontal)
+            .padding(Spacing::horizontal(1))
+            .gap(1)
+            .border_with(Border::with_style(theme.border_style, color))
+            .role(Role::Button)
+            .label(self.label.clone());
+
+        if self.loading {
+            button = button.child(Node::Component(Arc::new(Spinner::new().color(color))));
+        }
+        button = button.child(label.into());
+
+        if self.disabled {
+            return button;
+        }
+
+        if let Some(focus) = theme.focus_style.clone() {
+            button = button.focus_style(focus);
+        }
+        if let Some(hover) = theme.hover_style.clone() {
+            button = button.hover_style(hover);
+        }
+
+        // Pressing draws the border heavier, whatever color it has at the time
+        let focus_color = theme
+            .focus_style
+            .as_ref()
+            .and_then(|style| style.border.as_ref())
+            .map_or(color, |border| border.color);
+        button
+            .pressed_style(Style {
+                border: Some(Border::with_style(BorderStyle::Thick, focus_color)),
+                ..Default::default()
+            })
+            .focusable(true)
+            .on_click(ctx.handler(ButtonMsg::Press))
+            .on_key(Key::Enter, ctx.handler(ButtonMsg::Press))
+            .on_char(' ', ctx.handler(ButtonMsg::Press))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Button {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Button::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Button::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::AtomicBool;
+
+    fn counting(button: Button) -> (Button, Rc<Cell<usize>>) {
+        let presses = Rc::new(Cell::new(0));
+        let counter = presses.clone();
+        let button = button.on_click(move || counter.set(counter.get() + 1));
+        (button, presses)
+    }
+
+    #[test]
+    fn test_disabled_and_loading_ignore_presses() {
+        let (button, presses) = counting(Button::new("Save"));
+        assert!(button.press());
+        assert_eq!(presses.get(), 1);
+
+        let button = button.disabled(true);
+        assert!(!button.press());
+        let button = button.disabled(false).loading(true);
+        assert!(!button.press());
+        assert_eq!(presses.get(), 1);
+    }
+
+    #[test]
+    fn test_disabled_renders_dimmed_without_focus() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let muted = ctx.theme().palette.muted;
+
+        let enabled = Button::new("Delete")
+            .variant(ButtonVariant::Danger)
+            .render(&ctx);
+        assert!(enabled.focusable);
+        assert_eq!(
+            enabled
+                .styles
+                .base
+                .and_then(|style| style.border)
+                .map(|b| b.color),
+            Some(ctx.theme().palette.error)
+        );
+
+        let disabled = Button::new("Delete")
+            .variant(ButtonVariant::Danger)
+            .disabled(true)
+            .render(&ctx);
+        assert!(!disabled.focusable);
+        assert!(disabled.styles.pressed.is_none());
+        assert!(disabled.events.on_click.is_none());
+        assert_eq!(
+            disabled
+                .styles
+                .base
+                .and_then(|style| style.border)
+                .map(|b| b.color),
+            Some(muted)
+        );
+    }
+
+    #[test]
+    fn test_loading_shows_spinner_first() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let button = Button::new("Sync").loading(true).render(&ctx);
+        assert_eq!(button.children.len(), 2);
+        assert!(matches!(button.children[0], Node::Component(_)));
+    }
+}
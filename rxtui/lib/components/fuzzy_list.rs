@@ -0,0 +1,500 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::list::{ListMsg, ListState};
+use crate::components::text_input::{TextInput, TextInputMsg, TextInputState};
+use crate::key::Key;
+use crate::node::{Node, RichText, Role, Text};
+use crate::style::{Dimension, Direction, Style, TextStyle};
+use crate::{Context, Div};
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Score for each matched character
+const MATCH_SCORE: i32 = 16;
+
+/// Bonus for a character matched right after the previous one
+const CONSECUTIVE_BONUS: i32 = 8;
+
+/// Bonus for a character matched at the start of a word
+const BOUNDARY_BONUS: i32 = 10;
+
+/// Most characters before the first match that count against the score
+const MAX_LEADING_PENALTY: usize = 3;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// State for FuzzyList component
+#[derive(Debug, Clone, Default)]
+pub struct FuzzyListState {
+    /// Editing state of the query input
+    pub input: TextInputState,
+
+    /// Highlight and scroll position within the matching items
+    pub list: ListState,
+}
+
+/// An item that matches the query
+#[derive(Debug, Clone, PartialEq)]
+struct Match {
+    /// Index of the item in the full list
+    index: usize,
+
+    /// How well the item matches; higher is better
+    score: i32,
+
+    /// Character positions of the matched query characters
+    positions: Vec<usize>,
+}
+
+/// A list filtered by a query typed above it, in the style of fzf
+///
+/// Items match when the query's characters appear in them in order, ignoring
+/// case unless the query has an uppercase letter; spaces in the query are
+/// ignored. Matches are ordered best first, favoring consecutive characters
+/// and characters at the start of words, with the matched characters drawn
+/// in `match_style`.
+///
+/// The query input keeps focus while navigating: typing filters, Up and Down
+/// move the highlight, PageUp and PageDown jump a page, and Enter or a click
+/// selects the highlighted item. `on_select` receives the item's index in
+/// the full list, not among the matches.
+///
+/// Navigation is handled through [`ListMsg`] and editing through
+/// [`TextInputMsg`], which the input also receives.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let picker = FuzzyList::new(["Cargo.toml", "README.md", "src/main.rs"])
+///     .placeholder("Search files...")
+///     .height(10)
+///     .on_select(|index| println!("opened {index}"));
+/// ```
+pub struct FuzzyList {
+    input: TextInput,
+    items: Vec<String>,
+    height: Option<u16>,
+    highlight_style: Option<Style>,
+    match_style: Option<TextStyle>,
+    on_select: Option<Box<dyn Fn(usize)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl FuzzyList {
+    /// Creates a filterable list of `items`
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            input: TextInput::new().width_fraction(1.0),
+            items: items.into_iter().map(Into::into).collect(),
+            height: None,
+            highlight_style: None,
+            match_style: None,
+            on_select: None,
+        }
+    }
+
+    /// Sets the placeholder shown while the query is empty
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.input = self.input.placeholder(text);
+        self
+    }
+
+    /// Sets the number of visible rows (all matches are shown by default)
+    pub fn height(mut self, rows: u16) -> Self {
+        self.height = Some(rows);
+        self
+    }
+
+    /// Sets the style of the highlighted row (the theme's selection colors by default)
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    /// Sets the style of matched characters (bold in the theme's primary color by default)
+    pub fn match_style(mut self, style: TextStyle) -> Self {
+        self.match_style = Some(style);
+        self
+    }
+
+    /// Sets the callback called with the full-list index of a selected item
+    pub fn on_select(mut self, callback: impl Fn(usize) + 'static) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Number of rows shown for `matches` matching items
+    fn rows(&self, matches: usize) -> usize {
+        match self.height {
+            Some(height) => (height as usize).max(1),
+            None => matches.max(1),
+        }
+    }
+
+    /// Items matching `query`, best first
+    fn matches(&self, query: &str) -> Vec<Match> {
+        let mut matches: Vec<Match> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let (score, positions) = fuzzy_match(query, item)?;
+                Some(Match {
+                    index,
+                    score,
+                    positions,
+                })
+            })
+            .collect();
+
+        // Stable, so equal scores keep the items' order
+        matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+        matches
+    }
+
+    /// Applies a navigation message, returning the full-list index of a selected item
+    fn navigate(&self, state: &mut FuzzyListState, msg: &ListMsg) -> Option<usize> {
+        let matches = self.matches(&state.input.content);
+        let rows = self.rows(matches.len());
+        let (_, selected) = state.list.apply(msg, matches.len(), rows);
+        selected.map(|position| matches[position].index)
+    }
+
+    /// Applies an editing message, returning the full-list index of a selected item
+    fn edit(&self, state: &mut FuzzyListState, msg: &TextInputMsg) -> Option<usize> {
+        match msg {
+            TextInputMsg::InsertNewline => None,
+            // Select before the wrapped input runs its submit callback
+            TextInputMsg::Submit => {
+                let selected = self.navigate(state, &ListMsg::Activate);
+                self.input.apply(&mut state.input, msg);
+                selected
+            }
+            _ => {
+                let query = state.input.content.clone();
+                self.input.apply(&mut state.input, msg);
+
+                // A new query starts from the best match
+                if state.input.content != query {
+                    state.list = ListState::default();
+                }
+                None
+            }
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let mut state = ctx.get_state::<FuzzyListState>();
+
+        let selected = if let Some(msg) = msg.downcast::<ListMsg>() {
+            // Keep typing in the query after clicking a row
+            if matches!(msg, ListMsg::Click(_)) {
+                ctx.focus_self();
+            }
+            self.navigate(&mut state, msg)
+        } else if let Some(msg) = msg.downcast::<TextInputMsg>() {
+            self.edit(&mut state, msg)
+        } else {
+            return Action::none();
+        };
+
+        if let (Some(index), Some(callback)) = (selected, &self.on_select) {
+            callback(index);
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<FuzzyListState>();
+        let palette = &ctx.theme().palette;
+        let matches = self.matches(&state.input.content);
+        let rows = self.rows(matches.len());
+
+        let input = self
+            .input
+            .render(ctx, &state.input)
+            .on_key(Key::Up, ctx.handler(ListMsg::Up))
+            .on_key(Key::Down, ctx.handler(ListMsg::Down))
+            .on_key(Key::PageUp, ctx.handler(ListMsg::PageUp))
+            .on_key(Key::PageDown, ctx.handler(ListMsg::PageDown));
+
+        let highlight_style = self
+            .highlight_style
+            .clone()
+            .unwrap_or_else(|| Style::default().background(palette.selection_background));
+        let match_style = self.match_style.clone().unwrap_or(TextStyle {
+            color: Some(palette.primary),
+            bold: Some(true),
+            ..Default::default()
+        });
+        let row_style = Style {
+            direction: Some(Direction::Horizontal),
+            width: Some(Dimension::Percentage(1.0)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        };
+
+        let mut list = Div::new()
+            .direction(Direction::Vertical)
+            .width_fraction(1.0)
+            .height(rows as u16)
+            .role(Role::List)
+            .on_scroll(ctx.handler_with_value(ListMsg::Wheel));
+
+        if matches.is_empty() {
+            list = list.child(Text::new("No matches").color(palette.muted).into());
+        }
+
+        let visible = matches
+            .iter()
+            .enumerate()
+            .skip(state.list.offset)
+            .take(rows);
+        for (position, item) in visible {
+            let highlighted = position == state.list.highlighted;
+            let foreground = if highlighted {
+                palette.selection_foreground
+            } else {
+                palette.foreground
+            };
+            let content = highlight_matches(&self.items[item.index], &item.positions, &match_style)
+                .color(foreground);
+
+            let style = if highlighted {
+                Style::merge(Some(row_style.clone()), Some(highlight_style.clone()))
+            } else {
+                Some(row_style.clone())
+            };
+
+            let mut row = Div::new()
+                .role(Role::ListItem)
+                .on_click(ctx.handler(ListMsg::Click(position)))
+                .child(content.into());
+            if let Some(style) = style {
+                row = row.style(style);
+            }
+            list = list.child(row.into());
+        }
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .width_fraction(1.0)
+            .child(input.into())
+            .child(list.into())
+            .into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Matches `query` against `candidate` as a subsequence
+///
+/// Returns a score and the character positions of the matched characters, or
+/// `None` when the candidate doesn't contain the query's characters in order.
+/// An empty query matches everything with a score of 0.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query.chars().filter(|ch| !ch.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    // Smart case: an uppercase letter in the query makes it case-sensitive
+    let case_sensitive = query.iter().any(|ch| ch.is_uppercase());
+    let same = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+    let chars: Vec<char> = candidate.chars().collect();
+
+    // Find where the first full match ends...
+    let mut next = 0;
+    let end = chars.iter().position(|&ch| {
+        if same(ch, query[next]) {
+            next += 1;
+        }
+        next == query.len()
+    })?;
+
+    // ...then walk back from there to find the tightest match ending at it
+    let mut positions = Vec::with_capacity(query.len());
+    let mut remaining = query.len();
+    for pos in (0..=end).rev() {
+        if same(chars[pos], query[remaining - 1]) {
+            positions.push(pos);
+            remaining -= 1;
+            if remaining == 0 {
+                break;
+            }
+        }
+    }
+    positions.reverse();
+
+    let mut score = -(positions[0].min(MAX_LEADING_PENALTY) as i32);
+    for (n, &pos) in positions.iter().enumerate() {
+        score += MATCH_SCORE;
+        if n > 0 {
+            let gap = pos - positions[n - 1] - 1;
+            score += if gap == 0 {
+                CONSECUTIVE_BONUS
+            } else {
+                -(gap as i32)
+            };
+        }
+        if is_word_start(&chars, pos) {
+            score += BOUNDARY_BONUS;
+        }
+    }
+
+    Some((score, positions))
+}
+
+/// Whether the character at `pos` starts a word, including camelCase humps
+fn is_word_start(chars: &[char], pos: usize) -> bool {
+    let Some(&previous) = pos.checked_sub(1).and_then(|i| chars.get(i)) else {
+        return true;
+    };
+    !previous.is_alphanumeric() || (previous.is_lowercase() && chars[pos].is_uppercase())
+}
+
+/// Builds rich text for `text` with the characters at `positions` in `style`
+fn highlight_matches(text: &str, positions: &[usize], style: &TextStyle) -> RichText {
+    let mut rich = RichText::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (pos, ch) in text.chars().enumerate() {
+        let matched = positions.binary_search(&pos).is_ok();
+        if matched != run_matched && !run.is_empty() {
+            let content = std::mem::take(&mut run);
+            rich = if run_matched {
+                rich.styled(content, style.clone())
+            } else {
+                rich.text(content)
+            };
+        }
+        run_matched = matched;
+        run.push(ch);
+    }
+
+    if !run.is_empty() {
+        rich = if run_matched {
+            rich.styled(run, style.clone())
+        } else {
+            rich.text(run)
+        };
+    }
+    rich
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for FuzzyList {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        FuzzyList::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        FuzzyList::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for FuzzyList {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(picker: &FuzzyList, state: &mut FuzzyListState, query: &str) {
+        picker.edit(state, &TextInputMsg::Focused);
+        for ch in query.chars() {
+            picker.edit(state, &TextInputMsg::CharInput(ch));
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_match_positions_and_case() {
+        assert_eq!(fuzzy_match("", "anything"), Some((0, vec![])));
+        assert_eq!(fuzzy_match("xyz", "src/main.rs"), None);
+        assert_eq!(fuzzy_match("mrs", "src/main.rs").unwrap().1, vec![4, 9, 10]);
+
+        // The tightest window wins over the first occurrence
+        assert_eq!(fuzzy_match("ab", "a-xab").unwrap().1, vec![3, 4]);
+
+        // Lowercase queries ignore case; uppercase ones don't
+        assert!(fuzzy_match("readme", "README.md").is_some());
+        assert!(fuzzy_match("Readme", "readme.md").is_none());
+    }
+
+    #[test]
+    fn test_matches_ranked_best_first() {
+        let picker = FuzzyList::new(["lib/fuzzy_list.rs", "list.rs", "src/lib.rs"]);
+        let ranked =
+            |query| -> Vec<usize> { picker.matches(query).iter().map(|m| m.index).collect() };
+
+        // An exact run at the start beats the same run mid-word
+        assert_eq!(ranked("list"), vec![1, 0]);
+
+        // Closer characters rank higher; ties would keep the items' order
+        assert_eq!(ranked("ls"), vec![1, 0, 2]);
+        assert_eq!(ranked(""), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_typing_filters_and_selects_full_index() {
+        let picker = FuzzyList::new(["apple", "banana", "cherry", "blueberry"]);
+        let mut state = FuzzyListState::default();
+
+        picker.navigate(&mut state, &ListMsg::Down);
+        assert_eq!(state.list.highlighted, 1);
+
+        // Typing resets the highlight to the best match
+        typed(&picker, &mut state, "er");
+        assert_eq!(state.list.highlighted, 0);
+        assert_eq!(picker.matches(&state.input.content).len(), 2);
+
+        picker.navigate(&mut state, &ListMsg::Down);
+        assert_eq!(picker.edit(&mut state, &TextInputMsg::Submit), Some(3));
+        assert_eq!(picker.navigate(&mut state, &ListMsg::Click(0)), Some(2));
+
+        // Nothing to select without matches
+        typed(&picker, &mut state, "zz");
+        assert_eq!(picker.edit(&mut state, &TextInputMsg::Submit), None);
+    }
+}
@@ -0,0 +1,430 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Node, Role, Text};
+use crate::style::{Dimension, Direction, Style};
+use crate::{Context, Div};
+use std::any::Any;
+use std::rc::Rc;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// An entry in a [`List`]
+///
+/// Either a line of text or a closure that renders the row, told whether the
+/// row is highlighted. Each item takes one row.
+#[derive(Clone)]
+pub enum ListItem {
+    /// A line of text
+    Text(String),
+
+    /// A row rendered by a closure, given whether it is highlighted
+    Custom(Rc<dyn Fn(bool) -> Node>),
+}
+
+/// Messages for List component
+#[derive(Debug, Clone)]
+pub enum ListMsg {
+    /// Move the highlight up, wrapping to the last item
+    Up,
+
+    /// Move the highlight down, wrapping to the first item
+    Down,
+
+    /// Move the highlight up by a page
+    PageUp,
+
+    /// Move the highlight down by a page
+    PageDown,
+
+    /// Highlight the first item
+    Home,
+
+    /// Highlight the last item
+    End,
+
+    /// Select the highlighted item (Enter)
+    Activate,
+
+    /// An item was clicked
+    Click(usize),
+
+    /// Mouse wheel moved (-1 up, 1 down)
+    Wheel(i16),
+}
+
+/// State for List component
+#[derive(Debug, Clone, Default)]
+pub struct ListState {
+    /// Index of the highlighted item (the cursor)
+    pub highlighted: usize,
+
+    /// Index of the first visible item
+    pub offset: usize,
+}
+
+/// A scrollable list of items with a keyboard-driven highlight
+///
+/// Up and Down move the highlight, wrapping around at either end; PageUp,
+/// PageDown, Home, and End jump further. Moving the highlight calls
+/// `on_highlight`, while Enter or a click selects the item and calls
+/// `on_select`. Both receive the item's index.
+///
+/// With `height`, only that many rows are shown and the list scrolls to keep
+/// the highlighted item visible. The mouse wheel scrolls the rows without
+/// moving the highlight.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let files = List::new(["Cargo.toml", "README.md", "src"])
+///     .height(10)
+///     .on_select(|index| println!("opened {index}"));
+/// ```
+pub struct List {
+    items: Vec<ListItem>,
+    height: Option<u16>,
+    highlight_style: Option<Style>,
+    on_highlight: Option<Box<dyn Fn(usize)>>,
+    on_select: Option<Box<dyn Fn(usize)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: ListItem
+//--------------------------------------------------------------------------------------------------
+
+impl ListItem {
+    /// Creates an item rendered by `render`, which is told whether it is highlighted
+    pub fn custom(render: impl Fn(bool) -> Node + 'static) -> Self {
+        Self::Custom(Rc::new(render))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: ListState
+//--------------------------------------------------------------------------------------------------
+
+impl ListState {
+    /// Scrolls so the highlighted item is visible in `rows` rows
+    fn reveal(&mut self, rows: usize) {
+        if self.highlighted < self.offset {
+            self.offset = self.highlighted;
+        } else if self.highlighted >= self.offset + rows {
+            self.offset = self.highlighted + 1 - rows;
+        }
+    }
+
+    /// Applies a message for a list of `len` items showing `rows` rows,
+    /// returning a newly highlighted and a selected index
+    pub(crate) fn apply(
+        &mut self,
+        msg: &ListMsg,
+        len: usize,
+        rows: usize,
+    ) -> (Option<usize>, Option<usize>) {
+        if len == 0 {
+            *self = Self::default();
+            return (None, None);
+        }
+
+        // Items may have been removed since the last message
+        self.highlighted = self.highlighted.min(len - 1);
+        let previous = self.highlighted;
+        let last = len - 1;
+        let page = rows.saturating_sub(1).max(1);
+        let mut selected = None;
+
+        match msg {
+            ListMsg::Up => self.highlighted = previous.checked_sub(1).unwrap_or(last),
+            ListMsg::Down => self.highlighted = if previous == last { 0 } else { previous + 1 },
+            ListMsg::PageUp => self.highlighted = previous.saturating_sub(page),
+            ListMsg::PageDown => self.highlighted = (previous + page).min(last),
+            ListMsg::Home => self.highlighted = 0,
+            ListMsg::End => self.highlighted = last,
+            ListMsg::Activate => selected = Some(previous),
+            ListMsg::Click(index) if *index < len => {
+                self.highlighted = *index;
+                selected = Some(*index);
+            }
+            ListMsg::Click(_) => {}
+            ListMsg::Wheel(delta) => {
+                let max_offset = len.saturating_sub(rows);
+                self.offset =
+                    (self.offset as isize + *delta as isize).clamp(0, max_offset as isize) as usize;
+                return (None, None);
+            }
+        }
+
+        self.reveal(rows);
+        let highlighted = (self.highlighted != previous).then_some(self.highlighted);
+        (highlighted, selected)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: List
+//--------------------------------------------------------------------------------------------------
+
+impl List {
+    /// Creates a list of `items`
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<ListItem>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            height: None,
+            highlight_style: None,
+            on_highlight: None,
+            on_select: None,
+        }
+    }
+
+    /// Sets the number of visible rows (all items are shown by default)
+    pub fn height(mut self, rows: u16) -> Self {
+        self.height = Some(rows);
+        self
+    }
+
+    /// Sets the style of the highlighted row (the theme's selection colors by default)
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    /// Sets the callback called with the index of a newly highlighted item
+    pub fn on_highlight(mut self, callback: impl Fn(usize) + 'static) -> Self {
+        self.on_highlight = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the callback called with the index of a selected item
+    pub fn on_select(mut self, callback: impl Fn(usize) + 'static) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Number of rows shown at once
+    fn rows(&self) -> usize {
+        match self.height {
+            Some(height) => (height as usize).max(1),
+            None => self.items.len().max(1),
+        }
+    }
+
+    /// Applies a message to the state, returning a newly highlighted and a selected index
+    fn apply(&self, state: &mut ListState, msg: &ListMsg) -> (Option<usize>, Option<usize>) {
+        state.apply(msg, self.items.len(), self.rows())
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<ListMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<ListState>();
+        let (highlighted, selected) = self.apply(&mut state, msg);
+
+        // Take focus when clicked so the keyboard works from there
+        if matches!(msg, ListMsg::Click(_)) {
+            ctx.focus_self();
+        }
+
+        if let (Some(index), Some(callback)) = (highlighted, &self.on_highlight) {
+            callback(index);
+        }
+        if let (Some(index), Some(callback)) = (selected, &self.on_select) {
+            callback(index);
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<ListState>();
+        let palette = &ctx.theme().palette;
+        let rows = self.rows();
+
+        let highlight_style = self
+            .highlight_style
+            .clone()
+            .unwrap_or_else(|| Style::default().background(palette.selection_background));
+        let row_style = Style {
+            direction: Some(Direction::Horizontal),
+            width: Some(Dimension::Percentage(1.0)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        };
+
+        let mut list = Div::new()
+            .direction(Direction::Vertical)
+            .width_fraction(1.0)
+            .height(rows as u16)
+            .role(Role::List)
+            .focusable(true)
+            .on_key(Key::Up, ctx.handler(ListMsg::Up))
+            .on_key(Key::Down, ctx.handler(ListMsg::Down))
+            .on_key(Key::PageUp, ctx.handler(ListMsg::PageUp))
+            .on_key(Key::PageDown, ctx.handler(ListMsg::PageDown))
+            .on_key(Key::Home, ctx.handler(ListMsg::Home))
+            .on_key(Key::End, ctx.handler(ListMsg::End))
+            .on_key(Key::Enter, ctx.handler(ListMsg::Activate))
+            .on_scroll(ctx.handler_with_value(ListMsg::Wheel));
+
+        let visible = self.items.iter().enumerate().skip(state.offset).take(rows);
+        for (index, item) in visible {
+            let highlighted = index == state.highlighted;
+            let content = match item {
+                ListItem::Text(label) if highlighted => {
+                    Text::new(label).color(palette.selection_foreground).into()
+                }
+                ListItem::Text(label) => Text::new(label).color(palette.foreground).into(),
+                ListItem::Custom(render) => render(highlighted),
+            };
+
+            let style = if highlighted {
+                Style::merge(Some(row_style.clone()), Some(highlight_style.clone()))
+            } else {
+                Some(row_style.clone())
+            };
+
+            let mut row = Div::new()
+                .role(Role::ListItem)
+                .on_click(ctx.handler(ListMsg::Click(index)))
+                .child(content);
+            if let Some(style) = style {
+                row = row.style(style);
+            }
+            list = list.child(row.into());
+        }
+
+        list.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for List {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        List::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        List::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self::new(Vec::<ListItem>::new())
+    }
+}
+
+impl From<&str> for ListItem {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for ListItem {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl std::fmt::Debug for ListItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(count: usize) -> List {
+        List::new((0..count).map(|i| ((b'a' + i as u8) as char).to_string()))
+    }
+
+    #[test]
+    fn test_navigation_wraps_and_reports_highlight() {
+        let list = letters(3);
+        let mut state = ListState::default();
+
+        assert_eq!(list.apply(&mut state, &ListMsg::Up), (Some(2), None));
+        assert_eq!(list.apply(&mut state, &ListMsg::Down), (Some(0), None));
+        assert_eq!(list.apply(&mut state, &ListMsg::End), (Some(2), None));
+        assert_eq!(list.apply(&mut state, &ListMsg::End), (None, None));
+        assert_eq!(list.apply(&mut state, &ListMsg::Home), (Some(0), None));
+
+        // Enter selects without moving; a click does both
+        assert_eq!(list.apply(&mut state, &ListMsg::Activate), (None, Some(0)));
+        assert_eq!(
+            list.apply(&mut state, &ListMsg::Click(1)),
+            (Some(1), Some(1))
+        );
+        assert_eq!(list.apply(&mut state, &ListMsg::Click(9)), (None, None));
+    }
+
+    #[test]
+    fn test_scrolls_to_keep_highlight_visible() {
+        let list = letters(10).height(3);
+        let mut state = ListState::default();
+
+        list.apply(&mut state, &ListMsg::Down);
+        list.apply(&mut state, &ListMsg::Down);
+        assert_eq!(state.offset, 0);
+        list.apply(&mut state, &ListMsg::Down);
+        assert_eq!((state.highlighted, state.offset), (3, 1));
+
+        // Wrapping to the top scrolls back, PageDown moves by a page less one
+        list.apply(&mut state, &ListMsg::End);
+        assert_eq!(state.offset, 7);
+        list.apply(&mut state, &ListMsg::Down);
+        assert_eq!(state.offset, 0);
+        list.apply(&mut state, &ListMsg::PageDown);
+        assert_eq!((state.highlighted, state.offset), (2, 0));
+
+        // The wheel scrolls within bounds without moving the highlight
+        for _ in 0..20 {
+            list.apply(&mut state, &ListMsg::Wheel(1));
+        }
+        assert_eq!((state.highlighted, state.offset), (2, 7));
+    }
+
+    #[test]
+    fn test_highlight_clamped_when_items_shrink() {
+        let mut state = ListState {
+            highlighted: 8,
+            ..ListState::default()
+        };
+
+        assert_eq!(letters(3).apply(&mut state, &ListMsg::Up), (Some(1), None));
+        assert_eq!(
+            letters(0).apply(&mut state, &ListMsg::Activate),
+            (None, None)
+        );
+        assert_eq!(state.highlighted, 0);
+    }
+}
@@ -0,0 +1,623 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Node, RichText};
+use crate::style::{Direction, Overflow, TextStyle, TextWrap};
+use crate::text;
+use crate::{Context, Div};
+use std::any::Any;
+use std::ops::Range;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Rows shown when no height is set
+const DEFAULT_HEIGHT: u16 = 10;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for LogView component
+#[derive(Debug, Clone)]
+pub enum LogViewMsg {
+    /// Scroll up by a line
+    Up,
+
+    /// Scroll down by a line
+    Down,
+
+    /// Scroll up by a page
+    PageUp,
+
+    /// Scroll down by a page
+    PageDown,
+
+    /// Scroll to the first line
+    Home,
+
+    /// Scroll to the last line, following new lines again
+    End,
+
+    /// Mouse wheel moved (-1 up, 1 down)
+    Wheel(i16),
+
+    /// Scroll to the next line matching the search
+    NextMatch,
+
+    /// Scroll to the previous line matching the search
+    PreviousMatch,
+}
+
+/// State for LogView component
+#[derive(Debug, Clone, Default)]
+pub struct LogViewState {
+    /// Index of the first visible line, or `None` while following the last lines
+    pub offset: Option<usize>,
+
+    /// Search query the view last scrolled to
+    pub query: String,
+
+    /// Position of the current match among the lines matching the query
+    pub current_match: usize,
+}
+
+/// A read-only, scrolling view of text lines, such as logs or help
+///
+/// Only the visible lines are laid out, so the view stays cheap with
+/// thousands of lines. It starts at the end and follows new lines while
+/// scrolled to the bottom; scrolling up stops following until End is pressed
+/// or the view is scrolled back down.
+///
+/// Up, Down, PageUp, PageDown, Home, End and the mouse wheel scroll while it
+/// is focused. With `search`, occurrences of the query are drawn in
+/// `match_style`, the view scrolls to the first matching line whenever the
+/// query changes, and `n` and `N` step through the matching lines. The search
+/// ignores case unless the query has an uppercase letter.
+///
+/// Long lines are clipped unless `wrap` is on, which breaks them at character
+/// boundaries to fit `width`.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let log = LogView::new(lines)
+///     .height(20)
+///     .width(80)
+///     .wrap(true)
+///     .line_numbers(true)
+///     .search("error");
+/// ```
+pub struct LogView {
+    lines: Vec<String>,
+    height: u16,
+    width: Option<u16>,
+    wrap: bool,
+    line_numbers: bool,
+    follow: bool,
+    search: String,
+    match_style: Option<TextStyle>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl LogView {
+    /// Creates a view of `lines`
+    pub fn new<I>(lines: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            lines: lines.into_iter().map(Into::into).collect(),
+            height: DEFAULT_HEIGHT,
+            width: None,
+            wrap: false,
+            line_numbers: false,
+            follow: true,
+            search: String::new(),
+            match_style: None,
+        }
+    }
+
+    /// Sets the number of visible rows (10 by default)
+    pub fn height(mut self, rows: u16) -> Self {
+        self.height = rows;
+        self
+    }
+
+    /// Sets the width in columns (the parent's width by default)
+    pub fn width(mut self, columns: u16) -> Self {
+        self.width = Some(columns);
+        self
+    }
+
+    /// Sets whether long lines wrap, which needs a `width` (off by default)
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets whether each line starts with its number (off by default)
+    pub fn line_numbers(mut self, enabled: bool) -> Self {
+        self.line_numbers = enabled;
+        self
+    }
+
+    /// Sets whether the view starts at the end and follows new lines (on by default)
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Sets the query to highlight and scroll to (none by default)
+    pub fn search(mut self, query: impl Into<String>) -> Self {
+        self.search = query.into();
+        self
+    }
+
+    /// Sets the style of search matches (the theme's selection colors by default)
+    pub fn match_style(mut self, style: TextStyle) -> Self {
+        self.match_style = Some(style);
+        self
+    }
+
+    /// Number of rows shown at once
+    fn rows(&self) -> usize {
+        usize::from(self.height.max(1))
+    }
+
+    /// Columns taken by line numbers, including the space after them
+    fn gutter_width(&self) -> usize {
+        if self.line_numbers {
+            self.lines.len().max(1).to_string().len() + 1
+        } else {
+            0
+        }
+    }
+
+    /// Character ranges of the rows a line takes
+    fn line_rows(&self, line: &str) -> Vec<Range<usize>> {
+        let width = match self.width {
+            Some(width) if self.wrap => width.saturating_sub(self.gutter_width() as u16),
+            _ => return std::iter::once(0..line.chars().count()).collect(),
+        };
+
+        let mut start = 0;
+        let mut rows: Vec<Range<usize>> = text::wrap(line, width.max(1), TextWrap::Character)
+            .iter()
+            .map(|row| {
+                let end = start + row.chars().count();
+                let range = start..end;
+                start = end;
+                range
+            })
+            .collect();
+        if rows.is_empty() {
+            rows.push(0..0);
+        }
+        rows
+    }
+
+    /// The first line that shows the last lines at the bottom of the view
+    fn tail_start(&self) -> usize {
+        let rows = self.rows();
+        let mut used = 0;
+        let mut start = self.lines.len();
+        while start > 0 {
+            let taken = self.line_rows(&self.lines[start - 1]).len();
+            if used + taken > rows && used > 0 {
+                break;
+            }
+            used += taken;
+            start -= 1;
+        }
+        start
+    }
+
+    /// The first visible line for the state
+    fn first_line(&self, state: &LogViewState) -> usize {
+        let tail = self.tail_start();
+        match state.offset {
+            Some(offset) => offset.min(tail),
+            None if self.follow => tail,
+            None => 0,
+        }
+    }
+
+    /// Number of lines from `first` that fit in the view
+    fn visible_lines(&self, first: usize) -> usize {
+        let rows = self.rows();
+        let mut used = 0;
+        let mut count = 0;
+        for line in &self.lines[first.min(self.lines.len())..] {
+            if used >= rows {
+                break;
+            }
+            used += self.line_rows(line).len();
+            count += 1;
+        }
+        count
+    }
+
+    /// Rows shown from `first`, as line index, row within the line and character range
+    ///
+    /// At the bottom, rows left free above the last lines show the end of the
+    /// line before them, so a tall line doesn't leave a gap below the tail.
+    fn visible_rows(&self, first: usize) -> Vec<(usize, usize, Range<usize>)> {
+        let rows = self.rows();
+        let mut visible = Vec::with_capacity(rows);
+        'lines: for (index, line) in self.lines.iter().enumerate().skip(first) {
+            for (row_index, row) in self.line_rows(line).into_iter().enumerate() {
+                if visible.len() == rows {
+                    break 'lines;
+                }
+                visible.push((index, row_index, row));
+            }
+        }
+
+        if visible.len() < rows && first > 0 && first == self.tail_start() {
+            let above = self.line_rows(&self.lines[first - 1]);
+            let skip = above.len().saturating_sub(rows - visible.len());
+            let mut filled: Vec<_> = above
+                .into_iter()
+                .enumerate()
+                .skip(skip)
+                .map(|(row_index, row)| (first - 1, row_index, row))
+                .collect();
+            filled.extend(visible);
+            visible = filled;
+        }
+        visible
+    }
+
+    /// Scrolls so `line` is the first visible line, following again at the bottom
+    fn scroll_to(&self, state: &mut LogViewState, line: usize) {
+        let tail = self.tail_start();
+        state.offset = if self.follow && line >= tail {
+            None
+        } else {
+            Some(line.min(tail))
+        };
+    }
+
+    /// Scrolls so `line` is visible, leaving the view alone if it already is
+    fn reveal(&self, state: &mut LogViewState, line: usize) {
+        let first = self.first_line(state);
+        if line < first || line >= first + self.visible_lines(first) {
+            self.scroll_to(state, line);
+        }
+    }
+
+    /// Indices of the lines containing the search query
+    fn matching_lines(&self) -> Vec<usize> {
+        if self.search.is_empty() {
+            return Vec::new();
+        }
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !find_matches(line, &self.search).is_empty())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Scrolls to the first match when the search query has changed since the last update
+    fn sync_search(&self, state: &mut LogViewState) {
+        if state.query == self.search {
+            return;
+        }
+        state.query = self.search.clone();
+        state.current_match = 0;
+        if let Some(&line) = self.matching_lines().first() {
+            self.reveal(state, line);
+        }
+    }
+
+    /// Applies a message to the state
+    pub(crate) fn apply(&self, state: &mut LogViewState, msg: &LogViewMsg) {
+        self.sync_search(state);
+
+        let first = self.first_line(state);
+        let page = self.rows().saturating_sub(1).max(1);
+        match msg {
+            LogViewMsg::Up => self.scroll_to(state, first.saturating_sub(1)),
+            LogViewMsg::Down => self.scroll_to(state, first + 1),
+            LogViewMsg::PageUp => self.scroll_to(state, first.saturating_sub(page)),
+            LogViewMsg::PageDown => self.scroll_to(state, first + page),
+            LogViewMsg::Home => self.scroll_to(state, 0),
+            LogViewMsg::End => self.scroll_to(state, self.tail_start()),
+            LogViewMsg::Wheel(delta) => {
+                let line = (first as isize + *delta as isize).max(0) as usize;
+                self.scroll_to(state, line);
+            }
+            LogViewMsg::NextMatch | LogViewMsg::PreviousMatch => {
+                let matches = self.matching_lines();
+                if matches.is_empty() {
+                    return;
+                }
+                let current = state.current_match.min(matches.len() - 1);
+                state.current_match = match msg {
+                    LogViewMsg::NextMatch => (current + 1) % matches.len(),
+                    _ => current.checked_sub(1).unwrap_or(matches.len() - 1),
+                };
+                self.reveal(state, matches[state.current_match]);
+            }
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<LogViewMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<LogViewState>();
+        self.apply(&mut state, msg);
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        // A new query shows its first match before any message arrives
+        let mut state = ctx.get_state::<LogViewState>();
+        self.sync_search(&mut state);
+
+        let palette = &ctx.theme().palette;
+        let match_style = self.match_style.clone().unwrap_or(TextStyle {
+            color: Some(palette.selection_foreground),
+            background: Some(palette.selection_background),
+            ..Default::default()
+        });
+        let rows = self.rows();
+        let gutter = self.gutter_width();
+
+        let mut view = Div::new()
+            .direction(Direction::Vertical)
+            .height(rows as u16)
+            .overflow(Overflow::Hidden)
+            .focusable(true)
+            .on_key(Key::Up, ctx.handler(LogViewMsg::Up))
+            .on_key(Key::Down, ctx.handler(LogViewMsg::Down))
+            .on_key(Key::PageUp, ctx.handler(LogViewMsg::PageUp))
+            .on_key(Key::PageDown, ctx.handler(LogViewMsg::PageDown))
+            .on_key(Key::Home, ctx.handler(LogViewMsg::Home))
+            .on_key(Key::End, ctx.handler(LogViewMsg::End))
+            .on_char('n', ctx.handler(LogViewMsg::NextMatch))
+            .on_char('N', ctx.handler(LogViewMsg::PreviousMatch))
+            .on_scroll(ctx.handler_with_value(LogViewMsg::Wheel));
+        view = match self.width {
+            Some(width) => view.width(width),
+            None => view.width_fraction(1.0),
+        };
+
+        let first = self.first_line(&state);
+        let mut matches = (usize::MAX, Vec::new());
+        for (index, row_index, row) in self.visible_rows(first) {
+            let line = &self.lines[index];
+            if matches.0 != index {
+                matches = (index, find_matches(line, &self.search));
+            }
+
+            let mut content = RichText::new();
+            if gutter > 0 {
+                let number = if row_index == 0 {
+                    format!("{:>width$} ", index + 1, width = gutter - 1)
+                } else {
+                    " ".repeat(gutter)
+                };
+                content = content.colored(number, palette.muted);
+            }
+            let row_text = highlight_ranges(line, row, &matches.1, &match_style);
+            content.spans.extend(row_text.spans);
+            view = view.child(content.color(palette.foreground).into());
+        }
+
+        view.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Character ranges of the occurrences of `query` in `line`
+///
+/// Ignores case unless the query has an uppercase letter. An empty query
+/// matches nothing.
+fn find_matches(line: &str, query: &str) -> Vec<Range<usize>> {
+    let query: Vec<char> = query.chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let case_sensitive = query.iter().any(|ch| ch.is_uppercase());
+    let same = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+    let chars: Vec<char> = line.chars().collect();
+
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start + query.len() <= chars.len() {
+        if chars[start..start + query.len()]
+            .iter()
+            .zip(&query)
+            .all(|(&a, &b)| same(a, b))
+        {
+            matches.push(start..start + query.len());
+            start += query.len();
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+/// Builds rich text for the characters of `line` in `row`, with `matches` in `style`
+fn highlight_ranges(
+    line: &str,
+    row: Range<usize>,
+    matches: &[Range<usize>],
+    style: &TextStyle,
+) -> RichText {
+    let mut rich = RichText::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (pos, ch) in line.chars().enumerate().take(row.end).skip(row.start) {
+        let matched = matches.iter().any(|range| range.contains(&pos));
+        if matched != run_matched && !run.is_empty() {
+            let content = std::mem::take(&mut run);
+            rich = if run_matched {
+                rich.styled(content, style.clone())
+            } else {
+                rich.text(content)
+            };
+        }
+        run_matched = matched;
+        run.push(ch);
+    }
+
+    if !run.is_empty() {
+        rich = if run_matched {
+            rich.styled(run, style.clone())
+        } else {
+            rich.text(run)
+        };
+    }
+    rich
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for LogView {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        LogView::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        LogView::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for LogView {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(count: usize) -> LogView {
+        LogView::new((1..=count).map(|i| format!("line {i}"))).height(3)
+    }
+
+    #[test]
+    fn test_follows_tail_until_scrolled_up() {
+        let log = numbered(10);
+        let mut state = LogViewState::default();
+        assert_eq!(log.first_line(&state), 7);
+
+        log.apply(&mut state, &LogViewMsg::Up);
+        assert_eq!(state.offset, Some(6));
+
+        // More lines arrive while scrolled up: the view stays put
+        let log = numbered(20);
+        assert_eq!(log.first_line(&state), 6);
+
+        // Scrolling back to the bottom follows again
+        log.apply(&mut state, &LogViewMsg::End);
+        assert_eq!(state.offset, None);
+        assert_eq!(numbered(30).first_line(&state), 27);
+
+        log.apply(&mut state, &LogViewMsg::Home);
+        assert_eq!(state.offset, Some(0));
+        for _ in 0..30 {
+            log.apply(&mut state, &LogViewMsg::Wheel(1));
+        }
+        assert_eq!(state.offset, None);
+    }
+
+    #[test]
+    fn test_wrapped_lines_fill_rows() {
+        let log = LogView::new(["short", "a much longer line", "end"])
+            .height(4)
+            .width(8)
+            .wrap(true);
+
+        assert_eq!(log.line_rows("a much longer line"), [0..8, 8..16, 16..18]);
+        // The long line and the last one take all four rows
+        assert_eq!(log.tail_start(), 1);
+
+        // With a row less, the rows above the tail show the end of the long line
+        let short = LogView::new(["short", "a much longer line", "end"])
+            .height(3)
+            .width(8)
+            .wrap(true);
+        assert_eq!(short.tail_start(), 2);
+        assert_eq!(
+            short.visible_rows(2),
+            [(1, 1, 8..16), (1, 2, 16..18), (2, 0, 0..3)]
+        );
+
+        // Line numbers narrow the rows
+        let numbered = log.line_numbers(true);
+        assert_eq!(numbered.line_rows("a much longer line").len(), 3);
+        assert_eq!(numbered.line_rows("a much longer line")[0], 0..6);
+    }
+
+    #[test]
+    fn test_search_scrolls_through_matches() {
+        let lines = (1..=20).map(|i| {
+            if i % 5 == 0 {
+                format!("{i}: ERROR failed")
+            } else {
+                format!("{i}: ok")
+            }
+        });
+        let log = LogView::new(lines).height(3).search("error");
+        let mut state = LogViewState::default();
+
+        // A new query jumps to its first match
+        log.sync_search(&mut state);
+        assert_eq!((state.offset, state.current_match), (Some(4), 0));
+
+        log.apply(&mut state, &LogViewMsg::NextMatch);
+        assert_eq!(state.offset, Some(9));
+        log.apply(&mut state, &LogViewMsg::PreviousMatch);
+        assert_eq!(state.offset, Some(4));
+        log.apply(&mut state, &LogViewMsg::PreviousMatch);
+        assert_eq!((state.offset, state.current_match), (None, 3));
+    }
+
+    #[test]
+    fn test_find_matches_smart_case() {
+        assert_eq!(find_matches("Error error", "error"), [0..5, 6..11]);
+        assert_eq!(find_matches("Error error", "Error"), vec![0..5]);
+        assert_eq!(find_matches("aaaa", "aa"), [0..2, 2..4]);
+        assert!(find_matches("anything", "").is_empty());
+    }
+}
@@ -0,0 +1,901 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::key::Key;
+use crate::node::{Node, Text};
+use crate::style::{BorderStyle, Color, Direction, Style};
+use crate::utils::display_width;
+use crate::{Context, Div};
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Z-index of dropdowns and context menus, above ordinary positioned content
+const OVERLAY_Z_INDEX: i32 = 100;
+
+/// Marker shown after items that open a submenu
+const SUBMENU_ARROW: &str = "▸";
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// An entry in a [`Menu`]
+///
+/// Items with children open a submenu instead of being selected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuItem {
+    id: String,
+    label: String,
+    accelerator: Option<String>,
+    disabled: bool,
+    separator: bool,
+    children: Vec<MenuItem>,
+}
+
+/// How a [`Menu`] presents its top-level items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuKind {
+    /// A horizontal bar whose items open dropdowns
+    #[default]
+    Bar,
+
+    /// A popup list opened by right-clicking the menu's content
+    Context,
+}
+
+/// Messages for Menu component
+#[derive(Debug, Clone)]
+pub enum MenuMsg {
+    /// Move up in a list
+    Up,
+
+    /// Move down in a list, or open the highlighted bar item's dropdown
+    Down,
+
+    /// Close a submenu, or move to the previous bar item
+    Left,
+
+    /// Open a submenu, or move to the next bar item
+    Right,
+
+    /// Open the highlighted submenu or select the highlighted item (Enter)
+    Activate,
+
+    /// Close the innermost open list (Esc)
+    Close,
+
+    /// An item was clicked, identified by its index at each level
+    Click(Vec<usize>),
+
+    /// Open the context menu at a position relative to the content
+    OpenAt((u16, u16)),
+
+    /// Close every open list
+    Dismiss,
+
+    /// The menu gained focus
+    Focused,
+
+    /// The menu lost focus
+    Blurred,
+}
+
+/// State for Menu component
+#[derive(Debug, Clone, Default)]
+pub struct MenuState {
+    /// Index of the highlighted item at each open level
+    ///
+    /// For a bar, the first entry is the highlighted bar item and the
+    /// dropdown is open while there is more than one entry.
+    pub path: Vec<usize>,
+
+    /// Where the context menu is open, relative to the content
+    pub anchor: Option<(u16, u16)>,
+
+    /// Whether the menu has focus
+    pub focused: bool,
+}
+
+/// A menu bar or context menu with nested submenus
+///
+/// Dropdowns and submenus are drawn as absolutely positioned overlays at a
+/// high z-index. They flip to the other side of their anchor when they would
+/// run off the screen, so a submenu near the right edge opens to the left.
+///
+/// Arrow keys move between items, Enter opens a submenu or selects an item,
+/// and Esc closes the innermost open list. Clicking works the same way.
+/// Selecting an item closes the menu and calls `on_select` with its id.
+/// Accelerators are only shown as hints; bind the keys themselves with
+/// global key handlers.
+///
+/// A [`MenuKind::Context`] menu wraps `content` and opens where it is right
+/// clicked.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let menu = Menu::bar(vec![
+///     MenuItem::new("file", "File").submenu(vec![
+///         MenuItem::new("open", "Open").accelerator("Ctrl+O"),
+///         MenuItem::separator(),
+///         MenuItem::new("recent", "Recent").submenu(vec![
+///             MenuItem::new("recent-1", "notes.txt"),
+///         ]),
+///         MenuItem::new("quit", "Quit").accelerator("Ctrl+Q"),
+///     ]),
+///     MenuItem::new("help", "Help"),
+/// ])
+/// .on_select(|id| println!("selected {id}"));
+/// ```
+pub struct Menu {
+    items: Vec<MenuItem>,
+    kind: MenuKind,
+    content: Option<Node>,
+    background: Option<Color>,
+    color: Option<Color>,
+    highlight: Option<Color>,
+    on_select: Option<Box<dyn Fn(String)>>,
+}
+
+/// Colors used to draw a menu, resolved against the theme
+struct MenuColors {
+    background: Color,
+    foreground: Color,
+    muted: Color,
+    highlight: Color,
+    highlight_text: Color,
+}
+
+/// Column widths shared by every row of a list
+struct ListColumns {
+    label: usize,
+    accelerator: usize,
+    arrows: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: MenuItem
+//--------------------------------------------------------------------------------------------------
+
+impl MenuItem {
+    /// Creates an item reported to `on_select` as `id`
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            accelerator: None,
+            disabled: false,
+            separator: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a horizontal rule between groups of items
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::new("", "")
+        }
+    }
+
+    /// Sets the shortcut hint shown at the right of the item, such as `Ctrl+S`
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        self.accelerator = Some(accelerator.into());
+        self
+    }
+
+    /// Makes the item open a submenu with `items`
+    pub fn submenu(mut self, items: Vec<MenuItem>) -> Self {
+        self.children = items;
+        self
+    }
+
+    /// Sets whether the item is greyed out and skipped by navigation
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the id reported when this item is selected
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Whether navigation can stop on this item
+    fn selectable(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Menu
+//--------------------------------------------------------------------------------------------------
+
+impl Menu {
+    /// Creates a menu bar with dropdowns for `items`
+    pub fn bar(items: Vec<MenuItem>) -> Self {
+        Self {
+            items,
+            kind: MenuKind::Bar,
+            content: None,
+            background: None,
+            color: None,
+            highlight: None,
+            on_select: None,
+        }
+    }
+
+    /// Creates a context menu showing `items` when the content is right clicked
+    pub fn context(items: Vec<MenuItem>) -> Self {
+        Self::bar(items).kind(MenuKind::Context)
+    }
+
+    /// Sets the top-level items
+    pub fn items(mut self, items: Vec<MenuItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Sets whether this is a menu bar or a context menu
+    pub fn kind(mut self, kind: MenuKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the content a context menu is attached to
+    pub fn content(mut self, content: impl Into<Node>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Sets the background color of the bar and lists (black by default)
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Sets the item text color (white by default)
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the background of the highlighted item (the theme's selection color by default)
+    pub fn highlight(mut self, color: Color) -> Self {
+        self.highlight = Some(color);
+        self
+    }
+
+    /// Sets the callback to be called with an item's id when it is selected
+    pub fn on_select(mut self, callback: impl Fn(String) + 'static) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the items of the list at `level` along `path`, if it exists
+    fn list_at(&self, path: &[usize], level: usize) -> Option<&[MenuItem]> {
+        let mut items = self.items.as_slice();
+        for &index in path.iter().take(level) {
+            items = items.get(index)?.children.as_slice();
+        }
+        Some(items)
+    }
+
+    /// Returns the item at the end of `path`
+    fn item_at(&self, path: &[usize]) -> Option<&MenuItem> {
+        let (&last, parents) = path.split_last()?;
+        self.list_at(parents, parents.len())?.get(last)
+    }
+
+    /// Number of path entries that are always present while the menu is open
+    ///
+    /// A bar keeps its highlighted bar item even when no dropdown is open.
+    fn base_depth(&self) -> usize {
+        match self.kind {
+            MenuKind::Bar => 1,
+            MenuKind::Context => 0,
+        }
+    }
+
+    /// Whether a dropdown or context menu is showing
+    fn is_open(&self, state: &MenuState) -> bool {
+        match self.kind {
+            MenuKind::Bar => state.path.len() > 1,
+            MenuKind::Context => state.anchor.is_some(),
+        }
+    }
+
+    /// Closes every open list, keeping the bar highlight
+    fn close(&self, state: &mut MenuState) {
+        state.path.truncate(self.base_depth());
+        state.anchor = None;
+    }
+
+    /// Opens the submenu of the item at the end of the path, if it has one
+    fn open_submenu(&self, state: &mut MenuState) -> bool {
+        let first = self
+            .item_at(&state.path)
+            .filter(|item| item.selectable())
+            .and_then(|item| step(&item.children, None, 1));
+        if let Some(first) = first {
+            state.path.push(first);
+        }
+        first.is_some()
+    }
+
+    /// Highlights another bar item, reopening its dropdown if one was open
+    fn move_bar(&self, state: &mut MenuState, delta: isize) {
+        let was_open = self.is_open(state);
+        if let Some(next) = step(&self.items, state.path.first().copied(), delta) {
+            state.path = vec![next];
+            if was_open {
+                self.open_submenu(state);
+            }
+        }
+    }
+
+    /// Applies a message to the state, returning the id of a selected item
+    fn apply(&self, state: &mut MenuState, msg: &MenuMsg) -> Option<String> {
+        // A bar always has a highlighted item to navigate from
+        if self.kind == MenuKind::Bar && state.path.is_empty() {
+            state.path.extend(step(&self.items, None, 1));
+        }
+
+        let on_bar = self.kind == MenuKind::Bar && state.path.len() <= 1;
+        let depth = state.path.len();
+
+        match msg {
+            MenuMsg::Up | MenuMsg::Down if on_bar => {
+                if matches!(msg, MenuMsg::Down) {
+                    self.open_submenu(state);
+                }
+            }
+            MenuMsg::Up | MenuMsg::Down => {
+                let delta = if matches!(msg, MenuMsg::Up) { -1 } else { 1 };
+                if let Some(items) = self.list_at(&state.path, depth.saturating_sub(1))
+                    && let Some(next) = step(items, state.path.last().copied(), delta)
+                {
+                    state.path[depth - 1] = next;
+                }
+            }
+            MenuMsg::Left if on_bar => self.move_bar(state, -1),
+            MenuMsg::Right if on_bar => self.move_bar(state, 1),
+            MenuMsg::Left => {
+                if depth > self.base_depth() + 1 {
+                    state.path.pop();
+                } else if self.kind == MenuKind::Bar {
+                    self.move_bar(state, -1);
+                }
+            }
+            MenuMsg::Right => {
+                if !self.open_submenu(state) && self.kind == MenuKind::Bar {
+                    self.move_bar(state, 1);
+                }
+            }
+            MenuMsg::Activate => {
+                if depth > 0 && !self.open_submenu(state) {
+                    return self.select(state);
+                }
+            }
+            MenuMsg::Close => {
+                if depth > self.base_depth() + 1 {
+                    state.path.pop();
+                } else {
+                    self.close(state);
+                }
+            }
+            MenuMsg::Click(path) => {
+                let item = self.item_at(path)?;
+                if !item.selectable() {
+                    return None;
+                }
+
+                // Clicking the bar item whose dropdown is open closes it
+                if self.kind == MenuKind::Bar
+                    && path.len() == 1
+                    && self.is_open(state)
+                    && state.path.first() == path.first()
+                {
+                    self.close(state);
+                    return None;
+                }
+
+                state.path = path.clone();
+                if !self.open_submenu(state) {
+                    return self.select(state);
+                }
+            }
+            MenuMsg::OpenAt(position) => {
+                if self.kind == MenuKind::Context
+                    && let Some(first) = step(&self.items, None, 1)
+                {
+                    state.anchor = Some(*position);
+                    state.path = vec![first];
+                }
+            }
+            MenuMsg::Dismiss => self.close(state),
+            MenuMsg::Focused => state.focused = true,
+            MenuMsg::Blurred => {
+                state.focused = false;
+                self.close(state);
+            }
+        }
+
+        None
+    }
+
+    /// Closes the menu and returns the id of the highlighted item if it can be selected
+    fn select(&self, state: &mut MenuState) -> Option<String> {
+        let id = self
+            .item_at(&state.path)
+            .filter(|item| item.selectable() && item.children.is_empty())
+            .map(|item| item.id.clone())?;
+        self.close(state);
+        Some(id)
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let Some(msg) = msg.downcast::<MenuMsg>() else {
+            return Action::none();
+        };
+
+        let mut state = ctx.get_state::<MenuState>();
+        let selected = self.apply(&mut state, msg);
+
+        // Take focus when opened with the mouse so the keyboard works from there
+        if matches!(msg, MenuMsg::Click(_) | MenuMsg::OpenAt(_)) && self.is_open(&state) {
+            ctx.focus_self();
+        }
+
+        if let (Some(id), Some(callback)) = (selected, &self.on_select) {
+            callback(id);
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<MenuState>();
+        let colors = MenuColors::resolve(self, ctx);
+
+        match self.kind {
+            MenuKind::Bar => self.render_bar(ctx, &state, &colors),
+            MenuKind::Context => self.render_context(ctx, &state, &colors),
+        }
+    }
+
+    /// Renders the bar and the open dropdown below its highlighted item
+    fn render_bar(&self, ctx: &Context, state: &MenuState, colors: &MenuColors) -> Node {
+        let mut bar = self
+            .with_key_handlers(ctx, Div::new())
+            .direction(Direction::Horizontal)
+            .width_fraction(1.0)
+            .height(1)
+            .background(colors.background);
+
+        let highlighted = state.path.first().copied();
+        let open = self.is_open(state);
+
+        for (index, item) in self.items.iter().enumerate() {
+            let active = highlighted == Some(index) && (open || state.focused);
+            let label = format!(" {} ", item.label);
+
+            let mut entry = Div::new()
+                .direction(Direction::Horizontal)
+                .width(display_width(&label) as u16)
+                .height(1)
+                .child(colors.text(&label, item, active));
+
+            if active {
+                entry = entry.background(colors.highlight);
+            }
+            if item.selectable() {
+                entry = entry
+                    .on_click(ctx.handler(MenuMsg::Click(vec![index])))
+                    .hover_style(Style::default().background(colors.highlight));
+            }
+            if open && highlighted == Some(index) {
+                let dropdown = self
+                    .render_list(ctx, state, colors, &item.children, &[index])
+                    .top(1)
+                    .left(0);
+                entry = entry.child(dropdown.into());
+            }
+
+            bar = bar.child(entry.into());
+        }
+
+        bar.into()
+    }
+
+    /// Renders the content with the context menu layered over it when open
+    fn render_context(&self, ctx: &Context, state: &MenuState, colors: &MenuColors) -> Node {
+        let mut wrapper = Div::new()
+            .on_right_click(ctx.handler_with_value(MenuMsg::OpenAt))
+            .on_click(ctx.handler(MenuMsg::Dismiss));
+
+        // The overlay goes first so it wins hit-testing and focus over the content
+        if let Some((x, y)) = state.anchor {
+            let list = self
+                .with_key_handlers(ctx, self.render_list(ctx, state, colors, &self.items, &[]))
+                .top(0)
+                .left(0);
+
+            // A single-cell anchor at the pointer lets the list flip around it
+            let anchor = Div::new()
+                .absolute_position()
+                .left(x as i16)
+                .top(y as i16)
+                .width(1)
+                .height(1)
+                .z_index(OVERLAY_Z_INDEX)
+                .child(list.into());
+            wrapper = wrapper.child(anchor.into());
+        }
+
+        if let Some(content) = &self.content {
+            wrapper = wrapper.child(content.clone());
+        }
+
+        wrapper.into()
+    }
+
+    /// Renders one dropdown list, recursing into the open submenu
+    ///
+    /// `prefix` is the path of the item that owns `items`.
+    fn render_list(
+        &self,
+        ctx: &Context,
+        state: &MenuState,
+        colors: &MenuColors,
+        items: &[MenuItem],
+        prefix: &[usize],
+    ) -> Div<Node> {
+        let level = prefix.len();
+        let highlighted = state.path.get(level).copied();
+        let columns = ListColumns::measure(items);
+        let width = columns.width();
+
+        let mut list = Div::new()
+            .direction(Direction::Vertical)
+            .absolute_position()
+            .z_index(OVERLAY_Z_INDEX)
+            .flip_to_fit(true)
+            .width(width + 2)
+            .height(items.len() as u16 + 2)
+            .background(colors.background)
+            .border_style_with_color(BorderStyle::Single, colors.foreground);
+
+        for (index, item) in items.iter().enumerate() {
+            if item.separator {
+                let rule = "─".repeat(width as usize);
+                list = list.child(Text::new(rule).color(colors.muted).into());
+                continue;
+            }
+
+            let active = highlighted == Some(index);
+            let mut row = Div::new()
+                .direction(Direction::Horizontal)
+                .width(width)
+                .height(1)
+                .child(colors.text(&columns.format(item), item, active));
+
+            if active {
+                row = row.background(colors.highlight);
+            }
+
+            if item.selectable() {
+                let mut path = prefix.to_vec();
+                path.push(index);
+                row = row
+                    .on_click(ctx.handler(MenuMsg::Click(path.clone())))
+                    .hover_style(Style::default().background(colors.highlight));
+
+                // The submenu sits beside the row, its first item level with it
+                if active && state.path.len() > level + 1 && !item.children.is_empty() {
+                    let submenu = self
+                        .render_list(ctx, state, colors, &item.children, &path)
+                        .left(width as i16 + 1)
+                        .top(-1);
+                    row = row.child(submenu.into());
+                }
+            }
+
+            list = list.child(row.into());
+        }
+
+        list
+    }
+
+    /// Makes `container` focusable and adds the keyboard navigation handlers
+    fn with_key_handlers(&self, ctx: &Context, container: Div<Node>) -> Div<Node> {
+        container
+            .focusable(true)
+            .on_focus(ctx.handler(MenuMsg::Focused))
+            .on_blur(ctx.handler(MenuMsg::Blurred))
+            .on_key(Key::Up, ctx.handler(MenuMsg::Up))
+            .on_key(Key::Down, ctx.handler(MenuMsg::Down))
+            .on_key(Key::Left, ctx.handler(MenuMsg::Left))
+            .on_key(Key::Right, ctx.handler(MenuMsg::Right))
+            .on_key(Key::Enter, ctx.handler(MenuMsg::Activate))
+            .on_key(Key::Esc, ctx.handler(MenuMsg::Close))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Helpers
+//--------------------------------------------------------------------------------------------------
+
+impl MenuColors {
+    fn resolve(menu: &Menu, ctx: &Context) -> Self {
+        let theme = ctx.theme();
+        let palette = &theme.palette;
+        Self {
+            background: menu.background.unwrap_or(Color::Black),
+            foreground: menu.color.unwrap_or(Color::White),
+            muted: palette.muted,
+            highlight: menu.highlight.unwrap_or(palette.selection_background),
+            highlight_text: palette.selection_foreground,
+        }
+    }
+
+    /// Styles an item's text for its enabled and highlighted state
+    fn text(&self, content: &str, item: &MenuItem, active: bool) -> Node {
+        let color = if item.disabled {
+            self.muted
+        } else if active {
+            self.highlight_text
+        } else {
+            self.foreground
+        };
+        Text::new(content).color(color).into()
+    }
+}
+
+impl ListColumns {
+    fn measure(items: &[MenuItem]) -> Self {
+        let visible = items.iter().filter(|item| !item.separator);
+        Self {
+            label: visible
+                .clone()
+                .map(|item| display_width(&item.label))
+                .max()
+                .unwrap_or(0),
+            accelerator: visible
+                .clone()
+                .filter_map(|item| item.accelerator.as_deref().map(display_width))
+                .max()
+                .unwrap_or(0),
+            arrows: visible.clone().any(|item| !item.children.is_empty()),
+        }
+    }
+
+    /// Inner width of the list, excluding its border
+    fn width(&self) -> u16 {
+        let accelerator = if self.accelerator > 0 {
+            self.accelerator + 2
+        } else {
+            0
+        };
+        let arrow = if self.arrows { 2 } else { 0 };
+        (self.label + accelerator + arrow + 2) as u16
+    }
+
+    /// Lays out a row as ` label   accelerator ▸ `
+    fn format(&self, item: &MenuItem) -> String {
+        let mut row = format!(" {}", item.label);
+        pad(&mut row, self.label + 1);
+
+        if self.accelerator > 0 {
+            let accelerator = item.accelerator.as_deref().unwrap_or("");
+            pad(
+                &mut row,
+                self.label + 3 + self.accelerator - display_width(accelerator),
+            );
+            row.push_str(accelerator);
+        }
+
+        if self.arrows {
+            row.push(' ');
+            row.push_str(if item.children.is_empty() {
+                " "
+            } else {
+                SUBMENU_ARROW
+            });
+        }
+
+        row.push(' ');
+        row
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Index of the next selectable item after `from` in `delta` direction, wrapping around
+///
+/// With no starting point, returns the first selectable item.
+fn step(items: &[MenuItem], from: Option<usize>, delta: isize) -> Option<usize> {
+    let len = items.len() as isize;
+    let start = match from {
+        Some(from) => from as isize,
+        None if delta > 0 => -1,
+        None => len,
+    };
+
+    (1..=len)
+        .map(|offset| (start + offset * delta.signum()).rem_euclid(len) as usize)
+        .find(|&index| items[index].selectable())
+}
+
+/// Pads `text` with spaces to a display width of `width`
+fn pad(text: &mut String, width: usize) {
+    let current = display_width(text);
+    if current < width {
+        text.push_str(&" ".repeat(width - current));
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Menu {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Menu::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Menu::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for Menu {
+    fn default() -> Self {
+        Self::bar(Vec::new())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_menu() -> Menu {
+        Menu::bar(vec![
+            MenuItem::new("file", "File").submenu(vec![
+                MenuItem::new("open", "Open").accelerator("Ctrl+O"),
+                MenuItem::separator(),
+                MenuItem::new("save", "Save").disabled(true),
+                MenuItem::new("recent", "Recent").submenu(vec![
+                    MenuItem::new("recent-a", "a.txt"),
+                    MenuItem::new("recent-b", "b.txt"),
+                ]),
+            ]),
+            MenuItem::new("help", "Help"),
+        ])
+    }
+
+    #[test]
+    fn test_step_skips_separators_and_disabled() {
+        let menu = file_menu();
+        let items = &menu.items[0].children;
+
+        assert_eq!(step(items, None, 1), Some(0));
+        assert_eq!(step(items, Some(0), 1), Some(3));
+        assert_eq!(step(items, Some(3), 1), Some(0));
+        assert_eq!(step(items, Some(0), -1), Some(3));
+        assert_eq!(step(&[MenuItem::separator()], None, 1), None);
+    }
+
+    #[test]
+    fn test_bar_keyboard_navigation() {
+        let menu = file_menu();
+        let mut state = MenuState::default();
+
+        // Down opens the dropdown of the first bar item
+        menu.apply(&mut state, &MenuMsg::Down);
+        assert_eq!(state.path, vec![0, 0]);
+
+        // Down skips the separator and disabled item, Right opens the submenu
+        menu.apply(&mut state, &MenuMsg::Down);
+        menu.apply(&mut state, &MenuMsg::Right);
+        assert_eq!(state.path, vec![0, 3, 0]);
+
+        // Esc closes one level at a time
+        menu.apply(&mut state, &MenuMsg::Close);
+        assert_eq!(state.path, vec![0, 3]);
+
+        // Right on a plain item moves to the next bar item
+        menu.apply(&mut state, &MenuMsg::Up);
+        menu.apply(&mut state, &MenuMsg::Right);
+        assert_eq!(state.path, vec![1]);
+
+        menu.apply(&mut state, &MenuMsg::Close);
+        assert_eq!(state.path, vec![1]);
+    }
+
+    #[test]
+    fn test_selecting_reports_id_and_closes() {
+        let menu = file_menu();
+        let mut state = MenuState::default();
+
+        menu.apply(&mut state, &MenuMsg::Click(vec![0]));
+        menu.apply(&mut state, &MenuMsg::Click(vec![0, 3]));
+        assert_eq!(state.path, vec![0, 3, 0]);
+
+        menu.apply(&mut state, &MenuMsg::Down);
+        assert_eq!(
+            menu.apply(&mut state, &MenuMsg::Activate).as_deref(),
+            Some("recent-b")
+        );
+        assert_eq!(state.path, vec![0]);
+
+        // Disabled items and separators can't be selected
+        menu.apply(&mut state, &MenuMsg::Click(vec![0]));
+        assert_eq!(menu.apply(&mut state, &MenuMsg::Click(vec![0, 2])), None);
+        assert_eq!(menu.apply(&mut state, &MenuMsg::Click(vec![0, 1])), None);
+
+        // A bar item without a submenu is selected directly
+        assert_eq!(
+            menu.apply(&mut state, &MenuMsg::Click(vec![1])).as_deref(),
+            Some("help")
+        );
+    }
+
+    #[test]
+    fn test_context_menu_opens_at_position() {
+        let menu = Menu::context(vec![
+            MenuItem::new("copy", "Copy"),
+            MenuItem::new("paste", "Paste"),
+        ]);
+        let mut state = MenuState::default();
+
+        menu.apply(&mut state, &MenuMsg::OpenAt((4, 2)));
+        assert_eq!(state.anchor, Some((4, 2)));
+        assert_eq!(state.path, vec![0]);
+
+        menu.apply(&mut state, &MenuMsg::Up);
+        assert_eq!(state.path, vec![1]);
+
+        menu.apply(&mut state, &MenuMsg::Close);
+        assert_eq!(state.anchor, None);
+        assert!(state.path.is_empty());
+    }
+
+    #[test]
+    fn test_row_columns() {
+        let menu = file_menu();
+        let columns = ListColumns::measure(&menu.items[0].children);
+
+        assert_eq!(columns.width(), 18);
+        assert_eq!(
+            columns.format(&menu.items[0].children[0]),
+            " Open    Ctrl+O   "
+        );
+        assert_eq!(
+            columns.format(&menu.items[0].children[3]),
+            " Recent         ▸ "
+        );
+    }
+}
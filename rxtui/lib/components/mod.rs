@@ -7,19 +7,71 @@
 // Modules
 //--------------------------------------------------------------------------------------------------
 
+/// Text colored with ANSI escape sequences, such as command output
+pub mod ansi_text;
+
 /// Animated shimmer text effect
 pub mod shimmer_text;
 
+/// Shimmering placeholder bars shown while content loads
+pub mod skeleton;
+
 /// Text input component for user text entry
 pub mod text_input;
 
 /// Spinner component for loading animations
 pub mod spinner;
 
+/// Numeric input component with stepping and range clamping
+pub mod number_input;
+
+/// Menu bar and context menu with nested submenus
+pub mod menu;
+
+/// Single-line bar of key hints
+pub mod status_bar;
+
+/// Scrollable list with a keyboard-driven highlight
+pub mod list;
+
+/// List filtered by a fuzzy-matched query
+pub mod fuzzy_list;
+
+/// Collapsible sections with toggling headers
+pub mod accordion;
+
+/// Tree of nested items with expandable levels
+pub mod tree;
+
+/// Read-only scrolling view of lines, for logs and help text
+pub mod log_view;
+
+/// Clickable button with variants, disabled and loading states
+pub mod button;
+
+/// One-line chart of a series of numbers
+pub mod sparkline;
+
+/// Timed notifications stacked in a corner of the screen
+pub mod toast;
+
 //--------------------------------------------------------------------------------------------------
 // Exports
 //--------------------------------------------------------------------------------------------------
 
+pub use accordion::{Accordion, AccordionMode};
+pub use ansi_text::AnsiText;
+pub use button::{Button, ButtonVariant};
+pub use fuzzy_list::FuzzyList;
+pub use list::{List, ListItem};
+pub use log_view::LogView;
+pub use menu::{Menu, MenuItem, MenuKind};
+pub use number_input::NumberInput;
 pub use shimmer_text::{ShimmerSpeed, ShimmerText};
+pub use skeleton::Skeleton;
+pub use sparkline::Sparkline;
 pub use spinner::{Spinner, SpinnerMsg, SpinnerSpeed, SpinnerType};
+pub use status_bar::StatusBar;
 pub use text_input::TextInput;
+pub use toast::{Toast, ToastLevel, ToastPosition};
+pub use tree::{Tree, TreeItem, TreePath};
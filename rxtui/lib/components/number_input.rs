@@ -0,0 +1,501 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::text_input::{TextInput, TextInputMsg, TextInputState};
+use crate::key::Key;
+use crate::node::Node;
+use crate::style::Color;
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Most decimal places kept when stepping, beyond which f64 output is noise
+const MAX_STEP_DECIMALS: usize = 10;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Messages for NumberInput component
+///
+/// Text editing is handled through [`TextInputMsg`], which the input also receives.
+#[derive(Debug, Clone)]
+pub enum NumberInputMsg {
+    /// Add one step (Up key)
+    Increment,
+
+    /// Subtract one step (Down key)
+    Decrement,
+
+    /// Mouse wheel moved (-1 up, 1 down)
+    Wheel(i16),
+}
+
+/// State for NumberInput component
+#[derive(Debug, Clone, Default)]
+pub struct NumberInputState {
+    /// Editing state of the underlying text input
+    pub input: TextInputState,
+
+    /// Last valid value, reported through `on_change`
+    pub value: Option<f64>,
+
+    /// Whether the initial value has been written into the buffer
+    initialized: bool,
+}
+
+/// A numeric input with stepping and range clamping
+///
+/// Wraps a [`TextInput`] for editing, focus, and styling, but only accepts
+/// characters that keep the buffer a valid number or a prefix of one. While
+/// typing, partial input such as `-` or `1.` is kept as is, and `on_change`
+/// fires whenever the buffer parses to a new value. On blur or submit the
+/// value is clamped to the range and reformatted; input that doesn't parse
+/// reverts to the last valid value.
+///
+/// With `integer(true)`, only whole numbers can be typed and values are shown
+/// without decimals.
+///
+/// Up and Down add or subtract one step, clamped to the range. With
+/// `mouse_wheel(true)`, the wheel does the same while the pointer is over the input.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let volume = NumberInput::new()
+///     .min(0.0)
+///     .max(100.0)
+///     .step(5.0)
+///     .value(50.0)
+///     .on_change(|value| println!("volume: {value}"));
+/// ```
+pub struct NumberInput {
+    input: TextInput,
+    value: Option<f64>,
+    min: Option<f64>,
+    max: Option<f64>,
+    step: f64,
+    integer: bool,
+    mouse_wheel: bool,
+    on_change: Option<Box<dyn Fn(f64)>>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl NumberInput {
+    /// Creates a new NumberInput with a step of 1 and no range limits
+    pub fn new() -> Self {
+        Self {
+            input: TextInput::new().width(12),
+            value: None,
+            min: None,
+            max: None,
+            step: 1.0,
+            integer: false,
+            mouse_wheel: false,
+            on_change: None,
+        }
+    }
+
+    /// Sets the initial value, clamped to the range
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets the smallest allowed value
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the largest allowed value
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the amount added or subtracted by one step
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Restricts input to whole numbers, rejecting `.` and rounding stepped values
+    pub fn integer(mut self, integer: bool) -> Self {
+        self.integer = integer;
+        self
+    }
+
+    /// Enables stepping with the mouse wheel while the pointer is over the input
+    pub fn mouse_wheel(mut self, enabled: bool) -> Self {
+        self.mouse_wheel = enabled;
+        self
+    }
+
+    /// Sets the callback to be called when the value changes
+    pub fn on_change(mut self, callback: impl Fn(f64) + 'static) -> Self {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the callback to be called when the input is submitted, after clamping
+    pub fn on_submit(mut self, callback: impl Fn() + 'static) -> Self {
+        self.input = self.input.on_submit(callback);
+        self
+    }
+
+    /// Sets the callback to be called when the input loses focus, after clamping
+    pub fn on_blur(mut self, callback: impl Fn() + 'static) -> Self {
+        self.input = self.input.on_blur(callback);
+        self
+    }
+
+    /// Sets the placeholder text shown while the input is empty
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.input = self.input.placeholder(text);
+        self
+    }
+
+    /// Sets whether this input can receive focus
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.input = self.input.focusable(focusable);
+        self
+    }
+
+    /// Sets the width in cells (12 by default)
+    pub fn width(mut self, width: u16) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the border color
+    pub fn border(mut self, color: Color) -> Self {
+        self.input = self.input.border(color);
+        self
+    }
+
+    /// Sets the border color when focused
+    pub fn focus_border(mut self, color: Color) -> Self {
+        self.input = self.input.focus_border(color);
+        self
+    }
+
+    /// Sets the background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.input = self.input.background(color);
+        self
+    }
+
+    /// Sets the typed content color
+    pub fn content_color(mut self, color: Color) -> Self {
+        self.input = self.input.content_color(color);
+        self
+    }
+
+    /// Reads the state, writing the initial value into the buffer on first use
+    fn load_state(&self, ctx: &Context) -> NumberInputState {
+        let mut state = ctx.get_state::<NumberInputState>();
+        if !state.initialized {
+            state.initialized = true;
+            if let Some(value) = self.value {
+                let value = self.normalize(value);
+                state.input.content = format_number(value);
+                state.value = Some(value);
+            }
+        }
+        state
+    }
+
+    /// Rounds in integer mode and limits a value to the configured range
+    fn normalize(&self, value: f64) -> f64 {
+        let value = if self.integer { value.round() } else { value };
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    /// Whether typing `ch` at the cursor keeps the buffer a valid partial number
+    fn accepts(&self, input: &TextInputState, ch: char) -> bool {
+        let mut chars: Vec<char> = input.content.chars().collect();
+        let position = input.cursor_position.min(chars.len());
+        chars.insert(position, ch);
+        is_partial_number(&chars.into_iter().collect::<String>(), !self.integer)
+    }
+
+    /// Replaces the buffer with `value` and reports it if it changed
+    fn set_value(&self, state: &mut NumberInputState, value: f64) {
+        state.input.content = format_number(value);
+        state.input.cursor_position = state.input.content.chars().count();
+        self.report(state, value);
+    }
+
+    /// Records `value` as the current value, calling `on_change` if it differs
+    fn report(&self, state: &mut NumberInputState, value: f64) {
+        if state.value != Some(value) {
+            state.value = Some(value);
+            if let Some(callback) = &self.on_change {
+                callback(value);
+            }
+        }
+    }
+
+    /// Moves the value by one step in `direction` (1 up, -1 down)
+    fn step_by(&self, state: &mut NumberInputState, direction: f64) {
+        let base = parse_number(&state.input.content)
+            .or(state.value)
+            .or(self.min)
+            .unwrap_or(0.0);
+        let decimals = decimal_places(self.step).max(decimal_places(base));
+        let value = round_to(base + direction * self.step, decimals);
+        self.set_value(state, self.normalize(value));
+    }
+
+    /// Clamps and reformats the buffer, reverting unparseable input
+    fn commit(&self, state: &mut NumberInputState) {
+        match parse_number(&state.input.content).or(state.value) {
+            Some(value) => self.set_value(state, self.normalize(value)),
+            None => {
+                state.input.content.clear();
+                state.input.cursor_position = 0;
+            }
+        }
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let mut state = self.load_state(ctx);
+
+        if let Some(msg) = msg.downcast::<NumberInputMsg>() {
+            match msg {
+                NumberInputMsg::Increment => self.step_by(&mut state, 1.0),
+                NumberInputMsg::Decrement => self.step_by(&mut state, -1.0),
+                // Wheel up increments
+                NumberInputMsg::Wheel(direction) => self.step_by(&mut state, -*direction as f64),
+            }
+            return Action::update(state);
+        }
+
+        if let Some(msg) = msg.downcast::<TextInputMsg>() {
+            match msg {
+                TextInputMsg::CharInput(ch) if !self.accepts(&state.input, *ch) => {
+                    return Action::update(state);
+                }
+                TextInputMsg::InsertNewline => return Action::update(state),
+                // Clamp before the wrapped input runs its blur/submit callbacks
+                TextInputMsg::Blurred | TextInputMsg::Submit => self.commit(&mut state),
+                _ => {}
+            }
+
+            self.input.apply(&mut state.input, msg);
+
+            // Report edits that leave a complete number in the buffer
+            if let Some(value) = parse_number(&state.input.content) {
+                self.report(&mut state, value);
+            }
+
+            return Action::update(state);
+        }
+
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = self.load_state(ctx);
+
+        let mut container = self
+            .input
+            .render(ctx, &state.input)
+            .on_key(Key::Up, ctx.handler(NumberInputMsg::Increment))
+            .on_key(Key::Down, ctx.handler(NumberInputMsg::Decrement));
+
+        if self.mouse_wheel {
+            container = container.on_scroll(ctx.handler_with_value(NumberInputMsg::Wheel));
+        }
+
+        container.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Whether `text` is a number or could become one by typing more
+///
+/// Accepts an optional leading `-`, digits, and at most one `.` when `fraction` is allowed.
+fn is_partial_number(text: &str, fraction: bool) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let mut seen_dot = false;
+    digits.chars().all(|ch| match ch {
+        '0'..='9' => true,
+        '.' if fraction && !seen_dot => {
+            seen_dot = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Parses a complete number, rejecting partial input such as `-` or `.`
+fn parse_number(text: &str) -> Option<f64> {
+    if !text.chars().any(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+    text.parse::<f64>().ok().filter(|value| value.is_finite())
+}
+
+/// Formats a value without trailing zeros
+fn format_number(value: f64) -> String {
+    // Avoid showing "-0" after stepping down to zero
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    value.to_string()
+}
+
+/// Counts the decimal places in the shortest representation of `value`
+fn decimal_places(value: f64) -> usize {
+    value
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+        .min(MAX_STEP_DECIMALS)
+}
+
+/// Rounds to a number of decimal places, removing floating point drift from stepping
+fn round_to(value: f64, decimals: usize) -> f64 {
+    let factor = 10f64.powi(decimals as i32);
+    (value * factor).round() / factor
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for NumberInput {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        NumberInput::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        NumberInput::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with(content: &str) -> NumberInputState {
+        let mut state = NumberInputState::default();
+        state.input.content = content.to_string();
+        state.input.cursor_position = content.chars().count();
+        state
+    }
+
+    #[test]
+    fn test_partial_numbers() {
+        for text in ["", "-", ".", "-.", "1.", "-12.5", "007"] {
+            assert!(is_partial_number(text, true), "{text:?} should be accepted");
+        }
+        for text in ["--1", "1.2.3", "1-", "1e5", "abc", " 1"] {
+            assert!(
+                !is_partial_number(text, true),
+                "{text:?} should be rejected"
+            );
+        }
+        assert!(!is_partial_number("1.", false));
+
+        assert_eq!(parse_number("-"), None);
+        assert_eq!(parse_number("-."), None);
+        assert_eq!(parse_number("1."), Some(1.0));
+    }
+
+    #[test]
+    fn test_commit_clamps_and_reverts() {
+        let input = NumberInput::new().min(0.0).max(100.0);
+
+        let mut state = state_with("150");
+        input.commit(&mut state);
+        assert_eq!(state.input.content, "100");
+        assert_eq!(state.value, Some(100.0));
+
+        // Unparseable input reverts to the last valid value
+        state.input.content = "-".to_string();
+        input.commit(&mut state);
+        assert_eq!(state.input.content, "100");
+
+        // With no previous value, it is cleared
+        let mut state = state_with("-");
+        input.commit(&mut state);
+        assert_eq!(state.input.content, "");
+        assert_eq!(state.value, None);
+    }
+
+    #[test]
+    fn test_step_rounds_and_clamps() {
+        let input = NumberInput::new().min(0.0).max(1.0).step(0.1);
+
+        let mut state = state_with("0.2");
+        input.step_by(&mut state, 1.0);
+        assert_eq!(state.input.content, "0.3");
+
+        let mut state = state_with("0.95");
+        input.step_by(&mut state, 1.0);
+        assert_eq!(state.input.content, "1");
+
+        // Empty buffers step from the minimum
+        let mut state = state_with("");
+        input.step_by(&mut state, -1.0);
+        assert_eq!(state.input.content, "0");
+    }
+
+    #[test]
+    fn test_integer_mode_rounds() {
+        let input = NumberInput::new().integer(true).step(0.5);
+
+        let mut state = state_with("2.6");
+        input.commit(&mut state);
+        assert_eq!(state.input.content, "3");
+
+        input.step_by(&mut state, 1.0);
+        assert_eq!(state.input.content, "4");
+        assert!(!input.accepts(&state.input, '.'));
+    }
+
+    #[test]
+    fn test_rejects_non_numeric_characters() {
+        let input = NumberInput::new();
+        let state = state_with("12");
+
+        assert!(input.accepts(&state.input, '.'));
+        assert!(!input.accepts(&state.input, 'x'));
+        assert!(!input.accepts(&state.input, '-'));
+
+        let mut at_start = state.input.clone();
+        at_start.cursor_position = 0;
+        assert!(input.accepts(&at_start, '-'));
+    }
+}
@@ -98,11 +98,11 @@ impl ShimmerSpeed {
         Self::new(33, 2)
     }
 
-    fn frame_delay(&self) -> Duration {
+    pub(crate) fn frame_delay(&self) -> Duration {
         Duration::from_millis(self.frame_delay_ms.max(1))
     }
 
-    fn phase_step(&self) -> usize {
+    pub(crate) fn phase_step(&self) -> usize {
         self.phase_step.max(1)
     }
 }
@@ -229,36 +229,11 @@ impl ShimmerText {
     }
 
     fn intensity_for_index(&self, index: usize, phase: usize, total: usize) -> f32 {
-        if total == 0 {
-            return 0.0;
-        }
-
-        (0..self.highlight_band)
-            .map(|offset| {
-                let pos = (phase + offset) % total;
-                self.circular_distance(index, pos, total)
-            })
-            .min()
-            .map(|distance| {
-                let normalized = 1.0 - (distance as f32 / self.highlight_band as f32);
-                normalized.clamp(0.0, 1.0).powf(1.4)
-            })
-            .unwrap_or(0.0)
-    }
-
-    fn circular_distance(&self, a: usize, b: usize, total: usize) -> usize {
-        let diff = a.abs_diff(b);
-        diff.min(total - diff)
+        shimmer_intensity(index, phase, total, self.highlight_band)
     }
 
     fn blend_color(&self, intensity: f32) -> Color {
-        let eased = 0.2 + intensity * 0.8;
-
-        let r = blend_channel(self.base_color.0, self.highlight_color.0, eased);
-        let g = blend_channel(self.base_color.1, self.highlight_color.1, eased);
-        let b = blend_channel(self.base_color.2, self.highlight_color.2, eased);
-
-        Color::Rgb(r, g, b)
+        shimmer_color(self.base_color, self.highlight_color, intensity)
     }
 }
 
@@ -292,13 +267,49 @@ impl Component for ShimmerText {
 // Functions: Helpers
 //--------------------------------------------------------------------------------------------------
 
+/// Brightness of the shimmer at `index`, from 0.0 to 1.0, with a highlight
+/// `band` wide starting at `phase` and wrapping around `total` positions.
+pub(crate) fn shimmer_intensity(index: usize, phase: usize, total: usize, band: usize) -> f32 {
+    if total == 0 {
+        return 0.0;
+    }
+
+    (0..band)
+        .map(|offset| {
+            let pos = (phase + offset) % total;
+            circular_distance(index, pos, total)
+        })
+        .min()
+        .map(|distance| {
+            let normalized = 1.0 - (distance as f32 / band as f32);
+            normalized.clamp(0.0, 1.0).powf(1.4)
+        })
+        .unwrap_or(0.0)
+}
+
+/// Color of the shimmer at `intensity`, between the base and highlight colors.
+pub(crate) fn shimmer_color(base: (u8, u8, u8), highlight: (u8, u8, u8), intensity: f32) -> Color {
+    let eased = 0.2 + intensity * 0.8;
+
+    let r = blend_channel(base.0, highlight.0, eased);
+    let g = blend_channel(base.1, highlight.1, eased);
+    let b = blend_channel(base.2, highlight.2, eased);
+
+    Color::Rgb(r, g, b)
+}
+
+fn circular_distance(a: usize, b: usize, total: usize) -> usize {
+    let diff = a.abs_diff(b);
+    diff.min(total - diff)
+}
+
 fn blend_channel(start: u8, end: u8, factor: f32) -> u8 {
     let start = start as f32;
     let end = end as f32;
     (start + (end - start) * factor).round().clamp(0.0, 255.0) as u8
 }
 
-fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+pub(crate) fn color_to_rgb(color: Color) -> (u8, u8, u8) {
     match color {
         Color::Black => (0, 0, 0),
         Color::Red => (205, 49, 49),
@@ -317,5 +328,7 @@ fn color_to_rgb(color: Color) -> (u8, u8, u8) {
         Color::BrightCyan => (41, 184, 219),
         Color::BrightWhite => (255, 255, 255),
         Color::Rgb(r, g, b) => (r, g, b),
+        // Unknown, so assume the light-on-dark foreground most terminals use
+        Color::Default => (229, 229, 229),
     }
 }
@@ -0,0 +1,351 @@
+use crate::Context;
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::shimmer_text::{
+    ShimmerSpeed, color_to_rgb, shimmer_color, shimmer_intensity,
+};
+use crate::effect::Effect;
+use crate::node::{Div, Node, RichText, TextSpan};
+use crate::style::{Color, Direction, TextStyle};
+
+//--------------------------------------------------------------------------------------------------
+// Types: Internal
+//--------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+enum SkeletonMsg {
+    Tick,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SkeletonState {
+    phase: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Types: Public API
+//--------------------------------------------------------------------------------------------------
+
+/// A loading placeholder of shimmering bars that stand in for content.
+///
+/// Draws `lines` bars of solid blocks with rounded ends, and sweeps a
+/// highlight across them with the same animation as [`ShimmerText`]. When
+/// there are several bars the last one is shorter, like the end of a
+/// paragraph. Taller bars, set with [`line_height`](Self::line_height), get
+/// rounded corners and can stand in for images or cards.
+///
+/// [`ShimmerText`]: crate::components::ShimmerText
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::components::{ShimmerSpeed, Skeleton};
+///
+/// let placeholder = Skeleton::new()
+///     .lines(4)
+///     .width(40)
+///     .gap(1)
+///     .speed(ShimmerSpeed::slow());
+/// ```
+#[derive(Clone)]
+pub struct Skeleton {
+    lines: usize,
+    width: u16,
+    line_height: u16,
+    gap: u16,
+    speed: ShimmerSpeed,
+    highlight_band: usize,
+    base_color: (u8, u8, u8),
+    highlight_color: (u8, u8, u8),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+const DEFAULT_LINES: usize = 3;
+const DEFAULT_WIDTH: u16 = 30;
+const DEFAULT_HIGHLIGHT_BAND: usize = 8;
+const DEFAULT_BASE_COLOR: (u8, u8, u8) = (45, 52, 68);
+const DEFAULT_HIGHLIGHT_COLOR: (u8, u8, u8) = (110, 125, 155);
+
+/// Share of the width taken by the last of several bars
+const LAST_LINE_FRACTION: f32 = 0.6;
+
+/// Block filling the inside of a bar
+const FULL_BLOCK: char = '█';
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Skeleton Builders
+//--------------------------------------------------------------------------------------------------
+
+impl Skeleton {
+    /// Creates a placeholder of three one-row bars, 30 cells wide.
+    pub fn new() -> Self {
+        Self {
+            lines: DEFAULT_LINES,
+            width: DEFAULT_WIDTH,
+            line_height: 1,
+            gap: 0,
+            speed: ShimmerSpeed::default(),
+            highlight_band: DEFAULT_HIGHLIGHT_BAND,
+            base_color: DEFAULT_BASE_COLOR,
+            highlight_color: DEFAULT_HIGHLIGHT_COLOR,
+        }
+    }
+
+    /// Sets the number of bars.
+    pub fn lines(mut self, lines: usize) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// Sets the width of the bars in cells.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of each bar in rows (minimum of 1).
+    pub fn line_height(mut self, height: u16) -> Self {
+        self.line_height = height.max(1);
+        self
+    }
+
+    /// Sets the empty rows between bars.
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the animation speed.
+    pub fn speed(mut self, speed: ShimmerSpeed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets the highlight band width (minimum of 1).
+    pub fn highlight_band(mut self, band: usize) -> Self {
+        self.highlight_band = band.max(1);
+        self
+    }
+
+    /// Sets the color of the bars away from the highlight.
+    pub fn base_color(mut self, color: Color) -> Self {
+        self.base_color = color_to_rgb(color);
+        self
+    }
+
+    /// Sets the color at the center of the highlight.
+    pub fn highlight_color(mut self, color: Color) -> Self {
+        self.highlight_color = color_to_rgb(color);
+        self
+    }
+
+    /// Sets both base and highlight colors at once.
+    pub fn gradient(mut self, base: Color, highlight: Color) -> Self {
+        self.base_color = color_to_rgb(base);
+        self.highlight_color = color_to_rgb(highlight);
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Skeleton Component Logic
+//--------------------------------------------------------------------------------------------------
+
+impl Skeleton {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        if let Some(SkeletonMsg::Tick) = msg.downcast::<SkeletonMsg>() {
+            if self.width == 0 {
+                return Action::none();
+            }
+
+            let mut state = ctx.get_state::<SkeletonState>();
+            state.phase = (state.phase + self.speed.phase_step()) % self.width as usize;
+            return Action::update(state);
+        }
+
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<SkeletonState>();
+        self.render(state.phase).into()
+    }
+
+    /// Builds the bars with the highlight at `phase`
+    fn render(&self, phase: usize) -> Div<Node> {
+        let bars = self.bar_widths().into_iter().map(|width| {
+            let rows = (0..self.line_height)
+                .map(|row| self.render_row(width, row, phase).into())
+                .collect();
+            Div::new()
+                .direction(Direction::Vertical)
+                .children(rows)
+                .into()
+        });
+
+        Div::new()
+            .direction(Direction::Vertical)
+            .gap(self.gap)
+            .children(bars.collect())
+    }
+
+    /// Returns the width of each bar, the last of several shortened
+    fn bar_widths(&self) -> Vec<u16> {
+        let mut widths = vec![self.width; self.lines];
+        if self.lines > 1
+            && let Some(last) = widths.last_mut()
+        {
+            *last = ((self.width as f32 * LAST_LINE_FRACTION).round() as u16).max(1);
+        }
+        widths
+    }
+
+    /// Builds one row of a bar, coloring each cell by its distance from the highlight
+    fn render_row(&self, width: u16, row: u16, phase: usize) -> RichText {
+        let spans = (0..width)
+            .map(|column| {
+                let intensity = shimmer_intensity(
+                    column as usize,
+                    phase,
+                    self.width as usize,
+                    self.highlight_band,
+                );
+                TextSpan {
+                    content: self.glyph(width, row, column).to_string(),
+                    style: Some(TextStyle {
+                        color: Some(shimmer_color(
+                            self.base_color,
+                            self.highlight_color,
+                            intensity,
+                        )),
+                        ..Default::default()
+                    }),
+                    is_cursor: false,
+                }
+            })
+            .collect();
+
+        RichText { spans, style: None }
+    }
+
+    /// Returns the glyph of a cell, with half blocks rounding off the ends
+    /// of one-row bars and quadrants rounding the corners of taller ones
+    fn glyph(&self, width: u16, row: u16, column: u16) -> char {
+        let last_row = self.line_height - 1;
+        let (left, right) = (column == 0, column + 1 == width);
+        if width < 2 {
+            return FULL_BLOCK;
+        }
+        match (last_row, row, left, right) {
+            (0, _, true, _) => '▐',
+            (0, _, _, true) => '▌',
+            (_, 0, true, _) => '▗',
+            (_, 0, _, true) => '▖',
+            (last, row, true, _) if row == last => '▝',
+            (last, row, _, true) if row == last => '▘',
+            _ => FULL_BLOCK,
+        }
+    }
+
+    fn effects(&self, ctx: &Context) -> Vec<Effect> {
+        let delay = self.speed.frame_delay();
+        let ctx = ctx.clone();
+
+        let effect = Box::pin(async move {
+            loop {
+                tokio::time::sleep(delay).await;
+                ctx.send(SkeletonMsg::Tick);
+            }
+        });
+
+        vec![effect]
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations: Component
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Skeleton {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Skeleton::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Skeleton::view(self, ctx)
+    }
+
+    fn effects(&self, ctx: &Context) -> Vec<Effect> {
+        Skeleton::effects(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the glyphs of each row drawn at `phase`
+    fn rows(skeleton: &Skeleton, phase: usize) -> Vec<String> {
+        skeleton
+            .render(phase)
+            .children
+            .iter()
+            .flat_map(|bar| match bar {
+                Node::Div(bar) => bar.children.clone(),
+                _ => panic!("expected a bar div"),
+            })
+            .map(|row| match row {
+                Node::RichText(text) => text.spans.iter().map(|s| s.content.as_str()).collect(),
+                _ => panic!("expected a rich text row"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bars_round_their_ends_and_shorten_the_last() {
+        let lines = Skeleton::new().width(5);
+        assert_eq!(rows(&lines, 0), ["▐███▌", "▐███▌", "▐█▌"]);
+
+        let block = Skeleton::new().lines(1).width(4).line_height(3);
+        assert_eq!(rows(&block, 0), ["▗██▖", "████", "▝██▘"]);
+    }
+
+    #[test]
+    fn test_highlight_follows_the_phase() {
+        let skeleton = Skeleton::new().lines(1).width(10).highlight_band(1);
+        let brightest = |phase| {
+            let bar = skeleton.render(phase);
+            let Node::Div(bar) = &bar.children[0] else {
+                panic!("expected a bar div");
+            };
+            let Node::RichText(row) = &bar.children[0] else {
+                panic!("expected a rich text row");
+            };
+            row.spans.iter().position(|span| {
+                span.style.as_ref().and_then(|s| s.color) == Some(Color::Rgb(110, 125, 155))
+            })
+        };
+        assert_eq!(brightest(0), Some(0));
+        assert_eq!(brightest(7), Some(7));
+    }
+}
@@ -0,0 +1,305 @@
+use crate::Context;
+use crate::canvas;
+use crate::component::Component;
+use crate::node::{Div, Node, RichText};
+use crate::style::{Color, TextStyle};
+use crate::theme::Palette;
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Bars from lowest to highest, one level per eighth of a cell
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Level drawn for every value when they are all equal
+const FLAT_LEVEL: usize = 3;
+
+/// Lowest level colored by `color_high` when no threshold is set
+const DEFAULT_HIGH_LEVEL: usize = 6;
+
+/// Highest level colored by `color_low` when no threshold is set
+const DEFAULT_LOW_LEVEL: usize = 1;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A one-line chart of a series of numbers, drawn with block characters
+///
+/// Each value takes a cell, as one of `▁▂▃▄▅▆▇█` scaled between the smallest
+/// and largest values shown, or the bounds set with [`range`](Self::range).
+/// Values that aren't finite, like `NaN`, are left out of the scale and leave
+/// a gap in their place. When all values are equal they are drawn halfway up.
+///
+/// With a [`width`](Self::width), only the latest values that fit are shown
+/// and a shorter series is pushed to the right end, so the newest value stays
+/// in the same place as the series grows.
+///
+/// Values can be colored by how high they are with [`color_high`](Self::color_high)
+/// and [`color_low`](Self::color_low). Without a threshold those apply to the
+/// top and bottom quarter of the scale.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let cpu = Sparkline::new(&state.cpu_history)
+///     .width(20)
+///     .range(0.0, 100.0)
+///     .color_high(Color::Red)
+///     .high_threshold(90.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Sparkline {
+    data: Vec<f64>,
+    width: Option<u16>,
+    range: Option<(f64, f64)>,
+    color: Option<Color>,
+    color_high: Option<Color>,
+    high_threshold: Option<f64>,
+    color_low: Option<Color>,
+    low_threshold: Option<f64>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Sparkline {
+    /// Creates a chart of `data`, one cell per value
+    pub fn new(data: &[f64]) -> Self {
+        Self {
+            data: data.to_vec(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the width in cells, showing the latest values that fit
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Scales values between `min` and `max` instead of the data's own bounds.
+    ///
+    /// Values outside the range are drawn at its nearest end.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets the color of the bars (the theme's primary color by default)
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the color of values at or above the high threshold
+    pub fn color_high(mut self, color: Color) -> Self {
+        self.color_high = Some(color);
+        self
+    }
+
+    /// Sets the value from which `color_high` applies
+    pub fn high_threshold(mut self, value: f64) -> Self {
+        self.high_threshold = Some(value);
+        self
+    }
+
+    /// Sets the color of values at or below the low threshold
+    pub fn color_low(mut self, color: Color) -> Self {
+        self.color_low = Some(color);
+        self
+    }
+
+    /// Sets the value up to which `color_low` applies
+    pub fn low_threshold(mut self, value: f64) -> Self {
+        self.low_threshold = Some(value);
+        self
+    }
+
+    /// Builds the chart with colors resolved against `palette`
+    fn render(&self, palette: &Palette) -> Div<Node> {
+        let width = self
+            .width
+            .unwrap_or(self.data.len().min(u16::MAX as usize) as u16);
+        let shown = canvas::latest(&self.data, width as usize);
+        let (min, max) = self
+            .range
+            .or_else(|| canvas::finite_bounds(shown))
+            .unwrap_or((0.0, 0.0));
+        let base = self.color.unwrap_or(palette.primary);
+
+        // Runs of cells with the same color share a span
+        let mut text = RichText::new();
+        let mut run = " ".repeat(width as usize - shown.len());
+        let mut run_color = base;
+        for &value in shown {
+            let (glyph, color) = if value.is_finite() {
+                let level = level(value, min, max);
+                (BARS[level], self.color_of(value, level).unwrap_or(base))
+            } else {
+                (' ', run_color)
+            };
+            if color != run_color && !run.is_empty() {
+                let style = TextStyle::default().color(run_color);
+                text = text.styled(std::mem::take(&mut run), style);
+            }
+            run_color = color;
+            run.push(glyph);
+        }
+        if !run.is_empty() {
+            text = text.styled(run, TextStyle::default().color(run_color));
+        }
+
+        Div::new().width(width).height(1).child(text.into())
+    }
+
+    /// Returns the threshold color for a value drawn at `level`, if any
+    fn color_of(&self, value: f64, level: usize) -> Option<Color> {
+        let high = match self.high_threshold {
+            Some(threshold) => value >= threshold,
+            None => level >= DEFAULT_HIGH_LEVEL,
+        };
+        let low = match self.low_threshold {
+            Some(threshold) => value <= threshold,
+            None => level <= DEFAULT_LOW_LEVEL,
+        };
+        if high && self.color_high.is_some() {
+            self.color_high
+        } else if low {
+            self.color_low
+        } else {
+            None
+        }
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        self.render(&ctx.theme().palette).into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the bar index of `value` scaled between `min` and `max`
+fn level(value: f64, min: f64, max: f64) -> usize {
+    canvas::scale(value, min, max, BARS.len() - 1).unwrap_or(FLAT_LEVEL)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Sparkline {
+    fn view(&self, ctx: &Context) -> Node {
+        Sparkline::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Dimension;
+
+    /// Returns the spans of the chart as (text, color) pairs
+    fn spans(sparkline: &Sparkline) -> Vec<(String, Option<Color>)> {
+        let chart = sparkline.render(&Palette::dark());
+        let Node::RichText(text) = &chart.children[0] else {
+            panic!("expected a rich text chart");
+        };
+        text.spans
+            .iter()
+            .map(|span| {
+                let color = span.style.as_ref().and_then(|style| style.color);
+                (span.content.clone(), color)
+            })
+            .collect()
+    }
+
+    /// Returns the text of the chart
+    fn text(sparkline: &Sparkline) -> String {
+        spans(sparkline).into_iter().map(|(text, _)| text).collect()
+    }
+
+    #[test]
+    fn test_values_scale_between_min_and_max() {
+        let data = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        assert_eq!(text(&Sparkline::new(&data)), "▁▂▃▄▅▆▇█");
+        assert_eq!(text(&Sparkline::new(&[10.0, 20.0])), "▁█");
+        assert_eq!(text(&Sparkline::new(&[1.0, 9.0]).range(0.0, 16.0)), "▁▅");
+        assert_eq!(text(&Sparkline::new(&[-5.0, 50.0]).range(0.0, 10.0)), "▁█");
+    }
+
+    #[test]
+    fn test_edge_cases_keep_their_width() {
+        assert_eq!(text(&Sparkline::new(&[])), "");
+        assert_eq!(text(&Sparkline::new(&[]).width(3)), "   ");
+        assert_eq!(text(&Sparkline::new(&[42.0])), "▄");
+        assert_eq!(
+            text(&Sparkline::new(&[0.0, f64::NAN, 7.0, f64::INFINITY])),
+            "▁ █ "
+        );
+
+        // The latest values are kept and a short series ends at the right edge
+        let data = [0.0, 7.0, 0.0, 7.0];
+        assert_eq!(text(&Sparkline::new(&data).width(2)), "▁█");
+        assert_eq!(text(&Sparkline::new(&data).width(6)), "  ▁█▁█");
+
+        // Without a width, a series too long for a u16 keeps its latest values
+        let long = vec![1.0; u16::MAX as usize + 2];
+        assert_eq!(
+            text(&Sparkline::new(&long)).chars().count(),
+            u16::MAX as usize
+        );
+        let chart = Sparkline::new(&data).width(6).render(&Palette::dark());
+        let width = chart.styles.base.and_then(|style| style.width);
+        assert_eq!(width, Some(Dimension::Fixed(6)));
+    }
+
+    #[test]
+    fn test_thresholds_color_runs_of_values() {
+        let primary = Palette::dark().primary;
+        let data = [1.0, 2.0, 5.0, 9.0, 10.0, 3.0];
+        let chart = Sparkline::new(&data)
+            .range(0.0, 10.0)
+            .color_high(Color::Red)
+            .high_threshold(9.0)
+            .color_low(Color::Green);
+        assert_eq!(
+            spans(&chart),
+            [
+                ("▂▂".to_string(), Some(Color::Green)),
+                ("▅".to_string(), Some(primary)),
+                ("▇█".to_string(), Some(Color::Red)),
+                ("▃".to_string(), Some(primary)),
+            ]
+        );
+
+        // Without a threshold, the top and bottom quarters are colored
+        let chart = Sparkline::new(&[0.0, 4.0, 7.0]).color_high(Color::Red);
+        assert_eq!(
+            spans(&chart),
+            [
+                ("▁▅".to_string(), Some(primary)),
+                ("█".to_string(), Some(Color::Red)),
+            ]
+        );
+    }
+}
@@ -3,6 +3,7 @@ use crate::component::{Action, Component, Message, MessageExt};
 use crate::effect::Effect;
 use crate::node::{Node, Text};
 use crate::style::{Color, TextStyle};
+use crate::utils::display_width;
 use std::time::Duration;
 
 //--------------------------------------------------------------------------------------------------
@@ -19,8 +20,11 @@ pub enum SpinnerMsg {
 /// State for Spinner component
 #[derive(Debug, Clone, Default)]
 struct SpinnerState {
-    /// Current frame index
+    /// Current frame index, counted in playback order
     frame_index: usize,
+
+    /// Whether a non-looping animation has played through
+    finished: bool,
 }
 
 /// Spinner pattern data
@@ -33,11 +37,12 @@ struct SpinnerPattern {
 //--------------------------------------------------------------------------------------------------
 
 /// Animation speed for the spinner
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SpinnerSpeed {
     /// Slow animation (150ms per frame)
     Slow,
     /// Normal animation (80ms per frame)
+    #[default]
     Normal,
     /// Fast animation (50ms per frame)
     Fast,
@@ -46,8 +51,9 @@ pub enum SpinnerSpeed {
 }
 
 /// Available spinner types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum SpinnerType {
+    #[default]
     Dots,
     Dots2,
     Dots3,
@@ -118,24 +124,30 @@ pub enum SpinnerType {
 /// let spinner = Spinner::new()
 ///     .spinner_type(SpinnerType::Hearts)
 ///     .speed(SpinnerSpeed::Fast);
+///
+/// // Own frames, played backwards once, ending on a check mark
+/// let spinner = Spinner::custom(vec!["🌑", "🌒", "🌓", "🌔", "🌕"])
+///     .reverse(true)
+///     .looping(false)
+///     .final_frame("✔");
 /// ```
+///
+/// Every frame is padded to the width of the widest one, so the spinner takes
+/// the same space on each frame.
 #[derive(Clone)]
 pub struct Spinner {
     spinner_type: SpinnerType,
     speed: SpinnerSpeed,
     color: Option<Color>,
+    reverse: bool,
+    looping: bool,
+    final_frame: Option<String>,
 }
 
 //--------------------------------------------------------------------------------------------------
-// Trait Implementations: SpinnerSpeed
+// Methods: SpinnerSpeed
 //--------------------------------------------------------------------------------------------------
 
-impl Default for SpinnerSpeed {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
 impl SpinnerSpeed {
     fn interval(&self) -> u64 {
         match self {
@@ -147,16 +159,6 @@ impl SpinnerSpeed {
     }
 }
 
-//--------------------------------------------------------------------------------------------------
-// Trait Implementations: SpinnerType
-//--------------------------------------------------------------------------------------------------
-
-impl Default for SpinnerType {
-    fn default() -> Self {
-        Self::Dots
-    }
-}
-
 //--------------------------------------------------------------------------------------------------
 // Constants
 //--------------------------------------------------------------------------------------------------
@@ -512,9 +514,20 @@ impl Spinner {
             spinner_type: SpinnerType::default(),
             speed: SpinnerSpeed::default(),
             color: None,
+            reverse: false,
+            looping: true,
+            final_frame: None,
         }
     }
 
+    /// Creates a Spinner that plays the given frames
+    pub fn custom<S>(frames: Vec<S>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new().custom_pattern(frames)
+    }
+
     /// Set the spinner animation type
     pub fn spinner_type(mut self, spinner_type: SpinnerType) -> Self {
         self.spinner_type = spinner_type;
@@ -527,7 +540,7 @@ impl Spinner {
         self
     }
 
-    /// Set the spinner color
+    /// Set the spinner color (defaults to the theme's primary color)
     pub fn color(mut self, color: Color) -> Self {
         self.color = Some(color);
         self
@@ -550,14 +563,80 @@ impl Spinner {
         self
     }
 
+    /// Play the frames last to first
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Repeat the animation (the default), or play it once and stop
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Set the frame shown once a non-looping animation has played through
+    ///
+    /// Defaults to the last frame played.
+    pub fn final_frame(mut self, frame: impl Into<String>) -> Self {
+        self.final_frame = Some(frame.into());
+        self
+    }
+
+    /// Moves the state to the next frame, returning whether it changed
+    fn advance(&self, state: &mut SpinnerState, frame_count: usize) -> bool {
+        if frame_count == 0 || state.finished {
+            return false;
+        }
+
+        if state.frame_index + 1 < frame_count {
+            state.frame_index += 1;
+        } else if self.looping {
+            state.frame_index = 0;
+        } else {
+            state.finished = true;
+        }
+        true
+    }
+
+    /// The frame to show for the state, padded to the widest frame
+    fn current_frame(&self, state: &SpinnerState, frames: &[String]) -> String {
+        let Some(last) = frames.len().checked_sub(1) else {
+            return String::new();
+        };
+
+        let played = |index: usize| {
+            let index = index.min(last);
+            if self.reverse {
+                &frames[last - index]
+            } else {
+                &frames[index]
+            }
+        };
+        let frame = match (&self.final_frame, state.finished) {
+            (Some(final_frame), true) => final_frame,
+            (None, true) => played(last),
+            (_, false) => played(state.frame_index),
+        };
+
+        let width = frames
+            .iter()
+            .chain(&self.final_frame)
+            .map(|frame| display_width(frame))
+            .max()
+            .unwrap_or(0);
+        let padding = width.saturating_sub(display_width(frame));
+        format!("{frame}{}", " ".repeat(padding))
+    }
+
     fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
         if let Some(msg) = msg.downcast::<SpinnerMsg>() {
             let mut state = ctx.get_state::<SpinnerState>();
             match msg {
                 SpinnerMsg::Tick => {
-                    let frames = self.get_frames();
-                    state.frame_index = (state.frame_index + 1) % frames.len();
-                    return Action::update(state);
+                    if self.advance(&mut state, self.get_frames().len()) {
+                        return Action::update(state);
+                    }
                 }
             }
         }
@@ -566,20 +645,14 @@ impl Spinner {
 
     fn view(&self, ctx: &Context) -> Node {
         let state = ctx.get_state::<SpinnerState>();
-        let frames = self.get_frames();
-
-        // Get current frame
-        let frame_index = state.frame_index % frames.len();
-        let frame = &frames[frame_index];
+        let frame = self.current_frame(&state, &self.get_frames());
 
-        // Create text node with optional color
+        // Use the configured color, falling back to the theme's primary color
         let mut text = Text::new(frame);
-        if let Some(color) = self.color {
-            text.style = Some(TextStyle {
-                color: Some(color),
-                ..Default::default()
-            });
-        }
+        text.style = Some(TextStyle {
+            color: Some(self.color.unwrap_or(ctx.theme().palette.primary)),
+            ..Default::default()
+        });
 
         text.into()
     }
@@ -588,10 +661,15 @@ impl Spinner {
         let ctx = ctx.clone();
         let interval = self.speed.interval();
 
+        // A single pass needs one tick per frame, the last one finishing it
+        let ticks = (!self.looping).then(|| self.get_frames().len());
+
         let effect = Box::pin(async move {
-            loop {
+            let mut sent = 0;
+            while ticks.is_none_or(|ticks| sent < ticks) {
                 tokio::time::sleep(Duration::from_millis(interval)).await;
                 ctx.send(SpinnerMsg::Tick);
+                sent += 1;
             }
         });
 
@@ -630,3 +708,50 @@ impl Default for Spinner {
         Self::new()
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(spinner: &Spinner, ticks: usize) -> Vec<String> {
+        let frames = spinner.get_frames();
+        let mut state = SpinnerState::default();
+        let mut shown = vec![spinner.current_frame(&state, &frames)];
+        for _ in 0..ticks {
+            spinner.advance(&mut state, frames.len());
+            shown.push(spinner.current_frame(&state, &frames));
+        }
+        shown
+    }
+
+    #[test]
+    fn test_custom_frames_reverse_and_loop() {
+        let spinner = Spinner::custom(vec!["a", "b", "c"]);
+        assert_eq!(play(&spinner, 4), ["a", "b", "c", "a", "b"]);
+
+        let spinner = spinner.reverse(true);
+        assert_eq!(play(&spinner, 4), ["c", "b", "a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_play_once_stops_on_final_frame() {
+        let spinner = Spinner::custom(vec!["a", "b"]).looping(false);
+        assert_eq!(play(&spinner, 3), ["a", "b", "b", "b"]);
+
+        let spinner = spinner.reverse(true).final_frame("done");
+        assert_eq!(play(&spinner, 3), ["b   ", "a   ", "done", "done"]);
+    }
+
+    #[test]
+    fn test_frames_padded_to_widest() {
+        // The emoji is two columns wide
+        let spinner = Spinner::custom(vec![".", "🌕", "..."]);
+        assert_eq!(play(&spinner, 2), [".  ", "🌕 ", "..."]);
+
+        assert_eq!(play(&Spinner::custom(Vec::<String>::new()), 1), ["", ""]);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::Context;
+use crate::component::Component;
+use crate::node::{Div, Node, RichText};
+use crate::style::{Color, Direction, Spacing, TextStyle, WrapMode};
+use crate::theme::Palette;
+use std::any::Any;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Default number of blank cells between hints
+const DEFAULT_SPACING: u16 = 2;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A full-width bar of key hints, such as `q Quit  / Search  Enter Select`
+///
+/// Each hint shows its key in bold followed by a label. Keys and labels use
+/// the theme's primary and foreground colors unless overridden. Hints added
+/// with `right_item` are pushed to the right end of the bar.
+///
+/// The bar is one line tall. With `wrap(true)`, hints that don't fit move to
+/// further lines instead of being cut off; a hint is never split between lines.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let hints = StatusBar::new()
+///     .item("q", "Quit")
+///     .item("/", "Search")
+///     .item("Enter", "Select")
+///     .right_item("?", "Help");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StatusBar {
+    items: Vec<(String, String)>,
+    right_items: Vec<(String, String)>,
+    key_color: Option<Color>,
+    label_color: Option<Color>,
+    background: Option<Color>,
+    spacing: Option<u16>,
+    wrap: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl StatusBar {
+    /// Creates an empty status bar
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a hint to the left section
+    pub fn item(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        self.items.push((key.into(), label.into()));
+        self
+    }
+
+    /// Adds a hint to the right-aligned section
+    pub fn right_item(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        self.right_items.push((key.into(), label.into()));
+        self
+    }
+
+    /// Sets the key color (the theme's primary color by default)
+    pub fn key_color(mut self, color: Color) -> Self {
+        self.key_color = Some(color);
+        self
+    }
+
+    /// Sets the label color (the theme's foreground color by default)
+    pub fn label_color(mut self, color: Color) -> Self {
+        self.label_color = Some(color);
+        self
+    }
+
+    /// Sets the background color of the whole bar
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Sets the number of blank cells between hints (2 by default)
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Moves hints that don't fit onto further lines instead of cutting them off
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Builds the bar with colors resolved against `palette`
+    fn render<T: From<RichText> + From<Div<T>>>(&self, palette: &Palette) -> Div<T> {
+        let key_style = TextStyle::default()
+            .color(self.key_color.unwrap_or(palette.primary))
+            .bold(true);
+        let label_style =
+            TextStyle::default().color(self.label_color.unwrap_or(palette.foreground));
+
+        let mut bar = Div::new()
+            .direction(Direction::Horizontal)
+            .width_fraction(1.0)
+            .height_content();
+
+        if let Some(background) = self.background {
+            bar = bar.background(background);
+        }
+
+        // The left section takes whatever the right section leaves
+        let left = self
+            .section(&self.items, &key_style, &label_style)
+            .width_auto()
+            .padding(Spacing {
+                left: 1,
+                ..Spacing::all(0)
+            });
+        bar = bar.child(left.into());
+
+        if !self.right_items.is_empty() {
+            let right = self
+                .section(&self.right_items, &key_style, &label_style)
+                .width_content()
+                .padding(Spacing {
+                    right: 1,
+                    ..Spacing::all(0)
+                });
+            bar = bar.child(right.into());
+        }
+
+        bar
+    }
+
+    /// Lays out a group of hints in a row, each kept whole.
+    ///
+    /// Hints are spaced with trailing padding rather than a gap, since a gap
+    /// would also open blank lines between wrapped rows.
+    fn section<T: From<RichText> + From<Div<T>>>(
+        &self,
+        items: &[(String, String)],
+        key_style: &TextStyle,
+        label_style: &TextStyle,
+    ) -> Div<T> {
+        let spacing = self.spacing.unwrap_or(DEFAULT_SPACING);
+        let mut section = Div::new().direction(Direction::Horizontal).height_content();
+
+        if self.wrap {
+            section = section.wrap(WrapMode::Wrap);
+        }
+
+        for (index, (key, label)) in items.iter().enumerate() {
+            let hint = RichText::new()
+                .styled(key.clone(), key_style.clone())
+                .styled(format!(" {label}"), label_style.clone());
+            let trailing = if index + 1 < items.len() { spacing } else { 0 };
+            let cell = Div::new()
+                .width_content()
+                .height_content()
+                .padding(Spacing {
+                    right: trailing,
+                    ..Spacing::all(0)
+                })
+                .child(hint.into());
+            section = section.child(cell.into());
+        }
+
+        section
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        self.render::<Node>(&ctx.theme().palette).into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for StatusBar {
+    fn view(&self, ctx: &Context) -> Node {
+        StatusBar::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::ScreenBuffer;
+    use crate::render_tree::RenderNode;
+    use crate::vdom::VDom;
+    use crate::vnode::VNode;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Lays out the bar at `width` and returns it with the screen it paints.
+    fn draw(bar: &StatusBar, width: u16) -> (Rc<RefCell<RenderNode>>, String) {
+        let node: VNode = bar.render(&Palette::dark()).into();
+        let mut vdom = VDom::new();
+        vdom.render(node);
+        vdom.layout(width, 10);
+
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let mut buffer = ScreenBuffer::new(width, 10);
+        crate::app::renderer::render_node_to_buffer(
+            &root.borrow(),
+            &mut buffer,
+            &crate::bounds::Rect::new(0, 0, width, 10),
+            None,
+        );
+
+        let screen = (0..10)
+            .map(|y| {
+                let line: String = (0..width)
+                    .map(|x| buffer.get_cell(x, y).unwrap().char)
+                    .collect();
+                line.trim_end().to_string()
+            })
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        (root, screen)
+    }
+
+    #[test]
+    fn test_hints_fill_width_with_right_section() {
+        let bar = StatusBar::new()
+            .item("q", "Quit")
+            .item("/", "Search")
+            .right_item("?", "Help");
+
+        let (root, screen) = draw(&bar, 30);
+        assert_eq!(root.borrow().width, 30);
+        assert_eq!(screen, " q Quit  / Search      ? Help");
+    }
+
+    #[test]
+    fn test_wrap_keeps_hints_whole() {
+        let bar = StatusBar::new()
+            .item("q", "Quit")
+            .item("/", "Search")
+            .item("Enter", "Select")
+            .wrap(true);
+
+        let (root, screen) = draw(&bar, 20);
+        assert_eq!(root.borrow().height, 2);
+        assert_eq!(screen, " q Quit  / Search\n Enter Select");
+    }
+}
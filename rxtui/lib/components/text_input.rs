@@ -3,9 +3,11 @@ use crate::key::{Key, KeyWithModifiers};
 use crate::node::Node;
 use crate::node::{DivStyles, RichText, Text};
 use crate::style::{
-    Border, BorderEdges, BorderStyle, Color, Dimension, Overflow, Position, Spacing, Style,
-    TextStyle, TextWrap,
+    Border, BorderEdges, BorderStyle, Color, Dimension, Offset, Overflow, Position, Spacing, Style,
+    TextStyle, TextWrap, ZIndex,
 };
+use crate::theme::Theme;
+use crate::utils::{grapheme_count, next_grapheme_boundary, prev_grapheme_boundary};
 use crate::{Context, Div};
 use std::any::Any;
 use std::rc::Rc;
@@ -14,6 +16,15 @@ use std::rc::Rc;
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// Autocomplete provider mapping the current content to a suggested line
+type SuggestionFn = Box<dyn Fn(&str) -> Option<String>>;
+
+/// Predicate deciding whether a typed character is accepted
+type FilterFn = Box<dyn Fn(char) -> bool>;
+
+/// Validator returning an error message for invalid content
+type ValidateFn = Box<dyn Fn(&str) -> Result<(), String>>;
+
 /// Messages for TextInput component
 #[derive(Debug, Clone)]
 pub enum TextInputMsg {
@@ -64,11 +75,26 @@ pub enum TextInputMsg {
     Copy,
     Paste(String),
 
-    /// Submit (Enter key)
+    /// Submit (Enter key by default, or the configured submit key)
     Submit,
 
+    /// Insert a newline at the cursor (Enter key when `enter_inserts_newline` is set)
+    InsertNewline,
+
     /// Clear the input content
     Clear,
+
+    /// Recall the previous history entry (Up key when history is enabled)
+    HistoryPrevious,
+
+    /// Recall the next history entry, or the line being edited (Down key)
+    HistoryNext,
+
+    /// Accept the current autocomplete suggestion (Tab/Right)
+    AcceptSuggestion,
+
+    /// Show or hide password content (the configured reveal key)
+    ToggleReveal,
 }
 
 /// State for TextInput component
@@ -88,19 +114,32 @@ pub struct TextInputState {
 
     /// End of selection (None if no selection)
     pub selection_end: Option<usize>,
+
+    /// Entries submitted during this session, oldest first
+    pub history: Vec<String>,
+
+    /// History entry currently recalled (None while editing a fresh line)
+    pub history_index: Option<usize>,
+
+    /// The line being edited before history browsing started
+    pub history_draft: String,
+
+    /// Whether password content is temporarily shown as plaintext
+    pub revealed: bool,
 }
 
 /// A text input component for user text entry with sensible defaults
 ///
-/// TextInput comes with default styling:
-/// - Cyan single-line border
+/// TextInput comes with default styling, with colors taken from the app [`Theme`]:
+/// - Border in the theme's border style and primary color (cyan single line by default)
 /// - Width of 30 cells
 /// - Height of 3 cells (to accommodate border)
 /// - Horizontal padding of 1 cell
-/// - Placeholder text in italic grey (BrightBlack)
-/// - Content text in normal white
+/// - Placeholder text in italic, in the muted palette color
+/// - Content text in the foreground palette color
+/// - The theme's focus and hover styles
 ///
-/// All defaults can be overridden using the builder methods.
+/// All defaults can be overridden per instance using the builder methods.
 /// Supports full text editing with keyboard input, placeholder styling,
 /// content styling, and focus styling.
 ///
@@ -141,6 +180,74 @@ pub struct TextInputState {
 ///     .placeholder_bold(true);            // Make it bold instead
 /// ```
 ///
+/// # Submit Behavior
+///
+/// By default, pressing Enter submits the input and fires `on_submit`.
+/// Use `enter_inserts_newline(true)` to make Enter insert a newline instead,
+/// and `submit_key(...)` to bind submission to another key combination.
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// // Enter inserts a newline, Alt+Enter submits
+/// let input = TextInput::new()
+///     .enter_inserts_newline(true)
+///     .submit_key(KeyWithModifiers::with_alt(Key::Enter))
+///     .clear_on_submit(true);
+/// ```
+///
+/// # Password Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// // Masked with `*`, Ctrl+R toggles showing the plaintext
+/// let input = TextInput::new()
+///     .password(true)
+///     .mask_char('*')
+///     .reveal_key(KeyWithModifiers::with_ctrl(Key::Char('r')));
+/// ```
+///
+/// # History and Suggestions
+///
+/// With `history(...)`, Up/Down cycle through previous entries and every submit
+/// is committed to the history. `suggestions(...)` shows a completion as dimmed
+/// ghost text after the cursor, accepted with Tab or Right.
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let commands = ["help", "history", "quit"];
+/// let input = TextInput::new()
+///     .history(vec!["help".into()])
+///     .suggestions(move |line| {
+///         commands
+///             .iter()
+///             .find(|cmd| !line.is_empty() && cmd.starts_with(line))
+///             .map(|cmd| cmd.to_string())
+///     })
+///     .clear_on_submit(true);
+/// ```
+///
+/// # Constraints and Validation
+///
+/// `max_length(...)` and `filter(...)` reject keystrokes before they reach the
+/// content, so `on_change` only ever sees accepted text. `validate(...)` never
+/// blocks typing; while it returns an error the input is drawn with the error
+/// style (the palette's error color on the border by default).
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let input = TextInput::new()
+///     .max_length(5)
+///     .filter(|ch| ch.is_ascii_digit())
+///     .validate(|text| match text.len() {
+///         5 => Ok(()),
+///         _ => Err("zip codes have 5 digits".into()),
+///     });
+/// ```
+///
 /// # Content Styling Example
 ///
 /// ```ignore
@@ -158,11 +265,24 @@ pub struct TextInput {
     content_style: Option<TextStyle>,
     cursor_style: Option<TextStyle>,
     selection_style: Option<TextStyle>,
+    suggestion_style: Option<TextStyle>,
     styles: DivStyles,
+    error_style: Option<Style>,
+    border_edges: Option<BorderEdges>,
     focusable: bool,
     wrap: Option<TextWrap>,
     password_mode: bool,
+    mask_char: char,
+    reveal: bool,
+    reveal_key: Option<KeyWithModifiers>,
     clear_on_submit: bool,
+    enter_inserts_newline: bool,
+    submit_key: Option<KeyWithModifiers>,
+    history: Option<Vec<String>>,
+    suggestions: Option<SuggestionFn>,
+    max_length: Option<usize>,
+    filter: Option<FilterFn>,
+    validate: Option<ValidateFn>,
     on_change: Option<Box<dyn Fn(String)>>,
     on_submit: Option<Box<dyn Fn()>>,
     on_blur: Option<Box<dyn Fn()>>,
@@ -177,6 +297,71 @@ pub struct TextInput {
 //--------------------------------------------------------------------------------------------------
 
 impl TextInput {
+    /// Helper to insert a character at the cursor, replacing any selection
+    fn insert_char(&self, state: &mut TextInputState, ch: char) {
+        // Drop the keystroke if it would break a constraint
+        if !self.accepts(state, ch) {
+            return;
+        }
+
+        // Clear selection and insert at cursor
+        if state.selection_start.is_some() {
+            self.delete_selection(state);
+        }
+
+        // Convert to char indices
+        let char_pos = state.cursor_position;
+        let mut chars: Vec<char> = state.content.chars().collect();
+
+        // Insert character at cursor position
+        if char_pos <= chars.len() {
+            chars.insert(char_pos, ch);
+            state.content = chars.into_iter().collect();
+            state.cursor_position += 1;
+
+            // Call on_change callback
+            if let Some(callback) = &self.on_change {
+                callback(state.content.clone());
+            }
+        }
+    }
+
+    /// Returns whether `ch` passes the filter and fits within the maximum length
+    ///
+    /// Typing over a selection frees the room the selection takes.
+    fn accepts(&self, state: &TextInputState, ch: char) -> bool {
+        if let Some(filter) = &self.filter
+            && !filter(ch)
+        {
+            return false;
+        }
+
+        let Some(max_length) = self.max_length else {
+            return true;
+        };
+        let selected = match (state.selection_start, state.selection_end) {
+            (Some(start), Some(end)) => start.abs_diff(end),
+            _ => 0,
+        };
+        state.content.chars().count() - selected < max_length
+    }
+
+    /// Returns `text` with filtered characters dropped and cut to the maximum length
+    fn constrain(&self, text: &str) -> String {
+        let chars = text
+            .chars()
+            .filter(|&ch| self.filter.as_ref().is_none_or(|filter| filter(ch)));
+        match self.max_length {
+            Some(max_length) => chars.take(max_length).collect(),
+            None => chars.collect(),
+        }
+    }
+
+    /// Returns the validator's error for the given content, if any
+    fn validation_error(&self, content: &str) -> Option<String> {
+        self.validate.as_ref()?(content).err()
+    }
+
     /// Helper to delete selected text
     fn delete_selection(&self, state: &mut TextInputState) {
         if let (Some(start), Some(end)) = (state.selection_start, state.selection_end) {
@@ -297,76 +482,113 @@ impl TextInput {
         chars.drain(state.cursor_position..);
         state.content = chars.into_iter().collect();
     }
-    /// Creates the default style for TextInput components
-    fn default_style() -> Style {
+
+    /// Returns the seeded history followed by the entries submitted this session
+    fn history_entries<'a>(&'a self, state: &'a TextInputState) -> Vec<&'a str> {
+        self.history
+            .iter()
+            .flatten()
+            .chain(&state.history)
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Helper to replace the whole content, leaving the cursor at the end
+    ///
+    /// The content is constrained like typed text.
+    fn set_content(&self, state: &mut TextInputState, content: String) {
+        state.content = self.constrain(&content);
+        state.cursor_position = state.content.chars().count();
+        state.selection_start = None;
+        state.selection_end = None;
+
+        if let Some(callback) = &self.on_change {
+            callback(state.content.clone());
+        }
+    }
+
+    /// Returns whether the content is currently shown as mask glyphs
+    fn is_masked(&self, state: &TextInputState) -> bool {
+        self.password_mode && !self.reveal && !state.revealed
+    }
+
+    /// Returns the suggested text to show after the cursor, if any
+    ///
+    /// Only offered while focused with the cursor at the end and nothing selected.
+    fn ghost_text(&self, state: &TextInputState) -> Option<String> {
+        let provider = self.suggestions.as_ref()?;
+        if !state.focused
+            || self.password_mode
+            || state.selection_start.is_some()
+            || state.cursor_position != state.content.chars().count()
+        {
+            return None;
+        }
+
+        let suggestion = provider(&state.content)?;
+        suggestion
+            .strip_prefix(state.content.as_str())
+            .filter(|rest| !rest.is_empty())
+            .map(str::to_string)
+    }
+    /// Creates the default container style from the theme
+    fn default_style(theme: &Theme) -> Style {
         Style {
             padding: Some(Spacing::horizontal(1)),
             width: Some(Dimension::Fixed(30)),
             height: Some(Dimension::Fixed(3)),
-            border: Some(Border {
-                enabled: true,
-                style: BorderStyle::Single,
-                color: Color::Cyan,
-                edges: BorderEdges::ALL,
-            }),
+            border: Some(Border::with_style(
+                theme.border_style,
+                theme.palette.primary,
+            )),
             overflow: Some(Overflow::Hidden),
             ..Default::default()
         }
     }
 
-    /// Creates the default placeholder text style (italic, grey)
-    fn default_placeholder_style() -> TextStyle {
+    /// Creates the default style for invalid content (border in the error color)
+    fn default_error_style(theme: &Theme, edges: Option<BorderEdges>) -> Style {
+        let mut border = Border::with_style(theme.border_style, theme.palette.error);
+        if let Some(edges) = edges {
+            border.edges = edges;
+        }
+        Style {
+            border: Some(border),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the default placeholder text style (italic, muted)
+    fn default_placeholder_style(theme: &Theme) -> TextStyle {
         TextStyle {
-            color: Some(Color::BrightBlack), // Grey color
-            background: None,
-            bold: None,
-            italic: Some(true), // Italic style
-            underline: None,
-            strikethrough: None,
-            wrap: None,
-            align: None,
+            color: Some(theme.palette.muted),
+            italic: Some(true),
+            ..Default::default()
         }
     }
 
-    /// Creates the default content text style (normal white text)
-    fn default_content_style() -> TextStyle {
+    /// Creates the default content text style (theme foreground)
+    fn default_content_style(theme: &Theme) -> TextStyle {
         TextStyle {
-            color: Some(Color::White), // Normal white text
-            background: None,
-            bold: None,
-            italic: None,
-            underline: None,
-            strikethrough: None,
-            wrap: None,
-            align: None,
+            color: Some(theme.palette.foreground),
+            ..Default::default()
         }
     }
 
-    /// Creates the default cursor style (inverted background)
-    fn default_cursor_style() -> TextStyle {
+    /// Creates the default suggestion ghost text style (muted)
+    fn default_suggestion_style(theme: &Theme) -> TextStyle {
         TextStyle {
-            color: Some(Color::Black),
-            background: Some(Color::White),
-            bold: None,
-            italic: None,
-            underline: None,
-            strikethrough: None,
-            wrap: None,
-            align: None,
+            color: Some(theme.palette.muted),
+            ..Default::default()
         }
     }
 
-    /// Creates the default selection style (blue background)
-    fn default_selection_style() -> TextStyle {
+    /// Creates the default cursor style (inverted cell)
+    fn default_cursor_style(theme: &Theme) -> TextStyle {
         TextStyle {
-            color: Some(Color::White),
-            background: Some(Color::Blue),
-            bold: None,
-            italic: None,
-            underline: None,
-            strikethrough: None,
-            wrap: None,
-            align: None,
+            color: Some(theme.palette.cursor_foreground),
+            background: Some(theme.palette.cursor_background),
+            ..Default::default()
         }
     }
 
@@ -374,19 +596,33 @@ impl TextInput {
     pub fn new() -> Self {
         Self {
             placeholder: None,
-            placeholder_style: Some(Self::default_placeholder_style()),
-            content_style: Some(Self::default_content_style()),
-            cursor_style: Some(Self::default_cursor_style()),
-            selection_style: Some(Self::default_selection_style()),
+            placeholder_style: None,
+            content_style: None,
+            cursor_style: None,
+            selection_style: None,
+            suggestion_style: None,
             styles: DivStyles {
-                base: Some(Self::default_style()),
+                base: None,
                 focus: None,
                 hover: None,
+                pressed: None,
             },
+            error_style: None,
+            border_edges: None,
             focusable: true,                 // Text inputs are focusable by default
             wrap: Some(TextWrap::WordBreak), // Default to WordBreak for better text wrapping
             password_mode: false,            // Default to normal text mode
-            clear_on_submit: false,          // Default to not clearing on submit
+            mask_char: '•',
+            reveal: false,
+            reveal_key: None,
+            clear_on_submit: false,       // Default to not clearing on submit
+            enter_inserts_newline: false, // Default to Enter submitting
+            submit_key: None,
+            history: None,
+            suggestions: None,
+            max_length: None,
+            filter: None,
+            validate: None,
             on_change: None,
             on_submit: None,
             on_blur: None,
@@ -397,7 +633,10 @@ impl TextInput {
         }
     }
 
-    /// Sets the placeholder text to display when the input is empty
+    /// Sets the placeholder text to display when the input is empty.
+    ///
+    /// It wraps like the content. A placeholder that does not fit the input's
+    /// laid out size ends with an ellipsis on the last line.
     pub fn placeholder(mut self, text: impl Into<String>) -> Self {
         self.placeholder = Some(text.into());
         self
@@ -415,19 +654,106 @@ impl TextInput {
         self
     }
 
+    /// Sets the glyph that masks each character in password mode (`•` by default)
+    pub fn mask_char(mut self, mask: char) -> Self {
+        self.mask_char = mask;
+        self
+    }
+
+    /// Shows password content as plaintext while set, e.g. from a "show password" checkbox
+    pub fn reveal(mut self, reveal: bool) -> Self {
+        self.reveal = reveal;
+        self
+    }
+
+    /// Sets a key that toggles showing password content while the input is focused
+    ///
+    /// The content is masked again when the input loses focus.
+    pub fn reveal_key(mut self, key: KeyWithModifiers) -> Self {
+        self.reveal_key = Some(key);
+        self
+    }
+
     /// Enables automatic clearing of input content on submit (Enter key)
+    ///
+    /// Content is only cleared on an actual submit, never when Enter inserts a newline.
     pub fn clear_on_submit(mut self, clear: bool) -> Self {
         self.clear_on_submit = clear;
         self
     }
 
+    /// Makes Enter insert a newline instead of submitting (Enter submits by default)
+    pub fn enter_inserts_newline(mut self, enabled: bool) -> Self {
+        self.enter_inserts_newline = enabled;
+        self
+    }
+
+    /// Sets an additional key combination that submits the input (e.g. Alt+Enter)
+    ///
+    /// Use a Ctrl/Alt/Meta combination when Enter inserts newlines, since plain
+    /// Enter handlers also fire for Shift+Enter.
+    pub fn submit_key(mut self, key: KeyWithModifiers) -> Self {
+        self.submit_key = Some(key);
+        self
+    }
+
+    /// Enables input history, seeded with earlier entries (oldest first)
+    ///
+    /// Up/Down cycle through the entries and each non-empty submit is appended.
+    /// In multiline inputs Up/Down only recall history from the first/last line.
+    pub fn history(mut self, entries: Vec<String>) -> Self {
+        self.history = Some(entries);
+        self
+    }
+
+    /// Sets the autocomplete provider, called with the current content
+    ///
+    /// A returned line that extends the content is shown as ghost text after
+    /// the cursor and accepted with Tab or Right.
+    pub fn suggestions(mut self, provider: impl Fn(&str) -> Option<String> + 'static) -> Self {
+        self.suggestions = Some(Box::new(provider));
+        self
+    }
+
+    /// Sets the style of the suggestion ghost text (muted palette color by default)
+    pub fn suggestion_style(mut self, style: TextStyle) -> Self {
+        self.suggestion_style = Some(style);
+        self
+    }
+
+    /// Limits the content to `max_length` characters
+    ///
+    /// Keystrokes past the limit are dropped, and recalled history entries or
+    /// accepted suggestions are cut to fit.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets a predicate that typed characters must pass to be inserted
+    ///
+    /// Rejected characters never reach the content or `on_change`.
+    pub fn filter(mut self, filter: impl Fn(char) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets a validator that marks the content invalid without blocking typing
+    ///
+    /// While it returns an error the input is drawn with the
+    /// [`error_style`](Self::error_style).
+    pub fn validate(mut self, validate: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
     /// Sets the callback to be called when the input content changes
     pub fn on_change(mut self, callback: impl Fn(String) + 'static) -> Self {
         self.on_change = Some(Box::new(callback));
         self
     }
 
-    /// Sets the callback to be called when Enter is pressed
+    /// Sets the callback to be called when the input is submitted
     pub fn on_submit(mut self, callback: impl Fn() + 'static) -> Self {
         self.on_submit = Some(Box::new(callback));
         self
@@ -476,64 +802,82 @@ impl TextInput {
     fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
         if let Some(msg) = msg.downcast::<TextInputMsg>() {
             let mut state = ctx.get_state::<TextInputState>();
+            self.apply(&mut state, msg);
+            return Action::update(state);
+        }
 
-            match msg {
-                TextInputMsg::Focused => {
-                    state.focused = true;
-                    // Move cursor to end when gaining focus
-                    state.cursor_position = state.content.chars().count();
-                }
-                TextInputMsg::Blurred => {
-                    state.focused = false;
-                    // Clear selection when losing focus
-                    state.selection_start = None;
-                    state.selection_end = None;
+        Action::none()
+    }
 
-                    if let Some(callback) = &self.on_blur {
-                        callback();
-                    }
+    /// Applies an editing message to the given state
+    ///
+    /// Shared with components that wrap a text input and keep its state inside their own.
+    pub(crate) fn apply(&self, state: &mut TextInputState, msg: &TextInputMsg) {
+        match msg {
+            TextInputMsg::Focused => {
+                state.focused = true;
+                // Move cursor to end when gaining focus
+                state.cursor_position = state.content.chars().count();
+            }
+            TextInputMsg::Blurred => {
+                state.focused = false;
+                state.revealed = false;
+                // Clear selection when losing focus
+                state.selection_start = None;
+                state.selection_end = None;
+
+                if let Some(callback) = &self.on_blur {
+                    callback();
                 }
-                TextInputMsg::CharInput(ch) => {
-                    // Only accept input when focused
-                    if state.focused {
-                        // Check for ESC sequences that indicate Alt+key combinations
-                        // Alt+b and Alt+f are common word navigation shortcuts
-                        // These often come through as ESC followed by the character
-
-                        // For now, just handle regular character input
-                        // Clear selection and insert at cursor
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        }
-
-                        // Convert to char indices
-                        let char_pos = state.cursor_position;
+            }
+            TextInputMsg::CharInput(ch) => {
+                // Only accept input when focused
+                if state.focused {
+                    // Check for ESC sequences that indicate Alt+key combinations
+                    // Alt+b and Alt+f are common word navigation shortcuts
+                    // These often come through as ESC followed by the character
+
+                    // For now, just handle regular character input
+                    self.insert_char(state, *ch);
+                }
+            }
+            TextInputMsg::InsertNewline => {
+                // Newline insertion edits content but never counts as a submit
+                if state.focused {
+                    self.insert_char(state, '\n');
+                }
+            }
+            TextInputMsg::Backspace => {
+                // Only process backspace when focused
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else if state.cursor_position > 0 {
+                        // Delete the whole cluster before cursor
+                        let start = prev_grapheme_boundary(&state.content, state.cursor_position);
                         let mut chars: Vec<char> = state.content.chars().collect();
+                        chars.drain(start..state.cursor_position);
+                        state.content = chars.into_iter().collect();
+                        state.cursor_position = start;
 
-                        // Insert character at cursor position
-                        if char_pos <= chars.len() {
-                            chars.insert(char_pos, *ch);
-                            state.content = chars.into_iter().collect();
-                            state.cursor_position += 1;
-
-                            // Call on_change callback
-                            if let Some(callback) = &self.on_change {
-                                callback(state.content.clone());
-                            }
+                        // Call on_change callback
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
                         }
                     }
                 }
-                TextInputMsg::Backspace => {
-                    // Only process backspace when focused
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else if state.cursor_position > 0 {
-                            // Delete character before cursor
-                            let mut chars: Vec<char> = state.content.chars().collect();
-                            chars.remove(state.cursor_position - 1);
+            }
+            TextInputMsg::Delete => {
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else {
+                        // Delete the whole cluster after cursor
+                        let mut chars: Vec<char> = state.content.chars().collect();
+                        if state.cursor_position < chars.len() {
+                            let end = next_grapheme_boundary(&state.content, state.cursor_position);
+                            chars.drain(state.cursor_position..end);
                             state.content = chars.into_iter().collect();
-                            state.cursor_position -= 1;
 
                             // Call on_change callback
                             if let Some(callback) = &self.on_change {
@@ -542,183 +886,250 @@ impl TextInput {
                         }
                     }
                 }
-                TextInputMsg::Delete => {
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else {
-                            // Delete character after cursor
-                            let mut chars: Vec<char> = state.content.chars().collect();
-                            if state.cursor_position < chars.len() {
-                                chars.remove(state.cursor_position);
-                                state.content = chars.into_iter().collect();
-
-                                // Call on_change callback
-                                if let Some(callback) = &self.on_change {
-                                    callback(state.content.clone());
-                                }
-                            }
-                        }
-                    }
-                }
-                TextInputMsg::DeleteWordBackward => {
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else {
-                            self.delete_word_backward(&mut state);
-                            // Call on_change callback
-                            if let Some(callback) = &self.on_change {
-                                callback(state.content.clone());
-                            }
-                        }
-                    }
-                }
-                TextInputMsg::DeleteWordForward => {
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else {
-                            self.delete_word_forward(&mut state);
+            }
+            TextInputMsg::DeleteWordBackward => {
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else {
+                        self.delete_word_backward(state);
+                        // Call on_change callback
+                        if let Some(callback) = &self.on_change {
+                            callback(state.content.clone());
                         }
                     }
                 }
-                TextInputMsg::DeleteToLineStart => {
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else {
-                            self.delete_to_line_start(&mut state);
-                        }
+            }
+            TextInputMsg::DeleteWordForward => {
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else {
+                        self.delete_word_forward(state);
                     }
                 }
-                TextInputMsg::DeleteToLineEnd => {
-                    if state.focused {
-                        if state.selection_start.is_some() {
-                            self.delete_selection(&mut state);
-                        } else {
-                            self.delete_to_line_end(&mut state);
-                        }
+            }
+            TextInputMsg::DeleteToLineStart => {
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else {
+                        self.delete_to_line_start(state);
                     }
                 }
-                TextInputMsg::CursorLeft => {
-                    if state.focused && state.cursor_position > 0 {
-                        state.cursor_position -= 1;
-                        // Clear selection when moving cursor
-                        state.selection_start = None;
-                        state.selection_end = None;
+            }
+            TextInputMsg::DeleteToLineEnd => {
+                if state.focused {
+                    if state.selection_start.is_some() {
+                        self.delete_selection(state);
+                    } else {
+                        self.delete_to_line_end(state);
                     }
                 }
-                TextInputMsg::CursorRight => {
-                    if state.focused {
-                        let char_count = state.content.chars().count();
-                        if state.cursor_position < char_count {
-                            state.cursor_position += 1;
-                        }
-                        // Clear selection when moving cursor
-                        state.selection_start = None;
-                        state.selection_end = None;
-                    }
+            }
+            TextInputMsg::CursorLeft => {
+                if state.focused && state.cursor_position > 0 {
+                    state.cursor_position =
+                        prev_grapheme_boundary(&state.content, state.cursor_position);
+                    // Clear selection when moving cursor
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                TextInputMsg::CursorHome => {
-                    if state.focused {
-                        state.cursor_position = 0;
-                        state.selection_start = None;
-                        state.selection_end = None;
-                    }
+            }
+            TextInputMsg::CursorRight => {
+                if state.focused {
+                    state.cursor_position =
+                        next_grapheme_boundary(&state.content, state.cursor_position);
+                    // Clear selection when moving cursor
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                TextInputMsg::CursorEnd => {
-                    if state.focused {
-                        state.cursor_position = state.content.chars().count();
-                        state.selection_start = None;
-                        state.selection_end = None;
-                    }
+            }
+            TextInputMsg::CursorHome => {
+                if state.focused {
+                    state.cursor_position = 0;
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                TextInputMsg::CursorWordLeft => {
-                    if state.focused {
-                        state.cursor_position =
-                            self.find_word_boundary_left(&state.content, state.cursor_position);
-                        state.selection_start = None;
-                        state.selection_end = None;
-                    }
+            }
+            TextInputMsg::CursorEnd => {
+                if state.focused {
+                    state.cursor_position = state.content.chars().count();
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                TextInputMsg::CursorWordRight => {
-                    if state.focused {
-                        state.cursor_position =
-                            self.find_word_boundary_right(&state.content, state.cursor_position);
-                        state.selection_start = None;
-                        state.selection_end = None;
-                    }
+            }
+            TextInputMsg::CursorWordLeft => {
+                if state.focused {
+                    state.cursor_position =
+                        self.find_word_boundary_left(&state.content, state.cursor_position);
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                // TODO: Implement selection operations
-                TextInputMsg::SelectLeft
-                | TextInputMsg::SelectRight
-                | TextInputMsg::SelectAll
-                | TextInputMsg::SelectWord
-                | TextInputMsg::ClearSelection => {
-                    // Will be implemented when we add selection support
+            }
+            TextInputMsg::CursorWordRight => {
+                if state.focused {
+                    state.cursor_position =
+                        self.find_word_boundary_right(&state.content, state.cursor_position);
+                    state.selection_start = None;
+                    state.selection_end = None;
                 }
-                // TODO: Implement clipboard operations
-                TextInputMsg::Cut | TextInputMsg::Copy | TextInputMsg::Paste(_) => {
-                    // Will be implemented when we add clipboard support
+            }
+            // TODO: Implement selection operations
+            TextInputMsg::SelectLeft
+            | TextInputMsg::SelectRight
+            | TextInputMsg::SelectAll
+            | TextInputMsg::SelectWord
+            | TextInputMsg::ClearSelection => {
+                // Will be implemented when we add selection support
+            }
+            // TODO: Implement clipboard operations
+            TextInputMsg::Cut | TextInputMsg::Copy | TextInputMsg::Paste(_) => {
+                // Will be implemented when we add clipboard support
+            }
+            TextInputMsg::Submit => {
+                // Call on_submit callback when Enter (or the submit key) is pressed
+                if let Some(callback) = &self.on_submit {
+                    callback();
                 }
-                TextInputMsg::Submit => {
-                    // Call on_submit callback when Enter is pressed
-                    if let Some(callback) = &self.on_submit {
-                        callback();
-                    }
 
-                    // Clear content if clear_on_submit is enabled
-                    if self.clear_on_submit {
-                        state.content.clear();
-                        state.cursor_position = 0;
-                        state.selection_start = None;
-                        state.selection_end = None;
-
-                        // Call on_change callback to notify of cleared content
-                        if let Some(callback) = &self.on_change {
-                            callback(state.content.clone());
-                        }
+                // Commit the line to history, skipping blanks and repeats
+                if self.history.is_some() {
+                    let entries = self.history_entries(state);
+                    if !state.content.trim().is_empty()
+                        && entries.last() != Some(&state.content.as_str())
+                    {
+                        state.history.push(state.content.clone());
                     }
+                    state.history_index = None;
+                    state.history_draft.clear();
                 }
-                TextInputMsg::Clear => {
+
+                // Clear content if clear_on_submit is enabled
+                if self.clear_on_submit {
                     state.content.clear();
                     state.cursor_position = 0;
                     state.selection_start = None;
                     state.selection_end = None;
 
-                    // Call on_change callback
+                    // Call on_change callback to notify of cleared content
                     if let Some(callback) = &self.on_change {
                         callback(state.content.clone());
                     }
                 }
             }
+            TextInputMsg::Clear => {
+                state.content.clear();
+                state.cursor_position = 0;
+                state.selection_start = None;
+                state.selection_end = None;
+
+                // Call on_change callback
+                if let Some(callback) = &self.on_change {
+                    callback(state.content.clone());
+                }
+            }
+            TextInputMsg::HistoryPrevious => {
+                // In multiline content, Up only leaves the first line
+                let before_cursor: String =
+                    state.content.chars().take(state.cursor_position).collect();
+                if !state.focused || self.history.is_none() || before_cursor.contains('\n') {
+                    return;
+                }
 
-            return Action::update(state);
+                let entries = self.history_entries(state);
+                let index = match state.history_index {
+                    Some(0) => return,
+                    Some(index) => index - 1,
+                    None if entries.is_empty() => return,
+                    None => entries.len() - 1,
+                };
+                let entry = entries[index].to_string();
+
+                if state.history_index.is_none() {
+                    state.history_draft = state.content.clone();
+                }
+                state.history_index = Some(index);
+                self.set_content(state, entry);
+            }
+            TextInputMsg::HistoryNext => {
+                // In multiline content, Down only leaves the last line
+                let after_cursor: String =
+                    state.content.chars().skip(state.cursor_position).collect();
+                if !state.focused || after_cursor.contains('\n') {
+                    return;
+                }
+                let Some(index) = state.history_index else {
+                    return;
+                };
+
+                let entries = self.history_entries(state);
+                if let Some(entry) = entries.get(index + 1) {
+                    let entry = entry.to_string();
+                    state.history_index = Some(index + 1);
+                    self.set_content(state, entry);
+                } else {
+                    // Past the newest entry, restore the line being edited
+                    state.history_index = None;
+                    let draft = std::mem::take(&mut state.history_draft);
+                    self.set_content(state, draft);
+                }
+            }
+            TextInputMsg::ToggleReveal => {
+                if state.focused {
+                    state.revealed = !state.revealed;
+                }
+            }
+            TextInputMsg::AcceptSuggestion => {
+                if let Some(ghost) = self.ghost_text(state) {
+                    let content = format!("{}{}", state.content, ghost);
+                    self.set_content(state, content);
+                }
+            }
         }
-
-        Action::none()
     }
 
     fn view(&self, ctx: &Context) -> Node {
         let state = ctx.get_state::<TextInputState>();
+        self.render(ctx, &state).into()
+    }
+
+    /// Builds the input container for the given state
+    ///
+    /// Event handlers send [`TextInputMsg`] to the component owning `ctx`.
+    pub(crate) fn render(&self, ctx: &Context, state: &TextInputState) -> Div<Node> {
+        let theme = ctx.theme();
 
-        // Create a div and apply our stored styles
+        // Create a div and layer our stored styles over the theme defaults
         let mut container = Div::new();
 
-        // Apply base style if we have one
-        if let Some(base) = &self.styles.base {
-            container = container.style(base.clone());
+        let mut default_style = Self::default_style(&theme);
+        if let (Some(edges), Some(border)) = (self.border_edges, default_style.border.as_mut()) {
+            border.edges = edges;
+        }
+        let mut base = Style::merge(Some(default_style), self.styles.base.clone());
+        let mut focus = Style::merge(theme.focus_style.clone(), self.styles.focus.clone());
+
+        // Invalid content shows the error style, focused or not
+        if self.validation_error(&state.content).is_some() {
+            let error = Style::merge(
+                Some(Self::default_error_style(&theme, self.border_edges)),
+                self.error_style.clone(),
+            );
+            base = Style::merge(base, error.clone());
+            focus = focus.and_then(|focus| Style::merge(Some(focus), error));
         }
 
-        // Apply focus style if we have one
-        if let Some(focus) = &self.styles.focus {
-            container = container.focus_style(focus.clone());
+        if let Some(base) = base {
+            container = container.style(base);
         }
 
-        // Apply hover style if we have one
-        if let Some(hover) = &self.styles.hover {
-            container = container.hover_style(hover.clone());
+        if let Some(focus) = focus {
+            container = container.focus_style(focus);
+        }
+
+        if let Some(hover) = Style::merge(theme.hover_style.clone(), self.styles.hover.clone()) {
+            container = container.hover_style(hover);
         }
 
         // Set focusable
@@ -726,6 +1137,13 @@ impl TextInput {
             container = container.focusable(true);
         }
 
+        // Use the placeholder as the accessible label
+        if let Some(placeholder) = &self.placeholder {
+            container = container.label(placeholder.clone());
+        }
+
+        let ghost = self.ghost_text(state);
+
         // Add event handlers
         container = container
             .on_focus(ctx.handler(TextInputMsg::Focused))
@@ -733,10 +1151,26 @@ impl TextInput {
             .on_key(Key::Backspace, ctx.handler(TextInputMsg::Backspace))
             .on_key(Key::Delete, ctx.handler(TextInputMsg::Delete))
             .on_key(Key::Left, ctx.handler(TextInputMsg::CursorLeft))
-            .on_key(Key::Right, ctx.handler(TextInputMsg::CursorRight))
+            // Right accepts a shown suggestion, since the cursor is already at the end
+            .on_key(
+                Key::Right,
+                ctx.handler(if ghost.is_some() {
+                    TextInputMsg::AcceptSuggestion
+                } else {
+                    TextInputMsg::CursorRight
+                }),
+            )
             .on_key(Key::Home, ctx.handler(TextInputMsg::CursorHome))
             .on_key(Key::End, ctx.handler(TextInputMsg::CursorEnd))
-            .on_key(Key::Enter, ctx.handler(TextInputMsg::Submit))
+            // Enter submits by default, or inserts a newline when configured to
+            .on_key(
+                Key::Enter,
+                ctx.handler(if self.enter_inserts_newline {
+                    TextInputMsg::InsertNewline
+                } else {
+                    TextInputMsg::Submit
+                }),
+            )
             // Add modifier-aware handlers for word navigation
             // Terminals send Alt+B/F as Char('b'/'f') with ALT, not Arrow keys
             .on_key_with_modifiers(
@@ -811,6 +1245,27 @@ impl TextInput {
                 TextInputMsg::CharInput(ch)
             }));
 
+        if let Some(submit_key) = self.submit_key {
+            container =
+                container.on_key_with_modifiers(submit_key, ctx.handler(TextInputMsg::Submit));
+        }
+
+        if let Some(reveal_key) = self.reveal_key {
+            container = container
+                .on_key_with_modifiers(reveal_key, ctx.handler(TextInputMsg::ToggleReveal));
+        }
+
+        if self.history.is_some() {
+            container = container
+                .on_key(Key::Up, ctx.handler(TextInputMsg::HistoryPrevious))
+                .on_key(Key::Down, ctx.handler(TextInputMsg::HistoryNext));
+        }
+
+        // While a suggestion is shown, Tab accepts it instead of moving focus
+        if ghost.is_some() {
+            container = container.on_key(Key::Tab, ctx.handler(TextInputMsg::AcceptSuggestion));
+        }
+
         for (key_with_modifiers, handler) in &self.key_with_modifiers_handlers {
             let handler = handler.clone();
             container =
@@ -835,22 +1290,39 @@ impl TextInput {
 
         // Display content if present, otherwise show placeholder
         if !state.content.is_empty() || state.focused {
-            // Mask content if in password mode
-            let display_content = if self.password_mode {
-                "•".repeat(state.content.chars().count())
+            // Mask content in password mode unless it's being revealed
+            // One glyph per cluster, so the cursor maps onto the masked text by cluster
+            let (display_content, display_cursor) = if self.is_masked(state) {
+                let before_cursor: String =
+                    state.content.chars().take(state.cursor_position).collect();
+                (
+                    self.mask_char
+                        .to_string()
+                        .repeat(grapheme_count(&state.content)),
+                    grapheme_count(&before_cursor),
+                )
             } else {
-                state.content.clone()
+                (state.content.clone(), state.cursor_position)
             };
 
+            let content_style = TextStyle::merge(
+                Some(Self::default_content_style(&theme)),
+                self.content_style.clone(),
+            );
+            let cursor_style = TextStyle::merge(
+                Some(Self::default_cursor_style(&theme)),
+                self.cursor_style.clone(),
+            )
+            .unwrap_or_default();
+
             // Show the actual content with cursor when focused
             let node = if state.focused {
-                // Use RichText with cursor
-                let cursor_style = self
-                    .cursor_style
-                    .clone()
-                    .unwrap_or_else(Self::default_cursor_style);
-                let mut rich_text =
-                    RichText::with_cursor(&display_content, state.cursor_position, cursor_style);
+                // Use RichText with cursor, resting on the first suggested character
+                let shown = match &ghost {
+                    Some(ghost) => format!("{display_content}{ghost}"),
+                    None => display_content.clone(),
+                };
+                let mut rich_text = RichText::with_cursor(&shown, display_cursor, cursor_style);
 
                 // Apply wrapping if specified
                 if let Some(wrap) = self.wrap {
@@ -858,7 +1330,7 @@ impl TextInput {
                 }
 
                 // Apply content style to non-cursor spans
-                if let Some(content_style) = &self.content_style {
+                if let Some(content_style) = &content_style {
                     for span in &mut rich_text.spans {
                         if span.style.is_none() || span.style.as_ref().unwrap().background.is_none()
                         {
@@ -867,15 +1339,24 @@ impl TextInput {
                     }
                 }
 
+                // Dim the rest of the suggestion after the cursor
+                if ghost.is_some()
+                    && let Some(span) = rich_text.spans.last_mut()
+                    && !span.is_cursor
+                {
+                    span.style = TextStyle::merge(
+                        Some(Self::default_suggestion_style(&theme)),
+                        self.suggestion_style.clone(),
+                    );
+                }
+
                 rich_text.into()
             } else if !state.content.is_empty() {
                 // Show content without cursor when not focused
                 let mut text = Text::new(display_content.clone());
-                let default_style = Self::default_content_style();
-                let final_style = TextStyle::merge(Some(default_style), self.content_style.clone());
 
-                if let Some(style) = final_style {
-                    text.style = Some(style.clone());
+                if let Some(style) = content_style {
+                    text.style = Some(style);
                 }
 
                 // Apply wrapping
@@ -886,10 +1367,6 @@ impl TextInput {
                 text.into()
             } else {
                 // Empty but focused - show just cursor
-                let cursor_style = self
-                    .cursor_style
-                    .clone()
-                    .unwrap_or_else(Self::default_cursor_style);
                 let rich_text = RichText::with_cursor("", 0, cursor_style);
                 rich_text.into()
             };
@@ -897,18 +1374,23 @@ impl TextInput {
             container = container.children(vec![node]);
         } else if let Some(placeholder) = &self.placeholder {
             // Show placeholder when content is empty and not focused
-            let mut text = Text::new(placeholder.clone());
-            let default_style = Self::default_placeholder_style();
-            let final_style = TextStyle::merge(Some(default_style), self.placeholder_style.clone());
+            let final_style = TextStyle::merge(
+                Some(Self::default_placeholder_style(&theme)),
+                self.placeholder_style.clone(),
+            );
 
-            if let Some(style) = final_style {
-                text.style = Some(style.clone());
-            }
+            // Cut short at the input's laid out size, ending with an ellipsis
+            let mut text = Text::new(placeholder.clone());
+            text.style = final_style;
+            let style = text.style.get_or_insert(TextStyle::default());
+            style.wrap = self.wrap.or(style.wrap);
+            style.ellipsis = Some(true);
+            let node = text.into();
 
-            container = container.children(vec![text.into()]);
+            container = container.children(vec![node]);
         }
 
-        container.into()
+        container
     }
 }
 
@@ -919,7 +1401,7 @@ impl TextInput {
 impl TextInput {
     /// Sets the background color
     pub fn background(mut self, color: Color) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.background = Some(color);
         self.styles.base = Some(style);
         self
@@ -932,7 +1414,7 @@ impl TextInput {
 
     /// Sets the border using an explicit Border configuration.
     pub fn border_with(mut self, border: Border) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.border = Some(border);
         self.styles.base = Some(style);
         self
@@ -940,7 +1422,7 @@ impl TextInput {
 
     /// Sets the border style and color
     pub fn border_style(mut self, border_style: BorderStyle, color: Color) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.border = Some(Border {
             enabled: true,
             style: border_style,
@@ -952,15 +1434,19 @@ impl TextInput {
     }
 
     /// Sets border edges to display
+    ///
+    /// Applies to the border set on this input, or to the theme's default
+    /// border when none has been set.
     pub fn border_edges(mut self, edges: BorderEdges) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        if style.border.is_none() {
-            style.border = Some(Border::new(Color::White));
-        }
-        if let Some(ref mut border) = style.border {
-            border.edges = edges;
+        match self
+            .styles
+            .base
+            .as_mut()
+            .and_then(|style| style.border.as_mut())
+        {
+            Some(border) => border.edges = edges,
+            None => self.border_edges = Some(edges),
         }
-        self.styles.base = Some(style);
         self
     }
 
@@ -971,7 +1457,7 @@ impl TextInput {
         color: Color,
         edges: BorderEdges,
     ) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.border = Some(Border {
             enabled: true,
             style: border_style,
@@ -984,7 +1470,7 @@ impl TextInput {
 
     /// Sets the inner padding around content
     pub fn padding(mut self, padding: Spacing) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.padding = Some(padding);
         self.styles.base = Some(style);
         self
@@ -992,7 +1478,7 @@ impl TextInput {
 
     /// Sets the width
     pub fn width(mut self, width: u16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.width = Some(Dimension::Fixed(width));
         self.styles.base = Some(style);
         self
@@ -1000,7 +1486,7 @@ impl TextInput {
 
     /// Sets the width as a fraction of the parent (0.0 to 1.0)
     pub fn width_fraction(mut self, fraction: f32) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.width = Some(Dimension::Percentage(fraction.clamp(0.0, 1.0)));
         self.styles.base = Some(style);
         self
@@ -1008,7 +1494,7 @@ impl TextInput {
 
     /// Sets the width to auto
     pub fn width_auto(mut self) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.width = Some(Dimension::Auto);
         self.styles.base = Some(style);
         self
@@ -1016,7 +1502,7 @@ impl TextInput {
 
     /// Sets the width to content-based sizing
     pub fn width_content(mut self) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.width = Some(Dimension::Content);
         self.styles.base = Some(style);
         self
@@ -1024,7 +1510,7 @@ impl TextInput {
 
     /// Sets the height
     pub fn height(mut self, height: u16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.height = Some(Dimension::Fixed(height));
         self.styles.base = Some(style);
         self
@@ -1032,7 +1518,7 @@ impl TextInput {
 
     /// Sets the height as a fraction of the parent (0.0 to 1.0)
     pub fn height_fraction(mut self, fraction: f32) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.height = Some(Dimension::Percentage(fraction.clamp(0.0, 1.0)));
         self.styles.base = Some(style);
         self
@@ -1040,7 +1526,7 @@ impl TextInput {
 
     /// Sets the height to auto
     pub fn height_auto(mut self) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.height = Some(Dimension::Auto);
         self.styles.base = Some(style);
         self
@@ -1048,7 +1534,7 @@ impl TextInput {
 
     /// Sets the height to content-based sizing
     pub fn height_content(mut self) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.height = Some(Dimension::Content);
         self.styles.base = Some(style);
         self
@@ -1066,6 +1552,14 @@ impl TextInput {
         self
     }
 
+    /// Sets the style layered on top while the validator reports an error
+    ///
+    /// Defaults to a border in the palette's error color.
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = Some(style);
+        self
+    }
+
     /// Sets the border color when focused
     pub fn focus_border(self, color: Color) -> Self {
         self.focus_border_with(Border::new(color))
@@ -1150,7 +1644,7 @@ impl TextInput {
 
     /// Sets the position type
     pub fn position(mut self, position: Position) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
+        let mut style = self.styles.base.clone().unwrap_or_default();
         style.position = Some(position);
         self.styles.base = Some(style);
         self
@@ -1162,41 +1656,41 @@ impl TextInput {
     }
 
     /// Sets the top offset
-    pub fn top(mut self, top: i16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        style.top = Some(top);
+    pub fn top(mut self, top: impl Into<Offset>) -> Self {
+        let mut style = self.styles.base.clone().unwrap_or_default();
+        (style.top, style.top_fraction) = top.into().into_parts();
         self.styles.base = Some(style);
         self
     }
 
     /// Sets the right offset
-    pub fn right(mut self, right: i16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        style.right = Some(right);
+    pub fn right(mut self, right: impl Into<Offset>) -> Self {
+        let mut style = self.styles.base.clone().unwrap_or_default();
+        (style.right, style.right_fraction) = right.into().into_parts();
         self.styles.base = Some(style);
         self
     }
 
     /// Sets the bottom offset
-    pub fn bottom(mut self, bottom: i16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        style.bottom = Some(bottom);
+    pub fn bottom(mut self, bottom: impl Into<Offset>) -> Self {
+        let mut style = self.styles.base.clone().unwrap_or_default();
+        (style.bottom, style.bottom_fraction) = bottom.into().into_parts();
         self.styles.base = Some(style);
         self
     }
 
     /// Sets the left offset
-    pub fn left(mut self, left: i16) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        style.left = Some(left);
+    pub fn left(mut self, left: impl Into<Offset>) -> Self {
+        let mut style = self.styles.base.clone().unwrap_or_default();
+        (style.left, style.left_fraction) = left.into().into_parts();
         self.styles.base = Some(style);
         self
     }
 
     /// Sets the z-index for layering
-    pub fn z_index(mut self, z_index: i32) -> Self {
-        let mut style = self.styles.base.clone().unwrap_or_else(Self::default_style);
-        style.z_index = Some(z_index);
+    pub fn z_index(mut self, z_index: impl Into<ZIndex>) -> Self {
+        let mut style = self.styles.base.clone().unwrap_or_default();
+        (style.z_index, style.fractional_z_index) = z_index.into().into_parts();
         self.styles.base = Some(style);
         self
     }
@@ -1209,10 +1703,7 @@ impl TextInput {
 
     /// Sets the placeholder text color
     pub fn placeholder_color(mut self, color: Color) -> Self {
-        let mut style = self
-            .placeholder_style
-            .clone()
-            .unwrap_or_else(Self::default_placeholder_style);
+        let mut style = self.placeholder_style.clone().unwrap_or_default();
         style.color = Some(color);
         self.placeholder_style = Some(style);
         self
@@ -1220,10 +1711,7 @@ impl TextInput {
 
     /// Sets the placeholder text background color
     pub fn placeholder_background(mut self, color: Color) -> Self {
-        let mut style = self
-            .placeholder_style
-            .clone()
-            .unwrap_or_else(Self::default_placeholder_style);
+        let mut style = self.placeholder_style.clone().unwrap_or_default();
         style.background = Some(color);
         self.placeholder_style = Some(style);
         self
@@ -1231,10 +1719,7 @@ impl TextInput {
 
     /// Makes the placeholder text bold
     pub fn placeholder_bold(mut self, bold: bool) -> Self {
-        let mut style = self
-            .placeholder_style
-            .clone()
-            .unwrap_or_else(Self::default_placeholder_style);
+        let mut style = self.placeholder_style.clone().unwrap_or_default();
         style.bold = Some(bold);
         self.placeholder_style = Some(style);
         self
@@ -1242,10 +1727,7 @@ impl TextInput {
 
     /// Makes the placeholder text italic
     pub fn placeholder_italic(mut self, italic: bool) -> Self {
-        let mut style = self
-            .placeholder_style
-            .clone()
-            .unwrap_or_else(Self::default_placeholder_style);
+        let mut style = self.placeholder_style.clone().unwrap_or_default();
         style.italic = Some(italic);
         self.placeholder_style = Some(style);
         self
@@ -1253,10 +1735,7 @@ impl TextInput {
 
     /// Makes the placeholder text underlined
     pub fn placeholder_underline(mut self, underline: bool) -> Self {
-        let mut style = self
-            .placeholder_style
-            .clone()
-            .unwrap_or_else(Self::default_placeholder_style);
+        let mut style = self.placeholder_style.clone().unwrap_or_default();
         style.underline = Some(underline);
         self.placeholder_style = Some(style);
         self
@@ -1270,10 +1749,7 @@ impl TextInput {
 
     /// Sets the content text color
     pub fn content_color(mut self, color: Color) -> Self {
-        let mut style = self
-            .content_style
-            .clone()
-            .unwrap_or_else(Self::default_content_style);
+        let mut style = self.content_style.clone().unwrap_or_default();
         style.color = Some(color);
         self.content_style = Some(style);
         self
@@ -1281,10 +1757,7 @@ impl TextInput {
 
     /// Sets the content text background color
     pub fn content_background(mut self, color: Color) -> Self {
-        let mut style = self
-            .content_style
-            .clone()
-            .unwrap_or_else(Self::default_content_style);
+        let mut style = self.content_style.clone().unwrap_or_default();
         style.background = Some(color);
         self.content_style = Some(style);
         self
@@ -1292,10 +1765,7 @@ impl TextInput {
 
     /// Makes the content text bold
     pub fn content_bold(mut self, bold: bool) -> Self {
-        let mut style = self
-            .content_style
-            .clone()
-            .unwrap_or_else(Self::default_content_style);
+        let mut style = self.content_style.clone().unwrap_or_default();
         style.bold = Some(bold);
         self.content_style = Some(style);
         self
@@ -1303,10 +1773,7 @@ impl TextInput {
 
     /// Makes the content text italic
     pub fn content_italic(mut self, italic: bool) -> Self {
-        let mut style = self
-            .content_style
-            .clone()
-            .unwrap_or_else(Self::default_content_style);
+        let mut style = self.content_style.clone().unwrap_or_default();
         style.italic = Some(italic);
         self.content_style = Some(style);
         self
@@ -1314,10 +1781,7 @@ impl TextInput {
 
     /// Makes the content text underlined
     pub fn content_underline(mut self, underline: bool) -> Self {
-        let mut style = self
-            .content_style
-            .clone()
-            .unwrap_or_else(Self::default_content_style);
+        let mut style = self.content_style.clone().unwrap_or_default();
         style.underline = Some(underline);
         self.content_style = Some(style);
         self
@@ -1331,10 +1795,7 @@ impl TextInput {
 
     /// Sets the cursor color (background when cursor is shown)
     pub fn cursor_color(mut self, color: Color) -> Self {
-        let mut style = self
-            .cursor_style
-            .clone()
-            .unwrap_or_else(Self::default_cursor_style);
+        let mut style = self.cursor_style.clone().unwrap_or_default();
         style.background = Some(color);
         // Automatically set text color for contrast
         style.color = Some(match color {
@@ -1353,10 +1814,7 @@ impl TextInput {
 
     /// Sets the selection background color
     pub fn selection_color(mut self, color: Color) -> Self {
-        let mut style = self
-            .selection_style
-            .clone()
-            .unwrap_or_else(Self::default_selection_style);
+        let mut style = self.selection_style.clone().unwrap_or_default();
         style.background = Some(color);
         self.selection_style = Some(style);
         self
@@ -1396,3 +1854,270 @@ impl Default for TextInput {
         Self::new()
     }
 }
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+
+    fn focused_with(input: &TextInput, content: &str) -> TextInputState {
+        let mut state = TextInputState::default();
+        input.apply(&mut state, &TextInputMsg::Focused);
+        for ch in content.chars() {
+            input.apply(&mut state, &TextInputMsg::CharInput(ch));
+        }
+        state
+    }
+
+    /// Fires the rendered input's handler for `key` and applies the messages it sends
+    fn press(input: &TextInput, ctx: &Context, state: &mut TextInputState, key: Key) {
+        let div = input.render(ctx, state);
+        let (_, handler, _) = div
+            .events
+            .on_key
+            .iter()
+            .find(|(k, _, _)| *k == key)
+            .expect("the input handles the key");
+        handler();
+        for msg in ctx.drain_messages(&ctx.current_component_id) {
+            if let Some(msg) = msg.downcast::<TextInputMsg>() {
+                input.apply(state, msg);
+            }
+        }
+    }
+
+    /// Returns an input that counts its submits, and the count
+    fn counting_submits(input: TextInput) -> (TextInput, Rc<std::cell::Cell<usize>>) {
+        let submits = Rc::new(std::cell::Cell::new(0));
+        let input = input.on_submit({
+            let submits = submits.clone();
+            move || submits.set(submits.get() + 1)
+        });
+        (input, submits)
+    }
+
+    #[test]
+    fn test_enter_submits_and_clears_when_asked() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let (input, submits) = counting_submits(TextInput::new().clear_on_submit(true));
+        let mut state = focused_with(&input, "ab");
+
+        press(&input, &ctx, &mut state, Key::Enter);
+        assert_eq!(submits.get(), 1);
+        assert!(state.content.is_empty());
+    }
+
+    #[test]
+    fn test_enter_inserts_newline_without_submitting() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let (input, submits) = counting_submits(
+            TextInput::new()
+                .enter_inserts_newline(true)
+                .clear_on_submit(true),
+        );
+        let mut state = focused_with(&input, "ab");
+
+        press(&input, &ctx, &mut state, Key::Enter);
+        assert_eq!(submits.get(), 0);
+        assert_eq!(state.content, "ab\n");
+        assert_eq!(state.cursor_position, 3);
+    }
+
+    #[test]
+    fn test_history_cycles_and_commits_on_submit() {
+        let input = TextInput::new()
+            .history(vec!["ls".into(), "cd src".into()])
+            .clear_on_submit(true);
+        let mut state = focused_with(&input, "git");
+
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "cd src");
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "ls");
+
+        input.apply(&mut state, &TextInputMsg::HistoryNext);
+        input.apply(&mut state, &TextInputMsg::HistoryNext);
+        assert_eq!(state.content, "git");
+        assert_eq!(state.history_index, None);
+
+        input.apply(&mut state, &TextInputMsg::Submit);
+        assert_eq!(state.history, vec!["git".to_string()]);
+        assert!(state.content.is_empty());
+
+        // Repeats and blank lines are not committed
+        for line in ["git", "   "] {
+            for ch in line.chars() {
+                input.apply(&mut state, &TextInputMsg::CharInput(ch));
+            }
+            input.apply(&mut state, &TextInputMsg::Submit);
+        }
+        assert_eq!(state.history, vec!["git".to_string()]);
+
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "git");
+    }
+
+    #[test]
+    fn test_history_in_multiline_content() {
+        let input = TextInput::new()
+            .history(vec!["previous".into()])
+            .enter_inserts_newline(true);
+        let mut state = focused_with(&input, "one");
+        input.apply(&mut state, &TextInputMsg::InsertNewline);
+        input.apply(&mut state, &TextInputMsg::CharInput('2'));
+
+        // Up from the second line stays within the content
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "one\n2");
+
+        input.apply(&mut state, &TextInputMsg::CursorHome);
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "previous");
+    }
+
+    #[test]
+    fn test_password_reveal_toggles_and_remasks_on_blur() {
+        use std::cell::RefCell;
+
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let input = TextInput::new().password(true).mask_char('*').on_change({
+            let changes = changes.clone();
+            move |text| changes.borrow_mut().push(text)
+        });
+        let mut state = focused_with(&input, "pw");
+        assert!(input.is_masked(&state));
+        assert_eq!(changes.borrow().last().map(String::as_str), Some("pw"));
+
+        input.apply(&mut state, &TextInputMsg::ToggleReveal);
+        assert!(!input.is_masked(&state));
+
+        input.apply(&mut state, &TextInputMsg::Blurred);
+        assert!(input.is_masked(&state));
+
+        // The external flag reveals regardless of the toggle
+        assert!(!input.reveal(true).is_masked(&state));
+    }
+
+    #[test]
+    fn test_suggestion_ghost_text_and_accept() {
+        let input = TextInput::new().suggestions(|line| {
+            ["checkout", "commit"]
+                .iter()
+                .find(|cmd| !line.is_empty() && cmd.starts_with(line))
+                .map(|cmd| cmd.to_string())
+        });
+        let mut state = focused_with(&input, "ch");
+        assert_eq!(input.ghost_text(&state).as_deref(), Some("eckout"));
+
+        // No ghost text unless the cursor sits at the end
+        input.apply(&mut state, &TextInputMsg::CursorLeft);
+        assert_eq!(input.ghost_text(&state), None);
+        input.apply(&mut state, &TextInputMsg::AcceptSuggestion);
+        assert_eq!(state.content, "ch");
+
+        input.apply(&mut state, &TextInputMsg::CursorEnd);
+        input.apply(&mut state, &TextInputMsg::AcceptSuggestion);
+        assert_eq!(state.content, "checkout");
+        assert_eq!(state.cursor_position, 8);
+        assert_eq!(input.ghost_text(&state), None);
+    }
+
+    #[test]
+    fn test_filter_and_max_length_drop_keystrokes() {
+        use std::cell::RefCell;
+
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let input = TextInput::new()
+            .max_length(3)
+            .filter(|ch| ch.is_ascii_digit())
+            .history(vec!["12a345".into()])
+            .on_change({
+                let changes = changes.clone();
+                move |text| changes.borrow_mut().push(text)
+            });
+        let mut state = focused_with(&input, "1a2b34");
+        assert_eq!(state.content, "123");
+        assert_eq!(*changes.borrow(), ["1", "12", "123"]);
+
+        // Typing over a selection makes room at the limit
+        state.selection_start = Some(0);
+        state.selection_end = Some(1);
+        input.apply(&mut state, &TextInputMsg::CharInput('9'));
+        assert_eq!(state.content, "923");
+
+        // Recalled entries are constrained like typed text
+        input.apply(&mut state, &TextInputMsg::HistoryPrevious);
+        assert_eq!(state.content, "123");
+        assert_eq!(changes.borrow().last().map(String::as_str), Some("123"));
+    }
+
+    #[test]
+    fn test_validate_marks_invalid_without_blocking() {
+        // The error style also wins over a focus style
+        let input = TextInput::new()
+            .focus_border(Color::Green)
+            .validate(|text| match text.len() {
+                3 => Ok(()),
+                _ => Err("three characters".into()),
+            });
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let error = Some(ctx.theme().palette.error);
+        let border = |div: &Div<Node>| div.styles.base.as_ref()?.border.as_ref().map(|b| b.color);
+
+        let mut state = focused_with(&input, "ab");
+        assert_eq!(state.content, "ab");
+        assert_eq!(border(&input.render(&ctx, &state)), error);
+        let focus = input.render(&ctx, &state).styles.focus;
+        assert_eq!(focus.and_then(|s| s.border).map(|b| b.color), error);
+
+        input.apply(&mut state, &TextInputMsg::CharInput('c'));
+        assert_ne!(border(&input.render(&ctx, &state)), error);
+    }
+
+    #[test]
+    fn test_cursor_steps_over_grapheme_clusters() {
+        let input = TextInput::new();
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let mut state = focused_with(&input, &format!("e\u{301}{family}🇺🇸"));
+        assert_eq!(state.cursor_position, 9);
+
+        // Backspace removes the whole flag, Left skips the whole family
+        input.apply(&mut state, &TextInputMsg::Backspace);
+        assert_eq!(state.content, format!("e\u{301}{family}"));
+        input.apply(&mut state, &TextInputMsg::CursorLeft);
+        assert_eq!(state.cursor_position, 2);
+
+        // Delete removes the family, then Right can't split the accented e
+        input.apply(&mut state, &TextInputMsg::Delete);
+        assert_eq!(state.content, "e\u{301}");
+        input.apply(&mut state, &TextInputMsg::CursorHome);
+        input.apply(&mut state, &TextInputMsg::CursorRight);
+        assert_eq!(state.cursor_position, 2);
+    }
+
+    #[test]
+    fn test_placeholder_follows_content() {
+        fn shown(node: &Node) -> Vec<String> {
+            match node {
+                Node::Div(div) => div.children.iter().flat_map(shown).collect(),
+                Node::Text(text) => vec![text.content.clone()],
+                _ => Vec::new(),
+            }
+        }
+
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let input = TextInput::new().width(12).placeholder("Name");
+        let mut state = focused_with(&input, "Al");
+        input.apply(&mut state, &TextInputMsg::Blurred);
+        assert_eq!(shown(&input.render(&ctx, &state).into()), ["Al"]);
+
+        input.apply(&mut state, &TextInputMsg::Clear);
+        assert_eq!(shown(&input.render(&ctx, &state).into()), ["Name"]);
+    }
+}
@@ -0,0 +1,449 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::effect::Effect;
+use crate::node::{Node, RichText};
+use crate::style::{Border, Color, Direction, Position, Spacing, TextWrap};
+use crate::{Context, Div};
+use std::any::Any;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Portal layer toasts paint in, above menus and other overlays
+const TOAST_LAYER: i32 = 200;
+
+/// Cells between the toast stack and the edges of the screen
+const EDGE_MARGIN: i16 = 1;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Severity of a toast, which picks its color and icon
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ToastLevel {
+    /// Neutral information, in the palette's primary color
+    #[default]
+    Info,
+
+    /// A completed action, in the palette's success color
+    Success,
+
+    /// Something worth attention, in the palette's warning color
+    Warning,
+
+    /// A failure, in the palette's error color
+    Error,
+}
+
+/// Corner of the screen the toast stack is anchored to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ToastPosition {
+    /// Top left corner, newest toast at the top
+    TopLeft,
+
+    /// Top right corner, newest toast at the top
+    TopRight,
+
+    /// Bottom left corner, newest toast at the bottom
+    BottomLeft,
+
+    /// Bottom right corner, newest toast at the bottom
+    #[default]
+    BottomRight,
+}
+
+/// Messages for Toast component
+#[derive(Debug, Clone)]
+pub enum ToastMsg {
+    /// Remove the toast with this id before its timer runs out
+    Dismiss(u64),
+}
+
+/// A toast waiting in the queue
+#[derive(Debug, Clone)]
+pub(crate) struct QueuedToast {
+    pub(crate) id: u64,
+    pub(crate) message: String,
+    pub(crate) level: ToastLevel,
+    pub(crate) expires: Instant,
+}
+
+/// Toasts shown by [`Toast`], oldest first, shared through the context
+#[derive(Debug, Default)]
+pub(crate) struct ToastQueue {
+    next_id: u64,
+    toasts: Vec<QueuedToast>,
+
+    /// Wakes the expiry effect when a toast is pushed
+    pushed: Arc<Notify>,
+}
+
+/// Overlay that shows the toasts queued with [`Context::toast`]
+///
+/// Place one `Toast` anywhere in the root view. Toasts stack in a corner of
+/// the screen, newest nearest the edge, each in a bordered box colored by its
+/// level. A toast disappears when its duration runs out or when it is
+/// clicked, and the ones left close the gap. Only the newest `max_visible`
+/// toasts are drawn; older ones still expire on schedule.
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+/// use std::time::Duration;
+///
+/// // In the root view
+/// node! {
+///     div [
+///         // ...
+///         node(Toast::new().position(ToastPosition::TopRight))
+///     ]
+/// }
+///
+/// // Anywhere else
+/// ctx.toast("Saved", ToastLevel::Success, Duration::from_secs(3));
+/// ```
+pub struct Toast {
+    position: ToastPosition,
+    width: u16,
+    max_visible: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ToastLevel {
+    /// Returns the icon drawn before the message
+    fn icon(self) -> &'static str {
+        match self {
+            ToastLevel::Info => "ℹ",
+            ToastLevel::Success => "✓",
+            ToastLevel::Warning => "⚠",
+            ToastLevel::Error => "✗",
+        }
+    }
+
+    /// Returns the palette color for the level
+    fn color(self, ctx: &Context) -> Color {
+        let palette = &ctx.theme().palette;
+        match self {
+            ToastLevel::Info => palette.primary,
+            ToastLevel::Success => palette.success,
+            ToastLevel::Warning => palette.warning,
+            ToastLevel::Error => palette.error,
+        }
+    }
+}
+
+impl ToastQueue {
+    /// Adds a toast that expires `duration` after `now` and returns its id
+    pub(crate) fn push(
+        &mut self,
+        message: String,
+        level: ToastLevel,
+        duration: Duration,
+        now: Instant,
+    ) -> u64 {
+        self.expire(now);
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push(QueuedToast {
+            id,
+            message,
+            level,
+            expires: now + duration,
+        });
+        self.pushed.notify_one();
+        id
+    }
+
+    /// Removes the toast with the given id, returning whether it was queued
+    pub(crate) fn dismiss(&mut self, id: u64) -> bool {
+        let len = self.toasts.len();
+        self.toasts.retain(|toast| toast.id != id);
+        self.toasts.len() != len
+    }
+
+    /// Removes toasts whose time is up, returning whether any were removed
+    pub(crate) fn expire(&mut self, now: Instant) -> bool {
+        let len = self.toasts.len();
+        self.toasts.retain(|toast| toast.expires > now);
+        self.toasts.len() != len
+    }
+
+    /// Returns when the next toast expires
+    pub(crate) fn next_expiry(&self) -> Option<Instant> {
+        self.toasts.iter().map(|toast| toast.expires).min()
+    }
+
+    /// Returns up to `max` of the newest toasts still showing at `now`, oldest
+    /// first
+    pub(crate) fn visible(&self, now: Instant, max: usize) -> Vec<QueuedToast> {
+        let live: Vec<_> = self
+            .toasts
+            .iter()
+            .filter(|toast| toast.expires > now)
+            .collect();
+        let skip = live.len().saturating_sub(max);
+        live.into_iter().skip(skip).cloned().collect()
+    }
+}
+
+impl Toast {
+    /// Creates a toast overlay in the bottom right corner
+    pub fn new() -> Self {
+        Self {
+            position: ToastPosition::default(),
+            width: 40,
+            max_visible: 5,
+        }
+    }
+
+    /// Sets the corner the toasts stack in
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of each toast in cells, borders included (40 by default)
+    ///
+    /// Longer messages wrap onto more lines.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how many toasts are drawn at once (5 by default)
+    pub fn max_visible(mut self, max: usize) -> Self {
+        self.max_visible = max;
+        self
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        if let Some(ToastMsg::Dismiss(id)) = msg.downcast::<ToastMsg>() {
+            ctx.toasts.lock().unwrap().dismiss(*id);
+        }
+        Action::none()
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        self.render(ctx).into()
+    }
+
+    /// Builds the stack of toasts
+    pub(crate) fn render(&self, ctx: &Context) -> Div<Node> {
+        let mut toasts = ctx
+            .toasts
+            .lock()
+            .unwrap()
+            .visible(Instant::now(), self.max_visible);
+
+        let mut stack = Div::new()
+            .direction(Direction::Vertical)
+            .position(Position::Fixed)
+            .width(self.width)
+            .height_content()
+            .portal(TOAST_LAYER);
+        stack = match self.position {
+            ToastPosition::TopLeft => stack.top(EDGE_MARGIN).left(EDGE_MARGIN),
+            ToastPosition::TopRight => stack.top(EDGE_MARGIN).right(EDGE_MARGIN),
+            ToastPosition::BottomLeft => stack.bottom(EDGE_MARGIN).left(EDGE_MARGIN),
+            ToastPosition::BottomRight => stack.bottom(EDGE_MARGIN).right(EDGE_MARGIN),
+        };
+
+        // The newest toast sits nearest the edge the stack is anchored to
+        if matches!(
+            self.position,
+            ToastPosition::TopLeft | ToastPosition::TopRight
+        ) {
+            toasts.reverse();
+        }
+
+        let theme = ctx.theme();
+        let foreground = theme.palette.foreground;
+        for toast in toasts {
+            let color = toast.level.color(ctx);
+            let text = RichText::new()
+                .colored(format!("{} ", toast.level.icon()), color)
+                .colored(toast.message, foreground)
+                .wrap(TextWrap::Word);
+            let item = Div::new()
+                .width_fraction(1.0)
+                .height_content()
+                .padding(Spacing::horizontal(1))
+                .border_with(Border::with_style(theme.border_style, color))
+                .on_click(ctx.handler(ToastMsg::Dismiss(toast.id)))
+                .child(text.into());
+            stack = stack.child(item.into());
+        }
+        stack
+    }
+
+    fn effects(&self, ctx: &Context) -> Vec<Effect> {
+        let ctx = ctx.clone();
+        let effect = Box::pin(async move {
+            loop {
+                let (next_expiry, pushed) = {
+                    let toasts = ctx.toasts.lock().unwrap();
+                    (toasts.next_expiry(), toasts.pushed.clone())
+                };
+
+                // Sleep until the next toast is due, or until a new one
+                // arrives that may be due sooner
+                match next_expiry {
+                    Some(at) => tokio::select! {
+                        _ = tokio::time::sleep_until(at.into()) => {}
+                        _ = pushed.notified() => {}
+                    },
+                    None => pushed.notified().await,
+                }
+                if ctx.toasts.lock().unwrap().expire(Instant::now()) {
+                    ctx.request_redraw();
+                }
+            }
+        });
+        vec![effect]
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Toast {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Toast::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Toast::view(self, ctx)
+    }
+
+    fn effects(&self, ctx: &Context) -> Vec<Effect> {
+        Toast::effects(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    fn secs(n: u64) -> Duration {
+        Duration::from_secs(n)
+    }
+
+    #[test]
+    fn test_toasts_expire_in_order() {
+        let start = Instant::now();
+        let mut queue = ToastQueue::default();
+        queue.push("short".into(), ToastLevel::Info, secs(1), start);
+        queue.push("long".into(), ToastLevel::Error, secs(3), start);
+        assert_eq!(queue.next_expiry(), Some(start + secs(1)));
+
+        assert!(!queue.expire(start));
+        assert!(queue.expire(start + secs(1)));
+        let left = queue.visible(start + secs(1), 5);
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].message, "long");
+
+        assert!(queue.expire(start + secs(5)));
+        assert_eq!(queue.next_expiry(), None);
+    }
+
+    #[test]
+    fn test_visible_keeps_the_newest() {
+        let start = Instant::now();
+        let mut queue = ToastQueue::default();
+        for message in ["a", "b", "c"] {
+            queue.push(message.into(), ToastLevel::Info, secs(2), start);
+        }
+        let messages: Vec<_> = queue
+            .visible(start, 2)
+            .into_iter()
+            .map(|toast| toast.message)
+            .collect();
+        assert_eq!(messages, ["b", "c"]);
+
+        let id = queue.visible(start, 1)[0].id;
+        assert!(queue.dismiss(id));
+        assert!(!queue.dismiss(id));
+        assert_eq!(queue.visible(start, 5).len(), 2);
+    }
+
+    #[test]
+    fn test_render_stacks_newest_nearest_edge() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        ctx.toast("first", ToastLevel::Info, secs(60));
+        ctx.toast("second", ToastLevel::Warning, secs(60));
+        assert!(ctx.take_redraw_request());
+
+        let top = Toast::new().position(ToastPosition::TopRight).render(&ctx);
+        let bottom = Toast::new().render(&ctx);
+        let border = |div: &Div<Node>, index: usize| match &div.children[index] {
+            Node::Div(item) => item
+                .styles
+                .base
+                .as_ref()
+                .and_then(|s| s.border.as_ref())
+                .map(|b| b.color),
+            _ => None,
+        };
+        let warning = Some(ctx.theme().palette.warning);
+        assert_eq!(border(&top, 0), warning);
+        assert_eq!(border(&bottom, 1), warning);
+    }
+
+    #[test]
+    fn test_expiry_effect_wakes_for_toasts_pushed_while_idle() {
+        let ctx = Context::new(Arc::new(AtomicBool::new(false)));
+        let effect = Toast::new().effects(&ctx).remove(0);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        // The effect starts with nothing queued, so only the push can wake it
+        let expired = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            ctx.toast("brief", ToastLevel::Info, Duration::from_millis(20));
+            while ctx.toasts.lock().unwrap().next_expiry().is_some() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        };
+        let finished = runtime.block_on(async {
+            tokio::time::timeout(secs(5), async {
+                tokio::select! {
+                    _ = effect => {}
+                    _ = expired => {}
+                }
+            })
+            .await
+        });
+        assert!(finished.is_ok());
+        assert!(ctx.take_redraw_request());
+    }
+}
@@ -0,0 +1,616 @@
+use crate::component::{Action, Component, Message, MessageExt};
+use crate::components::list::{ListMsg, ListState};
+use crate::key::Key;
+use crate::node::{Node, Role, Text};
+use crate::style::{Dimension, Direction, Style};
+use crate::{Context, Div};
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Default number of cells each level is indented by
+const DEFAULT_INDENT: u16 = 2;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Path of indices from the roots of a [`Tree`] down to one of its items
+pub type TreePath = Vec<usize>;
+
+/// Type alias for the closure rendering a tree item, told whether it is highlighted
+type TreeRenderer = Rc<dyn Fn(&TreeItem, bool) -> Node>;
+
+/// Type alias for the callback told a selected item's path
+type SelectHandler = Box<dyn Fn(&[usize])>;
+
+/// Type alias for the callback told an item's path and whether it is now expanded
+type ToggleHandler = Box<dyn Fn(&[usize], bool)>;
+
+/// An item in a [`Tree`], with the items nested under it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeItem {
+    /// Text shown for the item
+    pub label: String,
+
+    /// Items nested under this one
+    pub children: Vec<TreeItem>,
+
+    /// Whether the item starts expanded
+    pub expanded: bool,
+}
+
+/// Messages for Tree component, besides navigation through [`ListMsg`]
+#[derive(Debug, Clone)]
+pub enum TreeMsg {
+    /// Expand the highlighted item, or move into it when already expanded
+    Expand,
+
+    /// Collapse the highlighted item, or move to its parent when already collapsed
+    Collapse,
+
+    /// Expand or collapse the highlighted item
+    Toggle,
+}
+
+/// State for Tree component
+#[derive(Debug, Clone, Default)]
+pub struct TreeState {
+    /// Highlight and scroll position within the visible rows
+    pub list: ListState,
+
+    /// Paths of the expanded items, or `None` until the first toggle
+    pub expanded: Option<BTreeSet<TreePath>>,
+}
+
+/// A visible row of the tree
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    path: TreePath,
+    has_children: bool,
+    expanded: bool,
+}
+
+/// What a message did to the tree
+#[derive(Debug, Default, PartialEq)]
+struct Outcome {
+    /// An item that was selected
+    selected: Option<TreePath>,
+
+    /// An item that was expanded (`true`) or collapsed (`false`)
+    toggled: Option<(TreePath, bool)>,
+}
+
+/// A scrollable tree of nested items, for file browsers and other hierarchies
+///
+/// Each level is indented, with a marker showing whether an item with
+/// children is expanded. Up and Down move the highlight through the visible
+/// rows, Right expands the highlighted item or moves into it, and Left
+/// collapses it or moves to its parent. Space toggles the highlighted item and
+/// Enter selects it; a click selects an item and toggles it if it has children.
+///
+/// Items are identified by their [`TreePath`]. `on_select` and `on_toggle`
+/// receive the item's path, the latter also whether it is now expanded. With
+/// `height`, the tree scrolls like a [`List`](crate::components::List).
+///
+/// # Example
+///
+/// ```ignore
+/// use rxtui::prelude::*;
+///
+/// let files = Tree::new([
+///     TreeItem::new("src").expanded(true).children([
+///         TreeItem::new("main.rs"),
+///         TreeItem::new("lib.rs"),
+///     ]),
+///     TreeItem::new("Cargo.toml"),
+/// ])
+/// .height(10)
+/// .on_select(|path| println!("opened {path:?}"));
+/// ```
+pub struct Tree {
+    items: Vec<TreeItem>,
+    height: Option<u16>,
+    indent: u16,
+    highlight_style: Option<Style>,
+    render: Option<TreeRenderer>,
+    on_select: Option<SelectHandler>,
+    on_toggle: Option<ToggleHandler>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: TreeItem
+//--------------------------------------------------------------------------------------------------
+
+impl TreeItem {
+    /// Creates an item without children
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an item nested under this one
+    pub fn child(mut self, child: TreeItem) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds items nested under this one
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeItem>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Sets whether the item starts expanded
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Returns the item at `path` below this one
+    fn descendant(&self, path: &[usize]) -> Option<&TreeItem> {
+        path.iter()
+            .try_fold(self, |item, &index| item.children.get(index))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods: Tree
+//--------------------------------------------------------------------------------------------------
+
+impl Tree {
+    /// Creates a tree with `items` at its root level
+    pub fn new(items: impl IntoIterator<Item = TreeItem>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            height: None,
+            indent: DEFAULT_INDENT,
+            highlight_style: None,
+            render: None,
+            on_select: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets the number of visible rows (all visible items are shown by default)
+    pub fn height(mut self, rows: u16) -> Self {
+        self.height = Some(rows);
+        self
+    }
+
+    /// Sets the number of cells each level is indented by (2 by default)
+    pub fn indent(mut self, cells: u16) -> Self {
+        self.indent = cells;
+        self
+    }
+
+    /// Sets the style of the highlighted row (the theme's selection colors by default)
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    /// Renders items with a closure, told whether the item is highlighted,
+    /// instead of showing their labels
+    pub fn render(mut self, render: impl Fn(&TreeItem, bool) -> Node + 'static) -> Self {
+        self.render = Some(Rc::new(render));
+        self
+    }
+
+    /// Sets the callback called with the path of a selected item
+    pub fn on_select(mut self, callback: impl Fn(&[usize]) + 'static) -> Self {
+        self.on_select = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the callback called with an item's path and whether it is now expanded
+    pub fn on_toggle(mut self, callback: impl Fn(&[usize], bool) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the item at `path`
+    fn item(&self, path: &[usize]) -> Option<&TreeItem> {
+        let (first, rest) = path.split_first()?;
+        self.items.get(*first)?.descendant(rest)
+    }
+
+    /// Returns the paths of the items marked as expanded
+    fn initially_expanded(&self) -> BTreeSet<TreePath> {
+        fn collect(items: &[TreeItem], path: &mut TreePath, expanded: &mut BTreeSet<TreePath>) {
+            for (index, item) in items.iter().enumerate() {
+                path.push(index);
+                if item.expanded {
+                    expanded.insert(path.clone());
+                }
+                collect(&item.children, path, expanded);
+                path.pop();
+            }
+        }
+
+        let mut expanded = BTreeSet::new();
+        collect(&self.items, &mut Vec::new(), &mut expanded);
+        expanded
+    }
+
+    /// Returns the rows shown for the expanded items, in display order
+    fn rows(&self, expanded: &BTreeSet<TreePath>) -> Vec<Row> {
+        fn collect(
+            items: &[TreeItem],
+            path: &mut TreePath,
+            expanded: &BTreeSet<TreePath>,
+            rows: &mut Vec<Row>,
+        ) {
+            for (index, item) in items.iter().enumerate() {
+                path.push(index);
+                let open = !item.children.is_empty() && expanded.contains(path);
+                rows.push(Row {
+                    path: path.clone(),
+                    has_children: !item.children.is_empty(),
+                    expanded: open,
+                });
+                if open {
+                    collect(&item.children, path, expanded, rows);
+                }
+                path.pop();
+            }
+        }
+
+        let mut rows = Vec::new();
+        collect(&self.items, &mut Vec::new(), expanded, &mut rows);
+        rows
+    }
+
+    /// Number of rows shown at once
+    fn visible_rows(&self, len: usize) -> usize {
+        match self.height {
+            Some(height) => (height as usize).max(1),
+            None => len.max(1),
+        }
+    }
+
+    /// Expands or collapses the item at `path`, returning it and its new state
+    fn set_expanded(
+        &self,
+        state: &mut TreeState,
+        path: &TreePath,
+        expand: bool,
+    ) -> Option<(TreePath, bool)> {
+        let expanded = state
+            .expanded
+            .get_or_insert_with(|| self.initially_expanded());
+        let changed = if expand {
+            expanded.insert(path.clone())
+        } else {
+            expanded.remove(path)
+        };
+        changed.then(|| (path.clone(), expand))
+    }
+
+    /// Applies a navigation message to the state
+    fn navigate(&self, state: &mut TreeState, msg: &ListMsg) -> Outcome {
+        let expanded = state
+            .expanded
+            .clone()
+            .unwrap_or_else(|| self.initially_expanded());
+        let rows = self.rows(&expanded);
+        let visible = self.visible_rows(rows.len());
+        let (_, selected) = state.list.apply(msg, rows.len(), visible);
+
+        let Some(row) = selected.map(|index| &rows[index]) else {
+            return Outcome::default();
+        };
+
+        // Clicking an item with children also opens or closes it
+        let toggled = match msg {
+            ListMsg::Click(_) if row.has_children => {
+                self.set_expanded(state, &row.path, !row.expanded)
+            }
+            _ => None,
+        };
+
+        Outcome {
+            selected: Some(row.path.clone()),
+            toggled,
+        }
+    }
+
+    /// Applies an expand or collapse message to the state
+    fn apply(&self, state: &mut TreeState, msg: &TreeMsg) -> Outcome {
+        let expanded = state
+            .expanded
+            .clone()
+            .unwrap_or_else(|| self.initially_expanded());
+        let rows = self.rows(&expanded);
+        if rows.is_empty() {
+            return Outcome::default();
+        }
+
+        // Rows may have disappeared since the last message
+        let index = state.list.highlighted.min(rows.len() - 1);
+        let row = &rows[index];
+        let visible = self.visible_rows(rows.len());
+
+        let mut outcome = Outcome::default();
+        match msg {
+            TreeMsg::Toggle if row.has_children => {
+                outcome.toggled = self.set_expanded(state, &row.path, !row.expanded);
+            }
+            TreeMsg::Expand if row.has_children && !row.expanded => {
+                outcome.toggled = self.set_expanded(state, &row.path, true);
+            }
+            // An expanded item's first child is the next row
+            TreeMsg::Expand if row.expanded => {
+                state.list.apply(&ListMsg::Down, rows.len(), visible);
+            }
+            TreeMsg::Collapse if row.expanded => {
+                outcome.toggled = self.set_expanded(state, &row.path, false);
+            }
+            TreeMsg::Collapse if row.path.len() > 1 => {
+                let parent = &row.path[..row.path.len() - 1];
+                // The parent is above, so scrolling up to it is enough
+                if let Some(position) = rows.iter().position(|row| row.path == parent) {
+                    state.list.highlighted = position;
+                    state.list.offset = state.list.offset.min(position);
+                }
+            }
+            _ => {}
+        }
+        outcome
+    }
+
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, _topic: Option<&str>) -> Action {
+        let mut state = ctx.get_state::<TreeState>();
+
+        let outcome = if let Some(msg) = msg.downcast::<ListMsg>() {
+            // Take focus when clicked so the keyboard works from there
+            if matches!(msg, ListMsg::Click(_)) {
+                ctx.focus_self();
+            }
+            self.navigate(&mut state, msg)
+        } else if let Some(msg) = msg.downcast::<TreeMsg>() {
+            self.apply(&mut state, msg)
+        } else {
+            return Action::none();
+        };
+
+        if let (Some((path, expanded)), Some(callback)) = (&outcome.toggled, &self.on_toggle) {
+            callback(path, *expanded);
+        }
+        if let (Some(path), Some(callback)) = (&outcome.selected, &self.on_select) {
+            callback(path);
+        }
+
+        Action::update(state)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        let state = ctx.get_state::<TreeState>();
+        let palette = &ctx.theme().palette;
+        let expanded = state
+            .expanded
+            .clone()
+            .unwrap_or_else(|| self.initially_expanded());
+        let rows = self.rows(&expanded);
+        let visible = self.visible_rows(rows.len());
+
+        let highlight_style = self
+            .highlight_style
+            .clone()
+            .unwrap_or_else(|| Style::default().background(palette.selection_background));
+        let row_style = Style {
+            direction: Some(Direction::Horizontal),
+            width: Some(Dimension::Percentage(1.0)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        };
+
+        let mut tree = Div::new()
+            .direction(Direction::Vertical)
+            .width_fraction(1.0)
+            .height(visible as u16)
+            .role(Role::List)
+            .focusable(true)
+            .on_key(Key::Up, ctx.handler(ListMsg::Up))
+            .on_key(Key::Down, ctx.handler(ListMsg::Down))
+            .on_key(Key::PageUp, ctx.handler(ListMsg::PageUp))
+            .on_key(Key::PageDown, ctx.handler(ListMsg::PageDown))
+            .on_key(Key::Home, ctx.handler(ListMsg::Home))
+            .on_key(Key::End, ctx.handler(ListMsg::End))
+            .on_key(Key::Enter, ctx.handler(ListMsg::Activate))
+            .on_key(Key::Right, ctx.handler(TreeMsg::Expand))
+            .on_key(Key::Left, ctx.handler(TreeMsg::Collapse))
+            .on_char(' ', ctx.handler(TreeMsg::Toggle))
+            .on_scroll(ctx.handler_with_value(ListMsg::Wheel));
+
+        let shown = rows
+            .iter()
+            .enumerate()
+            .skip(state.list.offset)
+            .take(visible);
+        for (index, row) in shown {
+            let Some(item) = self.item(&row.path) else {
+                continue;
+            };
+            let highlighted = index == state.list.highlighted;
+            let foreground = if highlighted {
+                palette.selection_foreground
+            } else {
+                palette.foreground
+            };
+
+            let depth = row.path.len() - 1;
+            let marker = match (row.has_children, row.expanded) {
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+                (false, _) => "  ",
+            };
+            let prefix = format!("{}{marker}", " ".repeat(depth * self.indent as usize));
+
+            let content = match &self.render {
+                Some(render) => render(item, highlighted),
+                None => Text::new(&item.label).color(foreground).into(),
+            };
+
+            let style = if highlighted {
+                Style::merge(Some(row_style.clone()), Some(highlight_style.clone()))
+            } else {
+                Some(row_style.clone())
+            };
+
+            let mut line = Div::new()
+                .role(Role::ListItem)
+                .label(item.label.clone())
+                .on_click(ctx.handler(ListMsg::Click(index)))
+                .child(Text::new(prefix).color(palette.muted).into())
+                .child(content);
+            if let Some(style) = style {
+                line = line.style(style);
+            }
+            tree = tree.child(line.into());
+        }
+
+        tree.into()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Component for Tree {
+    fn update(&self, ctx: &Context, msg: Box<dyn Message>, topic: Option<&str>) -> Action {
+        Tree::update(self, ctx, msg, topic)
+    }
+
+    fn view(&self, ctx: &Context) -> Node {
+        Tree::view(self, ctx)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl From<&str> for TreeItem {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for TreeItem {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files() -> Tree {
+        Tree::new([
+            TreeItem::new("src")
+                .expanded(true)
+                .child(TreeItem::new("main.rs"))
+                .child(TreeItem::new("ui").child(TreeItem::new("view.rs"))),
+            TreeItem::new("Cargo.toml"),
+        ])
+    }
+
+    fn labels(tree: &Tree, state: &TreeState) -> Vec<String> {
+        let expanded = state
+            .expanded
+            .clone()
+            .unwrap_or_else(|| tree.initially_expanded());
+        tree.rows(&expanded)
+            .iter()
+            .map(|row| tree.item(&row.path).unwrap().label.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_collapse_and_move() {
+        let tree = files();
+        let mut state = TreeState::default();
+        assert_eq!(
+            labels(&tree, &state),
+            ["src", "main.rs", "ui", "Cargo.toml"]
+        );
+
+        // Right on an expanded item moves into it, Left goes back to the parent
+        tree.apply(&mut state, &TreeMsg::Expand);
+        assert_eq!(state.list.highlighted, 1);
+        tree.apply(&mut state, &TreeMsg::Collapse);
+        assert_eq!(state.list.highlighted, 0);
+
+        // Expanding "ui" reveals its child
+        tree.navigate(&mut state, &ListMsg::End);
+        tree.navigate(&mut state, &ListMsg::Up);
+        assert_eq!(
+            tree.apply(&mut state, &TreeMsg::Expand).toggled,
+            Some((vec![0, 1], true))
+        );
+        assert_eq!(
+            labels(&tree, &state),
+            ["src", "main.rs", "ui", "view.rs", "Cargo.toml"]
+        );
+
+        // Collapsing the root hides every descendant
+        tree.navigate(&mut state, &ListMsg::Home);
+        assert_eq!(
+            tree.apply(&mut state, &TreeMsg::Toggle).toggled,
+            Some((vec![0], false))
+        );
+        assert_eq!(labels(&tree, &state), ["src", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn test_select_and_click_toggle() {
+        let tree = files();
+        let mut state = TreeState::default();
+
+        tree.navigate(&mut state, &ListMsg::Down);
+        assert_eq!(
+            tree.navigate(&mut state, &ListMsg::Activate),
+            Outcome {
+                selected: Some(vec![0, 0]),
+                toggled: None,
+            }
+        );
+
+        // Clicking an item with children selects and toggles it
+        assert_eq!(
+            tree.navigate(&mut state, &ListMsg::Click(2)),
+            Outcome {
+                selected: Some(vec![0, 1]),
+                toggled: Some((vec![0, 1], true)),
+            }
+        );
+        assert_eq!(labels(&tree, &state).len(), 5);
+
+        // Leaves have nothing to toggle
+        tree.navigate(&mut state, &ListMsg::End);
+        assert_eq!(tree.apply(&mut state, &TreeMsg::Toggle), Outcome::default());
+    }
+}
@@ -26,11 +26,14 @@
 //! - Text content changes
 //! - Property/style updates
 //! - Child additions/removals
+//! - Children that moved, matched by their component path
 //! - Node replacements
 
+use crate::component::ComponentId;
 use crate::render_tree::{RenderNode, RenderNodeType};
 use crate::vnode::VNode;
 use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 
 //--------------------------------------------------------------------------------------------------
@@ -84,6 +87,13 @@ pub enum Patch {
         parent: Rc<RefCell<RenderNode>>,
         index: usize,
     },
+
+    /// Replace a node's children with some of its existing ones, in a new order.
+    /// Keeps nodes that moved, along with their focus and scroll state.
+    ReorderChildren {
+        parent: Rc<RefCell<RenderNode>>,
+        children: Vec<Rc<RefCell<RenderNode>>>,
+    },
 }
 
 /// Context for accumulating patches during the diff process.
@@ -91,6 +101,9 @@ pub enum Patch {
 pub struct DiffContext {
     /// Accumulated patches discovered during diffing
     pub patches: Vec<Patch>,
+
+    /// Number of nodes compared, excluding memoized subtrees that were skipped
+    pub nodes_compared: usize,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -113,6 +126,7 @@ pub struct DiffContext {
 pub fn diff(old: &Rc<RefCell<RenderNode>>, new: &VNode) -> Vec<Patch> {
     let mut context = DiffContext {
         patches: Vec::new(),
+        nodes_compared: 0,
     };
 
     diff_node(&mut context, old, new);
@@ -125,6 +139,7 @@ pub fn diff(old: &Rc<RefCell<RenderNode>>, new: &VNode) -> Vec<Patch> {
 /// based on the node type combination.
 fn diff_node(context: &mut DiffContext, old: &Rc<RefCell<RenderNode>>, new: &VNode) {
     let old_ref = old.borrow();
+    context.nodes_compared += 1;
 
     match (&old_ref.node_type, new) {
         (RenderNodeType::Text(old_text), VNode::Text(new_text)) => {
@@ -178,17 +193,24 @@ fn diff_div(
     old_ref: &RenderNode,
     new_div: &crate::node::Div<VNode>,
 ) {
+    // A memoized subtree whose key is unchanged keeps what was built last time
+    if new_div.memo_key.is_some() && new_div.memo_key == old_ref.memo_key {
+        return;
+    }
+
     let props_changed = {
         let old_style = &old_ref.style;
         // Use the OLD node's state flags, not the new div's (which default to false)
         let is_focused = old_ref.focused;
         let is_hovered = old_ref.hovered;
-        // Get effective style based on the preserved focus/hover state
+        let is_active = old_ref.active;
+        // Get effective style based on the preserved focus/hover/active state
         let new_style = RenderNode::compose_state_style(
             &new_div.styles,
             new_div.focusable,
             is_focused,
             is_hovered,
+            is_active,
         );
         let new_style_ref = &new_style;
 
@@ -201,7 +223,11 @@ fn diff_div(
             (None, None) => false,
         };
 
-        old_style != new_style_ref || dimensions_changed
+        // A new memo key must be stored for the next frame to compare against
+        old_style != new_style_ref
+            || dimensions_changed
+            || old_ref.memo_key != new_div.memo_key
+            || old_ref.scroll_capture != new_div.scroll_capture
     };
 
     if props_changed {
@@ -216,15 +242,9 @@ fn diff_div(
 
 /// Diffs two lists of children, handling additions, removals, and updates.
 ///
-/// Currently implements a simple index-based diff:
-/// 1. Diffs common children by index
-/// 2. Adds new children if new list is longer
-/// 3. Removes extra children if old list is longer
-///
-/// ## Note
-///
-/// Currently implements a simple index-based diff. Could be optimized
-/// with a key-based algorithm for better handling of reordered children.
+/// Children are compared by index while every div keeps the path it had at
+/// its position. Once a path has moved, as when keyed components are
+/// reordered, [`diff_moved_children`] matches children by path instead.
 fn diff_children(
     context: &mut DiffContext,
     parent: &Rc<RefCell<RenderNode>>,
@@ -235,6 +255,15 @@ fn diff_children(
     let new_len = new_children.len();
     let min_len = old_len.min(new_len);
 
+    let moved = old_children
+        .iter()
+        .zip(new_children)
+        .any(|(old, new)| old.borrow().component_path.as_ref() != vnode_path(new));
+    if moved {
+        diff_moved_children(context, parent, old_children, new_children);
+        return;
+    }
+
     for i in 0..min_len {
         diff_node(context, &old_children[i], &new_children[i]);
     }
@@ -256,3 +285,228 @@ fn diff_children(
         }
     }
 }
+
+/// Diffs children whose divs moved, matching each to the old child with its path.
+///
+/// Sibling divs sharing a path take the old children with that path in order.
+/// Children without a path (text) take the unmatched old children without one
+/// in order. Old children left unmatched are dropped, and new children without
+/// a match are added.
+fn diff_moved_children(
+    context: &mut DiffContext,
+    parent: &Rc<RefCell<RenderNode>>,
+    old_children: &[Rc<RefCell<RenderNode>>],
+    new_children: &[VNode],
+) {
+    let mut by_path: HashMap<ComponentId, VecDeque<usize>> = HashMap::new();
+    let mut unpathed = VecDeque::new();
+    for (index, old) in old_children.iter().enumerate() {
+        match old.borrow().component_path.clone() {
+            Some(path) => by_path.entry(path).or_default().push_back(index),
+            None => unpathed.push_back(index),
+        }
+    }
+
+    let matches: Vec<Option<usize>> = new_children
+        .iter()
+        .map(|new| match vnode_path(new) {
+            Some(path) => by_path.get_mut(path).and_then(VecDeque::pop_front),
+            None => unpathed.pop_front(),
+        })
+        .collect();
+
+    // Keep the matched nodes in their new order, then add the others around them
+    context.patches.push(Patch::ReorderChildren {
+        parent: parent.clone(),
+        children: matches
+            .iter()
+            .flatten()
+            .map(|&index| old_children[index].clone())
+            .collect(),
+    });
+    for (i, (new, matched)) in new_children.iter().zip(matches).enumerate() {
+        match matched {
+            Some(index) => diff_node(context, &old_children[index], new),
+            None => context.patches.push(Patch::AddChild {
+                parent: parent.clone(),
+                child: new.clone(),
+                index: i,
+            }),
+        }
+    }
+}
+
+/// Returns the component path a new child is identified by, if it is a div
+fn vnode_path(node: &VNode) -> Option<&ComponentId> {
+    match node {
+        VNode::Div(div) => div.component_path.as_ref(),
+        _ => None,
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{Div, Text};
+    use crate::vdom::VDom;
+    use std::time::{Duration, Instant};
+
+    /// A div with `rows` text children, memoized on `key` if given.
+    fn static_panel(rows: usize, key: Option<u64>) -> VNode {
+        let children = (0..rows)
+            .map(|i| VNode::Text(Text::new(format!("row {i}"))))
+            .collect();
+        let div = Div::new().children(children);
+        match key {
+            Some(key) => div.memo(key).into(),
+            None => div.into(),
+        }
+    }
+
+    fn compare(vdom: &VDom, new: &VNode) -> DiffContext {
+        let mut context = DiffContext {
+            patches: Vec::new(),
+            nodes_compared: 0,
+        };
+        diff_node(
+            &mut context,
+            vdom.get_render_tree().root.as_ref().unwrap(),
+            new,
+        );
+        context
+    }
+
+    #[test]
+    fn test_memo_skips_unchanged_subtree() {
+        let mut vdom = VDom::new();
+        vdom.render(static_panel(100, Some(1)));
+
+        let same = compare(&vdom, &static_panel(100, Some(1)));
+        assert_eq!(same.nodes_compared, 1);
+        assert!(same.patches.is_empty());
+
+        let unmemoized = compare(&vdom, &static_panel(100, None));
+        assert_eq!(unmemoized.nodes_compared, 101);
+
+        // A new key diffs the subtree and stores the key for the next frame
+        let mut changed = static_panel(100, Some(2));
+        if let VNode::Div(div) = &mut changed {
+            div.children[0] = VNode::text("updated");
+        }
+        let context = compare(&vdom, &changed);
+        assert_eq!(context.nodes_compared, 101);
+        assert!(matches!(context.patches[0], Patch::UpdateProps { .. }));
+        assert!(matches!(context.patches[1], Patch::UpdateText { .. }));
+
+        vdom.render(changed);
+        assert_eq!(
+            compare(&vdom, &static_panel(100, Some(2))).nodes_compared,
+            1
+        );
+    }
+
+    /// A div of rows identified by keyed paths, like keyed components render.
+    fn keyed_rows(keys: &[&str]) -> VNode {
+        let parent = ComponentId::new("0");
+        let children = keys
+            .iter()
+            .map(|key| {
+                let mut row = Div::new().children(vec![VNode::text(*key)]);
+                row.component_path = Some(parent.keyed(key));
+                row.into()
+            })
+            .collect();
+        Div::new().children(children).into()
+    }
+
+    #[test]
+    fn test_moved_children_keep_their_nodes() {
+        let mut vdom = VDom::new();
+        vdom.render(keyed_rows(&["a", "b", "c"]));
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let old: Vec<_> = root.borrow().children.clone();
+
+        // Positional children with unchanged paths are diffed by index
+        assert!(
+            compare(&vdom, &keyed_rows(&["a", "b", "c"]))
+                .patches
+                .is_empty()
+        );
+
+        vdom.render(keyed_rows(&["c", "b", "d"]));
+        let children = root.borrow().children.clone();
+        let texts: Vec<String> = children
+            .iter()
+            .map(
+                |child| match &child.borrow().children[0].borrow().node_type {
+                    RenderNodeType::Text(text) => text.clone(),
+                    _ => String::new(),
+                },
+            )
+            .collect();
+        assert_eq!(texts, ["c", "b", "d"]);
+        assert!(Rc::ptr_eq(&children[0], &old[2]));
+        assert!(Rc::ptr_eq(&children[1], &old[1]));
+        assert!(!old.iter().any(|node| Rc::ptr_eq(node, &children[2])));
+    }
+
+    #[test]
+    fn test_siblings_sharing_a_path_keep_their_nodes() {
+        // A component rendering several sibling divs gives them all its path
+        let rows = |rows: &[(&str, &str)]| -> VNode {
+            let parent = ComponentId::new("0");
+            let children = rows
+                .iter()
+                .map(|(key, text)| {
+                    let mut row = Div::new().children(vec![VNode::text(*text)]);
+                    row.component_path = Some(parent.keyed(key));
+                    row.into()
+                })
+                .collect();
+            Div::new().children(children).into()
+        };
+
+        let mut vdom = VDom::new();
+        vdom.render(rows(&[("a", "a"), ("b", "b1"), ("b", "b2"), ("b", "b3")]));
+        let root = vdom.get_render_tree().root.clone().unwrap();
+        let old: Vec<_> = root.borrow().children.clone();
+
+        vdom.render(rows(&[("b", "b1"), ("b", "b2"), ("b", "b3"), ("a", "a")]));
+        let children = root.borrow().children.clone();
+        assert_eq!(children.len(), 4);
+        for (child, old) in children.iter().zip([&old[1], &old[2], &old[3], &old[0]]) {
+            assert!(Rc::ptr_eq(child, old));
+        }
+    }
+
+    /// Times rerendering a large static panel with and without a memo key.
+    ///
+    /// Run with `cargo test --release -p rxtui bench_memo -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_memo_static_subtree() {
+        const ROWS: usize = 10_000;
+        const FRAMES: u32 = 200;
+
+        let time = |key: Option<u64>| -> Duration {
+            let mut vdom = VDom::new();
+            vdom.render(static_panel(ROWS, key));
+            let frames: Vec<VNode> = (0..FRAMES).map(|_| static_panel(ROWS, key)).collect();
+
+            let start = Instant::now();
+            for frame in frames {
+                vdom.render(frame);
+            }
+            start.elapsed() / FRAMES
+        };
+
+        let plain = time(None);
+        let memoized = time(Some(0));
+        println!("{ROWS} rows: {plain:?} per frame diffed, {memoized:?} per frame memoized");
+        assert!(memoized < plain);
+    }
+}
@@ -214,6 +214,34 @@ impl Key {
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
 
+impl From<Key> for KeyWithModifiers {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+impl std::fmt::Display for KeyWithModifiers {
+    /// Formats the combination for display, e.g. `Ctrl+Shift+S`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (held, name) in [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (self.shift, "Shift"),
+            (self.meta, "Meta"),
+        ] {
+            if held {
+                write!(f, "{name}+")?;
+            }
+        }
+        match self.key {
+            Key::Char(c) if self.ctrl || self.alt || self.meta => {
+                write!(f, "{}", c.to_ascii_uppercase())
+            }
+            key => write!(f, "{key}"),
+        }
+    }
+}
+
 impl std::fmt::Display for Key {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
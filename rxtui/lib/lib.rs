@@ -332,6 +332,10 @@ mod buffer;
 /// Minimizes escape sequences and I/O operations for best performance.
 mod terminal;
 
+/// Plain-text semantic outline of the UI for logs, accessibility, and tests.
+/// Describes structure and content rather than styled cells.
+mod semantic;
+
 //--------------------------------------------------------------------------------------------------
 // Modules: Application
 //--------------------------------------------------------------------------------------------------
@@ -352,6 +356,10 @@ pub mod style;
 /// Provides types for tracking screen regions that need redrawing.
 pub mod bounds;
 
+/// Application-wide theme with a palette and default component styles.
+/// Loading themes from TOML or JSON requires the `serde` feature.
+pub mod theme;
+
 //--------------------------------------------------------------------------------------------------
 // Modules: Input & Utilities
 //--------------------------------------------------------------------------------------------------
@@ -364,6 +372,18 @@ pub mod key;
 /// Provides helpers for display width, text manipulation, and wrapping algorithms.
 mod utils;
 
+/// Text measurement in terminal columns for custom widgets.
+/// Measures, truncates and wraps text the same way the renderer does.
+pub mod text;
+
+/// Braille canvas for plotting at 2x4 dots per cell.
+/// Draws points, lines and sparklines finer than whole cells.
+pub mod canvas;
+
+/// Parsing of ANSI-colored text, such as command output, into rich text.
+/// Maps SGR sequences to text styles and drops other escape sequences.
+pub mod ansi;
+
 /// Provider traits for Component macro system (internal use)
 /// Enables safe defaults via method shadowing for update/view/effects
 #[doc(hidden)]
@@ -422,17 +442,33 @@ pub use rxtui_macros::{component, update, view};
 #[cfg(feature = "effects")]
 pub use rxtui_macros::effect;
 
-pub use app::{App, Context, InlineConfig, InlineHeight, TerminalMode};
+pub use app::{
+    App, Binding, BindingScope, Breakpoint, Breakpoints, Context, FocusDirection, FrameStats,
+    InlineConfig, InlineHeight, Local, NonTtyFallback, RedrawHandle, RunningApp, TerminalMode,
+    ViewportConfig,
+};
 pub use bounds::Rect;
-pub use component::{Action, Component, Message, MessageExt, State};
+pub use buffer::{assert_snapshot, diff_snapshots};
+pub use component::{
+    Action, Component, ComponentId, Message, MessageExt, RenderError, State, props_differ,
+};
 #[cfg(feature = "components")]
-pub use components::{ShimmerSpeed, ShimmerText, TextInput};
+pub use components::{
+    Accordion, AccordionMode, AnsiText, Button, ButtonVariant, FuzzyList, List, ListItem, LogView,
+    Menu, MenuItem, MenuKind, NumberInput, ShimmerSpeed, ShimmerText, Skeleton, Sparkline,
+    StatusBar, TextInput, Toast, ToastLevel, ToastPosition,
+};
 pub use key::{Key, KeyWithModifiers};
-pub use node::{Div, Node, RichText, Text, TextSpan};
+pub use node::{Div, Node, RichText, Role, Text, TextSpan};
+pub use render_tree::{ElementPath, Hit};
 pub use style::{
-    BorderEdges, BorderStyle, Color, Dimension, Direction, Overflow, Position, Spacing, Style,
-    TextStyle, TextWrap, WrapMode,
+    BorderEdges, BorderStyle, Color, Dimension, Direction, LineBreak, Offset, Overflow,
+    ParseColorError, Position, Spacing, Style, TextStyle, TextTransform, TextWrap, UnderlineStyle,
+    WrapMode, ZIndex,
 };
+#[cfg(feature = "serde")]
+pub use theme::ThemeError;
+pub use theme::{Palette, Theme};
 
 //--------------------------------------------------------------------------------------------------
 // Tests
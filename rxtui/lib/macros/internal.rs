@@ -55,10 +55,16 @@ macro_rules! color_value {
     (bright_white) => {
         $crate::Color::BrightWhite
     };
+    (default) => {
+        $crate::Color::Default
+    };
 
-    // Hex color strings
-    ($hex:literal) => {
-        $crate::Color::hex($hex)
+    // Color strings: hex, CSS names, rgb() and hsl()
+    ($color:literal) => {
+        match <$crate::Color as ::std::str::FromStr>::from_str($color) {
+            Ok(color) => color,
+            Err(error) => panic!("{}", error),
+        }
     };
 
     // Any other expression - pass through
@@ -83,6 +89,21 @@ macro_rules! direction_value {
     (h) => {
         $crate::Direction::Horizontal
     };
+    (vertical_reverse) => {
+        $crate::Direction::VerticalReverse
+    };
+    (vr) => {
+        $crate::Direction::VerticalReverse
+    };
+    (reverse) => {
+        $crate::Direction::VerticalReverse
+    };
+    (horizontal_reverse) => {
+        $crate::Direction::HorizontalReverse
+    };
+    (hr) => {
+        $crate::Direction::HorizontalReverse
+    };
     ($dir:expr) => {
         $dir
     };
@@ -152,11 +173,38 @@ macro_rules! overflow_value {
     (auto) => {
         $crate::Overflow::Auto
     };
+    (visible) => {
+        $crate::Overflow::Visible
+    };
     ($overflow:expr) => {
         $overflow
     };
 }
 
+/// Converts role values to Role enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! role_value {
+    (heading) => {
+        $crate::Role::Heading
+    };
+    (button) => {
+        $crate::Role::Button
+    };
+    (list) => {
+        $crate::Role::List
+    };
+    (list_item) => {
+        $crate::Role::ListItem
+    };
+    (presentation) => {
+        $crate::Role::Presentation
+    };
+    ($role:expr) => {
+        $role
+    };
+}
+
 /// Converts wrap mode values to WrapMode enum
 #[doc(hidden)]
 #[macro_export]
@@ -215,11 +263,74 @@ macro_rules! text_align_value {
     (right) => {
         $crate::style::TextAlign::Right
     };
+    (justify) => {
+        $crate::style::TextAlign::Justify
+    };
     ($align:expr) => {
         $align
     };
 }
 
+/// Converts text transform values to TextTransform enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! text_transform_value {
+    (none) => {
+        $crate::style::TextTransform::None
+    };
+    (uppercase) => {
+        $crate::style::TextTransform::Uppercase
+    };
+    (lowercase) => {
+        $crate::style::TextTransform::Lowercase
+    };
+    (capitalize) => {
+        $crate::style::TextTransform::Capitalize
+    };
+    ($transform:expr) => {
+        $transform
+    };
+}
+
+/// Converts line break values to LineBreak enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! line_break_value {
+    (whitespace) => {
+        $crate::style::LineBreak::Whitespace
+    };
+    (cjk) => {
+        $crate::style::LineBreak::Cjk
+    };
+    ($line_break:expr) => {
+        $line_break
+    };
+}
+
+/// Converts underline style values to UnderlineStyle enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! underline_style_value {
+    (single) => {
+        $crate::UnderlineStyle::Single
+    };
+    (double) => {
+        $crate::UnderlineStyle::Double
+    };
+    (curly) => {
+        $crate::UnderlineStyle::Curly
+    };
+    (dotted) => {
+        $crate::UnderlineStyle::Dotted
+    };
+    (dashed) => {
+        $crate::UnderlineStyle::Dashed
+    };
+    ($style:expr) => {
+        $style
+    };
+}
+
 /// Converts position values to Position enum
 #[doc(hidden)]
 #[macro_export]
@@ -278,6 +389,9 @@ macro_rules! align_items_value {
     (end) => {
         $crate::style::AlignItems::End
     };
+    (baseline) => {
+        $crate::style::AlignItems::Baseline
+    };
     ($align:expr) => {
         $align
     };
@@ -299,6 +413,9 @@ macro_rules! align_self_value {
     (end) => {
         $crate::style::AlignSelf::End
     };
+    (baseline) => {
+        $crate::style::AlignSelf::Baseline
+    };
     ($align:expr) => {
         $align
     };
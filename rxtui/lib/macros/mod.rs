@@ -49,12 +49,14 @@
 //!
 //! Common properties have short aliases:
 //! - `bg` → background color
-//! - `dir` → direction (vertical/v, horizontal/h)
+//! - `dir` → direction (vertical/v, horizontal/h, vertical_reverse/vr/reverse, horizontal_reverse/hr)
 //! - `pad` → padding
 //! - `w` → width
 //! - `h` → height
 //! - `w_frac` → width fraction (0.0–1.0)
 //! - `h_frac` → height fraction (0.0–1.0)
+//! - `w_rem` → width fraction of the space left by siblings (0.0–1.0)
+//! - `h_rem` → height fraction of the space left by siblings (0.0–1.0)
 //!
 //! # Event Handlers
 //!
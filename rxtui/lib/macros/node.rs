@@ -10,6 +10,8 @@
 /// - **Divs**: `div(props) [children]` - Properties in parentheses, children in brackets
 /// - **Text**: `text("content", props)` - Content first, then properties
 /// - **Input**: `input(props)` - Text input field with properties
+/// - **Number**: `number(props)` - Numeric input with stepping
+/// - **Menu**: `menu(props)` - Menu bar or context menu
 /// - **Spacers**: `spacer(size)` - Simple spacing elements
 /// - **Components**: `node(instance)` - Embed other components
 ///
@@ -63,13 +65,19 @@
 ///     div(
 ///         // Colors
 ///         bg: black,              // Named color
+///         fill: '·',              // Repeat a character across the content area
+///         fill_color: bright_black, // Color of the fill character
 ///         border_color: "#FF5733", // Hex color (or use legacy 'border:')
 ///
 ///         // Border configuration
 ///         border_style: rounded,   // Style only (single, double, thick, rounded, dashed)
 ///         border_color: white,     // Border color
 ///         border_edges: top | bottom,  // Which edges (can use | for multiple)
+///         border_chars: (['+', '-', '+', '|', '|', '+', '-', '+']),  // Custom characters
 ///         border_full: (BorderStyle::Double, yellow, BorderEdges::ALL),  // Full config (legacy)
+///         join_borders: true,      // Collapse children's borders into junctions
+///         clip_corners: true,      // Rounded corners show the background beneath
+///         inherit_bg: false,       // Borders show what's beneath, not the parent's bg
 ///
 ///         // Dimensions
 ///         w: 50,                  // Fixed width
@@ -78,18 +86,22 @@
 ///         h_frac: 0.8,            // Height as fraction (80%)
 ///         w_auto,                // Automatic width
 ///         h_content,             // Height based on content
+///         aspect: 1.0,           // Width:height ratio, deriving the unset one
 ///
 ///         // Spacing
 ///         pad: 2,                // Padding on all sides
 ///         pad_h: 1,              // Horizontal padding only
 ///         pad_v: 1,              // Vertical padding only
 ///         padding: (Spacing::horizontal(2)), // Direct Spacing expression
+///         pad_frac: 0.05,        // Padding as a fraction of the parent
 ///         gap: 1,                // Gap between children
+///         gap_frac: 0.1,         // Gap as a fraction of the parent
 ///
 ///         // Layout
 ///         dir: horizontal,       // Direction (or use 'h')
 ///         wrap: wrap,           // Wrap mode (lowercase)
 ///         overflow: hidden,     // Overflow behavior (lowercase)
+///         role: heading,        // Accessibility role (lowercase)
 ///
 ///         // Positioning
 ///         pos: absolute,        // Position type (lowercase)
@@ -98,11 +110,23 @@
 ///         right: 10,           // Offset from right
 ///         bottom: 5,           // Offset from bottom
 ///         left: 10,            // Offset from left
+///         left_frac: 0.5,      // Offset as a fraction of the container (also top/right/bottom)
+///         translate: (Offset::percent(-0.5), 0),  // Shift by a fraction of its own size
 ///         z: 100,              // Z-index for layering
+///         portal: 1,           // Paint in a top-level overlay layer
+///
+///         // Prebuilt style, layered onto the props before it
+///         style: (card_style()),
+///
+///         // Skip diffing the subtree while the key is unchanged
+///         memo: (state.title.clone(), state.width),
 ///
 ///         // Interaction
 ///         focusable,           // Can receive focus
-///         focus_style: (Style::new().border(yellow))  // Style when focused
+///         focus_style: (Style::new().border(yellow)),  // Style when focused
+///         pressed_style: (Style::new().background(blue)),  // Style while pressed
+///         selected: (state.cursor),  // Child highlighted as selected
+///         selected_style: (Style::new().background(blue))  // Its highlight
 ///     ) [
 ///         text("Styled Div")
 ///     ]
@@ -118,12 +142,18 @@
 ///         text("Italic text", italic),
 ///         text("Underlined", underline),
 ///         text("Strikethrough", strikethrough),
+///         text("Selected", reverse),      // Swap foreground and background
+///         text("Alert", blink),
+///         text("secret", conceal),        // Alias: hidden
+///         text("Misspeled", underline_style: curly, underline_color: red),
 ///
 ///         // Colors
 ///         text("Red text", color: red),
 ///         text("Bright blue", color: bright_blue),
 ///         text("Custom hex", color: "#00FF00"),
 ///         text("With background", color: white, bg: blue),
+///         text("Solid bar", bg: blue, align: left, bg_fill: true),
+///         text("Long title", wrap: word, ellipsis: true),
 ///
 ///         // Multiple styles
 ///         text("Important!", color: yellow, bg: red, bold, underline),
@@ -131,6 +161,15 @@
 ///         // Text wrapping
 ///         text("Long text that wraps", wrap: word),
 ///
+///         // Displayed case, leaving the content as written
+///         text("section title", transform: uppercase),
+///
+///         // Tab stops every 4 columns instead of the app default
+///         text("name\tsize", tab_width: 4),
+///
+///         // Prebuilt TextStyle, layered onto the props before it
+///         text("Muted", style: (muted_style()), bold),
+///
 ///         // Text alignment
 ///         text("Centered text", align: center),
 ///         text("Right aligned", align: right),
@@ -156,6 +195,9 @@
 ///             h: 3
 ///         ),
 ///
+///         // Password input, masked with `*` until Ctrl+R is pressed
+///         input(password, mask_char: '*', reveal_key: (ctrl + 'r')),
+///
 ///         // Wrapped input for long text
 ///         input(
 ///             placeholder: "Long message...",
@@ -168,6 +210,30 @@
 /// }
 /// ```
 ///
+/// ## Number Inputs
+/// ```ignore
+/// node! {
+///     div [
+///         number(min: 0.0, max: 100.0, step: 5.0, value: 50.0, @change: |v| log(v)),
+///         number(integer, wheel, min: 1.0, w: 8)
+///     ]
+/// }
+/// ```
+///
+/// ## Menus
+/// ```ignore
+/// node! {
+///     div [
+///         // Menu bar; the handler receives the selected item's id
+///         menu(items: menu_items(), @select: ctx.handler_with_value(Msg::Command)),
+///
+///         // Context menu opened by right-clicking its content
+///         menu(context, items: edit_items(), content: node! { div [text("Right-click me")] },
+///             @select: ctx.handler_with_value(Msg::Command))
+///     ]
+/// }
+/// ```
+///
 /// ## Rich Text (Inline Styled Text)
 /// ```ignore
 /// node! {
@@ -296,6 +362,29 @@
 /// }
 /// ```
 ///
+/// ## Match Directive
+///
+/// `match` picks the children to render from a value. Each arm's children are
+/// spliced in among their siblings, and arms can bind and use guards:
+///
+/// ```ignore
+/// node! {
+///     div [
+///         text("Status:", bold),
+///         match state.phase {
+///             Phase::Loading => { text("Loading...") },
+///             Phase::Failed(error) if error.retryable => {
+///                 text(format!("Failed: {error}"), color: red),
+///                 text("Press r to retry")
+///             },
+///             Phase::Failed(error) => { text(format!("Failed: {error}"), color: red) },
+///             Phase::Done => {}
+///         },
+///         text("footer")
+///     ]
+/// }
+/// ```
+///
 /// ## Optional Properties
 ///
 /// Use the `!` suffix after a parenthesized expression to conditionally apply properties.
@@ -362,6 +451,8 @@
 ///
 /// - `vertical` or `v` - Stack children vertically
 /// - `horizontal` or `h` - Arrange children horizontally
+/// - `vertical_reverse`, `vr` or `reverse` - Stack children bottom to top
+/// - `horizontal_reverse` or `hr` - Arrange children right to left
 ///
 /// # Property Shortcuts
 ///
@@ -378,6 +469,9 @@
 /// | `h` | `height` | Fixed height |
 /// | `w_frac` | `width_fraction` | Width as fraction (0.0-1.0) |
 /// | `h_frac` | `height_fraction` | Height as fraction (0.0-1.0) |
+/// | `w_rem` | `width_remaining` | Width as fraction of remaining space (0.0-1.0) |
+/// | `h_rem` | `height_remaining` | Height as fraction of remaining space (0.0-1.0) |
+/// | `aspect` | `aspect_ratio` | Visual width:height ratio |
 ///
 /// # Event Handler Reference
 ///
@@ -386,6 +480,8 @@
 /// | Handler | Description | Example |
 /// |---------|-------------|---------|
 /// | `@click` | Mouse click | `@click: handler` |
+/// | `@right_click` | Right click, receives `(column, row)` within the element | `@right_click: ctx.handler_with_value(Msg::Menu)` |
+/// | `@middle_click` | Middle click, receives `(column, row)` within the element | `@middle_click: ctx.handler_with_value(Msg::Paste)` |
 /// | `@char(c)` | Character key press | `@char('a'): handler` |
 /// | `@key(k)` | Special key press | `@key(enter): handler` |
 /// | `@key(Char(c))` | Character in key enum | `@key(Char('-')): handler` |
@@ -393,9 +489,15 @@
 /// | `@char_global(c)` | Global character key | `@char_global('q'): handler` |
 /// | `@key_global(k)` | Global special key | `@key_global(esc): handler` |
 /// | `@key_global(mod + key)` | Global key with modifiers | `@key_global(ctrl + enter): handler` |
+/// | `@seq(s)` | Characters typed in order | `@seq("gg"): handler` |
+/// | `@seq_global(s)` | Global character sequence | `@seq_global("dd"): handler` |
 /// | `@focus` | Gained focus | `@focus: handler` |
 /// | `@blur` | Lost focus | `@blur: handler` |
+/// | `@focus_change` | Focus moved within the subtree | `@focus_change: \|path\| ...` |
 /// | `@any_char` | Any character typed | `@any_char: \|c\| handler(c)` |
+/// | `@key_if(pred)` | Any key the predicate accepts, receives the key | `@key_if(\|k\| matches!(k, Key::Up \| Key::Down)): \|k\| handler(k)` |
+/// | `@key_if_global(pred)` | Global predicate key handler | `@key_if_global(\|k\| k == Key::F1): \|_\| handler()` |
+/// | `@char_range(a, b)` | Any character from `a` to `b`, receives it | `@char_range('0', '9'): \|c\| handler(c)` |
 ///
 /// # Tips
 ///
@@ -478,6 +580,21 @@ macro_rules! tui_parse_element {
         $crate::Node::Component(std::sync::Arc::new($crate::TextInput::new()))
     }};
 
+    // Number input with properties
+    (number($($props:tt)*)) => {{
+        $crate::tui_build_number!($($props)*)
+    }};
+
+    // Number input without properties
+    (number) => {{
+        $crate::Node::Component(std::sync::Arc::new($crate::NumberInput::new()))
+    }};
+
+    // Menu with properties
+    (menu($($props:tt)*)) => {{
+        $crate::tui_build_menu!($($props)*)
+    }};
+
     // VStack with properties
     (vstack($($props:tt)*) [$($children:tt)*]) => {{
         $crate::tui_build_div!(
@@ -677,6 +794,11 @@ macro_rules! tui_parse_children {
         $crate::tui_parse_children!($children, $container)
     }};
 
+    // Children: match directive, splicing in the children of the matching arm
+    ($children:ident, $container:expr, match $($rest:tt)+) => {{
+        $crate::tui_parse_match!(@scrutinee $children, $container, [] $($rest)+)
+    }};
+
     // Child: input with props (and more children)
     ($children:ident, $container:expr, input($($props:tt)*), $($rest:tt)*) => {{
         let child = $crate::tui_parse_element!(input($($props)*));
@@ -705,6 +827,48 @@ macro_rules! tui_parse_children {
         $crate::tui_parse_children!($children, $container)
     }};
 
+    // Child: number input with props (and more children)
+    ($children:ident, $container:expr, number($($props:tt)*), $($rest:tt)*) => {{
+        let child = $crate::tui_parse_element!(number($($props)*));
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container, $($rest)*)
+    }};
+
+    // Child: number input with props (last child)
+    ($children:ident, $container:expr, number($($props:tt)*)) => {{
+        let child = $crate::tui_parse_element!(number($($props)*));
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container)
+    }};
+
+    // Child: number input without props (and more children)
+    ($children:ident, $container:expr, number, $($rest:tt)*) => {{
+        let child = $crate::tui_parse_element!(number);
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container, $($rest)*)
+    }};
+
+    // Child: number input without props (last child)
+    ($children:ident, $container:expr, number) => {{
+        let child = $crate::tui_parse_element!(number);
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container)
+    }};
+
+    // Child: menu with props (and more children)
+    ($children:ident, $container:expr, menu($($props:tt)*), $($rest:tt)*) => {{
+        let child = $crate::tui_parse_element!(menu($($props)*));
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container, $($rest)*)
+    }};
+
+    // Child: menu with props (last child)
+    ($children:ident, $container:expr, menu($($props:tt)*)) => {{
+        let child = $crate::tui_parse_element!(menu($($props)*));
+        $children.push(child);
+        $crate::tui_parse_children!($children, $container)
+    }};
+
     // Child: vstack with props (and more children)
     ($children:ident, $container:expr, vstack($($props:tt)*) [$($inner:tt)*], $($rest:tt)*) => {{
         let child = $crate::tui_parse_element!(vstack($($props)*) [$($inner)*]);
@@ -790,6 +954,46 @@ macro_rules! tui_parse_children {
     }};
 }
 
+/// Parse a `match` directive among children (internal)
+///
+/// The scrutinee is collected token by token up to the block of arms, since
+/// an `expr` fragment can't be followed by a block.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tui_parse_match {
+    // Arms reached (and more children)
+    (@scrutinee $children:ident, $container:expr, [$($scrutinee:tt)+] { $($arms:tt)* }, $($rest:tt)*) => {{
+        $crate::tui_parse_match!(@arms $children, [$($scrutinee)+] $($arms)*);
+        $crate::tui_parse_children!($children, $container, $($rest)*)
+    }};
+
+    // Arms reached (last child)
+    (@scrutinee $children:ident, $container:expr, [$($scrutinee:tt)+] { $($arms:tt)* }) => {{
+        $crate::tui_parse_match!(@arms $children, [$($scrutinee)+] $($arms)*);
+        $crate::tui_parse_children!($children, $container)
+    }};
+
+    // Next token of the scrutinee
+    (@scrutinee $children:ident, $container:expr, [$($scrutinee:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::tui_parse_match!(@scrutinee $children, $container, [$($scrutinee)* $next] $($rest)*)
+    };
+
+    // Each arm's body is parsed as children of a scratch div and spliced in
+    (@arms $children:ident, [$($scrutinee:tt)+] $($pat:pat $(if $guard:expr)? => { $($body:tt)* } $(,)?)*) => {
+        $children.extend(match $($scrutinee)+ {
+            $($pat $(if $guard)? => {
+                let mut __arm_children = Vec::new();
+                let __arm: $crate::Div<$crate::Node> = $crate::tui_parse_children!(
+                    __arm_children,
+                    $crate::Div::<$crate::Node>::new(),
+                    $($body)*
+                );
+                __arm.children
+            })*
+        })
+    };
+}
+
 /// Apply properties to div (internal)
 #[doc(hidden)]
 #[macro_export]
@@ -883,6 +1087,15 @@ macro_rules! tui_apply_props {
         $container.padding($crate::Spacing::vertical($pad))
     }};
 
+    // Padding as a fraction of the parent's content size
+    ($container:expr, pad_frac: $frac:expr, $($rest:tt)*) => {{
+        let c = $container.padding_fraction($frac);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, pad_frac: $frac:expr) => {{
+        $container.padding_fraction($frac)
+    }};
+
     // Direct padding expression
     ($container:expr, padding: ($padding:expr), $($rest:tt)*) => {{
         let c = $container.padding($padding);
@@ -927,6 +1140,15 @@ macro_rules! tui_apply_props {
         $container.width_fraction($frac)
     }};
 
+    // Width fraction of remaining space
+    ($container:expr, w_rem: $frac:expr, $($rest:tt)*) => {{
+        let c = $container.width_remaining($frac);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, w_rem: $frac:expr) => {{
+        $container.width_remaining($frac)
+    }};
+
     // Width auto
     ($container:expr, w_auto, $($rest:tt)*) => {{
         let c = $container.width_auto();
@@ -971,6 +1193,24 @@ macro_rules! tui_apply_props {
         }
     }};
 
+    // Aspect ratio
+    ($container:expr, aspect: $ratio:expr, $($rest:tt)*) => {{
+        let c = $container.aspect_ratio($ratio);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, aspect: $ratio:expr) => {{
+        $container.aspect_ratio($ratio)
+    }};
+
+    // Cell aspect for the aspect ratio
+    ($container:expr, cell_aspect: $factor:expr, $($rest:tt)*) => {{
+        let c = $container.cell_aspect($factor);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, cell_aspect: $factor:expr) => {{
+        $container.cell_aspect($factor)
+    }};
+
     // Height fraction
     ($container:expr, h_frac: $frac:expr, $($rest:tt)*) => {{
         let c = $container.height_fraction($frac);
@@ -980,6 +1220,15 @@ macro_rules! tui_apply_props {
         $container.height_fraction($frac)
     }};
 
+    // Height fraction of remaining space
+    ($container:expr, h_rem: $frac:expr, $($rest:tt)*) => {{
+        let c = $container.height_remaining($frac);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, h_rem: $frac:expr) => {{
+        $container.height_remaining($frac)
+    }};
+
     // Height auto
     ($container:expr, h_auto, $($rest:tt)*) => {{
         let c = $container.height_auto();
@@ -1007,6 +1256,31 @@ macro_rules! tui_apply_props {
         $container.gap($gap)
     }};
 
+    // Gap as a fraction of the parent's content size along the main axis
+    ($container:expr, gap_frac: $frac:expr, $($rest:tt)*) => {{
+        let c = $container.gap_fraction($frac);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, gap_frac: $frac:expr) => {{
+        $container.gap_fraction($frac)
+    }};
+
+    // Fill character and its color
+    ($container:expr, fill: $ch:expr, $($rest:tt)*) => {{
+        let c = $container.fill_char($ch);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, fill: $ch:expr) => {{
+        $container.fill_char($ch)
+    }};
+    ($container:expr, fill_color: $color:tt, $($rest:tt)*) => {{
+        let c = $container.fill_color($crate::color_value!($color));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, fill_color: $color:tt) => {{
+        $container.fill_color($crate::color_value!($color))
+    }};
+
     // Border color (renamed from border for clarity)
     ($container:expr, border_color: $color:tt, $($rest:tt)*) => {{
         let c = $container.border_color($crate::color_value!($color));
@@ -1142,6 +1416,15 @@ macro_rules! tui_apply_props {
         $container.border_style_with_color($style, $color)
     }};
 
+    // Border characters, e.g. (BorderStyle::ASCII.chars())
+    ($container:expr, border_chars: ($chars:expr), $($rest:tt)*) => {{
+        let c = $container.border_chars($chars);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, border_chars: ($chars:expr)) => {{
+        $container.border_chars($chars)
+    }};
+
     // Border edges - simple syntax support
     ($container:expr, border_edges: top, $($rest:tt)*) => {{
         let c = $container.border_edges($crate::BorderEdges::TOP);
@@ -1268,6 +1551,60 @@ macro_rules! tui_apply_props {
         $container.focusable(true)
     }};
 
+    // Scroll capture with value
+    ($container:expr, scroll_capture: $val:expr, $($rest:tt)*) => {{
+        let c = $container.scroll_capture($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, scroll_capture: $val:expr) => {{
+        $container.scroll_capture($val)
+    }};
+
+    // Scroll capture shorthand
+    ($container:expr, scroll_capture, $($rest:tt)*) => {{
+        let c = $container.scroll_capture(true);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, scroll_capture) => {{
+        $container.scroll_capture(true)
+    }};
+
+    // Memo key
+    ($container:expr, memo: $key:expr, $($rest:tt)*) => {{
+        let c = $container.memo($key);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, memo: $key:expr) => {{
+        $container.memo($key)
+    }};
+
+    // Join borders
+    ($container:expr, join_borders: $val:expr, $($rest:tt)*) => {{
+        let c = $container.join_borders($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, join_borders: $val:expr) => {{
+        $container.join_borders($val)
+    }};
+
+    // Transparent rounded corners
+    ($container:expr, clip_corners: $val:expr, $($rest:tt)*) => {{
+        let c = $container.clip_corners($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, clip_corners: $val:expr) => {{
+        $container.clip_corners($val)
+    }};
+
+    // Background inheritance from the parent
+    ($container:expr, inherit_bg: $val:expr, $($rest:tt)*) => {{
+        let c = $container.inherit_bg($val);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, inherit_bg: $val:expr) => {{
+        $container.inherit_bg($val)
+    }};
+
     // Show scrollbar with value
     ($container:expr, show_scrollbar: $val:expr, $($rest:tt)*) => {{
         let c = $container.show_scrollbar($val);
@@ -1277,6 +1614,32 @@ macro_rules! tui_apply_props {
         $container.show_scrollbar($val)
     }};
 
+    // Prebuilt style, layered onto the props before it
+    ($container:expr, style: ($style:expr), $($rest:tt)*) => {{
+        let c = $container.apply_style($style);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, style: ($style:expr)) => {{
+        $container.apply_style($style)
+    }};
+
+    // Prebuilt style - optional with ! suffix on expression
+    ($container:expr, style: ($style:expr)!, $($rest:tt)*) => {{
+        let c = if let Some(style_val) = $style {
+            $container.apply_style(style_val)
+        } else {
+            $container
+        };
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, style: ($style:expr)!) => {{
+        if let Some(style_val) = $style {
+            $container.apply_style(style_val)
+        } else {
+            $container
+        }
+    }};
+
     // Focus style
     ($container:expr, focus_style: ($style:expr), $($rest:tt)*) => {{
         let c = $container.focus_style($style);
@@ -1376,6 +1739,48 @@ macro_rules! tui_apply_props {
         }
     }};
 
+    // Active style
+    ($container:expr, pressed_style: ($style:expr), $($rest:tt)*) => {{
+        let c = $container.pressed_style($style);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, pressed_style: ($style:expr)) => {{
+        $container.pressed_style($style)
+    }};
+
+    // Active style - optional with ! suffix on expression
+    ($container:expr, pressed_style: ($style:expr)!, $($rest:tt)*) => {{
+        let c = if let Some(style_val) = $style {
+            $container.pressed_style(style_val)
+        } else {
+            $container
+        };
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, pressed_style: ($style:expr)!) => {{
+        if let Some(style_val) = $style {
+            $container.pressed_style(style_val)
+        } else {
+            $container
+        }
+    }};
+
+    // Selected child and its highlight
+    ($container:expr, selected: $index:expr, $($rest:tt)*) => {{
+        let c = $container.selected($index);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, selected: $index:expr) => {{
+        $container.selected($index)
+    }};
+    ($container:expr, selected_style: ($style:expr), $($rest:tt)*) => {{
+        let c = $container.selected_style($style);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, selected_style: ($style:expr)) => {{
+        $container.selected_style($style)
+    }};
+
     // Z-index
     ($container:expr, z: $index:expr, $($rest:tt)*) => {{
         let c = $container.z_index($index);
@@ -1385,6 +1790,15 @@ macro_rules! tui_apply_props {
         $container.z_index($index)
     }};
 
+    // Portal layer
+    ($container:expr, portal: $layer:expr, $($rest:tt)*) => {{
+        let c = $container.portal($layer);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, portal: $layer:expr) => {{
+        $container.portal($layer)
+    }};
+
     // Position
     ($container:expr, pos: $pos:tt, $($rest:tt)*) => {{
         let c = $container.position($crate::position_value!($pos));
@@ -1426,6 +1840,22 @@ macro_rules! tui_apply_props {
         $container.align_items($crate::align_items_value!($align))
     }};
 
+    // Place Content
+    ($container:expr, place: $place:tt, $($rest:tt)*) => {{
+        let c = $container.place_content($crate::align_items_value!($place));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, place: $place:tt) => {{
+        $container.place_content($crate::align_items_value!($place))
+    }};
+    ($container:expr, place_content: $place:tt, $($rest:tt)*) => {{
+        let c = $container.place_content($crate::align_items_value!($place));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, place_content: $place:tt) => {{
+        $container.place_content($crate::align_items_value!($place))
+    }};
+
     // Align Self
     ($container:expr, align_self: $align:tt, $($rest:tt)*) => {{
         let c = $container.align_self($crate::align_self_value!($align));
@@ -1477,6 +1907,48 @@ macro_rules! tui_apply_props {
         $container.left($val)
     }};
 
+    // Positioning offsets as fractions of the container
+    ($container:expr, top_frac: $val:expr, $($rest:tt)*) => {{
+        let c = $container.top($crate::Offset::percent($val));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, top_frac: $val:expr) => {{
+        $container.top($crate::Offset::percent($val))
+    }};
+
+    ($container:expr, right_frac: $val:expr, $($rest:tt)*) => {{
+        let c = $container.right($crate::Offset::percent($val));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, right_frac: $val:expr) => {{
+        $container.right($crate::Offset::percent($val))
+    }};
+
+    ($container:expr, bottom_frac: $val:expr, $($rest:tt)*) => {{
+        let c = $container.bottom($crate::Offset::percent($val));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, bottom_frac: $val:expr) => {{
+        $container.bottom($crate::Offset::percent($val))
+    }};
+
+    ($container:expr, left_frac: $val:expr, $($rest:tt)*) => {{
+        let c = $container.left($crate::Offset::percent($val));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, left_frac: $val:expr) => {{
+        $container.left($crate::Offset::percent($val))
+    }};
+
+    // Shift by fractions of the element's own size or by cells
+    ($container:expr, translate: ($x:expr, $y:expr), $($rest:tt)*) => {{
+        let c = $container.translate($x, $y);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, translate: ($x:expr, $y:expr)) => {{
+        $container.translate($x, $y)
+    }};
+
     // Wrap mode
     ($container:expr, wrap: $mode:tt, $($rest:tt)*) => {{
         let c = $container.wrap($crate::wrap_value!($mode));
@@ -1495,6 +1967,15 @@ macro_rules! tui_apply_props {
         $container.overflow($crate::overflow_value!($mode))
     }};
 
+    // Accessibility role
+    ($container:expr, role: $role:tt, $($rest:tt)*) => {{
+        let c = $container.role($crate::role_value!($role));
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, role: $role:tt) => {{
+        $container.role($crate::role_value!($role))
+    }};
+
     // Event handlers
 
     // @click handler
@@ -1506,6 +1987,24 @@ macro_rules! tui_apply_props {
         $container.on_click($handler)
     }};
 
+    // @right_click handler (receives the position relative to the element)
+    ($container:expr, @right_click: $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_right_click($handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @right_click: $handler:expr) => {{
+        $container.on_right_click($handler)
+    }};
+
+    // @middle_click handler (receives the position relative to the element)
+    ($container:expr, @middle_click: $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_middle_click($handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @middle_click: $handler:expr) => {{
+        $container.on_middle_click($handler)
+    }};
+
     // @char handler
     ($container:expr, @char($ch:literal): $handler:expr, $($rest:tt)*) => {{
         let c = $container.on_char($ch, $handler);
@@ -1524,6 +2023,24 @@ macro_rules! tui_apply_props {
         $container.on_char_global($ch, $handler)
     }};
 
+    // @seq handler
+    ($container:expr, @seq($seq:literal): $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_char_sequence($seq, $handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @seq($seq:literal): $handler:expr) => {{
+        $container.on_char_sequence($seq, $handler)
+    }};
+
+    // @seq_global handler
+    ($container:expr, @seq_global($seq:literal): $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_char_sequence_global($seq, $handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @seq_global($seq:literal): $handler:expr) => {{
+        $container.on_char_sequence_global($seq, $handler)
+    }};
+
     // @key with Char(...) handler
     ($container:expr, @key(Char($ch:literal)): $handler:expr, $($rest:tt)*) => {{
         let c = $container.on_key($crate::Key::Char($ch), $handler);
@@ -1590,6 +2107,15 @@ macro_rules! tui_apply_props {
         $container.on_focus($handler)
     }};
 
+    // @focus_change handler (focus moved within the subtree)
+    ($container:expr, @focus_change: $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_focus_change($handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @focus_change: $handler:expr) => {{
+        $container.on_focus_change($handler)
+    }};
+
     // @blur handler
     ($container:expr, @blur: $handler:expr, $($rest:tt)*) => {{
         let c = $container.on_blur($handler);
@@ -1607,6 +2133,33 @@ macro_rules! tui_apply_props {
     ($container:expr, @any_char: $handler:expr) => {{
         $container.on_any_char($handler)
     }};
+
+    // @key_if handler
+    ($container:expr, @key_if($predicate:expr): $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_key_if($predicate, $handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @key_if($predicate:expr): $handler:expr) => {{
+        $container.on_key_if($predicate, $handler)
+    }};
+
+    // @key_if_global handler
+    ($container:expr, @key_if_global($predicate:expr): $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_key_if_global($predicate, $handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @key_if_global($predicate:expr): $handler:expr) => {{
+        $container.on_key_if_global($predicate, $handler)
+    }};
+
+    // @char_range handler
+    ($container:expr, @char_range($start:literal, $end:literal): $handler:expr, $($rest:tt)*) => {{
+        let c = $container.on_char_range($start, $end, $handler);
+        $crate::tui_apply_props!(c, $($rest)*)
+    }};
+    ($container:expr, @char_range($start:literal, $end:literal): $handler:expr) => {{
+        $container.on_char_range($start, $end, $handler)
+    }};
 }
 
 /// Build text with properties (internal)
@@ -1719,6 +2272,24 @@ macro_rules! tui_apply_text_props {
         $text.underline()
     }};
 
+    // Underline style (single, double, curly, dotted, dashed)
+    ($text:expr, underline_style: $style:tt, $($rest:tt)*) => {{
+        let t = $text.underline_style($crate::underline_style_value!($style));
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, underline_style: $style:tt) => {{
+        $text.underline_style($crate::underline_style_value!($style))
+    }};
+
+    // Underline color
+    ($text:expr, underline_color: $color:tt, $($rest:tt)*) => {{
+        let t = $text.underline_color($crate::color_value!($color));
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, underline_color: $color:tt) => {{
+        $text.underline_color($crate::color_value!($color))
+    }};
+
     // Strikethrough
     ($text:expr, strikethrough, $($rest:tt)*) => {{
         let t = $text.strikethrough();
@@ -1728,6 +2299,40 @@ macro_rules! tui_apply_text_props {
         $text.strikethrough()
     }};
 
+    // Blink
+    ($text:expr, blink, $($rest:tt)*) => {{
+        let t = $text.blink();
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, blink) => {{
+        $text.blink()
+    }};
+
+    // Reverse video
+    ($text:expr, reverse, $($rest:tt)*) => {{
+        let t = $text.reverse();
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, reverse) => {{
+        $text.reverse()
+    }};
+
+    // Conceal (alias: hidden)
+    ($text:expr, conceal, $($rest:tt)*) => {{
+        let t = $text.conceal();
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, conceal) => {{
+        $text.conceal()
+    }};
+    ($text:expr, hidden, $($rest:tt)*) => {{
+        let t = $text.conceal();
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, hidden) => {{
+        $text.conceal()
+    }};
+
     // Wrap mode
     ($text:expr, wrap: $mode:tt, $($rest:tt)*) => {{
         let t = $text.wrap($crate::text_wrap_value!($mode));
@@ -1745,6 +2350,74 @@ macro_rules! tui_apply_text_props {
     ($text:expr, align: $align:tt) => {{
         $text.align($crate::text_align_value!($align))
     }};
+
+    // Case transform
+    ($text:expr, transform: $transform:tt, $($rest:tt)*) => {{
+        let t = $text.text_transform($crate::text_transform_value!($transform));
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, transform: $transform:tt) => {{
+        $text.text_transform($crate::text_transform_value!($transform))
+    }};
+
+    // Word-wrap break opportunities besides whitespace
+    ($text:expr, line_break: $line_break:tt, $($rest:tt)*) => {{
+        let t = $text.line_break($crate::line_break_value!($line_break));
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, line_break: $line_break:tt) => {{
+        $text.line_break($crate::line_break_value!($line_break))
+    }};
+    // Prebuilt text style, layered onto the props before it
+    ($text:expr, style: ($style:expr), $($rest:tt)*) => {{
+        let t = $text.apply_style($style);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, style: ($style:expr)) => {{
+        $text.apply_style($style)
+    }};
+    ($text:expr, style: ($style:expr)!, $($rest:tt)*) => {{
+        let t = if let Some(style_val) = $style {
+            $text.apply_style(style_val)
+        } else {
+            $text
+        };
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, style: ($style:expr)!) => {{
+        if let Some(style_val) = $style {
+            $text.apply_style(style_val)
+        } else {
+            $text
+        }
+    }};
+
+    // Tab stops
+    ($text:expr, tab_width: $width:expr, $($rest:tt)*) => {{
+        let t = $text.tab_width($width);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, tab_width: $width:expr) => {{
+        $text.tab_width($width)
+    }};
+
+    // Background across the full width
+    ($text:expr, bg_fill: $fill:expr, $($rest:tt)*) => {{
+        let t = $text.bg_fill($fill);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, bg_fill: $fill:expr) => {{
+        $text.bg_fill($fill)
+    }};
+
+    // Ellipsis when cut short
+    ($text:expr, ellipsis: $ellipsis:expr, $($rest:tt)*) => {{
+        let t = $text.ellipsis($ellipsis);
+        $crate::tui_apply_text_props!(t, $($rest)*)
+    }};
+    ($text:expr, ellipsis: $ellipsis:expr) => {{
+        $text.ellipsis($ellipsis)
+    }};
 }
 
 /// Build RichText elements (internal)
@@ -1874,6 +2547,80 @@ macro_rules! tui_apply_span_style {
         s.underline = Some(true);
         s
     }};
+
+    // Underline style
+    ($style:expr, underline_style: $ustyle:tt, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.underline = Some(true);
+        s.underline_style = Some($crate::underline_style_value!($ustyle));
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, underline_style: $ustyle:tt) => {{
+        let mut s = $style;
+        s.underline = Some(true);
+        s.underline_style = Some($crate::underline_style_value!($ustyle));
+        s
+    }};
+
+    // Underline color
+    ($style:expr, underline_color: $color:tt, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.underline = Some(true);
+        s.underline_color = Some($crate::color_value!($color));
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, underline_color: $color:tt) => {{
+        let mut s = $style;
+        s.underline = Some(true);
+        s.underline_color = Some($crate::color_value!($color));
+        s
+    }};
+
+    // Blink
+    ($style:expr, blink, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.blink = Some(true);
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, blink) => {{
+        let mut s = $style;
+        s.blink = Some(true);
+        s
+    }};
+
+    // Reverse video
+    ($style:expr, reverse, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.reverse = Some(true);
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, reverse) => {{
+        let mut s = $style;
+        s.reverse = Some(true);
+        s
+    }};
+
+    // Conceal (alias: hidden)
+    ($style:expr, conceal, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.conceal = Some(true);
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, conceal) => {{
+        let mut s = $style;
+        s.conceal = Some(true);
+        s
+    }};
+    ($style:expr, hidden, $($rest:tt)*) => {{
+        let mut s = $style;
+        s.conceal = Some(true);
+        $crate::tui_apply_span_style!(s, $($rest)*)
+    }};
+    ($style:expr, hidden) => {{
+        let mut s = $style;
+        s.conceal = Some(true);
+        s
+    }};
 }
 
 /// Apply top-level properties to RichText (internal)
@@ -1920,11 +2667,41 @@ macro_rules! tui_apply_richtext_props {
         $crate::tui_apply_richtext_props!(rt, $($rest)*)
     }};
 
+    // Tab stops
+    ($rt:expr, tab_width: $width:expr, $($rest:tt)*) => {{
+        let rt = $rt.tab_width($width);
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
+    // Case transform
+    ($rt:expr, transform: $transform:tt, $($rest:tt)*) => {{
+        let rt = $rt.text_transform($crate::text_transform_value!($transform));
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
+    // Word-wrap break opportunities besides whitespace
+    ($rt:expr, line_break: $line_break:tt, $($rest:tt)*) => {{
+        let rt = $rt.line_break($crate::line_break_value!($line_break));
+        $crate::tui_apply_richtext_props!(rt, $($rest)*)
+    }};
+
     // Single property cases (no trailing comma)
     ($rt:expr, wrap: $wrap:tt) => {{
         $rt.wrap($crate::text_wrap_value!($wrap))
     }};
 
+    ($rt:expr, tab_width: $width:expr) => {{
+        $rt.tab_width($width)
+    }};
+
+    ($rt:expr, transform: $transform:tt) => {{
+        $rt.text_transform($crate::text_transform_value!($transform))
+    }};
+
+    ($rt:expr, line_break: $line_break:tt) => {{
+        $rt.line_break($crate::line_break_value!($line_break))
+    }};
+
     ($rt:expr, color: $color:tt) => {{
         $rt.color($crate::color_value!($color))
     }};
@@ -2820,6 +3597,69 @@ macro_rules! tui_apply_input_props {
         $input.password(true)
     }};
 
+    // Password mask glyph
+    ($input:expr, mask_char: $mask:expr, $($rest:tt)*) => {{
+        let i = $input.mask_char($mask);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, mask_char: $mask:expr) => {{
+        $input.mask_char($mask)
+    }};
+
+    // Maximum content length in characters
+    ($input:expr, max_length: $value:expr, $($rest:tt)*) => {{
+        let i = $input.max_length($value);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, max_length: $value:expr) => {{
+        $input.max_length($value)
+    }};
+
+    // Predicate typed characters must pass
+    ($input:expr, filter: $filter:expr, $($rest:tt)*) => {{
+        let i = $input.filter($filter);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, filter: $filter:expr) => {{
+        $input.filter($filter)
+    }};
+
+    // Validator that marks the content invalid
+    ($input:expr, validate: $validate:expr, $($rest:tt)*) => {{
+        let i = $input.validate($validate);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, validate: $validate:expr) => {{
+        $input.validate($validate)
+    }};
+
+    // Show password content as plaintext
+    ($input:expr, reveal: $value:expr, $($rest:tt)*) => {{
+        let i = $input.reveal($value);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, reveal: $value:expr) => {{
+        $input.reveal($value)
+    }};
+
+    // Reveal toggle key with modifiers, e.g. reveal_key: (ctrl + 'r')
+    ($input:expr, reveal_key: ($modifier:ident + $($mods:tt)+), $($rest:tt)*) => {{
+        let i = $input.reveal_key($crate::key_with_modifiers_value!($modifier + $($mods)+));
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, reveal_key: ($modifier:ident + $($mods:tt)+)) => {{
+        $input.reveal_key($crate::key_with_modifiers_value!($modifier + $($mods)+))
+    }};
+
+    // Reveal toggle key with explicit KeyWithModifiers value
+    ($input:expr, reveal_key: $value:expr, $($rest:tt)*) => {{
+        let i = $input.reveal_key($value);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, reveal_key: $value:expr) => {{
+        $input.reveal_key($value)
+    }};
+
     // Clear on submit with explicit value
     ($input:expr, clear_on_submit: $value:expr, $($rest:tt)*) => {{
         let i = $input.clear_on_submit($value);
@@ -2838,6 +3678,42 @@ macro_rules! tui_apply_input_props {
         $input.clear_on_submit(true)
     }};
 
+    // Enter inserts newline with explicit value
+    ($input:expr, enter_inserts_newline: $value:expr, $($rest:tt)*) => {{
+        let i = $input.enter_inserts_newline($value);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, enter_inserts_newline: $value:expr) => {{
+        $input.enter_inserts_newline($value)
+    }};
+
+    // Enter inserts newline shorthand (Enter no longer submits)
+    ($input:expr, enter_inserts_newline, $($rest:tt)*) => {{
+        let i = $input.enter_inserts_newline(true);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, enter_inserts_newline) => {{
+        $input.enter_inserts_newline(true)
+    }};
+
+    // Submit key with modifiers, e.g. submit_key: (alt + enter)
+    ($input:expr, submit_key: ($modifier:ident + $($mods:tt)+), $($rest:tt)*) => {{
+        let i = $input.submit_key($crate::key_with_modifiers_value!($modifier + $($mods)+));
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, submit_key: ($modifier:ident + $($mods:tt)+)) => {{
+        $input.submit_key($crate::key_with_modifiers_value!($modifier + $($mods)+))
+    }};
+
+    // Submit key with explicit KeyWithModifiers value
+    ($input:expr, submit_key: $value:expr, $($rest:tt)*) => {{
+        let i = $input.submit_key($value);
+        $crate::tui_apply_input_props!(i, $($rest)*)
+    }};
+    ($input:expr, submit_key: $value:expr) => {{
+        $input.submit_key($value)
+    }};
+
     // @change handler
     ($input:expr, @change: $handler:expr, $($rest:tt)*) => {{
         let i = $input.on_change($handler);
@@ -2913,3 +3789,220 @@ macro_rules! tui_apply_input_props {
         $input.on_blur($handler)
     }};
 }
+
+/// Build number input with properties (internal)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tui_build_number {
+    () => {{
+        $crate::Node::Component(std::sync::Arc::new($crate::NumberInput::new()))
+    }};
+
+    ($($props:tt)*) => {{
+        let __number = $crate::NumberInput::new();
+        // Always add trailing comma for consistent parsing
+        let __number = $crate::tui_apply_number_props!(__number, $($props)* ,);
+        $crate::Node::Component(std::sync::Arc::new(__number))
+    }};
+}
+
+/// Apply number input properties (internal)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tui_apply_number_props {
+    // Base case - return the input
+    ($number:expr,) => { $number };
+    ($number:expr) => { $number };
+
+    // Value and range
+    ($number:expr, value: $value:expr, $($rest:tt)*) => {{
+        let n = $number.value($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, min: $value:expr, $($rest:tt)*) => {{
+        let n = $number.min($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, max: $value:expr, $($rest:tt)*) => {{
+        let n = $number.max($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, step: $value:expr, $($rest:tt)*) => {{
+        let n = $number.step($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Integer mode
+    ($number:expr, integer: $value:expr, $($rest:tt)*) => {{
+        let n = $number.integer($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, integer, $($rest:tt)*) => {{
+        let n = $number.integer(true);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Mouse wheel stepping
+    ($number:expr, wheel: $value:expr, $($rest:tt)*) => {{
+        let n = $number.mouse_wheel($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, wheel, $($rest:tt)*) => {{
+        let n = $number.mouse_wheel(true);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Placeholder
+    ($number:expr, placeholder: $text:expr, $($rest:tt)*) => {{
+        let n = $number.placeholder($text);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Focusable
+    ($number:expr, focusable: $value:expr, $($rest:tt)*) => {{
+        let n = $number.focusable($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, focusable, $($rest:tt)*) => {{
+        let n = $number.focusable(true);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Width
+    ($number:expr, w: $value:expr, $($rest:tt)*) => {{
+        let n = $number.width($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, width: $value:expr, $($rest:tt)*) => {{
+        let n = $number.width($value);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // Colors
+    ($number:expr, border: ($color:expr), $($rest:tt)*) => {{
+        let n = $number.border($color);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, border: $color:tt, $($rest:tt)*) => {{
+        let n = $number.border($crate::color_value!($color));
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, focus_border: ($color:expr), $($rest:tt)*) => {{
+        let n = $number.focus_border($color);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, focus_border: $color:tt, $($rest:tt)*) => {{
+        let n = $number.focus_border($crate::color_value!($color));
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, bg: ($color:expr), $($rest:tt)*) => {{
+        let n = $number.background($color);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, bg: $color:tt, $($rest:tt)*) => {{
+        let n = $number.background($crate::color_value!($color));
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, background: ($color:expr), $($rest:tt)*) => {{
+        let n = $number.background($color);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, background: $color:tt, $($rest:tt)*) => {{
+        let n = $number.background($crate::color_value!($color));
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, content_color: ($color:expr), $($rest:tt)*) => {{
+        let n = $number.content_color($color);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+    ($number:expr, content_color: $color:tt, $($rest:tt)*) => {{
+        let n = $number.content_color($crate::color_value!($color));
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // @change handler (receives the value as f64)
+    ($number:expr, @change: $handler:expr, $($rest:tt)*) => {{
+        let n = $number.on_change($handler);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // @submit handler
+    ($number:expr, @submit: $handler:expr, $($rest:tt)*) => {{
+        let n = $number.on_submit($handler);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+
+    // @blur handler
+    ($number:expr, @blur: $handler:expr, $($rest:tt)*) => {{
+        let n = $number.on_blur($handler);
+        $crate::tui_apply_number_props!(n, $($rest)*)
+    }};
+}
+
+/// Build menu with properties (internal)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tui_build_menu {
+    ($($props:tt)*) => {{
+        let __menu = $crate::Menu::default();
+        // Always add trailing comma for consistent parsing
+        let __menu = $crate::tui_apply_menu_props!(__menu, $($props)* ,);
+        $crate::Node::Component(std::sync::Arc::new(__menu))
+    }};
+}
+
+/// Apply menu properties (internal)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tui_apply_menu_props {
+    // Base case - return the menu
+    ($menu:expr,) => { $menu };
+    ($menu:expr) => { $menu };
+
+    // Items and kind
+    ($menu:expr, items: $items:expr, $($rest:tt)*) => {{
+        let m = $menu.items($items);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, context, $($rest:tt)*) => {{
+        let m = $menu.kind($crate::MenuKind::Context);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, content: $content:expr, $($rest:tt)*) => {{
+        let content: $crate::Node = $content;
+        let m = $menu.content(content);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+
+    // Colors
+    ($menu:expr, bg: ($color:expr), $($rest:tt)*) => {{
+        let m = $menu.background($color);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, bg: $color:tt, $($rest:tt)*) => {{
+        let m = $menu.background($crate::color_value!($color));
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, color: ($color:expr), $($rest:tt)*) => {{
+        let m = $menu.color($color);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, color: $color:tt, $($rest:tt)*) => {{
+        let m = $menu.color($crate::color_value!($color));
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, highlight: ($color:expr), $($rest:tt)*) => {{
+        let m = $menu.highlight($color);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+    ($menu:expr, highlight: $color:tt, $($rest:tt)*) => {{
+        let m = $menu.highlight($crate::color_value!($color));
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+
+    // @select handler (receives the item id as a String)
+    ($menu:expr, @select: $handler:expr, $($rest:tt)*) => {{
+        let m = $menu.on_select($handler);
+        $crate::tui_apply_menu_props!(m, $($rest)*)
+    }};
+}
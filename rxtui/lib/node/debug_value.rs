@@ -0,0 +1,436 @@
+//! A declarative JSON form of a node tree, taken before layout.
+//!
+//! Every node becomes an object tagged with its `type` (`div`, `text`,
+//! `rich_text`, `fragment` or `component`). Styles are written in the format
+//! themes are loaded from, with unset fields left out, and object keys are
+//! sorted, so the output is stable enough to compare in tests.
+//!
+//! ```json
+//! {
+//!   "type": "div",
+//!   "style": { "direction": "horizontal", "padding": 1 },
+//!   "focusable": true,
+//!   "handlers": ["on_click", "on_key(Enter)"],
+//!   "children": [{ "type": "text", "content": "Save" }]
+//! }
+//! ```
+//!
+//! Handlers and components can't be represented, so they are only named.
+//! Only static trees, made of divs, text and fragments without handlers, can
+//! be read back with [`Node::from_debug_value`].
+
+use super::{Div, Node, RichText, Role, Text, TextSpan};
+use crate::style::{Style, TextStyle};
+use serde::Deserialize;
+use serde::de::Error as _;
+use serde_json::Value;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A node as it is written out, tagged with its type
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum NodeValue {
+    Div(Box<DivValue>),
+    Text(TextValue),
+    RichText(RichTextValue),
+    Fragment(FragmentValue),
+    Component(ComponentValue),
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DivValue {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    style: Option<Style>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    focus_style: Option<Style>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hover_style: Option<Style>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pressed_style: Option<Style>,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    focusable: bool,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    scroll_capture: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<Role>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    selected_style: Option<Style>,
+
+    /// Names of the registered handlers, which are written but never read back
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    handlers: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    children: Vec<NodeValue>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TextValue {
+    content: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RichTextValue {
+    spans: Vec<SpanValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpanValue {
+    content: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    style: Option<TextStyle>,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    cursor: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FragmentValue {
+    #[serde(default)]
+    children: Vec<NodeValue>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ComponentValue {
+    name: String,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Node {
+    /// Returns a JSON description of this tree before layout.
+    ///
+    /// Captures each node's type, styles and other props, and its children.
+    /// Handlers are listed by name, such as `on_click` or `on_key(Enter)`,
+    /// and components by their type name without being expanded.
+    pub fn to_debug_value(&self) -> Value {
+        let mut value =
+            serde_json::to_value(NodeValue::from(self)).expect("node values are valid JSON");
+        strip_nulls(&mut value);
+        value
+    }
+
+    /// Builds a static tree from a value written by [`to_debug_value`](Self::to_debug_value).
+    ///
+    /// Fails on components and handlers, which can't be rebuilt from their
+    /// names, as well as on unknown types or props.
+    pub fn from_debug_value(value: &Value) -> Result<Node, serde_json::Error> {
+        NodeValue::deserialize(value)?.into_node()
+    }
+}
+
+impl NodeValue {
+    /// Rebuilds the node, refusing anything that isn't static
+    fn into_node(self) -> Result<Node, serde_json::Error> {
+        let node = match self {
+            NodeValue::Div(div) => {
+                if let Some(handler) = div.handlers.first() {
+                    return Err(serde_json::Error::custom(format!(
+                        "handler `{handler}` can't be deserialized; only static trees round-trip"
+                    )));
+                }
+                let mut node = Div::new()
+                    .children(
+                        div.children
+                            .into_iter()
+                            .map(NodeValue::into_node)
+                            .collect::<Result<_, _>>()?,
+                    )
+                    .focusable(div.focusable)
+                    .scroll_capture(div.scroll_capture);
+                node.styles.base = div.style;
+                node.styles.focus = div.focus_style;
+                node.styles.hover = div.hover_style;
+                node.styles.pressed = div.pressed_style;
+                node.label = div.label;
+                node.role = div.role;
+                node.selected = div.selected;
+                node.selected_style = div.selected_style;
+                Node::Div(node)
+            }
+            NodeValue::Text(text) => Node::Text(Text {
+                content: text.content,
+                style: text.style,
+            }),
+            NodeValue::RichText(rich) => Node::RichText(RichText {
+                spans: rich
+                    .spans
+                    .into_iter()
+                    .map(|span| TextSpan {
+                        content: span.content,
+                        style: span.style,
+                        is_cursor: span.cursor,
+                    })
+                    .collect(),
+                style: rich.style,
+            }),
+            NodeValue::Fragment(fragment) => Node::Fragment(
+                fragment
+                    .children
+                    .into_iter()
+                    .map(NodeValue::into_node)
+                    .collect::<Result<_, _>>()?,
+            ),
+            NodeValue::Component(component) => {
+                return Err(serde_json::Error::custom(format!(
+                    "component `{}` can't be deserialized; only static trees round-trip",
+                    component.name
+                )));
+            }
+        };
+        Ok(node)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Names the handlers registered on a div, with the keys they listen for
+fn handler_names(div: &Div<Node>) -> Vec<String> {
+    let events = &div.events;
+    let scope = |global: bool| if global { ", global" } else { "" };
+    let mut names = Vec::new();
+
+    for (name, set) in [
+        ("on_click", events.on_click.is_some()),
+        ("on_right_click", events.on_right_click.is_some()),
+        ("on_middle_click", events.on_middle_click.is_some()),
+    ] {
+        if set {
+            names.push(name.to_string());
+        }
+    }
+    for (key, _, global) in &events.on_key {
+        names.push(format!("on_key({key}{})", scope(*global)));
+    }
+    for (key, _, global) in &events.on_key_with_modifiers {
+        names.push(format!("on_key_with_modifiers({key}{})", scope(*global)));
+    }
+    for (keys, _, global) in &events.on_key_sequence {
+        let keys = keys
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        names.push(format!("on_key_sequence({keys}{})", scope(*global)));
+    }
+    for (_, _, global) in &events.on_key_if {
+        names.push(
+            if *global {
+                "on_key_if(global)"
+            } else {
+                "on_key_if"
+            }
+            .to_string(),
+        );
+    }
+    for (name, set) in [
+        ("on_any_char", events.on_any_char.is_some()),
+        ("on_any_key", events.on_any_key.is_some()),
+        ("on_focus", events.on_focus.is_some()),
+        ("on_blur", events.on_blur.is_some()),
+        ("on_focus_change", events.on_focus_change.is_some()),
+        ("on_scroll", events.on_scroll.is_some()),
+    ] {
+        if set {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Drops unset fields, which the derived style serializers write as `null`
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, field| !field.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl From<&Node> for NodeValue {
+    fn from(node: &Node) -> Self {
+        match node {
+            Node::Div(div) => NodeValue::Div(Box::new(DivValue {
+                style: div.styles.base.clone(),
+                focus_style: div.styles.focus.clone(),
+                hover_style: div.styles.hover.clone(),
+                pressed_style: div.styles.pressed.clone(),
+                focusable: div.focusable,
+                scroll_capture: div.scroll_capture,
+                label: div.label.clone(),
+                role: div.role,
+                selected: div.selected,
+                selected_style: div.selected_style.clone(),
+                handlers: handler_names(div),
+                children: div.children.iter().map(NodeValue::from).collect(),
+            })),
+            Node::Text(text) => NodeValue::Text(TextValue {
+                content: text.content.clone(),
+                style: text.style.clone(),
+            }),
+            Node::RichText(rich) => NodeValue::RichText(RichTextValue {
+                spans: rich
+                    .spans
+                    .iter()
+                    .map(|span| SpanValue {
+                        content: span.content.clone(),
+                        style: span.style.clone(),
+                        cursor: span.is_cursor,
+                    })
+                    .collect(),
+                style: rich.style.clone(),
+            }),
+            Node::Fragment(children) => NodeValue::Fragment(FragmentValue {
+                children: children.iter().map(NodeValue::from).collect(),
+            }),
+            Node::Component(component) => NodeValue::Component(ComponentValue {
+                name: component.type_name().to_string(),
+            }),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::key::Key;
+    use crate::style::{Color, Dimension, Direction, LineBreak, Spacing};
+    use serde_json::json;
+
+    fn panel() -> Node {
+        Div::new()
+            .direction(Direction::Horizontal)
+            .padding(Spacing::horizontal(2))
+            .width_fraction(0.5)
+            .background(Color::Rgb(30, 30, 46))
+            .children(vec![
+                Node::Text(Text::new("Title").color(Color::BrightYellow).bold()),
+                Node::fragment([Node::text("a"), Node::text("b")]),
+            ])
+            .into()
+    }
+
+    #[test]
+    fn test_to_debug_value_writes_props_and_children() {
+        assert_eq!(
+            panel().to_debug_value(),
+            json!({
+                "type": "div",
+                "style": {
+                    "background": "#1e1e2e",
+                    "direction": "horizontal",
+                    "padding": { "top": 0, "right": 2, "bottom": 0, "left": 2 },
+                    "width": "50%",
+                },
+                "children": [
+                    {
+                        "type": "text",
+                        "content": "Title",
+                        "style": { "color": "bright_yellow", "bold": true },
+                    },
+                    {
+                        "type": "fragment",
+                        "children": [
+                            { "type": "text", "content": "a" },
+                            { "type": "text", "content": "b" },
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_static_tree_round_trips() {
+        let tree = panel();
+        let value = tree.to_debug_value();
+        let rebuilt = Node::from_debug_value(&value).unwrap();
+        assert_eq!(rebuilt.to_debug_value(), value);
+
+        let Node::Div(div) = rebuilt else {
+            panic!("expected a div");
+        };
+        assert_eq!(
+            div.styles.base.and_then(|style| style.width),
+            Some(Dimension::Percentage(0.5))
+        );
+    }
+
+    #[test]
+    fn test_handlers_are_named_and_refused_on_read() {
+        let node: Node = Div::new()
+            .focusable(true)
+            .on_click(|| {})
+            .on_key(Key::Enter, || {})
+            .on_key_global(Key::Esc, || {})
+            .into();
+        let value = node.to_debug_value();
+        assert_eq!(
+            value["handlers"],
+            json!(["on_click", "on_key(Enter)", "on_key(Esc, global)"])
+        );
+
+        let error = Node::from_debug_value(&value).unwrap_err();
+        assert!(error.to_string().contains("only static trees round-trip"));
+    }
+
+    #[test]
+    fn test_custom_line_break_is_named_and_refused_on_read() {
+        let node = Node::Text(Text::new("a").line_break(LineBreak::Custom(|_, _| true)));
+        let value = node.to_debug_value();
+        assert_eq!(value["style"]["line_break"], json!("custom"));
+        assert!(Node::from_debug_value(&value).is_err());
+
+        let node = Node::Text(Text::new("a").line_break(LineBreak::Whitespace));
+        let rebuilt = Node::from_debug_value(&node.to_debug_value()).unwrap();
+        assert_eq!(rebuilt.to_debug_value(), node.to_debug_value());
+    }
+}
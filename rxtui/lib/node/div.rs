@@ -1,10 +1,12 @@
 use crate::component::ComponentId;
 use crate::key::{Key, KeyWithModifiers};
+use crate::render_tree::ElementPath;
 use crate::style::{
     AlignItems, AlignSelf, Border, BorderEdges, BorderStyle, Color, Dimension, Direction,
-    JustifyContent, Overflow, Position, Spacing, Style, WrapMode,
+    JustifyContent, Offset, Overflow, Position, Spacing, Style, WrapMode, ZIndex,
 };
 use std::fmt::Debug;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::rc::Rc;
 
@@ -18,6 +20,18 @@ pub type KeyHandler = (Key, Rc<dyn Fn()>, bool);
 /// Type alias for keyboard event handler with modifiers: (key_with_modifiers, handler, is_global)
 pub type KeyWithModifiersHandler = (KeyWithModifiers, Rc<dyn Fn()>, bool);
 
+/// Type alias for multi-key sequence handler tuple: (keys, handler, is_global)
+pub type KeySequenceHandler = (Vec<Key>, Rc<dyn Fn()>, bool);
+
+/// Type alias for predicate key handler tuple: (predicate, handler, is_global)
+pub type KeyPredicateHandler = (Rc<dyn Fn(Key) -> bool>, Rc<dyn Fn(Key)>, bool);
+
+/// Type alias for right- and middle-click handlers receiving the (column, row) within the div
+pub type PointerHandler = Rc<dyn Fn((u16, u16))>;
+
+/// Type alias for focus-within handlers receiving the path of the newly focused element
+pub type FocusChangeHandler = Rc<dyn Fn(Option<ElementPath>)>;
+
 /// A container that can hold child elements
 #[derive(Clone)]
 pub struct Div<T> {
@@ -33,6 +47,9 @@ pub struct Div<T> {
     /// Whether this container can receive focus
     pub focusable: bool,
 
+    /// Whether scroll keys nobody else handles scroll this container
+    pub scroll_capture: bool,
+
     /// Whether this container is currently focused
     pub focused: bool,
 
@@ -41,6 +58,48 @@ pub struct Div<T> {
 
     /// Component path that owns this div (used for focus targeting)
     pub component_path: Option<ComponentId>,
+
+    /// Name of the component whose view produced this div (used for semantic output)
+    pub component_name: Option<&'static str>,
+
+    /// Accessible label describing this div (used for semantic output)
+    pub label: Option<String>,
+
+    /// What this div is to assistive technology (used for accessible text)
+    pub role: Option<Role>,
+
+    /// Hash of the dependency key that lets diffing skip this subtree while it is unchanged
+    pub memo_key: Option<u64>,
+
+    /// Index of the child highlighted as selected
+    pub selected: Option<usize>,
+
+    /// Style layered onto the selected child (the theme's selection colors when unset)
+    pub selected_style: Option<Style>,
+}
+
+/// The part a div plays in the UI, announced in accessible text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Role {
+    /// A title introducing the content after it
+    Heading,
+
+    /// Something that performs an action when activated
+    Button,
+
+    /// A group of list items
+    List,
+
+    /// An entry in a list
+    ListItem,
+
+    /// Decoration with nothing to announce; the div and its children are skipped
+    Presentation,
 }
 
 /// Style configuration for a div in different states.
@@ -54,6 +113,9 @@ pub struct DivStyles {
 
     /// Style to apply when div is hovered
     pub hover: Option<Style>,
+
+    /// Style to apply while the mouse button is held down on the div
+    pub pressed: Option<Style>,
 }
 
 /// Event callbacks for a div.
@@ -62,6 +124,12 @@ pub struct EventCallbacks {
     /// Click event handler
     pub on_click: Option<Rc<dyn Fn()>>,
 
+    /// Right-click handler (receives the column and row relative to the div)
+    pub on_right_click: Option<PointerHandler>,
+
+    /// Middle-click handler (receives the column and row relative to the div)
+    pub on_middle_click: Option<PointerHandler>,
+
     /// Keyboard event handlers: (key, handler, is_global)
     /// Global handlers work regardless of focus state
     pub on_key: Vec<KeyHandler>,
@@ -70,6 +138,14 @@ pub struct EventCallbacks {
     /// These are checked before simple on_key handlers
     pub on_key_with_modifiers: Vec<KeyWithModifiersHandler>,
 
+    /// Multi-key sequence handlers: (keys, handler, is_global)
+    /// Checked before every other key handler
+    pub on_key_sequence: Vec<KeySequenceHandler>,
+
+    /// Keyboard handlers for every key a predicate accepts: (predicate, handler, is_global)
+    /// These are checked after the on_key handlers, in registration order
+    pub on_key_if: Vec<KeyPredicateHandler>,
+
     /// Handler for any character input (receives the character)
     pub on_any_char: Option<Rc<dyn Fn(char)>>,
 
@@ -81,6 +157,12 @@ pub struct EventCallbacks {
 
     /// Called when div loses focus
     pub on_blur: Option<Rc<dyn Fn()>>,
+
+    /// Called when focus moves to, within, or out of this div's subtree
+    pub on_focus_change: Option<FocusChangeHandler>,
+
+    /// Mouse wheel handler (receives -1 for wheel up, 1 for wheel down)
+    pub on_scroll: Option<Rc<dyn Fn(i16)>>,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -95,9 +177,16 @@ impl<T> Div<T> {
             styles: DivStyles::default(),
             events: EventCallbacks::default(),
             focusable: false,
+            scroll_capture: false,
             focused: false,
             hovered: false,
             component_path: None,
+            component_name: None,
+            label: None,
+            role: None,
+            memo_key: None,
+            selected: None,
+            selected_style: None,
         }
     }
 
@@ -119,6 +208,66 @@ impl<T> Div<T> {
         self
     }
 
+    /// Lets this div scroll with the arrow, page, Home and End keys while
+    /// another element, or none, is focused.
+    ///
+    /// The keys go to the first scroll capturing div in the tree that can
+    /// scroll, and only when no focused element, global handler, or (with
+    /// nothing focused) other handler would act on them.
+    pub fn scroll_capture(mut self, capture: bool) -> Self {
+        self.scroll_capture = capture;
+        self
+    }
+
+    /// Sets an accessible label for this div, shown in semantic output
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the role announced for this div in accessible text
+    pub fn role(mut self, role: Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Skips diffing this div and its subtree while `key` stays the same
+    ///
+    /// Rendering compares the key's hash with the previous frame's and, when
+    /// it matches, keeps the subtree drawn last time without comparing any of
+    /// its nodes. Use it for large parts of the UI that rarely change, such as
+    /// a static header, with a key built from everything the subtree shows.
+    /// Anything left out of the key, including nested components' state and
+    /// new event handlers, doesn't reach the screen until the key changes or
+    /// [`Context::request_render`](crate::Context::request_render) is called.
+    pub fn memo(mut self, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        self.memo_key = Some(hasher.finish());
+        self
+    }
+
+    /// Highlights the child at `index` as the selected one, or none for `None`
+    ///
+    /// For lists where the container takes focus and tracks the current item
+    /// in its state, rather than each item being focusable. The index counts
+    /// this div's own children, and the highlight is the
+    /// [`selected_style`](Self::selected_style).
+    pub fn selected(mut self, index: impl Into<Option<usize>>) -> Self {
+        self.selected = index.into();
+        self
+    }
+
+    /// Sets the style layered onto the selected child
+    ///
+    /// A div child gets the style merged over its own; a text child takes only
+    /// its background. Without one, the selected child is drawn in the theme's
+    /// selection colors.
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = Some(style);
+        self
+    }
+
     /// Sets the display direction
     pub fn direction(mut self, direction: Direction) -> Self {
         self.styles.base.get_or_insert(Style::default()).direction = Some(direction);
@@ -139,7 +288,22 @@ impl<T> Div<T> {
 
     /// Sets the padding
     pub fn padding(mut self, padding: Spacing) -> Self {
-        self.styles.base.get_or_insert(Style::default()).padding = Some(padding);
+        let style = self.styles.base.get_or_insert(Style::default());
+        style.padding = Some(padding);
+        style.padding_fraction = None;
+        self
+    }
+
+    /// Sets the padding as a fraction of the parent's content size
+    ///
+    /// Left and right padding are a fraction of its width, top and bottom a
+    /// fraction of its height, each rounded to whole cells at layout time so
+    /// the padding scales with the terminal.
+    pub fn padding_fraction(mut self, fraction: f32) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .padding_fraction = Some(fraction);
         self
     }
 
@@ -151,7 +315,19 @@ impl<T> Div<T> {
 
     /// Sets the gap between children
     pub fn gap(mut self, gap: u16) -> Self {
-        self.styles.base.get_or_insert(Style::default()).gap = Some(gap);
+        let style = self.styles.base.get_or_insert(Style::default());
+        style.gap = Some(gap);
+        style.gap_fraction = None;
+        self
+    }
+
+    /// Sets the gap between children as a fraction of the parent's content size
+    /// along the main axis
+    pub fn gap_fraction(mut self, fraction: f32) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .gap_fraction = Some(fraction);
         self
     }
 
@@ -201,6 +377,22 @@ impl<T> Div<T> {
         self
     }
 
+    /// Sets the width as fraction of the space left by siblings (0.0 to 1.0)
+    pub fn width_remaining(mut self, fraction: f32) -> Self {
+        let normalized = fraction.clamp(0.0, 1.0);
+        self.styles.base.get_or_insert(Style::default()).width =
+            Some(Dimension::FractionRemaining(normalized));
+        self
+    }
+
+    /// Sets the height as fraction of the space left by siblings (0.0 to 1.0)
+    pub fn height_remaining(mut self, fraction: f32) -> Self {
+        let normalized = fraction.clamp(0.0, 1.0);
+        self.styles.base.get_or_insert(Style::default()).height =
+            Some(Dimension::FractionRemaining(normalized));
+        self
+    }
+
     /// Sets the width to auto
     pub fn width_auto(mut self) -> Self {
         self.styles.base.get_or_insert(Style::default()).width = Some(Dimension::Auto);
@@ -219,6 +411,27 @@ impl<T> Div<T> {
         self
     }
 
+    /// Keeps a visual width:height ratio, deriving the unset dimension
+    ///
+    /// The ratio is in terms of what you see, so `1.0` is a square: with a
+    /// width of 20 the height becomes 10, as cells are about twice as tall as
+    /// they are wide (see [`cell_aspect`](Self::cell_aspect)). With only a
+    /// height set, the width is derived instead. When both are set, they win
+    /// over the ratio.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets how many times taller than wide a cell is, for `aspect_ratio` (default 2.0)
+    pub fn cell_aspect(mut self, factor: f32) -> Self {
+        self.styles.base.get_or_insert(Style::default()).cell_aspect = Some(factor);
+        self
+    }
+
     /// Sets the width to content
     pub fn width_content(mut self) -> Self {
         self.styles.base.get_or_insert(Style::default()).width = Some(Dimension::Content);
@@ -255,6 +468,21 @@ impl<T> Div<T> {
         self
     }
 
+    /// Fills the content area with a repeated character, such as `'·'` or `'╱'`
+    ///
+    /// The character is drawn over the background inside the border and
+    /// padding, beneath the children. Use a single-width character.
+    pub fn fill_char(mut self, ch: char) -> Self {
+        self.styles.base.get_or_insert(Style::default()).fill_char = Some(ch);
+        self
+    }
+
+    /// Sets the color of the fill character
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.styles.base.get_or_insert(Style::default()).fill_color = Some(color);
+        self
+    }
+
     /// Sets the border style
     pub fn border(mut self, border: BorderStyle) -> Self {
         self.styles.base.get_or_insert(Style::default()).border = Some(Border {
@@ -315,6 +543,64 @@ impl<T> Div<T> {
         self
     }
 
+    /// Draws the border with custom characters, in the order top-left, top,
+    /// top-right, left, right, bottom-left, bottom, bottom-right
+    pub fn border_chars(mut self, chars: [char; 8]) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        match &mut style.border {
+            Some(border) => border.style = BorderStyle::Custom(chars),
+            None => {
+                style.border = Some(Border {
+                    enabled: true,
+                    style: BorderStyle::Custom(chars),
+                    color: Color::White,
+                    edges: BorderEdges::ALL,
+                })
+            }
+        }
+        self
+    }
+
+    /// Collapses children's borders onto this div's border and each other's
+    ///
+    /// In-flow children start on this div's border instead of inside it, and
+    /// each overlaps the previous one by a cell, so neighbouring borders share
+    /// a line. Where lines meet, the corners are replaced with junctions such
+    /// as `├ ┤ ┬ ┴ ┼`. Set it on a bordered table and on its rows to get a
+    /// grid of cells. Wrapped children are not collapsed.
+    pub fn join_borders(mut self, join: bool) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .join_borders = Some(join);
+        self
+    }
+
+    /// Leaves the corners of a rounded border transparent
+    ///
+    /// The four corner cells keep the background of whatever is drawn beneath
+    /// them instead of this div's, so the rounding reads cleanly where panels
+    /// overlap or sit on a different background. Only rounded borders are
+    /// affected.
+    pub fn clip_corners(mut self, clip: bool) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .clip_corners = Some(clip);
+        self
+    }
+
+    /// Sets whether this div inherits its parent's background (on by default)
+    ///
+    /// Turned off, border cells of this div and of descendants without a
+    /// background keep whatever is already drawn beneath them, such as a lower
+    /// z-index sibling, instead of taking the parent's color. A background set
+    /// on the div or a descendant still paints and is inherited below it.
+    pub fn inherit_bg(mut self, inherit: bool) -> Self {
+        self.styles.base.get_or_insert(Style::default()).inherit_bg = Some(inherit);
+        self
+    }
+
     /// Sets whether to show scrollbar for scrollable content
     pub fn show_scrollbar(mut self, show: bool) -> Self {
         self.styles
@@ -352,32 +638,68 @@ impl<T> Div<T> {
     }
 
     /// Sets the top position
-    pub fn top(mut self, top: i16) -> Self {
-        self.styles.base.get_or_insert(Style::default()).top = Some(top);
+    pub fn top(mut self, top: impl Into<Offset>) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        (style.top, style.top_fraction) = top.into().into_parts();
         self
     }
 
     /// Sets the right position
-    pub fn right(mut self, right: i16) -> Self {
-        self.styles.base.get_or_insert(Style::default()).right = Some(right);
+    pub fn right(mut self, right: impl Into<Offset>) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        (style.right, style.right_fraction) = right.into().into_parts();
         self
     }
 
     /// Sets the bottom position
-    pub fn bottom(mut self, bottom: i16) -> Self {
-        self.styles.base.get_or_insert(Style::default()).bottom = Some(bottom);
+    pub fn bottom(mut self, bottom: impl Into<Offset>) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        (style.bottom, style.bottom_fraction) = bottom.into().into_parts();
         self
     }
 
     /// Sets the left position
-    pub fn left(mut self, left: i16) -> Self {
-        self.styles.base.get_or_insert(Style::default()).left = Some(left);
+    pub fn left(mut self, left: impl Into<Offset>) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        (style.left, style.left_fraction) = left.into().into_parts();
         self
     }
 
-    /// Sets the z-index
-    pub fn z_index(mut self, z: i32) -> Self {
-        self.styles.base.get_or_insert(Style::default()).z_index = Some(z);
+    /// Shifts the positioned div by fractions of its own size or by cells.
+    ///
+    /// `left(Offset::percent(0.5))` with `translate(Offset::percent(-0.5), 0)`
+    /// centers the div horizontally in its container.
+    pub fn translate(mut self, x: impl Into<Offset>, y: impl Into<Offset>) -> Self {
+        self.styles.base.get_or_insert(Style::default()).translate = Some((x.into(), y.into()));
+        self
+    }
+
+    /// Sets the z-index, whole (`5`) or fractional (`1.5`), which may be negative
+    pub fn z_index(mut self, z: impl Into<ZIndex>) -> Self {
+        let style = self.styles.base.get_or_insert(Style::default());
+        (style.z_index, style.fractional_z_index) = z.into().into_parts();
+        self
+    }
+
+    /// Flips an absolutely positioned div to the other side of its parent
+    /// when it would extend past the viewport
+    ///
+    /// Useful for dropdowns and popups anchored near a screen edge. If the
+    /// flipped position doesn't fit either, the div is shifted back on screen.
+    pub fn flip_to_fit(mut self, flip: bool) -> Self {
+        self.styles.base.get_or_insert(Style::default()).flip_to_fit = Some(flip);
+        self
+    }
+
+    /// Paints the div in a top-level overlay layer, after the main tree
+    ///
+    /// Portaled content is laid out where it is declared but escapes its
+    /// ancestors' clipping and stacking contexts, so dropdowns and modals
+    /// inside `overflow: hidden` containers stay visible. Higher layers paint
+    /// on top; combine with absolute or fixed positioning to take the div out
+    /// of the flow.
+    pub fn portal(mut self, layer: i32) -> Self {
+        self.styles.base.get_or_insert(Style::default()).portal = Some(layer);
         self
     }
 
@@ -402,6 +724,15 @@ impl<T> Div<T> {
         self
     }
 
+    /// Places the block of children within the content box on both axes
+    pub fn place_content(mut self, place: AlignItems) -> Self {
+        self.styles
+            .base
+            .get_or_insert(Style::default())
+            .place_content = Some(place);
+        self
+    }
+
     /// Sets the focus style
     pub fn focus_style(mut self, style: Style) -> Self {
         self.styles.focus = Some(style);
@@ -432,12 +763,31 @@ impl<T> Div<T> {
         self
     }
 
+    /// Sets the style shown while the div is pressed
+    ///
+    /// Applies from the moment the mouse button goes down on the div until it
+    /// is released, wherever the pointer is then, on top of the focus and
+    /// hover styles.
+    pub fn pressed_style(mut self, style: Style) -> Self {
+        self.styles.pressed = Some(style);
+        self
+    }
+
     /// Sets the base style directly
     pub fn style(mut self, style: Style) -> Self {
         self.styles.base = Some(style);
         self
     }
 
+    /// Layers a prebuilt style onto the base style.
+    ///
+    /// Properties set in `style` replace ones set before, and unset ones keep
+    /// them, as with [`Style::merge`]. Properties set afterwards win over it.
+    pub fn apply_style(mut self, style: Style) -> Self {
+        self.styles.base = Style::merge(self.styles.base.take(), Some(style));
+        self
+    }
+
     /// Registers a key handler
     pub fn on_key(mut self, key: Key, handler: impl Fn() + 'static) -> Self {
         self.events.on_key.push((key, Rc::new(handler), false));
@@ -466,6 +816,86 @@ impl<T> Div<T> {
         self
     }
 
+    /// Registers a handler for keys pressed one after another, like vim's `g g`
+    pub fn on_key_sequence(
+        mut self,
+        keys: impl IntoIterator<Item = Key>,
+        handler: impl Fn() + 'static,
+    ) -> Self {
+        self.events
+            .on_key_sequence
+            .push((keys.into_iter().collect(), Rc::new(handler), false));
+        self
+    }
+
+    /// Registers a global key sequence handler (works even when not focused)
+    pub fn on_key_sequence_global(
+        mut self,
+        keys: impl IntoIterator<Item = Key>,
+        handler: impl Fn() + 'static,
+    ) -> Self {
+        self.events
+            .on_key_sequence
+            .push((keys.into_iter().collect(), Rc::new(handler), true));
+        self
+    }
+
+    /// Registers a handler for every key the predicate accepts
+    ///
+    /// The handler receives the key pressed. It only fires when no
+    /// [`on_key`](Self::on_key) handler of this div took the key, and only the
+    /// first matching predicate handler fires.
+    ///
+    /// ```ignore
+    /// div.on_key_if(|key| matches!(key, Key::Up | Key::Down), move |key| step(key))
+    /// ```
+    pub fn on_key_if(
+        mut self,
+        predicate: impl Fn(Key) -> bool + 'static,
+        handler: impl Fn(Key) + 'static,
+    ) -> Self {
+        self.events
+            .on_key_if
+            .push((Rc::new(predicate), Rc::new(handler), false));
+        self
+    }
+
+    /// Registers a global predicate key handler (works even when not focused)
+    pub fn on_key_if_global(
+        mut self,
+        predicate: impl Fn(Key) -> bool + 'static,
+        handler: impl Fn(Key) + 'static,
+    ) -> Self {
+        self.events
+            .on_key_if
+            .push((Rc::new(predicate), Rc::new(handler), true));
+        self
+    }
+
+    /// Registers a handler for characters from `start` to `end`, both included
+    ///
+    /// The handler receives the character typed, e.g. a digit for `'0'..='9'`.
+    pub fn on_char_range(self, start: char, end: char, handler: impl Fn(char) + 'static) -> Self {
+        self.on_key_if(
+            move |key| matches!(key, Key::Char(ch) if (start..=end).contains(&ch)),
+            move |key| {
+                if let Key::Char(ch) = key {
+                    handler(ch);
+                }
+            },
+        )
+    }
+
+    /// Registers a handler for a sequence of characters, such as `"gg"`
+    pub fn on_char_sequence(self, chars: &str, handler: impl Fn() + 'static) -> Self {
+        self.on_key_sequence(chars.chars().map(Key::Char), handler)
+    }
+
+    /// Registers a global character sequence handler (works even when not focused)
+    pub fn on_char_sequence_global(self, chars: &str, handler: impl Fn() + 'static) -> Self {
+        self.on_key_sequence_global(chars.chars().map(Key::Char), handler)
+    }
+
     /// Registers a key handler with modifiers
     pub fn on_key_with_modifiers(
         mut self,
@@ -508,6 +938,29 @@ impl<T> Div<T> {
         self
     }
 
+    /// Registers a right-click handler
+    ///
+    /// The handler receives the pointer's `(column, row)` relative to the div's
+    /// top-left corner, ready to use as `left`/`top` offsets of an absolutely
+    /// positioned child. Right clicks on descendants without their own handler
+    /// bubble up to the nearest div that has one.
+    ///
+    /// The click fires when the button is released without the pointer having
+    /// been dragged, so right-drags never trigger it.
+    pub fn on_right_click(mut self, handler: impl Fn((u16, u16)) + 'static) -> Self {
+        self.events.on_right_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a middle-click handler
+    ///
+    /// Receives the pointer position and bubbles like
+    /// [`on_right_click`](Self::on_right_click), and likewise ignores drags.
+    pub fn on_middle_click(mut self, handler: impl Fn((u16, u16)) + 'static) -> Self {
+        self.events.on_middle_click = Some(Rc::new(handler));
+        self
+    }
+
     /// Registers a focus handler
     pub fn on_focus(mut self, handler: impl Fn() + 'static) -> Self {
         self.events.on_focus = Some(Rc::new(handler));
@@ -520,6 +973,27 @@ impl<T> Div<T> {
         self
     }
 
+    /// Registers a handler for focus changes inside this div.
+    ///
+    /// Fires whenever the focused element changes and either the old or the
+    /// new one lies in this div's subtree, the div included. It receives the
+    /// path of the newly focused element, or `None` once focus has left the
+    /// subtree. Runs after the elements' own blur and focus handlers.
+    pub fn on_focus_change(mut self, handler: impl Fn(Option<ElementPath>) + 'static) -> Self {
+        self.events.on_focus_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Registers a mouse wheel handler
+    ///
+    /// The handler receives -1 for wheel up and 1 for wheel down. A div with a
+    /// wheel handler captures the wheel, so enclosing scrollable containers
+    /// don't scroll while the pointer is over it.
+    pub fn on_scroll(mut self, handler: impl Fn(i16) + 'static) -> Self {
+        self.events.on_scroll = Some(Rc::new(handler));
+        self
+    }
+
     /// Converts a Div to a new type using a mapping function
     pub fn map<U, F>(self, f: F) -> Div<U>
     where
@@ -530,9 +1004,16 @@ impl<T> Div<T> {
             styles: self.styles,
             events: self.events,
             focusable: self.focusable,
+            scroll_capture: self.scroll_capture,
             focused: self.focused,
             hovered: self.hovered,
             component_path: self.component_path,
+            component_name: self.component_name,
+            label: self.label,
+            role: self.role,
+            memo_key: self.memo_key,
+            selected: self.selected,
+            selected_style: self.selected_style,
         }
     }
 
@@ -563,15 +1044,22 @@ impl<T: PartialEq> PartialEq for Div<T> {
         self.children == other.children
             && self.styles == other.styles
             && self.focusable == other.focusable
+            && self.scroll_capture == other.scroll_capture
             && self.focused == other.focused
             && self.hovered == other.hovered
             && self.component_path == other.component_path
+            && self.component_name == other.component_name
+            && self.label == other.label
+            && self.role == other.role
     }
 }
 
 impl PartialEq for DivStyles {
     fn eq(&self, other: &Self) -> bool {
-        self.base == other.base && self.focus == other.focus && self.hover == other.hover
+        self.base == other.base
+            && self.focus == other.focus
+            && self.hover == other.hover
+            && self.pressed == other.pressed
     }
 }
 
@@ -589,15 +1077,21 @@ impl Debug for EventCallbacks {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EventCallbacks")
             .field("on_click", &self.on_click.is_some())
+            .field("on_right_click", &self.on_right_click.is_some())
+            .field("on_middle_click", &self.on_middle_click.is_some())
             .field("on_key_count", &self.on_key.len())
             .field(
                 "on_key_with_modifiers_count",
                 &self.on_key_with_modifiers.len(),
             )
+            .field("on_key_sequence_count", &self.on_key_sequence.len())
+            .field("on_key_if_count", &self.on_key_if.len())
             .field("on_any_char", &self.on_any_char.is_some())
             .field("on_any_key", &self.on_any_key.is_some())
             .field("on_focus", &self.on_focus.is_some())
             .field("on_blur", &self.on_blur.is_some())
+            .field("on_focus_change", &self.on_focus_change.is_some())
+            .field("on_scroll", &self.on_scroll.is_some())
             .finish()
     }
 }
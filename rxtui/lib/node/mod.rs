@@ -1,11 +1,16 @@
 use crate::component::Component;
 use std::sync::Arc;
 
+#[cfg(feature = "serde")]
+mod debug_value;
 pub mod div;
 pub mod rich_text;
 pub mod text;
 
-pub use div::{Div, DivStyles, EventCallbacks, KeyHandler, KeyWithModifiersHandler};
+pub use div::{
+    Div, DivStyles, EventCallbacks, KeyHandler, KeyPredicateHandler, KeySequenceHandler,
+    KeyWithModifiersHandler, PointerHandler, Role,
+};
 pub use rich_text::{RichText, TextSpan};
 pub use text::Text;
 
@@ -27,6 +32,13 @@ pub enum Node {
 
     /// Rich text with multiple styled segments
     RichText(RichText),
+
+    /// Sibling nodes spliced into the parent's children without a wrapper.
+    ///
+    /// The children take part in the parent's layout directly, so its gap and
+    /// alignment apply between them. A fragment used as the root is laid out
+    /// as a plain div.
+    Fragment(Vec<Node>),
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -51,6 +63,12 @@ impl Node {
     pub fn rich_text() -> Node {
         Node::RichText(RichText::new())
     }
+
+    /// Creates a fragment from the given sibling nodes.
+    #[inline]
+    pub fn fragment(children: impl IntoIterator<Item = impl Into<Node>>) -> Node {
+        Node::Fragment(children.into_iter().map(Into::into).collect())
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -58,11 +76,13 @@ impl Node {
 //--------------------------------------------------------------------------------------------------
 
 impl Node {
-    /// Adds a single child (only valid for Div variant).
+    /// Adds a single child (only valid for Div and Fragment variants).
     #[inline]
     pub fn child(mut self, child: impl Into<Node>) -> Self {
-        if let Node::Div(ref mut div) = self {
-            div.children.push(child.into());
+        match self {
+            Node::Div(ref mut div) => div.children.push(child.into()),
+            Node::Fragment(ref mut children) => children.push(child.into()),
+            _ => {}
         }
         self
     }
@@ -79,6 +99,7 @@ impl Clone for Node {
             Node::Div(div) => Node::Div(div.clone()),
             Node::Text(text) => Node::Text(text.clone()),
             Node::RichText(rich) => Node::RichText(rich.clone()),
+            Node::Fragment(children) => Node::Fragment(children.clone()),
         }
     }
 }
@@ -90,6 +111,7 @@ impl std::fmt::Debug for Node {
             Node::Div(div) => write!(f, "Node::Div({div:?})"),
             Node::Text(text) => write!(f, "Node::Text({text:?})"),
             Node::RichText(rich) => write!(f, "Node::RichText({rich:?})"),
+            Node::Fragment(children) => write!(f, "Node::Fragment({children:?})"),
         }
     }
 }
@@ -99,6 +121,7 @@ impl PartialEq for Node {
         match (self, other) {
             (Node::Text(a), Node::Text(b)) => a == b,
             (Node::RichText(a), Node::RichText(b)) => a == b,
+            (Node::Fragment(a), Node::Fragment(b)) => a == b,
             // Components and containers can't be easily compared due to trait objects
             _ => false,
         }
@@ -128,3 +151,16 @@ impl From<Div<Node>> for Node {
         Node::Div(div)
     }
 }
+
+impl From<Vec<Node>> for Node {
+    fn from(children: Vec<Node>) -> Self {
+        Node::Fragment(children)
+    }
+}
+
+impl FromIterator<Node> for Node {
+    /// Collects nodes into a fragment.
+    fn from_iter<I: IntoIterator<Item = Node>>(iter: I) -> Self {
+        Node::Fragment(iter.into_iter().collect())
+    }
+}
@@ -1,4 +1,5 @@
-use crate::style::{TextAlign, TextStyle};
+use crate::style::{LineBreak, TextAlign, TextStyle, TextTransform};
+use crate::utils::next_grapheme_boundary;
 use crate::{Color, TextWrap};
 
 //--------------------------------------------------------------------------------------------------
@@ -37,11 +38,13 @@ impl RichText {
 
     /// Creates RichText with a cursor at the specified position
     /// The cursor style will be preserved even after text wrapping
+    /// The cursor covers the whole grapheme cluster at `cursor_pos` (a char index)
     /// Used internally by TextInput component
     pub fn with_cursor(text: &str, cursor_pos: usize, cursor_style: TextStyle) -> Self {
         let mut spans = Vec::new();
         let chars: Vec<char> = text.chars().collect();
         let char_count = chars.len();
+        let cursor_end = next_grapheme_boundary(text, cursor_pos);
 
         // Add text before cursor
         if cursor_pos > 0 && cursor_pos <= char_count {
@@ -57,13 +60,13 @@ impl RichText {
         if cursor_pos < char_count {
             // Cursor on a character
             spans.push(TextSpan {
-                content: chars[cursor_pos].to_string(),
+                content: chars[cursor_pos..cursor_end].iter().collect(),
                 style: Some(cursor_style.clone()),
                 is_cursor: true, // Mark as cursor span
             });
             // Add text after cursor
-            if cursor_pos + 1 < char_count {
-                let after: String = chars[cursor_pos + 1..].iter().collect();
+            if cursor_end < char_count {
+                let after: String = chars[cursor_end..].iter().collect();
                 spans.push(TextSpan {
                     content: after,
                     style: None,
@@ -153,6 +156,26 @@ impl RichText {
         self
     }
 
+    /// Sets the columns between tab stops that tabs expand to
+    pub fn tab_width(mut self, width: u16) -> Self {
+        self.style.get_or_insert(TextStyle::default()).tab_width = Some(width);
+        self
+    }
+
+    /// Sets the case transform for spans without their own, leaving the content as written
+    pub fn text_transform(mut self, transform: TextTransform) -> Self {
+        self.style
+            .get_or_insert(TextStyle::default())
+            .text_transform = Some(transform);
+        self
+    }
+
+    /// Sets where word wrapping may break lines besides whitespace
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.style.get_or_insert(TextStyle::default()).line_break = Some(line_break);
+        self
+    }
+
     /// Sets the color for all spans that don't already have a color
     pub fn color(mut self, color: Color) -> Self {
         for span in &mut self.spans {
@@ -323,6 +346,11 @@ mod tests {
             },
         );
 
+        // Cursor on a cluster covers all of it
+        let rich_flag = RichText::with_cursor("a🇺🇸b", 1, TextStyle::default());
+        assert_eq!(rich_flag.spans[1].content, "🇺🇸");
+        assert_eq!(rich_flag.spans[2].content, "b");
+
         assert_eq!(rich_end.spans.len(), 2);
         assert_eq!(rich_end.spans[0].content, "Hi");
         assert_eq!(rich_end.spans[1].content, " ");
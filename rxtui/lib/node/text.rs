@@ -1,4 +1,4 @@
-use crate::style::{TextAlign, TextStyle};
+use crate::style::{LineBreak, TextAlign, TextStyle, TextTransform, UnderlineStyle};
 use crate::{Color, TextWrap};
 
 //--------------------------------------------------------------------------------------------------
@@ -55,12 +55,46 @@ impl Text {
         self
     }
 
+    /// Sets the underline style (double, curly, ...), enabling the underline
+    pub fn underline_style(mut self, style: UnderlineStyle) -> Self {
+        let text_style = self.style.get_or_insert(TextStyle::default());
+        text_style.underline = Some(true);
+        text_style.underline_style = Some(style);
+        self
+    }
+
+    /// Sets the underline color, enabling the underline
+    pub fn underline_color(mut self, color: Color) -> Self {
+        let text_style = self.style.get_or_insert(TextStyle::default());
+        text_style.underline = Some(true);
+        text_style.underline_color = Some(color);
+        self
+    }
+
     /// Makes the text strikethrough
     pub fn strikethrough(mut self) -> Self {
         self.style.get_or_insert(TextStyle::default()).strikethrough = Some(true);
         self
     }
 
+    /// Makes the text blink (rendered steadily where blink is unsupported)
+    pub fn blink(mut self) -> Self {
+        self.style.get_or_insert(TextStyle::default()).blink = Some(true);
+        self
+    }
+
+    /// Swaps the foreground and background colors, as for a selection or cursor
+    pub fn reverse(mut self) -> Self {
+        self.style.get_or_insert(TextStyle::default()).reverse = Some(true);
+        self
+    }
+
+    /// Hides the text while keeping its space
+    pub fn conceal(mut self) -> Self {
+        self.style.get_or_insert(TextStyle::default()).conceal = Some(true);
+        self
+    }
+
     /// Sets the text wrapping mode
     pub fn wrap(mut self, wrap: TextWrap) -> Self {
         self.style.get_or_insert(TextStyle::default()).wrap = Some(wrap);
@@ -72,6 +106,57 @@ impl Text {
         self.style.get_or_insert(TextStyle::default()).align = Some(align);
         self
     }
+
+    /// Sets the text style directly
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Layers a prebuilt text style onto the current one.
+    ///
+    /// Properties set in `style` replace ones set before, and unset ones keep
+    /// them, as with [`TextStyle::merge`]. Properties set afterwards win over it.
+    pub fn apply_style(mut self, style: TextStyle) -> Self {
+        self.style = TextStyle::merge(self.style.take(), Some(style));
+        self
+    }
+
+    /// Sets the columns between tab stops that tabs expand to
+    pub fn tab_width(mut self, width: u16) -> Self {
+        self.style.get_or_insert(TextStyle::default()).tab_width = Some(width);
+        self
+    }
+
+    /// Paints the background across the node's full width, not just the text.
+    ///
+    /// Aligned text takes its container's content width, so with `align`
+    /// set every line becomes a solid bar.
+    pub fn bg_fill(mut self, fill: bool) -> Self {
+        self.style.get_or_insert(TextStyle::default()).bg_fill = Some(fill);
+        self
+    }
+
+    /// Cuts the text down to the lines and columns its layout gives it,
+    /// ending the last line kept with an ellipsis.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.style.get_or_insert(TextStyle::default()).ellipsis = Some(ellipsis);
+        self
+    }
+
+    /// Sets the case transform, leaving the content as written
+    pub fn text_transform(mut self, transform: TextTransform) -> Self {
+        self.style
+            .get_or_insert(TextStyle::default())
+            .text_transform = Some(transform);
+        self
+    }
+
+    /// Sets where word wrapping may break lines besides whitespace
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.style.get_or_insert(TextStyle::default()).line_break = Some(line_break);
+        self
+    }
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -9,10 +9,12 @@
 //! ```
 
 // Core app types
-pub use crate::app::{App, Context};
+pub use crate::app::{App, Binding, BindingScope, Breakpoint, Context, FocusDirection, Local};
 
 // Component system
-pub use crate::component::{Action, Message, MessageExt, State};
+pub use crate::component::{
+    Action, ComponentId, Message, MessageExt, RenderError, State, props_differ,
+};
 
 // Effects system
 pub use crate::effect::Effect;
@@ -27,15 +29,23 @@ pub use crate::effect;
 pub use crate::{component, update, view};
 
 // UI elements
-pub use crate::node::{Div, Node, RichText, Text};
+pub use crate::node::{Div, Node, RichText, Role, Text};
+pub use crate::render_tree::ElementPath;
 
 // Components
 #[cfg(feature = "components")]
-pub use crate::components::{ShimmerSpeed, ShimmerText, TextInput};
+pub use crate::components::{
+    Accordion, AccordionMode, AnsiText, Button, ButtonVariant, FuzzyList, List, ListItem, LogView,
+    Menu, MenuItem, MenuKind, NumberInput, ShimmerSpeed, ShimmerText, Skeleton, Sparkline,
+    StatusBar, TextInput, Toast, ToastLevel, ToastPosition,
+};
 
 // Style types
 pub use crate::style::*;
 
+// Theming
+pub use crate::theme::{Palette, Theme};
+
 // Key handling
 pub use crate::key::{Key, KeyWithModifiers};
 
@@ -4,7 +4,7 @@
 //! allowing the macro system to provide default implementations that can be optionally overridden.
 
 use crate::effect::Effect;
-use crate::{Action, Context, Message, Node};
+use crate::{Action, Component, Context, Message, Node};
 
 //--------------------------------------------------------------------------------------------------
 // Traits
@@ -57,6 +57,58 @@ pub trait EffectsProvider {
     }
 }
 
+/// Internal trait for the Component macro system to handle optional identity keys.
+///
+/// DO NOT implement or use this trait directly - it's automatically handled by the macro system.
+/// This uses Rust's method resolution order where inherent methods shadow trait methods,
+/// allowing a `key` method in a #[component] impl to override the default.
+#[doc(hidden)]
+pub trait KeyProvider {
+    /// Internal method that returns no key by default.
+    /// This is shadowed by an inherent method when #[component] renames `key`.
+    fn __component_key_impl(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Internal trait for the Component macro system to handle optional props comparison.
+///
+/// DO NOT implement or use this trait directly - it's automatically handled by the macro system.
+/// This uses Rust's method resolution order where inherent methods shadow trait methods,
+/// allowing a `props_changed` method in a #[component] impl to override the default.
+#[doc(hidden)]
+pub trait PropsProvider {
+    /// Internal method that reports unchanged props by default.
+    /// This is shadowed by an inherent method when #[component] renames `props_changed`.
+    fn __component_props_changed_impl(&self, _previous: &dyn Component) -> bool {
+        false
+    }
+}
+
+/// Internal trait for the Component macro system to handle optional mount hooks.
+///
+/// DO NOT implement or use this trait directly - it's automatically handled by the macro system.
+/// This uses Rust's method resolution order where inherent methods shadow trait methods,
+/// allowing an `on_mount` method in a #[component] impl to override the default.
+#[doc(hidden)]
+pub trait MountProvider {
+    /// Internal method that does nothing by default.
+    /// This is shadowed by an inherent method when #[component] renames `on_mount`.
+    fn __component_on_mount_impl(&self, _ctx: &Context) {}
+}
+
+/// Internal trait for the Component macro system to handle optional unmount hooks.
+///
+/// DO NOT implement or use this trait directly - it's automatically handled by the macro system.
+/// This uses Rust's method resolution order where inherent methods shadow trait methods,
+/// allowing an `on_unmount` method in a #[component] impl to override the default.
+#[doc(hidden)]
+pub trait UnmountProvider {
+    /// Internal method that does nothing by default.
+    /// This is shadowed by an inherent method when #[component] renames `on_unmount`.
+    fn __component_on_unmount_impl(&self, _ctx: &Context) {}
+}
+
 //--------------------------------------------------------------------------------------------------
 // Blanket Implementations
 //--------------------------------------------------------------------------------------------------
@@ -65,3 +117,7 @@ pub trait EffectsProvider {
 impl<T> UpdateProvider for T {}
 impl<T> ViewProvider for T {}
 impl<T> EffectsProvider for T {}
+impl<T> KeyProvider for T {}
+impl<T> PropsProvider for T {}
+impl<T> MountProvider for T {}
+impl<T> UnmountProvider for T {}
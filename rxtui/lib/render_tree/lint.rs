@@ -0,0 +1,171 @@
+//! Warnings for layouts that are valid but rarely do what was meant.
+//!
+//! The checks run over a laid out render tree, so sizes are the resolved
+//! ones. Each warning names the component that built the node and where the
+//! node ended up on screen.
+
+use crate::render_tree::node::{RenderNode, RenderNodeType};
+use crate::style::{Dimension, Style};
+use std::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// A layout mistake and the node it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LayoutWarning {
+    /// Path of the component that built the node, or `<unknown>`
+    pub(crate) component: String,
+
+    /// What is wrong, without the node's position
+    pub(crate) message: String,
+
+    /// Where the node was laid out: x, y, width and height
+    bounds: (u16, u16, u16, u16),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns a warning for each layout mistake found under `root`
+pub(crate) fn layout_warnings(root: &RenderNode) -> Vec<LayoutWarning> {
+    let mut warnings = Vec::new();
+    lint_node(root, None, &mut warnings);
+    warnings
+}
+
+/// Checks a node against its parent, then its children
+fn lint_node(node: &RenderNode, parent: Option<&RenderNode>, warnings: &mut Vec<LayoutWarning>) {
+    if !matches!(node.node_type, RenderNodeType::Element) {
+        return;
+    }
+    let component = node
+        .component_path
+        .as_ref()
+        .map_or("<unknown>", |path| path.0.as_str());
+    let mut warn = |message: String| {
+        warnings.push(LayoutWarning {
+            component: component.to_string(),
+            message,
+            bounds: (node.x, node.y, node.width, node.height),
+        })
+    };
+    let style = node.style.as_ref();
+
+    if let Some(parent) = parent {
+        let parent_style = parent.style.as_ref();
+        for (axis, child, parent_dimension) in [
+            (
+                "width",
+                style.and_then(|s| s.width),
+                parent_style.and_then(|s| s.width),
+            ),
+            (
+                "height",
+                style.and_then(|s| s.height),
+                parent_style.and_then(|s| s.height),
+            ),
+        ] {
+            if matches!(child, Some(Dimension::Percentage(_))) && content_sized(parent_dimension) {
+                warn(format!(
+                    "percentage {axis} inside a parent sized by its content; \
+                     give the parent a fixed, percentage or auto {axis}"
+                ));
+            }
+        }
+    }
+
+    if !node.focusable && has_focused_key_handlers(node) {
+        warn(
+            "key handlers on an element that isn't focusable only run while nothing \
+             is focused; add `focusable: true` or make them global"
+                .to_string(),
+        );
+    }
+
+    // A content-sized element is empty because its children are, and those
+    // get their own warnings
+    let squeezed = |size: u16, dimension: Option<Dimension>| size == 0 && !content_sized(dimension);
+    if !node.children.is_empty()
+        && (squeezed(node.width, style.and_then(|s| s.width))
+            || squeezed(node.height, style.and_then(|s| s.height)))
+    {
+        warn(format!(
+            "laid out at {}x{} with {} children, which are not visible",
+            node.width,
+            node.height,
+            node.children.len()
+        ));
+    }
+
+    if let Some(style) = style {
+        for message in conflicting_bounds(style) {
+            warn(message);
+        }
+    }
+
+    for child in &node.children {
+        lint_node(&child.borrow(), Some(node), warnings);
+    }
+}
+
+/// Returns whether a dimension leaves the size to the content, as an unset one does
+fn content_sized(dimension: Option<Dimension>) -> bool {
+    matches!(dimension, None | Some(Dimension::Content))
+}
+
+/// Returns whether a node has key handlers that only run while it is focused
+fn has_focused_key_handlers(node: &RenderNode) -> bool {
+    let events = &node.events;
+    events.on_key.iter().any(|(_, _, global)| !global)
+        || events
+            .on_key_with_modifiers
+            .iter()
+            .any(|(_, _, global)| !global)
+        || events.on_key_sequence.iter().any(|(_, _, global)| !global)
+        || events.on_key_if.iter().any(|(_, _, global)| !global)
+        || events.on_any_char.is_some()
+        || events.on_any_key.is_some()
+}
+
+/// Returns a message for each size that contradicts its minimum or maximum
+fn conflicting_bounds(style: &Style) -> Vec<String> {
+    let mut messages = Vec::new();
+    for (axis, dimension, min, max) in [
+        ("width", style.width, style.min_width, style.max_width),
+        ("height", style.height, style.min_height, style.max_height),
+    ] {
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            messages.push(format!("min_{axis} {min} is larger than max_{axis} {max}"));
+        }
+        if let Some(Dimension::Fixed(size)) = dimension {
+            if let Some(min) = min.filter(|&min| size < min) {
+                messages.push(format!("{axis} {size} is below min_{axis} {min}"));
+            }
+            if let Some(max) = max.filter(|&max| size > max) {
+                messages.push(format!("{axis} {size} is above max_{axis} {max}"));
+            }
+        }
+    }
+    messages
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl fmt::Display for LayoutWarning {
+    /// Names the component and where its node is, then the mistake
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y, width, height) = self.bounds;
+        write!(
+            f,
+            "component {}, div at ({x}, {y}) [{width}x{height}]: {}",
+            self.component, self.message
+        )
+    }
+}
@@ -23,11 +23,12 @@
 //! - Fixed and flexible sizing
 //! - Hit testing for mouse events
 
+mod lint;
 mod node;
 mod tree;
 
 pub use node::{RenderNode, RenderNodeType};
-pub use tree::RenderTree;
+pub use tree::{ElementPath, Hit, RenderTree};
 
 #[cfg(test)]
 mod tests;
@@ -6,7 +6,7 @@ use crate::style::{
     AlignItems, AlignSelf, Color, Dimension, Direction, JustifyContent, Overflow, Position,
     Spacing, Style, TextStyle, TextWrap,
 };
-use crate::utils::{display_width, wrap_text};
+use crate::utils::{display_width, substring_by_columns, wrap_text_with};
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
@@ -72,17 +72,23 @@ pub struct RenderNode {
     /// Whether this element can receive focus
     pub focusable: bool,
 
+    /// Whether scroll keys nobody else handles scroll this element
+    pub scroll_capture: bool,
+
     /// Whether this element is currently focused
     pub focused: bool,
 
     /// Whether this element is currently hovered
     pub hovered: bool,
 
+    /// Whether the mouse button is held down on this element
+    pub active: bool,
+
     /// Whether this node needs to be redrawn
     pub dirty: bool,
 
     /// Z-index for layering (higher values render on top)
-    pub z_index: i32,
+    pub z_index: f32,
 
     /// Position type (relative, absolute, fixed)
     pub position_type: Position,
@@ -96,11 +102,17 @@ pub struct RenderNode {
     /// Actual content height (may exceed container height)
     pub content_height: u16,
 
+    /// Content size of the parent, which fractional padding and gaps resolve against
+    pub containing_size: (u16, u16),
+
     /// Whether this node is scrollable (has overflow:scroll or auto)
     pub scrollable: bool,
 
     /// Component path that produced this node (used for focus targeting)
     pub component_path: Option<ComponentId>,
+
+    /// Memo key of the div this node was built from, if any
+    pub memo_key: Option<u64>,
 }
 
 /// Types of nodes that can be rendered.
@@ -127,36 +139,41 @@ pub enum RenderNodeType {
 //--------------------------------------------------------------------------------------------------
 
 /// Calculate offset and item spacing based on JustifyContent mode
+///
+/// `free_space` is the main-axis space left after children and gaps. Distributing
+/// modes add their share of it on top of `gap`, so gaps are never lost. When the
+/// container has flexible children the caller passes zero, since those children
+/// have already absorbed all free space; every mode then packs at the start.
 fn calculate_justify_offsets(
     justify: JustifyContent,
-    available_space: u16,
+    free_space: u16,
     item_count: usize,
     gap: u16,
 ) -> (u16, u16) {
     match justify {
         JustifyContent::Start => (0, gap),
-        JustifyContent::End => (available_space, gap),
-        JustifyContent::Center => (available_space / 2, gap),
+        JustifyContent::End => (free_space, gap),
+        JustifyContent::Center => (free_space / 2, gap),
         JustifyContent::SpaceBetween => {
             if item_count > 1 {
-                let spacing = available_space / (item_count as u16 - 1);
-                (0, spacing)
+                let spacing = free_space / (item_count as u16 - 1);
+                (0, gap + spacing)
             } else {
                 (0, gap)
             }
         }
         JustifyContent::SpaceAround => {
             if item_count > 0 {
-                let spacing = available_space / item_count as u16;
-                (spacing / 2, spacing)
+                let spacing = free_space / item_count as u16;
+                (spacing / 2, gap + spacing)
             } else {
                 (0, gap)
             }
         }
         JustifyContent::SpaceEvenly => {
             if item_count > 0 {
-                let spacing = available_space / (item_count as u16 + 1);
-                (spacing, spacing)
+                let spacing = free_space / (item_count as u16 + 1);
+                (spacing, gap + spacing)
             } else {
                 (0, gap)
             }
@@ -164,6 +181,109 @@ fn calculate_justify_offsets(
     }
 }
 
+/// Main-axis position of a child relative to the start of its run.
+///
+/// `offset` is how far the child sits from the leading edge. Reversed
+/// directions lead from the far end of `extent`, so the child is mirrored
+/// there instead.
+fn main_axis_position(reversed: bool, offset: u16, size: u16, extent: u16) -> u16 {
+    if reversed {
+        extent.saturating_sub(offset.saturating_add(size))
+    } else {
+        offset
+    }
+}
+
+/// Main-axis size of a child whose `aspect_ratio` derives it from the cross axis.
+///
+/// Returns `None` unless the child leaves its main dimension unset (or
+/// content-sized) and sets its cross dimension to a fixed size or percentage
+/// of the parent's content box.
+fn aspect_main_size(
+    style: &Style,
+    direction: Direction,
+    content_width: u16,
+    content_height: u16,
+) -> Option<u16> {
+    style.aspect_ratio?;
+
+    let resolve = |dimension: Option<Dimension>, parent_size: u16| match dimension {
+        Some(Dimension::Fixed(size)) => Some(size),
+        Some(Dimension::Percentage(pct)) => Some((parent_size as f32 * pct) as u16),
+        _ => None,
+    };
+    if direction.is_horizontal() {
+        if !matches!(style.width, None | Some(Dimension::Content)) {
+            return None;
+        }
+        let height = resolve(style.height, content_height)?;
+        Some(style.aspect_size(0, height).0)
+    } else {
+        if !matches!(style.height, None | Some(Dimension::Content)) {
+            return None;
+        }
+        let width = resolve(style.width, content_width)?;
+        Some(style.aspect_size(width, 0).1)
+    }
+}
+
+/// Offset that aligns an item within `free_space` of leftover room.
+///
+/// Baselines line up against other items, so on their own they sit at the start.
+fn align_offset(align: AlignItems, free_space: u16) -> u16 {
+    match align {
+        AlignItems::Start | AlignItems::Baseline => 0,
+        AlignItems::Center => free_space / 2,
+        AlignItems::End => free_space,
+    }
+}
+
+/// Shift along one axis that keeps an overlay of `size` at `start` inside `limit`.
+///
+/// The overlay is first mirrored across its parent's span; if that still
+/// doesn't fit, it's pushed back from the far edge instead. Returns zero when
+/// the overlay already fits.
+fn flip_offset(parent_start: u16, parent_size: u16, start: u16, size: u16, limit: u16) -> i32 {
+    let end = start as i32 + size as i32;
+    if end <= limit as i32 {
+        return 0;
+    }
+
+    let mirrored = 2 * parent_start as i32 + parent_size as i32 - end;
+    if mirrored >= 0 {
+        mirrored - start as i32
+    } else {
+        (limit as i32 - size as i32).max(0) - start as i32
+    }
+}
+
+/// Cuts lines down to at most `height` lines of `width` columns.
+///
+/// When anything was cut, the last line kept ends with an ellipsis. Returns
+/// `None` when the lines already fit.
+fn fit_with_ellipsis(lines: &[String], width: u16, height: u16) -> Option<Vec<String>> {
+    let columns = usize::from(width);
+    let max_lines = usize::from(height.max(1));
+    if lines.len() <= max_lines && lines.iter().all(|line| display_width(line) <= columns) {
+        return None;
+    }
+
+    let mut fitted: Vec<String> = lines
+        .iter()
+        .take(max_lines)
+        .map(|line| {
+            substring_by_columns(line, 0, columns)
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+    if let Some(last) = fitted.last_mut() {
+        let kept = substring_by_columns(last, 0, columns.saturating_sub(1));
+        *last = format!("{}…", kept.trim_end());
+    }
+    Some(fitted)
+}
+
 //--------------------------------------------------------------------------------------------------
 // Methods
 //--------------------------------------------------------------------------------------------------
@@ -187,16 +307,20 @@ impl RenderNode {
             styles: DivStyles::default(),
             events: EventCallbacks::default(),
             focusable: false,
+            scroll_capture: false,
             focused: false,
             hovered: false,
+            active: false,
             dirty: true,
-            z_index: 0,
+            z_index: 0.0,
             position_type: Position::Relative,
             scroll_y: 0,
             content_width: 0,
             content_height: 0,
+            containing_size: (0, 0),
             scrollable: false,
             component_path: None,
+            memo_key: None,
         }
     }
 
@@ -256,12 +380,13 @@ impl RenderNode {
         self.dirty = false;
     }
 
-    /// Computes the effective style for the current focus/hover state.
+    /// Computes the effective style for the current focus/hover/active state.
     pub fn compose_state_style(
         styles: &DivStyles,
         focusable: bool,
         focused: bool,
         hovered: bool,
+        active: bool,
     ) -> Option<Style> {
         let base = styles.base.clone();
 
@@ -278,8 +403,11 @@ impl RenderNode {
 
         let hover_overlay = if hovered { styles.hover.clone() } else { None };
 
+        let active_overlay = if active { styles.pressed.clone() } else { None };
+
         let with_focus = Style::merge(base, focus_overlay);
-        Style::merge(with_focus, hover_overlay)
+        let with_hover = Style::merge(with_focus, hover_overlay);
+        Style::merge(with_hover, active_overlay)
     }
 
     /// Applies the provided style to this node, updating derived properties.
@@ -292,19 +420,24 @@ impl RenderNode {
                 self.height = height;
             }
             self.position_type = style.position.unwrap_or(Position::Relative);
-            self.z_index = style.z_index.unwrap_or(0);
+            self.z_index = style.resolved_z_index().unwrap_or(0.0);
         } else {
             self.position_type = Position::Relative;
-            self.z_index = 0;
+            self.z_index = 0.0;
         }
 
         self.style = style;
     }
 
-    /// Recomputes the node style based on focus/hover state and marks dirty if needed.
+    /// Recomputes the node style based on focus/hover/active state and marks dirty if needed.
     pub fn refresh_state_style(&mut self) {
-        let new_style =
-            Self::compose_state_style(&self.styles, self.focusable, self.focused, self.hovered);
+        let new_style = Self::compose_state_style(
+            &self.styles,
+            self.focusable,
+            self.focused,
+            self.hovered,
+            self.active,
+        );
         let needs_dirty = self.style != new_style;
         self.apply_computed_style(new_style);
         if needs_dirty {
@@ -318,6 +451,36 @@ impl RenderNode {
         matches!(self.position_type, Position::Absolute | Position::Fixed)
     }
 
+    /// Returns true if this node establishes a stacking context.
+    ///
+    /// Absolute and fixed elements with an explicit z-index form a stacking context:
+    /// they're layered against the nearest ancestor context rather than only their siblings,
+    /// and their own descendants are layered within them. So do `overflow: visible`
+    /// elements with a z-index, so their overflowing children paint above later siblings.
+    pub fn establishes_stacking_context(&self) -> bool {
+        self.style.as_ref().is_some_and(|s| {
+            s.resolved_z_index().is_some()
+                && (self.is_positioned() || s.overflow == Some(Overflow::Visible))
+        })
+    }
+
+    /// Returns the children in the order they are painted, lowest z-index first.
+    ///
+    /// Children with equal z-indices keep their tree order.
+    pub fn children_in_paint_order(&self) -> Vec<&Rc<RefCell<RenderNode>>> {
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by(|a, b| a.borrow().z_index.total_cmp(&b.borrow().z_index));
+        children
+    }
+
+    /// Returns the overlay layer this node is portaled into, if any.
+    ///
+    /// Portaled nodes paint after the whole main tree, in layer order, clipped
+    /// only by the viewport. They keep their place in the tree otherwise.
+    pub fn portal_layer(&self) -> Option<i32> {
+        self.style.as_ref().and_then(|s| s.portal)
+    }
+
     /// Updates the vertical scroll position by the given delta, clamping to valid range.
     ///
     /// Returns true if the scroll position changed.
@@ -359,22 +522,32 @@ impl RenderNode {
     /// Returns (width, height) based on the node's content.
     pub fn calculate_intrinsic_size(&self) -> (u16, u16) {
         // Use multi-pass calculation for complex scenarios
-        self.calculate_intrinsic_size_multipass(3, None)
+        self.calculate_intrinsic_size_multipass(3, None, self.containing_size)
+    }
+
+    /// Calculates the intrinsic size of this node within the given available space.
+    ///
+    /// Unlike [`calculate_intrinsic_size`](Self::calculate_intrinsic_size), content
+    /// that wraps within a parent-resolved width is measured at that width.
+    pub(crate) fn calculate_intrinsic_size_with_hint(&self, width: u16, height: u16) -> (u16, u16) {
+        self.calculate_intrinsic_size_multipass(3, Some((width, height)), self.containing_size)
     }
 
     /// Multi-pass intrinsic size calculation with convergence detection.
     /// Handles complex scenarios like percentage children in content-sized parents.
+    /// `containing` is the parent's content size, as set by layout.
     fn calculate_intrinsic_size_multipass(
         &self,
         max_passes: usize,
         hint: Option<(u16, u16)>,
+        containing: (u16, u16),
     ) -> (u16, u16) {
-        let mut size = self.calculate_intrinsic_size_single_pass(hint);
+        let mut size = self.calculate_intrinsic_size_single_pass(hint, containing);
         let mut prev_size = size;
 
         for _pass in 1..max_passes {
             // Use previous size as hint for next pass
-            size = self.calculate_intrinsic_size_single_pass(Some(prev_size));
+            size = self.calculate_intrinsic_size_single_pass(Some(prev_size), containing);
 
             // Check for convergence
             if size == prev_size {
@@ -388,7 +561,11 @@ impl RenderNode {
 
     /// Single pass of intrinsic size calculation.
     /// Uses hint for resolving percentages and simulating wrapping.
-    fn calculate_intrinsic_size_single_pass(&self, hint: Option<(u16, u16)>) -> (u16, u16) {
+    fn calculate_intrinsic_size_single_pass(
+        &self,
+        hint: Option<(u16, u16)>,
+        containing: (u16, u16),
+    ) -> (u16, u16) {
         match &self.node_type {
             RenderNodeType::Text(text) => {
                 // Check if this text node has wrapping enabled
@@ -413,7 +590,8 @@ impl RenderNode {
 
                     if let Some(width) = wrap_width {
                         // Apply wrapping at the determined width to get accurate height
-                        let wrapped_lines = wrap_text(text, width, wrap_mode);
+                        let line_break = text_style.line_break.unwrap_or_default();
+                        let wrapped_lines = wrap_text_with(text, width, wrap_mode, line_break);
                         let height = wrapped_lines.len() as u16;
                         let actual_width = wrapped_lines
                             .iter()
@@ -465,10 +643,15 @@ impl RenderNode {
                 let direction = style
                     .and_then(|s| s.direction)
                     .unwrap_or(Direction::Vertical);
-                let padding = style.and_then(|s| s.padding).unwrap_or(Spacing::all(0));
-                let border_size = if style
-                    .and_then(|s| s.border.as_ref())
-                    .is_some_and(|b| b.enabled)
+                // Fractional padding and gap resolve against the containing block
+                let padding = style
+                    .map(|s| s.resolved_padding(containing.0, containing.1))
+                    .unwrap_or(Spacing::all(0));
+                // Collapsed children sit on the border, so it takes no extra room
+                let border_size = if !self.collapses_borders()
+                    && style
+                        .and_then(|s| s.border.as_ref())
+                        .is_some_and(|b| b.enabled)
                 {
                     2 // 1 cell on each side
                 } else {
@@ -477,23 +660,47 @@ impl RenderNode {
 
                 // Check for wrapping mode and constraints
                 let wrap_mode = style.and_then(|s| s.wrap);
-                let gap = style.and_then(|s| s.gap).unwrap_or(0);
+                let gap = style
+                    .map(|s| {
+                        if direction.is_horizontal() {
+                            s.resolved_gap(containing.0)
+                        } else {
+                            s.resolved_gap(containing.1)
+                        }
+                    })
+                    .unwrap_or(0);
+
+                // Children resolve theirs against this node's content area, as
+                // far as its size is known yet
+                let size_or_hint = |dimension: Option<Dimension>, hinted: u16| match dimension {
+                    Some(Dimension::Fixed(size)) => size,
+                    _ => hinted,
+                };
+                let (hint_width, hint_height) = hint.unwrap_or((0, 0));
+                let child_containing = (
+                    size_or_hint(style.and_then(|s| s.width), hint_width)
+                        .saturating_sub(padding.left + padding.right + border_size),
+                    size_or_hint(style.and_then(|s| s.height), hint_height)
+                        .saturating_sub(padding.top + padding.bottom + border_size),
+                );
 
                 // Check if we should simulate wrapping
                 let should_wrap = if let Some(crate::style::WrapMode::Wrap) = wrap_mode {
-                    match direction {
-                        Direction::Horizontal => {
-                            // Wrap horizontally if we have a fixed width constraint
-                            style
-                                .and_then(|s| s.width)
-                                .is_some_and(|w| matches!(w, Dimension::Fixed(_)))
-                        }
-                        Direction::Vertical => {
-                            // Wrap vertically if we have a fixed height constraint
-                            style
-                                .and_then(|s| s.height)
-                                .is_some_and(|h| matches!(h, Dimension::Fixed(_)))
-                        }
+                    if direction.is_horizontal() {
+                        // Wrap horizontally if we have a fixed width constraint, or
+                        // a width resolved by the parent that the hint stands in for
+                        style.and_then(|s| s.width).is_some_and(|w| match w {
+                            Dimension::Fixed(_) => true,
+                            Dimension::Percentage(_)
+                            | Dimension::Auto
+                            | Dimension::FractionRemaining(_) => hint.is_some(),
+                            Dimension::Content => false,
+                        })
+                    } else {
+                        // Wrap vertically if we have a fixed height constraint
+                        style
+                            .and_then(|s| s.height)
+                            .is_some_and(|h| matches!(h, Dimension::Fixed(_)))
                     }
                 } else {
                     false
@@ -507,6 +714,7 @@ impl RenderNode {
                         border_size,
                         gap,
                         hint,
+                        child_containing,
                     )
                 } else {
                     // Standard layout calculation (no wrapping)
@@ -516,6 +724,7 @@ impl RenderNode {
                         border_size,
                         gap,
                         hint,
+                        child_containing,
                     )
                 }
             }
@@ -530,6 +739,7 @@ impl RenderNode {
         border_size: u16,
         gap: u16,
         hint: Option<(u16, u16)>,
+        child_containing: (u16, u16),
     ) -> (u16, u16) {
         let mut total_width = 0u16;
         let mut total_height = 0u16;
@@ -577,7 +787,8 @@ impl RenderNode {
 
             // Calculate child's size, considering hints for percentages
             let (child_width, child_height) = {
-                let intrinsic = child_ref.calculate_intrinsic_size_multipass(2, child_hint);
+                let intrinsic =
+                    child_ref.calculate_intrinsic_size_multipass(2, child_hint, child_containing);
                 let mut width = intrinsic.0;
                 let mut height = intrinsic.1;
 
@@ -600,6 +811,8 @@ impl RenderNode {
                             height = (hint_h as f32 * pct) as u16;
                         }
                     }
+
+                    (width, height) = style.aspect_size(width, height);
                 }
 
                 (width, height)
@@ -609,15 +822,12 @@ impl RenderNode {
                 relative_children = relative_children.saturating_add(1);
             }
 
-            match direction {
-                Direction::Horizontal => {
-                    total_width = total_width.saturating_add(child_width);
-                    max_height = max_height.max(child_height);
-                }
-                Direction::Vertical => {
-                    total_height = total_height.saturating_add(child_height);
-                    max_width = max_width.max(child_width);
-                }
+            if direction.is_horizontal() {
+                total_width = total_width.saturating_add(child_width);
+                max_height = max_height.max(child_height);
+            } else {
+                total_height = total_height.saturating_add(child_height);
+                max_width = max_width.max(child_width);
             }
         }
 
@@ -627,14 +837,27 @@ impl RenderNode {
             0
         };
 
-        let content_width = match direction {
-            Direction::Horizontal => total_width.saturating_add(gap_total),
-            Direction::Vertical => max_width,
+        // Collapsed siblings share a border line with their neighbours
+        let overlap = if self.collapses_borders() {
+            relative_children.saturating_sub(1)
+        } else {
+            0
+        };
+
+        let content_width = if direction.is_horizontal() {
+            total_width
+                .saturating_add(gap_total)
+                .saturating_sub(overlap)
+        } else {
+            max_width
         };
 
-        let content_height = match direction {
-            Direction::Horizontal => max_height,
-            Direction::Vertical => total_height.saturating_add(gap_total),
+        let content_height = if direction.is_horizontal() {
+            max_height
+        } else {
+            total_height
+                .saturating_add(gap_total)
+                .saturating_sub(overlap)
         };
 
         let final_width = content_width
@@ -656,39 +879,35 @@ impl RenderNode {
         border_size: u16,
         gap: u16,
         hint: Option<(u16, u16)>,
+        child_containing: (u16, u16),
     ) -> (u16, u16) {
         // Get the fixed constraint dimension
-        let constraint = match direction {
-            Direction::Horizontal => {
-                // For horizontal wrap, we need fixed width
-                if let Some(Dimension::Fixed(w)) = self.style.as_ref().and_then(|s| s.width) {
-                    w.saturating_sub(padding.left + padding.right + border_size)
-                } else {
-                    // Shouldn't happen due to should_wrap check, but fallback to hint or large value
-                    hint.map(|(w, _)| w).unwrap_or(u16::MAX)
-                }
+        let constraint = if direction.is_horizontal() {
+            // For horizontal wrap, we need fixed width
+            if let Some(Dimension::Fixed(w)) = self.style.as_ref().and_then(|s| s.width) {
+                w.saturating_sub(padding.left + padding.right + border_size)
+            } else {
+                // Parent-resolved widths wrap within the hint, or never without one
+                hint.map(|(w, _)| w.saturating_sub(padding.left + padding.right + border_size))
+                    .unwrap_or(u16::MAX)
             }
-            Direction::Vertical => {
-                // For vertical wrap, we need fixed height
-                if let Some(Dimension::Fixed(h)) = self.style.as_ref().and_then(|s| s.height) {
-                    h.saturating_sub(padding.top + padding.bottom + border_size)
-                } else {
-                    // Shouldn't happen due to should_wrap check, but fallback to hint or large value
-                    hint.map(|(_, h)| h).unwrap_or(u16::MAX)
-                }
+        } else {
+            // For vertical wrap, we need fixed height
+            if let Some(Dimension::Fixed(h)) = self.style.as_ref().and_then(|s| s.height) {
+                h.saturating_sub(padding.top + padding.bottom + border_size)
+            } else {
+                // Shouldn't happen due to should_wrap check, but fallback to hint or large value
+                hint.map(|(_, h)| h).unwrap_or(u16::MAX)
             }
         };
 
         // Calculate hint to pass to children based on constraint
-        let child_hint = match direction {
-            Direction::Horizontal => {
-                // Pass the constrained width as hint
-                Some((constraint, hint.map(|(_, h)| h).unwrap_or(0)))
-            }
-            Direction::Vertical => {
-                // Pass the constrained height as hint
-                Some((hint.map(|(w, _)| w).unwrap_or(0), constraint))
-            }
+        let child_hint = if direction.is_horizontal() {
+            // Pass the constrained width as hint
+            Some((constraint, hint.map(|(_, h)| h).unwrap_or(0)))
+        } else {
+            // Pass the constrained height as hint
+            Some((hint.map(|(w, _)| w).unwrap_or(0), constraint))
         };
 
         // Collect children sizes
@@ -696,7 +915,8 @@ impl RenderNode {
         for child in &self.children {
             let child_ref = child.borrow();
             let (child_width, child_height) = {
-                let intrinsic = child_ref.calculate_intrinsic_size_multipass(2, child_hint);
+                let intrinsic =
+                    child_ref.calculate_intrinsic_size_multipass(2, child_hint, child_containing);
                 let mut width = intrinsic.0;
                 let mut height = intrinsic.1;
 
@@ -725,93 +945,114 @@ impl RenderNode {
         }
 
         // Simulate wrapping layout
-        match direction {
-            Direction::Horizontal => {
-                // Horizontal wrap: children flow left to right, wrap to new rows
-                let mut rows = Vec::new();
-                let mut current_row = Vec::new();
-                let mut current_row_width = 0u16;
-
-                for (child_width, child_height) in child_sizes {
-                    if current_row_width > 0 && current_row_width + gap + child_width > constraint {
-                        // Start new row
-                        rows.push(current_row);
-                        current_row = vec![(child_width, child_height)];
-                        current_row_width = child_width;
-                    } else {
-                        if !current_row.is_empty() {
-                            current_row_width += gap;
-                        }
-                        current_row_width += child_width;
-                        current_row.push((child_width, child_height));
-                    }
-                }
-                if !current_row.is_empty() {
+        if direction.is_horizontal() {
+            // Horizontal wrap: children flow left to right, wrap to new rows
+            let mut rows = Vec::new();
+            let mut current_row = Vec::new();
+            let mut current_row_width = 0u16;
+
+            for (child_width, child_height) in child_sizes {
+                if current_row_width > 0 && current_row_width + gap + child_width > constraint {
+                    // Start new row
                     rows.push(current_row);
+                    current_row = vec![(child_width, child_height)];
+                    current_row_width = child_width;
+                } else {
+                    if !current_row.is_empty() {
+                        current_row_width += gap;
+                    }
+                    current_row_width += child_width;
+                    current_row.push((child_width, child_height));
                 }
+            }
+            if !current_row.is_empty() {
+                rows.push(current_row);
+            }
 
-                // Calculate total size from rows
-                let total_width = constraint; // Width is fixed
-                let total_height = rows
-                    .iter()
-                    .map(|row| row.iter().map(|(_, h)| *h).max().unwrap_or(0))
-                    .sum::<u16>()
-                    + (rows.len().saturating_sub(1) as u16 * gap);
-
-                let final_width = total_width
-                    .saturating_add(padding.left + padding.right)
-                    .saturating_add(border_size);
+            // Calculate total size from rows
+            let total_width = constraint; // Width is fixed
+            let total_height = rows
+                .iter()
+                .map(|row| row.iter().map(|(_, h)| *h).max().unwrap_or(0))
+                .sum::<u16>()
+                + (rows.len().saturating_sub(1) as u16 * gap);
 
-                let final_height = total_height
-                    .saturating_add(padding.top + padding.bottom)
-                    .saturating_add(border_size);
+            let final_width = total_width
+                .saturating_add(padding.left + padding.right)
+                .saturating_add(border_size);
 
-                (final_width, final_height)
-            }
-            Direction::Vertical => {
-                // Vertical wrap: children flow top to bottom, wrap to new columns
-                let mut columns = Vec::new();
-                let mut current_column = Vec::new();
-                let mut current_column_height = 0u16;
+            let final_height = total_height
+                .saturating_add(padding.top + padding.bottom)
+                .saturating_add(border_size);
 
-                for (child_width, child_height) in child_sizes {
-                    if current_column_height > 0
-                        && current_column_height + gap + child_height > constraint
-                    {
-                        // Start new column
-                        columns.push(current_column);
-                        current_column = vec![(child_width, child_height)];
-                        current_column_height = child_height;
-                    } else {
-                        if !current_column.is_empty() {
-                            current_column_height += gap;
-                        }
-                        current_column_height += child_height;
-                        current_column.push((child_width, child_height));
-                    }
-                }
-                if !current_column.is_empty() {
+            (final_width, final_height)
+        } else {
+            // Vertical wrap: children flow top to bottom, wrap to new columns
+            let mut columns = Vec::new();
+            let mut current_column = Vec::new();
+            let mut current_column_height = 0u16;
+
+            for (child_width, child_height) in child_sizes {
+                if current_column_height > 0
+                    && current_column_height + gap + child_height > constraint
+                {
+                    // Start new column
                     columns.push(current_column);
+                    current_column = vec![(child_width, child_height)];
+                    current_column_height = child_height;
+                } else {
+                    if !current_column.is_empty() {
+                        current_column_height += gap;
+                    }
+                    current_column_height += child_height;
+                    current_column.push((child_width, child_height));
                 }
+            }
+            if !current_column.is_empty() {
+                columns.push(current_column);
+            }
 
-                // Calculate total size from columns
-                let total_width = columns
-                    .iter()
-                    .map(|col| col.iter().map(|(w, _)| *w).max().unwrap_or(0))
-                    .sum::<u16>()
-                    + (columns.len().saturating_sub(1) as u16 * gap);
-                let total_height = constraint; // Height is fixed
+            // Calculate total size from columns
+            let total_width = columns
+                .iter()
+                .map(|col| col.iter().map(|(w, _)| *w).max().unwrap_or(0))
+                .sum::<u16>()
+                + (columns.len().saturating_sub(1) as u16 * gap);
+            let total_height = constraint; // Height is fixed
 
-                let final_width = total_width
-                    .saturating_add(padding.left + padding.right)
-                    .saturating_add(border_size);
+            let final_width = total_width
+                .saturating_add(padding.left + padding.right)
+                .saturating_add(border_size);
 
-                let final_height = total_height
-                    .saturating_add(padding.top + padding.bottom)
-                    .saturating_add(border_size);
+            let final_height = total_height
+                .saturating_add(padding.top + padding.bottom)
+                .saturating_add(border_size);
 
-                (final_width, final_height)
-            }
+            (final_width, final_height)
+        }
+    }
+
+    /// Cuts plain text that overflows `width` columns or `height` lines when
+    /// its style asks for an ellipsis.
+    fn apply_ellipsis(&mut self, width: u16, height: u16) {
+        let ellipsis = self.text_style.as_ref().and_then(|style| style.ellipsis);
+        if ellipsis != Some(true) {
+            return;
+        }
+
+        let fitted = match &self.node_type {
+            RenderNodeType::Text(text) => fit_with_ellipsis(std::slice::from_ref(text), width, 1),
+            RenderNodeType::TextWrapped(lines) => fit_with_ellipsis(lines, width, height),
+            _ => None,
+        };
+        if let Some(lines) = fitted {
+            self.width = lines.iter().map(|l| display_width(l)).max().unwrap_or(0) as u16;
+            self.height = lines.len() as u16;
+            // Unwrapped text was fitted as its single line
+            self.node_type = match self.node_type {
+                RenderNodeType::Text(_) => RenderNodeType::Text(lines.concat()),
+                _ => RenderNodeType::TextWrapped(lines),
+            };
         }
     }
 
@@ -827,7 +1068,9 @@ impl RenderNode {
                     && available_width > 0
                 {
                     // Apply wrapping
-                    let wrapped_lines = wrap_text(text, available_width, wrap_mode);
+                    let line_break = text_style.line_break.unwrap_or_default();
+                    let wrapped_lines =
+                        wrap_text_with(text, available_width, wrap_mode, line_break);
 
                     // Update node type and dimensions
                     self.node_type = RenderNodeType::TextWrapped(wrapped_lines.clone());
@@ -861,7 +1104,9 @@ impl RenderNode {
                         .collect();
 
                     // Apply wrapping to the full text
-                    let wrapped_lines = wrap_text(&full_text, available_width, wrap_mode);
+                    let line_break = text_style.line_break.unwrap_or_default();
+                    let wrapped_lines =
+                        wrap_text_with(&full_text, available_width, wrap_mode, line_break);
 
                     // Build wrapped lines with correct span information
                     let mut wrapped_styled_lines = Vec::new();
@@ -954,10 +1199,28 @@ impl RenderNode {
         }
     }
 
+    /// Sizes remaining-fraction dimensions as plain fractions of the parent.
+    ///
+    /// Used where there are no siblings to share space with, such as wrapped
+    /// and absolutely positioned children.
+    fn resolve_remaining_fractions(&mut self, parent_width: u16, parent_height: u16) {
+        let Some(style) = &self.style else {
+            return;
+        };
+        if let Some(Dimension::FractionRemaining(fraction)) = style.width {
+            self.width = (parent_width as f32 * fraction.clamp(0.0, 1.0)) as u16;
+        }
+        if let Some(Dimension::FractionRemaining(fraction)) = style.height {
+            self.height = (parent_height as f32 * fraction.clamp(0.0, 1.0)) as u16;
+        }
+    }
+
     /// Performs layout calculation with parent dimensions for percentage resolution.
     ///
     /// This method resolves percentage-based dimensions before laying out children.
     pub fn layout_with_parent(&mut self, parent_width: u16, parent_height: u16) {
+        self.containing_size = (parent_width, parent_height);
+
         // First, calculate intrinsic size if we need it
         // Resolve the hint based on own style (percentage widths need parent dimensions)
         let hint_width = if let Some(style) = &self.style {
@@ -978,8 +1241,11 @@ impl RenderNode {
         } else {
             parent_height
         };
-        let (intrinsic_width, intrinsic_height) =
-            self.calculate_intrinsic_size_multipass(3, Some((hint_width, hint_height)));
+        let (intrinsic_width, intrinsic_height) = self.calculate_intrinsic_size_multipass(
+            3,
+            Some((hint_width, hint_height)),
+            self.containing_size,
+        );
 
         // Resolve percentage and fixed dimensions first (auto handled in layout_children_with_parent)
         if let Some(style) = &self.style {
@@ -998,8 +1264,8 @@ impl RenderNode {
                     // Use intrinsic width, but cap at parent width
                     self.width = intrinsic_width.min(parent_width);
                 }
-                Some(Dimension::Auto) => {
-                    // Auto should have been resolved by parent's layout
+                Some(Dimension::Auto | Dimension::FractionRemaining(_)) => {
+                    // Auto and remaining fractions are resolved by the parent's layout
                     // Don't override if already set
                 }
                 None => {
@@ -1030,8 +1296,8 @@ impl RenderNode {
                     // Use intrinsic height, but cap at parent height
                     self.height = intrinsic_height.min(parent_height);
                 }
-                Some(Dimension::Auto) => {
-                    // Auto should have been resolved by parent's layout
+                Some(Dimension::Auto | Dimension::FractionRemaining(_)) => {
+                    // Auto and remaining fractions are resolved by the parent's layout
                     // Don't override if already set
                 }
                 None => {
@@ -1052,6 +1318,13 @@ impl RenderNode {
             self.height = intrinsic_height.min(parent_height);
         }
 
+        // Derive the unset dimension from the resolved one to keep the aspect ratio
+        if let Some(style) = &self.style
+            && style.aspect_ratio.is_some()
+        {
+            (self.width, self.height) = style.aspect_size(self.width, self.height);
+        }
+
         // Apply text wrapping if this is a text node with wrapping enabled
         // Use the node's own width (which may have been set to Fixed) as the constraint
         // Note: Skip if already wrapped (TextWrapped or RichTextWrapped)
@@ -1071,6 +1344,9 @@ impl RenderNode {
             self.apply_text_wrapping(wrap_width);
         }
 
+        // Cut text that overflows the space it was given, when asked to
+        self.apply_ellipsis(self.width.min(parent_width), parent_height);
+
         // Now layout children with resolved dimensions
         let direction = self
             .style
@@ -1102,11 +1378,7 @@ impl RenderNode {
     ///   x=0    x=6    x=12
     /// ```
     fn layout_children(&mut self, direction: Direction) {
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
+        let padding = self.padding();
 
         // Check if border is enabled and adjust content area accordingly
         let border_offset = if self
@@ -1120,26 +1392,33 @@ impl RenderNode {
             0
         };
 
+        let reversed = direction.is_reversed();
+        let content_width = self
+            .width
+            .saturating_sub(padding.left + padding.right + (border_offset * 2));
+        let content_height = self
+            .height
+            .saturating_sub(padding.top + padding.bottom + (border_offset * 2));
+
         let mut offset = 0u16;
 
         for child in &self.children {
             let mut child_ref = child.borrow_mut();
 
-            match direction {
-                Direction::Vertical => {
-                    child_ref.set_position(
-                        self.x + padding.left + border_offset,
-                        self.y + padding.top + border_offset + offset,
-                    );
-                    offset += child_ref.height;
-                }
-                Direction::Horizontal => {
-                    child_ref.set_position(
-                        self.x + padding.left + border_offset + offset,
-                        self.y + padding.top + border_offset,
-                    );
-                    offset += child_ref.width;
-                }
+            if direction.is_horizontal() {
+                let x = main_axis_position(reversed, offset, child_ref.width, content_width);
+                child_ref.set_position(
+                    self.x + padding.left + border_offset + x,
+                    self.y + padding.top + border_offset,
+                );
+                offset += child_ref.width;
+            } else {
+                let y = main_axis_position(reversed, offset, child_ref.height, content_height);
+                child_ref.set_position(
+                    self.x + padding.left + border_offset,
+                    self.y + padding.top + border_offset + y,
+                );
+                offset += child_ref.height;
             }
 
             child_ref.layout();
@@ -1159,6 +1438,10 @@ impl RenderNode {
         let start_x = self.x + padding.left + border_offset;
         let start_y = self.y + padding.top + border_offset;
 
+        // Reversed directions fill each run from its far end; runs still
+        // stack in the usual order
+        let reversed = direction.is_reversed();
+
         // Get alignment settings
         let justify_content = self
             .style
@@ -1172,326 +1455,329 @@ impl RenderNode {
             .and_then(|s| s.align_items)
             .unwrap_or(AlignItems::Start);
 
-        match direction {
-            Direction::Horizontal => {
-                // Horizontal wrapping: items flow left to right, wrap to next row
-
-                // First pass: Calculate dimensions and group into rows
-                struct RowInfo {
-                    start_index: usize,
-                    end_index: usize,
-                    width: u16, // Total width of items WITHOUT gaps
-                    height: u16,
-                }
-
-                let mut rows = Vec::new();
-                let mut current_row_width = 0u16; // Width without gaps
-                let mut current_row_width_with_gaps = 0u16; // Width including gaps for fitting check
-                let mut current_row_height = 0u16;
-                let mut row_start_index = 0;
+        if direction.is_horizontal() {
+            // Horizontal wrapping: items flow left to right, wrap to next row
 
-                // Resolve all child dimensions first
-                for child in &self.children {
-                    let mut child_ref = child.borrow_mut();
-                    child_ref.layout_with_parent(content_width, content_height);
-                }
+            // First pass: Calculate dimensions and group into rows
+            struct RowInfo {
+                start_index: usize,
+                end_index: usize,
+                width: u16, // Total width of items WITHOUT gaps
+                height: u16,
+            }
 
-                // Group children into rows
-                for (i, child) in self.children.iter().enumerate() {
-                    let child_ref = child.borrow();
-                    let child_width = child_ref.width;
-                    let child_height = child_ref.height;
+            let mut rows = Vec::new();
+            let mut current_row_width = 0u16; // Width without gaps
+            let mut current_row_width_with_gaps = 0u16; // Width including gaps for fitting check
+            let mut current_row_height = 0u16;
+            let mut row_start_index = 0;
+
+            // Resolve all child dimensions first
+            for child in &self.children {
+                let mut child_ref = child.borrow_mut();
+                child_ref.resolve_remaining_fractions(content_width, content_height);
+                child_ref.layout_with_parent(content_width, content_height);
+            }
 
-                    // Check if child fits in current row (considering gaps)
-                    let width_if_added = if current_row_width > 0 {
-                        current_row_width_with_gaps + gap + child_width
-                    } else {
-                        child_width
-                    };
+            // Group children into rows
+            for (i, child) in self.children.iter().enumerate() {
+                let child_ref = child.borrow();
+                let child_width = child_ref.width;
+                let child_height = child_ref.height;
 
-                    if current_row_width > 0 && width_if_added > content_width {
-                        // Save current row and start new one
-                        rows.push(RowInfo {
-                            start_index: row_start_index,
-                            end_index: i,
-                            width: current_row_width, // Store width WITHOUT gaps
-                            height: current_row_height,
-                        });
-
-                        row_start_index = i;
-                        current_row_width = child_width;
-                        current_row_width_with_gaps = child_width;
-                        current_row_height = child_height;
-                    } else {
-                        // Add to current row
-                        current_row_width += child_width;
-                        current_row_width_with_gaps = width_if_added;
-                        current_row_height = current_row_height.max(child_height);
-                    }
-                }
+                // Check if child fits in current row (considering gaps)
+                let width_if_added = if current_row_width > 0 {
+                    current_row_width_with_gaps + gap + child_width
+                } else {
+                    child_width
+                };
 
-                // Don't forget the last row
-                if row_start_index < self.children.len() {
+                if current_row_width > 0 && width_if_added > content_width {
+                    // Save current row and start new one
                     rows.push(RowInfo {
                         start_index: row_start_index,
-                        end_index: self.children.len(),
+                        end_index: i,
                         width: current_row_width, // Store width WITHOUT gaps
                         height: current_row_height,
                     });
+
+                    row_start_index = i;
+                    current_row_width = child_width;
+                    current_row_width_with_gaps = child_width;
+                    current_row_height = child_height;
+                } else {
+                    // Add to current row
+                    current_row_width += child_width;
+                    current_row_width_with_gaps = width_if_added;
+                    current_row_height = current_row_height.max(child_height);
                 }
+            }
 
-                // Second pass: Position children with alignment
-                let mut current_y = start_y;
+            // Don't forget the last row
+            if row_start_index < self.children.len() {
+                rows.push(RowInfo {
+                    start_index: row_start_index,
+                    end_index: self.children.len(),
+                    width: current_row_width, // Store width WITHOUT gaps
+                    height: current_row_height,
+                });
+            }
 
-                for row in &rows {
-                    // Calculate horizontal positioning for this row based on justify_content
-                    let row_item_count = row.end_index - row.start_index;
-                    // Calculate total width including gaps
-                    let total_gaps_width = if row_item_count > 1 {
-                        gap * (row_item_count as u16 - 1)
-                    } else {
-                        0
-                    };
-                    let row_width_with_gaps = row.width + total_gaps_width;
-                    let available_width = content_width.saturating_sub(row_width_with_gaps);
-
-                    // Calculate starting X and spacing for this row
-                    let (row_start_x, item_spacing) = match justify_content {
-                        JustifyContent::Start => (start_x, gap),
-                        JustifyContent::End => (start_x + available_width, gap),
-                        JustifyContent::Center => (start_x + available_width / 2, gap),
-                        JustifyContent::SpaceBetween => {
-                            if row_item_count > 1 {
-                                let total_gaps = row_item_count - 1;
-                                let spacing =
-                                    (available_width + gap * total_gaps as u16) / total_gaps as u16;
-                                (start_x, spacing)
-                            } else {
-                                (start_x, gap)
-                            }
-                        }
-                        JustifyContent::SpaceAround => {
-                            if row_item_count > 0 {
-                                let spacing = available_width / row_item_count as u16;
-                                (start_x + spacing / 2, gap + spacing)
-                            } else {
-                                (start_x, gap)
-                            }
+            // Second pass: Position children with alignment
+            let mut current_y = start_y;
+
+            for row in &rows {
+                // Calculate horizontal positioning for this row based on justify_content
+                let row_item_count = row.end_index - row.start_index;
+                // Calculate total width including gaps
+                let total_gaps_width = if row_item_count > 1 {
+                    gap * (row_item_count as u16 - 1)
+                } else {
+                    0
+                };
+                let row_width_with_gaps = row.width + total_gaps_width;
+                let available_width = content_width.saturating_sub(row_width_with_gaps);
+
+                // Calculate starting X and spacing for this row
+                let (row_start_x, item_spacing) = match justify_content {
+                    JustifyContent::Start => (start_x, gap),
+                    JustifyContent::End => (start_x + available_width, gap),
+                    JustifyContent::Center => (start_x + available_width / 2, gap),
+                    JustifyContent::SpaceBetween => {
+                        if row_item_count > 1 {
+                            let total_gaps = row_item_count - 1;
+                            let spacing =
+                                (available_width + gap * total_gaps as u16) / total_gaps as u16;
+                            (start_x, spacing)
+                        } else {
+                            (start_x, gap)
                         }
-                        JustifyContent::SpaceEvenly => {
-                            if row_item_count > 0 {
-                                let spacing = available_width / (row_item_count as u16 + 1);
-                                (start_x + spacing, gap + spacing)
-                            } else {
-                                (start_x, gap)
-                            }
+                    }
+                    JustifyContent::SpaceAround => {
+                        if row_item_count > 0 {
+                            let spacing = available_width / row_item_count as u16;
+                            (start_x + spacing / 2, gap + spacing)
+                        } else {
+                            (start_x, gap)
                         }
-                    };
-
-                    // Position each child in this row
-                    let mut current_x = row_start_x;
-
-                    for i in row.start_index..row.end_index {
-                        let mut child_ref = self.children[i].borrow_mut();
-
-                        // Apply AlignItems for vertical positioning within the row
-                        let child_align = child_ref
-                            .style
-                            .as_ref()
-                            .and_then(|s| s.align_self)
-                            .unwrap_or(AlignSelf::Auto);
-
-                        let effective_align = match child_align {
-                            AlignSelf::Auto => align_items,
-                            AlignSelf::Start => AlignItems::Start,
-                            AlignSelf::Center => AlignItems::Center,
-                            AlignSelf::End => AlignItems::End,
-                        };
-
-                        let y_position = match effective_align {
-                            AlignItems::Start => current_y,
-                            AlignItems::Center => {
-                                let child_space = row.height.saturating_sub(child_ref.height);
-                                current_y + (child_space / 2)
-                            }
-                            AlignItems::End => {
-                                let child_space = row.height.saturating_sub(child_ref.height);
-                                current_y + child_space
-                            }
-                        };
-
-                        child_ref.set_position(current_x, y_position);
-                        current_x += child_ref.width;
-
-                        // Add spacing after each item except the last in row
-                        if i < row.end_index - 1 {
-                            current_x += item_spacing;
+                    }
+                    JustifyContent::SpaceEvenly => {
+                        if row_item_count > 0 {
+                            let spacing = available_width / (row_item_count as u16 + 1);
+                            (start_x + spacing, gap + spacing)
+                        } else {
+                            (start_x, gap)
                         }
                     }
+                };
 
-                    // Move to next row
-                    current_y += row.height + gap;
-                }
-            }
-            Direction::Vertical => {
-                // Vertical wrapping: items flow top to bottom, wrap to next column
-
-                // First pass: Calculate dimensions and group into columns
-                struct ColInfo {
-                    start_index: usize,
-                    end_index: usize,
-                    width: u16,
-                    height: u16, // Total height of items WITHOUT gaps
-                }
+                // Position each child in this row
+                let mut current_x = row_start_x;
 
-                let mut cols = Vec::new();
-                let mut current_col_width = 0u16;
-                let mut current_col_height = 0u16; // Height without gaps
-                let mut current_col_height_with_gaps = 0u16; // Height including gaps for fitting check
-                let mut col_start_index = 0;
+                // Baseline items line up with the lowest baseline among them
+                let row_baseline = self.children[row.start_index..row.end_index]
+                    .iter()
+                    .map(|child| child.borrow())
+                    .filter(|child| child.cross_align(align_items) == AlignItems::Baseline)
+                    .map(|child| child.baseline())
+                    .max()
+                    .unwrap_or(0);
 
-                // Resolve all child dimensions first
-                for child in &self.children {
-                    let mut child_ref = child.borrow_mut();
-                    child_ref.layout_with_parent(content_width, content_height);
-                }
+                for i in row.start_index..row.end_index {
+                    let mut child_ref = self.children[i].borrow_mut();
 
-                // Group children into columns
-                for (i, child) in self.children.iter().enumerate() {
-                    let child_ref = child.borrow();
-                    let child_width = child_ref.width;
-                    let child_height = child_ref.height;
+                    // Apply AlignItems for vertical positioning within the row
+                    let effective_align = child_ref.cross_align(align_items);
 
-                    // Check if child fits in current column (considering gaps)
-                    let height_if_added = if current_col_height > 0 {
-                        current_col_height_with_gaps + gap + child_height
-                    } else {
-                        child_height
+                    let y_position = match effective_align {
+                        AlignItems::Start => current_y,
+                        AlignItems::Center => {
+                            let child_space = row.height.saturating_sub(child_ref.height);
+                            current_y + (child_space / 2)
+                        }
+                        AlignItems::End => {
+                            let child_space = row.height.saturating_sub(child_ref.height);
+                            current_y + child_space
+                        }
+                        AlignItems::Baseline => {
+                            current_y + row_baseline.saturating_sub(child_ref.baseline())
+                        }
                     };
 
-                    if current_col_height > 0 && height_if_added > content_height {
-                        // Save current column and start new one
-                        cols.push(ColInfo {
-                            start_index: col_start_index,
-                            end_index: i,
-                            width: current_col_width,
-                            height: current_col_height, // Store height WITHOUT gaps
-                        });
-
-                        col_start_index = i;
-                        current_col_width = child_width;
-                        current_col_height = child_height;
-                        current_col_height_with_gaps = child_height;
-                    } else {
-                        // Add to current column
-                        current_col_height += child_height;
-                        current_col_height_with_gaps = height_if_added;
-                        current_col_width = current_col_width.max(child_width);
+                    let x_position = start_x
+                        + main_axis_position(
+                            reversed,
+                            current_x - start_x,
+                            child_ref.width,
+                            content_width,
+                        );
+                    child_ref.set_position(x_position, y_position);
+                    current_x += child_ref.width;
+
+                    // Add spacing after each item except the last in row
+                    if i < row.end_index - 1 {
+                        current_x += item_spacing;
                     }
                 }
 
-                // Don't forget the last column
-                if col_start_index < self.children.len() {
+                // Move to next row
+                current_y += row.height + gap;
+            }
+        } else {
+            // Vertical wrapping: items flow top to bottom, wrap to next column
+
+            // First pass: Calculate dimensions and group into columns
+            struct ColInfo {
+                start_index: usize,
+                end_index: usize,
+                width: u16,
+                height: u16, // Total height of items WITHOUT gaps
+            }
+
+            let mut cols = Vec::new();
+            let mut current_col_width = 0u16;
+            let mut current_col_height = 0u16; // Height without gaps
+            let mut current_col_height_with_gaps = 0u16; // Height including gaps for fitting check
+            let mut col_start_index = 0;
+
+            // Resolve all child dimensions first
+            for child in &self.children {
+                let mut child_ref = child.borrow_mut();
+                child_ref.resolve_remaining_fractions(content_width, content_height);
+                child_ref.layout_with_parent(content_width, content_height);
+            }
+
+            // Group children into columns
+            for (i, child) in self.children.iter().enumerate() {
+                let child_ref = child.borrow();
+                let child_width = child_ref.width;
+                let child_height = child_ref.height;
+
+                // Check if child fits in current column (considering gaps)
+                let height_if_added = if current_col_height > 0 {
+                    current_col_height_with_gaps + gap + child_height
+                } else {
+                    child_height
+                };
+
+                if current_col_height > 0 && height_if_added > content_height {
+                    // Save current column and start new one
                     cols.push(ColInfo {
                         start_index: col_start_index,
-                        end_index: self.children.len(),
+                        end_index: i,
                         width: current_col_width,
                         height: current_col_height, // Store height WITHOUT gaps
                     });
+
+                    col_start_index = i;
+                    current_col_width = child_width;
+                    current_col_height = child_height;
+                    current_col_height_with_gaps = child_height;
+                } else {
+                    // Add to current column
+                    current_col_height += child_height;
+                    current_col_height_with_gaps = height_if_added;
+                    current_col_width = current_col_width.max(child_width);
                 }
+            }
 
-                // Second pass: Position children with alignment
-                let mut current_x = start_x;
+            // Don't forget the last column
+            if col_start_index < self.children.len() {
+                cols.push(ColInfo {
+                    start_index: col_start_index,
+                    end_index: self.children.len(),
+                    width: current_col_width,
+                    height: current_col_height, // Store height WITHOUT gaps
+                });
+            }
 
-                for col in &cols {
-                    // Calculate vertical positioning for this column based on justify_content
-                    let col_item_count = col.end_index - col.start_index;
-                    // Calculate total height including gaps
-                    let total_gaps_height = if col_item_count > 1 {
-                        gap * (col_item_count as u16 - 1)
-                    } else {
-                        0
-                    };
-                    let col_height_with_gaps = col.height + total_gaps_height;
-                    let available_height = content_height.saturating_sub(col_height_with_gaps);
-
-                    // Calculate starting Y and spacing for this column
-                    let (col_start_y, item_spacing) = match justify_content {
-                        JustifyContent::Start => (start_y, gap),
-                        JustifyContent::End => (start_y + available_height, gap),
-                        JustifyContent::Center => (start_y + available_height / 2, gap),
-                        JustifyContent::SpaceBetween => {
-                            if col_item_count > 1 {
-                                let total_gaps = col_item_count - 1;
-                                let spacing = (available_height + gap * total_gaps as u16)
-                                    / total_gaps as u16;
-                                (start_y, spacing)
-                            } else {
-                                (start_y, gap)
-                            }
+            // Second pass: Position children with alignment
+            let mut current_x = start_x;
+
+            for col in &cols {
+                // Calculate vertical positioning for this column based on justify_content
+                let col_item_count = col.end_index - col.start_index;
+                // Calculate total height including gaps
+                let total_gaps_height = if col_item_count > 1 {
+                    gap * (col_item_count as u16 - 1)
+                } else {
+                    0
+                };
+                let col_height_with_gaps = col.height + total_gaps_height;
+                let available_height = content_height.saturating_sub(col_height_with_gaps);
+
+                // Calculate starting Y and spacing for this column
+                let (col_start_y, item_spacing) = match justify_content {
+                    JustifyContent::Start => (start_y, gap),
+                    JustifyContent::End => (start_y + available_height, gap),
+                    JustifyContent::Center => (start_y + available_height / 2, gap),
+                    JustifyContent::SpaceBetween => {
+                        if col_item_count > 1 {
+                            let total_gaps = col_item_count - 1;
+                            let spacing =
+                                (available_height + gap * total_gaps as u16) / total_gaps as u16;
+                            (start_y, spacing)
+                        } else {
+                            (start_y, gap)
                         }
-                        JustifyContent::SpaceAround => {
-                            if col_item_count > 0 {
-                                let spacing = available_height / col_item_count as u16;
-                                (start_y + spacing / 2, gap + spacing)
-                            } else {
-                                (start_y, gap)
-                            }
+                    }
+                    JustifyContent::SpaceAround => {
+                        if col_item_count > 0 {
+                            let spacing = available_height / col_item_count as u16;
+                            (start_y + spacing / 2, gap + spacing)
+                        } else {
+                            (start_y, gap)
                         }
-                        JustifyContent::SpaceEvenly => {
-                            if col_item_count > 0 {
-                                let spacing = available_height / (col_item_count as u16 + 1);
-                                (start_y + spacing, gap + spacing)
-                            } else {
-                                (start_y, gap)
-                            }
+                    }
+                    JustifyContent::SpaceEvenly => {
+                        if col_item_count > 0 {
+                            let spacing = available_height / (col_item_count as u16 + 1);
+                            (start_y + spacing, gap + spacing)
+                        } else {
+                            (start_y, gap)
                         }
-                    };
+                    }
+                };
 
-                    // Position each child in this column
-                    let mut current_y = col_start_y;
-
-                    for i in col.start_index..col.end_index {
-                        let mut child_ref = self.children[i].borrow_mut();
-
-                        // Apply AlignItems for horizontal positioning within the column
-                        let child_align = child_ref
-                            .style
-                            .as_ref()
-                            .and_then(|s| s.align_self)
-                            .unwrap_or(AlignSelf::Auto);
-
-                        let effective_align = match child_align {
-                            AlignSelf::Auto => align_items,
-                            AlignSelf::Start => AlignItems::Start,
-                            AlignSelf::Center => AlignItems::Center,
-                            AlignSelf::End => AlignItems::End,
-                        };
-
-                        let x_position = match effective_align {
-                            AlignItems::Start => current_x,
-                            AlignItems::Center => {
-                                let child_space = col.width.saturating_sub(child_ref.width);
-                                current_x + (child_space / 2)
-                            }
-                            AlignItems::End => {
-                                let child_space = col.width.saturating_sub(child_ref.width);
-                                current_x + child_space
-                            }
-                        };
+                // Position each child in this column
+                let mut current_y = col_start_y;
 
-                        child_ref.set_position(x_position, current_y);
-                        current_y += child_ref.height;
+                for i in col.start_index..col.end_index {
+                    let mut child_ref = self.children[i].borrow_mut();
 
-                        // Add spacing after each item except the last in column
-                        if i < col.end_index - 1 {
-                            current_y += item_spacing;
+                    // Apply AlignItems for horizontal positioning within the column
+                    let effective_align = child_ref.cross_align(align_items);
+
+                    let x_position = match effective_align {
+                        AlignItems::Start | AlignItems::Baseline => current_x,
+                        AlignItems::Center => {
+                            let child_space = col.width.saturating_sub(child_ref.width);
+                            current_x + (child_space / 2)
                         }
-                    }
+                        AlignItems::End => {
+                            let child_space = col.width.saturating_sub(child_ref.width);
+                            current_x + child_space
+                        }
+                    };
 
-                    // Move to next column
-                    current_x += col.width + gap;
+                    let y_position = start_y
+                        + main_axis_position(
+                            reversed,
+                            current_y - start_y,
+                            child_ref.height,
+                            content_height,
+                        );
+                    child_ref.set_position(x_position, y_position);
+                    current_y += child_ref.height;
+
+                    // Add spacing after each item except the last in column
+                    if i < col.end_index - 1 {
+                        current_y += item_spacing;
+                    }
                 }
+
+                // Move to next column
+                current_x += col.width + gap;
             }
         }
 
@@ -1509,35 +1795,63 @@ impl RenderNode {
 
     /// Lays out child nodes with parent dimension context for percentage resolution.
     pub(crate) fn layout_children_with_parent(&mut self, direction: Direction) {
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
+        let padding = self.padding();
 
-        // Check if border is enabled and adjust content area accordingly
-        let border_offset = if self
-            .style
-            .as_ref()
-            .and_then(|s| s.border.as_ref())
-            .is_some_and(|b| b.enabled)
+        // Check if border is enabled and adjust content area accordingly.
+        // Collapsed children are laid out over the border instead.
+        let collapse = self.collapses_borders();
+        let border_offset = if !collapse
+            && self
+                .style
+                .as_ref()
+                .and_then(|s| s.border.as_ref())
+                .is_some_and(|b| b.enabled)
         {
             1
         } else {
             0
         };
 
-        // Calculate content box dimensions (after padding and border)
-        let content_width = self
-            .width
-            .saturating_sub(padding.left + padding.right + (border_offset * 2));
-        let content_height = self
+        // Collapsed siblings overlap by a cell, which gives the main axis one
+        // extra cell per shared border
+        let overlap = if collapse {
+            self.children
+                .iter()
+                .filter(|child| !child.borrow().is_out_of_flow())
+                .count()
+                .saturating_sub(1) as u16
+        } else {
+            0
+        };
+        let (main_overlap_x, main_overlap_y) = if direction.is_horizontal() {
+            (overlap, 0)
+        } else {
+            (0, overlap)
+        };
+
+        // Calculate content box dimensions (after padding and border)
+        let content_width = self
+            .width
+            .saturating_sub(padding.left + padding.right + (border_offset * 2))
+            .saturating_add(main_overlap_x);
+        let content_height = self
             .height
-            .saturating_sub(padding.top + padding.bottom + (border_offset * 2));
+            .saturating_sub(padding.top + padding.bottom + (border_offset * 2))
+            .saturating_add(main_overlap_y);
+
+        // Children measured before their own layout resolve fractions against this
+        for child in &self.children {
+            child.borrow_mut().containing_size = (content_width, content_height);
+        }
 
         // Check if wrapping is enabled
         let wrap_mode = self.style.as_ref().and_then(|s| s.wrap);
-        let gap = self.style.as_ref().and_then(|s| s.gap).unwrap_or(0);
+        let place_content = self.style.as_ref().and_then(|s| s.place_content);
+
+        // Aligned text normally fills the cross axis so it can align within it.
+        // Placed content sizes text to fit so the block can be centered instead.
+        let fill_aligned_text = place_content.is_none();
+        let gap = self.gap(direction);
 
         // If wrapping is enabled, use wrapping layout
         if let Some(crate::style::WrapMode::Wrap) = wrap_mode {
@@ -1555,6 +1869,7 @@ impl RenderNode {
         // First pass: Identify child types and calculate fixed/percentage sizes
         let mut absolute_children = Vec::new();
         let mut auto_children = Vec::new();
+        let mut fraction_children = Vec::new();
         let mut used_space = 0u16;
         let mut child_sizes = Vec::new();
 
@@ -1565,10 +1880,10 @@ impl RenderNode {
             let (position_type, z_index) = if let Some(style) = &child_ref.style {
                 (
                     style.position.unwrap_or(Position::Relative),
-                    style.z_index.unwrap_or(0),
+                    style.resolved_z_index().unwrap_or(0.0),
                 )
             } else {
-                (Position::Relative, 0)
+                (Position::Relative, 0.0)
             };
 
             child_ref.position_type = position_type;
@@ -1607,25 +1922,40 @@ impl RenderNode {
             }
 
             // Determine child size based on dimension type
-            let dimension = match direction {
-                Direction::Vertical => child_ref.style.as_ref().and_then(|s| s.height),
-                Direction::Horizontal => child_ref.style.as_ref().and_then(|s| s.width),
+            let dimension = if direction.is_horizontal() {
+                child_ref.style.as_ref().and_then(|s| s.width)
+            } else {
+                child_ref.style.as_ref().and_then(|s| s.height)
             };
 
+            // A size derived from the aspect ratio counts as a fixed one
+            let dimension = child_ref
+                .style
+                .as_ref()
+                .and_then(|s| aspect_main_size(s, direction, content_width, content_height))
+                .map(Dimension::Fixed)
+                .or(dimension);
+
             let child_size = match dimension {
                 Some(Dimension::Fixed(size)) => {
                     used_space = used_space.saturating_add(size);
                     size
                 }
                 Some(Dimension::Percentage(pct)) => {
-                    let parent_size = match direction {
-                        Direction::Vertical => content_height,
-                        Direction::Horizontal => content_width,
+                    let parent_size = if direction.is_horizontal() {
+                        content_width
+                    } else {
+                        content_height
                     };
                     let size = (parent_size as f32 * pct) as u16;
                     used_space = used_space.saturating_add(size);
                     size
                 }
+                Some(Dimension::FractionRemaining(fraction)) => {
+                    // Sized in the second pass once the remaining space is known
+                    fraction_children.push((index, fraction.clamp(0.0, 1.0)));
+                    0
+                }
                 Some(Dimension::Content) => {
                     // Calculate intrinsic size for content-based dimension
                     // Calculate hint based on child's width/height settings
@@ -1649,11 +1979,15 @@ impl RenderNode {
                     } else {
                         content_height
                     };
-                    let (intrinsic_w, intrinsic_h) = child_ref
-                        .calculate_intrinsic_size_multipass(3, Some((hint_width, hint_height)));
-                    let size = match direction {
-                        Direction::Horizontal => intrinsic_w,
-                        Direction::Vertical => intrinsic_h,
+                    let (intrinsic_w, intrinsic_h) = child_ref.calculate_intrinsic_size_multipass(
+                        3,
+                        Some((hint_width, hint_height)),
+                        (content_width, content_height),
+                    );
+                    let size = if direction.is_horizontal() {
+                        intrinsic_w
+                    } else {
+                        intrinsic_h
                     };
                     used_space = used_space.saturating_add(size);
                     size
@@ -1662,46 +1996,43 @@ impl RenderNode {
                     auto_children.push(index);
                     // For text nodes with auto sizing, use content size
                     match &child_ref.node_type {
-                        RenderNodeType::Text(text) => match direction {
-                            Direction::Horizontal => {
+                        RenderNodeType::Text(text) => {
+                            if direction.is_horizontal() {
                                 let size = display_width(text) as u16;
                                 used_space = used_space.saturating_add(size);
                                 size
-                            }
-                            Direction::Vertical => {
+                            } else {
                                 used_space = used_space.saturating_add(1);
                                 1
                             }
-                        },
-                        RenderNodeType::RichText(spans) => match direction {
-                            Direction::Horizontal => {
+                        }
+                        RenderNodeType::RichText(spans) => {
+                            if direction.is_horizontal() {
                                 let size: u16 = spans
                                     .iter()
                                     .map(|span| display_width(&span.content) as u16)
                                     .sum();
                                 used_space = used_space.saturating_add(size);
                                 size
-                            }
-                            Direction::Vertical => {
+                            } else {
                                 used_space = used_space.saturating_add(1);
                                 1
                             }
-                        },
-                        RenderNodeType::TextWrapped(lines) => match direction {
-                            Direction::Horizontal => {
+                        }
+                        RenderNodeType::TextWrapped(lines) => {
+                            if direction.is_horizontal() {
                                 let size = lines.iter().map(|l| display_width(l)).max().unwrap_or(0)
                                     as u16;
                                 used_space = used_space.saturating_add(size);
                                 size
-                            }
-                            Direction::Vertical => {
+                            } else {
                                 let size = lines.len() as u16;
                                 used_space = used_space.saturating_add(size);
                                 size
                             }
-                        },
-                        RenderNodeType::RichTextWrapped(lines) => match direction {
-                            Direction::Horizontal => {
+                        }
+                        RenderNodeType::RichTextWrapped(lines) => {
+                            if direction.is_horizontal() {
                                 let size = lines
                                     .iter()
                                     .map(|line| {
@@ -1713,13 +2044,12 @@ impl RenderNode {
                                     .unwrap_or(0);
                                 used_space = used_space.saturating_add(size);
                                 size
-                            }
-                            Direction::Vertical => {
+                            } else {
                                 let size = lines.len() as u16;
                                 used_space = used_space.saturating_add(size);
                                 size
                             }
-                        },
+                        }
                         _ => 0, // Will be calculated in second pass
                     }
                 }
@@ -1746,11 +2076,15 @@ impl RenderNode {
                     } else {
                         content_height
                     };
-                    let (intrinsic_w, intrinsic_h) = child_ref
-                        .calculate_intrinsic_size_multipass(3, Some((hint_width, hint_height)));
-                    let size = match direction {
-                        Direction::Horizontal => intrinsic_w,
-                        Direction::Vertical => intrinsic_h,
+                    let (intrinsic_w, intrinsic_h) = child_ref.calculate_intrinsic_size_multipass(
+                        3,
+                        Some((hint_width, hint_height)),
+                        (content_width, content_height),
+                    );
+                    let size = if direction.is_horizontal() {
+                        intrinsic_w
+                    } else {
+                        intrinsic_h
                     };
                     used_space = used_space.saturating_add(size);
                     size
@@ -1760,44 +2094,82 @@ impl RenderNode {
             child_sizes.push(child_size);
         }
 
-        // Second pass: Calculate auto sizes
-        let available_space = match direction {
-            Direction::Vertical => content_height.saturating_sub(used_space),
-            Direction::Horizontal => content_width.saturating_sub(used_space),
+        // Calculate total gap space between relative children
+        let relative_children_count = self.children.len() - absolute_children.len();
+        let total_gaps = if relative_children_count > 1 {
+            gap * (relative_children_count as u16 - 1)
+        } else {
+            0
         };
 
-        let auto_size = if !auto_children.is_empty() {
-            available_space / auto_children.len() as u16
+        // Second pass: Calculate auto sizes
+        // Text nodes keep their content size; other auto children are flexible
+        // and share all remaining space, so the packed block fills the container
+        let main_size = if direction.is_horizontal() {
+            content_width
         } else {
-            0
+            content_height
         };
+        let mut available_space = main_size
+            .saturating_sub(used_space)
+            .saturating_sub(total_gaps);
+
+        // Remaining fractions come out of the free space before auto children
+        // share it. Fractions adding up to more than 1.0 are scaled down so
+        // together they take exactly the free space.
+        if !fraction_children.is_empty() {
+            let total: f32 = fraction_children
+                .iter()
+                .map(|&(_, fraction)| fraction)
+                .sum();
+            let scale = if total > 1.0 { total.recip() } else { 1.0 };
+
+            let mut assigned = 0u16;
+            for &(index, fraction) in &fraction_children {
+                let size = (available_space as f32 * fraction * scale) as u16;
+                child_sizes[index] = size;
+                assigned = assigned.saturating_add(size);
+            }
 
-        // Update auto-sized children
-        for &index in &auto_children {
-            let is_text = {
+            // Cells lost to rounding go to the fractions when they claim everything
+            if total >= 1.0 {
+                for &(index, _) in fraction_children
+                    .iter()
+                    .take((available_space - assigned) as usize)
+                {
+                    child_sizes[index] += 1;
+                    assigned += 1;
+                }
+            }
+
+            available_space = available_space.saturating_sub(assigned);
+        }
+
+        let flexible_children: Vec<usize> = auto_children
+            .iter()
+            .copied()
+            .filter(|&index| {
                 let child_ref = self.children[index].borrow();
-                matches!(
+                !matches!(
                     child_ref.node_type,
                     RenderNodeType::Text(_)
                         | RenderNodeType::TextWrapped(_)
                         | RenderNodeType::RichText(_)
                         | RenderNodeType::RichTextWrapped(_)
                 )
-            };
-            // Skip text nodes as they already have their size
-            if !is_text {
-                child_sizes[index] = auto_size;
+            })
+            .collect();
+
+        if !flexible_children.is_empty() {
+            let share = available_space / flexible_children.len() as u16;
+            let remainder = available_space % flexible_children.len() as u16;
+
+            // Hand out leftover cells one at a time so no free space remains
+            for (i, &index) in flexible_children.iter().enumerate() {
+                child_sizes[index] = share + u16::from((i as u16) < remainder);
             }
         }
 
-        // Calculate total space used by children and gaps
-        let relative_children_count = self.children.len() - absolute_children.len();
-        let total_gaps = if relative_children_count > 1 {
-            gap * (relative_children_count as u16 - 1)
-        } else {
-            0
-        };
-
         // Calculate total size of relative children in main axis
         let total_children_size: u16 = child_sizes
             .iter()
@@ -1808,34 +2180,31 @@ impl RenderNode {
 
         let total_used_space = total_children_size + total_gaps;
 
-        // Get justify content setting
+        // Get justify content setting. Placed content is packed at the start
+        // and moved as a block once every child is laid out.
         let justify_content = self
             .style
             .as_ref()
             .and_then(|s| s.justify_content)
+            .filter(|_| place_content.is_none())
             .unwrap_or(JustifyContent::Start);
 
-        // Calculate starting offset and spacing based on JustifyContent
-        let (mut offset, item_spacing) = match direction {
-            Direction::Vertical => {
-                let available_space = content_height.saturating_sub(total_used_space);
-                calculate_justify_offsets(
-                    justify_content,
-                    available_space,
-                    relative_children_count,
-                    gap,
-                )
-            }
-            Direction::Horizontal => {
-                let available_space = content_width.saturating_sub(total_used_space);
-                calculate_justify_offsets(
-                    justify_content,
-                    available_space,
-                    relative_children_count,
-                    gap,
-                )
-            }
+        // Calculate starting offset and spacing based on JustifyContent.
+        // Flexible children have already absorbed the free space, so there is
+        // nothing left to distribute and every mode packs at the start.
+        let free_space = if flexible_children.is_empty() {
+            main_size.saturating_sub(total_used_space)
+        } else {
+            0
         };
+        let (mut offset, item_spacing) =
+            calculate_justify_offsets(justify_content, free_space, relative_children_count, gap);
+
+        // Reversed directions measure offsets from the far edge. When children
+        // overflow, they are mirrored across their own extent so none is pushed
+        // before the content start.
+        let reversed = direction.is_reversed();
+        let main_extent = main_size.max(total_used_space);
 
         // Third pass: Position and layout all children
         for (index, child) in self.children.iter().enumerate() {
@@ -1847,290 +2216,304 @@ impl RenderNode {
             }
 
             // Set child dimensions based on calculated sizes
-            match direction {
-                Direction::Vertical => {
-                    child_ref.height = child_sizes[index];
-                    // Set width for the child (respecting its own width setting)
-                    if let Some(style) = &child_ref.style {
-                        match style.width {
-                            Some(Dimension::Fixed(w)) => child_ref.width = w,
-                            Some(Dimension::Percentage(pct)) => {
-                                child_ref.width = (content_width as f32 * pct) as u16;
+            if direction.is_horizontal() {
+                // Set width from calculated size (includes auto-sizing)
+                child_ref.width = child_sizes[index];
+
+                // Set height for the child (respecting its own height setting)
+                if let Some(style) = &child_ref.style {
+                    match style.height {
+                        Some(Dimension::Fixed(h)) => child_ref.height = h,
+                        Some(Dimension::Percentage(pct) | Dimension::FractionRemaining(pct)) => {
+                            child_ref.height = (content_height as f32 * pct) as u16;
+                        }
+                        Some(Dimension::Content) => {
+                            // Content-based height
+                            let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
+                            child_ref.height = intrinsic_h.min(content_height);
+                        }
+                        Some(Dimension::Auto) => match &child_ref.node_type {
+                            RenderNodeType::Text(_) | RenderNodeType::RichText(_) => {
+                                child_ref.height = 1;
                             }
-                            Some(Dimension::Content) => {
-                                // Content-based width
-                                let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
-                                child_ref.width = intrinsic_w.min(content_width);
+                            RenderNodeType::TextWrapped(lines) => {
+                                child_ref.height = lines.len() as u16;
+                            }
+                            RenderNodeType::RichTextWrapped(lines) => {
+                                child_ref.height = lines.len() as u16;
+                            }
+                            _ => {
+                                child_ref.height = content_height;
                             }
-                            Some(Dimension::Auto) => {
-                                // Auto in perpendicular direction means fill available space
-                                match &child_ref.node_type {
-                                    RenderNodeType::Text(text) => {
-                                        // If text has alignment, fill parent width for alignment to work
-                                        if child_ref
+                        },
+                        None => {
+                            // None means use content-based sizing
+                            let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
+                            child_ref.height = intrinsic_h.min(content_height);
+                        }
+                    }
+                } else {
+                    // No style - use intrinsic height
+                    let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
+                    child_ref.height = intrinsic_h.min(content_height);
+                }
+
+                // Keep the aspect ratio before aligning on the derived size
+                if let Some(style) = &child_ref.style
+                    && style.aspect_ratio.is_some()
+                {
+                    (child_ref.width, child_ref.height) =
+                        style.aspect_size(child_ref.width, child_ref.height);
+                }
+
+                // Apply AlignItems for cross-axis alignment (vertical axis in horizontal layout)
+                let align_items = self
+                    .style
+                    .as_ref()
+                    .and_then(|s| s.align_items)
+                    .unwrap_or(AlignItems::Start);
+
+                // Check if child overrides with align_self
+                let effective_align = child_ref.cross_align(align_items);
+
+                // Placed content is aligned within its block afterwards
+                let effective_align = if place_content.is_some() {
+                    AlignItems::Start
+                } else {
+                    effective_align
+                };
+
+                // Baselines are lined up once every child has its height
+                let y_position = match effective_align {
+                    AlignItems::Start | AlignItems::Baseline => {
+                        self.y + padding.top + border_offset
+                    }
+                    AlignItems::Center => {
+                        let child_space = content_height.saturating_sub(child_ref.height);
+                        self.y + padding.top + border_offset + (child_space / 2)
+                    }
+                    AlignItems::End => {
+                        let child_space = content_height.saturating_sub(child_ref.height);
+                        self.y + padding.top + border_offset + child_space
+                    }
+                };
+
+                let x_offset =
+                    main_axis_position(reversed, offset, child_sizes[index], main_extent);
+                child_ref
+                    .set_position(self.x + padding.left + border_offset + x_offset, y_position);
+                offset += child_sizes[index];
+                // Add spacing after each child based on justify mode
+                // For SpaceBetween, add spacing after all children except the last
+                // For SpaceAround and SpaceEvenly, add spacing after all children
+                // For Start, Center, End, use regular gap spacing
+                let is_last_relative_child = {
+                    // Find if this is the last non-absolute child
+                    let mut last_idx = index;
+                    for i in (index + 1)..self.children.len() {
+                        if !absolute_children.contains(&i) {
+                            last_idx = i;
+                        }
+                    }
+                    last_idx == index
+                };
+
+                // Add spacing based on justify mode
+                if !is_last_relative_child {
+                    offset += item_spacing;
+                } else if matches!(
+                    justify_content,
+                    JustifyContent::SpaceAround | JustifyContent::SpaceEvenly
+                ) {
+                    // These modes need spacing after the last item too
+                    offset += item_spacing;
+                }
+            } else {
+                child_ref.height = child_sizes[index];
+                // Set width for the child (respecting its own width setting)
+                if let Some(style) = &child_ref.style {
+                    match style.width {
+                        Some(Dimension::Fixed(w)) => child_ref.width = w,
+                        Some(Dimension::Percentage(pct) | Dimension::FractionRemaining(pct)) => {
+                            child_ref.width = (content_width as f32 * pct) as u16;
+                        }
+                        Some(Dimension::Content) => {
+                            // Content-based width
+                            let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
+                            child_ref.width = intrinsic_w.min(content_width);
+                        }
+                        Some(Dimension::Auto) => {
+                            // Auto in perpendicular direction means fill available space
+                            match &child_ref.node_type {
+                                RenderNodeType::Text(text) => {
+                                    // If text has alignment, fill parent width for alignment to work
+                                    if fill_aligned_text
+                                        && child_ref
                                             .text_style
                                             .as_ref()
                                             .and_then(|ts| ts.align)
                                             .is_some()
-                                        {
-                                            child_ref.width = content_width;
-                                        } else {
-                                            child_ref.width = display_width(text) as u16;
-                                        }
+                                    {
+                                        child_ref.width = content_width;
+                                    } else {
+                                        child_ref.width = display_width(text) as u16;
                                     }
-                                    RenderNodeType::RichText(spans) => {
-                                        // If RichText has alignment, fill parent width for alignment to work
-                                        let has_alignment =
-                                            child_ref.text_style.as_ref().and_then(|ts| ts.align);
-                                        if has_alignment.is_some() {
-                                            child_ref.width = content_width;
-                                        } else {
-                                            child_ref.width = spans
-                                                .iter()
-                                                .map(|span| display_width(&span.content) as u16)
-                                                .sum();
-                                        }
+                                }
+                                RenderNodeType::RichText(spans) => {
+                                    // If RichText has alignment, fill parent width for alignment to work
+                                    let has_alignment =
+                                        child_ref.text_style.as_ref().and_then(|ts| ts.align);
+                                    if fill_aligned_text && has_alignment.is_some() {
+                                        child_ref.width = content_width;
+                                    } else {
+                                        child_ref.width = spans
+                                            .iter()
+                                            .map(|span| display_width(&span.content) as u16)
+                                            .sum();
                                     }
-                                    RenderNodeType::TextWrapped(lines) => {
-                                        // If wrapped text has alignment, fill parent width for alignment to work
-                                        if child_ref
+                                }
+                                RenderNodeType::TextWrapped(lines) => {
+                                    // If wrapped text has alignment, fill parent width for alignment to work
+                                    if fill_aligned_text
+                                        && child_ref
                                             .text_style
                                             .as_ref()
                                             .and_then(|ts| ts.align)
                                             .is_some()
-                                        {
-                                            child_ref.width = content_width;
-                                        } else {
-                                            child_ref.width = lines
-                                                .iter()
-                                                .map(|l| display_width(l))
-                                                .max()
-                                                .unwrap_or(0)
-                                                as u16;
-                                        }
+                                    {
+                                        child_ref.width = content_width;
+                                    } else {
+                                        child_ref.width = lines
+                                            .iter()
+                                            .map(|l| display_width(l))
+                                            .max()
+                                            .unwrap_or(0)
+                                            as u16;
                                     }
-                                    RenderNodeType::RichTextWrapped(lines) => {
-                                        // If wrapped RichText has alignment, fill parent width for alignment to work
-                                        if child_ref
+                                }
+                                RenderNodeType::RichTextWrapped(lines) => {
+                                    // If wrapped RichText has alignment, fill parent width for alignment to work
+                                    if fill_aligned_text
+                                        && child_ref
                                             .text_style
                                             .as_ref()
                                             .and_then(|ts| ts.align)
                                             .is_some()
-                                        {
-                                            child_ref.width = content_width;
-                                        } else {
-                                            child_ref.width = lines
-                                                .iter()
-                                                .map(|line| {
-                                                    line.iter()
-                                                        .map(|span| {
-                                                            display_width(&span.content) as u16
-                                                        })
-                                                        .sum::<u16>()
-                                                })
-                                                .max()
-                                                .unwrap_or(0);
-                                        }
-                                    }
-                                    _ => {
+                                    {
                                         child_ref.width = content_width;
+                                    } else {
+                                        child_ref.width = lines
+                                            .iter()
+                                            .map(|line| {
+                                                line.iter()
+                                                    .map(|span| display_width(&span.content) as u16)
+                                                    .sum::<u16>()
+                                            })
+                                            .max()
+                                            .unwrap_or(0);
                                     }
                                 }
-                            }
-                            None => {
-                                // None means use content-based sizing
-                                // UNLESS this is a text node with alignment, then use full available width
-                                let has_alignment =
-                                    child_ref.text_style.as_ref().and_then(|ts| ts.align);
-
-                                if has_alignment.is_some() {
-                                    // Text with alignment needs full width to align within
+                                _ => {
                                     child_ref.width = content_width;
-                                } else {
-                                    let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
-                                    child_ref.width = intrinsic_w.min(content_width);
                                 }
                             }
                         }
-                    } else {
-                        // No style - use intrinsic width
-                        // UNLESS this is a text node with alignment, then use full available width
-                        let has_alignment = child_ref.text_style.as_ref().and_then(|ts| ts.align);
-
-                        if has_alignment.is_some() {
-                            // Text with alignment needs full width to align within
-                            child_ref.width = content_width;
-                        } else {
-                            let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
-                            child_ref.width = intrinsic_w.min(content_width);
-                        }
-                    }
-
-                    // Apply AlignItems for cross-axis alignment (horizontal axis in vertical layout)
-                    let align_items = self
-                        .style
-                        .as_ref()
-                        .and_then(|s| s.align_items)
-                        .unwrap_or(AlignItems::Start);
-
-                    // Check if child overrides with align_self
-                    let child_align = child_ref
-                        .style
-                        .as_ref()
-                        .and_then(|s| s.align_self)
-                        .unwrap_or(AlignSelf::Auto);
-
-                    let effective_align = match child_align {
-                        AlignSelf::Auto => align_items,
-                        AlignSelf::Start => AlignItems::Start,
-                        AlignSelf::Center => AlignItems::Center,
-                        AlignSelf::End => AlignItems::End,
-                    };
-
-                    let x_position = match effective_align {
-                        AlignItems::Start => self.x + padding.left + border_offset,
-                        AlignItems::Center => {
-                            let child_space = content_width.saturating_sub(child_ref.width);
-                            self.x + padding.left + border_offset + (child_space / 2)
-                        }
-                        AlignItems::End => {
-                            let child_space = content_width.saturating_sub(child_ref.width);
-                            self.x + padding.left + border_offset + child_space
-                        }
-                    };
-
-                    child_ref
-                        .set_position(x_position, self.y + padding.top + border_offset + offset);
-                    offset += child_sizes[index];
-                    // Add spacing after each child based on justify mode
-                    // For SpaceBetween, add spacing after all children except the last
-                    // For SpaceAround and SpaceEvenly, add spacing after all children
-                    // For Start, Center, End, use regular gap spacing
-                    let is_last_relative_child = {
-                        // Find if this is the last non-absolute child
-                        let mut last_idx = index;
-                        for i in (index + 1)..self.children.len() {
-                            if !absolute_children.contains(&i) {
-                                last_idx = i;
+                        None => {
+                            // None means use content-based sizing
+                            // UNLESS this is a text node with alignment, then use full available width
+                            let has_alignment =
+                                child_ref.text_style.as_ref().and_then(|ts| ts.align);
+
+                            if fill_aligned_text && has_alignment.is_some() {
+                                // Text with alignment needs full width to align within
+                                child_ref.width = content_width;
+                            } else {
+                                let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
+                                child_ref.width = intrinsic_w.min(content_width);
                             }
                         }
-                        last_idx == index
-                    };
-
-                    // Add spacing based on justify mode
-                    if !is_last_relative_child {
-                        offset += item_spacing;
-                    } else if matches!(
-                        justify_content,
-                        JustifyContent::SpaceAround | JustifyContent::SpaceEvenly
-                    ) {
-                        // These modes need spacing after the last item too
-                        offset += item_spacing;
                     }
-                }
-                Direction::Horizontal => {
-                    // Set width from calculated size (includes auto-sizing)
-                    child_ref.width = child_sizes[index];
+                } else {
+                    // No style - use intrinsic width
+                    // UNLESS this is a text node with alignment, then use full available width
+                    let has_alignment = child_ref.text_style.as_ref().and_then(|ts| ts.align);
 
-                    // Set height for the child (respecting its own height setting)
-                    if let Some(style) = &child_ref.style {
-                        match style.height {
-                            Some(Dimension::Fixed(h)) => child_ref.height = h,
-                            Some(Dimension::Percentage(pct)) => {
-                                child_ref.height = (content_height as f32 * pct) as u16;
-                            }
-                            Some(Dimension::Content) => {
-                                // Content-based height
-                                let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
-                                child_ref.height = intrinsic_h.min(content_height);
-                            }
-                            Some(Dimension::Auto) => match &child_ref.node_type {
-                                RenderNodeType::Text(_) | RenderNodeType::RichText(_) => {
-                                    child_ref.height = 1;
-                                }
-                                RenderNodeType::TextWrapped(lines) => {
-                                    child_ref.height = lines.len() as u16;
-                                }
-                                RenderNodeType::RichTextWrapped(lines) => {
-                                    child_ref.height = lines.len() as u16;
-                                }
-                                _ => {
-                                    child_ref.height = content_height;
-                                }
-                            },
-                            None => {
-                                // None means use content-based sizing
-                                let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
-                                child_ref.height = intrinsic_h.min(content_height);
-                            }
-                        }
+                    if fill_aligned_text && has_alignment.is_some() {
+                        // Text with alignment needs full width to align within
+                        child_ref.width = content_width;
                     } else {
-                        // No style - use intrinsic height
-                        let (_, intrinsic_h) = child_ref.calculate_intrinsic_size();
-                        child_ref.height = intrinsic_h.min(content_height);
+                        let (intrinsic_w, _) = child_ref.calculate_intrinsic_size();
+                        child_ref.width = intrinsic_w.min(content_width);
                     }
+                }
 
-                    // Apply AlignItems for cross-axis alignment (vertical axis in horizontal layout)
-                    let align_items = self
-                        .style
-                        .as_ref()
-                        .and_then(|s| s.align_items)
-                        .unwrap_or(AlignItems::Start);
-
-                    // Check if child overrides with align_self
-                    let child_align = child_ref
-                        .style
-                        .as_ref()
-                        .and_then(|s| s.align_self)
-                        .unwrap_or(AlignSelf::Auto);
-
-                    let effective_align = match child_align {
-                        AlignSelf::Auto => align_items,
-                        AlignSelf::Start => AlignItems::Start,
-                        AlignSelf::Center => AlignItems::Center,
-                        AlignSelf::End => AlignItems::End,
-                    };
+                // Keep the aspect ratio before aligning on the derived size
+                if let Some(style) = &child_ref.style
+                    && style.aspect_ratio.is_some()
+                {
+                    (child_ref.width, child_ref.height) =
+                        style.aspect_size(child_ref.width, child_ref.height);
+                }
 
-                    let y_position = match effective_align {
-                        AlignItems::Start => self.y + padding.top + border_offset,
-                        AlignItems::Center => {
-                            let child_space = content_height.saturating_sub(child_ref.height);
-                            self.y + padding.top + border_offset + (child_space / 2)
-                        }
-                        AlignItems::End => {
-                            let child_space = content_height.saturating_sub(child_ref.height);
-                            self.y + padding.top + border_offset + child_space
-                        }
-                    };
+                // Apply AlignItems for cross-axis alignment (horizontal axis in vertical layout)
+                let align_items = self
+                    .style
+                    .as_ref()
+                    .and_then(|s| s.align_items)
+                    .unwrap_or(AlignItems::Start);
 
-                    child_ref
-                        .set_position(self.x + padding.left + border_offset + offset, y_position);
-                    offset += child_sizes[index];
-                    // Add spacing after each child based on justify mode
-                    // For SpaceBetween, add spacing after all children except the last
-                    // For SpaceAround and SpaceEvenly, add spacing after all children
-                    // For Start, Center, End, use regular gap spacing
-                    let is_last_relative_child = {
-                        // Find if this is the last non-absolute child
-                        let mut last_idx = index;
-                        for i in (index + 1)..self.children.len() {
-                            if !absolute_children.contains(&i) {
-                                last_idx = i;
-                            }
-                        }
-                        last_idx == index
-                    };
+                // Check if child overrides with align_self
+                let effective_align = child_ref.cross_align(align_items);
+
+                // Placed content is aligned within its block afterwards
+                let effective_align = if place_content.is_some() {
+                    AlignItems::Start
+                } else {
+                    effective_align
+                };
+
+                let x_position = match effective_align {
+                    AlignItems::Start | AlignItems::Baseline => {
+                        self.x + padding.left + border_offset
+                    }
+                    AlignItems::Center => {
+                        let child_space = content_width.saturating_sub(child_ref.width);
+                        self.x + padding.left + border_offset + (child_space / 2)
+                    }
+                    AlignItems::End => {
+                        let child_space = content_width.saturating_sub(child_ref.width);
+                        self.x + padding.left + border_offset + child_space
+                    }
+                };
 
-                    // Add spacing based on justify mode
-                    if !is_last_relative_child {
-                        offset += item_spacing;
-                    } else if matches!(
-                        justify_content,
-                        JustifyContent::SpaceAround | JustifyContent::SpaceEvenly
-                    ) {
-                        // These modes need spacing after the last item too
-                        offset += item_spacing;
+                let y_offset =
+                    main_axis_position(reversed, offset, child_sizes[index], main_extent);
+                child_ref.set_position(x_position, self.y + padding.top + border_offset + y_offset);
+                offset += child_sizes[index];
+                // Add spacing after each child based on justify mode
+                // For SpaceBetween, add spacing after all children except the last
+                // For SpaceAround and SpaceEvenly, add spacing after all children
+                // For Start, Center, End, use regular gap spacing
+                let is_last_relative_child = {
+                    // Find if this is the last non-absolute child
+                    let mut last_idx = index;
+                    for i in (index + 1)..self.children.len() {
+                        if !absolute_children.contains(&i) {
+                            last_idx = i;
+                        }
                     }
+                    last_idx == index
+                };
+
+                // Add spacing based on justify mode
+                if !is_last_relative_child {
+                    offset += item_spacing;
+                } else if matches!(
+                    justify_content,
+                    JustifyContent::SpaceAround | JustifyContent::SpaceEvenly
+                ) {
+                    // These modes need spacing after the last item too
+                    offset += item_spacing;
                 }
             }
 
@@ -2138,37 +2521,35 @@ impl RenderNode {
             child_ref.layout_with_parent(content_width, content_height);
         }
 
-        // Second pass: position absolute/fixed children
+        if direction.is_horizontal() {
+            self.align_baselines(&absolute_children);
+        }
+
+        if overlap > 0 {
+            self.collapse_children(direction, overlap);
+        }
+
+        if let Some(place) = place_content {
+            let content_box = Rect::new(
+                self.x + padding.left + border_offset,
+                self.y + padding.top + border_offset,
+                content_width,
+                content_height,
+            );
+            self.place_children_block(place, direction, &absolute_children, content_box);
+        }
+
+        // Second pass: position and lay out absolute/fixed children. They are
+        // placed again afterwards, as right/bottom offsets and translation
+        // depend on the size the layout gives them
         for index in absolute_children {
             let child = &self.children[index];
             let mut child_ref = child.borrow_mut();
 
-            match child_ref.position_type {
-                Position::Fixed => {
-                    // Fixed positioning: relative to viewport (0, 0)
-                    self.position_absolute_child(
-                        &mut child_ref,
-                        0,
-                        0,
-                        content_width,
-                        content_height,
-                    );
-                }
-                Position::Absolute => {
-                    // Absolute positioning: relative to this container
-                    self.position_absolute_child(
-                        &mut child_ref,
-                        self.x,
-                        self.y,
-                        self.width,
-                        self.height,
-                    );
-                }
-                _ => {} // Already handled
-            }
-
-            // Layout the absolutely positioned child
+            self.place_out_of_flow_child(&mut child_ref, content_width, content_height);
+            child_ref.resolve_remaining_fractions(content_width, content_height);
             child_ref.layout_with_parent(content_width, content_height);
+            self.place_out_of_flow_child(&mut child_ref, content_width, content_height);
         }
 
         // Track content dimensions for scrolling
@@ -2189,6 +2570,285 @@ impl RenderNode {
                 }
             }
         }
+
+        // The scroll position outlives the content it was set against, so
+        // keep it within content that shrank since
+        self.scroll_y = if self.scrollable {
+            self.scroll_y.min(self.get_max_scroll_y())
+        } else {
+            0
+        };
+    }
+
+    /// Moves the packed block of in-flow children to `place` within `content_box`.
+    ///
+    /// Children are first laid out packed at the start of the content box. The
+    /// block they span is then moved as a whole, and each child is aligned on
+    /// the cross axis within the block by its `align_self` or the container's
+    /// `align_items`.
+    ///
+    /// ```text
+    /// ┌──────────────────┐
+    /// │                  │
+    /// │     ┌──────┐     │
+    /// │     │ ab   │     │  place_content: center
+    /// │     │ cdef │     │  align_items: start
+    /// │     └──────┘     │
+    /// │                  │
+    /// └──────────────────┘
+    /// ```
+    fn place_children_block(
+        &self,
+        place: AlignItems,
+        direction: Direction,
+        out_of_flow: &[usize],
+        content_box: Rect,
+    ) {
+        let in_flow = || {
+            self.children
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !out_of_flow.contains(index))
+                .map(|(_, child)| child)
+        };
+
+        let Some(block) = in_flow()
+            .map(|child| child.borrow().bounds())
+            .reduce(|block, bounds| block.union(&bounds))
+        else {
+            return;
+        };
+
+        let target_x =
+            content_box.x + align_offset(place, content_box.width.saturating_sub(block.width));
+        let target_y =
+            content_box.y + align_offset(place, content_box.height.saturating_sub(block.height));
+
+        let align_items = self
+            .style
+            .as_ref()
+            .and_then(|s| s.align_items)
+            .unwrap_or(AlignItems::Start);
+
+        for child in in_flow() {
+            let mut child_ref = child.borrow_mut();
+
+            let align = child_ref.cross_align(align_items);
+
+            let (cross_x, cross_y) = if !direction.is_horizontal() {
+                (
+                    align_offset(align, block.width.saturating_sub(child_ref.width)),
+                    0,
+                )
+            } else if align == AlignItems::Baseline {
+                // Lined up baselines keep their offsets within the block
+                (0, child_ref.y - block.y)
+            } else {
+                (
+                    0,
+                    align_offset(align, block.height.saturating_sub(child_ref.height)),
+                )
+            };
+
+            let dx = (target_x + cross_x) as i32 - block.x as i32;
+            let dy = (target_y + cross_y) as i32 - block.y as i32;
+            child_ref.translate(dx, dy);
+        }
+    }
+
+    /// Moves the baseline-aligned children of a horizontal layout down so
+    /// their baselines share the row of the lowest one.
+    fn align_baselines(&self, out_of_flow: &[usize]) {
+        let align_items = self
+            .style
+            .as_ref()
+            .and_then(|s| s.align_items)
+            .unwrap_or(AlignItems::Start);
+        let aligned: Vec<_> = self
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(index, child)| {
+                !out_of_flow.contains(index)
+                    && child.borrow().cross_align(align_items) == AlignItems::Baseline
+            })
+            .map(|(_, child)| child)
+            .collect();
+
+        let Some(line) = aligned
+            .iter()
+            .map(|child| {
+                let child_ref = child.borrow();
+                child_ref.y + child_ref.baseline()
+            })
+            .max()
+        else {
+            return;
+        };
+        for child in aligned {
+            let mut child_ref = child.borrow_mut();
+            let dy = line - (child_ref.y + child_ref.baseline());
+            child_ref.translate(0, dy as i32);
+        }
+    }
+
+    /// Cross axis alignment of this node, its `align_self` or else the parent's `align_items`.
+    fn cross_align(&self, align_items: AlignItems) -> AlignItems {
+        match self
+            .style
+            .as_ref()
+            .and_then(|s| s.align_self)
+            .unwrap_or(AlignSelf::Auto)
+        {
+            AlignSelf::Auto => align_items,
+            AlignSelf::Start => AlignItems::Start,
+            AlignSelf::Center => AlignItems::Center,
+            AlignSelf::End => AlignItems::End,
+            AlignSelf::Baseline => AlignItems::Baseline,
+        }
+    }
+
+    /// Row of the baseline from the top of this node.
+    ///
+    /// Text sits on its last line; other nodes have no text baseline of their
+    /// own, so their bottom row stands in for it.
+    fn baseline(&self) -> u16 {
+        self.height.saturating_sub(1)
+    }
+
+    /// Padding in cells, with a fraction resolved against the parent's content size.
+    pub(crate) fn padding(&self) -> Spacing {
+        let (width, height) = self.containing_size;
+        self.style
+            .as_ref()
+            .map(|s| s.resolved_padding(width, height))
+            .unwrap_or(Spacing::all(0))
+    }
+
+    /// Rows the border and padding take above and below the content.
+    pub(crate) fn vertical_insets(&self) -> (u16, u16) {
+        let padding = self.padding();
+        let border = u16::from(
+            self.style
+                .as_ref()
+                .and_then(|s| s.border.as_ref())
+                .is_some_and(|b| b.enabled),
+        );
+        (padding.top + border, padding.bottom + border)
+    }
+
+    /// Gap in cells, with a fraction resolved against the parent's content size
+    /// along the main axis.
+    fn gap(&self, direction: Direction) -> u16 {
+        let (width, height) = self.containing_size;
+        let main_size = if direction.is_horizontal() {
+            width
+        } else {
+            height
+        };
+        self.style
+            .as_ref()
+            .map(|s| s.resolved_gap(main_size))
+            .unwrap_or(0)
+    }
+
+    /// Whether in-flow children are collapsed onto this node's border and
+    /// each other's (`join_borders` outside wrapping layouts).
+    pub(crate) fn collapses_borders(&self) -> bool {
+        self.style.as_ref().is_some_and(|style| {
+            style.join_borders == Some(true)
+                && !matches!(style.wrap, Some(crate::style::WrapMode::Wrap))
+        })
+    }
+
+    /// Whether this node is taken out of its parent's flow.
+    fn is_out_of_flow(&self) -> bool {
+        self.style
+            .as_ref()
+            .and_then(|s| s.position)
+            .is_some_and(|position| matches!(position, Position::Absolute | Position::Fixed))
+    }
+
+    /// Slides each in-flow child back over its predecessor's trailing border.
+    ///
+    /// Children were laid out in a main axis `overlap` cells longer than the
+    /// content box, so after sliding they end flush with it again.
+    fn collapse_children(&self, direction: Direction, overlap: u16) {
+        let in_flow = self
+            .children
+            .iter()
+            .filter(|child| !child.borrow().is_out_of_flow());
+
+        for (index, child) in in_flow.enumerate() {
+            // Reversed layouts start from the far edge, which lies `overlap`
+            // cells past the content box
+            let shift = if direction.is_reversed() {
+                index as i32 - overlap as i32
+            } else {
+                -(index as i32)
+            };
+            let (dx, dy) = if direction.is_horizontal() {
+                (shift, 0)
+            } else {
+                (0, shift)
+            };
+            child.borrow_mut().translate(dx, dy);
+        }
+    }
+
+    /// Moves this node and its descendants by the given offset.
+    ///
+    /// Fixed-position descendants are anchored to the viewport and stay put.
+    fn translate(&mut self, dx: i32, dy: i32) {
+        self.x = (self.x as i32 + dx).clamp(0, u16::MAX as i32) as u16;
+        self.y = (self.y as i32 + dy).clamp(0, u16::MAX as i32) as u16;
+
+        for child in &self.children {
+            let mut child_ref = child.borrow_mut();
+            if child_ref.position_type != Position::Fixed {
+                child_ref.translate(dx, dy);
+            }
+        }
+    }
+
+    /// Keeps `flip_to_fit` descendants inside the viewport.
+    ///
+    /// An absolutely positioned child that would extend past the right or
+    /// bottom edge is mirrored across this node's box, so a submenu opening to
+    /// the right of its row opens to the left instead and a dropdown below its
+    /// anchor opens above it. If the mirrored position would leave the screen
+    /// on the other side, the child is shifted back just enough to fit.
+    pub(crate) fn flip_overflowing_children(&self, viewport_width: u16, viewport_height: u16) {
+        for child in &self.children {
+            let mut child_ref = child.borrow_mut();
+            let flips = child_ref.position_type == Position::Absolute
+                && child_ref
+                    .style
+                    .as_ref()
+                    .is_some_and(|style| style.flip_to_fit == Some(true));
+
+            if flips {
+                let dx = flip_offset(
+                    self.x,
+                    self.width,
+                    child_ref.x,
+                    child_ref.width,
+                    viewport_width,
+                );
+                let dy = flip_offset(
+                    self.y,
+                    self.height,
+                    child_ref.y,
+                    child_ref.height,
+                    viewport_height,
+                );
+                if dx != 0 || dy != 0 {
+                    child_ref.translate(dx, dy);
+                }
+            }
+
+            child_ref.flip_overflowing_children(viewport_width, viewport_height);
+        }
     }
 
     /// Calculates the actual content dimensions (may exceed container bounds).
@@ -2202,11 +2862,7 @@ impl RenderNode {
         }
 
         // Get padding values to account for them in content dimensions
-        let padding = self
-            .style
-            .as_ref()
-            .and_then(|s| s.padding)
-            .unwrap_or(Spacing::all(0));
+        let padding = self.padding();
 
         // Check if border is enabled
         let border_offset = if self
@@ -2260,7 +2916,30 @@ impl RenderNode {
         self.content_height = self.height.max(max_y);
     }
 
-    /// Positions an absolutely positioned child based on its offset properties.
+    /// Positions an absolute child in this container, or a fixed one in the viewport.
+    fn place_out_of_flow_child(
+        &self,
+        child: &mut RenderNode,
+        content_width: u16,
+        content_height: u16,
+    ) {
+        match child.position_type {
+            // Fixed positioning: relative to viewport (0, 0)
+            Position::Fixed => {
+                self.position_absolute_child(child, 0, 0, content_width, content_height)
+            }
+            // Absolute positioning: relative to this container
+            Position::Absolute => {
+                self.position_absolute_child(child, self.x, self.y, self.width, self.height)
+            }
+            _ => {} // Already handled
+        }
+    }
+
+    /// Moves an absolutely positioned child to its offsets.
+    ///
+    /// Percentage offsets resolve against the container, and `translate`
+    /// against the child's own size. The child's subtree moves along with it.
     fn position_absolute_child(
         &self,
         child: &mut RenderNode,
@@ -2273,26 +2952,34 @@ impl RenderNode {
             let mut x = container_x;
             let mut y = container_y;
 
-            // Apply position offsets
-            if let Some(left) = style.left {
+            // Apply position offsets, fractions relative to the container
+            let (top, right, bottom, left) =
+                style.resolved_offsets(container_width, container_height);
+            if let Some(left) = left {
                 x = container_x.saturating_add_signed(left);
-            } else if let Some(right) = style.right {
+            } else if let Some(right) = right {
                 // Position from right edge
                 x = (container_x + container_width)
                     .saturating_sub(child.width)
                     .saturating_add_signed(-right);
             }
 
-            if let Some(top) = style.top {
+            if let Some(top) = top {
                 y = container_y.saturating_add_signed(top);
-            } else if let Some(bottom) = style.bottom {
+            } else if let Some(bottom) = bottom {
                 // Position from bottom edge
                 y = (container_y + container_height)
                     .saturating_sub(child.height)
                     .saturating_add_signed(-bottom);
             }
 
-            child.set_position(x, y);
+            // Translation is relative to the child's own size
+            if let Some((translate_x, translate_y)) = style.translate {
+                x = x.saturating_add_signed(translate_x.resolve(child.width));
+                y = y.saturating_add_signed(translate_y.resolve(child.height));
+            }
+
+            child.translate(x as i32 - child.x as i32, y as i32 - child.y as i32);
         }
     }
 
@@ -2326,6 +3013,14 @@ impl RenderNode {
         for (k, handler, is_global) in &self.events.on_key {
             if *k == key && !is_global {
                 handler();
+                return;
+            }
+        }
+
+        // Predicates only see keys no specific handler took
+        for (predicate, handler, is_global) in &self.events.on_key_if {
+            if !is_global && predicate(key) {
+                handler(key);
                 break;
             }
         }
@@ -2341,6 +3036,11 @@ impl RenderNode {
                 // Don't break - allow multiple global handlers for same key
             }
         }
+        for (predicate, handler, is_global) in &self.events.on_key_if {
+            if *is_global && predicate(key) {
+                handler(key);
+            }
+        }
     }
 
     /// Checks if a handler is registered for the pressed key with modifiers
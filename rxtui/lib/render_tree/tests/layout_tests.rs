@@ -1,5 +1,8 @@
-use crate::render_tree::RenderNode;
-use crate::style::{Border, BorderStyle, Color, Dimension, Direction, Spacing, Style};
+use crate::render_tree::{RenderNode, RenderNodeType, RenderTree};
+use crate::style::{
+    AlignItems, Border, BorderStyle, Color, Dimension, Direction, JustifyContent, Offset, Overflow,
+    Position, Spacing, Style, TextAlign, TextStyle, TextWrap,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -370,6 +373,176 @@ fn test_auto_sizing_with_padding() {
     assert_eq!(child2_rc.borrow().x, 25, "Child 2 should be at x=25");
 }
 
+#[test]
+fn test_fractional_padding_and_gap_scale_with_size() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        padding_fraction: Some(0.05),
+        gap_fraction: Some(0.1),
+        width: Some(Dimension::Percentage(1.0)),
+        height: Some(Dimension::Percentage(1.0)),
+        ..Default::default()
+    });
+
+    let fixed = || {
+        let mut child = RenderNode::element();
+        child.style = Some(Style {
+            width: Some(Dimension::Fixed(5)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        });
+        Rc::new(RefCell::new(child))
+    };
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let child1_rc = fixed();
+    let child2_rc = fixed();
+    RenderNode::add_child_with_parent(&parent_rc, child1_rc.clone());
+    RenderNode::add_child_with_parent(&parent_rc, child2_rc.clone());
+
+    // 40x10: padding rounds to 2 columns and 1 row, the gap to 4 columns
+    parent_rc.borrow_mut().layout_with_parent(40, 10);
+    assert_eq!((child1_rc.borrow().x, child1_rc.borrow().y), (2, 1));
+    assert_eq!(child2_rc.borrow().x, 11);
+
+    // The same style resolves again for a larger terminal
+    parent_rc.borrow_mut().layout_with_parent(80, 20);
+    assert_eq!((child1_rc.borrow().x, child1_rc.borrow().y), (4, 1));
+    assert_eq!(child2_rc.borrow().x, 17);
+}
+
+#[test]
+fn test_fractional_padding_resolves_against_the_parent() {
+    let leaf = || {
+        let mut leaf = RenderNode::element();
+        leaf.style = Some(Style {
+            width: Some(Dimension::Fixed(5)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        });
+        Rc::new(RefCell::new(leaf))
+    };
+    let padded = |width| {
+        let mut panel = RenderNode::element();
+        panel.style = Some(Style {
+            width,
+            padding_fraction: Some(0.1),
+            ..Default::default()
+        });
+        Rc::new(RefCell::new(panel))
+    };
+
+    // Fixed, content-sized and auto panels all pad by a tenth of the 40x10
+    // parent, and a content-sized panel measures that padding around the leaf
+    for width in [Some(Dimension::Fixed(20)), None, Some(Dimension::Auto)] {
+        let mut parent = RenderNode::element();
+        parent.style = Some(Style {
+            direction: Some(Direction::Horizontal),
+            width: Some(Dimension::Fixed(40)),
+            height: Some(Dimension::Fixed(10)),
+            ..Default::default()
+        });
+        let parent_rc = Rc::new(RefCell::new(parent));
+        let panel_rc = padded(width);
+        let leaf_rc = leaf();
+        RenderNode::add_child_with_parent(&panel_rc, leaf_rc.clone());
+        RenderNode::add_child_with_parent(&parent_rc, panel_rc.clone());
+
+        parent_rc.borrow_mut().layout_with_parent(80, 24);
+        assert_eq!(
+            (leaf_rc.borrow().x, leaf_rc.borrow().y),
+            (4, 1),
+            "{width:?}"
+        );
+        if width.is_none() {
+            assert_eq!((panel_rc.borrow().width, panel_rc.borrow().height), (13, 3));
+        }
+    }
+}
+
+#[test]
+fn test_fractional_gap_resolves_against_the_parent() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(40)),
+        height: Some(Dimension::Fixed(10)),
+        ..Default::default()
+    });
+
+    // A content-sized row spaces its children by a tenth of the parent's width
+    let mut row = RenderNode::element();
+    row.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        gap_fraction: Some(0.1),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let row_rc = Rc::new(RefCell::new(row));
+    let children: Vec<_> = (0..2)
+        .map(|_| {
+            let mut child = RenderNode::element();
+            child.style = Some(Style {
+                width: Some(Dimension::Fixed(5)),
+                height: Some(Dimension::Fixed(1)),
+                ..Default::default()
+            });
+            let child_rc = Rc::new(RefCell::new(child));
+            RenderNode::add_child_with_parent(&row_rc, child_rc.clone());
+            child_rc
+        })
+        .collect();
+    RenderNode::add_child_with_parent(&parent_rc, row_rc.clone());
+
+    parent_rc.borrow_mut().layout_with_parent(80, 24);
+    assert_eq!(row_rc.borrow().width, 14);
+    assert_eq!(children[1].borrow().x, 9);
+}
+
+#[test]
+fn test_aspect_ratio_derives_unset_dimension() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::Vertical),
+        align_items: Some(AlignItems::Center),
+        width: Some(Dimension::Fixed(40)),
+        height: Some(Dimension::Fixed(20)),
+        ..Default::default()
+    });
+
+    let child = |width, height, ratio| {
+        let mut child = RenderNode::element();
+        child.style = Some(Style {
+            width,
+            height,
+            aspect_ratio: Some(ratio),
+            ..Default::default()
+        });
+        Rc::new(RefCell::new(child))
+    };
+
+    // A square from a percentage width, a 2:1 box from a fixed height, and a
+    // box whose fixed dimensions override the ratio
+    let square = child(Some(Dimension::Percentage(0.5)), None, 1.0);
+    let wide = child(None, Some(Dimension::Fixed(4)), 2.0);
+    let fixed = child(Some(Dimension::Fixed(6)), Some(Dimension::Fixed(2)), 1.0);
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    for child in [&square, &wide, &fixed] {
+        RenderNode::add_child_with_parent(&parent_rc, child.clone());
+    }
+    parent_rc.borrow_mut().layout_with_parent(40, 20);
+
+    let bounds = |node: &Rc<RefCell<RenderNode>>| {
+        let node = node.borrow();
+        (node.x, node.y, node.width, node.height)
+    };
+    assert_eq!(bounds(&square), (10, 0, 20, 10));
+    assert_eq!(bounds(&wide), (12, 10, 16, 4));
+    assert_eq!(bounds(&fixed), (17, 14, 6, 2));
+}
+
 #[test]
 fn test_no_space_for_auto() {
     // Create a parent with horizontal layout where fixed elements take all space
@@ -424,3 +597,597 @@ fn test_no_space_for_auto() {
         "Auto child should get 0 width when no space available"
     );
 }
+
+/// Lays out a 20-wide horizontal row with the given child widths and returns
+/// each child's (x, width).
+fn layout_justified_row(
+    justify: JustifyContent,
+    gap: u16,
+    widths: &[Dimension],
+) -> Vec<(u16, u16)> {
+    layout_row(Direction::Horizontal, justify, gap, widths)
+}
+
+/// Like [`layout_justified_row`], with an explicit row direction.
+fn layout_row(
+    direction: Direction,
+    justify: JustifyContent,
+    gap: u16,
+    widths: &[Dimension],
+) -> Vec<(u16, u16)> {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(direction),
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Fixed(3)),
+        justify_content: Some(justify),
+        gap: Some(gap),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let children: Vec<_> = widths
+        .iter()
+        .map(|&width| {
+            let mut child = RenderNode::element();
+            child.style = Some(Style {
+                width: Some(width),
+                height: Some(Dimension::Fixed(1)),
+                ..Default::default()
+            });
+            Rc::new(RefCell::new(child))
+        })
+        .collect();
+
+    for child in &children {
+        RenderNode::add_child_with_parent(&parent_rc, child.clone());
+    }
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    children
+        .iter()
+        .map(|child| (child.borrow().x, child.borrow().width))
+        .collect()
+}
+
+const ALL_JUSTIFY_MODES: [JustifyContent; 6] = [
+    JustifyContent::Start,
+    JustifyContent::Center,
+    JustifyContent::End,
+    JustifyContent::SpaceBetween,
+    JustifyContent::SpaceAround,
+    JustifyContent::SpaceEvenly,
+];
+
+#[test]
+fn test_justify_with_flex_child_packs_at_start() {
+    // Fixed 4, auto, fixed 3 with gap 1: the auto child takes 20 - 7 - 2 = 11
+    // and leaves no free space, so every mode produces the same packed row
+    for justify in ALL_JUSTIFY_MODES {
+        let row = layout_justified_row(
+            justify,
+            1,
+            &[Dimension::Fixed(4), Dimension::Auto, Dimension::Fixed(3)],
+        );
+        assert_eq!(row, vec![(0, 4), (5, 11), (17, 3)], "{justify:?}");
+    }
+}
+
+#[test]
+fn test_justify_with_single_flex_child_fills_row() {
+    for justify in ALL_JUSTIFY_MODES {
+        let row = layout_justified_row(justify, 2, &[Dimension::Auto]);
+        assert_eq!(row, vec![(0, 20)], "{justify:?}");
+    }
+}
+
+#[test]
+fn test_flex_children_absorb_remainder() {
+    // 20 - 3 (gaps) = 17 shared by 4 flexible children: 5, 4, 4, 4
+    let row = layout_justified_row(JustifyContent::SpaceBetween, 1, &[Dimension::Auto; 4]);
+    assert_eq!(row, vec![(0, 5), (6, 4), (11, 4), (16, 4)]);
+}
+
+#[test]
+fn test_justify_fixed_children_distribute_free_space() {
+    // Three 2-wide children with gap 1 leave 20 - 6 - 2 = 12 free cells
+    let widths = [Dimension::Fixed(2); 3];
+    let x_positions = |justify| {
+        layout_justified_row(justify, 1, &widths)
+            .into_iter()
+            .map(|(x, _)| x)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(x_positions(JustifyContent::Start), vec![0, 3, 6]);
+    assert_eq!(x_positions(JustifyContent::Center), vec![6, 9, 12]);
+    assert_eq!(x_positions(JustifyContent::End), vec![12, 15, 18]);
+    // Free space is added on top of the gap
+    assert_eq!(x_positions(JustifyContent::SpaceBetween), vec![0, 9, 18]);
+    assert_eq!(x_positions(JustifyContent::SpaceAround), vec![2, 9, 16]);
+    assert_eq!(x_positions(JustifyContent::SpaceEvenly), vec![3, 9, 15]);
+}
+
+#[test]
+fn test_distributing_justify_keeps_gap_and_fills_row() {
+    // Three 2-wide children with gap 2 leave 20 - 6 - 4 = 10 free cells,
+    // which are spread on top of the gap rather than in place of it
+    let widths = [Dimension::Fixed(2); 3];
+    let x_positions = |justify| {
+        layout_justified_row(justify, 2, &widths)
+            .into_iter()
+            .map(|(x, _)| x)
+            .collect::<Vec<_>>()
+    };
+
+    let between = x_positions(JustifyContent::SpaceBetween);
+    assert_eq!(between, vec![0, 9, 18]);
+    assert_eq!(between[2] + 2, 20, "the last child ends at the far edge");
+    assert_eq!(x_positions(JustifyContent::SpaceAround), vec![1, 8, 15]);
+    assert_eq!(x_positions(JustifyContent::SpaceEvenly), vec![2, 8, 14]);
+}
+
+#[test]
+fn test_reversed_row_justifies_from_far_edge() {
+    let widths = [Dimension::Fixed(2); 3];
+    let x_positions = |justify| {
+        layout_row(Direction::HorizontalReverse, justify, 1, &widths)
+            .into_iter()
+            .map(|(x, _)| x)
+            .collect::<Vec<_>>()
+    };
+
+    // The first child sits at the right edge and gaps count from there
+    assert_eq!(x_positions(JustifyContent::Start), vec![18, 15, 12]);
+    assert_eq!(x_positions(JustifyContent::Center), vec![12, 9, 6]);
+    assert_eq!(x_positions(JustifyContent::End), vec![6, 3, 0]);
+    assert_eq!(x_positions(JustifyContent::SpaceBetween), vec![18, 9, 0]);
+}
+
+#[test]
+fn test_reversed_row_with_flex_child() {
+    let row = layout_row(
+        Direction::HorizontalReverse,
+        JustifyContent::Start,
+        1,
+        &[Dimension::Fixed(4), Dimension::Auto, Dimension::Fixed(3)],
+    );
+    assert_eq!(row, vec![(16, 4), (4, 11), (0, 3)]);
+}
+
+#[test]
+fn test_vertical_reverse_stacks_from_bottom() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::VerticalReverse),
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(10)),
+        gap: Some(1),
+        border: Some(Border::new(Color::White)),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let children: Vec<_> = [2, 3]
+        .into_iter()
+        .map(|height| {
+            let mut child = RenderNode::element();
+            child.style = Some(Style {
+                height: Some(Dimension::Fixed(height)),
+                ..Default::default()
+            });
+            Rc::new(RefCell::new(child))
+        })
+        .collect();
+
+    for child in &children {
+        RenderNode::add_child_with_parent(&parent_rc, child.clone());
+    }
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Content box spans rows 1..9 inside the border
+    assert_eq!(children[0].borrow().y, 7);
+    assert_eq!(children[1].borrow().y, 3);
+}
+
+#[test]
+fn test_place_content_centers_aligned_text() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(40)),
+        height: Some(Dimension::Fixed(20)),
+        place_content: Some(AlignItems::Center),
+        ..Default::default()
+    });
+
+    // Left-aligned text would normally fill the row and stick to the left
+    let mut label = RenderNode::text("hello");
+    label.text_style = Some(TextStyle {
+        align: Some(TextAlign::Left),
+        ..Default::default()
+    });
+    let label = Rc::new(RefCell::new(label));
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    RenderNode::add_child_with_parent(&parent_rc, label.clone());
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    let label = label.borrow();
+    assert_eq!((label.x, label.y, label.width), (17, 9, 5));
+}
+
+#[test]
+fn test_ellipsis_fits_text_to_the_laid_out_parent() {
+    // Half of 24 columns and 40% of 10 rows, less padding: 10 columns by 2 lines
+    let fitted = |wrap: Option<TextWrap>| {
+        let mut parent = RenderNode::element();
+        parent.style = Some(Style {
+            width: Some(Dimension::Percentage(0.5)),
+            height: Some(Dimension::Percentage(0.4)),
+            padding: Some(Spacing::all(1)),
+            ..Default::default()
+        });
+
+        let mut label = RenderNode::text("Search by name or email");
+        label.text_style = Some(TextStyle {
+            wrap,
+            ellipsis: Some(true),
+            ..Default::default()
+        });
+        let label = Rc::new(RefCell::new(label));
+
+        let parent_rc = Rc::new(RefCell::new(parent));
+        RenderNode::add_child_with_parent(&parent_rc, label.clone());
+        parent_rc.borrow_mut().layout_with_parent(24, 10);
+
+        let label = label.borrow();
+        match &label.node_type {
+            RenderNodeType::Text(text) => vec![text.clone()],
+            RenderNodeType::TextWrapped(lines) => lines.clone(),
+            _ => unreachable!(),
+        }
+    };
+
+    assert_eq!(fitted(Some(TextWrap::Word)), ["Search by", "name or…"]);
+    // Without wrapping only one line is used
+    assert_eq!(fitted(None), ["Search by…"]);
+}
+
+#[test]
+fn test_place_content_aligns_children_within_block() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Fixed(10)),
+        place_content: Some(AlignItems::Center),
+        align_items: Some(AlignItems::Center),
+        // Ignored in favor of place_content
+        justify_content: Some(JustifyContent::End),
+        ..Default::default()
+    });
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    let children: Vec<_> = [4, 8]
+        .into_iter()
+        .map(|width| {
+            let mut child = RenderNode::element();
+            child.style = Some(Style {
+                width: Some(Dimension::Fixed(width)),
+                height: Some(Dimension::Fixed(1)),
+                ..Default::default()
+            });
+            Rc::new(RefCell::new(child))
+        })
+        .collect();
+
+    for child in &children {
+        RenderNode::add_child_with_parent(&parent_rc, child.clone());
+    }
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // The 8x2 block sits at (6, 4); the narrow child centers within it
+    let positions: Vec<_> = children
+        .iter()
+        .map(|child| (child.borrow().x, child.borrow().y))
+        .collect();
+    assert_eq!(positions, vec![(8, 4), (6, 5)]);
+}
+
+#[test]
+fn test_fraction_remaining_shares_space_left_by_siblings() {
+    // 20 - 4 (fixed) = 16 left: half goes to the fraction, the rest to the auto child
+    let row = layout_justified_row(
+        JustifyContent::Start,
+        0,
+        &[
+            Dimension::Fixed(4),
+            Dimension::FractionRemaining(0.5),
+            Dimension::Auto,
+        ],
+    );
+    assert_eq!(row, vec![(0, 4), (4, 8), (12, 8)]);
+}
+
+#[test]
+fn test_fraction_remaining_over_one_is_scaled_down() {
+    // 20 - 5 (fixed) - 3 (gaps) = 12 left. The fractions add up to 1.5, so each
+    // gets 12 * 0.5 = 6, and the auto child gets nothing rather than overflowing
+    let row = layout_justified_row(
+        JustifyContent::Start,
+        1,
+        &[
+            Dimension::FractionRemaining(0.75),
+            Dimension::FractionRemaining(0.75),
+            Dimension::Fixed(5),
+            Dimension::Auto,
+        ],
+    );
+    assert_eq!(row, vec![(0, 6), (7, 6), (14, 5), (20, 0)]);
+}
+
+#[test]
+fn test_flip_to_fit_keeps_overlays_on_screen() {
+    let mut root = RenderNode::element();
+    root.style = Some(Style {
+        width: Some(Dimension::Fixed(80)),
+        height: Some(Dimension::Fixed(24)),
+        ..Default::default()
+    });
+    let root = Rc::new(RefCell::new(root));
+
+    // An anchor near the bottom-right corner with a dropdown below it, and one
+    // near the left edge whose dropdown is too wide to mirror
+    let mut overlays = Vec::new();
+    for (left, top, width) in [(70, 22, 20), (10, 0, 78)] {
+        let mut anchor = RenderNode::element();
+        anchor.style = Some(Style {
+            position: Some(Position::Absolute),
+            left: Some(left),
+            top: Some(top),
+            width: Some(Dimension::Fixed(4)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        });
+        let anchor = Rc::new(RefCell::new(anchor));
+        RenderNode::add_child_with_parent(&root, anchor.clone());
+
+        let mut dropdown = RenderNode::element();
+        dropdown.style = Some(Style {
+            position: Some(Position::Absolute),
+            top: Some(1),
+            left: Some(0),
+            width: Some(Dimension::Fixed(width)),
+            height: Some(Dimension::Fixed(5)),
+            flip_to_fit: Some(true),
+            ..Default::default()
+        });
+        let dropdown = Rc::new(RefCell::new(dropdown));
+        RenderNode::add_child_with_parent(&anchor, dropdown.clone());
+        overlays.push(dropdown);
+    }
+
+    root.borrow_mut().layout_with_parent(80, 24);
+    root.borrow().flip_overflowing_children(80, 24);
+
+    // Mirrored above and to the left of its anchor, right edges aligned
+    let flipped = overlays[0].borrow();
+    assert_eq!((flipped.x, flipped.y), (54, 17));
+
+    // Fits below, but can only be shifted back horizontally
+    let shifted = overlays[1].borrow();
+    assert_eq!((shifted.x, shifted.y), (2, 1));
+}
+
+#[test]
+fn test_percentage_offsets_and_translate() {
+    let mut root = RenderNode::element();
+    root.style = Some(Style {
+        width: Some(Dimension::Fixed(40)),
+        height: Some(Dimension::Fixed(20)),
+        ..Default::default()
+    });
+    let root = Rc::new(RefCell::new(root));
+
+    let place = |style: Style| {
+        let mut child = RenderNode::element();
+        child.style = Some(Style {
+            position: Some(Position::Absolute),
+            width: Some(Dimension::Fixed(10)),
+            height: Some(Dimension::Fixed(4)),
+            ..style
+        });
+        let child = Rc::new(RefCell::new(child));
+        RenderNode::add_child_with_parent(&root, child.clone());
+        child
+    };
+
+    // Centered on both axes: 50% of the container minus half of the child
+    let centered = place(Style {
+        left_fraction: Some(0.5),
+        top_fraction: Some(0.5),
+        translate: Some((Offset::percent(-0.5), Offset::percent(-0.5))),
+        ..Default::default()
+    });
+    // Percentages from the far edges, then nudged by whole cells
+    let corner = place(Style {
+        right_fraction: Some(0.25),
+        bottom_fraction: Some(0.1),
+        translate: Some((Offset::Cells(1), Offset::Cells(-1))),
+        ..Default::default()
+    });
+
+    root.borrow_mut().layout_with_parent(40, 20);
+
+    let centered = centered.borrow();
+    assert_eq!((centered.x, centered.y), (15, 8));
+    let corner = corner.borrow();
+    assert_eq!((corner.x, corner.y), (21, 13));
+}
+
+#[test]
+fn test_layout_string_lists_boxes_and_scroll_state() {
+    let root = Rc::new(RefCell::new(RenderNode::element()));
+    root.borrow_mut().style = Some(Style {
+        direction: Some(Direction::Vertical),
+        width: Some(Dimension::Fixed(12)),
+        height: Some(Dimension::Fixed(3)),
+        padding: Some(Spacing::horizontal(1)),
+        overflow: Some(Overflow::Scroll),
+        ..Default::default()
+    });
+    for line in ["one", "two", "three", "four"] {
+        let text = Rc::new(RefCell::new(RenderNode::text(line)));
+        RenderNode::add_child_with_parent(&root, text);
+    }
+
+    let mut tree = RenderTree::new();
+    tree.set_root(root.clone());
+    tree.layout(40, 10);
+    root.borrow_mut().set_scroll_y(1);
+
+    assert_eq!(
+        tree.layout_string(),
+        "Div @ (0, 0) [12x3] content [12x4] dir=vertical w=12 h=3 pad=(0,1,0,1) \
+         overflow=scroll scrollable scroll_y=1\n  \
+         Text @ (1, 0) [3x1] \"one\"\n  \
+         Text @ (1, 1) [3x1] \"two\"\n  \
+         Text @ (1, 2) [5x1] \"three\"\n  \
+         Text @ (1, 3) [4x1] \"four\"\n"
+    );
+}
+
+/// A bordered, scrolling column of `rows` one-line texts, `height` rows tall
+fn scrolling_column(rows: usize, height: u16) -> (Rc<RefCell<RenderNode>>, RenderTree) {
+    let root = Rc::new(RefCell::new(RenderNode::element()));
+    root.borrow_mut().style = Some(Style {
+        direction: Some(Direction::Vertical),
+        width: Some(Dimension::Fixed(12)),
+        height: Some(Dimension::Fixed(height)),
+        overflow: Some(Overflow::Scroll),
+        border: Some(Border::new(Color::White)),
+        ..Default::default()
+    });
+    for row in 0..rows {
+        let text = Rc::new(RefCell::new(RenderNode::text(format!("row {row}"))));
+        RenderNode::add_child_with_parent(&root, text);
+    }
+
+    let mut tree = RenderTree::new();
+    tree.set_root(root.clone());
+    tree.layout(40, 20);
+    (root, tree)
+}
+
+#[test]
+fn test_scroll_into_view_moves_as_little_as_needed() {
+    // Three visible rows inside the border
+    let (root, tree) = scrolling_column(8, 5);
+    let row = |index: usize| root.borrow().children[index].clone();
+
+    assert!(tree.scroll_into_view(&row(5)));
+    assert_eq!(root.borrow().scroll_y, 3);
+
+    // Already visible rows leave the scroll alone
+    assert!(!tree.scroll_into_view(&row(4)));
+    assert_eq!(root.borrow().scroll_y, 3);
+
+    assert!(tree.scroll_into_view(&row(1)));
+    assert_eq!(root.borrow().scroll_y, 1);
+}
+
+#[test]
+fn test_scroll_into_view_aligns_tall_nodes_to_the_top() {
+    let (root, mut tree) = scrolling_column(2, 4);
+    let tall = Rc::new(RefCell::new(RenderNode::element()));
+    tall.borrow_mut().style = Some(Style {
+        height: Some(Dimension::Fixed(5)),
+        ..Default::default()
+    });
+    RenderNode::add_child_with_parent(&root, tall.clone());
+    tree.layout(40, 20);
+
+    tree.scroll_into_view(&tall);
+    assert_eq!(root.borrow().scroll_y, 2);
+}
+
+#[test]
+fn test_scroll_is_clamped_when_content_shrinks() {
+    // Scrolled to the bottom of eight rows, three of them visible
+    let (root, mut tree) = scrolling_column(8, 5);
+    root.borrow_mut().set_scroll_y(5);
+
+    // Three rows removed leave two to scroll past
+    root.borrow_mut().children.truncate(5);
+    tree.layout(40, 20);
+    assert_eq!(root.borrow().get_max_scroll_y(), 2);
+    assert_eq!(root.borrow().scroll_y, 2);
+
+    // Content that fits resets it, and growing content doesn't bring it back
+    root.borrow_mut().children.truncate(2);
+    tree.layout(40, 20);
+    assert_eq!(root.borrow().scroll_y, 0);
+    for row in 2..8 {
+        let text = Rc::new(RefCell::new(RenderNode::text(format!("row {row}"))));
+        RenderNode::add_child_with_parent(&root, text);
+    }
+    tree.layout(40, 20);
+    assert_eq!(root.borrow().scroll_y, 0);
+}
+
+#[test]
+fn test_hit_test_reports_scrolled_element_and_local_point() {
+    use crate::bounds::Rect;
+    use crate::component::ComponentId;
+
+    // Eight rows, six cells wide, scrolled down by two inside the border
+    let (root, mut tree) = scrolling_column(0, 5);
+    for row in 0..8 {
+        let node = Rc::new(RefCell::new(RenderNode::element()));
+        node.borrow_mut().style = Some(Style {
+            width: Some(Dimension::Fixed(6)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        });
+        node.borrow_mut().component_path = Some(ComponentId::new(format!("row{row}")));
+        RenderNode::add_child_with_parent(&root, node);
+    }
+    tree.layout(40, 20);
+    root.borrow_mut().set_scroll_y(2);
+
+    let hit = tree.hit_test(3, 2).unwrap();
+    assert_eq!(hit.component, Some(ComponentId::new("row3")));
+    assert_eq!(hit.bounds, Rect::new(1, 2, 6, 1));
+    assert_eq!(hit.local, (2, 0));
+
+    // Rows scrolled above the screen are gone rather than piled on row 0
+    let hit = tree.hit_test(3, 0).unwrap();
+    assert_eq!(hit.component, Some(ComponentId::new("row1")));
+    assert_eq!(hit.bounds, Rect::new(1, 0, 6, 1));
+
+    // Past the rows, the column itself is hit
+    let hit = tree.hit_test(9, 2).unwrap();
+    assert_eq!(hit.component, None);
+    assert_eq!((hit.bounds, hit.local), (Rect::new(0, 0, 12, 5), (9, 2)));
+    assert!(tree.hit_test(20, 2).is_none());
+}
+
+#[test]
+fn test_focus_moves_reveal_after_layout() {
+    let (root, mut tree) = scrolling_column(8, 5);
+    for child in &root.borrow().children {
+        child.borrow_mut().focusable = true;
+    }
+
+    // Focusing backwards from nothing lands on the last row
+    tree.focus_prev();
+    tree.layout(40, 20);
+    assert_eq!(root.borrow().scroll_y, 5);
+
+    tree.clear_reveals();
+    root.borrow_mut().set_scroll_y(0);
+    tree.layout(40, 20);
+    assert_eq!(root.borrow().scroll_y, 0);
+}
@@ -0,0 +1,120 @@
+use crate::component::ComponentId;
+use crate::key::Key;
+use crate::render_tree::{RenderNode, RenderTree};
+use crate::style::{Dimension, Style};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn element(style: Style) -> Rc<RefCell<RenderNode>> {
+    let mut node = RenderNode::element();
+    node.style = Some(style);
+    Rc::new(RefCell::new(node))
+}
+
+fn tree_with(root: Rc<RefCell<RenderNode>>) -> RenderTree {
+    let mut tree = RenderTree::new();
+    tree.set_root(root);
+    tree.layout(40, 10);
+    tree
+}
+
+#[test]
+fn test_percentage_inside_content_sized_parent_warns() {
+    let root = element(Style::default());
+    let parent = element(Style::default());
+    let child = element(Style {
+        width: Some(Dimension::Percentage(0.5)),
+        height: Some(Dimension::Fixed(1)),
+        ..Default::default()
+    });
+    RenderNode::add_child_with_parent(&parent, child);
+    RenderNode::add_child_with_parent(&root, parent);
+
+    let warnings = tree_with(root).layout_warnings();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].starts_with("component <unknown>, div at (0, 0) [1x1]"));
+    assert!(warnings[0].contains("percentage width inside a parent sized by its content"));
+}
+
+#[test]
+fn test_percentage_inside_sized_parent_does_not_warn() {
+    let root = element(Style {
+        width: Some(Dimension::Auto),
+        height: Some(Dimension::Fixed(4)),
+        ..Default::default()
+    });
+    let child = element(Style {
+        width: Some(Dimension::Percentage(0.5)),
+        height: Some(Dimension::Percentage(0.5)),
+        ..Default::default()
+    });
+    RenderNode::add_child_with_parent(&root, child);
+
+    assert_eq!(tree_with(root).layout_warnings(), Vec::<String>::new());
+}
+
+#[test]
+fn test_key_handlers_without_focusable_warn() {
+    let root = element(Style {
+        width: Some(Dimension::Fixed(10)),
+        height: Some(Dimension::Fixed(2)),
+        ..Default::default()
+    });
+    let local = element(Style::default());
+    local.borrow_mut().component_path = Some(ComponentId::new("0.1"));
+    local
+        .borrow_mut()
+        .events
+        .on_key
+        .push((Key::Enter, Rc::new(|| {}), false));
+    let global = element(Style::default());
+    global
+        .borrow_mut()
+        .events
+        .on_key
+        .push((Key::Esc, Rc::new(|| {}), true));
+    RenderNode::add_child_with_parent(&root, local);
+    RenderNode::add_child_with_parent(&root, global);
+
+    let warnings = tree_with(root.clone()).layout_warnings();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].starts_with("component 0.1, div at (0, 0)"));
+    assert!(warnings[0].contains("isn't focusable"));
+
+    root.borrow().children[0].borrow_mut().focusable = true;
+    assert!(tree_with(root).layout_warnings().is_empty());
+}
+
+#[test]
+fn test_zero_size_parent_and_conflicting_bounds_warn() {
+    let root = element(Style::default());
+    let collapsed = element(Style {
+        width: Some(Dimension::Fixed(0)),
+        height: Some(Dimension::Fixed(2)),
+        ..Default::default()
+    });
+    RenderNode::add_child_with_parent(&collapsed, Rc::new(RefCell::new(RenderNode::text("hi"))));
+    let bounded = element(Style {
+        width: Some(Dimension::Fixed(30)),
+        max_width: Some(20),
+        min_height: Some(5),
+        max_height: Some(3),
+        ..Default::default()
+    });
+    RenderNode::add_child_with_parent(&root, collapsed);
+    RenderNode::add_child_with_parent(&root, bounded);
+
+    let warnings = tree_with(root).layout_warnings();
+    let messages: Vec<_> = warnings
+        .iter()
+        .map(|warning| warning.split_once("]: ").unwrap().1)
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "laid out at 0x2 with 1 children, which are not visible",
+            "width 30 is above max_width 20",
+            "min_height 5 is larger than max_height 3",
+        ]
+    );
+}
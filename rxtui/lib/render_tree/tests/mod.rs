@@ -1,4 +1,5 @@
 mod layout_tests;
+mod lint_tests;
 mod rich_text_tests;
 mod sizing_tests;
 mod wrapping_tests;
@@ -141,6 +141,84 @@ fn test_element_wrap_with_fixed_width() {
     assert_eq!(parent_ref.height, 8, "Parent should expand to fit 3 rows");
 }
 
+#[test]
+fn test_element_wrap_with_percentage_width() {
+    // A full-width wrapping row inside a content-height parent
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Content),
+        ..Default::default()
+    });
+
+    let mut row = RenderNode::element();
+    row.style = Some(Style {
+        direction: Some(Direction::Horizontal),
+        width: Some(Dimension::Percentage(1.0)),
+        height: Some(Dimension::Content),
+        wrap: Some(WrapMode::Wrap),
+        ..Default::default()
+    });
+    let row_rc = Rc::new(RefCell::new(row));
+
+    for _ in 0..3 {
+        let mut child = RenderNode::element();
+        child.style = Some(Style {
+            width: Some(Dimension::Fixed(8)),
+            height: Some(Dimension::Fixed(1)),
+            ..Default::default()
+        });
+        RenderNode::add_child_with_parent(&row_rc, Rc::new(RefCell::new(child)));
+    }
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    RenderNode::add_child_with_parent(&parent_rc, row_rc.clone());
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Two children fit in 20 cells, the third wraps, and both levels grow to fit
+    assert_eq!(row_rc.borrow().height, 2);
+    assert_eq!(parent_rc.borrow().height, 2);
+}
+
+#[test]
+fn test_reversed_wrap_fills_rows_from_right() {
+    let mut parent = RenderNode::element();
+    parent.style = Some(Style {
+        direction: Some(Direction::HorizontalReverse),
+        width: Some(Dimension::Fixed(20)),
+        height: Some(Dimension::Content),
+        wrap: Some(WrapMode::Wrap),
+        gap: Some(1),
+        ..Default::default()
+    });
+
+    let children: Vec<_> = (0..3)
+        .map(|_| {
+            let mut child = RenderNode::element();
+            child.style = Some(Style {
+                width: Some(Dimension::Fixed(8)),
+                height: Some(Dimension::Fixed(2)),
+                ..Default::default()
+            });
+            Rc::new(RefCell::new(child))
+        })
+        .collect();
+
+    let parent_rc = Rc::new(RefCell::new(parent));
+    for child in &children {
+        RenderNode::add_child_with_parent(&parent_rc, child.clone());
+    }
+
+    parent_rc.borrow_mut().layout_with_parent(100, 50);
+
+    // Rows still stack downward; items within a row run right to left
+    let positions: Vec<_> = children
+        .iter()
+        .map(|child| (child.borrow().x, child.borrow().y))
+        .collect();
+    assert_eq!(positions, vec![(12, 0), (3, 0), (12, 3)]);
+}
+
 #[test]
 fn test_element_wrap_with_percentage_children() {
     // Create a parent with wrapping and fixed width
@@ -1,8 +1,11 @@
+use crate::app::FocusDirection;
 use crate::bounds::Rect;
 use crate::component::ComponentId;
+use crate::render_tree::lint::{self, LayoutWarning};
 use crate::render_tree::node::{RenderNode, RenderNodeType};
-use crate::style::{Dimension, Direction, Overflow};
-use std::cell::RefCell;
+use crate::style::{Dimension, Direction, Overflow, Position, Style};
+use crossterm::event::MouseButton;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 use std::sync::{
     Arc,
@@ -13,6 +16,37 @@ use std::sync::{
 // Types
 //--------------------------------------------------------------------------------------------------
 
+/// A stacking context or portaled child deferred during hit testing: (node, clip, scroll offset)
+type HitLayer = (Rc<RefCell<RenderNode>>, Option<Rect>, i16);
+
+/// The element drawn at a point on the screen, as found by [`App::hit_test`].
+///
+/// [`App::hit_test`]: crate::App::hit_test
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hit {
+    /// Component whose view built the element
+    pub component: Option<ComponentId>,
+
+    /// Where the element is drawn, after scrolling, cut at the top of the screen
+    pub bounds: Rect,
+
+    /// The point relative to the element's top-left corner, scrolling included
+    pub local: (u16, u16),
+}
+
+/// Where a focused element sits, as passed to `on_focus_change` handlers.
+///
+/// Elements are told apart even when one component renders several of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementPath {
+    /// Component whose view built the element
+    pub component: Option<ComponentId>,
+
+    /// Child indices from that component's root element down to the element,
+    /// empty for the root element itself
+    pub indices: Vec<usize>,
+}
+
 /// Container for the render tree with layout capabilities.
 ///
 /// The render tree maintains the root node and provides
@@ -27,8 +61,20 @@ pub struct RenderTree {
     /// The currently hovered node (uses RefCell for interior mutability)
     hovered_node: RefCell<Option<Rc<RefCell<RenderNode>>>>,
 
+    /// The node the left mouse button is held down on
+    active_node: RefCell<Option<Rc<RefCell<RenderNode>>>>,
+
+    /// Right or middle button press awaiting release: (button, column, row)
+    pending_press: Cell<Option<(MouseButton, u16, u16)>>,
+
     /// Tracks whether a focus clear has been requested this frame
     pending_focus_clear: Arc<AtomicBool>,
+
+    /// Nodes to scroll into view once the next layout has placed them
+    pending_reveals: RefCell<Vec<Rc<RefCell<RenderNode>>>>,
+
+    /// Viewport width, height and `unclamped_height` of the last layout
+    last_layout: Option<(u16, u16, bool)>,
 }
 
 //--------------------------------------------------------------------------------------------------
@@ -42,7 +88,11 @@ impl RenderTree {
             root: None,
             focused_node: RefCell::new(None),
             hovered_node: RefCell::new(None),
+            active_node: RefCell::new(None),
+            pending_press: Cell::new(None),
             pending_focus_clear: Arc::new(AtomicBool::new(false)),
+            pending_reveals: RefCell::new(Vec::new()),
+            last_layout: None,
         }
     }
 
@@ -192,6 +242,90 @@ impl RenderTree {
         }
     }
 
+    /// Returns the computed layout of the tree as indented text.
+    ///
+    /// Each line is one node: its kind, position and box size, then for divs
+    /// the size of their content, scroll state and a summary of the layout
+    /// style they were given. Unlike [`debug_string`](Self::debug_string)
+    /// it leaves out colors and dirty flags, so two layouts of the same UI
+    /// compare equal and diff cleanly.
+    ///
+    /// ```text
+    /// Div @ (0, 0) [20x5] content [18x6] dir=vertical pad=(0,1,0,1) overflow=scroll scrollable scroll_y=1
+    ///   Text @ (1, 0) [5x1] "hello"
+    /// ```
+    pub fn layout_string(&self) -> String {
+        let mut output = String::new();
+        if let Some(root) = &self.root {
+            Self::layout_node(&root.borrow(), &mut output, 0);
+        }
+        output
+    }
+
+    /// Returns warnings about layout mistakes in the laid out tree, such as
+    /// percentage sizes inside content-sized parents.
+    pub fn layout_warnings(&self) -> Vec<String> {
+        self.lint().iter().map(ToString::to_string).collect()
+    }
+
+    /// Returns the layout warnings with the component and message kept apart.
+    pub(crate) fn lint(&self) -> Vec<LayoutWarning> {
+        self.root
+            .as_ref()
+            .map_or_else(Vec::new, |root| lint::layout_warnings(&root.borrow()))
+    }
+
+    /// Appends the layout line of a node and then those of its children.
+    fn layout_node(node: &RenderNode, output: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let kind = match &node.node_type {
+            RenderNodeType::Element => "Div",
+            RenderNodeType::Text(_) | RenderNodeType::TextWrapped(_) => "Text",
+            RenderNodeType::RichText(_) | RenderNodeType::RichTextWrapped(_) => "RichText",
+        };
+        output.push_str(&format!(
+            "{indent}{kind} @ ({}, {}) [{}x{}]",
+            node.x, node.y, node.width, node.height
+        ));
+
+        match &node.node_type {
+            RenderNodeType::Element => {
+                output.push_str(&format!(
+                    " content [{}x{}]",
+                    node.content_width, node.content_height
+                ));
+                if let Some(style) = &node.style {
+                    output.push_str(&style_summary(style));
+                }
+                if node.scrollable {
+                    output.push_str(&format!(" scrollable scroll_y={}", node.scroll_y));
+                }
+            }
+            RenderNodeType::Text(content) => {
+                output.push_str(&format!(" {content:?}"));
+            }
+            RenderNodeType::TextWrapped(lines) => {
+                output.push_str(&format!(" {:?}", lines.join("\n")));
+            }
+            RenderNodeType::RichText(spans) => {
+                let content: String = spans.iter().map(|span| span.content.as_str()).collect();
+                output.push_str(&format!(" {content:?}"));
+            }
+            RenderNodeType::RichTextWrapped(lines) => {
+                let content: Vec<String> = lines
+                    .iter()
+                    .map(|line| line.iter().map(|span| span.content.as_str()).collect())
+                    .collect();
+                output.push_str(&format!(" {:?}", content.join("\n")));
+            }
+        }
+        output.push('\n');
+
+        for child in &node.children {
+            Self::layout_node(&child.borrow(), output, depth + 1);
+        }
+    }
+
     /// Sets the root node of the render tree.
     pub fn set_root(&mut self, root: Rc<RefCell<RenderNode>>) {
         self.root = Some(root);
@@ -205,6 +339,12 @@ impl RenderTree {
         self.layout_with_options(viewport_width, viewport_height, false);
     }
 
+    /// Returns the viewport width, height and `unclamped_height` the tree was
+    /// last laid out with, if it has been laid out.
+    pub fn last_layout(&self) -> Option<(u16, u16, bool)> {
+        self.last_layout
+    }
+
     /// Performs layout with additional options for inline rendering mode.
     ///
     /// When `unclamped_height` is true, height is not clamped to the viewport.
@@ -215,12 +355,16 @@ impl RenderTree {
         viewport_height: u16,
         unclamped_height: bool,
     ) {
+        self.last_layout = Some((viewport_width, viewport_height, unclamped_height));
         if let Some(root) = &self.root {
             let mut root_ref = root.borrow_mut();
             root_ref.set_position(0, 0);
+            root_ref.containing_size = (viewport_width, viewport_height);
 
-            // Calculate intrinsic size for content-based dimensions
-            let (intrinsic_width, intrinsic_height) = root_ref.calculate_intrinsic_size();
+            // Calculate intrinsic size for content-based dimensions, wrapping
+            // content that fills the viewport at the viewport width
+            let (intrinsic_width, intrinsic_height) =
+                root_ref.calculate_intrinsic_size_with_hint(viewport_width, viewport_height);
 
             // For the root node, resolve dimensions using viewport as parent
             if let Some(style) = &root_ref.style {
@@ -233,7 +377,7 @@ impl RenderTree {
                     Some(Dimension::Fixed(w)) => {
                         root_ref.width = w.min(viewport_width);
                     }
-                    Some(Dimension::Percentage(pct)) => {
+                    Some(Dimension::Percentage(pct) | Dimension::FractionRemaining(pct)) => {
                         let calculated_width = (viewport_width as f32 * pct) as u16;
                         root_ref.width = calculated_width.max(1).min(viewport_width);
                     }
@@ -260,7 +404,7 @@ impl RenderTree {
                             h.min(viewport_height)
                         };
                     }
-                    Some(Dimension::Percentage(pct)) => {
+                    Some(Dimension::Percentage(pct) | Dimension::FractionRemaining(pct)) => {
                         let calculated_height = (viewport_height as f32 * pct) as u16;
                         root_ref.height = if unclamped_height {
                             calculated_height.max(1)
@@ -306,20 +450,104 @@ impl RenderTree {
                 .and_then(|s| s.direction)
                 .unwrap_or(Direction::Vertical);
             root_ref.layout_children_with_parent(direction);
+
+            // Inline mode lets content grow past the viewport, so overlays may too
+            let bottom = if unclamped_height {
+                root_ref.height.max(viewport_height)
+            } else {
+                viewport_height
+            };
+            root_ref.flip_overflowing_children(viewport_width, bottom);
+        }
+
+        // Positions are final now, so requested nodes can be revealed
+        for node in self.pending_reveals.borrow().iter() {
+            self.scroll_into_view(node);
         }
     }
 
     /// Finds the topmost node at the given terminal coordinates.
     ///
     /// Used for mouse event handling. Returns the deepest node
-    /// in the tree that contains the given point.
+    /// in the tree that contains the given point. Positioned elements with
+    /// a z-index are tested in the same stacking order they are painted in,
+    /// so the element on top receives the event even when it comes from a
+    /// different subtree. Portaled elements are tested before everything else.
     pub fn find_node_at(&self, x: u16, y: u16) -> Option<Rc<RefCell<RenderNode>>> {
-        if let Some(root) = &self.root {
-            // Start with no clipping and no scroll offset
-            Self::find_node_at_recursive(root, x, y, None, 0)
-        } else {
-            None
+        // Start with no clipping and no scroll offset
+        let root = self.root.as_ref()?;
+        let mut portals = Vec::new();
+        let hit = Self::find_node_in_context(root, x, y, None, 0, &mut portals);
+        Self::find_node_in_portals(portals, x, y).or(hit)
+    }
+
+    /// Returns the topmost element at the given terminal coordinates.
+    ///
+    /// This is the element a click there goes to, see
+    /// [`find_node_at`](Self::find_node_at), described by its component and
+    /// where it is drawn.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<Hit> {
+        self.find_node_at(x, y).map(|node| Hit::of(&node, x, y))
+    }
+
+    /// Searches portaled subtrees in reverse paint order.
+    ///
+    /// Portals found inside these ones paint after all of them, so they are
+    /// tested first; then the highest layer, and the later one in the tree
+    /// among equal layers. Portals are clipped only by the viewport.
+    fn find_node_in_portals(
+        mut portals: Vec<HitLayer>,
+        x: u16,
+        y: u16,
+    ) -> Option<Rc<RefCell<RenderNode>>> {
+        if portals.is_empty() {
+            return None;
         }
+
+        portals.sort_by_key(|(portal, _, _)| portal.borrow().portal_layer());
+        let mut nested = Vec::new();
+        let hits: Vec<_> = portals
+            .iter()
+            .map(|(portal, _, offset)| {
+                Self::find_node_in_context(portal, x, y, None, *offset, &mut nested)
+            })
+            .collect();
+
+        Self::find_node_in_portals(nested, x, y).or_else(|| hits.into_iter().rev().flatten().next())
+    }
+
+    /// Searches a stacking context, testing its layers before its flow content.
+    ///
+    /// Layers are tested from the highest z-index down, and among equal
+    /// z-indices the one later in the tree first, mirroring the paint order.
+    /// Portaled descendants are set aside in `portals`.
+    fn find_node_in_context(
+        context: &Rc<RefCell<RenderNode>>,
+        x: u16,
+        y: u16,
+        clip_rect: Option<Rect>,
+        scroll_offset: i16,
+        portals: &mut Vec<HitLayer>,
+    ) -> Option<Rc<RefCell<RenderNode>>> {
+        let mut layers = Vec::new();
+        let flow_hit = Self::find_node_at_recursive(
+            context,
+            x,
+            y,
+            clip_rect,
+            scroll_offset,
+            &mut layers,
+            portals,
+        );
+
+        layers.sort_by(|(a, _, _), (b, _, _)| a.borrow().z_index.total_cmp(&b.borrow().z_index));
+        layers
+            .iter()
+            .rev()
+            .find_map(|(layer, clip, offset)| {
+                Self::find_node_in_context(layer, x, y, *clip, *offset, portals)
+            })
+            .or(flow_hit)
     }
 
     /// Recursively searches for a node containing the given point.
@@ -329,25 +557,23 @@ impl RenderTree {
     /// Respects overflow clipping - nodes with overflow:hidden will
     /// clip their children's click areas.
     /// Text nodes are transparent to clicks and pass events to their parent.
+    ///
+    /// Children that establish a stacking context with a non-negative z-index
+    /// are pushed onto `layers` with their clip and scroll offset instead, and
+    /// portaled children onto `portals`.
     fn find_node_at_recursive(
         node: &Rc<RefCell<RenderNode>>,
         x: u16,
         y: u16,
         clip_rect: Option<Rect>,
         parent_scroll_offset: i16,
+        layers: &mut Vec<HitLayer>,
+        portals: &mut Vec<HitLayer>,
     ) -> Option<Rc<RefCell<RenderNode>>> {
         let node_ref = node.borrow();
 
-        // Calculate the actual rendered position with parent scroll offset
-        let rendered_y = if parent_scroll_offset > 0 {
-            node_ref.y.saturating_sub(parent_scroll_offset as u16)
-        } else {
-            node_ref.y
-        };
-        let rendered_x = node_ref.x;
-
         // Get bounds with scroll offset applied
-        let node_bounds = Rect::new(rendered_x, rendered_y, node_ref.width, node_ref.height);
+        let node_bounds = scrolled_bounds(&node_ref, parent_scroll_offset as i32);
 
         // Check if this node is clickable
         let is_node_clickable = if let Some(ref clip) = clip_rect {
@@ -387,25 +613,47 @@ impl RenderTree {
         };
 
         // Always check children first, even if this node isn't clickable
-        // This is important for overflow:none where children can extend outside
-        for child in &node_ref.children {
-            if let Some(found) =
-                Self::find_node_at_recursive(child, x, y, child_clip, child_scroll_offset)
+        // This is important for overflow:none where children can extend outside.
+        // Children are visited in paint order and the last match wins, so one
+        // with a negative z-index loses to the siblings painted over it. The
+        // search carries on past a match to collect layers from later children.
+        let mut found = None;
+        for child in node_ref.children_in_paint_order() {
             {
-                // Check if the found child is a text node
-                let found_ref = found.borrow();
-                if matches!(
-                    found_ref.node_type,
-                    RenderNodeType::Text(_) | RenderNodeType::TextWrapped(_)
-                ) {
-                    // Text nodes are transparent to clicks, don't return them
-                    drop(found_ref);
+                let child_ref = child.borrow();
+                if child_ref.portal_layer().is_some() {
+                    portals.push((child.clone(), None, child_scroll_offset));
                     continue;
                 }
-                drop(found_ref);
-                return Some(found);
+                if child_ref.establishes_stacking_context() && child_ref.z_index >= 0.0 {
+                    layers.push((child.clone(), child_clip, child_scroll_offset));
+                    continue;
+                }
+            }
+
+            let hit = Self::find_node_at_recursive(
+                child,
+                x,
+                y,
+                child_clip,
+                child_scroll_offset,
+                layers,
+                portals,
+            );
+            // Text nodes are transparent to clicks, don't return them
+            let hit = hit.filter(|hit| {
+                !matches!(
+                    hit.borrow().node_type,
+                    RenderNodeType::Text(_) | RenderNodeType::TextWrapped(_)
+                )
+            });
+            if hit.is_some() {
+                found = hit;
             }
         }
+        if found.is_some() {
+            return found;
+        }
 
         // Only return this node if it's clickable and no child matched
         // Text nodes should never be returned as click targets
@@ -625,7 +873,7 @@ impl RenderTree {
             return;
         }
 
-        if let Some(old_focused) = current {
+        if let Some(old_focused) = &current {
             let mut old_ref = old_focused.borrow_mut();
             if let Some(on_blur) = &old_ref.events.on_blur {
                 on_blur();
@@ -648,7 +896,19 @@ impl RenderTree {
         // Reset pending clear whenever focus moves or is explicitly cleared
         self.pending_focus_clear.store(false, Ordering::SeqCst);
 
-        *self.focused_node.borrow_mut() = node;
+        *self.focused_node.borrow_mut() = node.clone();
+
+        notify_focus_change(current.as_ref(), node.as_ref());
+    }
+
+    /// Records a button press that becomes a click if released without dragging.
+    pub(crate) fn set_pending_press(&self, press: Option<(MouseButton, u16, u16)>) {
+        self.pending_press.set(press);
+    }
+
+    /// Takes the pending button press, if any.
+    pub(crate) fn take_pending_press(&self) -> Option<(MouseButton, u16, u16)> {
+        self.pending_press.take()
     }
 
     /// Sets the hovered node and updates hover flags/styles.
@@ -679,7 +939,98 @@ impl RenderTree {
         *self.hovered_node.borrow_mut() = node;
     }
 
-    /// Moves focus to the next focusable element.
+    /// Sets the pressed node and updates active flags/styles.
+    pub fn set_active_node(&self, node: Option<Rc<RefCell<RenderNode>>>) {
+        let current = self.active_node.replace(node.clone());
+        if let (Some(old), Some(new)) = (&current, &node)
+            && Rc::ptr_eq(old, new)
+        {
+            return;
+        }
+
+        if let Some(old_active) = current {
+            let mut old_ref = old_active.borrow_mut();
+            old_ref.active = false;
+            old_ref.refresh_state_style();
+        }
+
+        if let Some(new_active) = node {
+            let mut new_ref = new_active.borrow_mut();
+            new_ref.active = true;
+            new_ref.refresh_state_style();
+        }
+    }
+
+    /// Scrolls the node's scrollable ancestors so it is visible, returning
+    /// whether any of them moved.
+    ///
+    /// Each ancestor scrolls as little as it can: a node above the visible
+    /// area ends up at its top, one below at its bottom. A node taller than an
+    /// ancestor's visible area is aligned to its top. Nested containers are
+    /// handled innermost first, each outer one revealing the part of the node
+    /// the inner one shows. Positions come from the last layout.
+    pub fn scroll_into_view(&self, node: &Rc<RefCell<RenderNode>>) -> bool {
+        let (mut top, mut height) = {
+            let node_ref = node.borrow();
+            (node_ref.y as i32, node_ref.height as i32)
+        };
+        let mut changed = false;
+
+        for ancestor in ancestors_of(node).into_iter().skip(1) {
+            let mut ancestor = ancestor.borrow_mut();
+            if !ancestor.scrollable {
+                continue;
+            }
+
+            let (inset_top, inset_bottom) = ancestor.vertical_insets();
+            let window_top = ancestor.y as i32 + inset_top as i32;
+            let window_height =
+                (ancestor.height as i32 - inset_top as i32 - inset_bottom as i32).max(0);
+
+            // Offset of the node within the content, and the scroll showing it
+            let offset = top - window_top;
+            let scroll = ancestor.scroll_y as i32;
+            let target = if offset < scroll || height > window_height {
+                offset
+            } else if offset + height > scroll + window_height {
+                offset + height - window_height
+            } else {
+                scroll
+            };
+
+            let old_scroll = ancestor.scroll_y;
+            ancestor.set_scroll_y(target.clamp(0, u16::MAX as i32) as u16);
+            if ancestor.scroll_y != old_scroll {
+                ancestor.mark_dirty();
+                changed = true;
+            }
+
+            // Outer containers reveal what this one shows of the node
+            let shown_top = (top - ancestor.scroll_y as i32).max(window_top);
+            let shown_bottom =
+                (top - ancestor.scroll_y as i32 + height).min(window_top + window_height);
+            top = shown_top;
+            height = (shown_bottom - shown_top).max(0);
+        }
+
+        changed
+    }
+
+    /// Scrolls the node into view after the next layout, and again after
+    /// each layout until [`clear_reveals`](Self::clear_reveals).
+    ///
+    /// Nodes that were just created or moved have no final position until
+    /// then.
+    pub(crate) fn request_reveal(&self, node: Rc<RefCell<RenderNode>>) {
+        self.pending_reveals.borrow_mut().push(node);
+    }
+
+    /// Drops the reveal requests once the frame they were made for is drawn.
+    pub(crate) fn clear_reveals(&self) {
+        self.pending_reveals.borrow_mut().clear();
+    }
+
+    /// Moves focus to the next focusable element and scrolls it into view.
     pub fn focus_next(&self) {
         let focusable = self.collect_focusable_nodes();
         if focusable.is_empty() {
@@ -702,9 +1053,10 @@ impl RenderTree {
         };
 
         self.set_focused_node(Some(focusable[next_idx].clone()));
+        self.request_reveal(focusable[next_idx].clone());
     }
 
-    /// Moves focus to the previous focusable element.
+    /// Moves focus to the previous focusable element and scrolls it into view.
     pub fn focus_prev(&self) {
         let focusable = self.collect_focusable_nodes();
         if focusable.is_empty() {
@@ -733,6 +1085,250 @@ impl RenderTree {
         };
 
         self.set_focused_node(Some(focusable[prev_idx].clone()));
+        self.request_reveal(focusable[prev_idx].clone());
+    }
+
+    /// Moves focus to the nearest focusable element in a direction on screen
+    /// and scrolls it into view.
+    ///
+    /// Focuses the first focusable element when nothing is focused. Returns
+    /// whether focus moved.
+    pub fn focus_direction(&self, direction: FocusDirection) -> bool {
+        let focusable = self.collect_focusable_nodes();
+        let Some(current) = self.get_focused_node() else {
+            let Some(first) = focusable.first() else {
+                return false;
+            };
+            self.set_focused_node(Some(first.clone()));
+            self.request_reveal(first.clone());
+            return true;
+        };
+
+        let from = current.borrow().bounds();
+        let target = focusable
+            .iter()
+            .filter(|node| !Rc::ptr_eq(node, &current))
+            .filter_map(|node| {
+                let score = spatial_focus_score(from, node.borrow().bounds(), direction)?;
+                Some((score, node))
+            })
+            .min_by_key(|(score, _)| *score)
+            .map(|(_, node)| node.clone());
+
+        let Some(target) = target else {
+            return false;
+        };
+        self.set_focused_node(Some(target.clone()));
+        self.request_reveal(target);
+        true
+    }
+}
+
+impl Hit {
+    /// Describes `node` as hit at the given terminal coordinates.
+    ///
+    /// The point needn't be inside the node: coordinates left of or above
+    /// it are clamped to its edge.
+    pub(crate) fn of(node: &Rc<RefCell<RenderNode>>, x: u16, y: u16) -> Self {
+        // Scrollable ancestors move the node up by their scroll position
+        let scroll: i32 = ancestors_of(node)
+            .iter()
+            .skip(1)
+            .map(|ancestor| ancestor.borrow())
+            .filter(|ancestor| ancestor.scrollable)
+            .map(|ancestor| ancestor.scroll_y as i32)
+            .sum();
+
+        let node = node.borrow();
+        let top = node.y as i32 - scroll;
+        Self {
+            component: node.component_path.clone(),
+            bounds: scrolled_bounds(&node, scroll),
+            local: (
+                x.saturating_sub(node.x),
+                (y as i32 - top).clamp(0, u16::MAX as i32) as u16,
+            ),
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Scores a focus candidate for a move from `from` in `direction`, lower
+/// being nearer, or `None` when the candidate doesn't lie that way.
+///
+/// The candidate's center must be past the current one's and its near edge
+/// not behind the current near edge. The score is the gap along the
+/// direction plus twice the gap across it, so a candidate overlapping the
+/// current element's rows or columns beats one off to the side at the same
+/// distance. Ties go to the candidate whose center is more closely aligned.
+fn spatial_focus_score(from: Rect, to: Rect, direction: FocusDirection) -> Option<(u32, u32)> {
+    if to.is_empty() {
+        return None;
+    }
+
+    // Project both boxes onto the axis of movement (main) and the other one (cross),
+    // flipping the main axis so that "ahead" is always increasing
+    let span = |start: u16, size: u16| (start as i32, start as i32 + size as i32);
+    let (from_main, to_main, from_cross, to_cross) = match direction {
+        FocusDirection::Right | FocusDirection::Left => (
+            span(from.x, from.width),
+            span(to.x, to.width),
+            span(from.y, from.height),
+            span(to.y, to.height),
+        ),
+        FocusDirection::Down | FocusDirection::Up => (
+            span(from.y, from.height),
+            span(to.y, to.height),
+            span(from.x, from.width),
+            span(to.x, to.width),
+        ),
+    };
+    let flip = |(start, end): (i32, i32)| (-end, -start);
+    let (from_main, to_main) = match direction {
+        FocusDirection::Right | FocusDirection::Down => (from_main, to_main),
+        FocusDirection::Left | FocusDirection::Up => (flip(from_main), flip(to_main)),
+    };
+
+    let center = |(start, end): (i32, i32)| start + end;
+    if center(to_main) <= center(from_main) || to_main.0 < from_main.0 {
+        return None;
+    }
+
+    let main_gap = (to_main.0 - from_main.1).max(0);
+    let cross_gap = (to_cross.0 - from_cross.1)
+        .max(from_cross.0 - to_cross.1)
+        .max(0);
+    let misalignment = (center(to_cross) - center(from_cross)).unsigned_abs();
+    Some(((main_gap + 2 * cross_gap) as u32, misalignment))
+}
+
+/// Returns where a node is drawn when its ancestors scroll it up by `scroll`
+/// rows, without the rows that end up above the screen.
+fn scrolled_bounds(node: &RenderNode, scroll: i32) -> Rect {
+    let top = node.y as i32 - scroll;
+    let hidden = (-top).clamp(0, node.height as i32) as u16;
+    Rect::new(node.x, top.max(0) as u16, node.width, node.height - hidden)
+}
+
+/// Returns the node and its ancestors, innermost first.
+fn ancestors_of(node: &Rc<RefCell<RenderNode>>) -> Vec<Rc<RefCell<RenderNode>>> {
+    let mut chain = vec![node.clone()];
+    while let Some(parent) = chain
+        .last()
+        .and_then(|node| node.borrow().parent.as_ref().and_then(|p| p.upgrade()))
+    {
+        chain.push(parent);
+    }
+    chain
+}
+
+/// Locates a node within its component from the node and its ancestors, innermost first.
+fn element_path(chain: &[Rc<RefCell<RenderNode>>]) -> Option<ElementPath> {
+    let mut indices = Vec::new();
+    for (index, node) in chain.iter().enumerate() {
+        let component = node.borrow().component_path.clone();
+        let parent = chain.get(index + 1);
+        if component.is_some() || parent.is_none() {
+            indices.reverse();
+            return Some(ElementPath { component, indices });
+        }
+        let position = parent?
+            .borrow()
+            .children
+            .iter()
+            .position(|child| Rc::ptr_eq(child, node))?;
+        indices.push(position);
+    }
+    None
+}
+
+/// Calls the focus-within handlers of the subtrees focus moved out of, into, or within.
+///
+/// Subtrees focus left are told first, innermost first, with `None`; then
+/// subtrees holding the new focus get the path of the newly focused element,
+/// innermost first.
+fn notify_focus_change(
+    old: Option<&Rc<RefCell<RenderNode>>>,
+    new: Option<&Rc<RefCell<RenderNode>>>,
+) {
+    let old_chain = old.map(ancestors_of).unwrap_or_default();
+    let new_chain = new.map(ancestors_of).unwrap_or_default();
+    let focused_path = element_path(&new_chain);
+
+    let left = old_chain
+        .iter()
+        .filter(|node| !new_chain.iter().any(|other| Rc::ptr_eq(node, other)))
+        .map(|node| (node, None));
+    let entered = new_chain.iter().map(|node| (node, focused_path.clone()));
+
+    // Collect first so handlers run without any node borrowed
+    let calls: Vec<_> = left
+        .chain(entered)
+        .filter_map(|(node, path)| {
+            let handler = node.borrow().events.on_focus_change.clone()?;
+            Some((handler, path))
+        })
+        .collect();
+    for (handler, path) in calls {
+        handler(path);
+    }
+}
+
+/// Summarizes the layout properties set on a div's style, in a fixed order.
+fn style_summary(style: &Style) -> String {
+    let mut summary = String::new();
+    if let Some(direction) = style.direction {
+        let direction = match direction {
+            Direction::Vertical => "vertical",
+            Direction::Horizontal => "horizontal",
+            Direction::VerticalReverse => "vertical-reverse",
+            Direction::HorizontalReverse => "horizontal-reverse",
+        };
+        summary.push_str(&format!(" dir={direction}"));
+    }
+    if let Some(width) = style.width {
+        summary.push_str(&format!(" w={}", dimension_summary(width)));
+    }
+    if let Some(height) = style.height {
+        summary.push_str(&format!(" h={}", dimension_summary(height)));
+    }
+    if let Some(padding) = style.padding {
+        summary.push_str(&format!(
+            " pad=({},{},{},{})",
+            padding.top, padding.right, padding.bottom, padding.left
+        ));
+    }
+    if let Some(gap) = style.gap {
+        summary.push_str(&format!(" gap={gap}"));
+    }
+    if style.border.as_ref().is_some_and(|border| border.enabled) {
+        summary.push_str(" border");
+    }
+    match style.position {
+        Some(Position::Absolute) => summary.push_str(" position=absolute"),
+        Some(Position::Fixed) => summary.push_str(" position=fixed"),
+        Some(Position::Relative) | None => {}
+    }
+    if let Some(overflow) = style.overflow {
+        summary.push_str(&format!(
+            " overflow={}",
+            format!("{overflow:?}").to_lowercase()
+        ));
+    }
+    summary
+}
+
+/// Writes a dimension the way it is usually spelled in a style.
+fn dimension_summary(dimension: Dimension) -> String {
+    match dimension {
+        Dimension::Fixed(cells) => cells.to_string(),
+        Dimension::Percentage(fraction) => format!("{}%", (fraction * 1000.0).round() / 10.0),
+        Dimension::Auto => "auto".to_string(),
+        Dimension::Content => "content".to_string(),
+        Dimension::FractionRemaining(fraction) => format!("{fraction}fr"),
     }
 }
 
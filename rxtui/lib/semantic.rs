@@ -0,0 +1,400 @@
+//! Plain-text semantic outline of the UI.
+//!
+//! Produces a readable, indentation-based description of the expanded node
+//! tree for CI logs, screen readers, and test assertions. It describes what
+//! the UI means (structure, text, labels, focus) rather than how it is drawn.
+//!
+//! ## Format
+//!
+//! ```text
+//! App
+//!   div
+//!     text "Name"
+//!     TextInput "Enter your name..." [focusable, focused]
+//!       text "Alice"
+//!     div [clickable]
+//!       text "Submit"
+//! ```
+//!
+//! - Containers print as `div`, or as the component name when they are the
+//!   root of a component's view.
+//! - A div's accessible label follows its name in quotes.
+//! - Flags are listed in brackets: `focusable`, `focused`, `clickable`.
+//! - Text and rich text print as `text "content"`; cursor padding is omitted.
+//!
+//! ## Accessible Text
+//!
+//! [`accessible_text`] linearizes the same tree for screen readers: one line
+//! per piece of content, in reading order (top to bottom, then left to
+//! right), with roles announced and decoration left out.
+//!
+//! ```text
+//! Heading: Files
+//! List (2 items)
+//! - Cargo.toml (focused)
+//! - README.md
+//! Button: Open
+//! ```
+//!
+//! - Headings, buttons, and list items read as one line with their label, or
+//!   with their text when unlabeled.
+//! - A labeled div without a role reads as `label: text`.
+//! - `Role::Presentation` divs, and text made only of box-drawing, block, or
+//!   braille characters, are skipped.
+
+use crate::node::{Div, RichText, Role};
+use crate::render_tree::RenderNode;
+use crate::vnode::VNode;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Builds the semantic outline for a vnode tree.
+///
+/// The matching render tree, when available, supplies the live focus state
+/// since focus changes are applied to render nodes rather than vnodes.
+pub(crate) fn semantic_outline(
+    vnode: &VNode,
+    render_node: Option<&Rc<RefCell<RenderNode>>>,
+) -> String {
+    let mut output = String::new();
+    write_node(&mut output, vnode, render_node, 0);
+    output
+}
+
+/// Builds the accessible text for a vnode tree.
+///
+/// Siblings are read in screen order when the matching render tree is
+/// available, so reversed and positioned layouts read as they appear.
+pub(crate) fn accessible_text(
+    vnode: &VNode,
+    render_node: Option<&Rc<RefCell<RenderNode>>>,
+) -> String {
+    let mut lines = Vec::new();
+    write_accessible(&mut lines, vnode, render_node);
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Appends the lines read for a node and its children.
+fn write_accessible(
+    lines: &mut Vec<String>,
+    vnode: &VNode,
+    render_node: Option<&Rc<RefCell<RenderNode>>>,
+) {
+    let div = match vnode {
+        VNode::Div(div) => div,
+        VNode::Text(_) | VNode::RichText(_) => {
+            if let Some(text) = spoken_text(vnode) {
+                lines.push(text);
+            }
+            return;
+        }
+    };
+
+    let focused = render_node.is_some_and(|node| node.borrow().focused);
+    let state = if focused { " (focused)" } else { "" };
+    let name = || {
+        div.label
+            .clone()
+            .unwrap_or_else(|| flattened_text(div, render_node))
+    };
+
+    match div.role {
+        Some(Role::Presentation) => {}
+        Some(role @ (Role::Heading | Role::Button | Role::ListItem)) => {
+            let name = name();
+            if !name.is_empty() {
+                let prefix = match role {
+                    Role::Heading => "Heading: ",
+                    Role::Button => "Button: ",
+                    _ => "- ",
+                };
+                lines.push(format!("{prefix}{name}{state}"));
+            }
+        }
+        Some(Role::List) => {
+            let count = div.children.len();
+            let items = if count == 1 { "item" } else { "items" };
+            let label = div
+                .label
+                .as_ref()
+                .map(|label| format!(": {label}"))
+                .unwrap_or_default();
+            lines.push(format!("List{label} ({count} {items}){state}"));
+            for (child, render_child) in reading_order(div, render_node) {
+                write_accessible(lines, child, render_child.as_ref());
+            }
+        }
+        None => match &div.label {
+            Some(label) => {
+                let text = flattened_text(div, render_node);
+                let separator = if text.is_empty() { "" } else { ": " };
+                lines.push(format!("{label}{separator}{text}{state}"));
+            }
+            None => {
+                for (child, render_child) in reading_order(div, render_node) {
+                    write_accessible(lines, child, render_child.as_ref());
+                }
+            }
+        },
+    }
+}
+
+/// All text read inside a div, joined into one line.
+fn flattened_text(div: &Div<VNode>, render_node: Option<&Rc<RefCell<RenderNode>>>) -> String {
+    let mut lines = Vec::new();
+    for (child, render_child) in reading_order(div, render_node) {
+        write_accessible(&mut lines, child, render_child.as_ref());
+    }
+    lines.join(" ")
+}
+
+/// Pairs a div's children with their render nodes, sorted top to bottom and
+/// then left to right when the layout is known.
+fn reading_order<'a>(
+    div: &'a Div<VNode>,
+    render_node: Option<&Rc<RefCell<RenderNode>>>,
+) -> Vec<(&'a VNode, Option<Rc<RefCell<RenderNode>>>)> {
+    let render_children = render_node.map(|node| node.borrow().children.clone());
+    let mut children: Vec<_> = div
+        .children
+        .iter()
+        .enumerate()
+        .map(|(i, child)| {
+            let render_child = render_children.as_ref().and_then(|c| c.get(i)).cloned();
+            (child, render_child)
+        })
+        .collect();
+
+    children.sort_by_key(|(_, render_child)| {
+        render_child.as_ref().map(|node| {
+            let node = node.borrow();
+            (node.y, node.x)
+        })
+    });
+    children
+}
+
+/// The trimmed content of a text node, or `None` when there is nothing to read.
+fn spoken_text(vnode: &VNode) -> Option<String> {
+    let content = match vnode {
+        VNode::Text(text) => text.content.clone(),
+        VNode::RichText(rich) => rich_text_content(rich),
+        VNode::Div(_) => return None,
+    };
+    let content = content.trim();
+    (!content.chars().all(is_decorative)).then(|| content.to_string())
+}
+
+/// Whether a character only draws lines, blocks, or spinner dots.
+fn is_decorative(ch: char) -> bool {
+    ch.is_whitespace()
+        || ('\u{2500}'..='\u{259F}').contains(&ch)
+        || ('\u{2800}'..='\u{28FF}').contains(&ch)
+}
+
+/// Writes a single node and its children at the given depth.
+fn write_node(
+    output: &mut String,
+    vnode: &VNode,
+    render_node: Option<&Rc<RefCell<RenderNode>>>,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+
+    match vnode {
+        VNode::Div(div) => {
+            output.push_str(&indent);
+            output.push_str(&div_heading(div, render_node));
+            output.push('\n');
+
+            let render_children = render_node.map(|node| node.borrow().children.clone());
+            for (i, child) in div.children.iter().enumerate() {
+                let render_child = render_children.as_ref().and_then(|c| c.get(i));
+                write_node(output, child, render_child, depth + 1);
+            }
+        }
+        VNode::Text(text) => {
+            output.push_str(&format!("{indent}text {:?}\n", text.content));
+        }
+        VNode::RichText(rich) => {
+            output.push_str(&format!("{indent}text {:?}\n", rich_text_content(rich)));
+        }
+    }
+}
+
+/// Formats the heading line for a div: name, label, and flags.
+fn div_heading(div: &Div<VNode>, render_node: Option<&Rc<RefCell<RenderNode>>>) -> String {
+    let mut heading = div
+        .component_name
+        .map(short_type_name)
+        .unwrap_or("div")
+        .to_string();
+
+    if let Some(label) = &div.label {
+        heading.push_str(&format!(" {label:?}"));
+    }
+
+    let focused = match render_node {
+        Some(node) => node.borrow().focused,
+        None => div.focused,
+    };
+
+    let mut flags = Vec::new();
+    if div.focusable {
+        flags.push("focusable");
+    }
+    if focused {
+        flags.push("focused");
+    }
+    if div.events.on_click.is_some() {
+        flags.push("clickable");
+    }
+
+    if !flags.is_empty() {
+        heading.push_str(&format!(" [{}]", flags.join(", ")));
+    }
+
+    heading
+}
+
+/// Joins rich text spans, dropping the trailing cursor cell.
+fn rich_text_content(rich: &RichText) -> String {
+    rich.spans
+        .iter()
+        .filter(|span| !(span.is_cursor && span.content.trim().is_empty()))
+        .map(|span| span.content.as_str())
+        .collect()
+}
+
+/// Strips the module path from a type name, leaving generic arguments untouched.
+///
+/// `my_app::widgets::Panel<my_app::Item>` becomes `Panel<my_app::Item>`.
+fn short_type_name(name: &'static str) -> &'static str {
+    let base_end = name.find('<').unwrap_or(name.len());
+    match name[..base_end].rfind("::") {
+        Some(pos) => &name[pos + 2..],
+        None => name,
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::Text;
+    use crate::vdom::VDom;
+
+    #[test]
+    fn test_semantic_outline_structure() {
+        let mut panel: Div<VNode> = Div::new()
+            .focusable(true)
+            .label("Name")
+            .child(VNode::text("Alice"));
+        panel.component_name = Some("my_app::forms::NameInput");
+
+        let root: VNode = Div::new()
+            .child(Text::new("Header").into())
+            .child(VNode::Div(panel))
+            .child(
+                Div::new()
+                    .on_click(|| {})
+                    .child(VNode::text("Submit"))
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+
+        let outline = vdom.render_semantic();
+        assert_eq!(
+            outline,
+            "div\n  text \"Header\"\n  NameInput \"Name\" [focusable]\n    text \"Alice\"\n  div [clickable]\n    text \"Submit\"\n"
+        );
+    }
+
+    #[test]
+    fn test_semantic_outline_reports_focus() {
+        let root: VNode = Div::new().child(Div::new().focusable(true).into()).into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+
+        let render_tree = vdom.get_render_tree();
+        let target = render_tree.find_first_focusable_global();
+        render_tree.set_focused_node(target);
+
+        assert_eq!(vdom.render_semantic(), "div\n  div [focusable, focused]\n");
+    }
+
+    #[test]
+    fn test_accessible_text_reading_order_and_roles() {
+        let item = |label: &str| -> VNode {
+            Div::new()
+                .role(Role::ListItem)
+                .child(VNode::text(label))
+                .into()
+        };
+
+        // The footer comes first in the tree but is drawn below the list
+        let root: VNode = Div::new()
+            .direction(crate::Direction::VerticalReverse)
+            .child(
+                Div::new()
+                    .direction(crate::Direction::Horizontal)
+                    .child(Div::new().label("Path").child(VNode::text("~/src")).into())
+                    .child(Div::new().role(Role::Button).label("Open").into())
+                    .into(),
+            )
+            .child(VNode::text("───────"))
+            .child(
+                Div::new()
+                    .role(Role::List)
+                    .child(item("Cargo.toml"))
+                    .child(item("README.md"))
+                    .into(),
+            )
+            .child(
+                Div::new()
+                    .role(Role::Heading)
+                    .child(VNode::text(" Files "))
+                    .child(
+                        Div::new()
+                            .role(Role::Presentation)
+                            .child(VNode::text("★"))
+                            .into(),
+                    )
+                    .into(),
+            )
+            .into();
+
+        let mut vdom = VDom::new();
+        vdom.render(root);
+        vdom.layout(30, 10);
+
+        assert_eq!(
+            vdom.accessible_text(),
+            "Heading: Files\nList (2 items)\n- Cargo.toml\n- README.md\nPath: ~/src\nButton: Open\n"
+        );
+    }
+
+    #[test]
+    fn test_rich_text_omits_cursor() {
+        let rich = RichText::with_cursor("abc", 3, Default::default());
+        assert_eq!(rich_text_content(&rich), "abc");
+    }
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name("rxtui::components::TextInput"), "TextInput");
+        assert_eq!(short_type_name("a::Panel<b::Item>"), "Panel<b::Item>");
+        assert_eq!(short_type_name("Plain"), "Plain");
+    }
+}
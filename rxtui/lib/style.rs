@@ -8,6 +8,7 @@
 //! The framework supports:
 //! - 16 standard terminal colors (8 normal + 8 bright)
 //! - 24-bit RGB colors (on terminals that support it)
+//! - Parsing from strings: hex, CSS color names, `rgb(...)` and `hsl(...)`
 //!
 //! ## Style Composition
 //!
@@ -19,6 +20,167 @@
 //! ```
 
 use bitflags::bitflags;
+use std::fmt;
+use std::str::FromStr;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// How many times taller than wide a terminal cell is, unless a style sets `cell_aspect`
+pub const DEFAULT_CELL_ASPECT: f32 = 2.0;
+
+/// CSS named colors, sorted by name
+const CSS_COLORS: &[(&str, Color)] = &[
+    ("aliceblue", Color::Rgb(240, 248, 255)),
+    ("antiquewhite", Color::Rgb(250, 235, 215)),
+    ("aqua", Color::Rgb(0, 255, 255)),
+    ("aquamarine", Color::Rgb(127, 255, 212)),
+    ("azure", Color::Rgb(240, 255, 255)),
+    ("beige", Color::Rgb(245, 245, 220)),
+    ("bisque", Color::Rgb(255, 228, 196)),
+    ("black", Color::Rgb(0, 0, 0)),
+    ("blanchedalmond", Color::Rgb(255, 235, 205)),
+    ("blue", Color::Rgb(0, 0, 255)),
+    ("blueviolet", Color::Rgb(138, 43, 226)),
+    ("brown", Color::Rgb(165, 42, 42)),
+    ("burlywood", Color::Rgb(222, 184, 135)),
+    ("cadetblue", Color::Rgb(95, 158, 160)),
+    ("chartreuse", Color::Rgb(127, 255, 0)),
+    ("chocolate", Color::Rgb(210, 105, 30)),
+    ("coral", Color::Rgb(255, 127, 80)),
+    ("cornflowerblue", Color::Rgb(100, 149, 237)),
+    ("cornsilk", Color::Rgb(255, 248, 220)),
+    ("crimson", Color::Rgb(220, 20, 60)),
+    ("cyan", Color::Rgb(0, 255, 255)),
+    ("darkblue", Color::Rgb(0, 0, 139)),
+    ("darkcyan", Color::Rgb(0, 139, 139)),
+    ("darkgoldenrod", Color::Rgb(184, 134, 11)),
+    ("darkgray", Color::Rgb(169, 169, 169)),
+    ("darkgreen", Color::Rgb(0, 100, 0)),
+    ("darkgrey", Color::Rgb(169, 169, 169)),
+    ("darkkhaki", Color::Rgb(189, 183, 107)),
+    ("darkmagenta", Color::Rgb(139, 0, 139)),
+    ("darkolivegreen", Color::Rgb(85, 107, 47)),
+    ("darkorange", Color::Rgb(255, 140, 0)),
+    ("darkorchid", Color::Rgb(153, 50, 204)),
+    ("darkred", Color::Rgb(139, 0, 0)),
+    ("darksalmon", Color::Rgb(233, 150, 122)),
+    ("darkseagreen", Color::Rgb(143, 188, 143)),
+    ("darkslateblue", Color::Rgb(72, 61, 139)),
+    ("darkslategray", Color::Rgb(47, 79, 79)),
+    ("darkslategrey", Color::Rgb(47, 79, 79)),
+    ("darkturquoise", Color::Rgb(0, 206, 209)),
+    ("darkviolet", Color::Rgb(148, 0, 211)),
+    ("deeppink", Color::Rgb(255, 20, 147)),
+    ("deepskyblue", Color::Rgb(0, 191, 255)),
+    ("dimgray", Color::Rgb(105, 105, 105)),
+    ("dimgrey", Color::Rgb(105, 105, 105)),
+    ("dodgerblue", Color::Rgb(30, 144, 255)),
+    ("firebrick", Color::Rgb(178, 34, 34)),
+    ("floralwhite", Color::Rgb(255, 250, 240)),
+    ("forestgreen", Color::Rgb(34, 139, 34)),
+    ("fuchsia", Color::Rgb(255, 0, 255)),
+    ("gainsboro", Color::Rgb(220, 220, 220)),
+    ("ghostwhite", Color::Rgb(248, 248, 255)),
+    ("gold", Color::Rgb(255, 215, 0)),
+    ("goldenrod", Color::Rgb(218, 165, 32)),
+    ("gray", Color::Rgb(128, 128, 128)),
+    ("green", Color::Rgb(0, 128, 0)),
+    ("greenyellow", Color::Rgb(173, 255, 47)),
+    ("grey", Color::Rgb(128, 128, 128)),
+    ("honeydew", Color::Rgb(240, 255, 240)),
+    ("hotpink", Color::Rgb(255, 105, 180)),
+    ("indianred", Color::Rgb(205, 92, 92)),
+    ("indigo", Color::Rgb(75, 0, 130)),
+    ("ivory", Color::Rgb(255, 255, 240)),
+    ("khaki", Color::Rgb(240, 230, 140)),
+    ("lavender", Color::Rgb(230, 230, 250)),
+    ("lavenderblush", Color::Rgb(255, 240, 245)),
+    ("lawngreen", Color::Rgb(124, 252, 0)),
+    ("lemonchiffon", Color::Rgb(255, 250, 205)),
+    ("lightblue", Color::Rgb(173, 216, 230)),
+    ("lightcoral", Color::Rgb(240, 128, 128)),
+    ("lightcyan", Color::Rgb(224, 255, 255)),
+    ("lightgoldenrodyellow", Color::Rgb(250, 250, 210)),
+    ("lightgray", Color::Rgb(211, 211, 211)),
+    ("lightgreen", Color::Rgb(144, 238, 144)),
+    ("lightgrey", Color::Rgb(211, 211, 211)),
+    ("lightpink", Color::Rgb(255, 182, 193)),
+    ("lightsalmon", Color::Rgb(255, 160, 122)),
+    ("lightseagreen", Color::Rgb(32, 178, 170)),
+    ("lightskyblue", Color::Rgb(135, 206, 250)),
+    ("lightslategray", Color::Rgb(119, 136, 153)),
+    ("lightslategrey", Color::Rgb(119, 136, 153)),
+    ("lightsteelblue", Color::Rgb(176, 196, 222)),
+    ("lightyellow", Color::Rgb(255, 255, 224)),
+    ("lime", Color::Rgb(0, 255, 0)),
+    ("limegreen", Color::Rgb(50, 205, 50)),
+    ("linen", Color::Rgb(250, 240, 230)),
+    ("magenta", Color::Rgb(255, 0, 255)),
+    ("maroon", Color::Rgb(128, 0, 0)),
+    ("mediumaquamarine", Color::Rgb(102, 205, 170)),
+    ("mediumblue", Color::Rgb(0, 0, 205)),
+    ("mediumorchid", Color::Rgb(186, 85, 211)),
+    ("mediumpurple", Color::Rgb(147, 112, 219)),
+    ("mediumseagreen", Color::Rgb(60, 179, 113)),
+    ("mediumslateblue", Color::Rgb(123, 104, 238)),
+    ("mediumspringgreen", Color::Rgb(0, 250, 154)),
+    ("mediumturquoise", Color::Rgb(72, 209, 204)),
+    ("mediumvioletred", Color::Rgb(199, 21, 133)),
+    ("midnightblue", Color::Rgb(25, 25, 112)),
+    ("mintcream", Color::Rgb(245, 255, 250)),
+    ("mistyrose", Color::Rgb(255, 228, 225)),
+    ("moccasin", Color::Rgb(255, 228, 181)),
+    ("navajowhite", Color::Rgb(255, 222, 173)),
+    ("navy", Color::Rgb(0, 0, 128)),
+    ("oldlace", Color::Rgb(253, 245, 230)),
+    ("olive", Color::Rgb(128, 128, 0)),
+    ("olivedrab", Color::Rgb(107, 142, 35)),
+    ("orange", Color::Rgb(255, 165, 0)),
+    ("orangered", Color::Rgb(255, 69, 0)),
+    ("orchid", Color::Rgb(218, 112, 214)),
+    ("palegoldenrod", Color::Rgb(238, 232, 170)),
+    ("palegreen", Color::Rgb(152, 251, 152)),
+    ("paleturquoise", Color::Rgb(175, 238, 238)),
+    ("palevioletred", Color::Rgb(219, 112, 147)),
+    ("papayawhip", Color::Rgb(255, 239, 213)),
+    ("peachpuff", Color::Rgb(255, 218, 185)),
+    ("peru", Color::Rgb(205, 133, 63)),
+    ("pink", Color::Rgb(255, 192, 203)),
+    ("plum", Color::Rgb(221, 160, 221)),
+    ("powderblue", Color::Rgb(176, 224, 230)),
+    ("purple", Color::Rgb(128, 0, 128)),
+    ("rebeccapurple", Color::Rgb(102, 51, 153)),
+    ("red", Color::Rgb(255, 0, 0)),
+    ("rosybrown", Color::Rgb(188, 143, 143)),
+    ("royalblue", Color::Rgb(65, 105, 225)),
+    ("saddlebrown", Color::Rgb(139, 69, 19)),
+    ("salmon", Color::Rgb(250, 128, 114)),
+    ("sandybrown", Color::Rgb(244, 164, 96)),
+    ("seagreen", Color::Rgb(46, 139, 87)),
+    ("seashell", Color::Rgb(255, 245, 238)),
+    ("sienna", Color::Rgb(160, 82, 45)),
+    ("silver", Color::Rgb(192, 192, 192)),
+    ("skyblue", Color::Rgb(135, 206, 235)),
+    ("slateblue", Color::Rgb(106, 90, 205)),
+    ("slategray", Color::Rgb(112, 128, 144)),
+    ("slategrey", Color::Rgb(112, 128, 144)),
+    ("snow", Color::Rgb(255, 250, 250)),
+    ("springgreen", Color::Rgb(0, 255, 127)),
+    ("steelblue", Color::Rgb(70, 130, 180)),
+    ("tan", Color::Rgb(210, 180, 140)),
+    ("teal", Color::Rgb(0, 128, 128)),
+    ("thistle", Color::Rgb(216, 191, 216)),
+    ("tomato", Color::Rgb(255, 99, 71)),
+    ("turquoise", Color::Rgb(64, 224, 208)),
+    ("violet", Color::Rgb(238, 130, 238)),
+    ("wheat", Color::Rgb(245, 222, 179)),
+    ("white", Color::Rgb(255, 255, 255)),
+    ("whitesmoke", Color::Rgb(245, 245, 245)),
+    ("yellow", Color::Rgb(255, 255, 0)),
+    ("yellowgreen", Color::Rgb(154, 205, 50)),
+];
 
 //--------------------------------------------------------------------------------------------------
 // Types
@@ -59,6 +221,15 @@ pub enum Dimension {
     ///
     /// For text nodes, uses the natural text dimensions.
     Content,
+
+    /// Fraction of the space left along the parent's main axis (0.0 to 1.0)
+    ///
+    /// Resolved after fixed, percentage, content and text children and the
+    /// gaps are measured. When the fractions of all siblings add up to more
+    /// than 1.0 they are scaled down to share the remaining space, and
+    /// flexible auto siblings share whatever the fractions leave over. On the
+    /// cross axis, and for root elements, this behaves like `Percentage`.
+    FractionRemaining(f32),
 }
 
 /// Represents spacing values for all four sides of an element.
@@ -108,6 +279,9 @@ pub struct Spacing {
 /// BrightBlack   BrightRed     BrightGreen   BrightYellow
 /// BrightBlue    BrightMagenta BrightCyan    BrightWhite
 /// ```
+///
+/// `Default` stands for whatever the terminal's own foreground or background
+/// is.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     /// Standard black (color 0)
@@ -160,6 +334,20 @@ pub enum Color {
 
     /// 24-bit RGB color (requires terminal support)
     Rgb(u8, u8, u8),
+
+    /// The terminal's own default foreground or background (SGR 39/49)
+    ///
+    /// Follows the user's terminal theme, including transparent backgrounds.
+    /// Unlike leaving a color unset, it overrides the color inherited from a
+    /// parent.
+    Default,
+}
+
+/// Error returned when a string can't be parsed as a [`Color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+    reason: String,
 }
 
 /// Layout direction for arranging child elements.
@@ -183,19 +371,39 @@ pub enum Color {
 /// │ Ch1 │ Ch2 │ Ch3 │
 /// └─────┴─────┴─────┘
 /// ```
+///
+/// The reverse variants place the first child at the bottom or right edge.
+/// Justify content and gaps are measured from that edge, so
+/// `JustifyContent::Start` packs children against it.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Direction {
     /// Stack children vertically (top to bottom)
     Vertical,
 
     /// Stack children horizontally (left to right)
     Horizontal,
+
+    /// Stack children vertically (bottom to top)
+    VerticalReverse,
+
+    /// Stack children horizontally (right to left)
+    HorizontalReverse,
 }
 
 /// Overflow behavior for content that exceeds container bounds.
 ///
 /// Controls how content is displayed when it's larger than its container.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Overflow {
     /// Content is not clipped and may be rendered outside the container bounds (default)
     None,
@@ -208,12 +416,24 @@ pub enum Overflow {
 
     /// Automatically show scrollbars when content overflows
     Auto,
+
+    /// Content deliberately renders outside the container bounds
+    ///
+    /// Unlike `None`, children are painted even when the container itself is
+    /// clipped away or has no content area, and a container with a z-index
+    /// layers above later siblings (e.g. an overflowing dropdown).
+    Visible,
 }
 
 /// Text alignment modes for controlling horizontal text positioning.
 ///
 /// Determines how text content is aligned within its container.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum TextAlign {
     /// Align text to the left edge (default)
     #[default]
@@ -224,12 +444,45 @@ pub enum TextAlign {
 
     /// Align text to the right edge
     Right,
+
+    /// Stretch the gaps between words so every wrapped line except the last
+    /// fills the width. Unwrapped text stays left aligned.
+    Justify,
+}
+
+/// Case transforms applied to text when it is displayed.
+///
+/// The text's content is left untouched; layout measures the transformed text.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum TextTransform {
+    /// Text is shown as written (default)
+    #[default]
+    None,
+
+    /// Every letter in upper case
+    Uppercase,
+
+    /// Every letter in lower case
+    Lowercase,
+
+    /// The first letter of each word in upper case, the rest as written
+    Capitalize,
 }
 
 /// Text wrapping modes for controlling how text breaks across lines.
 ///
 /// Determines how text content wraps when it exceeds its container width.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum TextWrap {
     /// No wrapping - text overflows or is clipped (default)
     None,
@@ -238,7 +491,7 @@ pub enum TextWrap {
     /// Good for fixed-width content or when space is limited
     Character,
 
-    /// Break only at word boundaries (spaces)
+    /// Break only at word boundaries (spaces, and where [`LineBreak`] allows)
     /// Words longer than line width will overflow
     Word,
 
@@ -247,10 +500,69 @@ pub enum TextWrap {
     WordBreak,
 }
 
+/// Where word wrapping may break a line besides whitespace.
+///
+/// Applies to [`TextWrap::Word`] and [`TextWrap::WordBreak`]; whitespace is
+/// always a break opportunity.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum LineBreak {
+    /// Only at whitespace
+    Whitespace,
+
+    /// Also before and after CJK characters, which are written without
+    /// spaces, while keeping closing punctuation on the line before and
+    /// opening punctuation on the line after (default)
+    #[default]
+    Cjk,
+
+    /// Also wherever the function returns true for two adjacent clusters
+    /// that aren't whitespace, the one before the break and the one after
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(fn(&str, &str) -> bool),
+}
+
+/// Underline decoration styles.
+///
+/// Styled underlines use the extended SGR 4:x sequences. Terminals that
+/// don't support them fall back to a plain single underline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UnderlineStyle {
+    /// Single straight underline (default)
+    #[default]
+    Single,
+
+    /// Double straight underline
+    Double,
+
+    /// Curly/wavy underline, commonly used for errors and spelling hints
+    Curly,
+
+    /// Dotted underline
+    Dotted,
+
+    /// Dashed underline
+    Dashed,
+}
+
 /// Element wrapping modes for controlling how children wrap.
 ///
 /// Determines how child elements wrap when they exceed container width.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum WrapMode {
     /// No wrapping - children laid out in single row/column (default)
     NoWrap,
@@ -266,6 +578,11 @@ pub enum WrapMode {
 ///
 /// Determines how an element is positioned relative to its parent or the viewport.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum Position {
     /// Element is positioned in normal document flow (default)
     /// Children are laid out according to parent's direction
@@ -280,12 +597,46 @@ pub enum Position {
     Fixed,
 }
 
+/// Offset of a positioned element from an edge of its container.
+///
+/// Percentages resolve against the container's width for `left`/`right` and
+/// its height for `top`/`bottom`. Integers convert into cell offsets, so
+/// builders taking `impl Into<Offset>` still accept plain numbers; they store
+/// percentages in the style's `*_fraction` fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    /// Offset in terminal cells
+    Cells(i16),
+
+    /// Fraction of the reference size (0.5 is 50%, negative values allowed)
+    Percent(f32),
+}
+
+/// Stacking order of an element among its stacking context.
+///
+/// Integers convert into whole z-indices and floats into fractional ones, so
+/// builders taking `impl Into<ZIndex>` accept both `5` and `1.5`; fractional
+/// values are stored in the style's `fractional_z_index` field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZIndex {
+    /// A whole z-index
+    Whole(i32),
+
+    /// A z-index with a fractional part, ordered between the whole values around it
+    Fractional(f32),
+}
+
 /// Controls how content is distributed along the main axis.
 ///
 /// The main axis is determined by the Direction:
 /// - Horizontal: main axis is horizontal (left to right)
 /// - Vertical: main axis is vertical (top to bottom)
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum JustifyContent {
     /// Pack items at the start of the main axis (default)
     #[default]
@@ -313,6 +664,11 @@ pub enum JustifyContent {
 /// - Horizontal layout: cross axis is vertical
 /// - Vertical layout: cross axis is horizontal
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum AlignItems {
     /// Align items at the start of the cross axis (default)
     #[default]
@@ -323,6 +679,13 @@ pub enum AlignItems {
 
     /// Align items at the end of the cross axis
     End,
+
+    /// Line up the baselines of items in a horizontal layout
+    ///
+    /// The baseline of a text item is its last line, so a single-line label
+    /// sits on the last line of wrapped text next to it. Other items use
+    /// their bottom row. Vertical layouts align baseline items at the start.
+    Baseline,
 }
 
 /// Allows an item to override its parent's AlignItems setting.
@@ -330,6 +693,11 @@ pub enum AlignItems {
 /// Individual items can specify their own alignment on the cross axis,
 /// overriding the parent container's AlignItems value.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum AlignSelf {
     /// Use the parent's AlignItems value (default)
     #[default]
@@ -343,6 +711,9 @@ pub enum AlignSelf {
 
     /// Align at the end of the cross axis
     End,
+
+    /// Line up with the baselines of the other baseline items
+    Baseline,
 }
 
 bitflags! {
@@ -385,6 +756,11 @@ bitflags! {
 
 /// Border style variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum BorderStyle {
     /// Single line border (┌─┐│└┘)
     #[default]
@@ -401,6 +777,12 @@ pub enum BorderStyle {
 
     /// Dashed line border (┌╌┐╎└╌┘)
     Dashed,
+
+    /// User-defined characters, in the order top-left, top, top-right, left,
+    /// right, bottom-left, bottom, bottom-right
+    ///
+    /// Each character should be a single column wide.
+    Custom([char; 8]),
 }
 
 /// Border configuration for UI elements.
@@ -428,16 +810,31 @@ pub struct Border {
 /// Combines colors, layout, and spacing properties.
 /// All properties are optional and inherit defaults if not set.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct Style {
     /// Background fill color
     pub background: Option<Color>,
 
+    /// Character repeated across the content area, drawn over the background
+    pub fill_char: Option<char>,
+
+    /// Color of the fill character
+    pub fill_color: Option<Color>,
+
     /// Layout direction for children
     pub direction: Option<Direction>,
 
     /// Inner spacing around content
     pub padding: Option<Spacing>,
 
+    /// Inner spacing as a fraction of the parent's content size, overriding `padding`.
+    /// Left and right are a fraction of its width, top and bottom of its height.
+    pub padding_fraction: Option<f32>,
+
     /// Overflow behavior for content exceeding bounds
     pub overflow: Option<Overflow>,
 
@@ -447,33 +844,83 @@ pub struct Style {
     /// Height dimension specification
     pub height: Option<Dimension>,
 
+    /// Visual width:height ratio kept by deriving the unset dimension from the
+    /// set one. Ignored when both `width` and `height` are set.
+    pub aspect_ratio: Option<f32>,
+
+    /// How many times taller than wide a cell is, for `aspect_ratio`
+    /// (defaults to [`DEFAULT_CELL_ASPECT`])
+    pub cell_aspect: Option<f32>,
+
     /// Border configuration
     pub border: Option<Border>,
 
+    /// Collapses children onto this element's border and each other's, and
+    /// joins where the lines meet into junctions (`├ ┬ ┼`)
+    pub join_borders: Option<bool>,
+
+    /// Leaves the corner cells of a rounded border transparent, showing the
+    /// background beneath instead of this element's
+    pub clip_corners: Option<bool>,
+
+    /// Whether the element takes its parent's background (the default). With
+    /// `false` its border cells and descendants see no inherited background and
+    /// show what is already drawn beneath them, as text does
+    pub inherit_bg: Option<bool>,
+
     /// Positioning mode (relative, absolute, fixed)
     pub position: Option<Position>,
 
     /// Z-index for layering (higher values render on top)
     pub z_index: Option<i32>,
 
+    /// Z-index with a fractional part (e.g. `1.5`), overriding `z_index`
+    pub fractional_z_index: Option<f32>,
+
+    /// Moves an absolutely positioned element to the other side of its parent
+    /// when it would extend past the viewport (dropdowns, popups)
+    pub flip_to_fit: Option<bool>,
+
+    /// Paints the element in a top-level overlay layer after the main tree,
+    /// outside its ancestors' clipping (higher layers paint on top)
+    pub portal: Option<i32>,
+
     /// Position offset from top edge (for absolute/fixed positioning)
     pub top: Option<i16>,
 
+    /// Offset from the top edge as a fraction of the container, overriding `top`
+    pub top_fraction: Option<f32>,
+
     /// Position offset from right edge (for absolute/fixed positioning)
     pub right: Option<i16>,
 
+    /// Offset from the right edge as a fraction of the container, overriding `right`
+    pub right_fraction: Option<f32>,
+
     /// Position offset from bottom edge (for absolute/fixed positioning)
     pub bottom: Option<i16>,
 
+    /// Offset from the bottom edge as a fraction of the container, overriding `bottom`
+    pub bottom_fraction: Option<f32>,
+
     /// Position offset from left edge (for absolute/fixed positioning)
     pub left: Option<i16>,
 
+    /// Offset from the left edge as a fraction of the container, overriding `left`
+    pub left_fraction: Option<f32>,
+
+    /// Shift of a positioned element by `(x, y)`, with percentages of its own size
+    pub translate: Option<(Offset, Offset)>,
+
     /// Wrapping mode for child elements
     pub wrap: Option<WrapMode>,
 
     /// Gap between wrapped rows/columns
     pub gap: Option<u16>,
 
+    /// Gap as a fraction of the parent's content size along the main axis, overriding `gap`
+    pub gap_fraction: Option<f32>,
+
     /// Outer spacing around element
     pub margin: Option<Spacing>,
 
@@ -509,6 +956,13 @@ pub struct Style {
 
     /// Allows this element to override parent's align_items
     pub align_self: Option<AlignSelf>,
+
+    /// Places the packed block of children within the content box on both axes.
+    ///
+    /// Takes precedence over `justify_content`; `align_items` and `align_self`
+    /// then align children within the block rather than the content box.
+    /// Wrapping containers ignore it.
+    pub place_content: Option<AlignItems>,
 }
 
 /// Style properties specific to text elements.
@@ -516,6 +970,11 @@ pub struct Style {
 /// Controls the visual appearance of text including color,
 /// decorations, and other text-specific properties.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 pub struct TextStyle {
     /// Foreground color of the text
     pub color: Option<Color>,
@@ -532,14 +991,44 @@ pub struct TextStyle {
     /// Underlined text decoration
     pub underline: Option<bool>,
 
+    /// Underline decoration style (single, double, curly, ...)
+    pub underline_style: Option<UnderlineStyle>,
+
+    /// Underline color, independent of the text color
+    pub underline_color: Option<Color>,
+
     /// Strikethrough text decoration
     pub strikethrough: Option<bool>,
 
+    /// Blinking text (ignored by terminals without blink support)
+    pub blink: Option<bool>,
+
+    /// Swapped foreground and background colors
+    pub reverse: Option<bool>,
+
+    /// Concealed text, drawn as blank cells by terminals that support it
+    pub conceal: Option<bool>,
+
     /// Text wrapping mode
     pub wrap: Option<TextWrap>,
 
     /// Text alignment within container
     pub align: Option<TextAlign>,
+
+    /// Columns between tab stops that `\t` expands to, overriding the app default
+    pub tab_width: Option<u16>,
+
+    /// Paints the background across the node's width rather than only behind the text
+    pub bg_fill: Option<bool>,
+
+    /// Case transform applied when the text is displayed
+    pub text_transform: Option<TextTransform>,
+
+    /// Where word wrapping may break lines besides whitespace
+    pub line_break: Option<LineBreak>,
+
+    /// Ends text cut short by the space it is laid out in with an ellipsis
+    pub ellipsis: Option<bool>,
 }
 
 /// Builder for creating styles with a fluent API.
@@ -665,6 +1154,61 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Self {
         Color::Rgb(r, g, b)
     }
+
+    /// Creates an RGB color from hue, saturation and lightness.
+    ///
+    /// The hue is in degrees and wraps around, so `-30.0` is `330.0`.
+    /// Saturation and lightness are fractions, clamped to `0.0..=1.0`.
+    /// Stepping the hue gives evenly spaced palette colors:
+    ///
+    /// ```text
+    /// let series: Vec<Color> = (0..6).map(|i| Color::hsl(i as f32 * 60.0, 0.7, 0.6)).collect();
+    /// ```
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+
+        let offset = lightness - chroma / 2.0;
+        let channel = |value: f32| ((value + offset) * 255.0).round() as u8;
+        Color::Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Returns the terminal color with a snake_case name such as `bright_blue`.
+    fn from_terminal_name(name: &str) -> Option<Self> {
+        let color = match name {
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "white" => Color::White,
+            "bright_black" => Color::BrightBlack,
+            "bright_red" => Color::BrightRed,
+            "bright_green" => Color::BrightGreen,
+            "bright_yellow" => Color::BrightYellow,
+            "bright_blue" => Color::BrightBlue,
+            "bright_magenta" => Color::BrightMagenta,
+            "bright_cyan" => Color::BrightCyan,
+            "bright_white" => Color::BrightWhite,
+            "default" => Color::Default,
+            _ => return None,
+        };
+        Some(color)
+    }
 }
 
 /// Parses a single hex digit into a u8 value.
@@ -677,6 +1221,117 @@ fn parse_hex_digit(c: char) -> Result<u8, &'static str> {
     }
 }
 
+/// Parses the arguments of `rgb(...)`: three channels from 0 to 255, or percentages.
+fn parse_rgb_args(args: &str) -> Result<Color, &'static str> {
+    let [r, g, b] = color_args(args).ok_or("rgb() takes three values")?;
+    let channel = |value: &str| match value.strip_suffix('%') {
+        Some(percent) => percent
+            .parse::<f32>()
+            .ok()
+            .filter(|percent| (0.0..=100.0).contains(percent))
+            .map(|percent| (percent * 2.55).round() as u8),
+        None => value.parse::<u8>().ok(),
+    };
+    let invalid = "rgb() values must be 0 to 255 or a percentage";
+    Ok(Color::Rgb(
+        channel(r).ok_or(invalid)?,
+        channel(g).ok_or(invalid)?,
+        channel(b).ok_or(invalid)?,
+    ))
+}
+
+/// Parses the arguments of `hsl(...)`: a hue in degrees, then saturation and lightness percentages.
+fn parse_hsl_args(args: &str) -> Result<Color, &'static str> {
+    let [h, s, l] = color_args(args).ok_or("hsl() takes three values")?;
+    let hue = h
+        .strip_suffix("deg")
+        .unwrap_or(h)
+        .parse::<f32>()
+        .ok()
+        .filter(|hue| hue.is_finite())
+        .ok_or("hsl() hue must be a number of degrees")?;
+    let percent = |value: &str| {
+        value
+            .strip_suffix('%')
+            .unwrap_or(value)
+            .parse::<f32>()
+            .ok()
+            .filter(|percent| (0.0..=100.0).contains(percent))
+            .ok_or("hsl() saturation and lightness must be 0% to 100%")
+    };
+    Ok(Color::hsl(hue, percent(s)? / 100.0, percent(l)? / 100.0))
+}
+
+/// Splits exactly three color function arguments, separated by commas or spaces.
+fn color_args(args: &str) -> Option<[&str; 3]> {
+    let mut values = args
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|value| !value.is_empty());
+    let args = [values.next()?, values.next()?, values.next()?];
+    values.next().is_none().then_some(args)
+}
+
+/// A fraction of `size`, rounded to the nearest whole cell.
+fn fraction_of(size: u16, fraction: f32) -> u16 {
+    (size as f32 * fraction.max(0.0)).round() as u16
+}
+
+impl Offset {
+    /// An offset of whole cells.
+    pub fn cells(cells: i16) -> Self {
+        Offset::Cells(cells)
+    }
+
+    /// An offset of a fraction of the reference size, e.g. `0.5` for 50%.
+    pub fn percent(fraction: f32) -> Self {
+        Offset::Percent(fraction)
+    }
+
+    /// Splits the offset into the cell and fraction fields of a [`Style`].
+    pub(crate) fn into_parts(self) -> (Option<i16>, Option<f32>) {
+        match self {
+            Offset::Cells(cells) => (Some(cells), None),
+            Offset::Percent(fraction) => (None, Some(fraction)),
+        }
+    }
+
+    /// The offset in cells for a reference size of `size`, rounded to the nearest cell.
+    pub fn resolve(self, size: u16) -> i16 {
+        match self {
+            Offset::Cells(cells) => cells,
+            Offset::Percent(fraction) => (size as f32 * fraction)
+                .round()
+                .clamp(i16::MIN as f32, i16::MAX as f32)
+                as i16,
+        }
+    }
+}
+
+impl ZIndex {
+    /// Splits the z-index into the whole and fractional fields of a [`Style`].
+    pub(crate) fn into_parts(self) -> (Option<i32>, Option<f32>) {
+        match self {
+            ZIndex::Whole(z) => (Some(z), None),
+            ZIndex::Fractional(z) => (None, Some(z)),
+        }
+    }
+}
+
+impl Direction {
+    /// Returns whether children are laid out along the horizontal axis.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Horizontal | Direction::HorizontalReverse)
+    }
+
+    /// Returns whether children are placed starting from the far edge.
+    pub fn is_reversed(self) -> bool {
+        matches!(
+            self,
+            Direction::VerticalReverse | Direction::HorizontalReverse
+        )
+    }
+}
+
 impl Style {
     /// Creates the default focus style for focusable elements.
     ///
@@ -695,10 +1350,119 @@ impl Style {
         }
     }
 
+    /// Builds a style from an already-parsed document value.
+    ///
+    /// Accepts any self-describing serde value, such as `toml::Value` or
+    /// `serde_json::Value`. Colors may be named (`bright_blue`) or hex
+    /// (`"#ff8800"`), and unknown keys are rejected.
+    ///
+    /// ## Example
+    ///
+    /// ```text
+    /// let value: toml::Value = toml::from_str("background = \"blue\"\npadding = 1")?;
+    /// let style = Style::from_value(value)?;
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_value<'de, D>(value: D) -> Result<Style, crate::theme::ThemeError>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(value)
+            .map_err(|e: D::Error| crate::theme::ThemeError::Value(e.to_string()))
+    }
+
+    /// Padding in whole cells for an element whose parent's content box has the given size.
+    ///
+    /// A `padding_fraction` takes precedence over `padding` and is rounded to
+    /// the nearest cell.
+    pub fn resolved_padding(&self, width: u16, height: u16) -> Spacing {
+        match self.padding_fraction {
+            Some(fraction) => {
+                let horizontal = fraction_of(width, fraction);
+                let vertical = fraction_of(height, fraction);
+                Spacing {
+                    top: vertical,
+                    right: horizontal,
+                    bottom: vertical,
+                    left: horizontal,
+                }
+            }
+            None => self.padding.unwrap_or(Spacing::all(0)),
+        }
+    }
+
+    /// Gap in whole cells when the parent's content box is `main_size` long on the main axis.
+    ///
+    /// A `gap_fraction` takes precedence over `gap` and is rounded to the
+    /// nearest cell.
+    pub fn resolved_gap(&self, main_size: u16) -> u16 {
+        match self.gap_fraction {
+            Some(fraction) => fraction_of(main_size, fraction),
+            None => self.gap.unwrap_or(0),
+        }
+    }
+
+    /// Position offsets in whole cells as `(top, right, bottom, left)` inside a
+    /// container of the given size.
+    ///
+    /// Each `*_fraction` takes precedence over the matching cell offset and is
+    /// rounded to the nearest cell.
+    pub fn resolved_offsets(
+        &self,
+        width: u16,
+        height: u16,
+    ) -> (Option<i16>, Option<i16>, Option<i16>, Option<i16>) {
+        let resolve = |cells: Option<i16>, fraction: Option<f32>, size: u16| {
+            fraction
+                .map(|fraction| Offset::Percent(fraction).resolve(size))
+                .or(cells)
+        };
+        (
+            resolve(self.top, self.top_fraction, height),
+            resolve(self.right, self.right_fraction, width),
+            resolve(self.bottom, self.bottom_fraction, height),
+            resolve(self.left, self.left_fraction, width),
+        )
+    }
+
+    /// The z-index used for stacking, if one is set.
+    ///
+    /// `fractional_z_index` takes precedence over `z_index`.
+    pub fn resolved_z_index(&self) -> Option<f32> {
+        self.fractional_z_index.or(self.z_index.map(|z| z as f32))
+    }
+
+    /// Applies `aspect_ratio` to a resolved `(width, height)` in cells.
+    ///
+    /// With only `height` set, the width is derived from it; otherwise the
+    /// height is derived from the width. When both `width` and `height` are
+    /// set, or there is no usable ratio, the size is returned unchanged.
+    pub fn aspect_size(&self, width: u16, height: u16) -> (u16, u16) {
+        let cell_aspect = self.cell_aspect.unwrap_or(DEFAULT_CELL_ASPECT);
+        let Some(factor) = self
+            .aspect_ratio
+            .map(|ratio| ratio * cell_aspect)
+            .filter(|factor| factor.is_finite() && *factor > 0.0)
+        else {
+            return (width, height);
+        };
+
+        let is_set = |dimension: Option<Dimension>| {
+            dimension.is_some_and(|d| !matches!(d, Dimension::Content))
+        };
+        match (is_set(self.width), is_set(self.height)) {
+            (true, true) => (width, height),
+            (false, true) => ((height as f32 * factor).round() as u16, height),
+            _ => (width, (width as f32 / factor).round() as u16),
+        }
+    }
+
     /// Merges two styles, with the overlay style taking precedence.
     ///
-    /// This is used to apply focus styles on top of base styles.
-    /// Any property set in the overlay will override the base.
+    /// Every property set in the overlay replaces the base's, and unset (`None`)
+    /// properties leave the base's value in place, so later layers win. A
+    /// fixed `padding` or `gap` in the overlay also clears the base's fraction.
+    /// Focus and hover styles are layered onto the base style this way.
     pub fn merge(base: Option<Style>, overlay: Option<Style>) -> Option<Style> {
         match (base, overlay) {
             (None, None) => None,
@@ -709,11 +1473,22 @@ impl Style {
                 if overlay.background.is_some() {
                     base.background = overlay.background;
                 }
+                if overlay.fill_char.is_some() {
+                    base.fill_char = overlay.fill_char;
+                }
+                if overlay.fill_color.is_some() {
+                    base.fill_color = overlay.fill_color;
+                }
                 if overlay.direction.is_some() {
                     base.direction = overlay.direction;
                 }
+                // A fixed value in the overlay replaces a fraction in the base
                 if overlay.padding.is_some() {
                     base.padding = overlay.padding;
+                    base.padding_fraction = None;
+                }
+                if overlay.padding_fraction.is_some() {
+                    base.padding_fraction = overlay.padding_fraction;
                 }
                 if overlay.overflow.is_some() {
                     base.overflow = overlay.overflow;
@@ -724,32 +1499,80 @@ impl Style {
                 if overlay.height.is_some() {
                     base.height = overlay.height;
                 }
+                if overlay.aspect_ratio.is_some() {
+                    base.aspect_ratio = overlay.aspect_ratio;
+                }
+                if overlay.cell_aspect.is_some() {
+                    base.cell_aspect = overlay.cell_aspect;
+                }
                 if overlay.border.is_some() {
                     base.border = overlay.border;
                 }
+                if overlay.join_borders.is_some() {
+                    base.join_borders = overlay.join_borders;
+                }
+                if overlay.clip_corners.is_some() {
+                    base.clip_corners = overlay.clip_corners;
+                }
+                if overlay.inherit_bg.is_some() {
+                    base.inherit_bg = overlay.inherit_bg;
+                }
                 if overlay.position.is_some() {
                     base.position = overlay.position;
                 }
                 if overlay.z_index.is_some() {
                     base.z_index = overlay.z_index;
+                    base.fractional_z_index = None;
+                }
+                if overlay.fractional_z_index.is_some() {
+                    base.fractional_z_index = overlay.fractional_z_index;
+                }
+                if overlay.flip_to_fit.is_some() {
+                    base.flip_to_fit = overlay.flip_to_fit;
+                }
+                if overlay.portal.is_some() {
+                    base.portal = overlay.portal;
                 }
                 if overlay.top.is_some() {
                     base.top = overlay.top;
+                    base.top_fraction = None;
+                }
+                if overlay.top_fraction.is_some() {
+                    base.top_fraction = overlay.top_fraction;
                 }
                 if overlay.right.is_some() {
                     base.right = overlay.right;
+                    base.right_fraction = None;
+                }
+                if overlay.right_fraction.is_some() {
+                    base.right_fraction = overlay.right_fraction;
                 }
                 if overlay.bottom.is_some() {
                     base.bottom = overlay.bottom;
+                    base.bottom_fraction = None;
+                }
+                if overlay.bottom_fraction.is_some() {
+                    base.bottom_fraction = overlay.bottom_fraction;
                 }
                 if overlay.left.is_some() {
                     base.left = overlay.left;
+                    base.left_fraction = None;
+                }
+                if overlay.left_fraction.is_some() {
+                    base.left_fraction = overlay.left_fraction;
+                }
+                if overlay.translate.is_some() {
+                    base.translate = overlay.translate;
                 }
                 if overlay.wrap.is_some() {
                     base.wrap = overlay.wrap;
                 }
                 if overlay.gap.is_some() {
                     base.gap = overlay.gap;
+                    base.gap_fraction = None;
+                }
+                if overlay.gap_fraction.is_some() {
+                    base.gap_fraction = overlay.gap_fraction;
                 }
                 if overlay.show_scrollbar.is_some() {
                     base.show_scrollbar = overlay.show_scrollbar;
@@ -763,17 +1586,100 @@ impl Style {
                 if overlay.align_self.is_some() {
                     base.align_self = overlay.align_self;
                 }
+                if overlay.place_content.is_some() {
+                    base.place_content = overlay.place_content;
+                }
+                if overlay.margin.is_some() {
+                    base.margin = overlay.margin;
+                }
+                if overlay.min_width.is_some() {
+                    base.min_width = overlay.min_width;
+                }
+                if overlay.min_height.is_some() {
+                    base.min_height = overlay.min_height;
+                }
+                if overlay.max_width.is_some() {
+                    base.max_width = overlay.max_width;
+                }
+                if overlay.max_height.is_some() {
+                    base.max_height = overlay.max_height;
+                }
+                if overlay.border_color.is_some() {
+                    base.border_color = overlay.border_color;
+                }
+                if overlay.x.is_some() {
+                    base.x = overlay.x;
+                }
+                if overlay.y.is_some() {
+                    base.y = overlay.y;
+                }
                 Some(base)
             }
         }
     }
 
+    /// Creates a style with all properties unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a style that fills the background with a color.
+    pub fn filled(color: Color) -> Self {
+        Self::new().background(color)
+    }
+
+    /// Creates a style with a single-line border in a color.
+    pub fn bordered(color: Color) -> Self {
+        Self::new().border(color)
+    }
+
+    /// Creates a style with the given padding.
+    pub fn padded(padding: Spacing) -> Self {
+        Self::new().padding(padding)
+    }
+
+    /// Creates a style that centers children on both axes.
+    pub fn centered() -> Self {
+        Self::new()
+            .justify_content(JustifyContent::Center)
+            .align_items(AlignItems::Center)
+    }
+
+    /// Layers another style on top of this one.
+    ///
+    /// Same as [`Style::merge`]: properties set in `overlay` win and unset
+    /// ones are transparent, which makes it easy to derive variants from a
+    /// shared base:
+    ///
+    /// ```text
+    /// fn card() -> Style {
+    ///     Style::filled(Color::Black).padding(Spacing::all(1))
+    /// }
+    ///
+    /// let selected = card().overlay(Style::bordered(Color::Yellow));
+    /// ```
+    pub fn overlay(self, overlay: Style) -> Self {
+        Self::merge(Some(self), Some(overlay)).unwrap_or_default()
+    }
+
     /// Sets the background color.
     pub fn background(mut self, color: Color) -> Self {
         self.background = Some(color);
         self
     }
 
+    /// Fills the content area with a repeated character, such as `'·'`.
+    pub fn fill_char(mut self, ch: char) -> Self {
+        self.fill_char = Some(ch);
+        self
+    }
+
+    /// Sets the color of the fill character.
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
     /// Sets the layout direction for child elements.
     pub fn direction(mut self, direction: Direction) -> Self {
         self.direction = Some(direction);
@@ -786,6 +1692,12 @@ impl Style {
         self
     }
 
+    /// Sets the inner padding as a fraction of the parent's content size.
+    pub fn padding_fraction(mut self, fraction: f32) -> Self {
+        self.padding_fraction = Some(fraction);
+        self
+    }
+
     /// Sets the overflow behavior.
     pub fn overflow(mut self, overflow: Overflow) -> Self {
         self.overflow = Some(overflow);
@@ -804,6 +1716,18 @@ impl Style {
         self
     }
 
+    /// Sets the visual width:height ratio to keep.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets how many times taller than wide a cell is, for `aspect_ratio`.
+    pub fn cell_aspect(mut self, factor: f32) -> Self {
+        self.cell_aspect = Some(factor);
+        self
+    }
+
     /// Enables border with the specified color.
     pub fn border(mut self, color: Color) -> Self {
         self.border = Some(Border {
@@ -821,33 +1745,71 @@ impl Style {
         self
     }
 
+    /// Sets whether children's borders are collapsed and joined with this one.
+    pub fn join_borders(mut self, join: bool) -> Self {
+        self.join_borders = Some(join);
+        self
+    }
+
+    /// Sets whether rounded border corners show the background beneath.
+    pub fn clip_corners(mut self, clip: bool) -> Self {
+        self.clip_corners = Some(clip);
+        self
+    }
+
+    /// Sets whether the element inherits its parent's background.
+    pub fn inherit_bg(mut self, inherit: bool) -> Self {
+        self.inherit_bg = Some(inherit);
+        self
+    }
+
     /// Sets the z-index for layering.
-    pub fn z_index(mut self, z_index: i32) -> Self {
-        self.z_index = Some(z_index);
+    ///
+    /// Accepts whole (`5`) or fractional (`1.5`) values, which may be negative.
+    pub fn z_index(mut self, z_index: impl Into<ZIndex>) -> Self {
+        (self.z_index, self.fractional_z_index) = z_index.into().into_parts();
+        self
+    }
+
+    /// Sets whether an absolutely positioned element flips to stay on screen.
+    pub fn flip_to_fit(mut self, flip: bool) -> Self {
+        self.flip_to_fit = Some(flip);
+        self
+    }
+
+    /// Paints the element in the top-level overlay layer `layer`.
+    pub fn portal(mut self, layer: i32) -> Self {
+        self.portal = Some(layer);
         self
     }
 
     /// Sets the top position offset.
-    pub fn top(mut self, top: i16) -> Self {
-        self.top = Some(top);
+    pub fn top(mut self, top: impl Into<Offset>) -> Self {
+        (self.top, self.top_fraction) = top.into().into_parts();
         self
     }
 
     /// Sets the right position offset.
-    pub fn right(mut self, right: i16) -> Self {
-        self.right = Some(right);
+    pub fn right(mut self, right: impl Into<Offset>) -> Self {
+        (self.right, self.right_fraction) = right.into().into_parts();
         self
     }
 
     /// Sets the bottom position offset.
-    pub fn bottom(mut self, bottom: i16) -> Self {
-        self.bottom = Some(bottom);
+    pub fn bottom(mut self, bottom: impl Into<Offset>) -> Self {
+        (self.bottom, self.bottom_fraction) = bottom.into().into_parts();
         self
     }
 
     /// Sets the left position offset.
-    pub fn left(mut self, left: i16) -> Self {
-        self.left = Some(left);
+    pub fn left(mut self, left: impl Into<Offset>) -> Self {
+        (self.left, self.left_fraction) = left.into().into_parts();
+        self
+    }
+
+    /// Shifts a positioned element by fractions of its own size or by cells.
+    pub fn translate(mut self, x: impl Into<Offset>, y: impl Into<Offset>) -> Self {
+        self.translate = Some((x.into(), y.into()));
         self
     }
 
@@ -863,11 +1825,89 @@ impl Style {
         self
     }
 
+    /// Sets the gap as a fraction of the parent's content size along the main axis.
+    pub fn gap_fraction(mut self, fraction: f32) -> Self {
+        self.gap_fraction = Some(fraction);
+        self
+    }
+
     /// Sets whether to show scrollbar for scrollable content.
     pub fn show_scrollbar(mut self, show: bool) -> Self {
         self.show_scrollbar = Some(show);
         self
     }
+
+    /// Sets the outer spacing around the element.
+    pub fn margin(mut self, margin: Spacing) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    /// Sets the minimum width in cells.
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Sets the minimum height in cells.
+    pub fn min_height(mut self, height: u16) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    /// Sets the maximum width in cells.
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the maximum height in cells.
+    pub fn max_height(mut self, height: u16) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Sets how children are distributed along the main axis.
+    pub fn justify_content(mut self, justify: JustifyContent) -> Self {
+        self.justify_content = Some(justify);
+        self
+    }
+
+    /// Sets how children are aligned on the cross axis.
+    pub fn align_items(mut self, align: AlignItems) -> Self {
+        self.align_items = Some(align);
+        self
+    }
+
+    /// Overrides the parent's `align_items` for this element.
+    pub fn align_self(mut self, align: AlignSelf) -> Self {
+        self.align_self = Some(align);
+        self
+    }
+
+    /// Places the packed block of children within the content box.
+    pub fn place_content(mut self, place: AlignItems) -> Self {
+        self.place_content = Some(place);
+        self
+    }
+}
+
+impl BorderStyle {
+    /// ASCII-only border (+-+|+-+) for terminals without box-drawing glyphs
+    pub const ASCII: Self = Self::Custom(['+', '-', '+', '|', '|', '+', '-', '+']);
+
+    /// Returns the characters drawn for the border, in the order top-left,
+    /// top, top-right, left, right, bottom-left, bottom, bottom-right.
+    pub fn chars(self) -> [char; 8] {
+        match self {
+            Self::Single => ['┌', '─', '┐', '│', '│', '└', '─', '┘'],
+            Self::Double => ['╔', '═', '╗', '║', '║', '╚', '═', '╝'],
+            Self::Thick => ['┏', '━', '┓', '┃', '┃', '┗', '━', '┛'],
+            Self::Rounded => ['╭', '─', '╮', '│', '│', '╰', '─', '╯'],
+            Self::Dashed => ['┌', '╌', '┐', '╎', '╎', '└', '╌', '┘'],
+            Self::Custom(chars) => chars,
+        }
+    }
 }
 
 impl Border {
@@ -910,6 +1950,12 @@ impl Border {
             edges,
         }
     }
+
+    /// Draws the border with custom characters (see [`BorderStyle::Custom`]).
+    pub fn chars(mut self, chars: [char; 8]) -> Self {
+        self.style = BorderStyle::Custom(chars);
+        self
+    }
 }
 
 impl Style {
@@ -923,10 +1969,28 @@ impl Style {
 }
 
 impl TextStyle {
+    /// Builds a text style from an already-parsed document value.
+    ///
+    /// See [`Style::from_value`] for the accepted value formats.
+    #[cfg(feature = "serde")]
+    pub fn from_value<'de, D>(value: D) -> Result<TextStyle, crate::theme::ThemeError>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(value)
+            .map_err(|e: D::Error| crate::theme::ThemeError::Value(e.to_string()))
+    }
+
+    /// Creates a text style with all properties unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Merges two text styles, with the overlay style taking precedence.
     ///
     /// This is used to apply custom text styles on top of default styles.
-    /// Any property set in the overlay will override the base.
+    /// Any property set in the overlay will override the base, and unset
+    /// (`None`) properties leave the base's value in place.
     pub fn merge(base: Option<TextStyle>, overlay: Option<TextStyle>) -> Option<TextStyle> {
         match (base, overlay) {
             (None, None) => None,
@@ -949,15 +2013,45 @@ impl TextStyle {
                 if overlay.underline.is_some() {
                     base.underline = overlay.underline;
                 }
+                if overlay.underline_style.is_some() {
+                    base.underline_style = overlay.underline_style;
+                }
+                if overlay.underline_color.is_some() {
+                    base.underline_color = overlay.underline_color;
+                }
                 if overlay.strikethrough.is_some() {
                     base.strikethrough = overlay.strikethrough;
                 }
+                if overlay.blink.is_some() {
+                    base.blink = overlay.blink;
+                }
+                if overlay.reverse.is_some() {
+                    base.reverse = overlay.reverse;
+                }
+                if overlay.conceal.is_some() {
+                    base.conceal = overlay.conceal;
+                }
                 if overlay.wrap.is_some() {
                     base.wrap = overlay.wrap;
                 }
                 if overlay.align.is_some() {
                     base.align = overlay.align;
                 }
+                if overlay.tab_width.is_some() {
+                    base.tab_width = overlay.tab_width;
+                }
+                if overlay.bg_fill.is_some() {
+                    base.bg_fill = overlay.bg_fill;
+                }
+                if overlay.text_transform.is_some() {
+                    base.text_transform = overlay.text_transform;
+                }
+                if overlay.line_break.is_some() {
+                    base.line_break = overlay.line_break;
+                }
+                if overlay.ellipsis.is_some() {
+                    base.ellipsis = overlay.ellipsis;
+                }
                 Some(base)
             }
         }
@@ -973,9 +2067,19 @@ impl TextStyle {
                 bold: None,
                 italic: None,
                 underline: None,
+                underline_style: None,
+                underline_color: None,
                 strikethrough: None,
+                blink: None,
+                reverse: None,
+                conceal: None,
                 wrap: None,
                 align: None,
+                tab_width: None,
+                bg_fill: None,
+                text_transform: None,
+                line_break: None,
+                ellipsis: None,
             },
         }
     }
@@ -1010,12 +2114,44 @@ impl TextStyle {
         self
     }
 
+    /// Sets the underline style, enabling the underline.
+    pub fn underline_style(mut self, style: UnderlineStyle) -> Self {
+        self.underline = Some(true);
+        self.underline_style = Some(style);
+        self
+    }
+
+    /// Sets the underline color, enabling the underline.
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.underline = Some(true);
+        self.underline_color = Some(color);
+        self
+    }
+
     /// Makes the text strikethrough.
     pub fn strikethrough(mut self, strikethrough: bool) -> Self {
         self.strikethrough = Some(strikethrough);
         self
     }
 
+    /// Makes the text blink.
+    pub fn blink(mut self, blink: bool) -> Self {
+        self.blink = Some(blink);
+        self
+    }
+
+    /// Swaps the text's foreground and background colors.
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = Some(reverse);
+        self
+    }
+
+    /// Conceals the text.
+    pub fn conceal(mut self, conceal: bool) -> Self {
+        self.conceal = Some(conceal);
+        self
+    }
+
     /// Sets the text wrapping mode.
     pub fn wrap(mut self, wrap: TextWrap) -> Self {
         self.wrap = Some(wrap);
@@ -1027,6 +2163,36 @@ impl TextStyle {
         self.align = Some(align);
         self
     }
+
+    /// Sets the columns between tab stops.
+    pub fn tab_width(mut self, width: u16) -> Self {
+        self.tab_width = Some(width);
+        self
+    }
+
+    /// Sets whether the background fills the node's full width.
+    pub fn bg_fill(mut self, fill: bool) -> Self {
+        self.bg_fill = Some(fill);
+        self
+    }
+
+    /// Sets whether text cut short by its space ends with an ellipsis.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
+
+    /// Sets the case transform applied when displayed.
+    pub fn text_transform(mut self, transform: TextTransform) -> Self {
+        self.text_transform = Some(transform);
+        self
+    }
+
+    /// Sets where word wrapping may break lines besides whitespace.
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.line_break = Some(line_break);
+        self
+    }
 }
 
 impl TextStyleBuilder {
@@ -1066,6 +2232,24 @@ impl TextStyleBuilder {
         self
     }
 
+    /// Makes the text blink.
+    pub fn blink(mut self) -> Self {
+        self.style.blink = Some(true);
+        self
+    }
+
+    /// Swaps the text's foreground and background colors.
+    pub fn reverse(mut self) -> Self {
+        self.style.reverse = Some(true);
+        self
+    }
+
+    /// Conceals the text.
+    pub fn conceal(mut self) -> Self {
+        self.style.conceal = Some(true);
+        self
+    }
+
     /// Convenience method for making text bold (alias for bold()).
     pub fn strong(self) -> Self {
         self.bold()
@@ -1088,6 +2272,36 @@ impl TextStyleBuilder {
         self
     }
 
+    /// Sets the columns between tab stops.
+    pub fn tab_width(mut self, width: u16) -> Self {
+        self.style.tab_width = Some(width);
+        self
+    }
+
+    /// Sets whether the background fills the node's full width.
+    pub fn bg_fill(mut self, fill: bool) -> Self {
+        self.style.bg_fill = Some(fill);
+        self
+    }
+
+    /// Sets whether text cut short by its space ends with an ellipsis.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.style.ellipsis = Some(ellipsis);
+        self
+    }
+
+    /// Sets the case transform applied when displayed.
+    pub fn text_transform(mut self, transform: TextTransform) -> Self {
+        self.style.text_transform = Some(transform);
+        self
+    }
+
+    /// Sets where word wrapping may break lines besides whitespace.
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.style.line_break = Some(line_break);
+        self
+    }
+
     /// Builds the final TextStyle instance.
     pub fn build(self) -> TextStyle {
         self.style
@@ -1162,6 +2376,18 @@ impl StyleBuilder {
         self
     }
 
+    /// Fills the content area with a repeated character, such as `'·'`.
+    pub fn fill_char(mut self, ch: char) -> Self {
+        self.style.fill_char = Some(ch);
+        self
+    }
+
+    /// Sets the color of the fill character.
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.style.fill_color = Some(color);
+        self
+    }
+
     /// Sets the layout direction for child elements.
     pub fn direction(mut self, direction: Direction) -> Self {
         self.style.direction = Some(direction);
@@ -1174,6 +2400,12 @@ impl StyleBuilder {
         self
     }
 
+    /// Sets the inner padding as a fraction of the parent's content size.
+    pub fn padding_fraction(mut self, fraction: f32) -> Self {
+        self.style.padding_fraction = Some(fraction);
+        self
+    }
+
     /// Sets the overflow behavior.
     pub fn overflow(mut self, overflow: Overflow) -> Self {
         self.style.overflow = Some(overflow);
@@ -1192,6 +2424,18 @@ impl StyleBuilder {
         self
     }
 
+    /// Sets the visual width:height ratio to keep.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.style.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Sets how many times taller than wide a cell is, for `aspect_ratio`.
+    pub fn cell_aspect(mut self, factor: f32) -> Self {
+        self.style.cell_aspect = Some(factor);
+        self
+    }
+
     /// Enables border with the specified color.
     pub fn border(mut self, color: Color) -> Self {
         self.style.border = Some(Border {
@@ -1209,33 +2453,69 @@ impl StyleBuilder {
         self
     }
 
+    /// Sets whether children's borders are collapsed and joined with this one.
+    pub fn join_borders(mut self, join: bool) -> Self {
+        self.style.join_borders = Some(join);
+        self
+    }
+
+    /// Sets whether rounded border corners show the background beneath.
+    pub fn clip_corners(mut self, clip: bool) -> Self {
+        self.style.clip_corners = Some(clip);
+        self
+    }
+
+    /// Sets whether the element inherits its parent's background.
+    pub fn inherit_bg(mut self, inherit: bool) -> Self {
+        self.style.inherit_bg = Some(inherit);
+        self
+    }
+
     /// Sets the z-index for layering.
-    pub fn z_index(mut self, z_index: i32) -> Self {
-        self.style.z_index = Some(z_index);
+    pub fn z_index(mut self, z_index: impl Into<ZIndex>) -> Self {
+        (self.style.z_index, self.style.fractional_z_index) = z_index.into().into_parts();
+        self
+    }
+
+    /// Sets whether an absolutely positioned element flips to stay on screen.
+    pub fn flip_to_fit(mut self, flip: bool) -> Self {
+        self.style.flip_to_fit = Some(flip);
+        self
+    }
+
+    /// Paints the element in the top-level overlay layer `layer`.
+    pub fn portal(mut self, layer: i32) -> Self {
+        self.style.portal = Some(layer);
         self
     }
 
     /// Sets the top position offset.
-    pub fn top(mut self, top: i16) -> Self {
-        self.style.top = Some(top);
+    pub fn top(mut self, top: impl Into<Offset>) -> Self {
+        (self.style.top, self.style.top_fraction) = top.into().into_parts();
         self
     }
 
     /// Sets the right position offset.
-    pub fn right(mut self, right: i16) -> Self {
-        self.style.right = Some(right);
+    pub fn right(mut self, right: impl Into<Offset>) -> Self {
+        (self.style.right, self.style.right_fraction) = right.into().into_parts();
         self
     }
 
     /// Sets the bottom position offset.
-    pub fn bottom(mut self, bottom: i16) -> Self {
-        self.style.bottom = Some(bottom);
+    pub fn bottom(mut self, bottom: impl Into<Offset>) -> Self {
+        (self.style.bottom, self.style.bottom_fraction) = bottom.into().into_parts();
         self
     }
 
     /// Sets the left position offset.
-    pub fn left(mut self, left: i16) -> Self {
-        self.style.left = Some(left);
+    pub fn left(mut self, left: impl Into<Offset>) -> Self {
+        (self.style.left, self.style.left_fraction) = left.into().into_parts();
+        self
+    }
+
+    /// Shifts a positioned element by fractions of its own size or by cells.
+    pub fn translate(mut self, x: impl Into<Offset>, y: impl Into<Offset>) -> Self {
+        self.style.translate = Some((x.into(), y.into()));
         self
     }
 
@@ -1251,6 +2531,12 @@ impl StyleBuilder {
         self
     }
 
+    /// Sets the gap as a fraction of the parent's content size along the main axis.
+    pub fn gap_fraction(mut self, fraction: f32) -> Self {
+        self.style.gap_fraction = Some(fraction);
+        self
+    }
+
     /// Builds the final Style instance.
     pub fn build(self) -> Style {
         self.style
@@ -1261,25 +2547,146 @@ impl StyleBuilder {
 // Trait Implementations
 //--------------------------------------------------------------------------------------------------
 
+/// Parses a color written as in CSS or a theme file.
+///
+/// Accepted forms, tried in this order:
+/// - `#`-prefixed hex: `"#f53"`, `"#ff5733"`
+/// - terminal colors in snake_case: `"red"`, `"bright_blue"`, and `"default"`
+/// - `"rgb(255, 87, 51)"`, with commas or spaces, channels 0-255 or percentages
+/// - `"hsl(210, 50%, 40%)"`, hue in degrees
+/// - CSS named colors: `"tomato"`, `"slategray"`
+/// - hex without the `#`, as accepted by [`Color::from_hex`]
+///
+/// Names and function names are case-insensitive. Terminal names win over the
+/// CSS colors of the same name, so `"red"` is the terminal's red rather than
+/// `rgb(255, 0, 0)`.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| ParseColorError {
+            input: value.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let trimmed = value.trim();
+        if trimmed.starts_with('#') {
+            return Color::from_hex(trimmed).map_err(error);
+        }
+
+        let name = trimmed.to_ascii_lowercase();
+        if let Some(color) = Color::from_terminal_name(&name) {
+            return Ok(color);
+        }
+
+        for (function, parse) in [
+            (
+                "rgb(",
+                parse_rgb_args as fn(&str) -> Result<Color, &'static str>,
+            ),
+            ("hsl(", parse_hsl_args),
+        ] {
+            if let Some(rest) = name.strip_prefix(function) {
+                let args = rest.strip_suffix(')').ok_or_else(|| error("missing `)`"))?;
+                return parse(args).map_err(error);
+            }
+        }
+
+        if let Ok(index) = CSS_COLORS.binary_search_by_key(&name.as_str(), |(name, _)| name) {
+            return Ok(CSS_COLORS[index].1);
+        }
+
+        Color::from_hex(trimmed).map_err(|_| error("unknown color name"))
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Custom rules are equal when they are the same function.
+impl PartialEq for LineBreak {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (LineBreak::Whitespace, LineBreak::Whitespace) => true,
+            (LineBreak::Cjk, LineBreak::Cjk) => true,
+            (LineBreak::Custom(a), LineBreak::Custom(b)) => std::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+/// Custom rules are written as `"custom"`, which is refused when read back.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LineBreak {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (index, name) = match self {
+            LineBreak::Whitespace => (0, "whitespace"),
+            LineBreak::Cjk => (1, "cjk"),
+            LineBreak::Custom(_) => (2, "custom"),
+        };
+        serializer.serialize_unit_variant("LineBreak", index, name)
+    }
+}
+
+impl From<i16> for Offset {
+    fn from(cells: i16) -> Self {
+        Offset::Cells(cells)
+    }
+}
+
+impl From<i32> for ZIndex {
+    fn from(z: i32) -> Self {
+        ZIndex::Whole(z)
+    }
+}
+
+impl From<f32> for ZIndex {
+    fn from(z: f32) -> Self {
+        ZIndex::Fractional(z)
+    }
+}
+
 /// Default style with all properties unset.
 impl Default for Style {
     fn default() -> Self {
         Self {
             background: None,
+            fill_char: None,
+            fill_color: None,
             direction: None,
             padding: None,
+            padding_fraction: None,
             overflow: None,
             width: None,
             height: None,
+            aspect_ratio: None,
+            cell_aspect: None,
             border: None,
+            join_borders: None,
+            clip_corners: None,
+            inherit_bg: None,
             position: None,
             z_index: None,
+            fractional_z_index: None,
+            flip_to_fit: None,
+            portal: None,
             top: None,
             right: None,
             bottom: None,
             left: None,
+            top_fraction: None,
+            right_fraction: None,
+            bottom_fraction: None,
+            left_fraction: None,
+            translate: None,
             wrap: None,
             gap: None,
+            gap_fraction: None,
             margin: None,
             min_width: None,
             min_height: None,
@@ -1292,6 +2699,7 @@ impl Default for Style {
             justify_content: None,
             align_items: None,
             align_self: None,
+            place_content: None,
         }
     }
 }
@@ -1305,9 +2713,19 @@ impl Default for TextStyle {
             bold: None,
             italic: None,
             underline: None,
+            underline_style: None,
+            underline_color: None,
             strikethrough: None,
+            blink: None,
+            reverse: None,
+            conceal: None,
             wrap: None,
             align: None,
+            tab_width: None,
+            bg_fill: None,
+            text_transform: None,
+            line_break: None,
+            ellipsis: None,
         }
     }
 }
@@ -1320,6 +2738,50 @@ impl Default for TextStyle {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_color_from_str() {
+        assert_eq!("#FF5733".parse(), Ok(Color::Rgb(255, 87, 51)));
+        assert_eq!("bright_blue".parse(), Ok(Color::BrightBlue));
+        assert_eq!(" Red ".parse(), Ok(Color::Red));
+        assert_eq!("tomato".parse(), Ok(Color::Rgb(255, 99, 71)));
+        assert_eq!("SlateGray".parse(), Ok(Color::Rgb(112, 128, 144)));
+        assert_eq!("rgb(10, 20, 30)".parse(), Ok(Color::Rgb(10, 20, 30)));
+        assert_eq!("rgb(100% 0% 50%)".parse(), Ok(Color::Rgb(255, 0, 128)));
+        assert_eq!("hsl(120, 100%, 25%)".parse(), Ok(Color::Rgb(0, 128, 0)));
+        assert_eq!("HSL(-120deg 100% 50%)".parse(), Ok(Color::Rgb(0, 0, 255)));
+        assert_eq!("F53".parse(), Ok(Color::Rgb(255, 85, 51)));
+    }
+
+    #[test]
+    fn test_color_from_str_errors() {
+        let reason = |value: &str| value.parse::<Color>().unwrap_err().reason;
+        assert_eq!(reason("tomatoes"), "unknown color name");
+        assert_eq!(reason("#GG0000"), "Invalid hex color format");
+        assert_eq!(reason("rgb(1, 2)"), "rgb() takes three values");
+        assert_eq!(
+            reason("rgb(1, 2, 300)"),
+            "rgb() values must be 0 to 255 or a percentage"
+        );
+        assert_eq!(reason("rgb(1, 2, 3"), "missing `)`");
+        assert_eq!(
+            reason("hsl(0, 150%, 50%)"),
+            "hsl() saturation and lightness must be 0% to 100%"
+        );
+        assert_eq!(
+            "nope".parse::<Color>().unwrap_err().to_string(),
+            "invalid color \"nope\": unknown color name"
+        );
+    }
+
+    #[test]
+    fn test_hsl_primaries_and_grays() {
+        assert_eq!(Color::hsl(0.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::hsl(360.0, 1.0, 0.5), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::hsl(240.0, 1.0, 0.5), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::hsl(90.0, 0.0, 0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(Color::hsl(0.0, 2.0, 1.5), Color::Rgb(255, 255, 255));
+    }
+
     #[test]
     fn test_hex_color_parsing() {
         // Test 1-digit hex (grayscale)
@@ -1378,4 +2840,85 @@ mod tests {
         assert_eq!(Color::rgb(0, 0, 0), Color::Rgb(0, 0, 0));
         assert_eq!(Color::rgb(255, 255, 255), Color::Rgb(255, 255, 255));
     }
+
+    #[test]
+    fn test_fractional_spacing_resolution() {
+        let style = Style::default()
+            .padding(Spacing::all(3))
+            .padding_fraction(0.1);
+        let padding = style.resolved_padding(45, 10);
+        assert_eq!((padding.left, padding.right), (5, 5));
+        assert_eq!((padding.top, padding.bottom), (1, 1));
+        assert_eq!(style.gap_fraction(0.25).resolved_gap(10), 3);
+    }
+
+    #[test]
+    fn test_fractional_z_index() {
+        assert_eq!(Style::default().z_index(1.5).resolved_z_index(), Some(1.5));
+        assert_eq!(Style::default().z_index(-2).resolved_z_index(), Some(-2.0));
+        assert_eq!(Style::default().resolved_z_index(), None);
+
+        // A whole value in an overlay replaces the base fraction
+        let merged = Style::merge(
+            Some(Style::default().z_index(1.5)),
+            Some(Style::default().z_index(3)),
+        )
+        .unwrap();
+        assert_eq!(merged.resolved_z_index(), Some(3.0));
+    }
+
+    #[test]
+    fn test_aspect_size() {
+        // A square is twice as many columns as rows
+        let square = Style::default().aspect_ratio(1.0);
+        assert_eq!(
+            square
+                .clone()
+                .width(Dimension::Fixed(20))
+                .aspect_size(20, 0),
+            (20, 10)
+        );
+        assert_eq!(
+            square.clone().height(Dimension::Fixed(5)).aspect_size(0, 5),
+            (10, 5)
+        );
+        assert_eq!(square.clone().aspect_size(9, 0), (9, 5));
+
+        // Both dimensions set win over the ratio
+        let both = square
+            .width(Dimension::Fixed(4))
+            .height(Dimension::Percentage(0.5));
+        assert_eq!(both.aspect_size(4, 7), (4, 7));
+
+        let wide = Style::default().aspect_ratio(16.0 / 9.0).cell_aspect(1.0);
+        assert_eq!(wide.height(Dimension::Fixed(9)).aspect_size(0, 9), (16, 9));
+        assert_eq!(Style::default().aspect_ratio(0.0).aspect_size(3, 4), (3, 4));
+
+        // A fixed value in an overlay replaces the base fraction
+        let merged = Style::merge(
+            Some(Style::default().padding_fraction(0.1)),
+            Some(Style::default().padding(Spacing::all(1))),
+        )
+        .unwrap();
+        assert_eq!(merged.resolved_padding(80, 24), Spacing::all(1));
+    }
+
+    #[test]
+    fn test_overlay_precedence() {
+        let base = Style::padded(Spacing::all(1))
+            .background(Color::Blue)
+            .min_width(10)
+            .margin(Spacing::all(2));
+        let styled = base.overlay(Style::filled(Color::Red).min_width(20));
+
+        // Set properties win, unset ones are transparent
+        assert_eq!(styled.background, Some(Color::Red));
+        assert_eq!(styled.min_width, Some(20));
+        assert_eq!(styled.padding, Some(Spacing::all(1)));
+        assert_eq!(styled.margin, Some(Spacing::all(2)));
+
+        let centered = Style::centered();
+        assert_eq!(centered.justify_content, Some(JustifyContent::Center));
+        assert_eq!(centered.align_items, Some(AlignItems::Center));
+    }
 }
@@ -5,15 +5,28 @@
 //! to achieve optimal performance and eliminate flicker.
 
 use crate::buffer::{Cell, CellStyle, CellUpdate};
-use crate::style::Color;
+use crate::style::{Color, UnderlineStyle};
 use crate::utils::display_width;
 use crossterm::{
     ExecutableCommand, cursor,
-    style::{Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    style::{
+        Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor,
+        SetUnderlineColor,
+    },
     terminal,
 };
 use std::io::{self, Write};
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Saves the window title on the xterm title stack
+const PUSH_TITLE: &[u8] = b"\x1b[22;0t";
+
+/// Restores the window title saved by [`PUSH_TITLE`]
+pub(crate) const POP_TITLE: &[u8] = b"\x1b[23;0t";
+
 //--------------------------------------------------------------------------------------------------
 // Types
 //--------------------------------------------------------------------------------------------------
@@ -38,7 +51,7 @@ use std::io::{self, Write};
 /// ```
 pub struct TerminalRenderer {
     /// Output stream (usually stdout)
-    stdout: io::Stdout,
+    stdout: CountingStdout,
 
     /// Current cursor position (x, y)
     current_pos: Option<(u16, u16)>,
@@ -54,6 +67,18 @@ pub struct TerminalRenderer {
 
     /// Whether synchronized output is supported
     supports_synchronized: bool,
+
+    /// Whether styled (SGR 4:x) and colored (SGR 58) underlines are supported
+    supports_styled_underline: bool,
+
+    /// Raw escape sequences waiting for the end of the frame
+    raw_queue: Vec<String>,
+}
+
+/// Stdout wrapper that counts the bytes written through it.
+struct CountingStdout {
+    inner: Box<dyn Write>,
+    bytes_written: usize,
 }
 
 /// A terminal command abstraction for batching operations.
@@ -101,13 +126,23 @@ struct Run {
 impl TerminalRenderer {
     /// Creates a new terminal renderer.
     pub fn new() -> Self {
+        Self::with_writer(io::stdout())
+    }
+
+    /// Creates a terminal renderer that writes to the given output.
+    fn with_writer(writer: impl Write + 'static) -> Self {
         Self {
-            stdout: io::stdout(),
+            stdout: CountingStdout {
+                inner: Box::new(writer),
+                bytes_written: 0,
+            },
             current_pos: None,
             current_fg: None,
             current_bg: None,
             current_style: CellStyle::default(),
             supports_synchronized: Self::detect_synchronized_output(),
+            supports_styled_underline: Self::detect_styled_underline(),
+            raw_queue: Vec::new(),
         }
     }
 
@@ -123,6 +158,28 @@ impl TerminalRenderer {
         })
     }
 
+    /// Detects if the terminal supports styled and colored underlines.
+    fn detect_styled_underline() -> bool {
+        // Terminals that misparse SGR 4:x may render it as something else entirely,
+        // so only enable it for terminals known to support it
+        let term_program = std::env::var("TERM_PROGRAM").is_ok_and(|term| {
+            matches!(
+                term.as_str(),
+                "iTerm.app" | "kitty" | "WezTerm" | "wezterm" | "ghostty" | "vscode"
+            )
+        });
+        let term = std::env::var("TERM").is_ok_and(|term| {
+            term.contains("kitty") || term.contains("ghostty") || term.contains("wezterm")
+        });
+        // VTE-based terminals (GNOME Terminal, Tilix, ...) support it since 0.51
+        let vte = std::env::var("VTE_VERSION")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .is_some_and(|v| v >= 5100);
+
+        term_program || term || vte
+    }
+
     /// Applies a list of cell updates to the terminal.
     ///
     /// ```text
@@ -228,6 +285,7 @@ impl TerminalRenderer {
             Color::BrightCyan => crossterm::style::Color::Cyan,
             Color::BrightWhite => crossterm::style::Color::White,
             Color::Rgb(r, g, b) => crossterm::style::Color::Rgb { r, g, b },
+            Color::Default => crossterm::style::Color::Reset,
         }
     }
 
@@ -254,11 +312,20 @@ impl TerminalRenderer {
             self.stdout.execute(SetAttribute(Attribute::Italic))?;
         }
         if cell.style.underline {
-            self.stdout.execute(SetAttribute(Attribute::Underlined))?;
+            self.apply_underline(&cell.style)?;
         }
         if cell.style.strikethrough {
             self.stdout.execute(SetAttribute(Attribute::CrossedOut))?;
         }
+        if cell.style.reverse {
+            self.stdout.execute(SetAttribute(Attribute::Reverse))?;
+        }
+        if cell.style.blink {
+            self.stdout.execute(SetAttribute(Attribute::SlowBlink))?;
+        }
+        if cell.style.conceal {
+            self.stdout.execute(SetAttribute(Attribute::Hidden))?;
+        }
         Ok(())
     }
 
@@ -395,23 +462,113 @@ impl TerminalRenderer {
                 self.stdout.execute(SetAttribute(Attribute::Italic))?;
             }
             if style.underline {
-                self.stdout.execute(SetAttribute(Attribute::Underlined))?;
+                self.apply_underline(&style)?;
             }
             if style.strikethrough {
                 self.stdout.execute(SetAttribute(Attribute::CrossedOut))?;
             }
+            if style.reverse {
+                self.stdout.execute(SetAttribute(Attribute::Reverse))?;
+            }
+            if style.blink {
+                self.stdout.execute(SetAttribute(Attribute::SlowBlink))?;
+            }
+            if style.conceal {
+                self.stdout.execute(SetAttribute(Attribute::Hidden))?;
+            }
 
             self.current_style = style;
         }
         Ok(())
     }
 
+    /// Emits the underline attribute, falling back to a plain underline when
+    /// styled underlines aren't supported.
+    fn apply_underline(&mut self, style: &CellStyle) -> io::Result<()> {
+        if !self.supports_styled_underline {
+            self.stdout.execute(SetAttribute(Attribute::Underlined))?;
+            return Ok(());
+        }
+
+        let attribute = match style.underline_style {
+            UnderlineStyle::Single => Attribute::Underlined,
+            UnderlineStyle::Double => Attribute::DoubleUnderlined,
+            UnderlineStyle::Curly => Attribute::Undercurled,
+            UnderlineStyle::Dotted => Attribute::Underdotted,
+            UnderlineStyle::Dashed => Attribute::Underdashed,
+        };
+        self.stdout.execute(SetAttribute(attribute))?;
+
+        if let Some(color) = style.underline_color {
+            self.stdout
+                .execute(SetUnderlineColor(to_crossterm_color(color)))?;
+        }
+        Ok(())
+    }
+
     /// Resets the renderer state.
     #[allow(dead_code)]
     pub fn reset(&mut self) -> io::Result<()> {
         self.apply_command(TerminalCommand::Reset)
     }
 
+    /// Returns the number of bytes sent to the terminal since the last call.
+    pub(crate) fn take_bytes_written(&mut self) -> usize {
+        std::mem::take(&mut self.stdout.bytes_written)
+    }
+
+    /// Queues raw escape sequences to write with [`flush_raw`](Self::flush_raw).
+    pub(crate) fn queue_raw(&mut self, sequences: Vec<String>) {
+        self.raw_queue.extend(sequences);
+    }
+
+    /// Writes the queued raw sequences, outside of any frame's output.
+    ///
+    /// The sequences may have moved the cursor or changed colors, so the
+    /// attributes are reset and the tracked state forgotten, making the next
+    /// frame position and style its cells from scratch.
+    pub(crate) fn flush_raw(&mut self) -> io::Result<()> {
+        if self.raw_queue.is_empty() {
+            return Ok(());
+        }
+
+        for sequence in self.raw_queue.drain(..) {
+            self.stdout.write_all(sequence.as_bytes())?;
+        }
+        self.stdout.execute(SetAttribute(Attribute::Reset))?;
+        self.stdout.execute(ResetColor)?;
+        self.current_pos = None;
+        self.current_fg = None;
+        self.current_bg = None;
+        self.current_style = CellStyle::default();
+        self.stdout.flush()
+    }
+
+    /// Rings the terminal bell.
+    pub fn bell(&mut self) -> io::Result<()> {
+        self.stdout.write_all(b"\x07")?;
+        self.stdout.flush()
+    }
+
+    /// Sets the terminal window title, dropping control characters that would end it early.
+    pub fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.stdout
+            .execute(terminal::SetTitle(sanitize_title(title)))?;
+        self.stdout.flush()
+    }
+
+    /// Saves the current window title on the terminal's title stack.
+    pub(crate) fn push_title(&mut self) -> io::Result<()> {
+        self.stdout.write_all(PUSH_TITLE)?;
+        self.stdout.flush()
+    }
+
+    /// Restores the window title last saved with [`push_title`](Self::push_title).
+    pub(crate) fn pop_title(&mut self) -> io::Result<()> {
+        self.stdout.write_all(POP_TITLE)?;
+        self.stdout.flush()
+    }
+
     /// Clears specific lines in the terminal (for inline mode).
     ///
     /// Clears `count` lines starting from `start_row`.
@@ -434,25 +591,26 @@ impl TerminalRenderer {
         &mut self,
         updates: Vec<CellUpdate>,
         origin_row: u16,
+    ) -> io::Result<()> {
+        self.apply_updates_at(updates, 0, origin_row)
+    }
+
+    /// Applies updates relative to an origin anywhere on the terminal.
+    ///
+    /// Used for viewport mode, where the buffer covers a sub-rectangle of the
+    /// screen starting at (`origin_x`, `origin_y`).
+    pub fn apply_updates_at(
+        &mut self,
+        updates: Vec<CellUpdate>,
+        origin_x: u16,
+        origin_y: u16,
     ) -> io::Result<()> {
         if updates.is_empty() {
             return Ok(());
         }
 
-        // Transform coordinates to account for origin
-        let transformed: Vec<CellUpdate> = updates
-            .into_iter()
-            .map(|update| match update {
-                CellUpdate::Single { x, y, cell } => CellUpdate::Single {
-                    x,
-                    y: y + origin_row,
-                    cell,
-                },
-            })
-            .collect();
-
         // Use existing optimized update path
-        self.apply_updates_optimized(transformed)
+        self.apply_updates_optimized(offset_updates(updates, origin_x, origin_y))
     }
 }
 
@@ -623,6 +781,25 @@ impl Run {
 // Functions
 //--------------------------------------------------------------------------------------------------
 
+/// Removes control characters, which would terminate or corrupt the title sequence.
+fn sanitize_title(title: &str) -> String {
+    title.chars().filter(|c| !c.is_control()).collect()
+}
+
+/// Moves every update by the given origin.
+fn offset_updates(updates: Vec<CellUpdate>, origin_x: u16, origin_y: u16) -> Vec<CellUpdate> {
+    updates
+        .into_iter()
+        .map(|update| match update {
+            CellUpdate::Single { x, y, cell } => CellUpdate::Single {
+                x: x + origin_x,
+                y: y + origin_y,
+                cell,
+            },
+        })
+        .collect()
+}
+
 /// Converts our Color enum to crossterm's Color type.
 fn to_crossterm_color(color: Color) -> crossterm::style::Color {
     match color {
@@ -643,6 +820,7 @@ fn to_crossterm_color(color: Color) -> crossterm::style::Color {
         Color::BrightCyan => crossterm::style::Color::Cyan,
         Color::BrightWhite => crossterm::style::Color::White,
         Color::Rgb(r, g, b) => crossterm::style::Color::Rgb { r, g, b },
+        Color::Default => crossterm::style::Color::Reset,
     }
 }
 
@@ -656,6 +834,18 @@ impl Default for TerminalRenderer {
     }
 }
 
+impl Write for CountingStdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bytes_written += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -665,6 +855,48 @@ mod tests {
     use super::*;
     use crate::buffer::{Cell, CellStyle, CellUpdate};
     use crate::style::Color;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Output that keeps what was written for the test to inspect.
+    #[derive(Clone, Default)]
+    struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flush_raw_writes_the_queued_sequences() {
+        let output = SharedOutput::default();
+        let mut renderer = TerminalRenderer::with_writer(output.clone());
+        renderer.current_pos = Some((3, 4));
+
+        renderer.queue_raw(vec!["\x1b[?1004h".into(), "\x1b]52;c;aGk=\x07".into()]);
+        assert!(output.0.borrow().is_empty());
+
+        renderer.flush_raw().unwrap();
+        let written = String::from_utf8(output.0.take()).unwrap();
+        assert!(written.starts_with("\x1b[?1004h\x1b]52;c;aGk=\x07"));
+        assert_eq!(renderer.current_pos, None);
+
+        // Nothing is left queued for the next flush
+        renderer.flush_raw().unwrap();
+        assert!(output.0.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_title_drops_control_characters() {
+        assert_eq!(sanitize_title("build: ok"), "build: ok");
+        assert_eq!(sanitize_title("a\x07b\x1b]0;c\nd"), "ab]0;cd");
+    }
 
     #[test]
     fn test_update_batcher_single_cell() {
@@ -955,6 +1187,25 @@ mod tests {
                 b: 200
             }
         );
+        assert_eq!(
+            to_crossterm_color(Color::Default),
+            crossterm::style::Color::Reset
+        );
+    }
+
+    #[test]
+    fn test_offset_updates() {
+        let updates = vec![CellUpdate::Single {
+            x: 1,
+            y: 2,
+            cell: Cell::new('A'),
+        }];
+
+        let moved = offset_updates(updates, 10, 5);
+        assert!(matches!(
+            moved.as_slice(),
+            [CellUpdate::Single { x: 11, y: 7, cell }] if cell.char == 'A'
+        ));
     }
 
     #[test]
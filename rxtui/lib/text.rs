@@ -0,0 +1,117 @@
+//! Measuring, truncating and wrapping text in terminal columns.
+//!
+//! These are the functions the renderer itself uses, so custom widgets that
+//! lay out text by hand line up with built-in text nodes. Widths count
+//! terminal columns rather than bytes or chars: wide characters such as CJK
+//! and most emoji take two columns, combining marks take none, and grapheme
+//! clusters are never split.
+//!
+//! ```
+//! use rxtui::TextWrap;
+//! use rxtui::text;
+//!
+//! assert_eq!(text::display_width("日本"), 4);
+//! assert_eq!(text::truncate_to_columns("日本語", 5), "日本");
+//! assert_eq!(text::wrap("日本語", 5, TextWrap::Character), ["日本", "語"]);
+//! ```
+
+use crate::style::{LineBreak, TextWrap};
+use crate::utils;
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the number of terminal columns the text takes.
+pub fn display_width(text: &str) -> usize {
+    utils::display_width(text)
+}
+
+/// Returns the longest prefix of the text that fits in `columns` columns.
+///
+/// A wide character that would straddle the limit is left out, so the result
+/// can be a column narrower than asked.
+pub fn truncate_to_columns(text: &str, columns: usize) -> &str {
+    utils::substring_by_columns(text, 0, columns)
+}
+
+/// Wraps the text into lines of at most `width` columns, like a text node with
+/// the same [`TextWrap`] mode.
+///
+/// [`TextWrap::Word`] lets words longer than the width overflow, and
+/// [`TextWrap::None`] returns the text as a single line. A zero width yields
+/// no lines.
+pub fn wrap(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
+    utils::wrap_text(text, width, mode)
+}
+
+/// Wraps the text like [`wrap`], with `line_break` deciding where the word
+/// modes may break besides whitespace.
+///
+/// [`wrap`] uses [`LineBreak::Cjk`], the default for text nodes too.
+pub fn wrap_with(text: &str, width: u16, mode: TextWrap, line_break: LineBreak) -> Vec<String> {
+    utils::wrap_text_with(text, width, mode, line_break)
+}
+
+/// Returns whether word wrapping may break between two adjacent clusters
+/// under [`LineBreak::Cjk`]
+///
+/// Useful as the base of a [`LineBreak::Custom`] rule.
+pub fn cjk_line_break(before: &str, after: &str) -> bool {
+    utils::cjk_line_break(before, after)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_characters_take_two_columns() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("日本"), 4);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("👍"), 2);
+    }
+
+    #[test]
+    fn test_truncate_never_splits_wide_characters() {
+        assert_eq!(truncate_to_columns("hello", 3), "hel");
+        assert_eq!(truncate_to_columns("日本語", 4), "日本");
+        assert_eq!(truncate_to_columns("日本語", 3), "日");
+        assert_eq!(truncate_to_columns("a👍b", 2), "a");
+        assert_eq!(truncate_to_columns("short", 10), "short");
+        assert_eq!(truncate_to_columns("abc", 0), "");
+    }
+
+    #[test]
+    fn test_wrap_counts_columns() {
+        assert_eq!(
+            wrap("日本語テキスト", 5, TextWrap::Character),
+            ["日本", "語テ", "キス", "ト"]
+        );
+        assert_eq!(wrap("日本語", 5, TextWrap::WordBreak), ["日本", "語"]);
+        assert_eq!(wrap("unchanged", 3, TextWrap::None), ["unchanged"]);
+        assert!(wrap("text", 0, TextWrap::Word).is_empty());
+    }
+
+    #[test]
+    fn test_wrap_with_custom_line_break() {
+        // Also break after hyphens
+        fn after_hyphen(before: &str, after: &str) -> bool {
+            before == "-" || cjk_line_break(before, after)
+        }
+        let text = "well-known 日本";
+        assert_eq!(
+            wrap_with(text, 6, TextWrap::Word, LineBreak::Custom(after_hyphen)),
+            ["well-", "known ", "日本"]
+        );
+        assert_eq!(
+            wrap_with(text, 6, TextWrap::Word, LineBreak::Whitespace),
+            ["well-known", "日本"]
+        );
+    }
+}
@@ -0,0 +1,676 @@
+//! Loading themes from TOML or JSON documents.
+//!
+//! Requires the `serde` feature. Every section is optional; anything left out
+//! keeps the value from [`Theme::dark`].
+//!
+//! ## Format
+//!
+//! ```toml
+//! border_style = "rounded"
+//!
+//! [palette]
+//! primary = "magenta"
+//! focus = "#ffaf00"
+//!
+//! [focus_style]
+//! border = "bright_yellow"
+//!
+//! [styles.panel]
+//! background = "#1e1e2e"
+//! padding = 1
+//! width = "50%"
+//! border = { color = "cyan", style = "rounded" }
+//!
+//! [text.title]
+//! color = "bright_yellow"
+//! bold = true
+//! ```
+//!
+//! Field names match the fields of [`Theme`], [`Palette`](super::Palette),
+//! [`Style`](crate::style::Style), and [`TextStyle`](crate::style::TextStyle).
+//! Colors are anything [`Color`] parses from a string, such as `bright_blue`,
+//! `"#ff8800"`, `"tomato"` or `"hsl(210, 50%, 40%)"`, or `[r, g, b]` arrays.
+//! Dimensions are cell counts, percentages (`"50%"`), fractions of the
+//! remaining space (`"0.5fr"`), `"auto"`, or `"content"`. Translations are
+//! signed cell counts or percentages (`"-50%"`). Unknown keys are rejected
+//! with an error naming the offending field.
+//!
+//! The style types also serialize to this format, which is how
+//! [`Node::to_debug_value`](crate::Node::to_debug_value) writes them.
+
+use super::Theme;
+use crate::style::{Border, BorderEdges, BorderStyle, Color, Dimension, Offset, Spacing};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::path::Path;
+
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Border edge names, groups first so that serializing prefers them.
+const BORDER_EDGE_NAMES: &[(&str, BorderEdges)] = &[
+    ("all", BorderEdges::ALL),
+    ("edges", BorderEdges::EDGES),
+    ("corners", BorderEdges::CORNERS),
+    ("horizontal", BorderEdges::HORIZONTAL),
+    ("vertical", BorderEdges::VERTICAL),
+    ("top", BorderEdges::TOP),
+    ("right", BorderEdges::RIGHT),
+    ("bottom", BorderEdges::BOTTOM),
+    ("left", BorderEdges::LEFT),
+    ("top_left", BorderEdges::TOP_LEFT),
+    ("top_right", BorderEdges::TOP_RIGHT),
+    ("bottom_right", BorderEdges::BOTTOM_RIGHT),
+    ("bottom_left", BorderEdges::BOTTOM_LEFT),
+];
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Errors produced while loading a theme or style document.
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    /// The theme file could not be read
+    #[error("failed to read theme file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The document is not valid TOML or doesn't match the theme schema
+    #[error("invalid TOML theme: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    /// The document is not valid JSON or doesn't match the theme schema
+    #[error("invalid JSON theme: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A parsed value doesn't match the style schema
+    #[error("invalid style value: {0}")]
+    Value(String),
+
+    /// The file extension isn't `.toml` or `.json`
+    #[error("unsupported theme format `{0}`, expected .toml or .json")]
+    UnsupportedFormat(String),
+}
+
+/// Table form of a border, with every field optional.
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BorderTable {
+    #[serde(default = "default_true")]
+    enabled: bool,
+
+    #[serde(default)]
+    style: BorderStyle,
+
+    #[serde(default = "default_border_color")]
+    color: Color,
+
+    #[serde(default = "default_border_edges")]
+    edges: BorderEdges,
+}
+
+/// Table form of spacing, with unset sides defaulting to zero.
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SpacingTable {
+    top: u16,
+    right: u16,
+    bottom: u16,
+    left: u16,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Theme {
+    /// Parses a theme from a TOML document.
+    pub fn from_toml_str(source: &str) -> Result<Self, ThemeError> {
+        Ok(toml::from_str(source)?)
+    }
+
+    /// Parses a theme from a JSON document.
+    pub fn from_json_str(source: &str) -> Result<Self, ThemeError> {
+        Ok(serde_json::from_str(source)?)
+    }
+
+    /// Loads a theme file, choosing the format from its `.toml` or `.json` extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match extension.as_str() {
+            "toml" => Self::from_toml_str(&std::fs::read_to_string(path)?),
+            "json" => Self::from_json_str(&std::fs::read_to_string(path)?),
+            _ => Err(ThemeError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+
+    /// Builds a theme from an already-parsed document value.
+    pub fn from_value<'de, D>(value: D) -> Result<Self, ThemeError>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(value).map_err(|e: D::Error| ThemeError::Value(e.to_string()))
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_border_color() -> Color {
+    Color::White
+}
+
+fn default_border_edges() -> BorderEdges {
+    BorderEdges::ALL
+}
+
+/// Parses a single border edge or edge group name.
+fn parse_border_edges(value: &str) -> Option<BorderEdges> {
+    BORDER_EDGE_NAMES
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|&(_, edges)| edges)
+}
+
+/// Converts a signed integer into a cell count, rejecting out-of-range values.
+fn cells<E: de::Error>(value: i64) -> Result<u16, E> {
+    u16::try_from(value).map_err(|_| E::custom(format!("{value} is out of range for a cell count")))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> Visitor<'de> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(
+                    "a color name, a \"#rrggbb\" hex string, rgb(), hsl(), or an [r, g, b] array",
+                )
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+                let mut channel = |index| {
+                    seq.next_element::<u8>()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))
+                };
+                let (r, g, b) = (channel(0)?, channel(1)?, channel(2)?);
+                Ok(Color::Rgb(r, g, b))
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimension {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DimensionVisitor;
+
+        impl<'de> Visitor<'de> for DimensionVisitor {
+            type Value = Dimension;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(
+                    "a cell count, a percentage like \"50%\", a fraction of the remaining \
+                     space like \"0.5fr\", \"auto\", or \"content\"",
+                )
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Dimension, E> {
+                Ok(Dimension::Fixed(cells(value)?))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Dimension, E> {
+                self.visit_i64(i64::try_from(value).unwrap_or(i64::MAX))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Dimension, E> {
+                match value {
+                    "auto" => Ok(Dimension::Auto),
+                    "content" => Ok(Dimension::Content),
+                    _ if value.ends_with("fr") => value
+                        .strip_suffix("fr")
+                        .and_then(|fraction| fraction.trim().parse::<f32>().ok())
+                        .filter(|fraction| fraction.is_finite())
+                        .map(|fraction| Dimension::FractionRemaining(fraction.clamp(0.0, 1.0)))
+                        .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self)),
+                    _ => value
+                        .strip_suffix('%')
+                        .and_then(|percent| percent.trim().parse::<f32>().ok())
+                        .filter(|percent| percent.is_finite())
+                        .map(|percent| Dimension::Percentage((percent / 100.0).clamp(0.0, 1.0)))
+                        .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(DimensionVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Offset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OffsetVisitor;
+
+        impl<'de> Visitor<'de> for OffsetVisitor {
+            type Value = Offset;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a signed cell count or a percentage like \"50%\"")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Offset, E> {
+                i16::try_from(value)
+                    .map(Offset::Cells)
+                    .map_err(|_| E::custom(format!("{value} is out of range for an offset")))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Offset, E> {
+                self.visit_i64(i64::try_from(value).unwrap_or(i64::MAX))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Offset, E> {
+                value
+                    .strip_suffix('%')
+                    .and_then(|percent| percent.trim().parse::<f32>().ok())
+                    .filter(|percent| percent.is_finite())
+                    .map(|percent| Offset::Percent(percent / 100.0))
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(OffsetVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Spacing {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SpacingVisitor;
+
+        impl<'de> Visitor<'de> for SpacingVisitor {
+            type Value = Spacing;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a cell count or a table of top/right/bottom/left")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Spacing, E> {
+                Ok(Spacing::all(cells(value)?))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Spacing, E> {
+                self.visit_i64(i64::try_from(value).unwrap_or(i64::MAX))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Spacing, A::Error> {
+                let table = SpacingTable::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(Spacing {
+                    top: table.top,
+                    right: table.right,
+                    bottom: table.bottom,
+                    left: table.left,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(SpacingVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for BorderEdges {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BorderEdgesVisitor;
+
+        impl<'de> Visitor<'de> for BorderEdgesVisitor {
+            type Value = BorderEdges;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an edge name like \"top\" or \"horizontal\", or a list of them")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<BorderEdges, E> {
+                parse_border_edges(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<BorderEdges, A::Error> {
+                let mut edges = BorderEdges::empty();
+                while let Some(name) = seq.next_element::<String>()? {
+                    edges |= BorderEdgesVisitor.visit_str::<A::Error>(&name)?;
+                }
+                Ok(edges)
+            }
+        }
+
+        deserializer.deserialize_any(BorderEdgesVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Border {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BorderVisitor;
+
+        impl<'de> Visitor<'de> for BorderVisitor {
+            type Value = Border;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a border color, `false`, or a table of enabled/style/color/edges")
+            }
+
+            fn visit_bool<E: de::Error>(self, enabled: bool) -> Result<Border, E> {
+                Ok(if enabled {
+                    Border::new(default_border_color())
+                } else {
+                    Border::none()
+                })
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Border, E> {
+                let color = Color::deserialize(de::value::StrDeserializer::<E>::new(value))?;
+                Ok(Border::new(color))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Border, A::Error> {
+                let table = BorderTable::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(Border {
+                    enabled: table.enabled,
+                    style: table.style,
+                    color: table.color,
+                    edges: table.edges,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(BorderVisitor)
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = match self {
+            Color::Black => "black",
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+            Color::White => "white",
+            Color::BrightBlack => "bright_black",
+            Color::BrightRed => "bright_red",
+            Color::BrightGreen => "bright_green",
+            Color::BrightYellow => "bright_yellow",
+            Color::BrightBlue => "bright_blue",
+            Color::BrightMagenta => "bright_magenta",
+            Color::BrightCyan => "bright_cyan",
+            Color::BrightWhite => "bright_white",
+            Color::Default => "default",
+            Color::Rgb(r, g, b) => {
+                return serializer.collect_str(&format_args!("#{r:02x}{g:02x}{b:02x}"));
+            }
+        };
+        serializer.serialize_str(name)
+    }
+}
+
+impl Serialize for Dimension {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Dimension::Fixed(cells) => serializer.serialize_u16(cells),
+            Dimension::Percentage(fraction) => {
+                serializer.collect_str(&format_args!("{}%", fraction * 100.0))
+            }
+            Dimension::Auto => serializer.serialize_str("auto"),
+            Dimension::Content => serializer.serialize_str("content"),
+            Dimension::FractionRemaining(fraction) => {
+                serializer.collect_str(&format_args!("{fraction}fr"))
+            }
+        }
+    }
+}
+
+impl Serialize for Offset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Offset::Cells(cells) => serializer.serialize_i16(cells),
+            Offset::Percent(fraction) => {
+                serializer.collect_str(&format_args!("{}%", fraction * 100.0))
+            }
+        }
+    }
+}
+
+impl Serialize for Spacing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if *self == Spacing::all(self.top) {
+            return serializer.serialize_u16(self.top);
+        }
+        SpacingTable {
+            top: self.top,
+            right: self.right,
+            bottom: self.bottom,
+            left: self.left,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for BorderEdges {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let Some((name, _)) = BORDER_EDGE_NAMES.iter().find(|(_, edges)| edges == self) {
+            return serializer.serialize_str(name);
+        }
+        let names = BORDER_EDGE_NAMES
+            .iter()
+            .filter(|(_, edges)| edges.bits().is_power_of_two() && self.contains(*edges))
+            .map(|(name, _)| name);
+        serializer.collect_seq(names)
+    }
+}
+
+impl Serialize for Border {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BorderTable {
+            enabled: self.enabled,
+            style: self.style,
+            color: self.color,
+            edges: self.edges,
+        }
+        .serialize(serializer)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{Direction, Style, TextAlign};
+    use crate::theme::Palette;
+
+    #[test]
+    fn test_theme_from_toml() {
+        let theme = Theme::from_toml_str(
+            r##"
+            [styles.panel]
+            background = "#ff8800"
+            padding = 1
+            width = "50%"
+            height = "auto"
+            direction = "horizontal"
+            border = { color = "cyan", style = "rounded", edges = ["top", "bottom"] }
+
+            [text.title]
+            color = "bright_yellow"
+            bold = true
+            align = "center"
+            "##,
+        )
+        .unwrap();
+
+        let panel = theme.style("panel").unwrap();
+        assert_eq!(panel.background, Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(panel.padding, Some(Spacing::all(1)));
+        assert_eq!(panel.width, Some(Dimension::Percentage(0.5)));
+        assert_eq!(panel.height, Some(Dimension::Auto));
+        assert_eq!(panel.direction, Some(Direction::Horizontal));
+        assert_eq!(
+            panel.border,
+            Some(Border::with_edges(
+                BorderStyle::Rounded,
+                Color::Cyan,
+                BorderEdges::HORIZONTAL
+            ))
+        );
+
+        let title = theme.text_style("title").unwrap();
+        assert_eq!(title.color, Some(Color::BrightYellow));
+        assert_eq!(title.bold, Some(true));
+        assert_eq!(title.align, Some(TextAlign::Center));
+        assert!(theme.style("missing").is_none());
+    }
+
+    #[test]
+    fn test_theme_from_json() {
+        let theme = Theme::from_json_str(
+            r#"{
+                "styles": {
+                    "sidebar": { "border": "bright_black", "margin": { "left": 2 }, "width": 20 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let sidebar = theme.style("sidebar").unwrap();
+        assert_eq!(sidebar.border, Some(Border::new(Color::BrightBlack)));
+        assert_eq!(
+            sidebar.margin,
+            Some(Spacing {
+                top: 0,
+                right: 0,
+                bottom: 0,
+                left: 2
+            })
+        );
+        assert_eq!(sidebar.width, Some(Dimension::Fixed(20)));
+    }
+
+    #[test]
+    fn test_theme_sections_default_to_dark() {
+        let theme =
+            Theme::from_toml_str("border_style = \"rounded\"\n[palette]\nprimary = \"magenta\"")
+                .unwrap();
+
+        assert_eq!(theme.palette.primary, Color::Magenta);
+        assert_eq!(theme.palette.foreground, Palette::dark().foreground);
+        assert_eq!(theme.border_style, BorderStyle::Rounded);
+        assert_eq!(theme.focus_style, Theme::dark().focus_style);
+    }
+
+    #[test]
+    fn test_style_from_value() {
+        let value: toml::Value = toml::from_str("background = [10, 20, 30]\ngap = 2").unwrap();
+        let style = Style::from_value(value).unwrap();
+        assert_eq!(style.background, Some(Color::Rgb(10, 20, 30)));
+        assert_eq!(style.gap, Some(2));
+
+        let value: toml::Value =
+            toml::from_str("left_fraction = 0.5\ntop = -1\ntranslate = [\"-50%\", 0]").unwrap();
+        let style = Style::from_value(value).unwrap();
+        assert_eq!(style.left_fraction, Some(0.5));
+        assert_eq!(style.top, Some(-1));
+        assert_eq!(
+            style.translate,
+            Some((Offset::Percent(-0.5), Offset::Cells(0)))
+        );
+
+        let value: toml::Value = toml::from_str("z_index = 2\nfractional_z_index = 1.5").unwrap();
+        let style = Style::from_value(value).unwrap();
+        assert_eq!(style.resolved_z_index(), Some(1.5));
+    }
+
+    #[test]
+    fn test_unknown_keys_are_rejected() {
+        let err = Theme::from_toml_str("[styles.panel]\ncolour = \"red\"").unwrap_err();
+        assert!(err.to_string().contains("unknown field `colour`"), "{err}");
+
+        let err = Theme::from_toml_str("[style.panel]\nbackground = \"red\"").unwrap_err();
+        assert!(err.to_string().contains("unknown field `style`"), "{err}");
+
+        let err = Theme::from_json_str(r#"{"styles": {"a": {"border": {"colour": "red"}}}}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown field `colour`"), "{err}");
+    }
+
+    #[test]
+    fn test_invalid_values_are_reported() {
+        let err = Theme::from_toml_str("[styles.a]\nbackground = \"purplish\"").unwrap_err();
+        assert!(err.to_string().contains("purplish"), "{err}");
+
+        let err = Theme::from_toml_str("[styles.a]\nwidth = -3").unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+
+        let err = Theme::from_file("theme.yaml").unwrap_err();
+        assert!(matches!(err, ThemeError::UnsupportedFormat(_)));
+    }
+
+    #[test]
+    fn test_css_color_strings() {
+        let theme = Theme::from_toml_str(
+            "[palette]\nprimary = \"tomato\"\nfocus = \"hsl(120, 100%, 25%)\"\nerror = \"rgb(200 0 0)\"",
+        )
+        .unwrap();
+        assert_eq!(theme.palette.primary, Color::Rgb(255, 99, 71));
+        assert_eq!(theme.palette.focus, Color::Rgb(0, 128, 0));
+        assert_eq!(theme.palette.error, Color::Rgb(200, 0, 0));
+    }
+
+    #[test]
+    fn test_styles_serialize_to_the_loaded_format() {
+        let style = Style {
+            width: Some(Dimension::FractionRemaining(0.25)),
+            translate: Some((Offset::Percent(-0.5), Offset::Cells(1))),
+            padding: Some(Spacing::all(1)),
+            margin: Some(Spacing::vertical(2)),
+            border: Some(Border::with_edges(
+                BorderStyle::Rounded,
+                Color::Rgb(255, 136, 0),
+                BorderEdges::TOP | BorderEdges::LEFT,
+            )),
+            ..Style::default()
+        };
+        let value = serde_json::to_value(&style).unwrap();
+        assert_eq!(value["width"], "0.25fr");
+        assert_eq!(value["translate"], serde_json::json!(["-50%", 1]));
+        assert_eq!(value["padding"], 1);
+        assert_eq!(value["border"]["color"], "#ff8800");
+        assert_eq!(value["border"]["edges"], serde_json::json!(["top", "left"]));
+
+        assert_eq!(Style::deserialize(value).unwrap(), style);
+    }
+}
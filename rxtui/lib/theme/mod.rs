@@ -0,0 +1,319 @@
+//! Application-wide theme: palette, default border style, and state styles.
+//!
+//! A [`Theme`] is installed on the [`App`](crate::App) and read by components
+//! through [`Context::theme`](crate::Context::theme). Built-in components take
+//! their default colors from it, so switching between [`Theme::dark`] and
+//! [`Theme::light`] restyles them without touching each instance. Values set
+//! on an instance always win over the theme.
+//!
+//! ```text
+//!   Theme
+//!   ├── palette: Palette       (named colors used by built-ins)
+//!   ├── border_style           (default border for built-ins)
+//!   ├── focus_style / hover_style
+//!   └── styles / text          (role-keyed styles for your own components)
+//! ```
+
+use crate::style::{BorderStyle, Color, Style, TextStyle};
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+mod loader;
+
+#[cfg(feature = "serde")]
+mod watcher;
+
+#[cfg(feature = "serde")]
+pub use loader::ThemeError;
+
+#[cfg(feature = "serde")]
+pub(crate) use watcher::ThemeWatcher;
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Named colors shared by built-in components.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Palette {
+    /// Accent for interactive components such as input borders and spinners
+    pub primary: Color,
+
+    /// Default text color
+    pub foreground: Color,
+
+    /// Secondary content such as placeholders and scrollbars
+    pub muted: Color,
+
+    /// Color for plain, non-interactive borders
+    pub border: Color,
+
+    /// Border color that marks the focused element
+    pub focus: Color,
+
+    /// Text color of selected text
+    pub selection_foreground: Color,
+
+    /// Background color of selected text
+    pub selection_background: Color,
+
+    /// Text color under the cursor
+    pub cursor_foreground: Color,
+
+    /// Background color of the cursor cell
+    pub cursor_background: Color,
+
+    /// Positive status
+    pub success: Color,
+
+    /// Cautionary status
+    pub warning: Color,
+
+    /// Error status
+    pub error: Color,
+}
+
+/// Application-wide visual defaults.
+///
+/// `styles` and `text` hold role-keyed styles for application components,
+/// such as `theme.style("sidebar")`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Theme {
+    /// Named colors used by built-in components
+    pub palette: Palette,
+
+    /// Border style used by built-in components
+    pub border_style: BorderStyle,
+
+    /// Style applied to focused built-in components, none by default
+    pub focus_style: Option<Style>,
+
+    /// Style applied to hovered built-in components
+    pub hover_style: Option<Style>,
+
+    /// Container styles keyed by component or role name
+    pub styles: HashMap<String, Style>,
+
+    /// Text styles keyed by role name
+    pub text: HashMap<String, TextStyle>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl Palette {
+    /// Palette for terminals with a dark background.
+    pub fn dark() -> Self {
+        Self {
+            primary: Color::Cyan,
+            foreground: Color::White,
+            muted: Color::BrightBlack,
+            border: Color::White,
+            focus: Color::Yellow,
+            selection_foreground: Color::White,
+            selection_background: Color::Blue,
+            cursor_foreground: Color::Black,
+            cursor_background: Color::White,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+        }
+    }
+
+    /// Palette for terminals with a light background.
+    pub fn light() -> Self {
+        Self {
+            primary: Color::Blue,
+            foreground: Color::Black,
+            muted: Color::BrightBlack,
+            border: Color::Black,
+            focus: Color::Magenta,
+            selection_foreground: Color::White,
+            selection_background: Color::Blue,
+            cursor_foreground: Color::White,
+            cursor_background: Color::Black,
+            success: Color::Rgb(0, 135, 0),
+            warning: Color::Rgb(175, 95, 0),
+            error: Color::Rgb(175, 0, 0),
+        }
+    }
+}
+
+impl Theme {
+    /// The default theme, for dark terminal backgrounds.
+    pub fn dark() -> Self {
+        Self::from_palette(Palette::dark())
+    }
+
+    /// A theme for light terminal backgrounds.
+    pub fn light() -> Self {
+        Self::from_palette(Palette::light())
+    }
+
+    /// Builds a theme around a palette.
+    ///
+    /// No focus style is set, so built-in components keep their own focus
+    /// look; set one with [`focus_style`](Self::focus_style), for example a
+    /// border in `palette.focus`.
+    pub fn from_palette(palette: Palette) -> Self {
+        Self {
+            palette,
+            border_style: BorderStyle::Single,
+            focus_style: None,
+            hover_style: None,
+            styles: HashMap::new(),
+            text: HashMap::new(),
+        }
+    }
+
+    /// Sets the palette.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the border style used by built-in components.
+    pub fn border_style(mut self, style: BorderStyle) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Sets the style applied to focused built-in components.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Sets the style applied to hovered built-in components.
+    pub fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = Some(style);
+        self
+    }
+
+    /// Registers a container style for a role.
+    pub fn with_style(mut self, role: impl Into<String>, style: Style) -> Self {
+        self.styles.insert(role.into(), style);
+        self
+    }
+
+    /// Registers a text style for a role.
+    pub fn with_text_style(mut self, role: impl Into<String>, style: TextStyle) -> Self {
+        self.text.insert(role.into(), style);
+        self
+    }
+
+    /// Returns the container style registered for a role.
+    pub fn style(&self, role: &str) -> Option<&Style> {
+        self.styles.get(role)
+    }
+
+    /// Returns the text style registered for a role.
+    pub fn text_style(&self, role: &str) -> Option<&TextStyle> {
+        self.text.get(role)
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "components")]
+    use crate::style::Border;
+
+    #[test]
+    fn test_builtin_themes_leave_focus_style_unset() {
+        let dark = Theme::dark();
+        assert_eq!(dark, Theme::default());
+        assert_eq!(dark.focus_style, None);
+
+        let light = Theme::light();
+        assert_eq!(light.palette.foreground, Color::Black);
+        assert_eq!(light.focus_style, None);
+    }
+
+    #[cfg(feature = "components")]
+    #[test]
+    fn test_text_input_reads_theme_defaults() {
+        use crate::app::Context;
+        use crate::component::Component;
+        use crate::components::TextInput;
+        use crate::node::Node;
+
+        let ctx = Context::default();
+        ctx.set_theme(std::sync::Arc::new(
+            Theme::light().border_style(BorderStyle::Rounded),
+        ));
+
+        let border_of = |input: TextInput| match input.view(&ctx) {
+            Node::Div(div) => div.styles.base.and_then(|style| style.border),
+            _ => None,
+        };
+
+        let themed = border_of(TextInput::new()).unwrap();
+        assert_eq!(themed.style, BorderStyle::Rounded);
+        assert_eq!(themed.color, Color::Blue);
+
+        // Per-instance values still win over the theme
+        let overridden = border_of(TextInput::new().border(Color::Green)).unwrap();
+        assert_eq!(overridden.color, Color::Green);
+        assert_eq!(overridden.style, BorderStyle::Single);
+    }
+
+    #[cfg(feature = "components")]
+    #[test]
+    fn test_text_input_focus_border_is_opt_in() {
+        use crate::app::Context;
+        use crate::component::Component;
+        use crate::components::TextInput;
+        use crate::node::Node;
+
+        let focus_border = |theme: Theme| {
+            let ctx = Context::default();
+            ctx.set_theme(std::sync::Arc::new(theme));
+            match TextInput::new().view(&ctx) {
+                Node::Div(div) => div.styles.focus.and_then(|style| style.border),
+                _ => None,
+            }
+        };
+
+        assert_eq!(focus_border(Theme::default()), None);
+        let focus = Style {
+            border: Some(Border::new(Color::Yellow)),
+            ..Default::default()
+        };
+        assert_eq!(
+            focus_border(Theme::default().focus_style(focus)),
+            Some(Border::new(Color::Yellow))
+        );
+    }
+}
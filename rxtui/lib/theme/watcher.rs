@@ -0,0 +1,144 @@
+//! Background polling of a theme file for hot reload.
+//!
+//! A [`ThemeWatcher`] owns a thread that checks the file's modification time
+//! and size at a fixed interval. Whenever they change, the file is parsed
+//! again and the result is queued for the event loop to pick up. The file is
+//! also loaded once up front, so the initial theme comes from the watcher too.
+
+use super::{Theme, ThemeError};
+use std::path::{Path, PathBuf};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+    mpsc::{self, Receiver},
+};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+//--------------------------------------------------------------------------------------------------
+// Types
+//--------------------------------------------------------------------------------------------------
+
+/// Watches a theme file and reloads it when it changes.
+pub(crate) struct ThemeWatcher {
+    /// Parse results in the order the file changed
+    results: Receiver<Result<Theme, ThemeError>>,
+
+    /// Tells the polling thread to exit
+    stop: Arc<AtomicBool>,
+}
+
+/// What the watcher compares to detect a change.
+type FileSignature = Option<(SystemTime, u64)>;
+
+//--------------------------------------------------------------------------------------------------
+// Methods
+//--------------------------------------------------------------------------------------------------
+
+impl ThemeWatcher {
+    /// Loads `path` and starts polling it every `interval`.
+    ///
+    /// The initial load is queued before this returns, so the first poll
+    /// always yields it.
+    pub(crate) fn spawn(path: PathBuf, interval: Duration) -> Self {
+        let (sender, results) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        let mut last_seen = file_signature(&path);
+        let _ = sender.send(Theme::from_file(&path));
+
+        thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                thread::sleep(interval);
+
+                let current = file_signature(&path);
+                if current != last_seen {
+                    last_seen = current;
+                    if sender.send(Theme::from_file(&path)).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self { results, stop }
+    }
+
+    /// Returns the most recent reload result, if the file changed since the last call.
+    ///
+    /// Older results are discarded; only the latest state of the file matters.
+    pub(crate) fn poll(&self) -> Option<Result<Theme, ThemeError>> {
+        self.results.try_iter().last()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions
+//--------------------------------------------------------------------------------------------------
+
+/// Reads the modification time and size of a file, or `None` if it cannot be read.
+fn file_signature(path: &Path) -> FileSignature {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Trait Implementations
+//--------------------------------------------------------------------------------------------------
+
+impl Drop for ThemeWatcher {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Tests
+//--------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{BorderStyle, Color};
+
+    /// Waits until the watcher reports a result matching `check`.
+    ///
+    /// Intermediate results are skipped since a write may be observed half done.
+    fn wait_for(
+        watcher: &ThemeWatcher,
+        check: impl Fn(&Result<Theme, ThemeError>) -> bool,
+    ) -> bool {
+        for _ in 0..400 {
+            if watcher.poll().is_some_and(|result| check(&result)) {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        false
+    }
+
+    #[test]
+    fn test_watcher_reloads_on_change() {
+        let path = std::env::temp_dir().join(format!("rxtui-theme-{}.toml", std::process::id()));
+        std::fs::write(&path, "border_style = \"rounded\"\n").unwrap();
+
+        let watcher = ThemeWatcher::spawn(path.clone(), Duration::from_millis(5));
+        assert!(wait_for(&watcher, |result| matches!(
+            result,
+            Ok(theme) if theme.border_style == BorderStyle::Rounded
+        )));
+
+        std::fs::write(&path, "[palette]\nprimary = \"magenta\"\n").unwrap();
+        assert!(wait_for(&watcher, |result| matches!(
+            result,
+            Ok(theme) if theme.palette.primary == Color::Magenta
+        )));
+
+        std::fs::write(&path, "[palette]\nprimary = 42\n").unwrap();
+        assert!(wait_for(&watcher, |result| result.is_err()));
+
+        drop(watcher);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -4,9 +4,21 @@
 //! including calculating the display width of Unicode strings and characters,
 //! and text wrapping algorithms for fitting text within width constraints.
 
-use crate::style::TextWrap;
+use crate::style::{LineBreak, TextTransform, TextWrap};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+//--------------------------------------------------------------------------------------------------
+// Constants
+//--------------------------------------------------------------------------------------------------
+
+/// Closing punctuation, small kana and marks that stay on the line before a break
+const NO_LINE_START: &str =
+    ")]},.:;!?、。，．：；！？）］｝」』】〕〉》〗〙・ー々ぁぃぅぇぉっゃゅょァィゥェォッャュョ…";
+
+/// Opening punctuation that stays on the line after a break
+const NO_LINE_END: &str = "([{（［｛「『【〔〈《〖〘";
+
 //--------------------------------------------------------------------------------------------------
 // Macros: Debug Logging
 //--------------------------------------------------------------------------------------------------
@@ -70,8 +82,8 @@ pub fn char_width(c: char) -> usize {
 /// Extracts a substring based on display column positions.
 ///
 /// Returns a substring that starts at `start_col` and ends at `end_col` display columns.
-/// Handles multibyte UTF-8 characters correctly. If a wide character spans the boundary,
-/// it is excluded to maintain valid UTF-8.
+/// Works on grapheme clusters, so an emoji sequence or a letter with combining
+/// marks is never split. If a wide cluster spans a boundary, it is excluded.
 pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
     if start_col >= end_col {
         return "";
@@ -81,16 +93,16 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
     let mut start_byte = None;
     let mut end_byte = s.len();
 
-    for (byte_idx, ch) in s.char_indices() {
-        let ch_width = char_width(ch);
+    for (byte_idx, grapheme) in s.grapheme_indices(true) {
+        let width = display_width(grapheme);
 
         // Find start byte index
         if start_byte.is_none() {
             if current_col >= start_col {
                 start_byte = Some(byte_idx);
-            } else if current_col + ch_width > start_col {
-                // Wide character spans the start boundary, start after it
-                start_byte = Some(byte_idx + ch.len_utf8());
+            } else if current_col + width > start_col {
+                // Wide cluster spans the start boundary, start after it
+                start_byte = Some(byte_idx + grapheme.len());
             }
         }
 
@@ -98,13 +110,13 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
         if current_col >= end_col {
             end_byte = byte_idx;
             break;
-        } else if current_col + ch_width > end_col {
-            // Wide character spans the end boundary, end before it
+        } else if current_col + width > end_col {
+            // Wide cluster spans the end boundary, end before it
             end_byte = byte_idx;
             break;
         }
 
-        current_col += ch_width;
+        current_col += width;
     }
 
     let start = start_byte.unwrap_or(s.len());
@@ -115,6 +127,139 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
     }
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions: Grapheme Clusters
+//--------------------------------------------------------------------------------------------------
+
+/// Returns the number of grapheme clusters (user-perceived characters) in a string.
+#[cfg(any(feature = "components", test))]
+pub fn grapheme_count(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Returns the char index where the cluster before `char_pos` starts.
+///
+/// Cursor positions are char indices; stepping back with this keeps the cursor
+/// off the inside of clusters such as flags, family emoji and accented letters.
+#[cfg(any(feature = "components", test))]
+pub fn prev_grapheme_boundary(s: &str, char_pos: usize) -> usize {
+    let mut start = 0;
+    for grapheme in s.graphemes(true) {
+        let end = start + grapheme.chars().count();
+        if end >= char_pos {
+            return start;
+        }
+        start = end;
+    }
+    start
+}
+
+/// Returns the char index where the cluster at `char_pos` ends.
+///
+/// Returns the char count of the string when `char_pos` is at or past its end.
+pub fn next_grapheme_boundary(s: &str, char_pos: usize) -> usize {
+    let mut end = 0;
+    for grapheme in s.graphemes(true) {
+        end += grapheme.chars().count();
+        if end > char_pos {
+            return end;
+        }
+    }
+    end
+}
+
+/// Returns whether a grapheme cluster is whitespace.
+fn is_whitespace_grapheme(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Tab Expansion
+//--------------------------------------------------------------------------------------------------
+
+/// Expands tab characters to spaces up to the next tab stop.
+///
+/// Tab stops are every `tab_width` columns from the start of each line, so
+/// tab-separated columns line up regardless of wide characters before them.
+pub fn expand_tabs(text: &str, tab_width: u16) -> String {
+    let mut column = 0;
+    expand_tabs_from(text, tab_width, &mut column)
+}
+
+/// Expands tabs in text that starts at `column` of the current line.
+///
+/// `column` is advanced past the text, so consecutive pieces of one line, such
+/// as rich text spans, share tab stops.
+pub fn expand_tabs_from(text: &str, tab_width: u16, column: &mut usize) -> String {
+    let tab_width = usize::from(tab_width.max(1));
+    let mut expanded = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\t' => {
+                let spaces = tab_width - *column % tab_width;
+                expanded.extend(std::iter::repeat_n(' ', spaces));
+                *column += spaces;
+            }
+            '\n' => {
+                expanded.push(ch);
+                *column = 0;
+            }
+            _ => {
+                expanded.push(ch);
+                *column += char_width(ch);
+            }
+        }
+    }
+    expanded
+}
+
+//--------------------------------------------------------------------------------------------------
+// Functions: Text Transform
+//--------------------------------------------------------------------------------------------------
+
+/// Applies a case transform using Unicode case mapping.
+///
+/// Mapping can change the length, as with `ß` uppercasing to `SS`, so measure
+/// the transformed text rather than the original.
+pub fn transform_text(text: &str, transform: TextTransform) -> String {
+    let mut word_start = true;
+    transform_text_from(text, transform, &mut word_start)
+}
+
+/// Transforms text that continues the current line.
+///
+/// `word_start` says whether the text begins a word and is updated past it,
+/// so consecutive pieces of one line, such as rich text spans, capitalize a
+/// word split across them only once.
+pub fn transform_text_from(text: &str, transform: TextTransform, word_start: &mut bool) -> String {
+    let transformed = match transform {
+        TextTransform::None => text.to_string(),
+        TextTransform::Uppercase => text.to_uppercase(),
+        TextTransform::Lowercase => text.to_lowercase(),
+        TextTransform::Capitalize => {
+            let mut capitalized = String::with_capacity(text.len());
+            for ch in text.chars() {
+                if *word_start && ch.is_alphanumeric() {
+                    capitalized.extend(ch.to_uppercase());
+                } else {
+                    capitalized.push(ch);
+                }
+                // Punctuation before a word, like an opening quote, keeps waiting for it
+                if ch.is_whitespace() {
+                    *word_start = true;
+                } else if ch.is_alphanumeric() {
+                    *word_start = false;
+                }
+            }
+            return capitalized;
+        }
+    };
+    if let Some(last) = text.chars().last() {
+        *word_start = last.is_whitespace();
+    }
+    transformed
+}
+
 //--------------------------------------------------------------------------------------------------
 // Functions: Text Wrapping
 //--------------------------------------------------------------------------------------------------
@@ -124,6 +269,17 @@ pub fn substring_by_columns(s: &str, start_col: usize, end_col: usize) -> &str {
 /// Returns a vector of lines that fit within the given width.
 /// Empty lines are preserved in the output.
 pub fn wrap_text(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
+    wrap_text_with(text, width, mode, LineBreak::default())
+}
+
+/// Wraps text like [`wrap_text`], with `line_break` deciding where word modes
+/// may break besides whitespace.
+pub fn wrap_text_with(
+    text: &str,
+    width: u16,
+    mode: TextWrap,
+    line_break: LineBreak,
+) -> Vec<String> {
     if width == 0 {
         return vec![];
     }
@@ -139,18 +295,75 @@ pub fn wrap_text(text: &str, width: u16, mode: TextWrap) -> Vec<String> {
         }
         TextWrap::Word => {
             // Break at word boundaries only
-            wrap_word(text, width)
+            wrap_word(text, width, line_break)
         }
         TextWrap::WordBreak => {
             // Try word boundaries first, break words if necessary
-            wrap_word_break(text, width)
+            wrap_word_break(text, width, line_break)
         }
     }
 }
 
+/// Returns whether a line may break between two adjacent clusters, neither of
+/// them whitespace.
+///
+/// This is the default rule for word wrapping: breaks are allowed before and
+/// after CJK characters, since those scripts don't separate words with spaces,
+/// but never before closing punctuation or after opening punctuation, so a
+/// line doesn't start with `。` or end with `「`.
+pub fn cjk_line_break(before: &str, after: &str) -> bool {
+    let (Some(last), Some(first)) = (before.chars().next_back(), after.chars().next()) else {
+        return false;
+    };
+    if is_closing_punctuation(first) || is_opening_punctuation(last) {
+        return false;
+    }
+    is_cjk(last) || is_cjk(first)
+}
+
+/// Returns whether the rule allows a break between two non-whitespace clusters.
+fn allows_break(line_break: LineBreak, before: &str, after: &str) -> bool {
+    match line_break {
+        LineBreak::Whitespace => false,
+        LineBreak::Cjk => cjk_line_break(before, after),
+        LineBreak::Custom(rule) => rule(before, after),
+    }
+}
+
+/// Returns whether a character belongs to a script written without spaces
+/// between words (Han, kana, hangul) or to the CJK punctuation and full-width
+/// forms used with them.
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{1100}'..='\u{11FF}' // Hangul Jamo
+            | '\u{2E80}'..='\u{2FDF}' // CJK radicals
+            | '\u{3000}'..='\u{303F}' // CJK symbols and punctuation
+            | '\u{3040}'..='\u{30FF}' // Hiragana and Katakana
+            | '\u{3100}'..='\u{31FF}' // Bopomofo, Hangul compatibility, Katakana extensions
+            | '\u{3400}'..='\u{4DBF}' // CJK extension A
+            | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+            | '\u{AC00}'..='\u{D7AF}' // Hangul syllables
+            | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+            | '\u{FF00}'..='\u{FFEF}' // Half-width and full-width forms
+            | '\u{20000}'..='\u{3FFFF}' // CJK extensions B and later
+    )
+}
+
+/// Returns whether a character must not start a line.
+fn is_closing_punctuation(ch: char) -> bool {
+    NO_LINE_START.contains(ch)
+}
+
+/// Returns whether a character must not end a line.
+fn is_opening_punctuation(ch: char) -> bool {
+    NO_LINE_END.contains(ch)
+}
+
 /// Wraps text at character boundaries.
 ///
-/// Breaks the text based on display width, accounting for wide characters.
+/// Breaks the text based on display width, accounting for wide characters,
+/// and never inside a grapheme cluster.
 fn wrap_character(text: &str, width: u16) -> Vec<String> {
     let width = width as usize;
     let mut lines = Vec::new();
@@ -162,8 +375,8 @@ fn wrap_character(text: &str, width: u16) -> Vec<String> {
     let mut current_line = String::new();
     let mut current_width = 0;
 
-    for ch in text.chars() {
-        let ch_width = char_width(ch);
+    for grapheme in text.graphemes(true) {
+        let ch_width = display_width(grapheme);
 
         if current_width + ch_width > width && !current_line.is_empty() {
             // Start a new line
@@ -174,13 +387,13 @@ fn wrap_character(text: &str, width: u16) -> Vec<String> {
 
         // Add character if it fits (or if line is empty to avoid infinite loop)
         if current_width + ch_width <= width || current_line.is_empty() {
-            current_line.push(ch);
+            current_line.push_str(grapheme);
             current_width += ch_width;
         } else {
             // Character doesn't fit even on empty line (width too small for wide char)
             // Start next line with this character
             lines.push(current_line);
-            current_line = ch.to_string();
+            current_line = grapheme.to_string();
             current_width = ch_width;
         }
     }
@@ -192,54 +405,117 @@ fn wrap_character(text: &str, width: u16) -> Vec<String> {
     lines
 }
 
+/// Places a word on the current line, or starts a new line with it when it
+/// doesn't fit. Words wider than the line overflow.
+fn place_word(
+    lines: &mut Vec<String>,
+    line: &mut String,
+    line_width: &mut usize,
+    word: &str,
+    word_width: usize,
+    width: usize,
+) {
+    if *line_width == 0 || *line_width + word_width <= width {
+        line.push_str(word);
+        *line_width += word_width;
+    } else {
+        lines.push(std::mem::take(line));
+        line.push_str(word);
+        *line_width = word_width;
+    }
+}
+
+/// Places a word like [`place_word`], breaking words wider than the line at
+/// cluster boundaries.
+fn place_word_breaking(
+    lines: &mut Vec<String>,
+    line: &mut String,
+    line_width: &mut usize,
+    word: &str,
+    word_width: usize,
+    width: usize,
+) {
+    if *line_width > 0 && *line_width + word_width > width {
+        lines.push(std::mem::take(line));
+        *line_width = 0;
+    }
+
+    if *line_width + word_width <= width {
+        line.push_str(word);
+        *line_width += word_width;
+        return;
+    }
+
+    for grapheme in word.graphemes(true) {
+        let ch_width = display_width(grapheme);
+        if *line_width + ch_width > width && *line_width > 0 {
+            lines.push(std::mem::take(line));
+            *line_width = 0;
+        }
+        line.push_str(grapheme);
+        *line_width += ch_width;
+    }
+}
+
 /// Wraps text at word boundaries.
 ///
-/// Attempts to break lines at spaces and other word boundaries.
+/// Attempts to break lines at spaces and wherever `line_break` allows.
 /// If a word is longer than the line width, it will overflow.
 /// Preserves all spaces (leading, trailing, and in-between).
-fn wrap_word(text: &str, width: u16) -> Vec<String> {
+fn wrap_word(text: &str, width: u16, line_break: LineBreak) -> Vec<String> {
     let width = width as usize;
     let mut lines = Vec::new();
     let mut current_line = String::new();
     let mut current_width = 0;
 
-    // Process character by character to preserve all spaces
+    // Process cluster by cluster to preserve all spaces
     let mut in_word = false;
     let mut word = String::new();
     let mut word_width = 0;
     let mut pending_spaces = String::new();
     let mut pending_spaces_width = 0;
+    let mut previous: Option<&str> = None;
 
-    for ch in text.chars() {
-        if ch.is_whitespace() {
+    for grapheme in text.graphemes(true) {
+        if is_whitespace_grapheme(grapheme) {
             // Handle any accumulated word first
             if in_word {
-                // Check if word fits on current line
-                if current_width == 0 {
-                    // First content on line
-                    current_line.push_str(&word);
-                    current_width = word_width;
-                } else if current_width + word_width <= width {
-                    // Word fits on current line
-                    current_line.push_str(&word);
-                    current_width += word_width;
-                } else {
-                    // Word doesn't fit, start new line
-                    lines.push(current_line.clone());
-                    current_line = word.clone();
-                    current_width = word_width;
-                }
+                place_word(
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                    &word,
+                    word_width,
+                    width,
+                );
                 word.clear();
                 word_width = 0;
                 in_word = false;
             }
 
             // Now accumulate the space
-            pending_spaces.push(ch);
-            pending_spaces_width += char_width(ch);
+            pending_spaces.push_str(grapheme);
+            pending_spaces_width += display_width(grapheme);
         } else {
             // Non-whitespace character
 
+            // A break opportunity inside a run of text ends the word there
+            if in_word
+                && let Some(previous) = previous
+                && allows_break(line_break, previous, grapheme)
+            {
+                place_word(
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                    &word,
+                    word_width,
+                    width,
+                );
+                word.clear();
+                word_width = 0;
+            }
+
             // If we have pending spaces, handle them first
             if !pending_spaces.is_empty() {
                 // Check if spaces fit on current line
@@ -275,19 +551,22 @@ fn wrap_word(text: &str, width: u16) -> Vec<String> {
 
             // Start or continue building a word
             in_word = true;
-            word.push(ch);
-            word_width += char_width(ch);
+            word.push_str(grapheme);
+            word_width += display_width(grapheme);
         }
+        previous = Some(grapheme);
     }
 
     // Handle any remaining word
     if in_word {
-        if current_width == 0 || current_width + word_width <= width {
-            current_line.push_str(&word);
-        } else {
-            lines.push(current_line.clone());
-            current_line = word;
-        }
+        place_word(
+            &mut lines,
+            &mut current_line,
+            &mut current_width,
+            &word,
+            word_width,
+            width,
+        );
     }
 
     // Handle any trailing spaces
@@ -320,150 +599,94 @@ fn wrap_word(text: &str, width: u16) -> Vec<String> {
 
 /// Wraps text at word boundaries, breaking words if necessary.
 ///
-/// First attempts to break at word boundaries. If a word is longer than
-/// the line width, it breaks the word at character boundaries considering display width.
-fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
+/// First attempts to break at spaces and wherever `line_break` allows. If a
+/// word is longer than the line width, it breaks the word at character
+/// boundaries considering display width.
+fn wrap_word_break(text: &str, width: u16, line_break: LineBreak) -> Vec<String> {
     let width = width as usize;
     let mut lines = Vec::new();
     let mut current_line = String::new();
     let mut current_width = 0;
 
-    // Process text character by character to preserve spaces
-    let chars = text.chars();
+    // Process text cluster by cluster to preserve spaces
     let mut in_word = false;
     let mut word = String::new();
     let mut word_width = 0;
+    let mut previous: Option<&str> = None;
 
-    for ch in chars {
-        if ch.is_whitespace() {
+    for grapheme in text.graphemes(true) {
+        if is_whitespace_grapheme(grapheme) {
             // Handle any accumulated word first
             if in_word {
-                // Try to add the word to current line
-                if current_width == 0 {
-                    // First word on line
-                    if word_width <= width {
-                        current_line.push_str(&word);
-                        current_width = word_width;
-                    } else {
-                        // Word too long, break it
-                        for word_ch in word.chars() {
-                            let ch_width = char_width(word_ch);
-                            if current_width + ch_width > width && current_width > 0 {
-                                lines.push(current_line.clone());
-                                current_line.clear();
-                                current_width = 0;
-                            }
-                            current_line.push(word_ch);
-                            current_width += ch_width;
-                        }
-                    }
-                } else if current_width + word_width <= width {
-                    // Word fits on current line
-                    current_line.push_str(&word);
-                    current_width += word_width;
-                } else {
-                    // Word doesn't fit, start new line
-                    lines.push(current_line.clone());
-                    current_line.clear();
-                    current_width = 0;
-
-                    // Add word to new line (possibly breaking it)
-                    if word_width <= width {
-                        current_line.push_str(&word);
-                        current_width = word_width;
-                    } else {
-                        // Break the word
-                        for word_ch in word.chars() {
-                            let ch_width = char_width(word_ch);
-                            if current_width + ch_width > width && current_width > 0 {
-                                lines.push(current_line.clone());
-                                current_line.clear();
-                                current_width = 0;
-                            }
-                            current_line.push(word_ch);
-                            current_width += ch_width;
-                        }
-                    }
-                }
-
+                place_word_breaking(
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                    &word,
+                    word_width,
+                    width,
+                );
                 word.clear();
                 word_width = 0;
                 in_word = false;
             }
 
             // Now handle the whitespace character
-            let ch_width = char_width(ch);
+            let ch_width = display_width(grapheme);
             if current_width + ch_width > width && current_width > 0 {
                 // Whitespace would exceed width, start new line
                 lines.push(current_line.clone());
                 current_line.clear();
                 // Skip first space when starting new line, preserve other whitespace
-                if ch == ' ' {
+                if grapheme == " " {
                     // Skip the first space that would lead the new line
                     current_width = 0;
                 } else {
                     // Preserve tabs and other whitespace
-                    current_line.push(ch);
+                    current_line.push_str(grapheme);
                     current_width = ch_width;
                 }
             } else {
                 // Add the whitespace character
-                current_line.push(ch);
+                current_line.push_str(grapheme);
                 current_width += ch_width;
             }
         } else {
+            // A break opportunity inside a run of text ends the word there
+            if in_word
+                && let Some(previous) = previous
+                && allows_break(line_break, previous, grapheme)
+            {
+                place_word_breaking(
+                    &mut lines,
+                    &mut current_line,
+                    &mut current_width,
+                    &word,
+                    word_width,
+                    width,
+                );
+                word.clear();
+                word_width = 0;
+            }
+
             // Non-whitespace character - accumulate in word
             in_word = true;
-            word.push(ch);
-            word_width += char_width(ch);
+            word.push_str(grapheme);
+            word_width += display_width(grapheme);
         }
+        previous = Some(grapheme);
     }
 
     // Handle any remaining word
     if in_word {
-        if current_width == 0 {
-            // First word on line
-            if word_width <= width {
-                current_line.push_str(&word);
-            } else {
-                // Word too long, break it
-                for word_ch in word.chars() {
-                    let ch_width = char_width(word_ch);
-                    if current_width + ch_width > width && current_width > 0 {
-                        lines.push(current_line.clone());
-                        current_line.clear();
-                        current_width = 0;
-                    }
-                    current_line.push(word_ch);
-                    current_width += ch_width;
-                }
-            }
-        } else if current_width + word_width <= width {
-            // Word fits on current line
-            current_line.push_str(&word);
-        } else {
-            // Word doesn't fit, start new line
-            lines.push(current_line.clone());
-            current_line.clear();
-
-            // Add word to new line (possibly breaking it)
-            if word_width <= width {
-                current_line = word;
-            } else {
-                // Break the word
-                current_width = 0;
-                for word_ch in word.chars() {
-                    let ch_width = char_width(word_ch);
-                    if current_width + ch_width > width && current_width > 0 {
-                        lines.push(current_line.clone());
-                        current_line.clear();
-                        current_width = 0;
-                    }
-                    current_line.push(word_ch);
-                    current_width += ch_width;
-                }
-            }
-        }
+        place_word_breaking(
+            &mut lines,
+            &mut current_line,
+            &mut current_width,
+            &word,
+            word_width,
+            width,
+        );
     }
 
     // Add the last line if not empty
@@ -477,6 +700,78 @@ fn wrap_word_break(text: &str, width: u16) -> Vec<String> {
     lines
 }
 
+//--------------------------------------------------------------------------------------------------
+// Functions: Text Justification
+//--------------------------------------------------------------------------------------------------
+
+/// Widens the gaps between words so a line fills `width` columns.
+///
+/// ```text
+/// "the quick fox" at width 17 → "the   quick   fox"
+/// ```
+///
+/// Trailing spaces are dropped. Lines with a single word, or that already
+/// fill the width, are returned unchanged.
+pub fn justify_line(line: &str, width: u16) -> String {
+    justify_segments(&[line], width).pop().unwrap_or_default()
+}
+
+/// Justifies a line split into consecutive segments, such as styled spans.
+///
+/// A gap is a run of spaces between two words, even when it crosses segment
+/// boundaries. Extra spaces are spread evenly over the gaps, leftmost gaps
+/// first, and added to the segment where each gap ends so styling stays with
+/// the surrounding text.
+pub fn justify_segments(segments: &[&str], width: u16) -> Vec<String> {
+    let mut justified: Vec<String> = segments.iter().map(|s| s.to_string()).collect();
+
+    // Drop trailing spaces, which may span several segments
+    for segment in justified.iter_mut().rev() {
+        let trimmed_len = segment.trim_end_matches(' ').len();
+        let all_spaces = trimmed_len == 0;
+        segment.truncate(trimmed_len);
+        if !all_spaces {
+            break;
+        }
+    }
+
+    // Find where each gap ends as (segment index, byte offset)
+    let mut gaps = Vec::new();
+    let mut seen_word = false;
+    let mut gap_end = None;
+    for (index, segment) in justified.iter().enumerate() {
+        for (offset, ch) in segment.char_indices() {
+            if ch == ' ' {
+                if seen_word {
+                    gap_end = Some((index, offset + 1));
+                }
+            } else {
+                if let Some(end) = gap_end.take() {
+                    gaps.push(end);
+                }
+                seen_word = true;
+            }
+        }
+    }
+
+    let used: usize = justified.iter().map(|s| display_width(s)).sum();
+    let extra = (width as usize).saturating_sub(used);
+    if gaps.is_empty() || extra == 0 {
+        return segments.iter().map(|s| s.to_string()).collect();
+    }
+
+    let share = extra / gaps.len();
+    let remainder = extra % gaps.len();
+
+    // Insert from the right so earlier offsets stay valid
+    for (gap, &(index, offset)) in gaps.iter().enumerate().rev() {
+        let count = share + usize::from(gap < remainder);
+        justified[index].insert_str(offset, &" ".repeat(count));
+    }
+
+    justified
+}
+
 //--------------------------------------------------------------------------------------------------
 // Tests
 //--------------------------------------------------------------------------------------------------
@@ -546,6 +841,73 @@ mod tests {
     // Tests: Text Wrapping Functions
     //----------------------------------------------------------------------------------------------
 
+    #[test]
+    fn test_grapheme_clusters() {
+        let flag = "🇺🇸";
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let accent = "e\u{301}";
+        let text = format!("a{flag}{family}{accent}b");
+
+        assert_eq!(grapheme_count(&text), 5);
+        assert_eq!(display_width(family), 2);
+
+        // Stepping by clusters: a | flag (2 chars) | family (5) | accent (2) | b
+        let stops = [0, 1, 3, 8, 10, 11];
+        for pair in stops.windows(2) {
+            assert_eq!(next_grapheme_boundary(&text, pair[0]), pair[1]);
+            assert_eq!(prev_grapheme_boundary(&text, pair[1]), pair[0]);
+        }
+        assert_eq!(next_grapheme_boundary(&text, 11), 11);
+
+        // Column slicing and wrapping keep clusters whole
+        assert_eq!(substring_by_columns(&text, 1, 5), format!("{flag}{family}"));
+        assert_eq!(
+            substring_by_columns(&text, 3, 6),
+            format!("{family}{accent}")
+        );
+        assert_eq!(
+            wrap_text(&text, 3, TextWrap::Character),
+            vec![
+                format!("a{flag}"),
+                format!("{family}{accent}"),
+                "b".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expand_tabs("a\tb", 4), "a   b");
+        assert_eq!(expand_tabs("abcd\tb", 4), "abcd    b");
+        assert_eq!(expand_tabs("你\tb\n\tc", 4), "你  b\n    c");
+
+        // Spans continue the line's tab stops
+        let mut column = 0;
+        assert_eq!(expand_tabs_from("ab", 8, &mut column), "ab");
+        assert_eq!(expand_tabs_from("\tc", 8, &mut column), "      c");
+        assert_eq!(column, 9);
+    }
+
+    #[test]
+    fn test_transform_text() {
+        assert_eq!(
+            transform_text("straße", TextTransform::Uppercase),
+            "STRASSE"
+        );
+        assert_eq!(transform_text("ΣΟΦΟΣ", TextTransform::Lowercase), "σοφος");
+        assert_eq!(
+            transform_text("élan vital (über) don't", TextTransform::Capitalize),
+            "Élan Vital (Über) Don't"
+        );
+        assert_eq!(transform_text("Mixed", TextTransform::None), "Mixed");
+
+        // A word split across spans is capitalized once
+        let mut word_start = true;
+        let spans = ["hel", "lo ", "world"]
+            .map(|span| transform_text_from(span, TextTransform::Capitalize, &mut word_start));
+        assert_eq!(spans, ["Hel", "lo ", "World"]);
+    }
+
     #[test]
     fn test_wrap_none() {
         let text = "This is a very long line that should not be wrapped";
@@ -634,6 +996,45 @@ mod tests {
         assert_eq!(wrapped, vec!["Hello 世界", "World"]); // "Hello " = 6, "世界" = 4, first space trimmed on next line
     }
 
+    #[test]
+    fn test_wrap_word_breaks_between_cjk_characters() {
+        // No spaces to break at, so each ideograph is a break opportunity
+        let text = "日本語のテキスト";
+        assert_eq!(
+            wrap_text(text, 6, TextWrap::Word),
+            vec!["日本語", "のテキ", "スト"]
+        );
+        assert_eq!(
+            wrap_text(text, 6, TextWrap::WordBreak),
+            vec!["日本語", "のテキ", "スト"]
+        );
+
+        // Latin runs inside CJK text stay whole
+        assert_eq!(
+            wrap_text("価格はlatteです", 8, TextWrap::Word),
+            vec!["価格は", "latteで", "す"]
+        );
+
+        // Whitespace-only breaking keeps the old overflow
+        assert_eq!(
+            wrap_text_with(text, 6, TextWrap::Word, LineBreak::Whitespace),
+            vec![text]
+        );
+    }
+
+    #[test]
+    fn test_wrap_keeps_punctuation_with_its_text() {
+        // 。 never starts a line and 「 never ends one
+        assert_eq!(
+            wrap_text("日本語。「本」", 6, TextWrap::Word),
+            vec!["日本", "語。", "「本」"]
+        );
+        assert!(!cjk_line_break("語", "、"));
+        assert!(!cjk_line_break("「", "本"));
+        assert!(cjk_line_break("a", "本"));
+        assert!(!cjk_line_break("a", "b"));
+    }
+
     #[test]
     fn test_wrap_emoji() {
         // Emoji typically have width 2
@@ -666,4 +1067,41 @@ mod tests {
         let wrapped = wrap_text(text, 10, TextWrap::Word);
         assert_eq!(wrapped, vec!["Hello ", "World    "]);
     }
+
+    //----------------------------------------------------------------------------------------------
+    // Tests: Text Justification
+    //----------------------------------------------------------------------------------------------
+
+    #[test]
+    fn test_justify_line_spreads_extra_space_left_first() {
+        assert_eq!(justify_line("the quick fox", 17), "the   quick   fox");
+        assert_eq!(justify_line("a b c d", 10), "a  b  c  d");
+        assert_eq!(justify_line("a b c d", 9), "a  b  c d");
+    }
+
+    #[test]
+    fn test_justify_line_leaves_single_words_and_full_lines() {
+        assert_eq!(justify_line("word", 10), "word");
+        assert_eq!(justify_line("  indented", 12), "  indented");
+        assert_eq!(justify_line("full line", 9), "full line");
+    }
+
+    #[test]
+    fn test_justify_line_drops_trailing_spaces() {
+        assert_eq!(justify_line("ab cd  ", 8), "ab    cd");
+    }
+
+    #[test]
+    fn test_justify_line_wide_characters() {
+        // Each CJK character is two columns wide
+        let justified = justify_line("你好 世界", 12);
+        assert_eq!(justified, "你好    世界");
+        assert_eq!(display_width(&justified), 12);
+    }
+
+    #[test]
+    fn test_justify_segments_keeps_gap_in_ending_segment() {
+        let justified = justify_segments(&["one ", "two", " three "], 16);
+        assert_eq!(justified, vec!["one   ", "two", "  three"]);
+    }
 }
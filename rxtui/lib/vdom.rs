@@ -41,6 +41,7 @@
 
 use crate::diff::{Patch, diff};
 use crate::render_tree::{RenderNode, RenderNodeType, RenderTree};
+use crate::semantic::{accessible_text, semantic_outline};
 use crate::utils::display_width;
 use crate::vnode::VNode;
 use std::cell::RefCell;
@@ -134,6 +135,26 @@ impl VDom {
         &self.render_tree
     }
 
+    /// Returns a plain-text semantic outline of the current node tree.
+    ///
+    /// Returns an empty string if nothing has been rendered yet.
+    pub fn render_semantic(&self) -> String {
+        match &self.current_vnode {
+            Some(vnode) => semantic_outline(vnode, self.render_tree.root.as_ref()),
+            None => String::new(),
+        }
+    }
+
+    /// Returns the current UI as plain text for screen readers.
+    ///
+    /// Returns an empty string if nothing has been rendered yet.
+    pub fn accessible_text(&self) -> String {
+        match &self.current_vnode {
+            Some(vnode) => accessible_text(vnode, self.render_tree.root.as_ref()),
+            None => String::new(),
+        }
+    }
+
     /// Returns the shared focus-clear flag for coordination with contexts.
     pub fn focus_clear_flag(&self) -> Arc<AtomicBool> {
         self.render_tree.focus_clear_flag()
@@ -166,9 +187,11 @@ impl VDom {
         render_node.styles = div.styles.clone();
         render_node.events = div.events.clone();
         render_node.focusable = div.focusable;
+        render_node.scroll_capture = div.scroll_capture;
         render_node.focused = div.focused;
         render_node.hovered = div.hovered;
         render_node.component_path = div.component_path.clone();
+        render_node.memo_key = div.memo_key;
         render_node.refresh_state_style();
 
         let node_rc = Rc::new(RefCell::new(render_node));
@@ -296,6 +319,7 @@ impl VDom {
     /// - **UpdateProps**: Update styles/dimensions
     /// - **AddChild**: Insert new child node
     /// - **RemoveChild**: Delete child node
+    /// - **ReorderChildren**: Keep existing children in a new order
     fn apply_patch(&mut self, patch: Patch) {
         match patch {
             Patch::Replace { old, new } => {
@@ -370,14 +394,18 @@ impl VDom {
                 // Preserve the existing focus state from the old node
                 let is_focused = node_ref.focused;
                 let is_hovered = node_ref.hovered;
+                let is_active = node_ref.active;
 
                 // Update container properties but preserve focus state
                 node_ref.styles = div.styles.clone();
                 node_ref.events = div.events.clone();
                 node_ref.focusable = div.focusable;
+                node_ref.scroll_capture = div.scroll_capture;
                 node_ref.focused = is_focused;
                 node_ref.hovered = is_hovered;
+                node_ref.active = is_active;
                 node_ref.component_path = div.component_path.clone();
+                node_ref.memo_key = div.memo_key;
                 node_ref.refresh_state_style();
                 node_ref.mark_dirty();
             }
@@ -408,6 +436,15 @@ impl VDom {
                     parent_ref.mark_dirty();
                 }
             }
+            Patch::ReorderChildren { parent, children } => {
+                // Moved nodes are laid out again at their new positions
+                for child in &children {
+                    child.borrow_mut().mark_dirty();
+                }
+                let mut parent_ref = parent.borrow_mut();
+                parent_ref.children = children;
+                parent_ref.mark_dirty();
+            }
         }
     }
 }
@@ -0,0 +1,58 @@
+//! Tests for the #[derive(Component)] and #[component] macros
+
+use rxtui::prelude::*;
+
+//--------------------------------------------------------------------------------------------------
+// Hook Routing Tests
+//--------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Row {
+    id: u32,
+}
+
+#[component]
+impl Row {
+    #[view]
+    fn view(&self, _ctx: &Context) -> Node {
+        node! { div [] }
+    }
+
+    fn key(&self) -> Option<String> {
+        Some(format!("row-{}", self.id))
+    }
+}
+
+#[derive(Component)]
+struct Table;
+
+#[component]
+impl Table {
+    #[view]
+    fn view(&self, _ctx: &Context) -> Node {
+        node! { div [] }
+    }
+
+    /// Shares a hook's name with a different signature, so it stays a plain method
+    fn key(&self, column: usize) -> String {
+        format!("column-{column}")
+    }
+
+    /// Takes a value rather than the previous component, so it isn't the props hook
+    fn props_changed(&self, rows: usize) -> bool {
+        rows > 0
+    }
+}
+
+#[test]
+fn test_hook_methods_are_routed_to_the_component() {
+    let row = Row { id: 3 };
+    assert_eq!(Component::key(&row), Some("row-3".to_string()));
+}
+
+#[test]
+fn test_methods_sharing_a_hook_name_are_left_alone() {
+    assert_eq!(Component::key(&Table), None);
+    assert_eq!(Table.key(2), "column-2");
+    assert!(Table.props_changed(1));
+}
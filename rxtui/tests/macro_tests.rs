@@ -42,6 +42,32 @@ fn test_div_with_hex_color() {
     }
 }
 
+#[test]
+fn test_color_strings_in_css_forms() {
+    let node = node! {
+        div(bg: "slategray", border: "hsl(120, 100%, 25%)") [
+            text("hi", color: "rgb(255, 99, 71)")
+        ]
+    };
+
+    let Node::Div(div) = node else {
+        panic!("Expected div node");
+    };
+    let style = div.styles.base.unwrap();
+    assert_eq!(style.background, Some(Color::Rgb(112, 128, 144)));
+    assert_eq!(
+        style.border.map(|border| border.color),
+        Some(Color::Rgb(0, 128, 0))
+    );
+    let Node::Text(text) = &div.children[0] else {
+        panic!("Expected text node");
+    };
+    assert_eq!(
+        text.style.as_ref().unwrap().color,
+        Some(Color::Rgb(255, 99, 71))
+    );
+}
+
 #[test]
 fn test_div_with_percentage_dimensions() {
     let node = node! {
@@ -82,6 +108,51 @@ fn test_div_focus_border_none() {
     }
 }
 
+#[test]
+fn test_div_prebuilt_style() {
+    let card = Style::filled(Color::Blue).padding(Spacing::all(1));
+    let node = node! {
+        div(bg: red, w: 10, style: (card), pad: 2) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.expect("base style missing");
+            assert_eq!(style.background, Some(Color::Blue));
+            assert_eq!(style.width, Some(Dimension::Fixed(10)));
+            assert_eq!(style.padding, Some(Spacing::all(2)));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_prebuilt_style_optional_and_text() {
+    let muted = TextStyle::new().color(Color::BrightBlack).italic(true);
+    let missing: Option<Style> = None;
+    let node = node! {
+        div(pad: 1, style: (missing)!) [
+            text("note", bold, style: (muted), color: white)
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.expect("base style missing");
+            assert_eq!(style.padding, Some(Spacing::all(1)));
+
+            let Node::Text(text) = &container.children[0] else {
+                panic!("Expected text node");
+            };
+            let text_style = text.style.as_ref().unwrap();
+            assert_eq!(text_style.bold, Some(true));
+            assert_eq!(text_style.italic, Some(true));
+            assert_eq!(text_style.color, Some(Color::White));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 #[test]
 fn test_div_focus_border_color() {
     let node = node! {
@@ -99,6 +170,22 @@ fn test_div_focus_border_color() {
     }
 }
 
+#[test]
+fn test_div_pressed_style() {
+    let pressed = Style::default().background(Color::Red);
+    let node = node! {
+        div(bg: blue, pressed_style: (pressed), @click: || {}) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let pressed = container.styles.pressed.expect("pressed style missing");
+            assert_eq!(pressed.background, Some(Color::Red));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Text Tests
 //--------------------------------------------------------------------------------------------------
@@ -151,6 +238,57 @@ fn test_text_with_multiple_styles() {
     }
 }
 
+#[test]
+fn test_text_with_underline_style() {
+    let node = node! {
+        div [
+            text("Misspeled", underline_style: curly, underline_color: red)
+        ]
+    };
+
+    match node {
+        Node::Div(container) => match &container.children[0] {
+            Node::Text(text) => {
+                let style = text.style.as_ref().unwrap();
+                assert_eq!(style.underline, Some(true));
+                assert_eq!(style.underline_style, Some(UnderlineStyle::Curly));
+                assert_eq!(style.underline_color, Some(Color::Red));
+            }
+            _ => panic!("Expected text node"),
+        },
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_text_with_sgr_attributes() {
+    let node = node! {
+        div [
+            text("Selected", reverse, bold),
+            text("Alert", blink),
+            text("secret", hidden)
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let styles: Vec<_> = container
+                .children
+                .iter()
+                .map(|child| match child {
+                    Node::Text(text) => text.style.clone().unwrap(),
+                    _ => panic!("Expected text node"),
+                })
+                .collect();
+            assert_eq!(styles[0].reverse, Some(true));
+            assert_eq!(styles[0].bold, Some(true));
+            assert_eq!(styles[1].blink, Some(true));
+            assert_eq!(styles[2].conceal, Some(true));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 #[test]
 fn test_text_with_bright_colors() {
     let node = node! {
@@ -360,6 +498,65 @@ fn test_absolute_positioning() {
     }
 }
 
+#[test]
+fn test_fractional_z_index() {
+    let node = node! {
+        div(pos: absolute, z: 1.5) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.as_ref().unwrap();
+            assert_eq!(style.z_index, None);
+            assert_eq!(style.fractional_z_index, Some(1.5));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_portal_layer() {
+    let node = node! {
+        div(absolute, top: 1, portal: 2) [
+            text("Option")
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.as_ref().unwrap();
+            assert_eq!(style.portal, Some(2));
+            assert_eq!(style.position, Some(Position::Absolute));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_percentage_offsets() {
+    let node = node! {
+        div(
+            absolute,
+            left_frac: 0.5,
+            bottom: (Offset::percent(0.25)),
+            translate: (Offset::percent(-0.5), 1)
+        ) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.as_ref().unwrap();
+            assert_eq!(style.left_fraction, Some(0.5));
+            assert_eq!(style.bottom_fraction, Some(0.25));
+            assert_eq!(
+                style.translate,
+                Some((Offset::Percent(-0.5), Offset::Cells(1)))
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 #[test]
 fn test_absolute_shorthand() {
     let node = node! {
@@ -446,6 +643,336 @@ fn test_direction_shortcuts() {
     }
 }
 
+#[test]
+fn test_reverse_direction_shortcuts() {
+    let direction_of = |node: Node| match node {
+        Node::Div(div) => div.styles.base.and_then(|s| s.direction),
+        _ => panic!("Expected div node"),
+    };
+
+    assert_eq!(
+        direction_of(node! { div(dir: vertical_reverse) [] }),
+        Some(Direction::VerticalReverse)
+    );
+    assert_eq!(
+        direction_of(node! { div(dir: reverse) [] }),
+        Some(Direction::VerticalReverse)
+    );
+    assert_eq!(
+        direction_of(node! { div(dir: hr) [] }),
+        Some(Direction::HorizontalReverse)
+    );
+}
+
+#[test]
+fn test_place_content_prop() {
+    let node = node! {
+        div(w: 40, h: 20, place: center, align: end) [
+            text("centered")
+        ]
+    };
+
+    match node {
+        Node::Div(div) => {
+            let style = div.styles.base.unwrap();
+            assert_eq!(style.place_content, Some(AlignItems::Center));
+            assert_eq!(style.align_items, Some(AlignItems::End));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_baseline_alignment_props() {
+    let node = node! {
+        div(dir: h, align: baseline) [
+            div(align_self: baseline) []
+        ]
+    };
+
+    match node {
+        Node::Div(div) => {
+            assert_eq!(
+                div.styles.base.unwrap().align_items,
+                Some(AlignItems::Baseline)
+            );
+            let Node::Div(child) = &div.children[0] else {
+                panic!("Expected div child");
+            };
+            let style = child.styles.base.as_ref().unwrap();
+            assert_eq!(style.align_self, Some(AlignSelf::Baseline));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_scroll_capture_props() {
+    let node = node! {
+        div [
+            div(overflow: scroll, scroll_capture) [],
+            div(scroll_capture: false) []
+        ]
+    };
+
+    let Node::Div(div) = node else {
+        panic!("Expected div node");
+    };
+    let captures: Vec<bool> = div
+        .children
+        .iter()
+        .map(|child| match child {
+            Node::Div(child) => child.scroll_capture,
+            _ => panic!("Expected div child"),
+        })
+        .collect();
+    assert_eq!(captures, [true, false]);
+}
+
+#[test]
+fn test_text_align_justify() {
+    let node = node! {
+        text("prose", wrap: word, align: justify)
+    };
+
+    match node {
+        Node::Text(text) => {
+            assert_eq!(text.style.unwrap().align, Some(TextAlign::Justify));
+        }
+        _ => panic!("Expected text node"),
+    }
+}
+
+#[test]
+fn test_text_tab_width() {
+    let node = node! {
+        div [
+            text("a\tb", tab_width: 4, bold),
+            richtext(tab_width: 2) [
+                text("x\ty")
+            ]
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let Node::Text(text) = &container.children[0] else {
+                panic!("Expected text node");
+            };
+            assert_eq!(text.style.as_ref().unwrap().tab_width, Some(4));
+            let Node::RichText(rich) = &container.children[1] else {
+                panic!("Expected rich text node");
+            };
+            assert_eq!(rich.style.as_ref().unwrap().tab_width, Some(2));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_line_break_props() {
+    let node = node! {
+        div [
+            text("日本語", wrap: word, line_break: whitespace),
+            richtext(line_break: cjk) [
+                text("テキスト")
+            ]
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let Node::Text(text) = &container.children[0] else {
+                panic!("Expected text node");
+            };
+            let style = text.style.as_ref().unwrap();
+            assert_eq!(style.line_break, Some(LineBreak::Whitespace));
+            let Node::RichText(rich) = &container.children[1] else {
+                panic!("Expected rich text node");
+            };
+            assert_eq!(
+                rich.style.as_ref().unwrap().line_break,
+                Some(LineBreak::Cjk)
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_text_transform() {
+    let node = node! {
+        div [
+            text("Section", transform: uppercase, bold),
+            richtext(transform: capitalize) [
+                text("hello world")
+            ]
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let Node::Text(text) = &container.children[0] else {
+                panic!("Expected text node");
+            };
+            // Only the displayed case changes
+            assert_eq!(text.content, "Section");
+            let style = text.style.as_ref().unwrap();
+            assert_eq!(style.text_transform, Some(TextTransform::Uppercase));
+            let Node::RichText(rich) = &container.children[1] else {
+                panic!("Expected rich text node");
+            };
+            assert_eq!(
+                rich.style.as_ref().unwrap().text_transform,
+                Some(TextTransform::Capitalize)
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_text_bg_fill() {
+    let node = node! {
+        text("Selected", bg: blue, align: center, bg_fill: true)
+    };
+
+    match node {
+        Node::Text(text) => {
+            let style = text.style.unwrap();
+            assert_eq!(style.bg_fill, Some(true));
+            assert_eq!(style.background, Some(Color::Blue));
+        }
+        _ => panic!("Expected text node"),
+    }
+}
+
+#[test]
+fn test_fill_char() {
+    let node = node! {
+        div(w: 10, h: 3, fill: '·', fill_color: bright_black) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.as_ref().unwrap();
+            assert_eq!(style.fill_char, Some('·'));
+            assert_eq!(style.fill_color, Some(Color::BrightBlack));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_match_directive() {
+    enum Phase {
+        Loading,
+        Failed(u32),
+        Done,
+    }
+
+    let render = |phase: Phase| -> Vec<String> {
+        let node = node! {
+            div [
+                text("head"),
+                match phase {
+                    Phase::Loading => { text("loading") },
+                    Phase::Failed(code) if code >= 500 => {
+                        text(format!("server {code}")),
+                        div [ text("retry") ]
+                    },
+                    Phase::Failed(code) => { text(format!("client {code}")) }
+                    Phase::Done => {}
+                },
+                text("foot")
+            ]
+        };
+
+        let Node::Div(container) = node else {
+            panic!("Expected div node");
+        };
+        container
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::Text(text) => text.content.clone(),
+                Node::Div(_) => "div".to_string(),
+                _ => panic!("Unexpected child"),
+            })
+            .collect()
+    };
+
+    assert_eq!(render(Phase::Loading), ["head", "loading", "foot"]);
+    assert_eq!(
+        render(Phase::Failed(503)),
+        ["head", "server 503", "div", "foot"]
+    );
+    assert_eq!(render(Phase::Failed(404)), ["head", "client 404", "foot"]);
+    assert_eq!(render(Phase::Done), ["head", "foot"]);
+
+    // As the last child, with a field as the scrutinee
+    let toggles = (false, true);
+    let node = node! {
+        vstack [
+            match toggles.1 {
+                true => { text("on") },
+                false => { text("off") }
+            }
+        ]
+    };
+    let Node::Div(container) = node else {
+        panic!("Expected div node");
+    };
+    let [Node::Text(text)] = container.children.as_slice() else {
+        panic!("Expected a single text child");
+    };
+    assert_eq!(text.content, "on");
+}
+
+#[test]
+fn test_memo_key() {
+    let title = "Dashboard";
+    let memo_key = |node: Node| match node {
+        Node::Div(container) => container.memo_key,
+        _ => panic!("Expected div node"),
+    };
+
+    let first = memo_key(node! { div(memo: (title, 80)) [ text(title) ] });
+    let same = memo_key(node! { div(memo: (title, 80)) [ text(title) ] });
+    let resized = memo_key(node! { div(memo: (title, 120)) [ text(title) ] });
+
+    assert!(first.is_some());
+    assert_eq!(first, same);
+    assert_ne!(first, resized);
+    assert_eq!(memo_key(node! { div [] }), None);
+}
+
+#[test]
+fn test_border_chars() {
+    let node = node! {
+        div(border_color: yellow, border_chars: (['+', '=', '+', '!', '!', '+', '=', '+'])) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let border = container
+                .styles
+                .base
+                .as_ref()
+                .unwrap()
+                .border
+                .as_ref()
+                .unwrap();
+            assert_eq!(
+                border.style,
+                BorderStyle::Custom(['+', '=', '+', '!', '!', '+', '=', '+'])
+            );
+            assert_eq!(border.color, Color::Yellow);
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 #[test]
 fn test_all_color_names() {
     let node = node! {
@@ -469,6 +996,27 @@ fn test_all_color_names() {
     }
 }
 
+#[test]
+fn test_default_color_keyword() {
+    let node = node! {
+        div(bg: default) [
+            text("Plain", color: default)
+        ]
+    };
+
+    let Node::Div(container) = node else {
+        panic!("Expected div node");
+    };
+    assert_eq!(
+        container.styles.base.as_ref().unwrap().background,
+        Some(Color::Default)
+    );
+    let Node::Text(text) = &container.children[0] else {
+        panic!("Expected text node");
+    };
+    assert_eq!(text.style.as_ref().unwrap().color, Some(Color::Default));
+}
+
 #[test]
 fn test_wrap_and_overflow_modes() {
     let node = node! {
@@ -487,6 +1035,39 @@ fn test_wrap_and_overflow_modes() {
     }
 }
 
+#[test]
+fn test_role_prop() {
+    let node = node! {
+        div [
+            div(role: heading) [text("Files")],
+            div(role: list_item, pad: 1) [text("Cargo.toml")],
+            div(role: (Role::Button)) [text("Open")]
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            let roles: Vec<_> = container
+                .children
+                .iter()
+                .map(|child| match child {
+                    Node::Div(div) => div.role,
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                roles,
+                vec![
+                    Some(Role::Heading),
+                    Some(Role::ListItem),
+                    Some(Role::Button)
+                ]
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Focus and Interaction Tests
 //--------------------------------------------------------------------------------------------------
@@ -520,6 +1101,123 @@ fn test_focusable_with_value() {
     }
 }
 
+#[test]
+#[cfg(feature = "components")]
+fn test_input_submit_options() {
+    let node = node! {
+        input(
+            enter_inserts_newline,
+            submit_key: (alt + enter),
+            clear_on_submit
+        )
+    };
+
+    match node {
+        Node::Component(_) => {}
+        _ => panic!("Expected component node"),
+    }
+}
+
+#[test]
+#[cfg(feature = "components")]
+fn test_input_password_props() {
+    let show = false;
+    let node = node! {
+        div [
+            input(password, mask_char: '*', reveal_key: (ctrl + 'r')),
+            input(password, reveal: show)
+        ]
+    };
+
+    match node {
+        Node::Div(container) => assert_eq!(container.children.len(), 2),
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+#[cfg(feature = "components")]
+fn test_input_constraint_props() {
+    let node = node! {
+        input(
+            max_length: 5,
+            filter: |ch: char| ch.is_ascii_digit(),
+            validate: |text: &str| if text.len() == 5 { Ok(()) } else { Err("5 digits".into()) },
+            @change: |_text: String| {}
+        )
+    };
+
+    match node {
+        Node::Component(_) => {}
+        _ => panic!("Expected component node"),
+    }
+}
+
+#[test]
+#[cfg(feature = "components")]
+fn test_number_input_props() {
+    let node = node! {
+        div [
+            number(min: 0.0, max: 10.0, step: 0.5, value: 2.0, @change: |_value: f64| {}),
+            number(integer, wheel, w: 8, border: cyan),
+            number
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            assert_eq!(container.children.len(), 3);
+            assert!(
+                container
+                    .children
+                    .iter()
+                    .all(|child| matches!(child, Node::Component(_)))
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+#[cfg(feature = "components")]
+fn test_menu_props() {
+    let items = vec![MenuItem::new("copy", "Copy").accelerator("Ctrl+C")];
+    let node = node! {
+        div [
+            menu(items: items.clone(), highlight: cyan, @select: |_id: String| {}),
+            menu(context, items: items, content: node! { div [text("body")] }, bg: black)
+        ]
+    };
+
+    match node {
+        Node::Div(container) => {
+            assert_eq!(container.children.len(), 2);
+            assert!(
+                container
+                    .children
+                    .iter()
+                    .all(|child| matches!(child, Node::Component(_)))
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_pointer_click_handlers() {
+    let node = node! {
+        div(@right_click: |_position: (u16, u16)| {}, @middle_click: |_position: (u16, u16)| {}) []
+    };
+
+    match node {
+        Node::Div(div) => {
+            assert!(div.events.on_right_click.is_some());
+            assert!(div.events.on_middle_click.is_some());
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Edge Cases
 //--------------------------------------------------------------------------------------------------
@@ -579,3 +1277,128 @@ fn test_expression_in_dimensions() {
         _ => panic!("Expected div node"),
     }
 }
+
+#[test]
+fn test_fragment_child() {
+    let items: Node = ["a", "b"]
+        .iter()
+        .map(|item| node! { text(*item) })
+        .collect();
+    assert_eq!(
+        items,
+        Node::fragment(vec![Node::text("a"), Node::text("b")])
+    );
+
+    let node = node! {
+        div(gap: 1) [
+            text("header"),
+            (items)
+        ]
+    };
+
+    match node {
+        Node::Div(div) => {
+            assert_eq!(div.children.len(), 2);
+            assert!(matches!(&div.children[1], Node::Fragment(children) if children.len() == 2));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_remaining_fraction_props() {
+    let node = node! {
+        div(w_rem: 0.3, h_rem: 1.5) []
+    };
+
+    match node {
+        Node::Div(container) => {
+            let style = container.styles.base.as_ref().unwrap();
+            assert_eq!(style.width, Some(Dimension::FractionRemaining(0.3)));
+            assert_eq!(style.height, Some(Dimension::FractionRemaining(1.0)));
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_key_sequence_handlers() {
+    let node = node! {
+        div(@seq("gg"): || {}, @seq_global("dd"): || {}) []
+    };
+
+    match node {
+        Node::Div(div) => {
+            let sequences: Vec<_> = div
+                .events
+                .on_key_sequence
+                .iter()
+                .map(|(keys, _, is_global)| (keys.clone(), *is_global))
+                .collect();
+            assert_eq!(
+                sequences,
+                vec![
+                    (vec![Key::Char('g'), Key::Char('g')], false),
+                    (vec![Key::Char('d'), Key::Char('d')], true),
+                ]
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_key_predicate_handlers() {
+    let node = node! {
+        div(
+            @char_range('0', '9'): |_digit| {},
+            @key_if(|key| matches!(key, Key::Up | Key::Down)): |_key| {},
+            @key_if_global(|key| key == Key::F1): |_key| {}
+        ) []
+    };
+
+    match node {
+        Node::Div(div) => {
+            let handlers = &div.events.on_key_if;
+            assert_eq!(handlers.len(), 3);
+            assert!(handlers[0].0(Key::Char('7')) && !handlers[0].0(Key::Char('a')));
+            assert!(handlers[1].0(Key::Down) && !handlers[1].0(Key::Left));
+            assert!(handlers[2].2);
+        }
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_focus_change_handler() {
+    let node = node! {
+        div(@focus_change: |_path: Option<ElementPath>| {}) []
+    };
+
+    match node {
+        Node::Div(div) => assert!(div.events.on_focus_change.is_some()),
+        _ => panic!("Expected div node"),
+    }
+}
+
+#[test]
+fn test_selected_child_props() {
+    let cursor = Some(1);
+    let node = node! {
+        div(selected: cursor, selected_style: (Style::new().background(Color::Blue))) [
+            text("one"),
+            text("two")
+        ]
+    };
+
+    match node {
+        Node::Div(div) => {
+            assert_eq!(div.selected, Some(1));
+            assert_eq!(
+                div.selected_style.and_then(|style| style.background),
+                Some(Color::Blue)
+            );
+        }
+        _ => panic!("Expected div node"),
+    }
+}